@@ -31,4 +31,10 @@ pub enum BenchmarkTransport {
     #[display("QUIC")]
     #[serde(rename = "quic")]
     Quic,
+    #[display("Unix")]
+    #[serde(rename = "unix")]
+    Unix,
+    #[display("WebSocket")]
+    #[serde(rename = "websocket")]
+    WebSocket,
 }
@@ -143,8 +143,15 @@ impl IggyBenchArgs {
     }
 
     pub fn validate(&mut self) {
-        let server_address = self.server_address().parse::<SocketAddr>().unwrap();
-        if (self.cleanup || self.verbose) && !server_address.ip().is_loopback() {
+        // Unix domain sockets are always local to the machine running the benchmark.
+        let is_local_server = *self.transport() == Transport::Unix
+            || self
+                .server_address()
+                .parse::<SocketAddr>()
+                .unwrap()
+                .ip()
+                .is_loopback();
+        if (self.cleanup || self.verbose) && !is_local_server {
             Self::command()
                 .error(
                     ErrorKind::ArgumentConflict,
@@ -370,6 +377,8 @@ impl IggyBenchArgs {
             BenchmarkTransportCommand::Tcp(_) => "tcp",
             BenchmarkTransportCommand::Quic(_) => "quic",
             BenchmarkTransportCommand::Http(_) => "http",
+            BenchmarkTransportCommand::Unix(_) => "unix",
+            BenchmarkTransportCommand::WebSocket(_) => "websocket",
         };
 
         let actors = match &self.benchmark_kind {
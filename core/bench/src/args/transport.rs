@@ -19,6 +19,7 @@
 use super::defaults::{
     DEFAULT_HTTP_SERVER_ADDRESS, DEFAULT_QUIC_CLIENT_ADDRESS, DEFAULT_QUIC_SERVER_ADDRESS,
     DEFAULT_QUIC_SERVER_NAME, DEFAULT_QUIC_VALIDATE_CERTIFICATE, DEFAULT_TCP_SERVER_ADDRESS,
+    DEFAULT_UNIX_SOCKET_PATH,
 };
 use super::{output::BenchmarkOutputCommand, props::BenchmarkTransportProps};
 use clap::{Parser, Subcommand};
@@ -30,6 +31,9 @@ pub enum BenchmarkTransportCommand {
     Http(HttpArgs),
     Tcp(TcpArgs),
     Quic(QuicArgs),
+    Unix(UnixArgs),
+    #[command(name = "websocket")]
+    WebSocket(WebSocketArgs),
 }
 
 impl Serialize for BenchmarkTransportCommand {
@@ -41,6 +45,8 @@ impl Serialize for BenchmarkTransportCommand {
             Self::Http(_) => "http",
             Self::Tcp(_) => "tcp",
             Self::Quic(_) => "quic",
+            Self::Unix(_) => "unix",
+            Self::WebSocket(_) => "websocket",
         };
         serializer.serialize_str(variant_str)
     }
@@ -72,6 +78,8 @@ impl BenchmarkTransportProps for BenchmarkTransportCommand {
             Self::Http(args) => args,
             Self::Tcp(args) => args,
             Self::Quic(args) => args,
+            Self::Unix(args) => args,
+            Self::WebSocket(args) => args,
         }
     }
 
@@ -222,3 +230,77 @@ impl BenchmarkTransportProps for QuicArgs {
         self.output.as_ref()
     }
 }
+
+#[derive(Parser, Debug, Clone)]
+pub struct UnixArgs {
+    /// Path to the Unix domain socket of the iggy-server
+    #[arg(long, default_value_t = DEFAULT_UNIX_SOCKET_PATH.to_owned())]
+    pub server_address: String,
+
+    /// Optional output command, used to output results (charts, raw json data) to a directory
+    #[command(subcommand)]
+    pub output: Option<BenchmarkOutputCommand>,
+}
+
+impl BenchmarkTransportProps for UnixArgs {
+    fn transport(&self) -> &Transport {
+        &Transport::Unix
+    }
+
+    fn server_address(&self) -> &str {
+        &self.server_address
+    }
+
+    fn validate_certificate(&self) -> bool {
+        panic!("Cannot validate certificate for Unix transport!")
+    }
+
+    fn client_address(&self) -> &str {
+        panic!("Setting client address for Unix transport is not supported!")
+    }
+
+    fn nodelay(&self) -> bool {
+        panic!("Setting nodelay for Unix transport is not supported!")
+    }
+
+    fn output_command(&self) -> Option<&BenchmarkOutputCommand> {
+        self.output.as_ref()
+    }
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct WebSocketArgs {
+    /// Address of the HTTP iggy-server exposing the WebSocket endpoint
+    #[arg(long, default_value_t = DEFAULT_HTTP_SERVER_ADDRESS.to_owned())]
+    pub server_address: String,
+
+    /// Optional output command, used to output results (charts, raw json data) to a directory
+    #[command(subcommand)]
+    pub output: Option<BenchmarkOutputCommand>,
+}
+
+impl BenchmarkTransportProps for WebSocketArgs {
+    fn transport(&self) -> &Transport {
+        &Transport::WebSocket
+    }
+
+    fn server_address(&self) -> &str {
+        &self.server_address
+    }
+
+    fn validate_certificate(&self) -> bool {
+        panic!("Cannot validate certificate for WebSocket transport!")
+    }
+
+    fn client_address(&self) -> &str {
+        panic!("Setting client address for WebSocket transport is not supported!")
+    }
+
+    fn nodelay(&self) -> bool {
+        panic!("Setting nodelay for WebSocket transport is not supported!")
+    }
+
+    fn output_command(&self) -> Option<&BenchmarkOutputCommand> {
+        self.output.as_ref()
+    }
+}
@@ -21,7 +21,7 @@ use integration::test_server::{IpAddrKind, SYSTEM_PATH_ENV_VAR, TestServer, Tran
 use serde::Deserialize;
 use std::net::SocketAddr;
 use std::{collections::HashMap, time::Instant};
-use tokio::net::{TcpStream, UdpSocket};
+use tokio::net::{TcpStream, UdpSocket, UnixStream};
 use tracing::{info, warn};
 
 #[derive(Debug, Deserialize)]
@@ -96,7 +96,7 @@ async fn evaluate_server_start_condition(args: &IggyBenchArgs) -> (bool, HashMap
         toml::from_str(include_str!("../../../configs/server.toml")).unwrap();
 
     match &args.transport() {
-        Transport::Http => {
+        Transport::Http | Transport::WebSocket => {
             let args_http_address = args.server_address().parse::<SocketAddr>().unwrap();
             let config_http_address = default_config.http.address.parse::<SocketAddr>().unwrap();
             let envs = HashMap::from([
@@ -149,6 +149,18 @@ async fn evaluate_server_start_condition(args: &IggyBenchArgs) -> (bool, HashMap
                 envs,
             )
         }
+        Transport::Unix => {
+            let socket_path = args.server_address();
+            let envs = HashMap::from([
+                ("IGGY_UNIX_ENABLED".to_owned(), "true".to_owned()),
+                ("IGGY_UNIX_PATH".to_owned(), socket_path.to_owned()),
+                ("IGGY_HTTP_ENABLED".to_owned(), "false".to_owned()),
+                ("IGGY_TCP_ENABLED".to_owned(), "false".to_owned()),
+                ("IGGY_QUIC_ENABLED".to_owned(), "false".to_owned()),
+            ]);
+
+            (!is_unix_socket_in_use(socket_path).await, envs)
+        }
     }
 }
 
@@ -156,6 +168,10 @@ async fn is_tcp_addr_in_use(addr: &SocketAddr) -> bool {
     TcpStream::connect(addr).await.is_ok()
 }
 
+async fn is_unix_socket_in_use(socket_path: &str) -> bool {
+    UnixStream::connect(socket_path).await.is_ok()
+}
+
 async fn is_udp_addr_in_use(addr: &SocketAddr) -> bool {
     UdpSocket::bind(addr).await.is_err()
 }
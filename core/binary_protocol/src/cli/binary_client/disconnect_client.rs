@@ -0,0 +1,62 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy_common::disconnect_client::DisconnectClient;
+use tracing::{Level, event};
+
+pub struct DisconnectClientCmd {
+    disconnect_client: DisconnectClient,
+}
+
+impl DisconnectClientCmd {
+    pub fn new(client_id: u32) -> Self {
+        Self {
+            disconnect_client: DisconnectClient { client_id },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for DisconnectClientCmd {
+    fn explain(&self) -> String {
+        format!(
+            "disconnect client with ID: {}",
+            self.disconnect_client.client_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .disconnect_client(self.disconnect_client.client_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem disconnecting client with ID: {}",
+                    self.disconnect_client.client_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO, "Client with ID: {} disconnected", self.disconnect_client.client_id);
+
+        Ok(())
+    }
+}
@@ -75,6 +75,52 @@ impl CliCommand for GetClientCmd {
 
         table.add_row(vec!["Address", client_details.address.as_str()]);
         table.add_row(vec!["Transport", client_details.transport.as_str()]);
+        table.add_row(vec![
+            "Authentication Method",
+            client_details
+                .authentication_method
+                .as_deref()
+                .unwrap_or("None"),
+        ]);
+        table.add_row(vec![
+            "Connected At",
+            client_details
+                .connected_at
+                .to_local_string("%Y-%m-%d %H:%M:%S")
+                .as_str(),
+        ]);
+        table.add_row(vec![
+            "Last Activity At",
+            client_details
+                .last_activity_at
+                .to_local_string("%Y-%m-%d %H:%M:%S")
+                .as_str(),
+        ]);
+        table.add_row(vec![
+            "Bytes Received",
+            client_details.bytes_received.as_human_string().as_str(),
+        ]);
+        table.add_row(vec![
+            "Bytes Sent",
+            client_details.bytes_sent.as_human_string().as_str(),
+        ]);
+        if let Some(metadata) = &client_details.metadata {
+            table.add_row(vec!["Application", metadata.application_name.as_str()]);
+            table.add_row(vec![
+                "SDK",
+                format!("{} {}", metadata.sdk_language, metadata.sdk_version).as_str(),
+            ]);
+            table.add_row(vec!["Hostname", metadata.hostname.as_str()]);
+            if !metadata.labels.is_empty() {
+                let labels = metadata
+                    .labels
+                    .iter()
+                    .map(|(key, value)| format!("{key}={value}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                table.add_row(vec!["Labels", labels.as_str()]);
+            }
+        }
         table.add_row(vec![
             "Consumer Groups Count",
             format!("{}", client_details.consumer_groups_count).as_str(),
@@ -82,6 +82,9 @@ impl CliCommand for GetClientsCmd {
                     "User ID",
                     "Address",
                     "Transport",
+                    "Application",
+                    "Authentication",
+                    "Last Activity",
                     "Consumer Groups",
                 ]);
 
@@ -94,6 +97,18 @@ impl CliCommand for GetClientsCmd {
                         },
                         format!("{}", client_info.address),
                         format!("{}", client_info.transport),
+                        client_info
+                            .metadata
+                            .as_ref()
+                            .map(|metadata| metadata.application_name.clone())
+                            .unwrap_or_default(),
+                        client_info
+                            .authentication_method
+                            .clone()
+                            .unwrap_or_default(),
+                        client_info
+                            .last_activity_at
+                            .to_local_string("%Y-%m-%d %H:%M:%S"),
                         format!("{}", client_info.consumer_groups_count),
                     ]);
                 });
@@ -103,7 +118,7 @@ impl CliCommand for GetClientsCmd {
             GetClientsOutput::List => {
                 clients.iter().for_each(|client_info| {
                     event!(target: PRINT_TARGET, Level::INFO,
-                        "{}|{}|{}|{}|{}",
+                        "{}|{}|{}|{}|{}|{}|{}|{}",
                         client_info.client_id,
                         match client_info.user_id {
                             Some(user_id) => format!("{user_id}"),
@@ -111,6 +126,18 @@ impl CliCommand for GetClientsCmd {
                         },
                         client_info.address,
                         client_info.transport,
+                        client_info
+                            .metadata
+                            .as_ref()
+                            .map(|metadata| metadata.application_name.as_str())
+                            .unwrap_or_default(),
+                        client_info
+                            .authentication_method
+                            .as_deref()
+                            .unwrap_or_default(),
+                        client_info
+                            .last_activity_at
+                            .to_local_string("%Y-%m-%d %H:%M:%S"),
                         client_info.consumer_groups_count
                     );
                 });
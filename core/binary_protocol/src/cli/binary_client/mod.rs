@@ -16,5 +16,6 @@
  * under the License.
  */
 
+pub mod disconnect_client;
 pub mod get_client;
 pub mod get_clients;
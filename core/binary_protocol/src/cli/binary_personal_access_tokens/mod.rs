@@ -19,3 +19,4 @@
 pub mod create_personal_access_token;
 pub mod delete_personal_access_tokens;
 pub mod get_personal_access_tokens;
+pub mod update_personal_access_token_quota;
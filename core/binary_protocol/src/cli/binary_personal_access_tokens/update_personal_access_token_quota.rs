@@ -0,0 +1,74 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy_common::update_personal_access_token_quota::UpdatePersonalAccessTokenQuota;
+use iggy_common::{Identifier, ThroughputQuota};
+use tracing::{Level, event};
+
+pub struct UpdatePersonalAccessTokenQuotaCmd {
+    update_quota: UpdatePersonalAccessTokenQuota,
+}
+
+impl UpdatePersonalAccessTokenQuotaCmd {
+    pub fn new(user_id: Identifier, name: String, quota: Option<ThroughputQuota>) -> Self {
+        Self {
+            update_quota: UpdatePersonalAccessTokenQuota {
+                user_id,
+                name,
+                quota,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for UpdatePersonalAccessTokenQuotaCmd {
+    fn explain(&self) -> String {
+        format!(
+            "update quota for personal access token: {} of user with ID: {}",
+            self.update_quota.name, self.update_quota.user_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .update_personal_access_token_quota(
+                &self.update_quota.user_id,
+                &self.update_quota.name,
+                self.update_quota.quota,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem updating quota for personal access token: {} of user with ID: {}",
+                    self.update_quota.name, self.update_quota.user_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Quota for personal access token: {} of user with ID: {} updated",
+            self.update_quota.name, self.update_quota.user_id
+        );
+
+        Ok(())
+    }
+}
@@ -75,6 +75,18 @@ impl CliCommand for GetStreamCmd {
             "Stream topics count",
             format!("{}", stream.topics_count).as_str(),
         ]);
+        table.add_row(vec![
+            "Stream max size",
+            format!("{}", stream.max_size).as_str(),
+        ]);
+        table.add_row(vec![
+            "Stream expiry",
+            format!("{}", stream.message_expiry).as_str(),
+        ]);
+        table.add_row(vec![
+            "Stream size policy",
+            format!("{}", stream.size_policy).as_str(),
+        ]);
 
         event!(target: PRINT_TARGET, Level::INFO, "{table}");
 
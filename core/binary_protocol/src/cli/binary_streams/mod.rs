@@ -22,3 +22,4 @@ pub mod get_stream;
 pub mod get_streams;
 pub mod purge_stream;
 pub mod update_stream;
+pub mod update_stream_retention;
@@ -0,0 +1,84 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy_common::update_stream_retention::UpdateStreamRetention;
+use iggy_common::{Identifier, IggyExpiry, MaxTopicSize, StreamSizePolicy};
+use tracing::{Level, event};
+
+pub struct UpdateStreamRetentionCmd {
+    update_stream_retention: UpdateStreamRetention,
+}
+
+impl UpdateStreamRetentionCmd {
+    pub fn new(
+        stream_id: Identifier,
+        max_size: MaxTopicSize,
+        message_expiry: IggyExpiry,
+        size_policy: StreamSizePolicy,
+    ) -> Self {
+        UpdateStreamRetentionCmd {
+            update_stream_retention: UpdateStreamRetention {
+                stream_id,
+                max_size,
+                message_expiry,
+                size_policy,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for UpdateStreamRetentionCmd {
+    fn explain(&self) -> String {
+        format!(
+            "update retention for stream with ID: {}, max size: {}, message expiry: {}, size policy: {}",
+            self.update_stream_retention.stream_id,
+            self.update_stream_retention.max_size,
+            self.update_stream_retention.message_expiry,
+            self.update_stream_retention.size_policy
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .update_stream_retention(
+                &self.update_stream_retention.stream_id,
+                self.update_stream_retention.max_size,
+                self.update_stream_retention.message_expiry,
+                self.update_stream_retention.size_policy,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem updating retention for stream with ID: {}",
+                    self.update_stream_retention.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Retention for stream with ID: {} updated",
+            self.update_stream_retention.stream_id
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::{Context, bail};
+use async_trait::async_trait;
+use comfy_table::Table;
+use iggy_common::HealthProbe;
+use iggy_common::get_health::GetHealth;
+use tracing::{Level, event};
+
+pub struct GetHealthCmd {
+    get_health: GetHealth,
+}
+
+impl GetHealthCmd {
+    pub fn new(probe: HealthProbe) -> Self {
+        Self {
+            get_health: GetHealth { probe },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetHealthCmd {
+    fn explain(&self) -> String {
+        format!("check server {}", self.get_health.probe)
+    }
+
+    fn login_required(&self) -> bool {
+        false
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let probe = self.get_health.probe;
+        let health = client
+            .get_health(probe)
+            .await
+            .with_context(|| format!("Problem checking server {probe}"))?;
+
+        if !health.checks.is_empty() {
+            let mut table = Table::new();
+            table.set_header(vec!["Check", "Status", "Details"]);
+            for check in &health.checks {
+                table.add_row(vec![
+                    check.name.clone(),
+                    check.status.to_string(),
+                    check.details.clone(),
+                ]);
+            }
+            event!(target: PRINT_TARGET, Level::INFO, "{table}");
+        }
+
+        if !health.is_up() {
+            bail!("Server {probe} check failed, status: {}", health.status);
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "Server {probe} check passed, status: {}", health.status);
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy_common::get_log_filter::GetLogFilter;
+use iggy_common::set_log_filter::SetLogFilter;
+use iggy_common::{IggyDuration, LogFilter};
+use tracing::{Level, event};
+
+pub struct GetLogLevelCmd {
+    _get_log_filter: GetLogFilter,
+}
+
+impl GetLogLevelCmd {
+    pub fn new() -> Self {
+        Self {
+            _get_log_filter: GetLogFilter {},
+        }
+    }
+}
+
+impl Default for GetLogLevelCmd {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetLogLevelCmd {
+    fn explain(&self) -> String {
+        "get server log level".to_owned()
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let log_filter = client
+            .get_log_filter()
+            .await
+            .with_context(|| "Problem getting server log level".to_owned())?;
+
+        print_log_filter(&log_filter);
+        Ok(())
+    }
+}
+
+pub struct SetLogLevelCmd {
+    set_log_filter: SetLogFilter,
+}
+
+impl SetLogLevelCmd {
+    pub fn new(filter: Option<String>, ttl: Option<IggyDuration>) -> Self {
+        Self {
+            set_log_filter: SetLogFilter { filter, ttl },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for SetLogLevelCmd {
+    fn explain(&self) -> String {
+        match (&self.set_log_filter.filter, &self.set_log_filter.ttl) {
+            (Some(filter), Some(ttl)) => format!("set server log level to: {filter} for: {ttl}"),
+            (Some(filter), None) => format!("set server log level to: {filter}"),
+            (None, _) => "reset server log level".to_owned(),
+        }
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let log_filter = client
+            .set_log_filter(
+                self.set_log_filter.filter.as_deref(),
+                self.set_log_filter.ttl,
+            )
+            .await
+            .with_context(|| format!("Problem setting server log level: {}", self.explain()))?;
+
+        print_log_filter(&log_filter);
+        Ok(())
+    }
+}
+
+fn print_log_filter(log_filter: &LogFilter) {
+    event!(target: PRINT_TARGET, Level::INFO, "Log level: {}", log_filter.filter);
+    event!(target: PRINT_TARGET, Level::INFO, "Default log level: {}", log_filter.default_filter);
+    if let Some(expires_at) = log_filter.expires_at {
+        event!(target: PRINT_TARGET, Level::INFO, "Reverts to default at: {}", expires_at.to_local_string("%Y-%m-%d %H:%M:%S"));
+    }
+}
@@ -16,10 +16,15 @@
  * under the License.
  */
 
+pub mod health;
+pub mod log_level;
 pub mod login;
 pub mod logout;
 pub mod me;
 pub mod ping;
+pub mod profile;
+pub mod reload_config;
 pub mod session;
 pub mod snapshot;
 pub mod stats;
+pub mod stats_history;
@@ -0,0 +1,103 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::path::Path;
+
+use crate::Client;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use iggy_common::get_profile::GetProfile;
+use iggy_common::{IggyDuration, ProfileFormat, ProfileKind};
+use tokio::io::AsyncWriteExt;
+use tracing::{Level, event};
+
+pub struct GetProfileCmd {
+    get_profile: GetProfile,
+    out_dir: String,
+}
+
+impl GetProfileCmd {
+    pub fn new(
+        kind: ProfileKind,
+        format: ProfileFormat,
+        duration: IggyDuration,
+        out_dir: Option<String>,
+    ) -> Self {
+        Self {
+            get_profile: GetProfile {
+                kind,
+                format,
+                duration,
+            },
+            out_dir: out_dir.unwrap_or_else(|| ".".to_string()),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetProfileCmd {
+    fn explain(&self) -> String {
+        match self.get_profile.kind {
+            ProfileKind::Cpu => format!(
+                "profile command, capturing {} CPU profile for {}",
+                self.get_profile.format,
+                self.get_profile.duration.as_human_time_string()
+            ),
+            ProfileKind::Heap => "profile command, capturing heap profile".to_owned(),
+        }
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let profile = client
+            .profile(
+                self.get_profile.kind,
+                self.get_profile.format,
+                self.get_profile.duration,
+            )
+            .await
+            .with_context(|| "Problem sending profile command".to_owned())?;
+        let file_path = Path::new(&self.out_dir).join(format!(
+            "{}_profile_{}.{}",
+            self.get_profile.kind,
+            chrono::Local::now().format("%Y%m%d_%H%M%S"),
+            self.get_profile
+                .format
+                .file_extension(self.get_profile.kind)
+        ));
+        let file_size = profile.0.len();
+
+        let mut file = tokio::fs::File::create(&file_path)
+            .await
+            .with_context(|| format!("Failed to create file at {file_path:?}"))?;
+
+        file.write_all(&profile.0)
+            .await
+            .with_context(|| "Failed to write profile data to file".to_owned())?;
+
+        let mut table = Table::new();
+        table.set_header(vec!["Property", "Value"]);
+        table.add_row(vec!["File Path", file_path.to_string_lossy().as_ref()]);
+        table.add_row(vec!["File Size (bytes)", &file_size.to_string()]);
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::Client;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy_common::reload_config::ReloadConfig;
+use tracing::{Level, event};
+
+pub struct ReloadConfigCmd {
+    _reload_config: ReloadConfig,
+}
+
+impl ReloadConfigCmd {
+    pub fn new() -> Self {
+        Self {
+            _reload_config: ReloadConfig {},
+        }
+    }
+}
+
+impl Default for ReloadConfigCmd {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CliCommand for ReloadConfigCmd {
+    fn explain(&self) -> String {
+        "reload server config".to_owned()
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let config_reload = client
+            .reload_config()
+            .await
+            .with_context(|| "Problem reloading server config".to_owned())?;
+
+        if config_reload.is_unchanged() {
+            event!(target: PRINT_TARGET, Level::INFO, "Server config reloaded, no settings changed");
+            return Ok(());
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "Server config reloaded");
+        for path in &config_reload.applied {
+            event!(target: PRINT_TARGET, Level::INFO, "Applied: {path}");
+        }
+        for path in &config_reload.restart_required {
+            event!(target: PRINT_TARGET, Level::INFO, "Requires restart: {path}");
+        }
+
+        Ok(())
+    }
+}
@@ -158,6 +158,14 @@ impl CliCommand for GetStatsCmd {
                     "Consumer Groups Count",
                     format!("{}", stats.consumer_groups_count).as_str(),
                 ]);
+                table.add_row(vec![
+                    "Throttled Requests Count",
+                    format!("{}", stats.throttled_requests_count).as_str(),
+                ]);
+                table.add_row(vec![
+                    "Throttle Time",
+                    stats.throttle_time.as_human_time_string().as_str(),
+                ]);
 
                 table.add_row(vec!["OS Name", stats.os_name.as_str()]);
                 table.add_row(vec!["OS Version", stats.os_version.as_str()]);
@@ -207,6 +215,14 @@ impl CliCommand for GetStatsCmd {
                     "Consumer Groups Count|{}",
                     stats.consumer_groups_count
                 ));
+                list.push(format!(
+                    "Throttled Requests Count|{}",
+                    stats.throttled_requests_count
+                ));
+                list.push(format!(
+                    "Throttle Time|{}",
+                    stats.throttle_time.as_human_time_string()
+                ));
 
                 list.push(format!("OS Name|{}", stats.os_name));
                 list.push(format!("OS Version|{}", stats.os_version));
@@ -0,0 +1,225 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use iggy_common::get_stats_history::GetStatsHistory;
+use iggy_common::{IggyByteSize, StatsSample, StatsSeries};
+use std::collections::BTreeMap;
+use tracing::{Level, event};
+
+const SPARKLINE_TICKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const SPARKLINE_WIDTH: usize = 60;
+const TOP_TOPICS_COUNT: usize = 5;
+
+pub struct GetStatsHistoryCmd {
+    json: bool,
+    _get_stats_history: GetStatsHistory,
+}
+
+impl GetStatsHistoryCmd {
+    pub fn new(json: bool) -> Self {
+        Self {
+            json,
+            _get_stats_history: GetStatsHistory {},
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetStatsHistoryCmd {
+    fn explain(&self) -> String {
+        "stats history command".to_owned()
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let stats_history = client
+            .get_stats_history()
+            .await
+            .with_context(|| "Problem sending get_stats_history command".to_owned())?;
+
+        if self.json {
+            let output = serde_json::to_string_pretty(&stats_history)?;
+            event!(target: PRINT_TARGET, Level::INFO, "{output}");
+            return Ok(());
+        }
+
+        for series in &stats_history.series {
+            event!(target: PRINT_TARGET, Level::INFO, "{}", render_series(series));
+        }
+        Ok(())
+    }
+}
+
+fn render_series(series: &StatsSeries) -> String {
+    let header = format!(
+        "Sampled every {} for the last {}, {} sample(s)",
+        series.interval.as_human_time_string(),
+        series.retention.as_human_time_string(),
+        series.samples.len()
+    );
+    if series.samples.is_empty() {
+        return format!("{header}\n");
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec!["Metric", "History", "Min", "Max", "Last"]);
+    add_percent_row(&mut table, "CPU Usage", &series.samples, |s| s.cpu_usage);
+    add_percent_row(&mut table, "Total CPU Usage", &series.samples, |s| {
+        s.total_cpu_usage
+    });
+    add_bytes_row(&mut table, "Memory Usage", &series.samples, |s| {
+        s.memory_usage.as_bytes_u64()
+    });
+    add_bytes_row(&mut table, "Available Memory", &series.samples, |s| {
+        s.available_memory.as_bytes_u64()
+    });
+    add_count_row(&mut table, "Clients Count", &series.samples, |s| {
+        s.clients_count as u64
+    });
+    add_count_row(&mut table, "Appended Messages", &series.samples, |s| {
+        s.appended_messages()
+    });
+    add_bytes_row(&mut table, "Appended Bytes", &series.samples, |s| {
+        s.appended_bytes().as_bytes_u64()
+    });
+    add_bytes_row(&mut table, "Read Bytes", &series.samples, |s| {
+        s.read_bytes.as_bytes_u64()
+    });
+    add_bytes_row(&mut table, "Written Bytes", &series.samples, |s| {
+        s.written_bytes.as_bytes_u64()
+    });
+
+    let mut topics = BTreeMap::<(u32, u32), Vec<u64>>::new();
+    for (index, sample) in series.samples.iter().enumerate() {
+        for topic in &sample.topics {
+            topics
+                .entry((topic.stream_id, topic.topic_id))
+                .or_insert_with(|| vec![0; series.samples.len()])[index] = topic.messages_count;
+        }
+    }
+    let mut topics = topics.into_iter().collect::<Vec<_>>();
+    topics.sort_by_key(|(_, values)| std::cmp::Reverse(values.iter().sum::<u64>()));
+    for ((stream_id, topic_id), values) in topics.into_iter().take(TOP_TOPICS_COUNT) {
+        let name = format!("Topic {stream_id}/{topic_id} Messages");
+        add_row(&mut table, &name, &values, |value| value.to_string());
+    }
+
+    format!("{header}\n{table}\n")
+}
+
+fn add_percent_row(
+    table: &mut Table,
+    name: &str,
+    samples: &[StatsSample],
+    value: impl Fn(&StatsSample) -> f32,
+) {
+    // Percentages are scaled to hundredths, so that the sparkline doesn't lose the precision.
+    let values = samples
+        .iter()
+        .map(|sample| (value(sample) * 100.0).round() as u64)
+        .collect::<Vec<_>>();
+    add_row(table, name, &values, |value| {
+        format!("{:.2} %", value as f64 / 100.0)
+    });
+}
+
+fn add_bytes_row(
+    table: &mut Table,
+    name: &str,
+    samples: &[StatsSample],
+    value: impl Fn(&StatsSample) -> u64,
+) {
+    let values = samples.iter().map(value).collect::<Vec<_>>();
+    add_row(table, name, &values, |value| {
+        IggyByteSize::from(value).as_human_string()
+    });
+}
+
+fn add_count_row(
+    table: &mut Table,
+    name: &str,
+    samples: &[StatsSample],
+    value: impl Fn(&StatsSample) -> u64,
+) {
+    let values = samples.iter().map(value).collect::<Vec<_>>();
+    add_row(table, name, &values, |value| value.to_string());
+}
+
+fn add_row(table: &mut Table, name: &str, values: &[u64], format: impl Fn(u64) -> String) {
+    let min = values.iter().copied().min().unwrap_or_default();
+    let max = values.iter().copied().max().unwrap_or_default();
+    let last = values.last().copied().unwrap_or_default();
+    table.add_row(vec![
+        name.to_owned(),
+        sparkline(values, SPARKLINE_WIDTH),
+        format(min),
+        format(max),
+        format(last),
+    ]);
+}
+
+/// Renders the values as a sparkline, if there are more values than the width,
+/// they're split into the buckets and the maximum of each bucket is displayed,
+/// so that the spikes are not hidden.
+fn sparkline(values: &[u64], width: usize) -> String {
+    if values.is_empty() || width == 0 {
+        return String::new();
+    }
+
+    let bucket_size = values.len().div_ceil(width);
+    let values = values
+        .chunks(bucket_size)
+        .map(|bucket| bucket.iter().copied().max().unwrap_or_default())
+        .collect::<Vec<_>>();
+    let min = values.iter().copied().min().unwrap_or_default();
+    let max = values.iter().copied().max().unwrap_or_default();
+    let range = max - min;
+    values
+        .iter()
+        .map(|value| {
+            if range == 0 {
+                return SPARKLINE_TICKS[0];
+            }
+            let index = ((value - min) as u128 * (SPARKLINE_TICKS.len() - 1) as u128
+                / range as u128) as usize;
+            SPARKLINE_TICKS[index]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparkline_should_scale_values_between_lowest_and_highest_tick() {
+        assert_eq!(sparkline(&[0, 7, 14], 10), "▁▄█");
+        assert_eq!(sparkline(&[5, 5, 5], 10), "▁▁▁");
+        assert_eq!(sparkline(&[], 10), "");
+    }
+
+    #[test]
+    fn sparkline_should_keep_maximum_of_each_bucket_when_values_exceed_width() {
+        assert_eq!(sparkline(&[0, 0, 0, 9, 0, 0], 3), "▁█▁");
+        assert_eq!(sparkline(&[1; 100], 10).chars().count(), 10);
+    }
+}
@@ -21,8 +21,8 @@ use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
 use anyhow::Context;
 use async_trait::async_trait;
 use comfy_table::Table;
-use iggy_common::Identifier;
 use iggy_common::get_user::GetUser;
+use iggy_common::{Identifier, IggyByteSize};
 use tracing::{Level, event};
 
 pub struct GetUserCmd {
@@ -88,8 +88,39 @@ impl CliCommand for GetUserCmd {
             }
         };
 
+        if let Some(quota) = user.quota {
+            table.add_row(vec![
+                "Produce Quota",
+                format_quota(
+                    quota.produce_bytes_per_second,
+                    quota.produce_messages_per_second,
+                )
+                .as_str(),
+            ]);
+            table.add_row(vec![
+                "Consume Quota",
+                format_quota(
+                    quota.consume_bytes_per_second,
+                    quota.consume_messages_per_second,
+                )
+                .as_str(),
+            ]);
+        }
+
         event!(target: PRINT_TARGET, Level::INFO, "{table}");
 
         Ok(())
     }
 }
+
+fn format_quota(bytes_per_second: u64, messages_per_second: u64) -> String {
+    let bytes = match bytes_per_second {
+        0 => "unlimited".to_string(),
+        bytes => format!("{}/s", IggyByteSize::from(bytes).as_human_string()),
+    };
+    let messages = match messages_per_second {
+        0 => "unlimited".to_string(),
+        messages => format!("{messages} msg/s"),
+    };
+    format!("{bytes}, {messages}")
+}
@@ -21,5 +21,7 @@ pub mod create_user;
 pub mod delete_user;
 pub mod get_user;
 pub mod get_users;
+pub mod revoke_user_sessions;
 pub mod update_permissions;
 pub mod update_user;
+pub mod update_user_quota;
@@ -0,0 +1,63 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy_common::Identifier;
+use iggy_common::revoke_user_sessions::RevokeUserSessions;
+use tracing::{Level, event};
+
+pub struct RevokeUserSessionsCmd {
+    revoke_user_sessions: RevokeUserSessions,
+}
+
+impl RevokeUserSessionsCmd {
+    pub fn new(user_id: Identifier) -> Self {
+        Self {
+            revoke_user_sessions: RevokeUserSessions { user_id },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for RevokeUserSessionsCmd {
+    fn explain(&self) -> String {
+        format!(
+            "revoke sessions of user with ID: {}",
+            self.revoke_user_sessions.user_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .revoke_user_sessions(&self.revoke_user_sessions.user_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem revoking sessions of user with ID: {}",
+                    self.revoke_user_sessions.user_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO, "Sessions of user with ID: {} revoked", self.revoke_user_sessions.user_id);
+
+        Ok(())
+    }
+}
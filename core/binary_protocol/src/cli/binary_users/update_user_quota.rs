@@ -0,0 +1,69 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::Client;
+use crate::cli::cli_command::{CliCommand, PRINT_TARGET};
+use anyhow::Context;
+use async_trait::async_trait;
+use iggy_common::update_user_quota::UpdateUserQuota;
+use iggy_common::{Identifier, ThroughputQuota};
+use tracing::{Level, event};
+
+pub struct UpdateUserQuotaCmd {
+    update_user_quota: UpdateUserQuota,
+}
+
+impl UpdateUserQuotaCmd {
+    pub fn new(user_id: Identifier, quota: Option<ThroughputQuota>) -> Self {
+        Self {
+            update_user_quota: UpdateUserQuota { user_id, quota },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for UpdateUserQuotaCmd {
+    fn explain(&self) -> String {
+        format!(
+            "update quota for user with ID: {}",
+            self.update_user_quota.user_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .update_user_quota(
+                &self.update_user_quota.user_id,
+                self.update_user_quota.quota,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem updating quota for user with ID: {}",
+                    self.update_user_quota.user_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Quota for user with ID: {} updated",
+            self.update_user_quota.user_id
+        );
+
+        Ok(())
+    }
+}
@@ -17,7 +17,8 @@
  */
 use async_trait::async_trait;
 use iggy_common::{
-    Consumer, Identifier, IggyError, IggyMessage, Partitioning, PolledMessages, PollingStrategy,
+    Consumer, Identifier, IggyDuration, IggyError, IggyMessage, Partitioning, PolledMessages,
+    PollingStrategy,
 };
 
 /// This trait defines the methods to interact with the messaging module.
@@ -49,6 +50,22 @@ pub trait MessageClient {
         messages: &mut [IggyMessage],
     ) -> Result<(), IggyError>;
 
+    /// Send messages like `send_messages`, returning the time for which the request has been throttled by the server
+    /// due to the exceeded quota, if any.
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    async fn send_messages_with_throttle_time(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [IggyMessage],
+    ) -> Result<Option<IggyDuration>, IggyError> {
+        self.send_messages(stream_id, topic_id, partitioning, messages)
+            .await
+            .map(|_| None)
+    }
+
     /// Force flush of the `unsaved_messages` buffer to disk, optionally fsyncing the data.
     #[allow(clippy::too_many_arguments)]
     async fn flush_unsaved_buffer(
@@ -18,8 +18,8 @@
 
 use async_trait::async_trait;
 use iggy_common::{
-    IdentityInfo, IggyError, PersonalAccessTokenExpiry, PersonalAccessTokenInfo,
-    RawPersonalAccessToken,
+    Identifier, IdentityInfo, IggyError, PersonalAccessTokenExpiry, PersonalAccessTokenInfo,
+    RawPersonalAccessToken, ThroughputQuota,
 };
 
 /// This trait defines the methods to interact with the personal access token module.
@@ -35,6 +35,16 @@ pub trait PersonalAccessTokenClient {
     ) -> Result<RawPersonalAccessToken, IggyError>;
     /// Delete a personal access token of the currently authenticated user by unique token name.
     async fn delete_personal_access_token(&self, name: &str) -> Result<(), IggyError>;
+    /// Update the throughput quota of the personal access token of a user by unique ID or username and token name.
+    /// The quota of the token takes precedence over the quota of the user for the clients authenticated with this token.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn update_personal_access_token_quota(
+        &self,
+        user_id: &Identifier,
+        name: &str,
+        quota: Option<ThroughputQuota>,
+    ) -> Result<(), IggyError>;
     /// Login the user with the provided personal access token.
     async fn login_with_personal_access_token(
         &self,
@@ -17,7 +17,9 @@
  */
 
 use async_trait::async_trait;
-use iggy_common::{Identifier, IggyError, Stream, StreamDetails};
+use iggy_common::{
+    Identifier, IggyError, IggyExpiry, MaxTopicSize, Stream, StreamDetails, StreamSizePolicy,
+};
 
 /// This trait defines the methods to interact with the stream module.
 #[async_trait]
@@ -50,4 +52,17 @@ pub trait StreamClient {
     ///
     /// Authentication is required, and the permission to manage the streams.
     async fn purge_stream(&self, stream_id: &Identifier) -> Result<(), IggyError>;
+    /// Update the storage limits of a stream by unique ID or name.
+    ///
+    /// The maximum size is shared by all the topics of the stream, and the message expiry
+    /// is applied to the topics which do not define their own expiry.
+    ///
+    /// Authentication is required, and the permission to manage the streams.
+    async fn update_stream_retention(
+        &self,
+        stream_id: &Identifier,
+        max_size: MaxTopicSize,
+        message_expiry: IggyExpiry,
+        size_policy: StreamSizePolicy,
+    ) -> Result<(), IggyError>;
 }
@@ -17,9 +17,11 @@
  */
 
 use async_trait::async_trait;
+use iggy_common::hello::Hello;
 use iggy_common::{
-    ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot, SnapshotCompression, Stats,
-    SystemSnapshotType,
+    ClientInfo, ClientInfoDetails, ClientMetadata, ConfigReload, Health, HealthProbe, IggyDuration,
+    IggyError, LogFilter, Profile, ProfileFormat, ProfileKind, ServerHello, Snapshot,
+    SnapshotCompression, Stats, StatsHistory, SystemSnapshotType,
 };
 
 /// This trait defines the methods to interact with the system module.
@@ -29,6 +31,11 @@ pub trait SystemClient {
     ///
     /// Authentication is required, and the permission to read the server info.
     async fn get_stats(&self) -> Result<Stats, IggyError>;
+    /// Get the history of the stats sampled periodically by the server, such as the CPU and memory usage
+    /// or the throughput of the topics, one series per configured resolution.
+    ///
+    /// Authentication is required, and the permission to read the server info.
+    async fn get_stats_history(&self) -> Result<StatsHistory, IggyError>;
     /// Get the info about the currently connected client (not to be confused with the user).
     ///
     /// Authentication is required.
@@ -41,6 +48,18 @@ pub trait SystemClient {
     ///
     /// Authentication is required, and the permission to read the server info.
     async fn get_clients(&self) -> Result<Vec<ClientInfo>, IggyError>;
+    /// Forcibly disconnect a specific client by unique ID (not to be confused with the user).
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn disconnect_client(&self, client_id: u32) -> Result<(), IggyError>;
+    /// Negotiate the protocol version and capabilities with the server, returning the ones supported by the server.
+    ///
+    /// Authentication is not required.
+    async fn hello(&self, hello: &Hello) -> Result<ServerHello, IggyError>;
+    /// Attach the identification metadata (application name, SDK, hostname and labels) to the currently connected client.
+    ///
+    /// Authentication is not required.
+    async fn identify_client(&self, metadata: &ClientMetadata) -> Result<(), IggyError>;
     /// Ping the server to check if it's alive.
     async fn ping(&self) -> Result<(), IggyError>;
     async fn heartbeat_interval(&self) -> IggyDuration;
@@ -52,4 +71,37 @@ pub trait SystemClient {
         compression: SnapshotCompression,
         snapshot_types: Vec<SystemSnapshotType>,
     ) -> Result<Snapshot, IggyError>;
+    /// Re-read the server configuration and apply the changes which don't require a restart,
+    /// returning the changed settings which have been applied and the ones requiring a restart.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn reload_config(&self) -> Result<ConfigReload, IggyError>;
+    /// Check whether the server is alive or ready to serve the traffic, returning the results of the component checks.
+    ///
+    /// Authentication is not required.
+    async fn get_health(&self, probe: HealthProbe) -> Result<Health, IggyError>;
+    /// Get the tracing filter of the running server, along with the default one.
+    ///
+    /// Authentication is required, and the permission to read the server info.
+    async fn get_log_filter(&self) -> Result<LogFilter, IggyError>;
+    /// Change the tracing filter of the running server (e.g. `info,server::streaming=debug`),
+    /// optionally reverting it to the default one after the TTL. If the filter is not provided,
+    /// the default one is restored.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn set_log_filter(
+        &self,
+        filter: Option<&str>,
+        ttl: Option<IggyDuration>,
+    ) -> Result<LogFilter, IggyError>;
+    /// Capture the profile of the running server, the CPU one is sampled for the provided duration,
+    /// while the heap one is available only if the server is built with the `jemalloc` feature.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn profile(
+        &self,
+        kind: ProfileKind,
+        format: ProfileFormat,
+        duration: IggyDuration,
+    ) -> Result<Profile, IggyError>;
 }
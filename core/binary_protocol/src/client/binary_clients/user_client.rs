@@ -18,7 +18,8 @@
 
 use async_trait::async_trait;
 use iggy_common::{
-    Identifier, IdentityInfo, IggyError, Permissions, UserInfo, UserInfoDetails, UserStatus,
+    Identifier, IdentityInfo, IggyError, Permissions, ThroughputQuota, UserInfo, UserInfoDetails,
+    UserStatus,
 };
 
 /// This trait defines the methods to interact with the user module.
@@ -63,6 +64,15 @@ pub trait UserClient {
         user_id: &Identifier,
         permissions: Option<Permissions>,
     ) -> Result<(), IggyError>;
+    /// Update the throughput quota of a user by unique ID or username.
+    /// When the quota is exceeded, the server delays the responses instead of rejecting the requests.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn update_user_quota(
+        &self,
+        user_id: &Identifier,
+        quota: Option<ThroughputQuota>,
+    ) -> Result<(), IggyError>;
     /// Change the password of a user by unique ID or username.
     ///
     /// Authentication is required, and the permission to manage the users, unless the provided user ID is the same as the authenticated user.
@@ -76,4 +86,8 @@ pub trait UserClient {
     async fn login_user(&self, username: &str, password: &str) -> Result<IdentityInfo, IggyError>;
     /// Logout the currently authenticated user.
     async fn logout_user(&self) -> Result<(), IggyError>;
+    /// Disconnect all the clients authenticated as the given user and invalidate the access tokens issued to that user.
+    ///
+    /// Authentication is required, and the permission to manage the users.
+    async fn revoke_user_sessions(&self, user_id: &Identifier) -> Result<(), IggyError>;
 }
@@ -18,10 +18,11 @@
 use crate::utils::auth::fail_if_not_authenticated;
 use crate::{BinaryClient, MessageClient};
 use iggy_common::{
-    BytesSerializable, Consumer, FlushUnsavedBuffer, Identifier, IggyError, IggyMessage,
-    POLL_MESSAGES_CODE, Partitioning, PollMessages, PolledMessages, PollingStrategy,
+    BytesSerializable, Consumer, FlushUnsavedBuffer, Identifier, IggyDuration, IggyError,
+    IggyMessage, POLL_MESSAGES_CODE, Partitioning, PollMessages, PolledMessages, PollingStrategy,
     SEND_MESSAGES_CODE, SendMessages,
 };
+use tracing::debug;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> MessageClient for B {
@@ -60,13 +61,38 @@ impl<B: BinaryClient> MessageClient for B {
         partitioning: &Partitioning,
         messages: &mut [IggyMessage],
     ) -> Result<(), IggyError> {
+        self.send_messages_with_throttle_time(stream_id, topic_id, partitioning, messages)
+            .await
+            .map(|_| ())
+    }
+
+    async fn send_messages_with_throttle_time(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [IggyMessage],
+    ) -> Result<Option<IggyDuration>, IggyError> {
         fail_if_not_authenticated(self).await?;
-        self.send_raw_with_response(
-            SEND_MESSAGES_CODE,
-            SendMessages::bytes(stream_id, topic_id, partitioning, messages),
-        )
-        .await?;
-        Ok(())
+        let response = self
+            .send_raw_with_response(
+                SEND_MESSAGES_CODE,
+                SendMessages::bytes(stream_id, topic_id, partitioning, messages),
+            )
+            .await?;
+        // The server responds with the throttle time (in microseconds) if the quota has been exceeded.
+        let Some(throttle_time) = response.get(..8) else {
+            return Ok(None);
+        };
+        let throttle_time = IggyDuration::from(u64::from_le_bytes(
+            throttle_time
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ));
+        debug!(
+            "Sending messages to stream: {stream_id}, topic: {topic_id} has been throttled by the server for: {throttle_time} due to the exceeded quota."
+        );
+        Ok(Some(throttle_time))
     }
 
     async fn flush_unsaved_buffer(
@@ -23,9 +23,10 @@ use iggy_common::create_personal_access_token::CreatePersonalAccessToken;
 use iggy_common::delete_personal_access_token::DeletePersonalAccessToken;
 use iggy_common::get_personal_access_tokens::GetPersonalAccessTokens;
 use iggy_common::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use iggy_common::update_personal_access_token_quota::UpdatePersonalAccessTokenQuota;
 use iggy_common::{
-    ClientState, IdentityInfo, IggyError, PersonalAccessTokenExpiry, PersonalAccessTokenInfo,
-    RawPersonalAccessToken,
+    ClientState, Identifier, IdentityInfo, IggyError, PersonalAccessTokenExpiry,
+    PersonalAccessTokenInfo, RawPersonalAccessToken, ThroughputQuota,
 };
 
 #[async_trait::async_trait]
@@ -60,6 +61,22 @@ impl<B: BinaryClient> PersonalAccessTokenClient for B {
         Ok(())
     }
 
+    async fn update_personal_access_token_quota(
+        &self,
+        user_id: &Identifier,
+        name: &str,
+        quota: Option<ThroughputQuota>,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdatePersonalAccessTokenQuota {
+            user_id: user_id.clone(),
+            name: name.to_string(),
+            quota,
+        })
+        .await?;
+        Ok(())
+    }
+
     async fn login_with_personal_access_token(
         &self,
         token: &str,
@@ -25,7 +25,10 @@ use iggy_common::get_stream::GetStream;
 use iggy_common::get_streams::GetStreams;
 use iggy_common::purge_stream::PurgeStream;
 use iggy_common::update_stream::UpdateStream;
-use iggy_common::{Identifier, IggyError, Stream, StreamDetails};
+use iggy_common::update_stream_retention::UpdateStreamRetention;
+use iggy_common::{
+    Identifier, IggyError, IggyExpiry, MaxTopicSize, Stream, StreamDetails, StreamSizePolicy,
+};
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> StreamClient for B {
@@ -91,4 +94,22 @@ impl<B: BinaryClient> StreamClient for B {
         .await?;
         Ok(())
     }
+
+    async fn update_stream_retention(
+        &self,
+        stream_id: &Identifier,
+        max_size: MaxTopicSize,
+        message_expiry: IggyExpiry,
+        size_policy: StreamSizePolicy,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateStreamRetention {
+            stream_id: stream_id.clone(),
+            max_size,
+            message_expiry,
+            size_policy,
+        })
+        .await?;
+        Ok(())
+    }
 }
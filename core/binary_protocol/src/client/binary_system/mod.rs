@@ -19,15 +19,25 @@
 use crate::utils::auth::fail_if_not_authenticated;
 use crate::utils::mapper;
 use crate::{BinaryClient, SystemClient};
+use iggy_common::disconnect_client::DisconnectClient;
 use iggy_common::get_client::GetClient;
 use iggy_common::get_clients::GetClients;
+use iggy_common::get_health::GetHealth;
+use iggy_common::get_log_filter::GetLogFilter;
 use iggy_common::get_me::GetMe;
+use iggy_common::get_profile::GetProfile;
 use iggy_common::get_snapshot::GetSnapshot;
 use iggy_common::get_stats::GetStats;
+use iggy_common::get_stats_history::GetStatsHistory;
+use iggy_common::hello::Hello;
+use iggy_common::identify_client::IdentifyClient;
 use iggy_common::ping::Ping;
+use iggy_common::reload_config::ReloadConfig;
+use iggy_common::set_log_filter::SetLogFilter;
 use iggy_common::{
-    ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot, SnapshotCompression, Stats,
-    SystemSnapshotType,
+    ClientInfo, ClientInfoDetails, ClientMetadata, ConfigReload, Health, HealthProbe, IggyDuration,
+    IggyError, LogFilter, Profile, ProfileFormat, ProfileKind, ServerHello, Snapshot,
+    SnapshotCompression, Stats, StatsHistory, SystemSnapshotType,
 };
 
 #[async_trait::async_trait]
@@ -37,6 +47,12 @@ impl<B: BinaryClient> SystemClient for B {
         mapper::map_stats(response)
     }
 
+    async fn get_stats_history(&self) -> Result<StatsHistory, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetStatsHistory {}).await?;
+        mapper::map_stats_history(response)
+    }
+
     async fn get_me(&self) -> Result<ClientInfoDetails, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self.send_with_response(&GetMe {}).await?;
@@ -59,6 +75,26 @@ impl<B: BinaryClient> SystemClient for B {
         mapper::map_clients(response)
     }
 
+    async fn disconnect_client(&self, client_id: u32) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&DisconnectClient { client_id })
+            .await?;
+        Ok(())
+    }
+
+    async fn hello(&self, hello: &Hello) -> Result<ServerHello, IggyError> {
+        let response = self.send_with_response(hello).await?;
+        mapper::map_server_hello(response)
+    }
+
+    async fn identify_client(&self, metadata: &ClientMetadata) -> Result<(), IggyError> {
+        self.send_with_response(&IdentifyClient {
+            metadata: metadata.clone(),
+        })
+        .await?;
+        Ok(())
+    }
+
     async fn ping(&self) -> Result<(), IggyError> {
         self.send_with_response(&Ping {}).await?;
         Ok(())
@@ -83,4 +119,53 @@ impl<B: BinaryClient> SystemClient for B {
         let snapshot = Snapshot::new(response.to_vec());
         Ok(snapshot)
     }
+
+    async fn reload_config(&self) -> Result<ConfigReload, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&ReloadConfig {}).await?;
+        mapper::map_config_reload(response)
+    }
+
+    async fn get_health(&self, probe: HealthProbe) -> Result<Health, IggyError> {
+        let response = self.send_with_response(&GetHealth { probe }).await?;
+        mapper::map_health(response)
+    }
+
+    async fn get_log_filter(&self) -> Result<LogFilter, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetLogFilter {}).await?;
+        mapper::map_log_filter(response)
+    }
+
+    async fn set_log_filter(
+        &self,
+        filter: Option<&str>,
+        ttl: Option<IggyDuration>,
+    ) -> Result<LogFilter, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&SetLogFilter {
+                filter: filter.map(|filter| filter.to_owned()),
+                ttl,
+            })
+            .await?;
+        mapper::map_log_filter(response)
+    }
+
+    async fn profile(
+        &self,
+        kind: ProfileKind,
+        format: ProfileFormat,
+        duration: IggyDuration,
+    ) -> Result<Profile, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetProfile {
+                kind,
+                format,
+                duration,
+            })
+            .await?;
+        Ok(Profile::new(response.to_vec()))
+    }
 }
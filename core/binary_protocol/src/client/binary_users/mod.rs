@@ -26,11 +26,13 @@ use iggy_common::get_user::GetUser;
 use iggy_common::get_users::GetUsers;
 use iggy_common::login_user::LoginUser;
 use iggy_common::logout_user::LogoutUser;
+use iggy_common::revoke_user_sessions::RevokeUserSessions;
 use iggy_common::update_permissions::UpdatePermissions;
 use iggy_common::update_user::UpdateUser;
+use iggy_common::update_user_quota::UpdateUserQuota;
 use iggy_common::{
-    ClientState, DiagnosticEvent, Identifier, IdentityInfo, IggyError, Permissions, UserInfo,
-    UserInfoDetails, UserStatus,
+    ClientState, DiagnosticEvent, Identifier, IdentityInfo, IggyError, Permissions,
+    ThroughputQuota, UserInfo, UserInfoDetails, UserStatus,
 };
 
 #[async_trait::async_trait]
@@ -113,6 +115,20 @@ impl<B: BinaryClient> UserClient for B {
         Ok(())
     }
 
+    async fn update_user_quota(
+        &self,
+        user_id: &Identifier,
+        quota: Option<ThroughputQuota>,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&UpdateUserQuota {
+            user_id: user_id.clone(),
+            quota,
+        })
+        .await?;
+        Ok(())
+    }
+
     async fn change_password(
         &self,
         user_id: &Identifier,
@@ -150,4 +166,13 @@ impl<B: BinaryClient> UserClient for B {
         self.publish_event(DiagnosticEvent::SignedOut).await;
         Ok(())
     }
+
+    async fn revoke_user_sessions(&self, user_id: &Identifier) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&RevokeUserSessions {
+            user_id: user_id.clone(),
+        })
+        .await?;
+        Ok(())
+    }
 }
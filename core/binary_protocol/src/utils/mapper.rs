@@ -19,10 +19,13 @@
 use bytes::Bytes;
 use iggy_common::{
     BytesSerializable, CacheMetrics, CacheMetricsKey, ClientInfo, ClientInfoDetails,
-    CompressionAlgorithm, ConsumerGroup, ConsumerGroupDetails, ConsumerGroupInfo,
-    ConsumerGroupMember, ConsumerOffsetInfo, IdentityInfo, IggyByteSize, IggyError, IggyExpiry,
-    MaxTopicSize, Partition, Permissions, PersonalAccessTokenInfo, RawPersonalAccessToken, Stats,
-    Stream, StreamDetails, Topic, TopicDetails, UserInfo, UserInfoDetails, UserStatus,
+    ClientMetadata, CompressionAlgorithm, ConfigReload, ConsumerGroup, ConsumerGroupDetails,
+    ConsumerGroupInfo, ConsumerGroupMember, ConsumerOffsetInfo, Health, HealthCheck, HealthStatus,
+    IdentityInfo, IggyByteSize, IggyDuration, IggyError, IggyExpiry, LogFilter, MaxTopicSize,
+    Partition, Permissions, PersonalAccessTokenInfo, ProtocolFeatures, RawPersonalAccessToken,
+    ServerHello, Stats, StatsHistory, StatsSample, StatsSeries, Stream, StreamDetails,
+    StreamSizePolicy, ThroughputQuota, Topic, TopicDetails, TopicThroughput, UserInfo,
+    UserInfoDetails, UserStatus,
 };
 use std::collections::HashMap;
 use std::str::from_utf8;
@@ -339,6 +342,24 @@ pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
         }
     }
 
+    // Read throttling stats (if they exist)
+    let mut throttled_requests_count = 0;
+    let mut throttle_time = IggyDuration::default();
+    if current_position + 16 <= payload.len() {
+        throttled_requests_count = u64::from_le_bytes(
+            payload[current_position..current_position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        current_position += 8;
+        throttle_time = u64::from_le_bytes(
+            payload[current_position..current_position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        )
+        .into();
+    }
+
     Ok(Stats {
         process_id,
         cpu_usage,
@@ -365,9 +386,295 @@ pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
         iggy_server_version,
         iggy_server_semver,
         cache_metrics,
+        throttled_requests_count,
+        throttle_time,
+    })
+}
+
+pub fn map_stats_history(payload: Bytes) -> Result<StatsHistory, IggyError> {
+    let series_count = map_to_u32(&payload, 0)?;
+    let mut position = 4;
+    let mut series = Vec::new();
+    for _ in 0..series_count {
+        let (stats_series, read_bytes) = map_to_stats_series(&payload, position)?;
+        series.push(stats_series);
+        position += read_bytes;
+    }
+    if position != payload.len() {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    Ok(StatsHistory { series })
+}
+
+fn map_to_stats_series(payload: &[u8], position: usize) -> Result<(StatsSeries, usize), IggyError> {
+    let interval = map_to_u64(payload, position)?.into();
+    let retention = map_to_u64(payload, position + 8)?.into();
+    let samples_count = map_to_u32(payload, position + 16)?;
+    let mut current_position = position + 20;
+    let mut samples = Vec::new();
+    for _ in 0..samples_count {
+        let (sample, read_bytes) = map_to_stats_sample(payload, current_position)?;
+        samples.push(sample);
+        current_position += read_bytes;
+    }
+
+    Ok((
+        StatsSeries {
+            interval,
+            retention,
+            samples,
+        },
+        current_position - position,
+    ))
+}
+
+fn map_to_stats_sample(payload: &[u8], position: usize) -> Result<(StatsSample, usize), IggyError> {
+    let timestamp = map_to_u64(payload, position)?.into();
+    let cpu_usage = f32::from_bits(map_to_u32(payload, position + 8)?);
+    let total_cpu_usage = f32::from_bits(map_to_u32(payload, position + 12)?);
+    let memory_usage = map_to_u64(payload, position + 16)?.into();
+    let available_memory = map_to_u64(payload, position + 24)?.into();
+    let clients_count = map_to_u32(payload, position + 32)?;
+    let messages_count = map_to_u64(payload, position + 36)?;
+    let messages_size_bytes = map_to_u64(payload, position + 44)?.into();
+    let read_bytes = map_to_u64(payload, position + 52)?.into();
+    let written_bytes = map_to_u64(payload, position + 60)?.into();
+    let topics_count = map_to_u32(payload, position + 68)?;
+    let mut current_position = position + 72;
+    let mut topics = Vec::new();
+    for _ in 0..topics_count {
+        topics.push(TopicThroughput {
+            stream_id: map_to_u32(payload, current_position)?,
+            topic_id: map_to_u32(payload, current_position + 4)?,
+            messages_count: map_to_u64(payload, current_position + 8)?,
+            size_bytes: map_to_u64(payload, current_position + 16)?.into(),
+        });
+        current_position += 24;
+    }
+
+    Ok((
+        StatsSample {
+            timestamp,
+            cpu_usage,
+            total_cpu_usage,
+            memory_usage,
+            available_memory,
+            clients_count,
+            messages_count,
+            messages_size_bytes,
+            read_bytes,
+            written_bytes,
+            topics,
+        },
+        current_position - position,
+    ))
+}
+
+fn map_to_u32(payload: &[u8], position: usize) -> Result<u32, IggyError> {
+    Ok(u32::from_le_bytes(
+        payload
+            .get(position..position + 4)
+            .ok_or(IggyError::InvalidCommand)?
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    ))
+}
+
+fn map_to_u64(payload: &[u8], position: usize) -> Result<u64, IggyError> {
+    Ok(u64::from_le_bytes(
+        payload
+            .get(position..position + 8)
+            .ok_or(IggyError::InvalidCommand)?
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    ))
+}
+
+pub fn map_server_hello(payload: Bytes) -> Result<ServerHello, IggyError> {
+    if payload.len() < 21 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let protocol_version = u32::from_le_bytes(
+        payload[..4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let min_client_protocol_version = u32::from_le_bytes(
+        payload[4..8]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let max_frame_size = u32::from_le_bytes(
+        payload[8..12]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    let features = ProtocolFeatures::from_bits(u64::from_le_bytes(
+        payload[12..20]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    ));
+    let compression_algorithms_count = payload[20] as usize;
+    let mut position = 21;
+    if payload.len() < position + compression_algorithms_count + 4 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    // The algorithms unknown to this version of the SDK are skipped, as they can't be used anyway.
+    let compression_algorithms = payload[position..position + compression_algorithms_count]
+        .iter()
+        .filter_map(|code| CompressionAlgorithm::from_code(*code).ok())
+        .collect();
+    position += compression_algorithms_count;
+    let commands_count = u32::from_le_bytes(
+        payload[position..position + 4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    ) as usize;
+    position += 4;
+    if payload.len() != position + 4 * commands_count {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let commands = payload[position..]
+        .chunks_exact(4)
+        .map(|code| u32::from_le_bytes([code[0], code[1], code[2], code[3]]))
+        .collect();
+    Ok(ServerHello {
+        protocol_version,
+        min_client_protocol_version,
+        max_frame_size,
+        features,
+        compression_algorithms,
+        commands,
+    })
+}
+
+pub fn map_config_reload(payload: Bytes) -> Result<ConfigReload, IggyError> {
+    let (applied, position) = map_to_config_paths(&payload, 0)?;
+    let (restart_required, position) = map_to_config_paths(&payload, position)?;
+    if position != payload.len() {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    Ok(ConfigReload {
+        applied,
+        restart_required,
+    })
+}
+
+fn map_to_config_paths(payload: &[u8], position: usize) -> Result<(Vec<String>, usize), IggyError> {
+    let count = u32::from_le_bytes(
+        payload
+            .get(position..position + 4)
+            .ok_or(IggyError::InvalidCommand)?
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    ) as usize;
+    let mut position = position + 4;
+    let mut paths = Vec::with_capacity(count);
+    for _ in 0..count {
+        let length = *payload.get(position).ok_or(IggyError::InvalidCommand)? as usize;
+        let path = payload
+            .get(position + 1..position + 1 + length)
+            .ok_or(IggyError::InvalidCommand)?;
+        let path = from_utf8(path)
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        paths.push(path);
+        position += 1 + length;
+    }
+    Ok((paths, position))
+}
+
+pub fn map_health(payload: Bytes) -> Result<Health, IggyError> {
+    let status = HealthStatus::from_code(*payload.first().ok_or(IggyError::InvalidCommand)?)?;
+    let count = u32::from_le_bytes(
+        payload
+            .get(1..5)
+            .ok_or(IggyError::InvalidCommand)?
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    ) as usize;
+    let mut position = 5;
+    let mut checks = Vec::with_capacity(count);
+    for _ in 0..count {
+        let name_length = *payload.get(position).ok_or(IggyError::InvalidCommand)? as usize;
+        let name = payload
+            .get(position + 1..position + 1 + name_length)
+            .ok_or(IggyError::InvalidCommand)?;
+        let name = from_utf8(name).map_err(|_| IggyError::InvalidUtf8)?;
+        position += 1 + name_length;
+        let check_status =
+            HealthStatus::from_code(*payload.get(position).ok_or(IggyError::InvalidCommand)?)?;
+        let details_length = u32::from_le_bytes(
+            payload
+                .get(position + 1..position + 5)
+                .ok_or(IggyError::InvalidCommand)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        position += 5;
+        let details = payload
+            .get(position..position + details_length)
+            .ok_or(IggyError::InvalidCommand)?;
+        let details = from_utf8(details)
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        position += details_length;
+        checks.push(HealthCheck::new(name, check_status, details));
+    }
+
+    Ok(Health { status, checks })
+}
+
+pub fn map_log_filter(payload: Bytes) -> Result<LogFilter, IggyError> {
+    let (filter, position) = map_to_log_filter_directives(&payload, 0)?;
+    let (default_filter, position) = map_to_log_filter_directives(&payload, position)?;
+    let expires_at = u64::from_le_bytes(
+        payload
+            .get(position..position + 8)
+            .ok_or(IggyError::InvalidCommand)?
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    );
+    if position + 8 != payload.len() {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    Ok(LogFilter {
+        filter,
+        default_filter,
+        expires_at: match expires_at {
+            0 => None,
+            expires_at => Some(expires_at.into()),
+        },
     })
 }
 
+fn map_to_log_filter_directives(
+    payload: &[u8],
+    position: usize,
+) -> Result<(String, usize), IggyError> {
+    let length = u32::from_le_bytes(
+        payload
+            .get(position..position + 4)
+            .ok_or(IggyError::InvalidCommand)?
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    ) as usize;
+    let position = position + 4;
+    let directives = payload
+        .get(position..position + length)
+        .ok_or(IggyError::InvalidCommand)?;
+    let directives = from_utf8(directives)
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+    Ok((directives, position + length))
+}
+
 pub fn map_consumer_offset(payload: Bytes) -> Result<ConsumerOffsetInfo, IggyError> {
     let partition_id = u32::from_le_bytes(
         payload[..4]
@@ -394,14 +701,26 @@ pub fn map_consumer_offset(payload: Bytes) -> Result<ConsumerOffsetInfo, IggyErr
 pub fn map_user(payload: Bytes) -> Result<UserInfoDetails, IggyError> {
     let (user, position) = map_to_user_info(payload.clone(), 0)?;
     let has_permissions = payload[position];
-    let permissions = if has_permissions == 1 {
+    let (permissions, position) = if has_permissions == 1 {
         let permissions_length = u32::from_le_bytes(
             payload[position + 1..position + 5]
                 .try_into()
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         ) as usize;
         let permissions = payload.slice(position + 5..position + 5 + permissions_length);
-        Some(Permissions::from_bytes(permissions)?)
+        (
+            Some(Permissions::from_bytes(permissions)?),
+            position + 5 + permissions_length,
+        )
+    } else {
+        (None, position + 4)
+    };
+
+    let quota = if payload.len() > position && payload[position] == 1 {
+        let quota = payload
+            .get(position + 1..position + 33)
+            .ok_or(IggyError::InvalidCommand)?;
+        Some(ThroughputQuota::from_bytes(Bytes::copy_from_slice(quota))?)
     } else {
         None
     };
@@ -412,6 +731,7 @@ pub fn map_user(payload: Bytes) -> Result<UserInfoDetails, IggyError> {
         status: user.status,
         username: user.username,
         permissions,
+        quota,
     };
     Ok(user)
 }
@@ -510,6 +830,12 @@ pub fn map_client(payload: Bytes) -> Result<ClientInfoDetails, IggyError> {
         address: client.address,
         transport: client.transport,
         consumer_groups_count: client.consumer_groups_count,
+        connected_at: client.connected_at,
+        last_activity_at: client.last_activity_at,
+        bytes_received: client.bytes_received,
+        bytes_sent: client.bytes_sent,
+        authentication_method: client.authentication_method,
+        metadata: client.metadata,
         consumer_groups,
     };
     Ok(client)
@@ -551,6 +877,24 @@ pub fn map_streams(payload: Bytes) -> Result<Vec<Stream>, IggyError> {
 
 pub fn map_stream(payload: Bytes) -> Result<StreamDetails, IggyError> {
     let (stream, mut position) = map_to_stream(payload.clone(), 0)?;
+    if payload.len() < position + 17 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let max_size = u64::from_le_bytes(
+        payload[position..position + 8]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
+    let message_expiry = u64::from_le_bytes(
+        payload[position + 8..position + 16]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
+    let size_policy = StreamSizePolicy::from_code(payload[position + 16])?;
+    position += 17;
     let mut topics = Vec::new();
     let length = payload.len();
     while position < length {
@@ -567,6 +911,9 @@ pub fn map_stream(payload: Bytes) -> Result<StreamDetails, IggyError> {
         size: stream.size,
         messages_count: stream.messages_count,
         name: stream.name,
+        max_size,
+        message_expiry,
+        size_policy,
         topics,
     };
     Ok(stream)
@@ -906,6 +1253,11 @@ fn map_to_client_info(
     let transport = match transport {
         1 => "TCP",
         2 => "QUIC",
+        3 => "Unix",
+        4 => "WebSocket",
+        5 => "Kafka",
+        6 => "MQTT",
+        7 => "HTTP",
         _ => "Unknown",
     }
     .to_string();
@@ -925,7 +1277,51 @@ fn map_to_client_info(
             .try_into()
             .map_err(|_| IggyError::InvalidNumberEncoding)?,
     );
-    read_bytes += 4;
+    let connected_at = u64::from_le_bytes(
+        payload[position + 4..position + 12]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
+    let last_activity_at = u64::from_le_bytes(
+        payload[position + 12..position + 20]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
+    let bytes_received = u64::from_le_bytes(
+        payload[position + 20..position + 28]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
+    let bytes_sent = u64::from_le_bytes(
+        payload[position + 28..position + 36]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    )
+    .into();
+    let authentication_method = match payload[position + 36] {
+        1 => Some("Password".to_string()),
+        2 => Some("PersonalAccessToken".to_string()),
+        3 => Some("PeerCredentials".to_string()),
+        4 => Some("JsonWebToken".to_string()),
+        _ => None,
+    };
+    let metadata_position = position + 37;
+    let metadata_length = u32::from_le_bytes(
+        payload[metadata_position..metadata_position + 4]
+            .try_into()
+            .map_err(|_| IggyError::InvalidNumberEncoding)?,
+    ) as usize;
+    let metadata = if metadata_length > 0 {
+        Some(ClientMetadata::from_bytes(payload.slice(
+            metadata_position + 4..metadata_position + 4 + metadata_length,
+        ))?)
+    } else {
+        None
+    };
+    read_bytes += 4 + 8 + 8 + 8 + 8 + 1 + 4 + metadata_length;
     Ok((
         ClientInfo {
             client_id,
@@ -933,6 +1329,12 @@ fn map_to_client_info(
             address,
             transport,
             consumer_groups_count,
+            connected_at,
+            last_activity_at,
+            bytes_received,
+            bytes_sent,
+            authentication_method,
+            metadata,
         },
         read_bytes,
     ))
@@ -18,7 +18,8 @@
 
 use crate::args::common::ListMode;
 
-use clap::{Args, Subcommand};
+use clap::{ArgGroup, Args, Subcommand};
+use iggy::prelude::Identifier;
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum ClientAction {
@@ -40,6 +41,16 @@ pub(crate) enum ClientAction {
     ///  iggy client list -l table
     #[clap(verbatim_doc_comment, visible_alias = "l")]
     List(ClientListArgs),
+    /// Forcibly disconnect a client or all the clients of a user
+    ///
+    /// Disconnecting all the clients of a user also invalidates
+    /// all the access tokens (JWT) issued to that user.
+    ///
+    /// Examples:
+    ///  iggy client kill 42
+    ///  iggy client kill --user testuser
+    #[clap(verbatim_doc_comment, visible_alias = "k")]
+    Kill(ClientKillArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -54,3 +65,16 @@ pub(crate) struct ClientListArgs {
     #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
     pub(crate) list_mode: ListMode,
 }
+
+#[derive(Debug, Clone, Args)]
+#[command(group = ArgGroup::new("target").required(true))]
+pub(crate) struct ClientKillArgs {
+    /// Client ID to disconnect
+    #[clap(group = "target")]
+    pub(crate) client_id: Option<u32>,
+    /// Revoke all the sessions of the user with given ID
+    ///
+    /// The user ID can be specified as either a username or an ID
+    #[clap(short, long, group = "target")]
+    pub(crate) user: Option<Identifier>,
+}
@@ -37,7 +37,7 @@ use crate::args::{
     partition::PartitionAction,
     personal_access_token::PersonalAccessTokenAction,
     stream::StreamAction,
-    system::{PingArgs, StatsArgs},
+    system::{PingArgs, StatsArgs, SystemAction},
     topic::TopicAction,
 };
 
@@ -164,6 +164,9 @@ pub(crate) enum Command {
     /// collect iggy server troubleshooting data
     #[clap(verbatim_doc_comment)]
     Snapshot(SnapshotArgs),
+    /// server administration operations
+    #[command(subcommand)]
+    System(SystemAction),
     /// personal access token operations
     #[command(subcommand)]
     Pat(PersonalAccessTokenAction),
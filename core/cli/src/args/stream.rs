@@ -17,8 +17,8 @@
  */
 
 use crate::args::common::ListMode;
-use clap::{Args, Subcommand};
-use iggy::prelude::Identifier;
+use clap::{Args, Subcommand, ValueEnum};
+use iggy::prelude::{Identifier, IggyExpiry, MaxTopicSize, StreamSizePolicy};
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum StreamAction {
@@ -76,6 +76,18 @@ pub(crate) enum StreamAction {
     ///  iggy stream purge test
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Purge(StreamPurgeArgs),
+    /// Set storage limits for stream with given ID
+    ///
+    /// Max size is shared by all topics in the stream, message expiry is applied
+    /// to the topics which do not define their own expiry.
+    /// Stream ID can be specified as a stream name or ID
+    ///
+    /// Examples:
+    ///  iggy stream retention 1 --max-size 10GB
+    ///  iggy stream retention test -m 5GB -p delete-oldest-segments 7days
+    ///  iggy stream retention test
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    Retention(StreamRetentionArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -127,3 +139,42 @@ pub(crate) struct StreamPurgeArgs {
     /// Stream ID can be specified as a stream name or ID
     pub(crate) stream_id: Identifier,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct StreamRetentionArgs {
+    /// Stream ID to update
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    pub(crate) stream_id: Identifier,
+    /// Max stream size in human-readable format like "unlimited" or "15GB"
+    ///
+    /// Can't be lower than segment size in the config.
+    #[arg(short, long, default_value = "unlimited", verbatim_doc_comment)]
+    pub(crate) max_size: MaxTopicSize,
+    /// Policy applied when the stream exceeds its max size
+    #[arg(short = 'p', long, value_enum, default_value_t = StreamSizePolicyArg::default())]
+    pub(crate) size_policy: StreamSizePolicyArg,
+    /// Default message expiry time in human-readable format like "unlimited" or "15days 2min 2s"
+    ///
+    /// Applied to the topics in the stream which do not define their own expiry time.
+    #[arg(default_value = "unlimited", value_parser = clap::value_parser!(IggyExpiry), verbatim_doc_comment)]
+    pub(crate) message_expiry: Vec<IggyExpiry>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Default)]
+pub(crate) enum StreamSizePolicyArg {
+    /// Reject new messages until the stream size drops below the max size
+    #[default]
+    RejectSends,
+    /// Delete the oldest segments across all topics in the stream
+    DeleteOldestSegments,
+}
+
+impl From<StreamSizePolicyArg> for StreamSizePolicy {
+    fn from(value: StreamSizePolicyArg) -> Self {
+        match value {
+            StreamSizePolicyArg::RejectSends => StreamSizePolicy::RejectSends,
+            StreamSizePolicyArg::DeleteOldestSegments => StreamSizePolicy::DeleteOldestSegments,
+        }
+    }
+}
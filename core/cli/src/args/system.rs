@@ -17,10 +17,121 @@
  */
 
 use crate::args::common::ListModeExt;
-use clap::Args;
-use iggy::prelude::{SnapshotCompression, SystemSnapshotType};
+use clap::{Args, Subcommand};
+use iggy::prelude::{
+    HealthProbe, IggyDuration, ProfileFormat, ProfileKind, SnapshotCompression, SystemSnapshotType,
+};
 use iggy_binary_protocol::cli::utils::login_session_expiry::LoginSessionExpiry;
 
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum SystemAction {
+    /// Reload the server configuration
+    ///
+    /// Server re-reads its configuration (file and environment variables) and applies
+    /// the changed settings which don't require a restart, like the log level, intervals,
+    /// quotas, CORS, JWT validation or TLS certificates. The changed settings which
+    /// require a restart are listed as well. Same as sending SIGHUP to the server process.
+    ///
+    /// Examples:
+    ///  iggy system reload-config
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    ReloadConfig,
+    /// Check the server health
+    ///
+    /// The liveness probe checks whether the server is running and responsive, while
+    /// the readiness probe checks whether it has loaded its data and can serve the traffic,
+    /// including the free disk space, the memory pool usage and the persister backlog.
+    /// Command fails if any of the checks is down. Login is not required.
+    ///
+    /// Examples:
+    ///  iggy system health
+    ///  iggy system health --probe readiness
+    #[clap(verbatim_doc_comment, visible_alias = "h")]
+    Health(HealthArgs),
+    /// Get or change the server log level
+    ///
+    /// Without arguments, the active log filter and the default one are printed.
+    /// The filter uses the RUST_LOG format, so that the level can be changed for the
+    /// selected modules only. The change is applied without restarting the server,
+    /// until the TTL elapses, the filter is reset, or the server is restarted.
+    ///
+    /// Examples:
+    ///  iggy system log-level
+    ///  iggy system log-level debug --ttl 10m
+    ///  iggy system log-level info,server::streaming=trace
+    ///  iggy system log-level --reset
+    #[clap(verbatim_doc_comment, visible_alias = "l")]
+    LogLevel(LogLevelArgs),
+    /// Capture the server profile
+    ///
+    /// The CPU profile is sampled for the given duration and saved either as the pprof
+    /// protobuf (readable by `go tool pprof`) or as the flamegraph SVG. The heap profile
+    /// is available only if the server is built with the jemalloc feature, and it's saved
+    /// as the jemalloc dump (readable by `jeprof`). Only one profile is captured at a time.
+    ///
+    /// Examples:
+    ///  iggy system profile
+    ///  iggy system profile --format flamegraph --duration 30s
+    ///  iggy system profile --kind heap --out-dir /tmp
+    #[clap(verbatim_doc_comment, visible_alias = "p")]
+    Profile(ProfileArgs),
+    /// Get the server stats history
+    ///
+    /// The server samples its stats periodically and keeps them in memory for the configured
+    /// time, e.g. every 10 seconds for the last hour and every minute for the last day.
+    /// Each series is rendered as the sparklines of the CPU and memory usage, the clients count,
+    /// the appended messages and bytes, the disk reads and writes, and the busiest topics.
+    ///
+    /// Examples:
+    ///  iggy system stats-history
+    ///  iggy system stats-history --json
+    #[clap(verbatim_doc_comment, visible_alias = "s")]
+    StatsHistory(StatsHistoryArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct HealthArgs {
+    /// Kind of the probe, either liveness or readiness
+    #[arg(short, long, value_parser = clap::value_parser!(HealthProbe), default_value = "liveness")]
+    pub(crate) probe: HealthProbe,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct LogLevelArgs {
+    /// Log filter in the RUST_LOG format, e.g. debug or info,server::streaming=trace
+    #[arg(conflicts_with = "reset")]
+    pub(crate) filter: Option<String>,
+    /// Time after which the default log filter is restored, e.g. 10m or 1h
+    #[arg(short, long, requires = "filter")]
+    pub(crate) ttl: Option<IggyDuration>,
+    /// Restore the default log filter
+    #[arg(short, long, default_value_t = false)]
+    pub(crate) reset: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ProfileArgs {
+    /// Kind of the profile, either cpu or heap
+    #[arg(short, long, value_parser = clap::value_parser!(ProfileKind), default_value = "cpu")]
+    pub(crate) kind: ProfileKind,
+    /// Format of the CPU profile, either pprof or flamegraph
+    #[arg(short, long, value_parser = clap::value_parser!(ProfileFormat), default_value = "pprof")]
+    pub(crate) format: ProfileFormat,
+    /// Time during which the CPU profile is sampled, from 1s to 5m
+    #[arg(short, long, default_value = "10s")]
+    pub(crate) duration: IggyDuration,
+    /// Output directory for the profile file
+    #[arg(short, long)]
+    pub(crate) out_dir: Option<String>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct StatsHistoryArgs {
+    /// Print the raw stats history as JSON instead of the sparklines
+    #[arg(short, long, default_value_t = false)]
+    pub(crate) json: bool,
+}
+
 #[derive(Debug, Clone, Args)]
 pub(crate) struct PingArgs {
     /// Stop after sending count Ping packets
@@ -74,6 +185,8 @@ pub(crate) struct SnapshotArgs {
     /// - `test`: Used for testing purposes.
     /// - `server_logs`: Server logs from the specified logging directory, useful for system diagnostics.
     /// - `server_config`: Server configuration.
+    /// - `clients`: Connected clients with their identification metadata.
+    /// - `cpu_profile`: CPU profile flamegraph, sampled for 5 seconds, not included in `all`.
     /// - `all`: Take all available snapshots.
     ///
     /// Examples:
@@ -20,7 +20,7 @@ use crate::args::common::ListMode;
 use crate::args::permissions::UserStatusArg;
 use crate::args::permissions::stream::StreamPermissionsArg;
 use clap::{Args, Subcommand};
-use iggy::prelude::Identifier;
+use iggy::prelude::{Identifier, IggyByteSize, ThroughputQuota};
 
 use super::permissions::global::GlobalPermissionsArg;
 
@@ -100,6 +100,18 @@ pub(crate) enum UserAction {
     ///  iggy user permissions client
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Permissions(UserPermissionsArgs),
+    /// Set throughput quota for user or personal access token with given ID
+    ///
+    /// The user ID can be specified as either a username or an ID. Quota limits
+    /// are configured based on the options provided with this command. If no
+    /// limits are set, the quota is removed for the specified user (or token).
+    ///
+    /// Examples:
+    ///  iggy user quota 2 --produce-bytes 10MB --consume-messages 1000
+    ///  iggy user quota client --token ci --produce-messages 500
+    ///  iggy user quota client
+    #[clap(verbatim_doc_comment, visible_alias = "q")]
+    Quota(UserQuotaArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -303,3 +315,50 @@ pub(crate) struct UserPermissionsArgs {
     #[arg(value_parser = clap::value_parser!(StreamPermissionsArg))]
     pub(crate) stream_permissions: Option<Vec<StreamPermissionsArg>>,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct UserQuotaArgs {
+    /// User ID to update
+    ///
+    /// The user ID can be specified as either a username or an ID
+    pub(crate) user_id: Identifier,
+    /// Name of the personal access token to update
+    ///
+    /// When set, the quota is applied to the given personal access token
+    /// instead of the user.
+    #[clap(short, long, verbatim_doc_comment)]
+    pub(crate) token: Option<String>,
+    /// Maximum number of bytes produced per second (0 means unlimited)
+    #[clap(long)]
+    pub(crate) produce_bytes: Option<IggyByteSize>,
+    /// Maximum number of messages produced per second (0 means unlimited)
+    #[clap(long)]
+    pub(crate) produce_messages: Option<u64>,
+    /// Maximum number of bytes consumed per second (0 means unlimited)
+    #[clap(long)]
+    pub(crate) consume_bytes: Option<IggyByteSize>,
+    /// Maximum number of messages consumed per second (0 means unlimited)
+    #[clap(long)]
+    pub(crate) consume_messages: Option<u64>,
+}
+
+impl UserQuotaArgs {
+    /// Returns the requested quota or `None` when no limit was provided,
+    /// which removes the quota on the server.
+    pub(crate) fn quota(&self) -> Option<ThroughputQuota> {
+        if self.produce_bytes.is_none()
+            && self.produce_messages.is_none()
+            && self.consume_bytes.is_none()
+            && self.consume_messages.is_none()
+        {
+            return None;
+        }
+
+        Some(ThroughputQuota {
+            produce_bytes_per_second: self.produce_bytes.map_or(0, |size| size.as_bytes_u64()),
+            produce_messages_per_second: self.produce_messages.unwrap_or(0),
+            consume_bytes_per_second: self.consume_bytes.map_or(0, |size| size.as_bytes_u64()),
+            consume_messages_per_second: self.consume_messages.unwrap_or(0),
+        })
+    }
+}
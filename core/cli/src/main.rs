@@ -24,7 +24,8 @@ mod logging;
 use crate::args::{
     Command, IggyConsoleArgs, client::ClientAction, consumer_group::ConsumerGroupAction,
     consumer_offset::ConsumerOffsetAction, permissions::PermissionsArgs,
-    personal_access_token::PersonalAccessTokenAction, stream::StreamAction, topic::TopicAction,
+    personal_access_token::PersonalAccessTokenAction, stream::StreamAction, system::SystemAction,
+    topic::TopicAction,
 };
 use crate::credentials::IggyCredentials;
 use crate::error::IggyCmdError;
@@ -42,10 +43,18 @@ use iggy::prelude::{Aes256GcmEncryptor, Args, EncryptorKind, PersonalAccessToken
 use iggy_binary_protocol::cli::binary_context::common::ContextManager;
 use iggy_binary_protocol::cli::binary_context::use_context::UseContextCmd;
 use iggy_binary_protocol::cli::binary_segments::delete_segments::DeleteSegmentsCmd;
+use iggy_binary_protocol::cli::binary_system::health::GetHealthCmd;
+use iggy_binary_protocol::cli::binary_system::log_level::{GetLogLevelCmd, SetLogLevelCmd};
+use iggy_binary_protocol::cli::binary_system::profile::GetProfileCmd;
+use iggy_binary_protocol::cli::binary_system::reload_config::ReloadConfigCmd;
 use iggy_binary_protocol::cli::binary_system::snapshot::GetSnapshotCmd;
+use iggy_binary_protocol::cli::binary_system::stats_history::GetStatsHistoryCmd;
 use iggy_binary_protocol::cli::cli_command::{CliCommand, PRINT_TARGET};
 use iggy_binary_protocol::cli::{
-    binary_client::{get_client::GetClientCmd, get_clients::GetClientsCmd},
+    binary_client::{
+        disconnect_client::DisconnectClientCmd, get_client::GetClientCmd,
+        get_clients::GetClientsCmd,
+    },
     binary_consumer_groups::{
         create_consumer_group::CreateConsumerGroupCmd,
         delete_consumer_group::DeleteConsumerGroupCmd, get_consumer_group::GetConsumerGroupCmd,
@@ -66,10 +75,12 @@ use iggy_binary_protocol::cli::{
         create_personal_access_token::CreatePersonalAccessTokenCmd,
         delete_personal_access_tokens::DeletePersonalAccessTokenCmd,
         get_personal_access_tokens::GetPersonalAccessTokensCmd,
+        update_personal_access_token_quota::UpdatePersonalAccessTokenQuotaCmd,
     },
     binary_streams::{
         create_stream::CreateStreamCmd, delete_stream::DeleteStreamCmd, get_stream::GetStreamCmd,
         get_streams::GetStreamsCmd, purge_stream::PurgeStreamCmd, update_stream::UpdateStreamCmd,
+        update_stream_retention::UpdateStreamRetentionCmd,
     },
     binary_system::{me::GetMeCmd, ping::PingCmd, stats::GetStatsCmd},
     binary_topics::{
@@ -82,8 +93,10 @@ use iggy_binary_protocol::cli::{
         delete_user::DeleteUserCmd,
         get_user::GetUserCmd,
         get_users::GetUsersCmd,
+        revoke_user_sessions::RevokeUserSessionsCmd,
         update_permissions::UpdatePermissionsCmd,
         update_user::{UpdateUserCmd, UpdateUserType},
+        update_user_quota::UpdateUserQuotaCmd,
     },
 };
 use std::sync::Arc;
@@ -117,6 +130,12 @@ fn get_command(
             StreamAction::Get(args) => Box::new(GetStreamCmd::new(args.stream_id.clone())),
             StreamAction::List(args) => Box::new(GetStreamsCmd::new(args.list_mode.into())),
             StreamAction::Purge(args) => Box::new(PurgeStreamCmd::new(args.stream_id.clone())),
+            StreamAction::Retention(args) => Box::new(UpdateStreamRetentionCmd::new(
+                args.stream_id.clone(),
+                args.max_size,
+                args.message_expiry.clone().into(),
+                args.size_policy.into(),
+            )),
         },
         Command::Topic(command) => match command {
             TopicAction::Create(args) => Box::new(CreateTopicCmd::new(
@@ -183,6 +202,21 @@ fn get_command(
             args.snapshot_types,
             args.out_dir,
         )),
+        Command::System(command) => match command {
+            SystemAction::ReloadConfig => Box::new(ReloadConfigCmd::new()),
+            SystemAction::Health(args) => Box::new(GetHealthCmd::new(args.probe)),
+            SystemAction::LogLevel(args) => match (args.filter, args.reset) {
+                (None, false) => Box::new(GetLogLevelCmd::new()),
+                (filter, _) => Box::new(SetLogLevelCmd::new(filter, args.ttl)),
+            },
+            SystemAction::Profile(args) => Box::new(GetProfileCmd::new(
+                args.kind,
+                args.format,
+                args.duration,
+                args.out_dir,
+            )),
+            SystemAction::StatsHistory(args) => Box::new(GetStatsHistoryCmd::new(args.json)),
+        },
         Command::Pat(command) => match command {
             PersonalAccessTokenAction::Create(pat_create_args) => {
                 Box::new(CreatePersonalAccessTokenCmd::new(
@@ -240,12 +274,28 @@ fn get_command(
                 )
                 .into(),
             )),
+            UserAction::Quota(quota_args) => {
+                let quota = quota_args.quota();
+                match &quota_args.token {
+                    Some(name) => Box::new(UpdatePersonalAccessTokenQuotaCmd::new(
+                        quota_args.user_id.clone(),
+                        name.clone(),
+                        quota,
+                    )),
+                    None => Box::new(UpdateUserQuotaCmd::new(quota_args.user_id.clone(), quota)),
+                }
+            }
         },
         Command::Client(command) => match command {
             ClientAction::Get(get_args) => Box::new(GetClientCmd::new(get_args.client_id)),
             ClientAction::List(list_args) => {
                 Box::new(GetClientsCmd::new(list_args.list_mode.into()))
             }
+            ClientAction::Kill(kill_args) => match (kill_args.client_id, &kill_args.user) {
+                (_, Some(user_id)) => Box::new(RevokeUserSessionsCmd::new(user_id.clone())),
+                (Some(client_id), None) => Box::new(DisconnectClientCmd::new(client_id)),
+                (None, None) => unreachable!("clap requires either the client ID or the user"),
+            },
         },
         Command::ConsumerGroup(command) => match command {
             ConsumerGroupAction::Create(create_args) => Box::new(CreateConsumerGroupCmd::new(
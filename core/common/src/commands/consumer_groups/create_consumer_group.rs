@@ -35,6 +35,7 @@ use std::str::from_utf8;
 /// - `group_id` - unique consumer group ID.
 /// - `name` - unique consumer group name, max length is 255 characters.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreateConsumerGroup {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
@@ -182,6 +182,31 @@ impl Display for SendMessages {
     }
 }
 
+/// The serialized form of `SendMessages`, used only to derive its OpenAPI schema.
+#[cfg(feature = "openapi")]
+#[derive(utoipa::ToSchema)]
+#[allow(dead_code)]
+struct SendMessagesSchema {
+    partitioning: Partitioning,
+    messages: Vec<SentMessageSchema>,
+}
+
+/// The message to send, the ID is generated by the server if it's missing or 0.
+#[cfg(feature = "openapi")]
+#[derive(utoipa::ToSchema)]
+#[schema(as = SentMessage)]
+#[allow(dead_code)]
+struct SentMessageSchema {
+    id: Option<u64>,
+    /// The base64-encoded payload.
+    #[schema(format = Byte)]
+    payload: String,
+    headers: Option<HashMap<crate::HeaderKey, crate::HeaderValue>>,
+}
+
+#[cfg(feature = "openapi")]
+crate::utils::openapi::impl_to_schema!(SendMessages, as SendMessagesSchema);
+
 impl Serialize for SendMessages {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -20,3 +20,4 @@ pub mod create_personal_access_token;
 pub mod delete_personal_access_token;
 pub mod get_personal_access_tokens;
 pub mod login_with_personal_access_token;
+pub mod update_personal_access_token_quota;
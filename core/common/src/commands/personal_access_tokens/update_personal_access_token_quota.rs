@@ -0,0 +1,174 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Identifier;
+use crate::Sizeable;
+use crate::ThroughputQuota;
+use crate::Validatable;
+use crate::defaults::*;
+use crate::error::IggyError;
+use crate::{Command, UPDATE_PERSONAL_ACCESS_TOKEN_QUOTA_CODE};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::from_utf8;
+
+/// `UpdatePersonalAccessTokenQuota` command is used to update the throughput quota of the user's personal access token.
+/// It has additional payload:
+/// - `user_id` - unique user ID (numeric or name).
+/// - `name` - unique name of the token, must be between 3 and 30 characters long.
+/// - `quota` - new throughput quota (optional)
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdatePersonalAccessTokenQuota {
+    /// Unique user ID (numeric or name).
+    #[serde(skip)]
+    pub user_id: Identifier,
+    /// Unique name of the token, must be between 3 and 30 characters long.
+    #[serde(skip)]
+    pub name: String,
+    /// New throughput quota, if `None` is provided, then the existing token's quota will be removed.
+    pub quota: Option<ThroughputQuota>,
+}
+
+impl Command for UpdatePersonalAccessTokenQuota {
+    fn code(&self) -> u32 {
+        UPDATE_PERSONAL_ACCESS_TOKEN_QUOTA_CODE
+    }
+}
+
+impl Default for UpdatePersonalAccessTokenQuota {
+    fn default() -> Self {
+        UpdatePersonalAccessTokenQuota {
+            user_id: Identifier::default(),
+            name: "token".to_string(),
+            quota: None,
+        }
+    }
+}
+
+impl Validatable<IggyError> for UpdatePersonalAccessTokenQuota {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.name.is_empty()
+            || self.name.len() > MAX_PERSONAL_ACCESS_TOKEN_NAME_LENGTH
+            || self.name.len() < MIN_PERSONAL_ACCESS_TOKEN_NAME_LENGTH
+        {
+            return Err(IggyError::InvalidPersonalAccessTokenName);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdatePersonalAccessTokenQuota {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&self.user_id.to_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.put_slice(self.name.as_bytes());
+        if let Some(quota) = &self.quota {
+            bytes.put_u8(1);
+            bytes.put_slice(&quota.to_bytes());
+        } else {
+            bytes.put_u8(0);
+        }
+
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<UpdatePersonalAccessTokenQuota, IggyError> {
+        if bytes.len() < 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let user_id = Identifier::from_bytes(bytes.clone())?;
+        let mut position = user_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() <= position {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name_length = bytes[position] as usize;
+        position += 1;
+        if bytes.len() <= position + name_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name = from_utf8(&bytes[position..position + name_length])
+            .map_err(|_| IggyError::InvalidUtf8)?
+            .to_string();
+        position += name_length;
+        let quota = match bytes[position] {
+            0 => None,
+            1 => Some(ThroughputQuota::from_bytes(bytes.slice(position + 1..))?),
+            _ => return Err(IggyError::InvalidCommand),
+        };
+
+        Ok(UpdatePersonalAccessTokenQuota {
+            user_id,
+            name,
+            quota,
+        })
+    }
+}
+
+impl Display for UpdatePersonalAccessTokenQuota {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let quota = if let Some(quota) = &self.quota {
+            quota.to_string()
+        } else {
+            "no_quota".to_string()
+        };
+        write!(f, "{}|{}|{}", self.user_id, self.name, quota)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = UpdatePersonalAccessTokenQuota {
+            user_id: Identifier::named("user").unwrap(),
+            name: "test".to_string(),
+            quota: Some(ThroughputQuota {
+                produce_bytes_per_second: 1024,
+                produce_messages_per_second: 0,
+                consume_bytes_per_second: 0,
+                consume_messages_per_second: 100,
+            }),
+        };
+        let bytes = command.to_bytes();
+        let deserialized = UpdatePersonalAccessTokenQuota::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_without_quota() {
+        let command = UpdatePersonalAccessTokenQuota {
+            user_id: Identifier::numeric(1).unwrap(),
+            name: "test".to_string(),
+            quota: None,
+        };
+        let bytes = command.to_bytes();
+        let deserialized = UpdatePersonalAccessTokenQuota::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+}
@@ -24,3 +24,4 @@ pub mod purge_stream;
 pub mod update_stream;
 
 const MAX_NAME_LENGTH: usize = 255;
+pub mod update_stream_retention;
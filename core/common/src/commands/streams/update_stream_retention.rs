@@ -0,0 +1,163 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Identifier;
+use crate::Sizeable;
+use crate::StreamSizePolicy;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::utils::expiry::IggyExpiry;
+use crate::utils::topic_size::MaxTopicSize;
+use crate::{Command, UPDATE_STREAM_RETENTION_CODE};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateStreamRetention` command is used to update the storage limits of the stream.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `max_size` - maximum size of the stream in bytes, shared by all of its topics,
+///   if `Unlimited` then stream size is unlimited. Can't be lower than segment size in the config.
+/// - `message_expiry` - default message expiry for the topics without their own expiry,
+///   if `NeverExpire` then no default expiry is applied.
+/// - `size_policy` - policy applied when the stream exceeds its maximum size.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateStreamRetention {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Max stream size, if `Unlimited` then stream size is unlimited.
+    /// Can't be lower than segment size in the config.
+    pub max_size: MaxTopicSize,
+    /// Default message expiry, if `NeverExpire` then no default expiry is applied.
+    pub message_expiry: IggyExpiry,
+    /// Policy applied when the stream exceeds its maximum size.
+    #[serde(default)]
+    pub size_policy: StreamSizePolicy,
+}
+
+impl Command for UpdateStreamRetention {
+    fn code(&self) -> u32 {
+        UPDATE_STREAM_RETENTION_CODE
+    }
+}
+
+impl Default for UpdateStreamRetention {
+    fn default() -> Self {
+        UpdateStreamRetention {
+            stream_id: Identifier::default(),
+            max_size: MaxTopicSize::Unlimited,
+            message_expiry: IggyExpiry::NeverExpire,
+            size_policy: StreamSizePolicy::default(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for UpdateStreamRetention {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateStreamRetention {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(17 + stream_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_u64_le(self.max_size.into());
+        bytes.put_u64_le(self.message_expiry.into());
+        bytes.put_u8(self.size_policy.as_code());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<UpdateStreamRetention, IggyError> {
+        if bytes.len() < 20 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        let position = stream_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 17 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let max_size = u64::from_le_bytes(
+            bytes[position..position + 8]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let message_expiry = u64::from_le_bytes(
+            bytes[position + 8..position + 16]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        let size_policy = StreamSizePolicy::from_code(bytes[position + 16])?;
+        Ok(UpdateStreamRetention {
+            stream_id,
+            max_size: max_size.into(),
+            message_expiry: message_expiry.into(),
+            size_policy,
+        })
+    }
+}
+
+impl Display for UpdateStreamRetention {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id, self.max_size, self.message_expiry, self.size_policy
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IggyByteSize;
+    use crate::IggyDuration;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = UpdateStreamRetention {
+            stream_id: Identifier::numeric(1).unwrap(),
+            max_size: MaxTopicSize::Custom(IggyByteSize::from(1_000_000_000)),
+            message_expiry: IggyExpiry::ExpireDuration(IggyDuration::from(3_600_000_000)),
+            size_policy: StreamSizePolicy::DeleteOldestSegments,
+        };
+
+        let bytes = command.to_bytes();
+        let deserialized = UpdateStreamRetention::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_given_invalid_size_policy() {
+        let stream_id = Identifier::named("stream").unwrap();
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&stream_id.to_bytes());
+        bytes.put_u64_le(MaxTopicSize::Unlimited.into());
+        bytes.put_u64_le(IggyExpiry::NeverExpire.into());
+        bytes.put_u8(0);
+
+        let command = UpdateStreamRetention::from_bytes(bytes.freeze());
+        assert!(command.is_err());
+    }
+}
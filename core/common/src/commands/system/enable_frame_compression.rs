@@ -0,0 +1,124 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::{Command, ENABLE_FRAME_COMPRESSION_CODE, FrameCompression, FrameCompressionAlgorithm};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `EnableFrameCompression` command is used to enable the transport-level compression of the frames on the connection.
+/// It has additional payload:
+/// - `compression` - the compression algorithm and the minimum size of the frame payload to compress.
+///
+/// Once the server responds with OK, either side may compress the payload of any subsequent frame of at least the minimum size.
+/// The compressed frame has the `COMPRESSED_FRAME_FLAG` set in its length, which then covers the compressed payload,
+/// preceded by the length of the uncompressed one (4 bytes). The status and the request ID are never compressed.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct EnableFrameCompression {
+    /// The compression algorithm and the minimum size of the frame payload to compress.
+    pub compression: FrameCompression,
+}
+
+impl Command for EnableFrameCompression {
+    fn code(&self) -> u32 {
+        ENABLE_FRAME_COMPRESSION_CODE
+    }
+}
+
+impl Validatable<IggyError> for EnableFrameCompression {
+    fn validate(&self) -> Result<(), IggyError> {
+        if !self.compression.is_enabled() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for EnableFrameCompression {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(5);
+        bytes.put_u8(self.compression.algorithm.as_code());
+        bytes.put_u32_le(self.compression.min_size);
+        bytes.freeze()
+    }
+
+    fn from_bytes(mut bytes: Bytes) -> Result<EnableFrameCompression, IggyError> {
+        if bytes.len() != 5 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let algorithm = FrameCompressionAlgorithm::from_code(bytes.get_u8())?;
+        let min_size = bytes.get_u32_le();
+        Ok(EnableFrameCompression {
+            compression: FrameCompression::new(algorithm, min_size),
+        })
+    }
+}
+
+impl Display for EnableFrameCompression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}",
+            self.compression.algorithm, self.compression.min_size
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = EnableFrameCompression {
+            compression: FrameCompression::new(FrameCompressionAlgorithm::Zstd, 1000),
+        };
+
+        let mut bytes = command.to_bytes();
+        assert_eq!(bytes.len(), 5);
+        assert_eq!(bytes.get_u8(), FrameCompressionAlgorithm::Zstd.as_code());
+        assert_eq!(bytes.get_u32_le(), 1000);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = EnableFrameCompression {
+            compression: FrameCompression::new(FrameCompressionAlgorithm::Lz4, 100),
+        };
+        let deserialized = EnableFrameCompression::from_bytes(command.to_bytes());
+        assert_eq!(deserialized, Ok(command));
+    }
+
+    #[test]
+    fn should_not_be_deserialized_with_unknown_algorithm() {
+        let mut bytes = BytesMut::new();
+        bytes.put_u8(100);
+        bytes.put_u32_le(0);
+        assert!(EnableFrameCompression::from_bytes(bytes.freeze()).is_err());
+    }
+
+    #[test]
+    fn should_not_be_valid_without_algorithm() {
+        assert!(EnableFrameCompression::default().validate().is_err());
+    }
+}
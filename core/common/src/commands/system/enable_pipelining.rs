@@ -0,0 +1,93 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::{Command, ENABLE_PIPELINING_CODE};
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `EnablePipelining` command is used to switch the TCP connection to the pipelined framing.
+/// It has no additional payload.
+///
+/// Once the server responds with OK (using the regular framing), every subsequent frame on the connection carries a request ID:
+/// - request: `length (4 bytes) | request ID (4 bytes) | code (4 bytes) | payload`, where the length covers the code and the payload.
+/// - response: `request ID (4 bytes) | status (4 bytes) | length (4 bytes) | payload`.
+///
+/// The client may send many requests without waiting for the responses and has to match the responses by the request ID,
+/// as the server is free to complete them in any order.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct EnablePipelining {}
+
+impl Command for EnablePipelining {
+    fn code(&self) -> u32 {
+        ENABLE_PIPELINING_CODE
+    }
+}
+
+impl Validatable<IggyError> for EnablePipelining {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for EnablePipelining {
+    fn to_bytes(&self) -> Bytes {
+        Bytes::new()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<EnablePipelining, IggyError> {
+        if !bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(EnablePipelining {})
+    }
+}
+
+impl Display for EnablePipelining {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_empty_bytes() {
+        let command = EnablePipelining {};
+        let bytes = command.to_bytes();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_empty_bytes() {
+        let command = EnablePipelining::from_bytes(Bytes::new());
+        assert!(command.is_ok());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_non_empty_bytes() {
+        let command = EnablePipelining::from_bytes(Bytes::from_static(&[0]));
+        assert!(command.is_err());
+    }
+}
@@ -0,0 +1,98 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::HealthProbe;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::{Command, GET_HEALTH_CODE};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetHealth` command is used to check whether the server is alive or ready to serve the traffic.
+/// It doesn't require authentication, so it can be used by the orchestrator probes.
+/// It has additional payload:
+/// - `probe` - the kind of the probe, either liveness or readiness.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct GetHealth {
+    /// The kind of the probe.
+    pub probe: HealthProbe,
+}
+
+impl Command for GetHealth {
+    fn code(&self) -> u32 {
+        GET_HEALTH_CODE
+    }
+}
+
+impl Validatable<IggyError> for GetHealth {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetHealth {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(1);
+        bytes.put_u8(self.probe.as_code());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetHealth, IggyError> {
+        if bytes.len() != 1 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let probe = HealthProbe::from_code(bytes[0])?;
+        Ok(GetHealth { probe })
+    }
+}
+
+impl Display for GetHealth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.probe)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetHealth {
+            probe: HealthProbe::Readiness,
+        };
+        let bytes = command.to_bytes();
+        assert_eq!(bytes.as_ref(), &[HealthProbe::Readiness.as_code()]);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let bytes = Bytes::from_static(&[1]);
+        let command = GetHealth::from_bytes(bytes).unwrap();
+        assert_eq!(command.probe, HealthProbe::Liveness);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_invalid_bytes() {
+        assert!(GetHealth::from_bytes(Bytes::new()).is_err());
+        assert!(GetHealth::from_bytes(Bytes::from_static(&[3])).is_err());
+    }
+}
@@ -0,0 +1,174 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::utils::duration::IggyDuration;
+use crate::{Command, GET_PROFILE_CODE};
+use crate::{ProfileFormat, ProfileKind};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::time::Duration;
+use tracing::error;
+
+const MIN_DURATION: Duration = Duration::from_secs(1);
+const MAX_DURATION: Duration = Duration::from_secs(300);
+
+/// `GetProfile` command is used to capture the profile of the running server.
+/// It has additional payload:
+/// - `kind` - the kind of the profile, CPU or heap (requires the server built with the `jemalloc` feature).
+/// - `format` - the format of the profile, pprof protobuf or flamegraph SVG (CPU profile only).
+/// - `duration` - the time during which the CPU profile is sampled, between 1 second and 5 minutes.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct GetProfile {
+    /// The kind of the profile.
+    #[serde(default)]
+    pub kind: ProfileKind,
+    /// The format of the profile.
+    #[serde(default)]
+    pub format: ProfileFormat,
+    /// The time during which the CPU profile is sampled.
+    #[serde(default = "default_duration")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub duration: IggyDuration,
+}
+
+fn default_duration() -> IggyDuration {
+    IggyDuration::new_from_secs(10)
+}
+
+impl Default for GetProfile {
+    fn default() -> Self {
+        Self {
+            kind: ProfileKind::default(),
+            format: ProfileFormat::default(),
+            duration: default_duration(),
+        }
+    }
+}
+
+impl Command for GetProfile {
+    fn code(&self) -> u32 {
+        GET_PROFILE_CODE
+    }
+}
+
+impl Validatable<IggyError> for GetProfile {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.kind == ProfileKind::Heap && self.format == ProfileFormat::Flamegraph {
+            error!("Flamegraph format is available only for the CPU profile");
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let duration = self.duration.get_duration();
+        if self.kind == ProfileKind::Cpu && !(MIN_DURATION..=MAX_DURATION).contains(&duration) {
+            error!(
+                "CPU profile duration must be between {} and {}",
+                IggyDuration::new(MIN_DURATION),
+                IggyDuration::new(MAX_DURATION)
+            );
+            return Err(IggyError::InvalidCommand);
+        }
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetProfile {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(10);
+        bytes.put_u8(self.kind.as_code());
+        bytes.put_u8(self.format.as_code());
+        bytes.put_u64_le(self.duration.as_micros());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetProfile, IggyError> {
+        if bytes.len() != 10 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let kind = ProfileKind::from_code(bytes[0])?;
+        let format = ProfileFormat::from_code(bytes[1])?;
+        let duration = IggyDuration::from((&bytes[2..]).get_u64_le());
+        Ok(GetProfile {
+            kind,
+            format,
+            duration,
+        })
+    }
+}
+
+impl Display for GetProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}|{}", self.kind, self.format, self.duration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetProfile {
+            kind: ProfileKind::Cpu,
+            format: ProfileFormat::Flamegraph,
+            duration: IggyDuration::new_from_secs(30),
+        };
+        let bytes = command.to_bytes();
+        let kind = ProfileKind::from_code(bytes[0]).unwrap();
+        let format = ProfileFormat::from_code(bytes[1]).unwrap();
+        let duration = u64::from_le_bytes(bytes[2..].try_into().unwrap());
+
+        assert_eq!(kind, command.kind);
+        assert_eq!(format, command.format);
+        assert_eq!(duration, command.duration.as_micros());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = GetProfile {
+            kind: ProfileKind::Heap,
+            format: ProfileFormat::Pprof,
+            duration: IggyDuration::new_from_secs(1),
+        };
+        let deserialized = GetProfile::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_heap_flamegraph_or_invalid_duration() {
+        let command = GetProfile {
+            kind: ProfileKind::Heap,
+            format: ProfileFormat::Flamegraph,
+            ..GetProfile::default()
+        };
+        assert!(command.validate().is_err());
+
+        let command = GetProfile {
+            duration: IggyDuration::new_from_secs(301),
+            ..GetProfile::default()
+        };
+        assert!(command.validate().is_err());
+
+        assert!(GetProfile::default().validate().is_ok());
+    }
+}
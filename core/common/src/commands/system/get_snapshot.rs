@@ -28,6 +28,7 @@ use tracing::error;
 
 /// `GetSnapshot` command is used to get snapshot information.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct GetSnapshot {
     pub snapshot_types: Vec<SystemSnapshotType>,
     pub compression: SnapshotCompression,
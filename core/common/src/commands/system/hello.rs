@@ -0,0 +1,183 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::{Command, CompressionAlgorithm, HELLO_CODE, PROTOCOL_VERSION, ProtocolFeatures};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `Hello` command is used to negotiate the protocol capabilities right after establishing the connection.
+/// The server responds with its own capabilities (`ServerHello`), or fails if the protocol version is below the required minimum.
+/// It has additional payload:
+/// - `protocol_version` - the version of the binary protocol implemented by the client.
+/// - `max_frame_size` - the maximum size of the response frame accepted by the client (0 means unlimited).
+/// - `features` - the optional protocol features supported by the client.
+/// - `compression_algorithms` - the compression algorithms supported by the client.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct Hello {
+    /// The version of the binary protocol implemented by the client.
+    pub protocol_version: u32,
+    /// The maximum size of the response frame accepted by the client (0 means unlimited).
+    pub max_frame_size: u32,
+    /// The optional protocol features supported by the client.
+    pub features: ProtocolFeatures,
+    /// The compression algorithms supported by the client.
+    pub compression_algorithms: Vec<CompressionAlgorithm>,
+}
+
+impl Default for Hello {
+    fn default() -> Self {
+        Hello {
+            protocol_version: PROTOCOL_VERSION,
+            max_frame_size: 0,
+            features: ProtocolFeatures::all(),
+            compression_algorithms: CompressionAlgorithm::all(),
+        }
+    }
+}
+
+impl Command for Hello {
+    fn code(&self) -> u32 {
+        HELLO_CODE
+    }
+}
+
+impl Validatable<IggyError> for Hello {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.compression_algorithms.len() > u8::MAX as usize {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for Hello {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(17 + self.compression_algorithms.len());
+        bytes.put_u32_le(self.protocol_version);
+        bytes.put_u32_le(self.max_frame_size);
+        bytes.put_u64_le(self.features.bits());
+        bytes.put_u8(self.compression_algorithms.len() as u8);
+        for compression_algorithm in &self.compression_algorithms {
+            bytes.put_u8(compression_algorithm.as_code());
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(mut bytes: Bytes) -> Result<Hello, IggyError> {
+        if bytes.len() < 17 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let protocol_version = bytes.get_u32_le();
+        let max_frame_size = bytes.get_u32_le();
+        let features = ProtocolFeatures::from_bits(bytes.get_u64_le());
+        let compression_algorithms_count = bytes.get_u8() as usize;
+        if bytes.len() != compression_algorithms_count {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        // The algorithms unknown to this side are skipped, as they can't be used anyway.
+        let compression_algorithms = bytes
+            .iter()
+            .filter_map(|code| CompressionAlgorithm::from_code(*code).ok())
+            .collect();
+        Ok(Hello {
+            protocol_version,
+            max_frame_size,
+            features,
+            compression_algorithms,
+        })
+    }
+}
+
+impl Display for Hello {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let compression_algorithms = self
+            .compression_algorithms
+            .iter()
+            .map(|algorithm| algorithm.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(
+            f,
+            "{}|{}|{}|{compression_algorithms}",
+            self.protocol_version, self.max_frame_size, self.features
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = Hello {
+            protocol_version: 1,
+            max_frame_size: 1000,
+            features: ProtocolFeatures::PIPELINING,
+            compression_algorithms: vec![CompressionAlgorithm::Gzip],
+        };
+
+        let mut bytes = command.to_bytes();
+        assert_eq!(bytes.len(), 18);
+        assert_eq!(bytes.get_u32_le(), command.protocol_version);
+        assert_eq!(bytes.get_u32_le(), command.max_frame_size);
+        assert_eq!(bytes.get_u64_le(), command.features.bits());
+        assert_eq!(bytes.get_u8(), 1);
+        assert_eq!(bytes.get_u8(), CompressionAlgorithm::Gzip.as_code());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = Hello::default();
+        let deserialized = Hello::from_bytes(command.to_bytes());
+        assert!(deserialized.is_ok());
+        assert_eq!(deserialized.unwrap(), command);
+    }
+
+    #[test]
+    fn should_skip_unknown_compression_algorithms() {
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(2);
+        bytes.put_u32_le(0);
+        bytes.put_u64_le(0);
+        bytes.put_u8(2);
+        bytes.put_u8(CompressionAlgorithm::None.as_code());
+        bytes.put_u8(100);
+
+        let command = Hello::from_bytes(bytes.freeze()).unwrap();
+        assert_eq!(command.protocol_version, 2);
+        assert_eq!(
+            command.compression_algorithms,
+            vec![CompressionAlgorithm::None]
+        );
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let bytes = Hello::default().to_bytes();
+        let command = Hello::from_bytes(bytes.slice(..bytes.len() - 1));
+        assert!(command.is_err());
+    }
+}
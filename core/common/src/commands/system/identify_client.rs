@@ -0,0 +1,103 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::{ClientMetadata, Command, IDENTIFY_CLIENT_CODE};
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `IdentifyClient` command is used to attach the identification metadata to the current client.
+/// It has additional payload:
+/// - `metadata` - the application name, SDK language and version, hostname and labels of the client.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct IdentifyClient {
+    /// The identification metadata of the client.
+    #[serde(flatten)]
+    pub metadata: ClientMetadata,
+}
+
+impl Command for IdentifyClient {
+    fn code(&self) -> u32 {
+        IDENTIFY_CLIENT_CODE
+    }
+}
+
+impl Validatable<IggyError> for IdentifyClient {
+    fn validate(&self) -> Result<(), IggyError> {
+        self.metadata.validate()
+    }
+}
+
+impl BytesSerializable for IdentifyClient {
+    fn to_bytes(&self) -> Bytes {
+        self.metadata.to_bytes()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<IdentifyClient, IggyError> {
+        let metadata = ClientMetadata::from_bytes(bytes)?;
+        Ok(IdentifyClient { metadata })
+    }
+}
+
+impl Display for IdentifyClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = IdentifyClient {
+            metadata: ClientMetadata {
+                application_name: "app".to_string(),
+                sdk_language: "rust".to_string(),
+                sdk_version: "1.0.0".to_string(),
+                hostname: "host".to_string(),
+                labels: BTreeMap::from([("env".to_string(), "dev".to_string())]),
+            },
+        };
+
+        let bytes = command.to_bytes();
+        let application_name_length = bytes[0] as usize;
+        let application_name = std::str::from_utf8(&bytes[1..1 + application_name_length]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(application_name, command.metadata.application_name);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let metadata = ClientMetadata {
+            application_name: "app".to_string(),
+            ..Default::default()
+        };
+        let command = IdentifyClient::from_bytes(metadata.to_bytes());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.metadata, metadata);
+    }
+}
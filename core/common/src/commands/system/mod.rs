@@ -16,9 +16,20 @@
  * under the License.
  */
 
+pub mod disconnect_client;
+pub mod enable_frame_compression;
+pub mod enable_pipelining;
 pub mod get_client;
 pub mod get_clients;
+pub mod get_health;
+pub mod get_log_filter;
 pub mod get_me;
+pub mod get_profile;
 pub mod get_snapshot;
 pub mod get_stats;
+pub mod get_stats_history;
+pub mod hello;
+pub mod identify_client;
 pub mod ping;
+pub mod reload_config;
+pub mod set_log_filter;
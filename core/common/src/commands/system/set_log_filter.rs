@@ -0,0 +1,164 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::utils::duration::IggyDuration;
+use crate::{Command, SET_LOG_FILTER_CODE};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+const MAX_FILTER_LENGTH: usize = 1024;
+
+/// `SetLogFilter` command is used to change the tracing filter of the running server,
+/// without restarting it, e.g. to enable the debug logs of a single module.
+/// It has additional payload:
+/// - `filter` - the filter directives in the `RUST_LOG` format, e.g. `info,server::streaming=debug`.
+///   If not provided, the default filter is restored.
+/// - `ttl` - the optional time after which the default filter is restored.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct SetLogFilter {
+    /// The filter directives, or `None` to restore the default filter.
+    pub filter: Option<String>,
+    /// The time after which the default filter is restored.
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
+    pub ttl: Option<IggyDuration>,
+}
+
+impl Command for SetLogFilter {
+    fn code(&self) -> u32 {
+        SET_LOG_FILTER_CODE
+    }
+}
+
+impl Validatable<IggyError> for SetLogFilter {
+    fn validate(&self) -> Result<(), IggyError> {
+        match &self.filter {
+            Some(filter) if filter.trim().is_empty() || filter.len() > MAX_FILTER_LENGTH => {
+                Err(IggyError::InvalidLogFilter(filter.to_owned()))
+            }
+            None if self.ttl.is_some() => Err(IggyError::InvalidCommand),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl BytesSerializable for SetLogFilter {
+    fn to_bytes(&self) -> Bytes {
+        let filter = self.filter.as_deref().unwrap_or_default();
+        let mut bytes = BytesMut::with_capacity(12 + filter.len());
+        bytes.put_u32_le(filter.len() as u32);
+        bytes.put_slice(filter.as_bytes());
+        bytes.put_u64_le(self.ttl.map(|ttl| ttl.as_micros()).unwrap_or_default());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<SetLogFilter, IggyError> {
+        if bytes.len() < 12 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let filter_length = u32::from_le_bytes(
+            bytes[..4]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ) as usize;
+        if bytes.len() != 12 + filter_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let filter = match filter_length {
+            0 => None,
+            _ => Some(
+                from_utf8(&bytes[4..4 + filter_length])
+                    .map_err(|_| IggyError::InvalidUtf8)?
+                    .to_owned(),
+            ),
+        };
+        let ttl = (&bytes[4 + filter_length..]).get_u64_le();
+        let ttl = match ttl {
+            0 => None,
+            ttl => Some(IggyDuration::from(ttl)),
+        };
+        Ok(SetLogFilter { filter, ttl })
+    }
+}
+
+impl Display for SetLogFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}",
+            self.filter.as_deref().unwrap_or_default(),
+            self.ttl.map(|ttl| ttl.to_string()).unwrap_or_default()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = SetLogFilter {
+            filter: Some("info,server=debug".to_owned()),
+            ttl: Some(IggyDuration::from(1_000_000)),
+        };
+        let bytes = command.to_bytes();
+        let filter_length = u32::from_le_bytes(bytes[..4].try_into().unwrap()) as usize;
+        let filter = from_utf8(&bytes[4..4 + filter_length]).unwrap();
+        let ttl = u64::from_le_bytes(bytes[4 + filter_length..].try_into().unwrap());
+
+        assert_eq!(filter, "info,server=debug");
+        assert_eq!(ttl, 1_000_000);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = SetLogFilter {
+            filter: Some("warn".to_owned()),
+            ttl: Some(IggyDuration::from(5_000_000)),
+        };
+        let deserialized = SetLogFilter::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+
+        let reset = SetLogFilter::default();
+        let deserialized = SetLogFilter::from_bytes(reset.to_bytes()).unwrap();
+        assert_eq!(deserialized, reset);
+    }
+
+    #[test]
+    fn should_not_be_valid_given_empty_filter_or_ttl_without_filter() {
+        let command = SetLogFilter {
+            filter: Some(" ".to_owned()),
+            ttl: None,
+        };
+        assert!(command.validate().is_err());
+
+        let command = SetLogFilter {
+            filter: None,
+            ttl: Some(IggyDuration::from(1_000_000)),
+        };
+        assert!(command.validate().is_err());
+    }
+}
@@ -34,6 +34,7 @@ use std::str::from_utf8;
 /// - `current_password` - current password, must be between 3 and 100 characters long.
 /// - `new_password` - new password, must be between 3 and 100 characters long.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ChangePassword {
     /// Unique user ID (numeric or name).
     #[serde(skip)]
@@ -24,5 +24,7 @@ pub mod get_user;
 pub mod get_users;
 pub mod login_user;
 pub mod logout_user;
+pub mod revoke_user_sessions;
 pub mod update_permissions;
 pub mod update_user;
+pub mod update_user_quota;
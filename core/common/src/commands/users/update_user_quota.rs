@@ -0,0 +1,133 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Identifier;
+use crate::Sizeable;
+use crate::ThroughputQuota;
+use crate::Validatable;
+use crate::error::IggyError;
+use crate::{Command, UPDATE_USER_QUOTA_CODE};
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `UpdateUserQuota` command is used to update a user's throughput quota.
+/// It has additional payload:
+/// - `user_id` - unique user ID (numeric or name).
+/// - `quota` - new throughput quota (optional)
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct UpdateUserQuota {
+    /// Unique user ID (numeric or name).
+    #[serde(skip)]
+    pub user_id: Identifier,
+    /// New throughput quota, if `None` is provided, then the existing user's quota will be removed.
+    pub quota: Option<ThroughputQuota>,
+}
+
+impl Command for UpdateUserQuota {
+    fn code(&self) -> u32 {
+        UPDATE_USER_QUOTA_CODE
+    }
+}
+
+impl Validatable<IggyError> for UpdateUserQuota {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for UpdateUserQuota {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&self.user_id.to_bytes());
+        if let Some(quota) = &self.quota {
+            bytes.put_u8(1);
+            bytes.put_slice(&quota.to_bytes());
+        } else {
+            bytes.put_u8(0);
+        }
+
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<UpdateUserQuota, IggyError> {
+        if bytes.len() < 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let user_id = Identifier::from_bytes(bytes.clone())?;
+        let position = user_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() <= position {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let quota = match bytes[position] {
+            0 => None,
+            1 => Some(ThroughputQuota::from_bytes(bytes.slice(position + 1..))?),
+            _ => return Err(IggyError::InvalidCommand),
+        };
+
+        Ok(UpdateUserQuota { user_id, quota })
+    }
+}
+
+impl Display for UpdateUserQuota {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let quota = if let Some(quota) = &self.quota {
+            quota.to_string()
+        } else {
+            "no_quota".to_string()
+        };
+        write!(f, "{}|{}", self.user_id, quota)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let command = UpdateUserQuota {
+            user_id: Identifier::numeric(1).unwrap(),
+            quota: Some(ThroughputQuota {
+                produce_bytes_per_second: 1024,
+                produce_messages_per_second: 10,
+                consume_bytes_per_second: 2048,
+                consume_messages_per_second: 20,
+            }),
+        };
+        let bytes = command.to_bytes();
+        let deserialized = UpdateUserQuota::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_be_deserialized_without_quota() {
+        let user_id = Identifier::named("user").unwrap();
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&user_id.to_bytes());
+        bytes.put_u8(0);
+
+        let command = UpdateUserQuota::from_bytes(bytes.freeze()).unwrap();
+        assert_eq!(command.user_id, user_id);
+        assert!(command.quota.is_none());
+    }
+}
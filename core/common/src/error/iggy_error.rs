@@ -64,6 +64,10 @@ pub enum IggyError {
     StateFileCorrupted = 15,
     #[error("Invalid state entry checksum: {0}, expected: {1}, for index: {2}")]
     InvalidStateEntryChecksum(u32, u32, u64) = 16,
+    #[error("Invalid log filter: {0}")]
+    InvalidLogFilter(String) = 17,
+    #[error("Cannot reload log filter")]
+    CannotReloadLogFilter = 18,
     #[error("Cannot open database, Path: {0}")]
     CannotOpenDatabase(String) = 19,
     #[error("Resource with key: {0} was not found.")]
@@ -80,6 +84,12 @@ pub enum IggyError {
     InvalidClientAddress = 34,
     #[error("Invalid IP address: {0}:{1}")]
     InvalidIpAddress(String, String) = 35,
+    #[error("Unsupported protocol version: {0}, minimum supported version: {1}")]
+    UnsupportedProtocolVersion(u32, u32) = 36,
+    #[error("Frame size: {0} exceeds the maximum allowed size: {1}")]
+    FrameTooLarge(u32, u32) = 37,
+    #[error("Invalid compressed frame")]
+    InvalidCompressedFrame = 38,
     #[error("Unauthenticated")]
     Unauthenticated = 40,
     #[error("Unauthorized")]
@@ -112,6 +122,10 @@ pub enum IggyError {
     PersonalAccessTokenExpired(String, u32) = 54,
     #[error("Users limit reached.")]
     UsersLimitReached = 55,
+    #[error("Password does not satisfy the password policy: {0}")]
+    PasswordPolicyViolation(String) = 56,
+    #[error("Too many login attempts")]
+    TooManyLoginAttempts = 58,
     #[error("Not connected")]
     NotConnected = 61,
     #[error("Client shutdown")]
@@ -156,6 +170,8 @@ pub enum IggyError {
     ClientNotFound(u32) = 100,
     #[error("Invalid client ID")]
     InvalidClientId = 101,
+    #[error("Invalid client metadata")]
+    InvalidClientMetadata = 102,
     #[error("Connection closed")]
     ConnectionClosed = 206,
     #[error("Cannot parse header kind from {0}")]
@@ -206,6 +222,12 @@ pub enum IggyError {
     InvalidStreamId = 1014,
     #[error("Cannot read streams")]
     CannotReadStreams = 1015,
+    #[error("Stream with ID: {0} has reached its maximum size.")]
+    StreamFull(u32) = 1016,
+    #[error("Invalid stream size policy")]
+    InvalidStreamSizePolicy = 1017,
+    #[error("Stream with ID: {0} is used internally by the server and cannot be modified.")]
+    InternalStream(u32) = 1018,
     #[error(
         "Max topic size cannot be lower than segment size. Max topic size: {0} < segment size: {1}."
     )]
@@ -433,6 +455,10 @@ pub enum IggyError {
     InvalidConnectionString = 8000,
     #[error("Snapshot file completion failed")]
     SnapshotFileCompletionFailed = 9000,
+    #[error("Profiler is already running")]
+    ProfilerAlreadyRunning = 9001,
+    #[error("Cannot capture profile: {0}")]
+    CannotCaptureProfile(String) = 9002,
     #[error("Cannot serialize resource")]
     CannotSerializeResource = 10000,
     #[error("Cannot deserialize resource")]
@@ -46,9 +46,12 @@ pub use traits::validatable::Validatable;
 // Types
 pub use types::args::*;
 pub use types::client::client_info::*;
+pub use types::client::client_metadata::*;
 pub use types::client_state::ClientState;
 pub use types::command::*;
 pub use types::compression::compression_algorithm::*;
+pub use types::compression::frame_compression::*;
+pub use types::config_reload::*;
 pub use types::configuration::auth_config::auto_login::*;
 pub use types::configuration::auth_config::connection_string::*;
 pub use types::configuration::auth_config::connection_string_options::*;
@@ -64,20 +67,34 @@ pub use types::configuration::tcp_config::tcp_client_config::*;
 pub use types::configuration::tcp_config::tcp_client_config_builder::*;
 pub use types::configuration::tcp_config::tcp_client_reconnection_config::*;
 pub use types::configuration::tcp_config::tcp_connection_string_options::*;
+pub use types::configuration::unix_config::unix_client_config::*;
+pub use types::configuration::unix_config::unix_client_config_builder::*;
+pub use types::configuration::unix_config::unix_connection_string_options::*;
+pub use types::configuration::websocket_config::websocket_client_config::*;
+pub use types::configuration::websocket_config::websocket_client_config_builder::*;
+pub use types::configuration::websocket_config::websocket_connection_string_options::*;
 pub use types::confirmation::*;
 pub use types::consumer::consumer_group::*;
 pub use types::consumer::consumer_kind::*;
 pub use types::consumer::consumer_offset_info::*;
 pub use types::diagnostic::diagnostic_event::DiagnosticEvent;
+pub use types::health::*;
 pub use types::identifier::*;
+pub use types::log_filter::*;
 pub use types::message::*;
 pub use types::partition::*;
 pub use types::permissions::permissions_global::*;
 pub use types::permissions::personal_access_token::*;
+pub use types::profile::*;
+pub use types::protocol::protocol_features::*;
+pub use types::protocol::server_hello::*;
 pub use types::snapshot::*;
 pub use types::stats::*;
+pub use types::stats_history::*;
 pub use types::stream::*;
+pub use types::system_event::*;
 pub use types::topic::*;
+pub use types::user::throughput_quota::*;
 pub use types::user::user_identity_info::*;
 pub use types::user::user_info::*;
 pub use types::user::user_status::*;
@@ -92,3 +109,4 @@ pub use utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
 pub use utils::text;
 pub use utils::timestamp::*;
 pub use utils::topic_size::MaxTopicSize;
+pub use utils::websocket;
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::FrameCompression;
 use crate::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
 use clap::Parser;
 use serde::{Deserialize, Serialize};
@@ -28,7 +29,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Parser, Debug, Clone, Deserialize, Serialize, Default)]
 #[command(author, version, about, long_about = None)]
 pub struct ArgsOptional {
-    /// The transport to use. Valid values are `quic`, `http` and `tcp`
+    /// The transport to use. Valid values are `quic`, `http`, `tcp`, `unix`, `ws` and `wss`
     ///
     /// [default: tcp]
     #[arg(long)]
@@ -103,6 +104,20 @@ pub struct ArgsOptional {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tcp_tls_domain: Option<String>,
 
+    /// The optional socket path for the Unix domain socket transport
+    ///
+    /// [default: /tmp/iggy.sock]
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unix_socket_path: Option<String>,
+
+    /// The optional endpoint URL for the WebSocket (`ws` and `wss`) transport
+    ///
+    /// [default: ws://localhost:3000/ws]
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub websocket_url: Option<String>,
+
     /// The optional client address for the QUIC transport
     ///
     /// [default: 127.0.0.1:0]
@@ -203,7 +218,7 @@ pub struct ArgsOptional {
 /// The arguments used by the `ClientProviderConfig` to create a client.
 #[derive(Debug, Clone)]
 pub struct Args {
-    /// The transport to use. Valid values are `quic`, `http` and `tcp`
+    /// The transport to use. Valid values are `quic`, `http`, `tcp`, `unix`, `ws` and `wss`
     pub transport: String,
 
     /// Optional encryption key for the message payload used by the client
@@ -251,6 +266,15 @@ pub struct Args {
     /// Disable nodelay for the TCP transport
     pub tcp_nodelay: bool,
 
+    /// Flag to enable pipelining of the concurrent requests for the TCP transport
+    pub tcp_pipelining: bool,
+
+    /// The optional socket path for the Unix domain socket transport
+    pub unix_socket_path: String,
+
+    /// The optional endpoint URL for the WebSocket (`ws` and `wss`) transport
+    pub websocket_url: String,
+
     /// The optional client address for the QUIC transport
     pub quic_client_address: String,
 
@@ -301,11 +325,17 @@ pub struct Args {
 
     /// The optional heartbeat interval for the QUIC transport
     pub quic_heartbeat_interval: String,
+
+    /// The transport-level compression of the frames for the TCP and QUIC transports
+    pub frame_compression: FrameCompression,
 }
 
 const QUIC_TRANSPORT: &str = "quic";
 const HTTP_TRANSPORT: &str = "http";
 const TCP_TRANSPORT: &str = "tcp";
+const UNIX_TRANSPORT: &str = "unix";
+const WS_TRANSPORT: &str = "ws";
+const WSS_TRANSPORT: &str = "wss";
 
 impl Args {
     pub fn get_server_address(&self) -> Option<String> {
@@ -318,9 +348,27 @@ impl Args {
                     .replace("localhost", "127.0.0.1"),
             ),
             TCP_TRANSPORT => Some(self.tcp_server_address.replace("localhost", "127.0.0.1")),
+            UNIX_TRANSPORT => Some(self.unix_socket_path.clone()),
+            WS_TRANSPORT | WSS_TRANSPORT => Some(
+                self.get_websocket_address_and_path()
+                    .0
+                    .replace("localhost", "127.0.0.1"),
+            ),
             _ => None,
         }
     }
+
+    /// Splits the WebSocket URL into the server address and the endpoint path.
+    pub fn get_websocket_address_and_path(&self) -> (String, String) {
+        let url = self
+            .websocket_url
+            .trim_start_matches("wss://")
+            .trim_start_matches("ws://");
+        match url.split_once('/') {
+            Some((address, path)) => (address.to_string(), format!("/{path}")),
+            None => (url.to_string(), "/".to_string()),
+        }
+    }
 }
 
 impl Default for Args {
@@ -342,6 +390,9 @@ impl Default for Args {
             tcp_tls_domain: "localhost".to_string(),
             tcp_tls_ca_file: None,
             tcp_nodelay: false,
+            tcp_pipelining: false,
+            unix_socket_path: "/tmp/iggy.sock".to_string(),
+            websocket_url: "ws://localhost:3000/ws".to_string(),
             quic_client_address: "127.0.0.1:0".to_string(),
             quic_server_address: "127.0.0.1:8080".to_string(),
             quic_server_name: "localhost".to_string(),
@@ -359,6 +410,7 @@ impl Default for Args {
             quic_max_idle_timeout: 10000,
             quic_validate_certificate: false,
             quic_heartbeat_interval: "5s".to_string(),
+            frame_compression: FrameCompression::default(),
         }
     }
 }
@@ -401,6 +453,12 @@ impl From<Vec<ArgsOptional>> for Args {
             if let Some(tcp_tls_domain) = optional_args.tcp_tls_domain {
                 args.tcp_tls_domain = tcp_tls_domain;
             }
+            if let Some(unix_socket_path) = optional_args.unix_socket_path {
+                args.unix_socket_path = unix_socket_path;
+            }
+            if let Some(websocket_url) = optional_args.websocket_url {
+                args.websocket_url = websocket_url;
+            }
             if let Some(quic_client_address) = optional_args.quic_client_address {
                 args.quic_client_address = quic_client_address;
             }
@@ -16,6 +16,8 @@
  * under the License.
  */
 
+use crate::ClientMetadata;
+use crate::utils::{byte_size::IggyByteSize, timestamp::IggyTimestamp};
 use serde::{Deserialize, Serialize};
 
 /// `ClientInfo` represents the information about a client.
@@ -25,7 +27,14 @@ use serde::{Deserialize, Serialize};
 /// - `address`: the remote address of the client.
 /// - `transport`: the transport protocol used by the client.
 /// - `consumer_groups_count`: the number of consumer groups the client is part of.
+/// - `connected_at`: the timestamp when the client has connected.
+/// - `last_activity_at`: the timestamp of the last command received from the client.
+/// - `bytes_received`: the total size of the requests received from the client.
+/// - `bytes_sent`: the total size of the responses sent to the client.
+/// - `authentication_method`: the method used by the client to authenticate, if any.
+/// - `metadata`: the identification metadata sent by the client, if any.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ClientInfo {
     /// The unique identifier of the client.
     pub client_id: u32,
@@ -37,6 +46,19 @@ pub struct ClientInfo {
     pub transport: String,
     /// The number of consumer groups the client is part of.
     pub consumer_groups_count: u32,
+    /// The timestamp when the client has connected.
+    pub connected_at: IggyTimestamp,
+    /// The timestamp of the last command received from the client.
+    pub last_activity_at: IggyTimestamp,
+    /// The total size of the requests received from the client.
+    pub bytes_received: IggyByteSize,
+    /// The total size of the responses sent to the client.
+    pub bytes_sent: IggyByteSize,
+    /// The method used by the client to authenticate (`Password` or `PersonalAccessToken`). This field is optional, as the client might be connected but not authenticated yet.
+    pub authentication_method: Option<String>,
+    /// The identification metadata sent by the client (application name, SDK, hostname and labels). This field is optional, as the client might not have identified itself.
+    #[serde(default)]
+    pub metadata: Option<ClientMetadata>,
 }
 
 /// `ClientInfoDetails` represents the detailed information about a client.
@@ -46,8 +68,15 @@ pub struct ClientInfo {
 /// - `address`: the remote address of the client.
 /// - `transport`: the transport protocol used by the client.
 /// - `consumer_groups_count`: the number of consumer groups the client is part of.
+/// - `connected_at`: the timestamp when the client has connected.
+/// - `last_activity_at`: the timestamp of the last command received from the client.
+/// - `bytes_received`: the total size of the requests received from the client.
+/// - `bytes_sent`: the total size of the responses sent to the client.
+/// - `authentication_method`: the method used by the client to authenticate, if any.
+/// - `metadata`: the identification metadata sent by the client, if any.
 /// - `consumer_groups`: the collection of consumer groups the client is part of.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ClientInfoDetails {
     /// The unique identifier of the client.
     pub client_id: u32,
@@ -59,6 +88,19 @@ pub struct ClientInfoDetails {
     pub transport: String,
     /// The number of consumer groups the client is part of.
     pub consumer_groups_count: u32,
+    /// The timestamp when the client has connected.
+    pub connected_at: IggyTimestamp,
+    /// The timestamp of the last command received from the client.
+    pub last_activity_at: IggyTimestamp,
+    /// The total size of the requests received from the client.
+    pub bytes_received: IggyByteSize,
+    /// The total size of the responses sent to the client.
+    pub bytes_sent: IggyByteSize,
+    /// The method used by the client to authenticate (`Password` or `PersonalAccessToken`). This field is optional, as the client might be connected but not authenticated yet.
+    pub authentication_method: Option<String>,
+    /// The identification metadata sent by the client (application name, SDK, hostname and labels). This field is optional, as the client might not have identified itself.
+    #[serde(default)]
+    pub metadata: Option<ClientMetadata>,
     /// The collection of consumer groups the client is part of.
     pub consumer_groups: Vec<ConsumerGroupInfo>,
 }
@@ -69,6 +111,7 @@ pub struct ClientInfoDetails {
 /// - `topic_id`: the unique identifier (numeric) of the topic.
 /// - `group_id`: the unique identifier (numeric) of the consumer group.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ConsumerGroupInfo {
     /// The unique identifier (numeric) of the stream.
     pub stream_id: u32,
@@ -0,0 +1,222 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Validatable;
+use crate::error::IggyError;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// The maximum number of labels which can be attached to the client metadata.
+pub const MAX_CLIENT_LABELS: usize = 32;
+
+/// `ClientMetadata` represents the identification data sent by the client after connecting.
+/// It consists of the following fields:
+/// - `application_name`: the name of the application using the client.
+/// - `sdk_language`: the programming language of the SDK, e.g. `rust`.
+/// - `sdk_version`: the version of the SDK.
+/// - `hostname`: the hostname of the machine the client is running on.
+/// - `labels`: the free-form key-value labels describing the client.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ClientMetadata {
+    /// The name of the application using the client.
+    #[serde(default)]
+    pub application_name: String,
+    /// The programming language of the SDK, e.g. `rust`.
+    #[serde(default)]
+    pub sdk_language: String,
+    /// The version of the SDK.
+    #[serde(default)]
+    pub sdk_version: String,
+    /// The hostname of the machine the client is running on.
+    #[serde(default)]
+    pub hostname: String,
+    /// The free-form key-value labels describing the client.
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+}
+
+impl Validatable<IggyError> for ClientMetadata {
+    fn validate(&self) -> Result<(), IggyError> {
+        let values = [
+            &self.application_name,
+            &self.sdk_language,
+            &self.sdk_version,
+            &self.hostname,
+        ];
+        if values.iter().any(|value| value.len() > 255) {
+            return Err(IggyError::InvalidClientMetadata);
+        }
+
+        if self.labels.len() > MAX_CLIENT_LABELS {
+            return Err(IggyError::InvalidClientMetadata);
+        }
+
+        for (key, value) in &self.labels {
+            if key.is_empty() || key.len() > 255 || value.len() > 255 {
+                return Err(IggyError::InvalidClientMetadata);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for ClientMetadata {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(self.get_buffer_size());
+        self.write_to_buffer(&mut bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<ClientMetadata, IggyError> {
+        let mut position = 0;
+        let application_name = read_string(&bytes, &mut position)?;
+        let sdk_language = read_string(&bytes, &mut position)?;
+        let sdk_version = read_string(&bytes, &mut position)?;
+        let hostname = read_string(&bytes, &mut position)?;
+        let labels_count = *bytes.get(position).ok_or(IggyError::InvalidCommand)?;
+        position += 1;
+        let mut labels = BTreeMap::new();
+        for _ in 0..labels_count {
+            let key = read_string(&bytes, &mut position)?;
+            let value = read_string(&bytes, &mut position)?;
+            labels.insert(key, value);
+        }
+
+        if position != bytes.len() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(ClientMetadata {
+            application_name,
+            sdk_language,
+            sdk_version,
+            hostname,
+            labels,
+        })
+    }
+
+    fn write_to_buffer(&self, bytes: &mut BytesMut) {
+        write_string(bytes, &self.application_name);
+        write_string(bytes, &self.sdk_language);
+        write_string(bytes, &self.sdk_version);
+        write_string(bytes, &self.hostname);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.labels.len() as u8);
+        for (key, value) in &self.labels {
+            write_string(bytes, key);
+            write_string(bytes, value);
+        }
+    }
+
+    fn get_buffer_size(&self) -> usize {
+        5 + self.application_name.len()
+            + self.sdk_language.len()
+            + self.sdk_version.len()
+            + self.hostname.len()
+            + self
+                .labels
+                .iter()
+                .map(|(key, value)| 2 + key.len() + value.len())
+                .sum::<usize>()
+    }
+}
+
+fn write_string(bytes: &mut BytesMut, value: &str) {
+    #[allow(clippy::cast_possible_truncation)]
+    bytes.put_u8(value.len() as u8);
+    bytes.put_slice(value.as_bytes());
+}
+
+fn read_string(bytes: &[u8], position: &mut usize) -> Result<String, IggyError> {
+    let length = *bytes.get(*position).ok_or(IggyError::InvalidCommand)? as usize;
+    *position += 1;
+    let value = bytes
+        .get(*position..*position + length)
+        .ok_or(IggyError::InvalidCommand)?;
+    let value = from_utf8(value)
+        .map_err(|_| IggyError::InvalidUtf8)?
+        .to_string();
+    *position += length;
+    Ok(value)
+}
+
+impl Display for ClientMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.application_name, self.sdk_language, self.sdk_version, self.hostname
+        )?;
+        for (key, value) in &self.labels {
+            write!(f, "|{key}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata() -> ClientMetadata {
+        ClientMetadata {
+            application_name: "orders-service".to_string(),
+            sdk_language: "rust".to_string(),
+            sdk_version: "0.7.0".to_string(),
+            hostname: "orders-1".to_string(),
+            labels: BTreeMap::from([
+                ("env".to_string(), "prod".to_string()),
+                ("region".to_string(), "eu-west-1".to_string()),
+            ]),
+        }
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let metadata = metadata();
+        let bytes = metadata.to_bytes();
+        assert_eq!(bytes.len(), metadata.get_buffer_size());
+
+        let deserialized = ClientMetadata::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, metadata);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let bytes = metadata().to_bytes();
+        let truncated = bytes.slice(..bytes.len() - 1);
+        assert!(ClientMetadata::from_bytes(truncated).is_err());
+    }
+
+    #[test]
+    fn should_not_be_valid_with_too_many_labels() {
+        let mut metadata = metadata();
+        for i in 0..MAX_CLIENT_LABELS {
+            metadata
+                .labels
+                .insert(format!("key-{i}"), "value".to_string());
+        }
+        assert!(metadata.validate().is_err());
+    }
+}
@@ -16,3 +16,4 @@
 // under the License.
 
 pub(crate) mod client_info;
+pub(crate) mod client_metadata;
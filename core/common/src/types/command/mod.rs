@@ -27,16 +27,40 @@ pub trait Command: BytesSerializable + Validatable<IggyError> + Send + Sync + Di
 
 pub const PING: &str = "ping";
 pub const PING_CODE: u32 = 1;
+pub const HELLO: &str = "hello";
+pub const HELLO_CODE: u32 = 2;
 pub const GET_STATS: &str = "stats";
 pub const GET_STATS_CODE: u32 = 10;
 pub const GET_SNAPSHOT_FILE: &str = "snapshot";
 pub const GET_SNAPSHOT_FILE_CODE: u32 = 11;
+pub const RELOAD_CONFIG: &str = "config.reload";
+pub const RELOAD_CONFIG_CODE: u32 = 12;
+pub const GET_HEALTH: &str = "health";
+pub const GET_HEALTH_CODE: u32 = 13;
+pub const GET_LOG_FILTER: &str = "logging.get";
+pub const GET_LOG_FILTER_CODE: u32 = 14;
+pub const SET_LOG_FILTER: &str = "logging.set";
+pub const SET_LOG_FILTER_CODE: u32 = 15;
+pub const GET_PROFILE: &str = "profile";
+pub const GET_PROFILE_CODE: u32 = 16;
+pub const GET_STATS_HISTORY: &str = "stats.history";
+pub const GET_STATS_HISTORY_CODE: u32 = 17;
 pub const GET_ME: &str = "me";
 pub const GET_ME_CODE: u32 = 20;
 pub const GET_CLIENT: &str = "client.get";
 pub const GET_CLIENT_CODE: u32 = 21;
 pub const GET_CLIENTS: &str = "client.list";
 pub const GET_CLIENTS_CODE: u32 = 22;
+pub const DISCONNECT_CLIENT: &str = "client.disconnect";
+pub const DISCONNECT_CLIENT_CODE: u32 = 23;
+pub const IDENTIFY_CLIENT: &str = "client.identify";
+pub const IDENTIFY_CLIENT_CODE: u32 = 24;
+pub const ENABLE_PIPELINING: &str = "client.pipelining";
+pub const ENABLE_PIPELINING_CODE: u32 = 25;
+pub const ENABLE_FRAME_COMPRESSION: &str = "client.compression";
+pub const ENABLE_FRAME_COMPRESSION_CODE: u32 = 26;
+pub const REVOKE_USER_SESSIONS: &str = "user.revoke_sessions";
+pub const REVOKE_USER_SESSIONS_CODE: u32 = 30;
 pub const GET_USER: &str = "user.get";
 pub const GET_USER_CODE: u32 = 31;
 pub const GET_USERS: &str = "user.list";
@@ -55,6 +79,8 @@ pub const LOGIN_USER: &str = "user.login";
 pub const LOGIN_USER_CODE: u32 = 38;
 pub const LOGOUT_USER: &str = "user.logout";
 pub const LOGOUT_USER_CODE: u32 = 39;
+pub const UPDATE_USER_QUOTA: &str = "user.quota";
+pub const UPDATE_USER_QUOTA_CODE: u32 = 40;
 pub const GET_PERSONAL_ACCESS_TOKENS: &str = "personal_access_token.list";
 pub const GET_PERSONAL_ACCESS_TOKENS_CODE: u32 = 41;
 pub const CREATE_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.create";
@@ -63,6 +89,8 @@ pub const DELETE_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.delete";
 pub const DELETE_PERSONAL_ACCESS_TOKEN_CODE: u32 = 43;
 pub const LOGIN_WITH_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.login";
 pub const LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE: u32 = 44;
+pub const UPDATE_PERSONAL_ACCESS_TOKEN_QUOTA: &str = "personal_access_token.quota";
+pub const UPDATE_PERSONAL_ACCESS_TOKEN_QUOTA_CODE: u32 = 45;
 pub const POLL_MESSAGES: &str = "message.poll";
 pub const POLL_MESSAGES_CODE: u32 = 100;
 pub const SEND_MESSAGES: &str = "message.send";
@@ -87,6 +115,8 @@ pub const UPDATE_STREAM: &str = "stream.update";
 pub const UPDATE_STREAM_CODE: u32 = 204;
 pub const PURGE_STREAM: &str = "stream.purge";
 pub const PURGE_STREAM_CODE: u32 = 205;
+pub const UPDATE_STREAM_RETENTION: &str = "stream.retention";
+pub const UPDATE_STREAM_RETENTION_CODE: u32 = 206;
 pub const GET_TOPIC: &str = "topic.get";
 pub const GET_TOPIC_CODE: u32 = 300;
 pub const GET_TOPICS: &str = "topic.list";
@@ -121,10 +151,16 @@ pub const LEAVE_CONSUMER_GROUP_CODE: u32 = 605;
 pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
     match code {
         PING_CODE => Ok(PING),
+        HELLO_CODE => Ok(HELLO),
         GET_STATS_CODE => Ok(GET_STATS),
         GET_ME_CODE => Ok(GET_ME),
         GET_CLIENT_CODE => Ok(GET_CLIENT),
         GET_CLIENTS_CODE => Ok(GET_CLIENTS),
+        DISCONNECT_CLIENT_CODE => Ok(DISCONNECT_CLIENT),
+        IDENTIFY_CLIENT_CODE => Ok(IDENTIFY_CLIENT),
+        ENABLE_PIPELINING_CODE => Ok(ENABLE_PIPELINING),
+        ENABLE_FRAME_COMPRESSION_CODE => Ok(ENABLE_FRAME_COMPRESSION),
+        REVOKE_USER_SESSIONS_CODE => Ok(REVOKE_USER_SESSIONS),
         GET_USER_CODE => Ok(GET_USER),
         GET_USERS_CODE => Ok(GET_USERS),
         CREATE_USER_CODE => Ok(CREATE_USER),
@@ -134,10 +170,12 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
         CHANGE_PASSWORD_CODE => Ok(CHANGE_PASSWORD),
         LOGIN_USER_CODE => Ok(LOGIN_USER),
         LOGOUT_USER_CODE => Ok(LOGOUT_USER),
+        UPDATE_USER_QUOTA_CODE => Ok(UPDATE_USER_QUOTA),
         GET_PERSONAL_ACCESS_TOKENS_CODE => Ok(GET_PERSONAL_ACCESS_TOKENS),
         CREATE_PERSONAL_ACCESS_TOKEN_CODE => Ok(CREATE_PERSONAL_ACCESS_TOKEN),
         DELETE_PERSONAL_ACCESS_TOKEN_CODE => Ok(DELETE_PERSONAL_ACCESS_TOKEN),
         LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE => Ok(LOGIN_WITH_PERSONAL_ACCESS_TOKEN),
+        UPDATE_PERSONAL_ACCESS_TOKEN_QUOTA_CODE => Ok(UPDATE_PERSONAL_ACCESS_TOKEN_QUOTA),
         SEND_MESSAGES_CODE => Ok(SEND_MESSAGES),
         POLL_MESSAGES_CODE => Ok(POLL_MESSAGES),
         FLUSH_UNSAVED_BUFFER_CODE => Ok(FLUSH_UNSAVED_BUFFER),
@@ -149,6 +187,7 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
         DELETE_STREAM_CODE => Ok(DELETE_STREAM),
         UPDATE_STREAM_CODE => Ok(UPDATE_STREAM),
         PURGE_STREAM_CODE => Ok(PURGE_STREAM),
+        UPDATE_STREAM_RETENTION_CODE => Ok(UPDATE_STREAM_RETENTION),
         GET_TOPIC_CODE => Ok(GET_TOPIC),
         GET_TOPICS_CODE => Ok(GET_TOPICS),
         CREATE_TOPIC_CODE => Ok(CREATE_TOPIC),
@@ -165,6 +204,12 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
         JOIN_CONSUMER_GROUP_CODE => Ok(JOIN_CONSUMER_GROUP),
         LEAVE_CONSUMER_GROUP_CODE => Ok(LEAVE_CONSUMER_GROUP),
         GET_SNAPSHOT_FILE_CODE => Ok(GET_SNAPSHOT_FILE),
+        RELOAD_CONFIG_CODE => Ok(RELOAD_CONFIG),
+        GET_HEALTH_CODE => Ok(GET_HEALTH),
+        GET_LOG_FILTER_CODE => Ok(GET_LOG_FILTER),
+        SET_LOG_FILTER_CODE => Ok(SET_LOG_FILTER),
+        GET_PROFILE_CODE => Ok(GET_PROFILE),
+        GET_STATS_HISTORY_CODE => Ok(GET_STATS_HISTORY),
         _ => Err(IggyError::InvalidCommand),
     }
 }
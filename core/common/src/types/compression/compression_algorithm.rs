@@ -52,6 +52,11 @@ impl FromStr for CompressionAlgorithm {
 }
 
 impl CompressionAlgorithm {
+    /// Returns all the compression algorithms supported by this version of the library.
+    pub fn all() -> Vec<CompressionAlgorithm> {
+        vec![CompressionAlgorithm::None, CompressionAlgorithm::Gzip]
+    }
+
     pub fn as_code(&self) -> u8 {
         match self {
             CompressionAlgorithm::None => 1,
@@ -77,6 +82,12 @@ impl Display for CompressionAlgorithm {
     }
 }
 
+#[cfg(feature = "openapi")]
+crate::utils::openapi::impl_to_schema!(
+    CompressionAlgorithm,
+    crate::utils::openapi::string_schema("The compression algorithm.", &["none", "gzip"])
+);
+
 impl Serialize for CompressionAlgorithm {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -0,0 +1,292 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use tracing::error;
+
+/// The flag set in the length of the request or the response frame, which payload is compressed.
+/// The compressed payload is preceded by the length of the uncompressed one.
+pub const COMPRESSED_FRAME_FLAG: u32 = 1 << 31;
+
+/// The default minimum size of the frame payload to compress.
+pub const DEFAULT_FRAME_COMPRESSION_MIN_SIZE: u32 = 1024;
+
+const UNCOMPRESSED_LENGTH_SIZE: usize = 4;
+
+/// The algorithms used to compress the binary protocol frames on the wire.
+/// Unlike `CompressionAlgorithm`, it has no impact on how the messages are stored.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FrameCompressionAlgorithm {
+    /// No compression.
+    #[default]
+    None,
+    /// Zstandard, which has the better compression ratio.
+    Zstd,
+    /// LZ4, which is faster, but compresses less.
+    Lz4,
+}
+
+impl FrameCompressionAlgorithm {
+    /// Returns all the frame compression algorithms supported by this version of the library.
+    pub fn all() -> Vec<FrameCompressionAlgorithm> {
+        vec![
+            FrameCompressionAlgorithm::None,
+            FrameCompressionAlgorithm::Zstd,
+            FrameCompressionAlgorithm::Lz4,
+        ]
+    }
+
+    pub fn as_code(&self) -> u8 {
+        match self {
+            FrameCompressionAlgorithm::None => 1,
+            FrameCompressionAlgorithm::Zstd => 2,
+            FrameCompressionAlgorithm::Lz4 => 3,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(FrameCompressionAlgorithm::None),
+            2 => Ok(FrameCompressionAlgorithm::Zstd),
+            3 => Ok(FrameCompressionAlgorithm::Lz4),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FrameCompressionAlgorithm::None => "none",
+            FrameCompressionAlgorithm::Zstd => "zstd",
+            FrameCompressionAlgorithm::Lz4 => "lz4",
+        }
+    }
+}
+
+impl FromStr for FrameCompressionAlgorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(FrameCompressionAlgorithm::None),
+            "zstd" => Ok(FrameCompressionAlgorithm::Zstd),
+            "lz4" => Ok(FrameCompressionAlgorithm::Lz4),
+            _ => Err(format!("Unknown frame compression algorithm: {s}")),
+        }
+    }
+}
+
+impl Display for FrameCompressionAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// `FrameCompression` is the transport-level compression of the binary protocol frames,
+/// negotiated per connection with the `EnableFrameCompression` command.
+/// Only the frames with the payload of at least `min_size` bytes are compressed,
+/// and only if the compression actually reduces their size.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct FrameCompression {
+    /// The compression algorithm, `none` disables the compression.
+    pub algorithm: FrameCompressionAlgorithm,
+    /// The minimum size of the frame payload to compress.
+    pub min_size: u32,
+}
+
+impl Default for FrameCompression {
+    fn default() -> Self {
+        FrameCompression {
+            algorithm: FrameCompressionAlgorithm::None,
+            min_size: DEFAULT_FRAME_COMPRESSION_MIN_SIZE,
+        }
+    }
+}
+
+impl FrameCompression {
+    pub fn new(algorithm: FrameCompressionAlgorithm, min_size: u32) -> Self {
+        FrameCompression {
+            algorithm,
+            min_size,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.algorithm != FrameCompressionAlgorithm::None
+    }
+
+    /// Compresses the frame payload, preceded by its uncompressed length.
+    /// Returns `None` if the payload should be sent as is, i.e. it's too small or incompressible.
+    pub fn compress(&self, payload: &[u8]) -> Option<Bytes> {
+        if !self.is_enabled() || payload.len() < self.min_size as usize {
+            return None;
+        }
+
+        let mut compressed = BytesMut::with_capacity(UNCOMPRESSED_LENGTH_SIZE + payload.len());
+        compressed.put_u32_le(payload.len() as u32);
+        match self.algorithm {
+            FrameCompressionAlgorithm::Zstd => {
+                match zstd::bulk::compress(payload, zstd::DEFAULT_COMPRESSION_LEVEL) {
+                    Ok(data) => compressed.put_slice(&data),
+                    Err(error) => {
+                        error!("Failed to compress the frame using zstd: {error}");
+                        return None;
+                    }
+                }
+            }
+            FrameCompressionAlgorithm::Lz4 => {
+                compressed.put_slice(&lz4_flex::block::compress(payload));
+            }
+            FrameCompressionAlgorithm::None => return None,
+        }
+
+        if compressed.len() >= payload.len() {
+            return None;
+        }
+
+        Some(compressed.freeze())
+    }
+
+    /// Decompresses the frame payload produced by `compress`.
+    /// Fails if the uncompressed payload would exceed `max_size` (0 means unlimited).
+    pub fn decompress(&self, payload: &[u8], max_size: u32) -> Result<Bytes, IggyError> {
+        if payload.len() < UNCOMPRESSED_LENGTH_SIZE {
+            return Err(IggyError::InvalidCompressedFrame);
+        }
+
+        let uncompressed_length = u32::from_le_bytes(
+            payload[..UNCOMPRESSED_LENGTH_SIZE]
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        );
+        if max_size > 0 && uncompressed_length > max_size {
+            return Err(IggyError::FrameTooLarge(uncompressed_length, max_size));
+        }
+
+        let data = &payload[UNCOMPRESSED_LENGTH_SIZE..];
+        let uncompressed_length = uncompressed_length as usize;
+        let decompressed = match self.algorithm {
+            FrameCompressionAlgorithm::Zstd => zstd::bulk::decompress(data, uncompressed_length)
+                .map_err(|error| {
+                    error!("Failed to decompress the frame using zstd: {error}");
+                    IggyError::InvalidCompressedFrame
+                })?,
+            FrameCompressionAlgorithm::Lz4 => {
+                lz4_flex::block::decompress(data, uncompressed_length).map_err(|error| {
+                    error!("Failed to decompress the frame using LZ4: {error}");
+                    IggyError::InvalidCompressedFrame
+                })?
+            }
+            FrameCompressionAlgorithm::None => return Err(IggyError::InvalidCompressedFrame),
+        };
+
+        if decompressed.len() != uncompressed_length {
+            return Err(IggyError::InvalidCompressedFrame);
+        }
+
+        Ok(Bytes::from(decompressed))
+    }
+}
+
+impl Display for FrameCompression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if !self.is_enabled() {
+            return write!(f, "none");
+        }
+
+        write!(f, "{} (min size: {} B)", self.algorithm, self.min_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload() -> Vec<u8> {
+        b"iggy message streaming platform "
+            .iter()
+            .copied()
+            .cycle()
+            .take(10_000)
+            .collect()
+    }
+
+    #[test]
+    fn should_compress_and_decompress_payload() {
+        for algorithm in [
+            FrameCompressionAlgorithm::Zstd,
+            FrameCompressionAlgorithm::Lz4,
+        ] {
+            let compression = FrameCompression::new(algorithm, 100);
+            let payload = payload();
+            let compressed = compression.compress(&payload).unwrap();
+            assert!(compressed.len() < payload.len());
+            let decompressed = compression.decompress(&compressed, 0).unwrap();
+            assert_eq!(decompressed, payload);
+        }
+    }
+
+    #[test]
+    fn should_not_compress_payload_smaller_than_min_size() {
+        let compression = FrameCompression::new(FrameCompressionAlgorithm::Zstd, 20_000);
+        assert!(compression.compress(&payload()).is_none());
+    }
+
+    #[test]
+    fn should_not_compress_when_disabled() {
+        let compression = FrameCompression::new(FrameCompressionAlgorithm::None, 0);
+        assert!(compression.compress(&payload()).is_none());
+    }
+
+    #[test]
+    fn should_not_decompress_payload_exceeding_max_size() {
+        let compression = FrameCompression::new(FrameCompressionAlgorithm::Lz4, 0);
+        let compressed = compression.compress(&payload()).unwrap();
+        assert_eq!(
+            compression.decompress(&compressed, 1000),
+            Err(IggyError::FrameTooLarge(10_000, 1000))
+        );
+    }
+
+    #[test]
+    fn should_not_decompress_invalid_payload() {
+        let compression = FrameCompression::new(FrameCompressionAlgorithm::Zstd, 0);
+        let mut payload = BytesMut::new();
+        payload.put_u32_le(100);
+        payload.put_slice(&[1, 2, 3]);
+        assert_eq!(
+            compression.decompress(&payload, 0),
+            Err(IggyError::InvalidCompressedFrame)
+        );
+    }
+
+    #[test]
+    fn should_be_parsed_from_string() {
+        for algorithm in FrameCompressionAlgorithm::all() {
+            assert_eq!(algorithm.to_string().parse(), Ok(algorithm));
+            assert_eq!(
+                FrameCompressionAlgorithm::from_code(algorithm.as_code()),
+                Ok(algorithm)
+            );
+        }
+    }
+}
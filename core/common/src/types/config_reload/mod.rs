@@ -0,0 +1,38 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use serde::{Deserialize, Serialize};
+
+/// `ConfigReload` is the response to the `ReloadConfig` command, describing the outcome of the reload.
+/// It consists of the following fields:
+/// - `applied`: the paths of the changed settings which have been applied to the running server.
+/// - `restart_required`: the paths of the changed settings which take effect only after a restart.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ConfigReload {
+    /// The paths of the changed settings (e.g. `heartbeat.interval`) applied to the running server.
+    pub applied: Vec<String>,
+    /// The paths of the changed settings which take effect only after a restart.
+    pub restart_required: Vec<String>,
+}
+
+impl ConfigReload {
+    /// Returns `true` if the reloaded configuration is identical to the running one.
+    pub fn is_unchanged(&self) -> bool {
+        self.applied.is_empty() && self.restart_required.is_empty()
+    }
+}
@@ -22,6 +22,27 @@ pub trait ConnectionStringOptions {
 
     fn heartbeat_interval(&self) -> IggyDuration;
 
+    /// Validates the server address, by default expected in the `host:port` format.
+    fn validate_server_address(server_address: &str) -> Result<(), IggyError>
+    where
+        Self: Sized,
+    {
+        if !server_address.contains(':') || server_address.starts_with(':') {
+            return Err(IggyError::InvalidConnectionString);
+        }
+
+        let port = server_address.split(':').collect::<Vec<&str>>()[1];
+        if port.is_empty() {
+            return Err(IggyError::InvalidConnectionString);
+        }
+
+        if port.parse::<u16>().is_err() {
+            return Err(IggyError::InvalidConnectionString);
+        }
+
+        Ok(())
+    }
+
     fn parse_options(options: &str) -> Result<Self, IggyError>
     where
         Self: Sized;
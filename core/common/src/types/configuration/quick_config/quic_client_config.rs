@@ -17,8 +17,8 @@
  */
 
 use crate::{
-    AutoLogin, ConnectionString, ConnectionStringOptions, IggyDuration,
-    QuicClientReconnectionConfig, QuicConnectionStringOptions,
+    AutoLogin, ClientMetadata, ConnectionString, ConnectionStringOptions, FrameCompression,
+    IggyDuration, QuicClientReconnectionConfig, QuicConnectionStringOptions,
 };
 use std::str::FromStr;
 
@@ -55,6 +55,10 @@ pub struct QuicClientConfig {
     pub validate_certificate: bool,
     /// Interval of heartbeats sent by the client
     pub heartbeat_interval: IggyDuration,
+    /// The transport-level compression of the frames, enabled if supported by the server.
+    pub frame_compression: FrameCompression,
+    /// Identification metadata sent to the server after establishing connection.
+    pub client_metadata: ClientMetadata,
 }
 
 impl Default for QuicClientConfig {
@@ -75,6 +79,8 @@ impl Default for QuicClientConfig {
             keep_alive_interval: 5000,
             max_idle_timeout: 10000,
             validate_certificate: false,
+            frame_compression: FrameCompression::default(),
+            client_metadata: ClientMetadata::default(),
         }
     }
 }
@@ -97,6 +103,8 @@ impl From<ConnectionString<QuicConnectionStringOptions>> for QuicClientConfig {
             max_idle_timeout: connection_string.options().max_idle_timeout(),
             validate_certificate: connection_string.options().validate_certificate(),
             heartbeat_interval: connection_string.options().heartbeat_interval(),
+            frame_compression: connection_string.options().frame_compression(),
+            client_metadata: ClientMetadata::default(),
         }
     }
 }
@@ -16,7 +16,9 @@
  * under the License.
  */
 
-use crate::{AutoLogin, IggyDuration, QuicClientConfig};
+use crate::{
+    AutoLogin, FrameCompression, FrameCompressionAlgorithm, IggyDuration, QuicClientConfig,
+};
 
 /// Builder for the QUIC client configuration.
 ///
@@ -35,6 +37,7 @@ use crate::{AutoLogin, IggyDuration, QuicClientConfig};
 /// - `keep_alive_interval`: Default is 5000 milliseconds.
 /// - `max_idle_timeout`: Default is 10,000 milliseconds.
 /// - `validate_certificate`: Default is false (certificate validation is disabled).
+/// - `client_metadata`: Default is empty, the SDK fills in the application name, language, version and hostname.
 #[derive(Debug, Default)]
 pub struct QuicClientConfigBuilder {
     config: QuicClientConfig,
@@ -147,6 +150,28 @@ impl QuicClientConfigBuilder {
         self
     }
 
+    /// Enables the transport-level compression of the frames of at least the given size, if supported by the server.
+    pub fn with_frame_compression(
+        mut self,
+        algorithm: FrameCompressionAlgorithm,
+        min_size: u32,
+    ) -> Self {
+        self.config.frame_compression = FrameCompression::new(algorithm, min_size);
+        self
+    }
+
+    /// Sets the application name sent to the server as part of the client identification metadata.
+    pub fn with_application_name(mut self, application_name: String) -> Self {
+        self.config.client_metadata.application_name = application_name;
+        self
+    }
+
+    /// Adds the label sent to the server as part of the client identification metadata.
+    pub fn with_client_label(mut self, key: String, value: String) -> Self {
+        self.config.client_metadata.labels.insert(key, value);
+        self
+    }
+
     /// Finalizes the builder and returns the `QuicClientConfig`.
     pub fn build(self) -> QuicClientConfig {
         self.config
@@ -16,7 +16,10 @@
 * under the License.
 */
 
-use crate::{ConnectionStringOptions, IggyDuration, IggyError, QuicClientReconnectionConfig};
+use crate::{
+    ConnectionStringOptions, FrameCompression, IggyDuration, IggyError,
+    QuicClientReconnectionConfig,
+};
 use std::str::FromStr;
 
 #[derive(Debug)]
@@ -32,6 +35,7 @@ pub struct QuicConnectionStringOptions {
     max_idle_timeout: u64,
     validate_certificate: bool,
     heartbeat_interval: IggyDuration,
+    frame_compression: FrameCompression,
 }
 
 impl QuicConnectionStringOptions {
@@ -74,6 +78,10 @@ impl QuicConnectionStringOptions {
     pub fn validate_certificate(&self) -> bool {
         self.validate_certificate
     }
+
+    pub fn frame_compression(&self) -> FrameCompression {
+        self.frame_compression
+    }
 }
 
 impl ConnectionStringOptions for QuicConnectionStringOptions {
@@ -97,6 +105,7 @@ impl ConnectionStringOptions for QuicConnectionStringOptions {
         let mut max_idle_timeout = 10000;
         let mut validate_certificate = false;
         let mut heartbeat_interval = "5s".to_owned();
+        let mut frame_compression = FrameCompression::default();
 
         // For reconnection config
         let mut reconnection_max_retries = "unlimited".to_owned();
@@ -188,6 +197,16 @@ impl ConnectionStringOptions for QuicConnectionStringOptions {
                 "reconnection_reestablish_after" => {
                     reconnection_reestablish_after = option_parts[1].to_string();
                 }
+                "frame_compression" => {
+                    frame_compression.algorithm = option_parts[1]
+                        .parse()
+                        .map_err(|_| IggyError::InvalidConnectionString)?;
+                }
+                "frame_compression_min_size" => {
+                    frame_compression.min_size = option_parts[1]
+                        .parse()
+                        .map_err(|_| IggyError::InvalidConnectionString)?;
+                }
                 _ => {
                     return Err(IggyError::InvalidConnectionString);
                 }
@@ -225,6 +244,7 @@ impl ConnectionStringOptions for QuicConnectionStringOptions {
             max_idle_timeout,
             validate_certificate,
             heartbeat_interval,
+            frame_compression,
         );
 
         Ok(connection_string_options)
@@ -245,6 +265,7 @@ impl QuicConnectionStringOptions {
         max_idle_timeout: u64,
         validate_certificate: bool,
         heartbeat_interval: IggyDuration,
+        frame_compression: FrameCompression,
     ) -> Self {
         Self {
             reconnection,
@@ -258,6 +279,7 @@ impl QuicConnectionStringOptions {
             max_idle_timeout,
             validate_certificate,
             heartbeat_interval,
+            frame_compression,
         }
     }
 }
@@ -276,6 +298,7 @@ impl Default for QuicConnectionStringOptions {
             max_idle_timeout: 10000,
             validate_certificate: false,
             heartbeat_interval: IggyDuration::from_str("5s").unwrap(),
+            frame_compression: FrameCompression::default(),
         }
     }
 }
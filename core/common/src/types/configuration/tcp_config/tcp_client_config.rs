@@ -18,7 +18,9 @@
 use crate::types::configuration::auth_config::connection_string::ConnectionString;
 use crate::types::configuration::auth_config::connection_string_options::ConnectionStringOptions;
 use crate::types::configuration::tcp_config::tcp_connection_string_options::TcpConnectionStringOptions;
-use crate::{AutoLogin, IggyDuration, TcpClientReconnectionConfig};
+use crate::{
+    AutoLogin, ClientMetadata, FrameCompression, IggyDuration, TcpClientReconnectionConfig,
+};
 use std::str::FromStr;
 
 /// Configuration for the TCP client.
@@ -42,6 +44,12 @@ pub struct TcpClientConfig {
     pub heartbeat_interval: IggyDuration,
     /// Disable Nagle algorithm for the TCP socket.
     pub nodelay: bool,
+    /// Whether to pipeline the requests of the concurrent callers on a single connection.
+    pub pipelining: bool,
+    /// The transport-level compression of the frames, enabled if supported by the server.
+    pub frame_compression: FrameCompression,
+    /// Identification metadata sent to the server after establishing connection.
+    pub client_metadata: ClientMetadata,
 }
 
 impl Default for TcpClientConfig {
@@ -56,6 +64,9 @@ impl Default for TcpClientConfig {
             auto_login: AutoLogin::Disabled,
             reconnection: TcpClientReconnectionConfig::default(),
             nodelay: false,
+            pipelining: false,
+            frame_compression: FrameCompression::default(),
+            client_metadata: ClientMetadata::default(),
         }
     }
 }
@@ -73,6 +84,9 @@ impl From<ConnectionString<TcpConnectionStringOptions>> for TcpClientConfig {
             reconnection: connection_string.options().reconnection().to_owned(),
             heartbeat_interval: connection_string.options().heartbeat_interval(),
             nodelay: connection_string.options().nodelay(),
+            pipelining: connection_string.options().pipelining(),
+            frame_compression: connection_string.options().frame_compression(),
+            client_metadata: ClientMetadata::default(),
         }
     }
 }
@@ -15,7 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::{AutoLogin, IggyDuration, IggyError, TcpClientConfig};
+use crate::{
+    AutoLogin, FrameCompression, FrameCompressionAlgorithm, IggyDuration, IggyError,
+    TcpClientConfig,
+};
 use std::net::SocketAddr;
 
 /// Builder for the TCP client configuration.
@@ -26,6 +29,7 @@ use std::net::SocketAddr;
 /// - `tls_enabled`: Default is false.
 /// - `tls_domain`: Default is "localhost".
 /// - `tls_ca_file`: Default is None.
+/// - `client_metadata`: Default is empty, the SDK fills in the application name, language, version and hostname.
 #[derive(Debug, Default)]
 pub struct TcpClientConfigBuilder {
     config: TcpClientConfig,
@@ -95,6 +99,34 @@ impl TcpClientConfigBuilder {
         self
     }
 
+    /// Enables pipelining of the requests of the concurrent callers on a single connection.
+    pub fn with_pipelining(mut self) -> Self {
+        self.config.pipelining = true;
+        self
+    }
+
+    /// Enables the transport-level compression of the frames of at least the given size, if supported by the server.
+    pub fn with_frame_compression(
+        mut self,
+        algorithm: FrameCompressionAlgorithm,
+        min_size: u32,
+    ) -> Self {
+        self.config.frame_compression = FrameCompression::new(algorithm, min_size);
+        self
+    }
+
+    /// Sets the application name sent to the server as part of the client identification metadata.
+    pub fn with_application_name(mut self, application_name: String) -> Self {
+        self.config.client_metadata.application_name = application_name;
+        self
+    }
+
+    /// Adds the label sent to the server as part of the client identification metadata.
+    pub fn with_client_label(mut self, key: String, value: String) -> Self {
+        self.config.client_metadata.labels.insert(key, value);
+        self
+    }
+
     /// Builds the TCP client configuration.
     pub fn build(self) -> Result<TcpClientConfig, IggyError> {
         let addr = self.config.server_address.trim();
@@ -16,7 +16,9 @@
 * under the License.
 */
 
-use crate::{ConnectionStringOptions, IggyDuration, IggyError, TcpClientReconnectionConfig};
+use crate::{
+    ConnectionStringOptions, FrameCompression, IggyDuration, IggyError, TcpClientReconnectionConfig,
+};
 use std::str::FromStr;
 
 #[derive(Debug)]
@@ -27,6 +29,8 @@ pub struct TcpConnectionStringOptions {
     reconnection: TcpClientReconnectionConfig,
     heartbeat_interval: IggyDuration,
     nodelay: bool,
+    pipelining: bool,
+    frame_compression: FrameCompression,
 }
 
 impl TcpConnectionStringOptions {
@@ -49,6 +53,14 @@ impl TcpConnectionStringOptions {
     pub fn nodelay(&self) -> bool {
         self.nodelay
     }
+
+    pub fn pipelining(&self) -> bool {
+        self.pipelining
+    }
+
+    pub fn frame_compression(&self) -> FrameCompression {
+        self.frame_compression
+    }
 }
 
 impl ConnectionStringOptions for TcpConnectionStringOptions {
@@ -70,6 +82,8 @@ impl ConnectionStringOptions for TcpConnectionStringOptions {
         let mut reestablish_after = "5s".to_owned();
         let mut heartbeat_interval = "5s".to_owned();
         let mut nodelay = false;
+        let mut pipelining = false;
+        let mut frame_compression = FrameCompression::default();
 
         for option in options {
             let option_parts = option.split('=').collect::<Vec<&str>>();
@@ -101,6 +115,19 @@ impl ConnectionStringOptions for TcpConnectionStringOptions {
                 "nodelay" => {
                     nodelay = option_parts[1] == "true";
                 }
+                "pipelining" => {
+                    pipelining = option_parts[1] == "true";
+                }
+                "frame_compression" => {
+                    frame_compression.algorithm = option_parts[1]
+                        .parse()
+                        .map_err(|_| IggyError::InvalidConnectionString)?;
+                }
+                "frame_compression_min_size" => {
+                    frame_compression.min_size = option_parts[1]
+                        .parse()
+                        .map_err(|_| IggyError::InvalidConnectionString)?;
+                }
                 _ => {
                     return Err(IggyError::InvalidConnectionString);
                 }
@@ -133,6 +160,8 @@ impl ConnectionStringOptions for TcpConnectionStringOptions {
             reconnection,
             heartbeat_interval,
             nodelay,
+            pipelining,
+            frame_compression,
         );
 
         Ok(connection_string_options)
@@ -140,6 +169,7 @@ impl ConnectionStringOptions for TcpConnectionStringOptions {
 }
 
 impl TcpConnectionStringOptions {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         tls_enabled: bool,
         tls_domain: String,
@@ -147,6 +177,8 @@ impl TcpConnectionStringOptions {
         reconnection: TcpClientReconnectionConfig,
         heartbeat_interval: IggyDuration,
         nodelay: bool,
+        pipelining: bool,
+        frame_compression: FrameCompression,
     ) -> Self {
         Self {
             tls_enabled,
@@ -155,6 +187,8 @@ impl TcpConnectionStringOptions {
             reconnection,
             heartbeat_interval,
             nodelay,
+            pipelining,
+            frame_compression,
         }
     }
 }
@@ -168,6 +202,8 @@ impl Default for TcpConnectionStringOptions {
             reconnection: Default::default(),
             heartbeat_interval: IggyDuration::from_str("5s").unwrap(),
             nodelay: false,
+            pipelining: false,
+            frame_compression: FrameCompression::default(),
         }
     }
 }
@@ -0,0 +1,75 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::types::configuration::auth_config::connection_string::ConnectionString;
+use crate::types::configuration::auth_config::connection_string_options::ConnectionStringOptions;
+use crate::types::configuration::unix_config::unix_connection_string_options::UnixConnectionStringOptions;
+use crate::{AutoLogin, ClientMetadata, IggyDuration, TcpClientReconnectionConfig};
+use std::str::FromStr;
+
+/// The default path of the Unix domain socket of the Iggy server.
+pub const DEFAULT_UNIX_SOCKET_PATH: &str = "/tmp/iggy.sock";
+
+/// Configuration for the Unix domain socket client.
+/// It uses the same binary protocol (and framing) as the TCP client, so the reconnection settings are shared.
+#[derive(Debug, Clone)]
+pub struct UnixClientConfig {
+    /// The path to the Unix domain socket of the Iggy server.
+    pub socket_path: String,
+    /// Whether to automatically login user after establishing connection.
+    pub auto_login: AutoLogin,
+    /// Whether to automatically reconnect when disconnected.
+    pub reconnection: TcpClientReconnectionConfig,
+    /// Interval of heartbeats sent by the client
+    pub heartbeat_interval: IggyDuration,
+    /// Whether to pipeline the requests of the concurrent callers on a single connection.
+    pub pipelining: bool,
+    /// Whether the server signs in the client based on the peer credentials (UID) of the connection.
+    /// If enabled and no auto login is configured, the client verifies the sign-in after connecting.
+    pub peer_credentials: bool,
+    /// Identification metadata sent to the server after establishing connection.
+    pub client_metadata: ClientMetadata,
+}
+
+impl Default for UnixClientConfig {
+    fn default() -> UnixClientConfig {
+        UnixClientConfig {
+            socket_path: DEFAULT_UNIX_SOCKET_PATH.to_string(),
+            auto_login: AutoLogin::Disabled,
+            reconnection: TcpClientReconnectionConfig::default(),
+            heartbeat_interval: IggyDuration::from_str("5s").unwrap(),
+            pipelining: false,
+            peer_credentials: false,
+            client_metadata: ClientMetadata::default(),
+        }
+    }
+}
+
+impl From<ConnectionString<UnixConnectionStringOptions>> for UnixClientConfig {
+    fn from(connection_string: ConnectionString<UnixConnectionStringOptions>) -> Self {
+        UnixClientConfig {
+            socket_path: connection_string.server_address().into(),
+            auto_login: connection_string.auto_login().to_owned(),
+            reconnection: connection_string.options().reconnection().to_owned(),
+            heartbeat_interval: connection_string.options().heartbeat_interval(),
+            pipelining: connection_string.options().pipelining(),
+            peer_credentials: connection_string.options().peer_credentials(),
+            client_metadata: ClientMetadata::default(),
+        }
+    }
+}
@@ -0,0 +1,120 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::{AutoLogin, IggyDuration, IggyError, UnixClientConfig};
+
+/// Builder for the Unix domain socket client configuration.
+/// Allows configuring the Unix client with custom settings or using defaults:
+/// - `socket_path`: Default is "/tmp/iggy.sock"
+/// - `auto_login`: Default is AutoLogin::Disabled.
+/// - `reconnection`: Default is enabled unlimited retries and 1 second interval.
+/// - `client_metadata`: Default is empty, the SDK fills in the application name, language, version and hostname.
+#[derive(Debug, Default)]
+pub struct UnixClientConfigBuilder {
+    config: UnixClientConfig,
+}
+
+impl UnixClientConfigBuilder {
+    pub fn new() -> Self {
+        UnixClientConfigBuilder::default()
+    }
+
+    /// Sets the path to the Unix domain socket of the server.
+    pub fn with_socket_path(mut self, socket_path: String) -> Self {
+        self.config.socket_path = socket_path;
+        self
+    }
+
+    /// Sets the auto sign in during connection.
+    pub fn with_auto_sign_in(mut self, auto_sign_in: AutoLogin) -> Self {
+        self.config.auto_login = auto_sign_in;
+        self
+    }
+
+    pub fn with_enabled_reconnection(mut self) -> Self {
+        self.config.reconnection.enabled = true;
+        self
+    }
+
+    /// Sets the number of retries when connecting to the server.
+    pub fn with_reconnection_max_retries(mut self, max_retries: Option<u32>) -> Self {
+        self.config.reconnection.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the interval between retries when connecting to the server.
+    pub fn with_reconnection_interval(mut self, interval: IggyDuration) -> Self {
+        self.config.reconnection.interval = interval;
+        self
+    }
+
+    /// Enables pipelining of the requests of the concurrent callers on a single connection.
+    pub fn with_pipelining(mut self) -> Self {
+        self.config.pipelining = true;
+        self
+    }
+
+    /// Expects the server to sign in the client based on the peer credentials of the connection.
+    pub fn with_peer_credentials(mut self) -> Self {
+        self.config.peer_credentials = true;
+        self
+    }
+
+    /// Sets the application name sent to the server as part of the client identification metadata.
+    pub fn with_application_name(mut self, application_name: String) -> Self {
+        self.config.client_metadata.application_name = application_name;
+        self
+    }
+
+    /// Adds the label sent to the server as part of the client identification metadata.
+    pub fn with_client_label(mut self, key: String, value: String) -> Self {
+        self.config.client_metadata.labels.insert(key, value);
+        self
+    }
+
+    /// Builds the Unix client configuration.
+    pub fn build(self) -> Result<UnixClientConfig, IggyError> {
+        if self.config.socket_path.trim().is_empty() {
+            return Err(IggyError::InvalidServerAddress);
+        }
+
+        Ok(self.config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_socket_path_should_succeed() {
+        let config = UnixClientConfigBuilder::new()
+            .with_socket_path("/var/run/iggy.sock".to_string())
+            .build()
+            .unwrap();
+        assert_eq!(config.socket_path, "/var/run/iggy.sock");
+    }
+
+    #[test]
+    fn empty_socket_path_should_fail() {
+        let result = UnixClientConfigBuilder::new()
+            .with_socket_path(" ".to_string())
+            .build();
+        assert!(matches!(result, Err(IggyError::InvalidServerAddress)));
+    }
+}
@@ -0,0 +1,92 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::types::configuration::auth_config::connection_string::ConnectionString;
+use crate::types::configuration::auth_config::connection_string_options::ConnectionStringOptions;
+use crate::types::configuration::websocket_config::websocket_connection_string_options::WebSocketConnectionStringOptions;
+use crate::{AutoLogin, ClientMetadata, IggyDuration, TcpClientReconnectionConfig};
+use std::str::FromStr;
+
+/// The default path of the WebSocket endpoint served by the HTTP API of the Iggy server.
+pub const DEFAULT_WEBSOCKET_PATH: &str = "/ws";
+
+/// Configuration for the WebSocket client.
+/// It carries the same binary protocol (and framing) as the TCP client, so the reconnection settings are shared.
+#[derive(Debug, Clone)]
+pub struct WebSocketClientConfig {
+    /// The address of the Iggy HTTP API.
+    pub server_address: String,
+    /// The path of the WebSocket endpoint.
+    pub path: String,
+    /// Whether to use TLS (`wss://`) when connecting to the server.
+    pub tls_enabled: bool,
+    /// The domain to use for TLS when connecting to the server.
+    pub tls_domain: String,
+    /// The path to the CA file for TLS.
+    pub tls_ca_file: Option<String>,
+    /// Whether to validate the TLS certificate.
+    pub tls_validate_certificate: bool,
+    /// Whether to automatically login user after establishing connection.
+    pub auto_login: AutoLogin,
+    /// Whether to automatically reconnect when disconnected.
+    pub reconnection: TcpClientReconnectionConfig,
+    /// Interval of heartbeats sent by the client
+    pub heartbeat_interval: IggyDuration,
+    /// Whether to pipeline the requests of the concurrent callers on a single connection.
+    pub pipelining: bool,
+    /// Identification metadata sent to the server after establishing connection.
+    pub client_metadata: ClientMetadata,
+}
+
+impl Default for WebSocketClientConfig {
+    fn default() -> WebSocketClientConfig {
+        WebSocketClientConfig {
+            server_address: "127.0.0.1:3000".to_string(),
+            path: DEFAULT_WEBSOCKET_PATH.to_string(),
+            tls_enabled: false,
+            tls_domain: "localhost".to_string(),
+            tls_ca_file: None,
+            tls_validate_certificate: true,
+            auto_login: AutoLogin::Disabled,
+            reconnection: TcpClientReconnectionConfig::default(),
+            heartbeat_interval: IggyDuration::from_str("5s").unwrap(),
+            pipelining: false,
+            client_metadata: ClientMetadata::default(),
+        }
+    }
+}
+
+impl From<ConnectionString<WebSocketConnectionStringOptions>> for WebSocketClientConfig {
+    fn from(connection_string: ConnectionString<WebSocketConnectionStringOptions>) -> Self {
+        WebSocketClientConfig {
+            server_address: connection_string.server_address().into(),
+            path: connection_string.options().path().into(),
+            // TLS is enabled by the client for the `wss` transport protocol.
+            tls_enabled: false,
+            tls_domain: connection_string.options().tls_domain().into(),
+            tls_ca_file: connection_string.options().tls_ca_file().to_owned(),
+            // Always validate TLS certificate for connection strings, we don't want to allow self-signed certificates for connection strings
+            tls_validate_certificate: true,
+            auto_login: connection_string.auto_login().to_owned(),
+            reconnection: connection_string.options().reconnection().to_owned(),
+            heartbeat_interval: connection_string.options().heartbeat_interval(),
+            pipelining: connection_string.options().pipelining(),
+            client_metadata: ClientMetadata::default(),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::{AutoLogin, IggyDuration, IggyError, WebSocketClientConfig};
+use std::net::SocketAddr;
+
+/// Builder for the WebSocket client configuration.
+/// Allows configuring the WebSocket client with custom settings or using defaults:
+/// - `server_address`: Default is "127.0.0.1:3000"
+/// - `path`: Default is "/ws"
+/// - `auto_login`: Default is AutoLogin::Disabled.
+/// - `reconnection`: Default is enabled unlimited retries and 1 second interval.
+/// - `tls_enabled`: Default is false.
+/// - `tls_domain`: Default is "localhost".
+/// - `tls_ca_file`: Default is None.
+/// - `client_metadata`: Default is empty, the SDK fills in the application name, language, version and hostname.
+#[derive(Debug, Default)]
+pub struct WebSocketClientConfigBuilder {
+    config: WebSocketClientConfig,
+}
+
+impl WebSocketClientConfigBuilder {
+    pub fn new() -> Self {
+        WebSocketClientConfigBuilder::default()
+    }
+
+    /// Sets the server address for the WebSocket client.
+    pub fn with_server_address(mut self, server_address: String) -> Self {
+        self.config.server_address = server_address;
+        self
+    }
+
+    /// Sets the path of the WebSocket endpoint.
+    pub fn with_path(mut self, path: String) -> Self {
+        self.config.path = path;
+        self
+    }
+
+    /// Sets the auto sign in during connection.
+    pub fn with_auto_sign_in(mut self, auto_sign_in: AutoLogin) -> Self {
+        self.config.auto_login = auto_sign_in;
+        self
+    }
+
+    pub fn with_enabled_reconnection(mut self) -> Self {
+        self.config.reconnection.enabled = true;
+        self
+    }
+
+    /// Sets the number of retries when connecting to the server.
+    pub fn with_reconnection_max_retries(mut self, max_retries: Option<u32>) -> Self {
+        self.config.reconnection.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the interval between retries when connecting to the server.
+    pub fn with_reconnection_interval(mut self, interval: IggyDuration) -> Self {
+        self.config.reconnection.interval = interval;
+        self
+    }
+
+    /// Sets whether to use TLS (`wss://`) when connecting to the server.
+    pub fn with_tls_enabled(mut self, tls_enabled: bool) -> Self {
+        self.config.tls_enabled = tls_enabled;
+        self
+    }
+
+    /// Sets the domain to use for TLS when connecting to the server.
+    pub fn with_tls_domain(mut self, tls_domain: String) -> Self {
+        self.config.tls_domain = tls_domain;
+        self
+    }
+
+    /// Sets the path to the CA file for TLS.
+    pub fn with_tls_ca_file(mut self, tls_ca_file: String) -> Self {
+        self.config.tls_ca_file = Some(tls_ca_file);
+        self
+    }
+
+    /// Sets whether to validate the TLS certificate.
+    pub fn with_tls_validate_certificate(mut self, tls_validate_certificate: bool) -> Self {
+        self.config.tls_validate_certificate = tls_validate_certificate;
+        self
+    }
+
+    /// Enables pipelining of the requests of the concurrent callers on a single connection.
+    pub fn with_pipelining(mut self) -> Self {
+        self.config.pipelining = true;
+        self
+    }
+
+    /// Sets the application name sent to the server as part of the client identification metadata.
+    pub fn with_application_name(mut self, application_name: String) -> Self {
+        self.config.client_metadata.application_name = application_name;
+        self
+    }
+
+    /// Adds the label sent to the server as part of the client identification metadata.
+    pub fn with_client_label(mut self, key: String, value: String) -> Self {
+        self.config.client_metadata.labels.insert(key, value);
+        self
+    }
+
+    /// Builds the WebSocket client configuration.
+    pub fn build(self) -> Result<WebSocketClientConfig, IggyError> {
+        let addr = self.config.server_address.trim();
+
+        // Check if the address is empty or missing a port
+        if addr.is_empty() || !addr.contains(':') {
+            return Err(IggyError::InvalidServerAddress);
+        }
+
+        // Check if the address is a valid IP address or a domain name with a port
+        if addr.parse::<SocketAddr>().is_err() {
+            let (domain, port) = addr.rsplit_once(':').unwrap();
+            if domain.is_empty() || port.parse::<u16>().is_err() {
+                return Err(IggyError::InvalidServerAddress);
+            }
+        }
+
+        if !self.config.path.starts_with('/') {
+            return Err(IggyError::InvalidServerAddress);
+        }
+
+        Ok(self.config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_address_and_path_should_succeed() {
+        let config = WebSocketClientConfigBuilder::new()
+            .with_server_address("localhost:3000".to_string())
+            .with_path("/iggy/ws".to_string())
+            .build()
+            .unwrap();
+        assert_eq!(config.server_address, "localhost:3000");
+        assert_eq!(config.path, "/iggy/ws");
+    }
+
+    #[test]
+    fn relative_path_should_fail() {
+        let result = WebSocketClientConfigBuilder::new()
+            .with_path("ws".to_string())
+            .build();
+        assert!(matches!(result, Err(IggyError::InvalidServerAddress)));
+    }
+}
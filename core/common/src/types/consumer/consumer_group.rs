@@ -25,6 +25,7 @@ use serde::{Deserialize, Serialize};
 /// - `partitions_count`: the number of partitions the consumer group is consuming.
 /// - `members_count`: the number of members in the consumer group.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ConsumerGroup {
     /// The unique identifier (numeric) of the consumer group.
     pub id: u32,
@@ -43,6 +44,7 @@ pub struct ConsumerGroup {
 /// - `partitions_count`: the number of partitions the consumer group is consuming.
 /// - `members_count`: the number of members in the consumer group.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ConsumerGroupDetails {
     /// The unique identifier (numeric) of the consumer group.
     pub id: u32,
@@ -62,6 +64,7 @@ pub struct ConsumerGroupDetails {
 /// - `partitions_count`: the number of partitions the consumer group member is consuming.
 /// - `partitions`: the collection of partitions the consumer group member is consuming.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ConsumerGroupMember {
     /// The unique identifier (numeric) of the consumer group member.
     pub id: u32,
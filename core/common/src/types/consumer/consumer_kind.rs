@@ -32,6 +32,7 @@ use std::fmt::Display;
 /// - `id`: the unique identifier of the consumer.
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Consumer {
     /// The type of consumer. It can be either `Consumer` or `ConsumerGroup`.
     #[serde(skip)]
@@ -39,11 +40,13 @@ pub struct Consumer {
     /// The unique identifier of the consumer.
     #[serde_as(as = "DisplayFromStr")]
     #[serde(default = "default_id")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub id: Identifier,
 }
 
 /// `ConsumerKind` is an enum that represents the type of consumer.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ConsumerKind {
     /// `Consumer` represents a regular consumer.
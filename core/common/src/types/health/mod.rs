@@ -0,0 +1,201 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// `Health` is the response to the `GetHealth` command, describing whether the server is alive
+/// or ready to serve the traffic.
+/// It consists of the following fields:
+/// - `status`: `up` if all the checks passed, `down` otherwise.
+/// - `checks`: the results of the component checks, empty for the liveness probe.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Health {
+    /// The overall status of the server.
+    pub status: HealthStatus,
+    /// The results of the component checks.
+    pub checks: Vec<HealthCheck>,
+}
+
+/// The result of a single component check, e.g. of the free disk space.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct HealthCheck {
+    /// The name of the checked component.
+    pub name: String,
+    /// The status of the checked component.
+    pub status: HealthStatus,
+    /// The human-readable details, e.g. the loading progress.
+    pub details: String,
+}
+
+/// The status of the server or of a single component.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    /// The server or the component is healthy.
+    Up,
+    /// The server or the component is unhealthy or not yet initialized.
+    Down,
+}
+
+/// The kind of the health probe.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum HealthProbe {
+    /// Whether the server process is running and responsive.
+    #[default]
+    Liveness,
+    /// Whether the server has loaded its data and can serve the traffic.
+    Readiness,
+}
+
+impl Health {
+    /// Creates the health from the component checks, `up` only if all the checks are `up`.
+    pub fn from_checks(checks: Vec<HealthCheck>) -> Self {
+        let status = match checks.iter().all(|check| check.status == HealthStatus::Up) {
+            true => HealthStatus::Up,
+            false => HealthStatus::Down,
+        };
+        Self { status, checks }
+    }
+
+    /// Returns `true` if the server is healthy.
+    pub fn is_up(&self) -> bool {
+        self.status == HealthStatus::Up
+    }
+}
+
+impl HealthCheck {
+    pub fn new(name: &str, status: HealthStatus, details: String) -> Self {
+        Self {
+            name: name.to_owned(),
+            status,
+            details,
+        }
+    }
+
+    pub fn up(name: &str, details: String) -> Self {
+        Self::new(name, HealthStatus::Up, details)
+    }
+
+    pub fn down(name: &str, details: String) -> Self {
+        Self::new(name, HealthStatus::Down, details)
+    }
+}
+
+impl HealthStatus {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            HealthStatus::Up => 1,
+            HealthStatus::Down => 2,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(HealthStatus::Up),
+            2 => Ok(HealthStatus::Down),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HealthStatus::Up => write!(f, "up"),
+            HealthStatus::Down => write!(f, "down"),
+        }
+    }
+}
+
+impl HealthProbe {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            HealthProbe::Liveness => 1,
+            HealthProbe::Readiness => 2,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(HealthProbe::Liveness),
+            2 => Ok(HealthProbe::Readiness),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for HealthProbe {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "liveness" | "live" => Ok(HealthProbe::Liveness),
+            "readiness" | "ready" => Ok(HealthProbe::Readiness),
+            _ => Err(format!("Invalid health probe: {s}")),
+        }
+    }
+}
+
+impl fmt::Display for HealthProbe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HealthProbe::Liveness => write!(f, "liveness"),
+            HealthProbe::Readiness => write!(f, "readiness"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn health_should_be_up_only_if_all_checks_are_up() {
+        let health = Health::from_checks(vec![
+            HealthCheck::up("state", "loaded".to_owned()),
+            HealthCheck::up("disk", "enough space".to_owned()),
+        ]);
+        assert!(health.is_up());
+
+        let health = Health::from_checks(vec![
+            HealthCheck::up("state", "loaded".to_owned()),
+            HealthCheck::down("partitions", "loaded 1/2".to_owned()),
+        ]);
+        assert!(!health.is_up());
+    }
+
+    #[test]
+    fn health_should_be_serialized_as_json() {
+        let health = Health::from_checks(vec![HealthCheck::down(
+            "partitions",
+            "loaded 1/2".to_owned(),
+        )]);
+        let json = serde_json::to_string(&health).unwrap();
+        assert_eq!(
+            json,
+            r#"{"status":"down","checks":[{"name":"partitions","status":"down","details":"loaded 1/2"}]}"#
+        );
+    }
+}
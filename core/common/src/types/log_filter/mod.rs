@@ -0,0 +1,44 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+/// `LogFilter` is the response to the `GetLogFilter` and `SetLogFilter` commands,
+/// describing the tracing filter of the running server.
+/// It consists of the following fields:
+/// - `filter`: the active filter directives, e.g. `info,server::streaming=debug`.
+/// - `default_filter`: the filter applied at the startup, restored on reset or once the TTL elapses.
+/// - `expires_at`: the time when the active filter is reverted, if it has been set with a TTL.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct LogFilter {
+    /// The active filter directives.
+    pub filter: String,
+    /// The filter applied at the startup, based on `RUST_LOG` and the logging config.
+    pub default_filter: String,
+    /// The time when the active filter is reverted to the default one.
+    pub expires_at: Option<IggyTimestamp>,
+}
+
+impl LogFilter {
+    /// Returns `true` if the filter has been changed at runtime.
+    pub fn is_overridden(&self) -> bool {
+        self.filter != self.default_filter
+    }
+}
@@ -23,7 +23,7 @@ use crate::Sizeable;
 use crate::error::IggyError;
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::timestamp::IggyTimestamp;
-use crate::{HeaderKey, HeaderValue};
+use crate::{HeaderKey, HeaderValue, TRACEPARENT_HEADER, TraceContext};
 use bon::bon;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -353,6 +353,53 @@ impl IggyMessage {
     pub fn payload_as_string(&self) -> Result<String, IggyError> {
         String::from_utf8(self.payload.to_vec()).map_err(|_| IggyError::InvalidUtf8)
     }
+
+    /// Gets the W3C trace context propagated in the `traceparent` user header, if any.
+    ///
+    /// A missing or malformed `traceparent` value results in `None`.
+    pub fn trace_context(&self) -> Option<TraceContext> {
+        let key = HeaderKey::new(TRACEPARENT_HEADER).ok()?;
+        let value = self.get_user_header(&key).ok()??;
+        value.as_str().ok()?.parse().ok()
+    }
+
+    /// Sets the `traceparent` user header to the provided W3C trace context,
+    /// replacing the existing one and keeping the other user headers intact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iggy_common::*;
+    /// use std::str::FromStr;
+    ///
+    /// let context =
+    ///     TraceContext::from_str("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01").unwrap();
+    /// let mut message = IggyMessage::from_str("Hello").unwrap();
+    /// message.set_trace_context(&context).unwrap();
+    ///
+    /// assert_eq!(message.trace_context(), Some(context));
+    /// ```
+    pub fn set_trace_context(&mut self, context: &TraceContext) -> Result<(), IggyError> {
+        let mut user_headers = match &self.user_headers {
+            Some(user_headers) => {
+                HashMap::<HeaderKey, HeaderValue>::from_bytes(user_headers.clone())?
+            }
+            None => HashMap::new(),
+        };
+        user_headers.insert(
+            HeaderKey::new(TRACEPARENT_HEADER)?,
+            HeaderValue::from_str(&context.to_string())?,
+        );
+
+        let user_headers = user_headers.to_bytes();
+        if user_headers.len() as u32 > MAX_USER_HEADERS_SIZE {
+            return Err(IggyError::TooBigUserHeaders);
+        }
+
+        self.header.user_headers_length = user_headers.len() as u32;
+        self.user_headers = Some(user_headers);
+        Ok(())
+    }
 }
 
 impl FromStr for IggyMessage {
@@ -536,6 +583,21 @@ impl TryFrom<Bytes> for IggyMessage {
     }
 }
 
+/// The serialized form of `IggyMessage`, used only to derive its OpenAPI schema.
+#[cfg(feature = "openapi")]
+#[derive(utoipa::ToSchema)]
+#[allow(dead_code)]
+struct IggyMessageSchema {
+    header: IggyMessageHeader,
+    /// The base64-encoded payload.
+    #[schema(format = Byte)]
+    payload: String,
+    user_headers: Option<HashMap<HeaderKey, HeaderValue>>,
+}
+
+#[cfg(feature = "openapi")]
+crate::utils::openapi::impl_to_schema!(IggyMessage, as IggyMessageSchema);
+
 impl Serialize for IggyMessage {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -23,7 +23,7 @@ use crate::IggyByteSize;
 use crate::Sizeable;
 use crate::error::IggyError;
 use crate::utils::checksum;
-use crate::{HeaderKey, IggyMessageHeaderView};
+use crate::{HeaderKey, IggyMessageHeaderView, TRACEPARENT_HEADER, TraceContext};
 use bytes::{Bytes, BytesMut};
 use std::{collections::HashMap, iter::Iterator};
 
@@ -98,6 +98,13 @@ impl<'a> IggyMessageView<'a> {
         }
     }
 
+    /// Return the W3C trace context propagated in the `traceparent` user header, if any.
+    pub fn trace_context(&self) -> Option<TraceContext> {
+        let key = HeaderKey::new(TRACEPARENT_HEADER).ok()?;
+        let headers = self.user_headers_map().ok()??;
+        headers.get(&key)?.as_str().ok()?.parse().ok()
+    }
+
     /// Returns the size of the entire message.
     pub fn size(&self) -> usize {
         let header_view = self.header();
@@ -29,6 +29,7 @@ pub mod partitioning_kind;
 pub mod polled_messages;
 pub mod polling_kind;
 pub mod polling_strategy;
+mod trace_context;
 mod user_headers;
 
 pub const INDEX_SIZE: usize = 16;
@@ -55,4 +56,5 @@ pub use partitioning_kind::PartitioningKind;
 pub use polled_messages::PolledMessages;
 pub use polling_kind::PollingKind;
 pub use polling_strategy::PollingStrategy;
+pub use trace_context::{TRACEPARENT_HEADER, TraceContext};
 pub use user_headers::{HeaderKey, HeaderKind, HeaderValue};
@@ -34,6 +34,7 @@ use std::{
 /// - `MessagesKey` - the partition ID is calculated by the server using the hash of the provided messages key.
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Partitioning {
     /// The kind of partitioning.
     pub kind: PartitioningKind,
@@ -41,6 +42,7 @@ pub struct Partitioning {
     /// The length of the value payload.
     pub length: u8,
     #[serde_as(as = "Base64")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String, format = Byte))]
     /// The binary value payload.
     pub value: Vec<u8>,
 }
@@ -25,6 +25,7 @@ use std::{
 
 /// `PartitioningKind` is an enum which specifies the kind of partitioning and is used by `Partitioning`.
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Default, Copy, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum PartitioningKind {
     /// The partition ID is calculated by the server using the round-robin algorithm.
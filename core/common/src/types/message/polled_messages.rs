@@ -17,7 +17,8 @@
  */
 
 use crate::{
-    BytesSerializable, IGGY_MESSAGE_HEADER_SIZE, IggyMessage, IggyMessageHeader, error::IggyError,
+    BytesSerializable, IGGY_MESSAGE_HEADER_SIZE, IggyDuration, IggyMessage, IggyMessageHeader,
+    error::IggyError,
 };
 use bytes::Bytes;
 use serde::{Deserialize, Serialize};
@@ -29,7 +30,9 @@ use tracing::error;
 /// - `current_offset`: the current offset of the partition.
 /// - `count`: the count of messages.
 /// - `messages`: the collection of messages.
+/// - `throttle_time`: the time for which the response has been throttled due to the exceeded quota, if any.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct PolledMessages {
     /// The identifier of the partition. If it's '0', then there's no partition assigned to the consumer group member.
     pub partition_id: u32,
@@ -39,6 +42,10 @@ pub struct PolledMessages {
     pub count: u32,
     /// The collection of messages.
     pub messages: Vec<IggyMessage>,
+    /// The time for which the response has been throttled by the server due to the exceeded quota, if any.
+    /// It's appended to the binary response after the messages and sent as the header over HTTP.
+    #[serde(skip)]
+    pub throttle_time: Option<IggyDuration>,
 }
 
 impl PolledMessages {
@@ -48,6 +55,7 @@ impl PolledMessages {
             current_offset: 0,
             count: 0,
             messages: Vec::new(),
+            throttle_time: None,
         }
     }
 }
@@ -74,19 +82,34 @@ impl BytesSerializable for PolledMessages {
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         );
 
-        let messages = messages_from_bytes_and_count(bytes.slice(16..), count)?;
+        let (messages, position) = messages_from_bytes_and_count(bytes.slice(16..), count)?;
+        // The server appends the throttle time (in microseconds) if the quota has been exceeded.
+        let throttle_time = match bytes.get(16 + position..) {
+            Some(throttle_time) if throttle_time.len() == 8 => {
+                Some(IggyDuration::from(u64::from_le_bytes(
+                    throttle_time
+                        .try_into()
+                        .map_err(|_| IggyError::InvalidNumberEncoding)?,
+                )))
+            }
+            _ => None,
+        };
 
         Ok(Self {
             partition_id,
             current_offset,
             count,
             messages,
+            throttle_time,
         })
     }
 }
 
-/// Convert Bytes to messages
-fn messages_from_bytes_and_count(buffer: Bytes, count: u32) -> Result<Vec<IggyMessage>, IggyError> {
+/// Convert Bytes to messages, along with the position of the first byte following them.
+fn messages_from_bytes_and_count(
+    buffer: Bytes,
+    count: u32,
+) -> Result<(Vec<IggyMessage>, usize), IggyError> {
     let mut messages = Vec::with_capacity(count as usize);
     let mut position = 0;
     let buf_len = buffer.len();
@@ -125,5 +148,5 @@ fn messages_from_bytes_and_count(buffer: Bytes, count: u32) -> Result<Vec<IggyMe
         });
     }
 
-    Ok(messages)
+    Ok((messages, position))
 }
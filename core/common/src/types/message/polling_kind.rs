@@ -23,6 +23,7 @@ use std::str::FromStr;
 
 /// `PollingKind` is an enum which specifies from where to start polling messages and is used by `PollingStrategy`.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum PollingKind {
     #[default]
@@ -0,0 +1,152 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::iggy_error::IggyError;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The user header key under which the W3C `traceparent` value is propagated.
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+const VERSION: &str = "00";
+const SAMPLED_FLAG: u8 = 0x01;
+
+/// W3C Trace Context (`traceparent`) carried in the message user headers.
+///
+/// It's injected by the producer, linked by the server to its send/poll spans
+/// and exposed to the consumer, so that a single trace spans the whole message flow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TraceContext {
+    pub trace_id: [u8; 16],
+    pub span_id: [u8; 8],
+    pub flags: u8,
+}
+
+impl TraceContext {
+    pub fn new(trace_id: [u8; 16], span_id: [u8; 8], flags: u8) -> Self {
+        Self {
+            trace_id,
+            span_id,
+            flags,
+        }
+    }
+
+    /// Returns `true` if neither the trace ID nor the span ID is all zeros.
+    pub fn is_valid(&self) -> bool {
+        self.trace_id != [0; 16] && self.span_id != [0; 8]
+    }
+
+    pub fn is_sampled(&self) -> bool {
+        self.flags & SAMPLED_FLAG == SAMPLED_FLAG
+    }
+}
+
+impl FromStr for TraceContext {
+    type Err = IggyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().split('-');
+        let (Some(version), Some(trace_id), Some(span_id), Some(flags)) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(IggyError::InvalidFormat);
+        };
+
+        // Future versions may append fields, but version "ff" is forbidden.
+        if version.len() != 2 || version == "ff" || (version == VERSION && parts.next().is_some()) {
+            return Err(IggyError::InvalidFormat);
+        }
+
+        let mut context = TraceContext::new([0; 16], [0; 8], 0);
+        decode_hex(trace_id, &mut context.trace_id)?;
+        decode_hex(span_id, &mut context.span_id)?;
+        let mut flags_byte = [0; 1];
+        decode_hex(flags, &mut flags_byte)?;
+        context.flags = flags_byte[0];
+
+        if !context.is_valid() {
+            return Err(IggyError::InvalidFormat);
+        }
+
+        Ok(context)
+    }
+}
+
+impl Display for TraceContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{VERSION}-")?;
+        for byte in self.trace_id {
+            write!(f, "{byte:02x}")?;
+        }
+        write!(f, "-")?;
+        for byte in self.span_id {
+            write!(f, "{byte:02x}")?;
+        }
+        write!(f, "-{:02x}", self.flags)
+    }
+}
+
+fn decode_hex(value: &str, output: &mut [u8]) -> Result<(), IggyError> {
+    if !value.is_ascii() || value.len() != output.len() * 2 {
+        return Err(IggyError::InvalidFormat);
+    }
+
+    for (index, byte) in output.iter_mut().enumerate() {
+        let digits = &value[index * 2..index * 2 + 2];
+        if !digits
+            .bytes()
+            .all(|digit| matches!(digit, b'0'..=b'9' | b'a'..=b'f'))
+        {
+            return Err(IggyError::InvalidFormat);
+        }
+        *byte = u8::from_str_radix(digits, 16).map_err(|_| IggyError::InvalidFormat)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[test]
+    fn should_parse_and_format_traceparent() {
+        let context = TraceContext::from_str(TRACEPARENT).unwrap();
+        assert_eq!(context.trace_id[0], 0x4b);
+        assert_eq!(context.span_id[7], 0xb7);
+        assert!(context.is_sampled());
+        assert_eq!(context.to_string(), TRACEPARENT);
+    }
+
+    #[test]
+    fn should_reject_invalid_traceparent() {
+        for value in [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e47-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+        ] {
+            assert!(TraceContext::from_str(value).is_err(), "{value}");
+        }
+    }
+}
@@ -29,6 +29,7 @@ use std::str::FromStr;
 
 /// Represents a header key with a unique name. The name is case-insensitive and wraps a string.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct HeaderKey(String);
 
 impl HeaderKey {
@@ -77,16 +78,19 @@ impl TryFrom<&str> for HeaderKey {
 /// - `value`: the value of the header.
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct HeaderValue {
     /// The kind of the header value.
     pub kind: HeaderKind,
     /// The binary value of the header payload.
     #[serde_as(as = "Base64")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String, format = Byte))]
     pub value: Bytes,
 }
 
 /// Represents the kind of a header value.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum HeaderKind {
     Raw,
@@ -632,8 +636,11 @@ impl BytesSerializable for HashMap<HeaderKey, HeaderValue> {
         let mut headers = Self::new();
         let mut position = 0;
         while position < bytes.len() {
+            // The headers sent by the clients might be truncated, so they're read with the bounds checks.
             let key_length = u32::from_le_bytes(
-                bytes[position..position + 4]
+                bytes
+                    .get(position..position + 4)
+                    .ok_or(IggyError::InvalidFormat)?
                     .try_into()
                     .map_err(|_| IggyError::InvalidNumberEncoding)?,
             ) as usize;
@@ -642,17 +649,25 @@ impl BytesSerializable for HashMap<HeaderKey, HeaderValue> {
                 return Err(IggyError::InvalidHeaderKey);
             }
             position += 4;
-            let key = String::from_utf8(bytes[position..position + key_length].to_vec());
+            let key = String::from_utf8(
+                bytes
+                    .get(position..position + key_length)
+                    .ok_or(IggyError::InvalidFormat)?
+                    .to_vec(),
+            );
             if key.is_err() {
                 tracing::error!("B Invalid header key: {}", key.unwrap_err());
                 return Err(IggyError::InvalidHeaderKey);
             }
             let key = key.unwrap();
             position += key_length;
-            let kind = HeaderKind::from_code(bytes[position])?;
+            let kind =
+                HeaderKind::from_code(*bytes.get(position).ok_or(IggyError::InvalidFormat)?)?;
             position += 1;
             let value_length = u32::from_le_bytes(
-                bytes[position..position + 4]
+                bytes
+                    .get(position..position + 4)
+                    .ok_or(IggyError::InvalidFormat)?
                     .try_into()
                     .map_err(|_| IggyError::InvalidNumberEncoding)?,
             ) as usize;
@@ -661,7 +676,10 @@ impl BytesSerializable for HashMap<HeaderKey, HeaderValue> {
                 return Err(IggyError::InvalidHeaderValue);
             }
             position += 4;
-            let value = bytes[position..position + value_length].to_vec();
+            let value = bytes
+                .get(position..position + value_length)
+                .ok_or(IggyError::InvalidFormat)?
+                .to_vec();
             position += value_length;
             headers.insert(
                 HeaderKey(key),
@@ -1298,4 +1316,22 @@ mod tests {
             assert_eq!(deserialized_value.value, value.value);
         }
     }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let mut headers = HashMap::new();
+        headers.insert(
+            HeaderKey::new("key-1").unwrap(),
+            HeaderValue::from_str("Value 1").unwrap(),
+        );
+        let bytes = headers.to_bytes();
+
+        for length in 1..bytes.len() {
+            let result = HashMap::<HeaderKey, HeaderValue>::from_bytes(bytes.slice(..length));
+            assert_eq!(
+                result.unwrap_err().as_code(),
+                IggyError::InvalidFormat.as_code()
+            );
+        }
+    }
 }
@@ -20,16 +20,23 @@ pub(crate) mod client;
 pub(crate) mod client_state;
 pub(crate) mod command;
 pub(crate) mod compression;
+pub(crate) mod config_reload;
 pub(crate) mod configuration;
 pub(crate) mod confirmation;
 pub(crate) mod consumer;
 pub(crate) mod diagnostic;
+pub(crate) mod health;
 pub(crate) mod identifier;
+pub(crate) mod log_filter;
 pub(crate) mod message;
 pub(crate) mod partition;
 pub(crate) mod permissions;
+pub(crate) mod profile;
+pub(crate) mod protocol;
 pub(crate) mod snapshot;
 pub(crate) mod stats;
+pub(crate) mod stats_history;
 pub(crate) mod stream;
+pub(crate) mod system_event;
 pub(crate) mod topic;
 pub(crate) mod user;
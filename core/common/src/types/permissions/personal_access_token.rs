@@ -23,6 +23,7 @@ use serde::{Deserialize, Serialize};
 /// It consists of the following fields:
 /// - `token`: the unique token that should be securely stored by the user and can be used for authentication.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct RawPersonalAccessToken {
     /// The unique token that should be securely stored by the user and can be used for authentication.
     pub token: String,
@@ -33,6 +34,7 @@ pub struct RawPersonalAccessToken {
 /// - `name`: the unique name of the token.
 /// - `expiry`: the optional expiry of the token.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct PersonalAccessTokenInfo {
     /// The unique name of the token.
     pub name: String,
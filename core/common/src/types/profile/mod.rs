@@ -0,0 +1,138 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// `Profile` is the response to the `GetProfile` command, containing the captured profile file.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Profile(pub Vec<u8>);
+
+impl Profile {
+    pub fn new(data: Vec<u8>) -> Self {
+        Profile(data)
+    }
+}
+
+/// Enum representing the kinds of profiles that can be captured from the running server.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum ProfileKind {
+    /// CPU profile sampled for the requested duration.
+    #[default]
+    Cpu,
+    /// Heap profile, available only when the server is built with the `jemalloc` feature.
+    Heap,
+}
+
+/// Enum representing the formats of the captured profile.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum ProfileFormat {
+    /// Protobuf file readable by `go tool pprof`, for the heap profile it's the jemalloc dump readable by `jeprof`.
+    #[default]
+    Pprof,
+    /// Flamegraph SVG, available only for the CPU profile.
+    Flamegraph,
+}
+
+impl ProfileKind {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            ProfileKind::Cpu => 1,
+            ProfileKind::Heap => 2,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(ProfileKind::Cpu),
+            2 => Ok(ProfileKind::Heap),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl fmt::Display for ProfileKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileKind::Cpu => write!(f, "cpu"),
+            ProfileKind::Heap => write!(f, "heap"),
+        }
+    }
+}
+
+impl FromStr for ProfileKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "cpu" => Ok(ProfileKind::Cpu),
+            "heap" => Ok(ProfileKind::Heap),
+            _ => Err(format!("Invalid profile kind: {s}")),
+        }
+    }
+}
+
+impl ProfileFormat {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            ProfileFormat::Pprof => 1,
+            ProfileFormat::Flamegraph => 2,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(ProfileFormat::Pprof),
+            2 => Ok(ProfileFormat::Flamegraph),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+
+    /// Returns the extension of the profile file, e.g. to name the downloaded file.
+    pub fn file_extension(&self, kind: ProfileKind) -> &'static str {
+        match (kind, self) {
+            (ProfileKind::Heap, _) => "heap",
+            (ProfileKind::Cpu, ProfileFormat::Pprof) => "pb",
+            (ProfileKind::Cpu, ProfileFormat::Flamegraph) => "svg",
+        }
+    }
+}
+
+impl fmt::Display for ProfileFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileFormat::Pprof => write!(f, "pprof"),
+            ProfileFormat::Flamegraph => write!(f, "flamegraph"),
+        }
+    }
+}
+
+impl FromStr for ProfileFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pprof" => Ok(ProfileFormat::Pprof),
+            "flamegraph" => Ok(ProfileFormat::Flamegraph),
+            _ => Err(format!("Invalid profile format: {s}")),
+        }
+    }
+}
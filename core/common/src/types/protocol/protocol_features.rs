@@ -0,0 +1,129 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::ops::BitOr;
+
+/// The version of the binary protocol implemented by this crate, exchanged using the `Hello` command.
+/// The clients which don't send the `Hello` command are considered to use the version 0.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// `ProtocolFeatures` is the set of the optional binary protocol features supported by the client or the server,
+/// which can't be detected by the supported command codes alone.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Default)]
+pub struct ProtocolFeatures(u64);
+
+impl ProtocolFeatures {
+    /// No optional features.
+    pub const NONE: ProtocolFeatures = ProtocolFeatures(0);
+    /// The pipelined framing with the request IDs, see the `EnablePipelining` command.
+    pub const PIPELINING: ProtocolFeatures = ProtocolFeatures(1);
+    /// The transport-level compression of the frames, see the `EnableFrameCompression` command.
+    pub const FRAME_COMPRESSION: ProtocolFeatures = ProtocolFeatures(2);
+    /// The throttle time appended to the `SendMessages` and `PollMessages` responses when the throughput quota has been exceeded.
+    pub const THROTTLE_TIME: ProtocolFeatures = ProtocolFeatures(4);
+
+    /// Returns all the features supported by this crate.
+    pub fn all() -> Self {
+        Self::PIPELINING | Self::FRAME_COMPRESSION | Self::THROTTLE_TIME
+    }
+
+    /// Returns the features present in this set, but not in the given one.
+    pub fn without(&self, features: ProtocolFeatures) -> Self {
+        ProtocolFeatures(self.0 & !features.0)
+    }
+
+    /// Returns the features present in both this set and the given one.
+    pub fn intersection(&self, features: ProtocolFeatures) -> Self {
+        ProtocolFeatures(self.0 & features.0)
+    }
+
+    pub fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns `true` if all the given features are present in the set.
+    pub fn contains(&self, features: ProtocolFeatures) -> bool {
+        self.0 & features.0 == features.0
+    }
+}
+
+impl BitOr for ProtocolFeatures {
+    type Output = ProtocolFeatures;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        ProtocolFeatures(self.0 | rhs.0)
+    }
+}
+
+impl Display for ProtocolFeatures {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut names = Vec::new();
+        if self.contains(Self::PIPELINING) {
+            names.push("pipelining");
+        }
+        if self.contains(Self::FRAME_COMPRESSION) {
+            names.push("frame compression");
+        }
+        if self.contains(Self::THROTTLE_TIME) {
+            names.push("throttle time");
+        }
+        if names.is_empty() {
+            return write!(f, "none");
+        }
+
+        write!(f, "{}", names.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_contain_the_combined_features() {
+        let features = ProtocolFeatures::NONE | ProtocolFeatures::PIPELINING;
+        assert!(features.contains(ProtocolFeatures::PIPELINING));
+        assert!(features.contains(ProtocolFeatures::NONE));
+        assert!(!ProtocolFeatures::NONE.contains(ProtocolFeatures::PIPELINING));
+        assert!(
+            !ProtocolFeatures::all()
+                .without(ProtocolFeatures::FRAME_COMPRESSION)
+                .contains(ProtocolFeatures::FRAME_COMPRESSION)
+        );
+        assert_eq!(
+            ProtocolFeatures::all().intersection(ProtocolFeatures::THROTTLE_TIME),
+            ProtocolFeatures::THROTTLE_TIME
+        );
+    }
+
+    #[test]
+    fn should_be_displayed_as_feature_names() {
+        assert_eq!(ProtocolFeatures::NONE.to_string(), "none");
+        assert_eq!(ProtocolFeatures::PIPELINING.to_string(), "pipelining");
+        assert_eq!(
+            ProtocolFeatures::all().to_string(),
+            "pipelining, frame compression, throttle time"
+        );
+    }
+}
@@ -0,0 +1,117 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::{CompressionAlgorithm, IggyError, ProtocolFeatures};
+use serde::{Deserialize, Serialize};
+
+/// `ServerHello` is the response to the `Hello` command, describing the capabilities of the server.
+/// It consists of the following fields:
+/// - `protocol_version`: the version of the binary protocol implemented by the server.
+/// - `min_client_protocol_version`: the minimum protocol version required from the clients.
+/// - `max_frame_size`: the maximum size of the request frame accepted by the server (0 means unlimited).
+/// - `features`: the optional protocol features supported by the server.
+/// - `compression_algorithms`: the compression algorithms supported by the server.
+/// - `commands`: the codes of the commands supported by the server.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct ServerHello {
+    /// The version of the binary protocol implemented by the server.
+    pub protocol_version: u32,
+    /// The minimum protocol version required from the clients.
+    pub min_client_protocol_version: u32,
+    /// The maximum size of the request frame accepted by the server (0 means unlimited).
+    pub max_frame_size: u32,
+    /// The optional protocol features supported by the server.
+    pub features: ProtocolFeatures,
+    /// The compression algorithms supported by the server.
+    pub compression_algorithms: Vec<CompressionAlgorithm>,
+    /// The codes of the commands supported by the server.
+    pub commands: Vec<u32>,
+}
+
+impl ServerHello {
+    pub fn supports_command(&self, code: u32) -> bool {
+        self.commands.contains(&code)
+    }
+
+    pub fn supports_feature(&self, features: ProtocolFeatures) -> bool {
+        self.features.contains(features)
+    }
+
+    /// Checks if the request with the given command code and the frame size can be handled by the server,
+    /// so that the client can fail early instead of sending it.
+    pub fn validate_request(&self, code: u32, frame_size: usize) -> Result<(), IggyError> {
+        if !self.supports_command(code) {
+            return Err(IggyError::FeatureUnavailable);
+        }
+
+        if self.max_frame_size > 0 && frame_size > self.max_frame_size as usize {
+            return Err(IggyError::FrameTooLarge(
+                frame_size as u32,
+                self.max_frame_size,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server_hello() -> ServerHello {
+        ServerHello {
+            protocol_version: 1,
+            min_client_protocol_version: 0,
+            max_frame_size: 1000,
+            features: ProtocolFeatures::PIPELINING,
+            compression_algorithms: vec![CompressionAlgorithm::None],
+            commands: vec![1, 10],
+        }
+    }
+
+    #[test]
+    fn should_accept_supported_command_within_max_frame_size() {
+        assert!(server_hello().validate_request(1, 1000).is_ok());
+    }
+
+    #[test]
+    fn should_reject_unsupported_command() {
+        assert_eq!(
+            server_hello().validate_request(2, 10),
+            Err(IggyError::FeatureUnavailable)
+        );
+    }
+
+    #[test]
+    fn should_reject_too_large_frame() {
+        assert_eq!(
+            server_hello().validate_request(10, 1001),
+            Err(IggyError::FrameTooLarge(1001, 1000))
+        );
+    }
+
+    #[test]
+    fn should_accept_any_frame_size_when_unlimited() {
+        let server_hello = ServerHello {
+            max_frame_size: 0,
+            ..server_hello()
+        };
+        assert!(server_hello.validate_request(10, usize::MAX).is_ok());
+    }
+}
@@ -33,6 +33,7 @@ impl Snapshot {
 
 /// Enum representing the different types of system snapshots that can be taken.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum SystemSnapshotType {
     /// Overview of the filesystem.
     FilesystemOverview,
@@ -46,12 +47,17 @@ pub enum SystemSnapshotType {
     ServerLogs,
     /// Server configuration
     ServerConfig,
+    /// Connected clients with their identification metadata
+    Clients,
+    /// CPU profile flamegraph, sampled for a few seconds, so it's not included in `All`
+    CpuProfile,
     /// Everything
     All,
 }
 
 /// Enum representing the various compression methods available for snapshots.
 #[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum SnapshotCompression {
     /// Store the file as is
     Stored,
@@ -77,6 +83,8 @@ impl SystemSnapshotType {
             SystemSnapshotType::Test => 4,
             SystemSnapshotType::ServerLogs => 5,
             SystemSnapshotType::ServerConfig => 6,
+            SystemSnapshotType::Clients => 7,
+            SystemSnapshotType::CpuProfile => 8,
             SystemSnapshotType::All => 100,
         }
     }
@@ -89,6 +97,8 @@ impl SystemSnapshotType {
             4 => Ok(SystemSnapshotType::Test),
             5 => Ok(SystemSnapshotType::ServerLogs),
             6 => Ok(SystemSnapshotType::ServerConfig),
+            7 => Ok(SystemSnapshotType::Clients),
+            8 => Ok(SystemSnapshotType::CpuProfile),
             100 => Ok(SystemSnapshotType::All),
             _ => Err(IggyError::InvalidCommand),
         }
@@ -101,6 +111,7 @@ impl SystemSnapshotType {
             SystemSnapshotType::ResourceUsage,
             SystemSnapshotType::ServerLogs,
             SystemSnapshotType::ServerConfig,
+            SystemSnapshotType::Clients,
         ]
     }
 
@@ -118,6 +129,8 @@ impl fmt::Display for SystemSnapshotType {
             SystemSnapshotType::Test => write!(f, "test"),
             SystemSnapshotType::ServerLogs => write!(f, "server_logs"),
             SystemSnapshotType::ServerConfig => write!(f, "server_config"),
+            SystemSnapshotType::Clients => write!(f, "clients"),
+            SystemSnapshotType::CpuProfile => write!(f, "cpu_profile"),
             SystemSnapshotType::All => write!(f, "all"),
         }
     }
@@ -134,6 +147,8 @@ impl FromStr for SystemSnapshotType {
             "test" => Ok(SystemSnapshotType::Test),
             "server_logs" => Ok(SystemSnapshotType::ServerLogs),
             "server_config" => Ok(SystemSnapshotType::ServerConfig),
+            "clients" => Ok(SystemSnapshotType::Clients),
+            "cpu_profile" => Ok(SystemSnapshotType::CpuProfile),
             "all" => Ok(SystemSnapshotType::All),
             _ => Err(format!("Invalid snapshot type: {s}")),
         }
@@ -22,6 +22,7 @@ use std::collections::HashMap;
 
 /// `Stats` represents the statistics and details of the server and running process.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Stats {
     /// The unique identifier of the process.
     pub process_id: u32,
@@ -73,7 +74,14 @@ pub struct Stats {
     pub iggy_server_semver: Option<u32>,
     /// Cache metrics per partition
     #[serde(with = "cache_metrics_serializer")]
+    #[cfg_attr(feature = "openapi", schema(value_type = HashMap<String, CacheMetrics>))]
     pub cache_metrics: HashMap<CacheMetricsKey, CacheMetrics>,
+    /// The total number of requests throttled due to the exceeded throughput quota.
+    #[serde(default)]
+    pub throttled_requests_count: u64,
+    /// The total time by which the responses were delayed due to the exceeded throughput quota.
+    #[serde(default)]
+    pub throttle_time: IggyDuration,
 }
 
 /// Key for identifying a specific partition's cache metrics
@@ -95,6 +103,7 @@ impl CacheMetricsKey {
 
 /// Cache metrics for a specific partition
 #[derive(Debug, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CacheMetrics {
     /// Number of cache hits
     pub hits: u64,
@@ -181,6 +190,8 @@ impl Default for Stats {
             iggy_server_version: "unknown_iggy_version".to_string(),
             iggy_server_semver: None,
             cache_metrics: HashMap::new(),
+            throttled_requests_count: 0,
+            throttle_time: IggyDuration::default(),
         }
     }
 }
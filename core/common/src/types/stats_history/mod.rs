@@ -0,0 +1,111 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::utils::{byte_size::IggyByteSize, duration::IggyDuration, timestamp::IggyTimestamp};
+use serde::{Deserialize, Serialize};
+
+/// `StatsHistory` is the response to the `GetStatsHistory` command, consisting of the series
+/// of the stats sampled periodically by the server and kept in memory for the configured time,
+/// ordered from the finest to the coarsest resolution.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct StatsHistory {
+    /// The series of the samples, one per configured resolution.
+    pub series: Vec<StatsSeries>,
+}
+
+impl StatsHistory {
+    /// Returns the series sampled with the provided interval.
+    pub fn get_series(&self, interval: IggyDuration) -> Option<&StatsSeries> {
+        self.series
+            .iter()
+            .find(|series| series.interval == interval)
+    }
+}
+
+/// `StatsSeries` represents the samples taken with the same interval, from the oldest to the newest one.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct StatsSeries {
+    /// The interval between the samples.
+    pub interval: IggyDuration,
+    /// The time for which the samples are kept, the older ones are discarded.
+    pub retention: IggyDuration,
+    /// The samples, from the oldest to the newest one.
+    pub samples: Vec<StatsSample>,
+}
+
+/// `StatsSample` represents the stats of the server at the given time.
+/// The gauges (e.g. the CPU or memory usage) are captured at the time of the sample,
+/// while the throughput is the amount of data processed since the previous sample in the series.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct StatsSample {
+    /// The time when the sample was taken.
+    pub timestamp: IggyTimestamp,
+    /// The CPU usage of the process.
+    pub cpu_usage: f32,
+    /// The total CPU usage of the system.
+    pub total_cpu_usage: f32,
+    /// The memory usage of the process.
+    pub memory_usage: IggyByteSize,
+    /// The available memory of the system.
+    pub available_memory: IggyByteSize,
+    /// The number of connected clients.
+    pub clients_count: u32,
+    /// The total number of stored messages.
+    pub messages_count: u64,
+    /// The total size of the stored messages.
+    pub messages_size_bytes: IggyByteSize,
+    /// The number of bytes read from the disk since the previous sample.
+    pub read_bytes: IggyByteSize,
+    /// The number of bytes written to the disk since the previous sample.
+    pub written_bytes: IggyByteSize,
+    /// The throughput of the topics which have appended any messages since the previous sample.
+    pub topics: Vec<TopicThroughput>,
+}
+
+impl StatsSample {
+    /// Returns the total number of messages appended to all the topics since the previous sample.
+    pub fn appended_messages(&self) -> u64 {
+        self.topics.iter().map(|topic| topic.messages_count).sum()
+    }
+
+    /// Returns the total size of the messages appended to all the topics since the previous sample.
+    pub fn appended_bytes(&self) -> IggyByteSize {
+        self.topics
+            .iter()
+            .map(|topic| topic.size_bytes.as_bytes_u64())
+            .sum::<u64>()
+            .into()
+    }
+}
+
+/// `TopicThroughput` represents the messages appended to the topic since the previous sample.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TopicThroughput {
+    /// The ID of the stream.
+    pub stream_id: u32,
+    /// The ID of the topic.
+    pub topic_id: u32,
+    /// The number of appended messages.
+    pub messages_count: u64,
+    /// The size of the appended messages.
+    pub size_bytes: IggyByteSize,
+}
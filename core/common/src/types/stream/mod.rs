@@ -16,8 +16,11 @@
  * under the License.
  */
 
-use crate::{IggyByteSize, IggyTimestamp, Topic};
+mod stream_size_policy;
+
+use crate::{IggyByteSize, IggyExpiry, IggyTimestamp, MaxTopicSize, Topic};
 use serde::{Deserialize, Serialize};
+pub use stream_size_policy::StreamSizePolicy;
 
 /// `Stream` represents the highest level of logical separation of data.
 /// It consists of the following fields:
@@ -28,6 +31,7 @@ use serde::{Deserialize, Serialize};
 /// - `messages_count`: the total number of messages in the stream.
 /// - `topics_count`: the total number of topics in the stream.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Stream {
     /// The unique identifier (numeric) of the stream.
     pub id: u32,
@@ -51,8 +55,12 @@ pub struct Stream {
 /// - `size_bytes`: the total size of the stream in bytes.
 /// - `messages_count`: the total number of messages in the stream.
 /// - `topics_count`: the total number of topics in the stream.
+/// - `max_size`: the maximum size of the stream.
+/// - `message_expiry`: the default expiry of the messages in the stream.
+/// - `size_policy`: the policy applied when the stream exceeds its maximum size.
 /// - `topics`: the list of topics in the stream.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct StreamDetails {
     /// The unique identifier (numeric) of the stream.
     pub id: u32,
@@ -66,6 +74,23 @@ pub struct StreamDetails {
     pub messages_count: u64,
     /// The total number of topics in the stream.
     pub topics_count: u32,
+    /// The maximum size of the stream, shared by all of its topics.
+    #[serde(default = "default_max_size")]
+    pub max_size: MaxTopicSize,
+    /// The default expiry of the messages, applied to the topics without their own expiry.
+    #[serde(default = "default_message_expiry")]
+    pub message_expiry: IggyExpiry,
+    /// The policy applied when the stream exceeds its maximum size.
+    #[serde(default)]
+    pub size_policy: StreamSizePolicy,
     /// The collection of topics in the stream.
     pub topics: Vec<Topic>,
 }
+
+fn default_max_size() -> MaxTopicSize {
+    MaxTopicSize::Unlimited
+}
+
+fn default_message_expiry() -> IggyExpiry {
+    IggyExpiry::NeverExpire
+}
@@ -0,0 +1,73 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `StreamSizePolicy` defines what happens when the stream exceeds its maximum size.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum StreamSizePolicy {
+    /// The new messages are rejected until the stream size drops below the limit.
+    #[default]
+    RejectSends,
+    /// The oldest segments across all the topics of the stream are deleted.
+    DeleteOldestSegments,
+}
+
+impl FromStr for StreamSizePolicy {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "reject_sends" => Ok(StreamSizePolicy::RejectSends),
+            "delete_oldest_segments" => Ok(StreamSizePolicy::DeleteOldestSegments),
+            _ => Err(IggyError::InvalidStreamSizePolicy),
+        }
+    }
+}
+
+impl Display for StreamSizePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamSizePolicy::RejectSends => write!(f, "reject_sends"),
+            StreamSizePolicy::DeleteOldestSegments => write!(f, "delete_oldest_segments"),
+        }
+    }
+}
+
+impl StreamSizePolicy {
+    /// Returns the code of the stream size policy.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            StreamSizePolicy::RejectSends => 1,
+            StreamSizePolicy::DeleteOldestSegments => 2,
+        }
+    }
+
+    /// Returns the stream size policy from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(StreamSizePolicy::RejectSends),
+            2 => Ok(StreamSizePolicy::DeleteOldestSegments),
+            _ => Err(IggyError::InvalidStreamSizePolicy),
+        }
+    }
+}
@@ -0,0 +1,676 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::Identifier;
+use crate::error::IggyError;
+use crate::utils::timestamp::IggyTimestamp;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+use std::fmt::Display;
+use std::str::{FromStr, from_utf8};
+
+/// The version of the system events published by the server, incremented on the breaking changes.
+pub const SYSTEM_EVENT_VERSION: u8 = 1;
+
+/// `SystemEvent` is the metadata change published by the server to the internal system events topic
+/// (by default `$system/events`), so that the clients can react to it by consuming the topic.
+/// It consists of the following fields:
+/// - `version`: the version of the event schema.
+/// - `timestamp`: the time when the change has been applied.
+/// - `initiator_id`: the ID of the user who initiated the change, `None` if it has been made by the server itself.
+/// - `kind`: the kind of the change, along with the affected resources.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct SystemEvent {
+    /// The version of the event schema.
+    pub version: u8,
+    /// The time when the change has been applied.
+    pub timestamp: IggyTimestamp,
+    /// The ID of the user who initiated the change.
+    pub initiator_id: Option<u32>,
+    /// The kind of the change.
+    #[serde(flatten)]
+    pub kind: SystemEventKind,
+}
+
+/// The kind of the metadata change. The resources are referenced by the identifiers
+/// used in the original command, thus they can be either numeric or named.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SystemEventKind {
+    StreamCreated {
+        #[serde_as(as = "DisplayFromStr")]
+        stream_id: Identifier,
+        name: String,
+    },
+    StreamUpdated {
+        #[serde_as(as = "DisplayFromStr")]
+        stream_id: Identifier,
+    },
+    StreamDeleted {
+        #[serde_as(as = "DisplayFromStr")]
+        stream_id: Identifier,
+    },
+    StreamPurged {
+        #[serde_as(as = "DisplayFromStr")]
+        stream_id: Identifier,
+    },
+    TopicCreated {
+        #[serde_as(as = "DisplayFromStr")]
+        stream_id: Identifier,
+        #[serde_as(as = "DisplayFromStr")]
+        topic_id: Identifier,
+        name: String,
+        partitions_count: u32,
+    },
+    TopicUpdated {
+        #[serde_as(as = "DisplayFromStr")]
+        stream_id: Identifier,
+        #[serde_as(as = "DisplayFromStr")]
+        topic_id: Identifier,
+    },
+    TopicDeleted {
+        #[serde_as(as = "DisplayFromStr")]
+        stream_id: Identifier,
+        #[serde_as(as = "DisplayFromStr")]
+        topic_id: Identifier,
+    },
+    TopicPurged {
+        #[serde_as(as = "DisplayFromStr")]
+        stream_id: Identifier,
+        #[serde_as(as = "DisplayFromStr")]
+        topic_id: Identifier,
+    },
+    PartitionsCreated {
+        #[serde_as(as = "DisplayFromStr")]
+        stream_id: Identifier,
+        #[serde_as(as = "DisplayFromStr")]
+        topic_id: Identifier,
+        partitions_count: u32,
+    },
+    PartitionsDeleted {
+        #[serde_as(as = "DisplayFromStr")]
+        stream_id: Identifier,
+        #[serde_as(as = "DisplayFromStr")]
+        topic_id: Identifier,
+        partitions_count: u32,
+    },
+    SegmentRolledOver {
+        #[serde_as(as = "DisplayFromStr")]
+        stream_id: Identifier,
+        #[serde_as(as = "DisplayFromStr")]
+        topic_id: Identifier,
+        partition_id: u32,
+        start_offset: u64,
+    },
+    SegmentsDeleted {
+        #[serde_as(as = "DisplayFromStr")]
+        stream_id: Identifier,
+        #[serde_as(as = "DisplayFromStr")]
+        topic_id: Identifier,
+        partition_id: u32,
+        segments_count: u32,
+    },
+    ConsumerGroupCreated {
+        #[serde_as(as = "DisplayFromStr")]
+        stream_id: Identifier,
+        #[serde_as(as = "DisplayFromStr")]
+        topic_id: Identifier,
+        #[serde_as(as = "DisplayFromStr")]
+        group_id: Identifier,
+        name: String,
+    },
+    ConsumerGroupDeleted {
+        #[serde_as(as = "DisplayFromStr")]
+        stream_id: Identifier,
+        #[serde_as(as = "DisplayFromStr")]
+        topic_id: Identifier,
+        #[serde_as(as = "DisplayFromStr")]
+        group_id: Identifier,
+    },
+    ConsumerGroupMemberJoined {
+        #[serde_as(as = "DisplayFromStr")]
+        stream_id: Identifier,
+        #[serde_as(as = "DisplayFromStr")]
+        topic_id: Identifier,
+        #[serde_as(as = "DisplayFromStr")]
+        group_id: Identifier,
+        client_id: u32,
+    },
+    ConsumerGroupMemberLeft {
+        #[serde_as(as = "DisplayFromStr")]
+        stream_id: Identifier,
+        #[serde_as(as = "DisplayFromStr")]
+        topic_id: Identifier,
+        #[serde_as(as = "DisplayFromStr")]
+        group_id: Identifier,
+        client_id: u32,
+    },
+    UserCreated {
+        #[serde_as(as = "DisplayFromStr")]
+        user_id: Identifier,
+        username: String,
+    },
+    UserUpdated {
+        #[serde_as(as = "DisplayFromStr")]
+        user_id: Identifier,
+    },
+    UserDeleted {
+        #[serde_as(as = "DisplayFromStr")]
+        user_id: Identifier,
+    },
+    UserPermissionsUpdated {
+        #[serde_as(as = "DisplayFromStr")]
+        user_id: Identifier,
+    },
+    UserPasswordChanged {
+        #[serde_as(as = "DisplayFromStr")]
+        user_id: Identifier,
+    },
+}
+
+/// The format of the system events stored as the message payloads.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum SystemEventFormat {
+    /// The event is serialized as a JSON object, with the `type` field describing its kind.
+    #[default]
+    Json,
+    /// The event is serialized using the binary encoding, as in `BytesSerializable`.
+    Binary,
+}
+
+impl SystemEvent {
+    /// Creates the event of the current version, timestamped with the current time.
+    pub fn new(initiator_id: Option<u32>, kind: SystemEventKind) -> Self {
+        Self {
+            version: SYSTEM_EVENT_VERSION,
+            timestamp: IggyTimestamp::now(),
+            initiator_id,
+            kind,
+        }
+    }
+}
+
+impl SystemEventKind {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            SystemEventKind::StreamCreated { .. } => 1,
+            SystemEventKind::StreamUpdated { .. } => 2,
+            SystemEventKind::StreamDeleted { .. } => 3,
+            SystemEventKind::StreamPurged { .. } => 4,
+            SystemEventKind::TopicCreated { .. } => 11,
+            SystemEventKind::TopicUpdated { .. } => 12,
+            SystemEventKind::TopicDeleted { .. } => 13,
+            SystemEventKind::TopicPurged { .. } => 14,
+            SystemEventKind::PartitionsCreated { .. } => 21,
+            SystemEventKind::PartitionsDeleted { .. } => 22,
+            SystemEventKind::SegmentRolledOver { .. } => 31,
+            SystemEventKind::SegmentsDeleted { .. } => 32,
+            SystemEventKind::ConsumerGroupCreated { .. } => 41,
+            SystemEventKind::ConsumerGroupDeleted { .. } => 42,
+            SystemEventKind::ConsumerGroupMemberJoined { .. } => 43,
+            SystemEventKind::ConsumerGroupMemberLeft { .. } => 44,
+            SystemEventKind::UserCreated { .. } => 51,
+            SystemEventKind::UserUpdated { .. } => 52,
+            SystemEventKind::UserDeleted { .. } => 53,
+            SystemEventKind::UserPermissionsUpdated { .. } => 54,
+            SystemEventKind::UserPasswordChanged { .. } => 55,
+        }
+    }
+}
+
+impl SystemEventFormat {
+    /// Serializes the event to be stored as the message payload.
+    pub fn encode(&self, event: &SystemEvent) -> Result<Bytes, IggyError> {
+        match self {
+            SystemEventFormat::Json => serde_json::to_vec(event)
+                .map(Bytes::from)
+                .map_err(|_| IggyError::InvalidFormat),
+            SystemEventFormat::Binary => Ok(event.to_bytes()),
+        }
+    }
+
+    /// Deserializes the event from the message payload.
+    pub fn decode(&self, payload: &[u8]) -> Result<SystemEvent, IggyError> {
+        match self {
+            SystemEventFormat::Json => {
+                serde_json::from_slice(payload).map_err(|_| IggyError::InvalidFormat)
+            }
+            SystemEventFormat::Binary => SystemEvent::from_bytes(Bytes::copy_from_slice(payload)),
+        }
+    }
+}
+
+impl Display for SystemEventFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SystemEventFormat::Json => write!(f, "json"),
+            SystemEventFormat::Binary => write!(f, "binary"),
+        }
+    }
+}
+
+impl FromStr for SystemEventFormat {
+    type Err = IggyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(SystemEventFormat::Json),
+            "binary" => Ok(SystemEventFormat::Binary),
+            _ => Err(IggyError::InvalidFormat),
+        }
+    }
+}
+
+impl BytesSerializable for SystemEvent {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(14);
+        bytes.put_u8(self.version);
+        bytes.put_u64_le(self.timestamp.as_micros());
+        bytes.put_u32_le(self.initiator_id.unwrap_or(0));
+        bytes.put_u8(self.kind.as_code());
+        match &self.kind {
+            SystemEventKind::StreamCreated { stream_id, name } => {
+                put_identifier(&mut bytes, stream_id);
+                put_string(&mut bytes, name);
+            }
+            SystemEventKind::StreamUpdated { stream_id }
+            | SystemEventKind::StreamDeleted { stream_id }
+            | SystemEventKind::StreamPurged { stream_id } => {
+                put_identifier(&mut bytes, stream_id);
+            }
+            SystemEventKind::TopicCreated {
+                stream_id,
+                topic_id,
+                name,
+                partitions_count,
+            } => {
+                put_identifier(&mut bytes, stream_id);
+                put_identifier(&mut bytes, topic_id);
+                put_string(&mut bytes, name);
+                bytes.put_u32_le(*partitions_count);
+            }
+            SystemEventKind::TopicUpdated {
+                stream_id,
+                topic_id,
+            }
+            | SystemEventKind::TopicDeleted {
+                stream_id,
+                topic_id,
+            }
+            | SystemEventKind::TopicPurged {
+                stream_id,
+                topic_id,
+            } => {
+                put_identifier(&mut bytes, stream_id);
+                put_identifier(&mut bytes, topic_id);
+            }
+            SystemEventKind::PartitionsCreated {
+                stream_id,
+                topic_id,
+                partitions_count,
+            }
+            | SystemEventKind::PartitionsDeleted {
+                stream_id,
+                topic_id,
+                partitions_count,
+            } => {
+                put_identifier(&mut bytes, stream_id);
+                put_identifier(&mut bytes, topic_id);
+                bytes.put_u32_le(*partitions_count);
+            }
+            SystemEventKind::SegmentRolledOver {
+                stream_id,
+                topic_id,
+                partition_id,
+                start_offset,
+            } => {
+                put_identifier(&mut bytes, stream_id);
+                put_identifier(&mut bytes, topic_id);
+                bytes.put_u32_le(*partition_id);
+                bytes.put_u64_le(*start_offset);
+            }
+            SystemEventKind::SegmentsDeleted {
+                stream_id,
+                topic_id,
+                partition_id,
+                segments_count,
+            } => {
+                put_identifier(&mut bytes, stream_id);
+                put_identifier(&mut bytes, topic_id);
+                bytes.put_u32_le(*partition_id);
+                bytes.put_u32_le(*segments_count);
+            }
+            SystemEventKind::ConsumerGroupCreated {
+                stream_id,
+                topic_id,
+                group_id,
+                name,
+            } => {
+                put_identifier(&mut bytes, stream_id);
+                put_identifier(&mut bytes, topic_id);
+                put_identifier(&mut bytes, group_id);
+                put_string(&mut bytes, name);
+            }
+            SystemEventKind::ConsumerGroupDeleted {
+                stream_id,
+                topic_id,
+                group_id,
+            } => {
+                put_identifier(&mut bytes, stream_id);
+                put_identifier(&mut bytes, topic_id);
+                put_identifier(&mut bytes, group_id);
+            }
+            SystemEventKind::ConsumerGroupMemberJoined {
+                stream_id,
+                topic_id,
+                group_id,
+                client_id,
+            }
+            | SystemEventKind::ConsumerGroupMemberLeft {
+                stream_id,
+                topic_id,
+                group_id,
+                client_id,
+            } => {
+                put_identifier(&mut bytes, stream_id);
+                put_identifier(&mut bytes, topic_id);
+                put_identifier(&mut bytes, group_id);
+                bytes.put_u32_le(*client_id);
+            }
+            SystemEventKind::UserCreated { user_id, username } => {
+                put_identifier(&mut bytes, user_id);
+                put_string(&mut bytes, username);
+            }
+            SystemEventKind::UserUpdated { user_id }
+            | SystemEventKind::UserDeleted { user_id }
+            | SystemEventKind::UserPermissionsUpdated { user_id }
+            | SystemEventKind::UserPasswordChanged { user_id } => {
+                put_identifier(&mut bytes, user_id);
+            }
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<SystemEvent, IggyError> {
+        let mut reader = Reader::new(&bytes);
+        let version = reader.u8()?;
+        let timestamp = IggyTimestamp::from(reader.u64()?);
+        let initiator_id = match reader.u32()? {
+            0 => None,
+            initiator_id => Some(initiator_id),
+        };
+        let kind = match reader.u8()? {
+            1 => SystemEventKind::StreamCreated {
+                stream_id: reader.identifier()?,
+                name: reader.string()?,
+            },
+            2 => SystemEventKind::StreamUpdated {
+                stream_id: reader.identifier()?,
+            },
+            3 => SystemEventKind::StreamDeleted {
+                stream_id: reader.identifier()?,
+            },
+            4 => SystemEventKind::StreamPurged {
+                stream_id: reader.identifier()?,
+            },
+            11 => SystemEventKind::TopicCreated {
+                stream_id: reader.identifier()?,
+                topic_id: reader.identifier()?,
+                name: reader.string()?,
+                partitions_count: reader.u32()?,
+            },
+            12 => SystemEventKind::TopicUpdated {
+                stream_id: reader.identifier()?,
+                topic_id: reader.identifier()?,
+            },
+            13 => SystemEventKind::TopicDeleted {
+                stream_id: reader.identifier()?,
+                topic_id: reader.identifier()?,
+            },
+            14 => SystemEventKind::TopicPurged {
+                stream_id: reader.identifier()?,
+                topic_id: reader.identifier()?,
+            },
+            21 => SystemEventKind::PartitionsCreated {
+                stream_id: reader.identifier()?,
+                topic_id: reader.identifier()?,
+                partitions_count: reader.u32()?,
+            },
+            22 => SystemEventKind::PartitionsDeleted {
+                stream_id: reader.identifier()?,
+                topic_id: reader.identifier()?,
+                partitions_count: reader.u32()?,
+            },
+            31 => SystemEventKind::SegmentRolledOver {
+                stream_id: reader.identifier()?,
+                topic_id: reader.identifier()?,
+                partition_id: reader.u32()?,
+                start_offset: reader.u64()?,
+            },
+            32 => SystemEventKind::SegmentsDeleted {
+                stream_id: reader.identifier()?,
+                topic_id: reader.identifier()?,
+                partition_id: reader.u32()?,
+                segments_count: reader.u32()?,
+            },
+            41 => SystemEventKind::ConsumerGroupCreated {
+                stream_id: reader.identifier()?,
+                topic_id: reader.identifier()?,
+                group_id: reader.identifier()?,
+                name: reader.string()?,
+            },
+            42 => SystemEventKind::ConsumerGroupDeleted {
+                stream_id: reader.identifier()?,
+                topic_id: reader.identifier()?,
+                group_id: reader.identifier()?,
+            },
+            43 => SystemEventKind::ConsumerGroupMemberJoined {
+                stream_id: reader.identifier()?,
+                topic_id: reader.identifier()?,
+                group_id: reader.identifier()?,
+                client_id: reader.u32()?,
+            },
+            44 => SystemEventKind::ConsumerGroupMemberLeft {
+                stream_id: reader.identifier()?,
+                topic_id: reader.identifier()?,
+                group_id: reader.identifier()?,
+                client_id: reader.u32()?,
+            },
+            51 => SystemEventKind::UserCreated {
+                user_id: reader.identifier()?,
+                username: reader.string()?,
+            },
+            52 => SystemEventKind::UserUpdated {
+                user_id: reader.identifier()?,
+            },
+            53 => SystemEventKind::UserDeleted {
+                user_id: reader.identifier()?,
+            },
+            54 => SystemEventKind::UserPermissionsUpdated {
+                user_id: reader.identifier()?,
+            },
+            55 => SystemEventKind::UserPasswordChanged {
+                user_id: reader.identifier()?,
+            },
+            _ => return Err(IggyError::InvalidFormat),
+        };
+
+        Ok(SystemEvent {
+            version,
+            timestamp,
+            initiator_id,
+            kind,
+        })
+    }
+}
+
+fn put_identifier(bytes: &mut BytesMut, identifier: &Identifier) {
+    bytes.put_slice(&identifier.to_bytes());
+}
+
+fn put_string(bytes: &mut BytesMut, value: &str) {
+    #[allow(clippy::cast_possible_truncation)]
+    bytes.put_u8(value.len() as u8);
+    bytes.put_slice(value.as_bytes());
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], IggyError> {
+        let end = self.position + length;
+        if end > self.bytes.len() {
+            return Err(IggyError::InvalidFormat);
+        }
+
+        let slice = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, IggyError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, IggyError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes(
+            bytes
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ))
+    }
+
+    fn u64(&mut self) -> Result<u64, IggyError> {
+        let bytes = self.take(8)?;
+        Ok(u64::from_le_bytes(
+            bytes
+                .try_into()
+                .map_err(|_| IggyError::InvalidNumberEncoding)?,
+        ))
+    }
+
+    fn string(&mut self) -> Result<String, IggyError> {
+        let length = self.u8()? as usize;
+        let bytes = self.take(length)?;
+        from_utf8(bytes)
+            .map(|value| value.to_owned())
+            .map_err(|_| IggyError::InvalidUtf8)
+    }
+
+    fn identifier(&mut self) -> Result<Identifier, IggyError> {
+        let start = self.position;
+        self.take(1)?;
+        let length = self.u8()? as usize;
+        self.take(length)?;
+        Identifier::from_raw_bytes(&self.bytes[start..self.position])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn events() -> Vec<SystemEvent> {
+        vec![
+            SystemEvent::new(
+                Some(1),
+                SystemEventKind::TopicCreated {
+                    stream_id: Identifier::named("orders").unwrap(),
+                    topic_id: Identifier::numeric(2).unwrap(),
+                    name: "payments".to_owned(),
+                    partitions_count: 3,
+                },
+            ),
+            SystemEvent::new(
+                None,
+                SystemEventKind::SegmentRolledOver {
+                    stream_id: Identifier::numeric(1).unwrap(),
+                    topic_id: Identifier::numeric(2).unwrap(),
+                    partition_id: 3,
+                    start_offset: 1000,
+                },
+            ),
+            SystemEvent::new(
+                Some(1),
+                SystemEventKind::UserCreated {
+                    user_id: Identifier::numeric(2).unwrap(),
+                    username: "user".to_owned(),
+                },
+            ),
+            SystemEvent::new(
+                Some(1),
+                SystemEventKind::ConsumerGroupMemberLeft {
+                    stream_id: Identifier::numeric(1).unwrap(),
+                    topic_id: Identifier::named("payments").unwrap(),
+                    group_id: Identifier::numeric(4).unwrap(),
+                    client_id: 5,
+                },
+            ),
+        ]
+        .into_iter()
+        .map(|mut event| {
+            // The timestamps are stored with the microsecond precision.
+            event.timestamp = IggyTimestamp::from(1_700_000_000_000_000);
+            event
+        })
+        .collect()
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_using_both_formats() {
+        for format in [SystemEventFormat::Json, SystemEventFormat::Binary] {
+            for event in events() {
+                let payload = format.encode(&event).unwrap();
+                let decoded = format.decode(&payload).unwrap();
+                assert_eq!(decoded, event);
+            }
+        }
+    }
+
+    #[test]
+    fn should_be_serialized_as_json_with_type_and_readable_identifiers() {
+        let event = events().remove(0);
+        let json: serde_json::Value =
+            serde_json::from_slice(&SystemEventFormat::Json.encode(&event).unwrap()).unwrap();
+        assert_eq!(json["version"], SYSTEM_EVENT_VERSION);
+        assert_eq!(json["initiator_id"], 1);
+        assert_eq!(json["type"], "topic_created");
+        assert_eq!(json["stream_id"], "orders");
+        assert_eq!(json["topic_id"], "2");
+        assert_eq!(json["partitions_count"], 3);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let bytes = events().remove(0).to_bytes();
+        let result = SystemEvent::from_bytes(bytes.slice(..bytes.len() - 1));
+        assert!(matches!(result, Err(IggyError::InvalidFormat)));
+    }
+}
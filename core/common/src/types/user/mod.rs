@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub(crate) mod throughput_quota;
 pub(crate) mod user_identity_info;
 pub(crate) mod user_info;
 pub(crate) mod user_status;
@@ -0,0 +1,127 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::BytesSerializable;
+use crate::error::IggyError;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+const THROUGHPUT_QUOTA_SIZE: usize = 32;
+
+/// `ThroughputQuota` represents the throughput limits which can be attached to the user or the personal access token.
+/// When the limit is exceeded, the server delays the responses (throttles the client) instead of failing the requests.
+/// It consists of the following fields (0 means unlimited):
+/// - `produce_bytes_per_second`: the maximum number of bytes per second which can be sent.
+/// - `produce_messages_per_second`: the maximum number of messages per second which can be sent.
+/// - `consume_bytes_per_second`: the maximum number of bytes per second which can be polled.
+/// - `consume_messages_per_second`: the maximum number of messages per second which can be polled.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ThroughputQuota {
+    /// The maximum number of bytes per second which can be sent.
+    #[serde(default)]
+    pub produce_bytes_per_second: u64,
+    /// The maximum number of messages per second which can be sent.
+    #[serde(default)]
+    pub produce_messages_per_second: u64,
+    /// The maximum number of bytes per second which can be polled.
+    #[serde(default)]
+    pub consume_bytes_per_second: u64,
+    /// The maximum number of messages per second which can be polled.
+    #[serde(default)]
+    pub consume_messages_per_second: u64,
+}
+
+impl ThroughputQuota {
+    /// Returns `true` if none of the limits is set.
+    pub fn is_unlimited(&self) -> bool {
+        self.produce_bytes_per_second == 0
+            && self.produce_messages_per_second == 0
+            && self.consume_bytes_per_second == 0
+            && self.consume_messages_per_second == 0
+    }
+}
+
+impl BytesSerializable for ThroughputQuota {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(THROUGHPUT_QUOTA_SIZE);
+        bytes.put_u64_le(self.produce_bytes_per_second);
+        bytes.put_u64_le(self.produce_messages_per_second);
+        bytes.put_u64_le(self.consume_bytes_per_second);
+        bytes.put_u64_le(self.consume_messages_per_second);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        if bytes.len() != THROUGHPUT_QUOTA_SIZE {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let read = |position: usize| {
+            u64::from_le_bytes(
+                bytes[position..position + 8]
+                    .try_into()
+                    .expect("Slice has the size of u64"),
+            )
+        };
+        Ok(ThroughputQuota {
+            produce_bytes_per_second: read(0),
+            produce_messages_per_second: read(8),
+            consume_bytes_per_second: read(16),
+            consume_messages_per_second: read(24),
+        })
+    }
+}
+
+impl Display for ThroughputQuota {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.produce_bytes_per_second,
+            self.produce_messages_per_second,
+            self.consume_bytes_per_second,
+            self.consume_messages_per_second
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let quota = ThroughputQuota {
+            produce_bytes_per_second: 1_000_000,
+            produce_messages_per_second: 1000,
+            consume_bytes_per_second: 2_000_000,
+            consume_messages_per_second: 0,
+        };
+        let bytes = quota.to_bytes();
+        assert_eq!(bytes.len(), THROUGHPUT_QUOTA_SIZE);
+        let deserialized = ThroughputQuota::from_bytes(bytes).unwrap();
+        assert_eq!(deserialized, quota);
+        assert!(!deserialized.is_unlimited());
+        assert!(ThroughputQuota::default().is_unlimited());
+    }
+}
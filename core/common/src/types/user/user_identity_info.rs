@@ -24,6 +24,7 @@ use serde::{Deserialize, Serialize};
 /// - `user_id`: the unique identifier (numeric) of the user.
 /// - `access_token`: the optional access token, used only by HTTP transport.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct IdentityInfo {
     /// The unique identifier (numeric) of the user.
     pub user_id: UserId,
@@ -36,6 +37,7 @@ pub struct IdentityInfo {
 /// - `token`: the value of token.
 /// - `expiry`: the expiry of token.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TokenInfo {
     /// The value of token.
     pub token: String,
@@ -17,6 +17,7 @@
  */
 
 use crate::Permissions;
+use crate::ThroughputQuota;
 use crate::types::user::user_status::UserStatus;
 use crate::utils::timestamp::IggyTimestamp;
 use serde::{Deserialize, Serialize};
@@ -35,6 +36,7 @@ pub type AtomicUserId = AtomicU32;
 /// - `status`: the status of the user.
 /// - `username`: the username of the user.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UserInfo {
     /// The unique identifier (numeric) of the user.
     pub id: UserId,
@@ -53,7 +55,9 @@ pub struct UserInfo {
 /// - `status`: the status of the user.
 /// - `username`: the username of the user.
 /// - `permissions`: the optional permissions of the user.
+/// - `quota`: the optional throughput quota of the user.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct UserInfoDetails {
     /// The unique identifier (numeric) of the user.
     pub id: UserId,
@@ -65,4 +69,7 @@ pub struct UserInfoDetails {
     pub username: String,
     /// The optional permissions of the user.
     pub permissions: Option<Permissions>,
+    /// The optional throughput quota of the user.
+    #[serde(default)]
+    pub quota: Option<ThroughputQuota>,
 }
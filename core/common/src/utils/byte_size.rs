@@ -56,6 +56,12 @@ use std::{
 #[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IggyByteSize(Byte);
 
+#[cfg(feature = "openapi")]
+crate::utils::openapi::impl_to_schema!(
+    IggyByteSize,
+    crate::utils::openapi::string_schema("The human-readable size, e.g. `1.5 MB`.", &[])
+);
+
 impl Default for IggyByteSize {
     fn default() -> Self {
         Self(Byte::from_u64(0))
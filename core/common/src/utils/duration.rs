@@ -163,6 +163,12 @@ impl Add for IggyDuration {
     }
 }
 
+#[cfg(feature = "openapi")]
+crate::utils::openapi::impl_to_schema!(
+    IggyDuration,
+    crate::utils::openapi::u64_schema("The duration in microseconds.")
+);
+
 impl Serialize for IggyDuration {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
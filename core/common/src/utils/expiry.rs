@@ -171,6 +171,14 @@ impl From<Option<u64>> for IggyExpiry {
     }
 }
 
+#[cfg(feature = "openapi")]
+crate::utils::openapi::impl_to_schema!(
+    IggyExpiry,
+    crate::utils::openapi::u64_schema(
+        "The expiry in microseconds, 0 for the server default and 18446744073709551615 for never.",
+    )
+);
+
 impl Serialize for IggyExpiry {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -20,7 +20,10 @@ pub(crate) mod checksum;
 pub(crate) mod crypto;
 pub(crate) mod duration;
 pub(crate) mod expiry;
+#[cfg(feature = "openapi")]
+pub(crate) mod openapi;
 pub(crate) mod personal_access_token_expiry;
 pub mod text;
 pub(crate) mod timestamp;
 pub(crate) mod topic_size;
+pub mod websocket;
@@ -141,6 +141,12 @@ impl fmt::Display for IggyTimestamp {
     }
 }
 
+#[cfg(feature = "openapi")]
+crate::utils::openapi::impl_to_schema!(
+    IggyTimestamp,
+    crate::utils::openapi::u64_schema("The number of microseconds since the Unix epoch.")
+);
+
 impl Serialize for IggyTimestamp {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
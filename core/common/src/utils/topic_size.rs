@@ -121,6 +121,14 @@ impl FromStr for MaxTopicSize {
     }
 }
 
+#[cfg(feature = "openapi")]
+crate::utils::openapi::impl_to_schema!(
+    MaxTopicSize,
+    crate::utils::openapi::u64_schema(
+        "The size in bytes, 0 for the server default and 18446744073709551615 for unlimited.",
+    )
+);
+
 impl Serialize for MaxTopicSize {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
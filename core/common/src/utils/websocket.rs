@@ -0,0 +1,550 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! Minimal WebSocket (RFC 6455) support for carrying the binary protocol frames.
+//! The [`WebSocketStream`] exposes the payload of the binary messages as a regular byte stream,
+//! so that the existing binary protocol readers and writers can be used on top of it.
+
+use crate::IggyError;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use bytes::{Buf, BufMut, BytesMut};
+use sha1::{Digest, Sha1};
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBuf};
+
+pub const WEBSOCKET_VERSION: &str = "13";
+const WEBSOCKET_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+const MAX_HANDSHAKE_RESPONSE_SIZE: usize = 8 * 1024;
+const MAX_OUTGOING_FRAME_PAYLOAD_SIZE: usize = 64 * 1024;
+const MAX_CONTROL_FRAME_PAYLOAD_SIZE: usize = 125;
+const READ_CHUNK_SIZE: usize = 8 * 1024;
+const NORMAL_CLOSURE: u16 = 1000;
+
+const OPCODE_CONTINUATION: u8 = 0x0;
+const OPCODE_TEXT: u8 = 0x1;
+const OPCODE_BINARY: u8 = 0x2;
+const OPCODE_CLOSE: u8 = 0x8;
+const OPCODE_PING: u8 = 0x9;
+const OPCODE_PONG: u8 = 0xA;
+
+/// The side of the connection, which determines the masking of the frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebSocketRole {
+    Client,
+    Server,
+}
+
+/// Returns the value of the `Sec-WebSocket-Accept` header for the provided `Sec-WebSocket-Key`.
+pub fn accept_key(key: &str) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(key.as_bytes());
+    hasher.update(WEBSOCKET_GUID.as_bytes());
+    STANDARD.encode(hasher.finalize())
+}
+
+/// Generates a random `Sec-WebSocket-Key` for the opening handshake.
+pub fn generate_key() -> String {
+    STANDARD.encode(rand::random::<[u8; 16]>())
+}
+
+/// Performs the client side of the opening handshake and returns the WebSocket stream on success.
+pub async fn connect<S>(
+    mut stream: S,
+    host: &str,
+    path: &str,
+    max_frame_size: usize,
+) -> Result<WebSocketStream<S>, IggyError>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let key = generate_key();
+    let request = format!(
+        "GET {path} HTTP/1.1\r\nHost: {host}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Key: {key}\r\nSec-WebSocket-Version: {WEBSOCKET_VERSION}\r\n\r\n"
+    );
+    stream
+        .write_all(request.as_bytes())
+        .await
+        .map_err(|_| IggyError::CannotEstablishConnection)?;
+    stream
+        .flush()
+        .await
+        .map_err(|_| IggyError::CannotEstablishConnection)?;
+
+    // The response is read byte by byte, as the server doesn't send any frames before the handshake completes.
+    let mut response = Vec::with_capacity(256);
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() >= MAX_HANDSHAKE_RESPONSE_SIZE {
+            return Err(IggyError::CannotEstablishConnection);
+        }
+        let byte = stream
+            .read_u8()
+            .await
+            .map_err(|_| IggyError::CannotEstablishConnection)?;
+        response.push(byte);
+    }
+
+    let response =
+        std::str::from_utf8(&response).map_err(|_| IggyError::CannotEstablishConnection)?;
+    let mut lines = response.split("\r\n");
+    let status = lines.next().unwrap_or_default();
+    if status.split(' ').nth(1) != Some("101") {
+        return Err(IggyError::CannotEstablishConnection);
+    }
+
+    let expected_accept = accept_key(&key);
+    let accepted = lines
+        .filter_map(|line| line.split_once(':'))
+        .any(|(name, value)| {
+            name.trim().eq_ignore_ascii_case("sec-websocket-accept")
+                && value.trim() == expected_accept
+        });
+    if !accepted {
+        return Err(IggyError::CannotEstablishConnection);
+    }
+
+    Ok(WebSocketStream::new(
+        stream,
+        WebSocketRole::Client,
+        max_frame_size,
+    ))
+}
+
+struct Frame {
+    fin: bool,
+    opcode: u8,
+    payload: BytesMut,
+}
+
+/// Byte stream on top of the WebSocket binary messages.
+/// Every write is sent as a binary frame (split into several frames if needed) once the stream
+/// is flushed, and the payloads of the received binary messages (which might be fragmented)
+/// are returned by the reads, regardless of the message boundaries.
+/// The ping and close frames are answered right away, while reading the stream.
+#[derive(Debug)]
+pub struct WebSocketStream<S> {
+    inner: S,
+    role: WebSocketRole,
+    max_frame_size: usize,
+    read_buffer: BytesMut,
+    payload: BytesMut,
+    write_buffer: BytesMut,
+    /// Set once the first frame of a fragmented message is received, until its final frame.
+    fragmented: bool,
+    close_received: bool,
+    close_sent: bool,
+}
+
+impl<S> WebSocketStream<S> {
+    /// Creates the stream for the connection, for which the opening handshake has already completed.
+    pub fn new(inner: S, role: WebSocketRole, max_frame_size: usize) -> Self {
+        Self {
+            inner,
+            role,
+            max_frame_size,
+            read_buffer: BytesMut::with_capacity(READ_CHUNK_SIZE),
+            payload: BytesMut::new(),
+            write_buffer: BytesMut::new(),
+            fragmented: false,
+            close_received: false,
+            close_sent: false,
+        }
+    }
+
+    fn encode_frame(&mut self, opcode: u8, payload: &[u8]) {
+        self.write_buffer.put_u8(0x80 | opcode);
+        let mask_bit = match self.role {
+            WebSocketRole::Client => 0x80,
+            WebSocketRole::Server => 0,
+        };
+        match payload.len() {
+            length if length < 126 => self.write_buffer.put_u8(mask_bit | length as u8),
+            length if length <= u16::MAX as usize => {
+                self.write_buffer.put_u8(mask_bit | 126);
+                self.write_buffer.put_u16(length as u16);
+            }
+            length => {
+                self.write_buffer.put_u8(mask_bit | 127);
+                self.write_buffer.put_u64(length as u64);
+            }
+        }
+
+        if self.role == WebSocketRole::Server {
+            self.write_buffer.put_slice(payload);
+            return;
+        }
+
+        let mask = rand::random::<[u8; 4]>();
+        self.write_buffer.put_slice(&mask);
+        self.write_buffer.extend(
+            payload
+                .iter()
+                .enumerate()
+                .map(|(index, byte)| byte ^ mask[index % 4]),
+        );
+    }
+
+    /// Decodes the next frame from the read buffer, if it has been received completely.
+    fn decode_frame(&mut self) -> io::Result<Option<Frame>> {
+        if self.read_buffer.len() < 2 {
+            return Ok(None);
+        }
+
+        let first = self.read_buffer[0];
+        let second = self.read_buffer[1];
+        if first & 0x70 != 0 {
+            return Err(invalid_data("reserved bits are not supported"));
+        }
+
+        let fin = first & 0x80 != 0;
+        let opcode = first & 0x0F;
+        let masked = second & 0x80 != 0;
+        if masked != (self.role == WebSocketRole::Server) {
+            return Err(invalid_data("invalid frame masking"));
+        }
+
+        let mut header_length = 2;
+        let payload_length = match second & 0x7F {
+            126 => {
+                header_length += 2;
+                if self.read_buffer.len() < header_length {
+                    return Ok(None);
+                }
+                u16::from_be_bytes([self.read_buffer[2], self.read_buffer[3]]) as u64
+            }
+            127 => {
+                header_length += 8;
+                if self.read_buffer.len() < header_length {
+                    return Ok(None);
+                }
+                u64::from_be_bytes(self.read_buffer[2..10].try_into().unwrap())
+            }
+            length => length as u64,
+        };
+
+        if opcode >= OPCODE_CLOSE
+            && (!fin || payload_length > MAX_CONTROL_FRAME_PAYLOAD_SIZE as u64)
+        {
+            return Err(invalid_data("invalid control frame"));
+        }
+
+        if payload_length > self.max_frame_size as u64 {
+            return Err(invalid_data("frame exceeds the maximum size"));
+        }
+
+        let payload_length = payload_length as usize;
+        let mask_length = if masked { 4 } else { 0 };
+        if self.read_buffer.len() < header_length + mask_length + payload_length {
+            return Ok(None);
+        }
+
+        self.read_buffer.advance(header_length);
+        let mask = self.read_buffer.split_to(mask_length);
+        let mut payload = self.read_buffer.split_to(payload_length);
+        if masked {
+            for (index, byte) in payload.iter_mut().enumerate() {
+                *byte ^= mask[index % 4];
+            }
+        }
+
+        Ok(Some(Frame {
+            fin,
+            opcode,
+            payload,
+        }))
+    }
+
+    fn handle_frame(&mut self, frame: Frame) -> io::Result<()> {
+        match frame.opcode {
+            // The control frames can be interleaved with the fragments, but the messages can't.
+            OPCODE_BINARY if self.fragmented => {
+                return Err(invalid_data(
+                    "new message started before the previous one has ended",
+                ));
+            }
+            OPCODE_CONTINUATION if !self.fragmented => {
+                return Err(invalid_data(
+                    "continuation frame without a fragmented message",
+                ));
+            }
+            OPCODE_BINARY | OPCODE_CONTINUATION => {
+                self.fragmented = !frame.fin;
+                self.payload.unsplit(frame.payload);
+            }
+            OPCODE_PING => self.encode_frame(OPCODE_PONG, &frame.payload),
+            OPCODE_PONG => {}
+            OPCODE_CLOSE => {
+                self.close_received = true;
+                if !self.close_sent {
+                    self.close_sent = true;
+                    let status = frame.payload.get(..2).unwrap_or_default().to_vec();
+                    self.encode_frame(OPCODE_CLOSE, &status);
+                }
+            }
+            OPCODE_TEXT => return Err(invalid_data("text messages are not supported")),
+            _ => return Err(invalid_data("unknown opcode")),
+        }
+        Ok(())
+    }
+}
+
+impl<S: AsyncWrite + Unpin> WebSocketStream<S> {
+    fn poll_write_buffer(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while !self.write_buffer.is_empty() {
+            let written = ready!(Pin::new(&mut self.inner).poll_write(cx, &self.write_buffer))?;
+            if written == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            self.write_buffer.advance(written);
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    /// Writes all the encoded frames and flushes the underlying stream.
+    fn poll_send(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.poll_write_buffer(cx))?;
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> AsyncRead for WebSocketStream<S> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            if !this.payload.is_empty() {
+                let length = buf.remaining().min(this.payload.len());
+                buf.put_slice(&this.payload.split_to(length));
+                return Poll::Ready(Ok(()));
+            }
+
+            if this.close_received {
+                return Poll::Ready(Ok(()));
+            }
+
+            if let Some(frame) = this.decode_frame()? {
+                let is_control = frame.opcode >= OPCODE_CLOSE;
+                this.handle_frame(frame)?;
+                // The pong and close frames are sent without waiting for the next flush, which might never come
+                // on an idle connection. If the write would block, it's resumed with the next read or flush.
+                if is_control && let Poll::Ready(Err(error)) = this.poll_send(cx) {
+                    return Poll::Ready(Err(error));
+                }
+                continue;
+            }
+
+            let mut chunk = [0; READ_CHUNK_SIZE];
+            let mut chunk = ReadBuf::new(&mut chunk);
+            ready!(Pin::new(&mut this.inner).poll_read(cx, &mut chunk))?;
+            if chunk.filled().is_empty() {
+                return Poll::Ready(Ok(()));
+            }
+            this.read_buffer.extend_from_slice(chunk.filled());
+        }
+    }
+}
+
+impl<S: AsyncWrite + Unpin> AsyncWrite for WebSocketStream<S> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        // Small frames are coalesced until the flush, so a request written in several parts
+        // doesn't end up in several TCP segments delayed by the Nagle's algorithm.
+        if this.write_buffer.len() >= MAX_OUTGOING_FRAME_PAYLOAD_SIZE {
+            ready!(this.poll_write_buffer(cx))?;
+        }
+        if this.close_sent {
+            return Poll::Ready(Err(io::ErrorKind::BrokenPipe.into()));
+        }
+
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        let length = buf.len().min(MAX_OUTGOING_FRAME_PAYLOAD_SIZE);
+        this.encode_frame(OPCODE_BINARY, &buf[..length]);
+        Poll::Ready(Ok(length))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().poll_send(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if !this.close_sent {
+            this.close_sent = true;
+            this.encode_frame(OPCODE_CLOSE, &NORMAL_CLOSURE.to_be_bytes());
+        }
+        ready!(this.poll_write_buffer(cx))?;
+        Pin::new(&mut this.inner).poll_shutdown(cx)
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    const MAX_FRAME_SIZE: usize = 1024 * 1024;
+
+    #[test]
+    fn should_compute_accept_key() {
+        assert_eq!(
+            accept_key("dGhlIHNhbXBsZSBub25jZQ=="),
+            "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+        );
+    }
+
+    #[tokio::test]
+    async fn should_transfer_data_between_client_and_server() {
+        let (client, server) = tokio::io::duplex(1024);
+        let mut client = WebSocketStream::new(client, WebSocketRole::Client, MAX_FRAME_SIZE);
+        let mut server = WebSocketStream::new(server, WebSocketRole::Server, MAX_FRAME_SIZE);
+        let request = (0..200_000).map(|value| value as u8).collect::<Vec<_>>();
+        let expected_request = request.clone();
+
+        let server_task = tokio::spawn(async move {
+            let mut received = vec![0; expected_request.len()];
+            server.read_exact(&mut received).await.unwrap();
+            assert_eq!(received, expected_request);
+            server.write_all(b"response").await.unwrap();
+            server.flush().await.unwrap();
+            server
+        });
+
+        client.write_all(&request).await.unwrap();
+        client.flush().await.unwrap();
+        let mut response = [0; 8];
+        client.read_exact(&mut response).await.unwrap();
+        assert_eq!(&response, b"response");
+        server_task.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_answer_ping_and_close() {
+        let (mut client, server) = tokio::io::duplex(1024);
+        let mut server = WebSocketStream::new(server, WebSocketRole::Server, MAX_FRAME_SIZE);
+        let mask = [1, 2, 3, 4];
+        let ping = [
+            0x89,
+            0x80 | 2,
+            mask[0],
+            mask[1],
+            mask[2],
+            mask[3],
+            b'a' ^ 1,
+            b'b' ^ 2,
+        ];
+        let close = [0x88, 0x80, mask[0], mask[1], mask[2], mask[3]];
+        client.write_all(&ping).await.unwrap();
+        client.write_all(&close).await.unwrap();
+
+        let mut buffer = Vec::new();
+        server.read_to_end(&mut buffer).await.unwrap();
+        assert!(buffer.is_empty());
+
+        let mut frames = [0; 6];
+        client.read_exact(&mut frames).await.unwrap();
+        assert_eq!(frames, [0x8A, 2, b'a', b'b', 0x88, 0]);
+    }
+
+    #[tokio::test]
+    async fn should_answer_ping_on_idle_connection() {
+        let (mut client, server) = tokio::io::duplex(1024);
+        let mut server = WebSocketStream::new(server, WebSocketRole::Server, MAX_FRAME_SIZE);
+        let server_task = tokio::spawn(async move {
+            let mut buffer = [0; 1];
+            let _ = server.read(&mut buffer).await;
+        });
+
+        client.write_all(&[0x89, 0x80, 1, 2, 3, 4]).await.unwrap();
+        let mut pong = [0; 2];
+        tokio::time::timeout(Duration::from_secs(5), client.read_exact(&mut pong))
+            .await
+            .expect("The ping should be answered without the flush")
+            .unwrap();
+        assert_eq!(pong, [0x8A, 0]);
+        server_task.abort();
+    }
+
+    #[tokio::test]
+    async fn should_join_fragmented_message_interleaved_with_ping() {
+        let (mut client, server) = tokio::io::duplex(1024);
+        let mut server = WebSocketStream::new(server, WebSocketRole::Server, MAX_FRAME_SIZE);
+        let frames = [
+            &[0x02, 0x82, 0, 0, 0, 0, b'a', b'b'][..],
+            &[0x89, 0x80, 0, 0, 0, 0],
+            &[0x80, 0x81, 0, 0, 0, 0, b'c'],
+            &[0x82, 0x81, 0, 0, 0, 0, b'd'],
+        ]
+        .concat();
+        client.write_all(&frames).await.unwrap();
+
+        let mut message = [0; 4];
+        server.read_exact(&mut message).await.unwrap();
+        assert_eq!(&message, b"abcd");
+    }
+
+    #[tokio::test]
+    async fn should_fail_for_invalid_fragmentation() {
+        let invalid_frames = [
+            // The continuation frame without the preceding message.
+            [&[0x80, 0x81, 0, 0, 0, 0, b'a'][..]].concat(),
+            // The new message before the fragmented one has ended.
+            [
+                &[0x02, 0x81, 0, 0, 0, 0, b'a'][..],
+                &[0x82, 0x81, 0, 0, 0, 0, b'b'],
+            ]
+            .concat(),
+        ];
+        for frames in invalid_frames {
+            let (mut client, server) = tokio::io::duplex(1024);
+            let mut server = WebSocketStream::new(server, WebSocketRole::Server, MAX_FRAME_SIZE);
+            client.write_all(&frames).await.unwrap();
+
+            let mut buffer = [0; 2];
+            let result = server.read_exact(&mut buffer).await;
+            assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+        }
+    }
+
+    #[tokio::test]
+    async fn should_fail_for_unmasked_client_frame() {
+        let (mut client, server) = tokio::io::duplex(1024);
+        let mut server = WebSocketStream::new(server, WebSocketRole::Server, MAX_FRAME_SIZE);
+        client.write_all(&[0x82, 1, 0]).await.unwrap();
+
+        let mut buffer = [0; 1];
+        let result = server.read_exact(&mut buffer).await;
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+}
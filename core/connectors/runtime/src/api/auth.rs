@@ -26,9 +26,9 @@ use axum::{
 };
 use std::sync::Arc;
 
-const API_KEY_HEADER: &str = "api-key";
+pub const API_KEY_HEADER: &str = "api-key";
 
-const PUBLIC_PATHS: &[&str] = &["/", "/health"];
+const PUBLIC_PATHS: &[&str] = &["/", "/health", "/openapi.json"];
 
 pub async fn resolve_api_key(
     State(context): State<Arc<RuntimeContext>>,
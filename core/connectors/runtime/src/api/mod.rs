@@ -17,13 +17,17 @@
  */
 
 use crate::context::RuntimeContext;
-use auth::resolve_api_key;
-use axum::{Json, Router, middleware, routing::get};
+use auth::{API_KEY_HEADER, resolve_api_key};
+use axum::{Json, Router, http::header, middleware, routing::get};
 use axum_server::tls_rustls::RustlsConfig;
 use config::{HttpApiConfig, configure_cors};
+use error::ErrorResponse;
 use std::{net::SocketAddr, path::PathBuf, sync::Arc};
 use tokio::spawn;
 use tracing::{error, info};
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+use utoipa_axum::{router::OpenApiRouter, routes};
 
 mod auth;
 pub mod config;
@@ -33,6 +37,30 @@ mod sink;
 mod source;
 
 const NAME: &str = env!("CARGO_PKG_NAME");
+const OPENAPI_PATH: &str = "/openapi.json";
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Iggy Connectors Runtime API"),
+    modifiers(&SecurityAddon),
+    security(("api_key" = [])),
+    components(schemas(ErrorResponse))
+)]
+struct ApiDoc;
+
+/// Adds the API key authentication, required by all the operations, unless the key isn't
+/// configured or the operation overrides the security requirements.
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new(API_KEY_HEADER))),
+        );
+    }
+}
 
 pub async fn init(config: &HttpApiConfig, context: Arc<RuntimeContext>) {
     if !config.enabled {
@@ -40,14 +68,7 @@ pub async fn init(config: &HttpApiConfig, context: Arc<RuntimeContext>) {
         return;
     }
 
-    let mut app = Router::new()
-        .route("/", get(|| async { "Connector Runtime API" }))
-        .route(
-            "/health",
-            get(|| async { Json(serde_json::json!({ "status": "healthy" })) }),
-        )
-        .merge(sink::router(context.clone()))
-        .merge(source::router(context.clone()));
+    let mut app = router().with_state(context.clone());
 
     app = app.layer(middleware::from_fn_with_state(
         context.clone(),
@@ -108,3 +129,84 @@ pub async fn init(config: &HttpApiConfig, context: Arc<RuntimeContext>) {
         }
     });
 }
+
+/// Returns the router of the API, along with the route serving its OpenAPI document.
+fn router() -> Router<Arc<RuntimeContext>> {
+    let (router, openapi) = api_router().split_for_parts();
+    let openapi = openapi
+        .to_json()
+        .expect("Failed to serialize the OpenAPI document");
+    router.route(
+        OPENAPI_PATH,
+        get(|| async move { ([(header::CONTENT_TYPE, "application/json")], openapi) }),
+    )
+}
+
+fn api_router() -> OpenApiRouter<Arc<RuntimeContext>> {
+    OpenApiRouter::with_openapi(ApiDoc::openapi())
+        .routes(routes!(get_name))
+        .routes(routes!(get_health))
+        .merge(sink::router())
+        .merge(source::router())
+}
+
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "Runtime",
+    security(()),
+    responses((status = 200, description = "The name of the API.", body = String))
+)]
+async fn get_name() -> &'static str {
+    "Connector Runtime API"
+}
+
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "Runtime",
+    security(()),
+    responses((status = 200, description = "The runtime is healthy.", body = serde_json::Value))
+)]
+async fn get_health() -> Json<serde_json::Value> {
+    Json(serde_json::json!({ "status": "healthy" }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    /// Returns the paths registered in the router, which axum exposes only through its debug representation.
+    fn registered_paths<S>(router: &Router<S>) -> BTreeSet<String> {
+        let debug = format!("{router:?}");
+        let (path_router, _) = debug
+            .split_once("fallback_router")
+            .expect("Unexpected debug representation of the router");
+        path_router
+            .split("RouteId(")
+            .skip(1)
+            .filter_map(|entry| {
+                let (_, path) = entry.split_once("): \"")?;
+                Some(path[..path.find('"')?].to_owned())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_route_should_be_documented() {
+        let registered_paths = registered_paths(&router());
+        assert!(!registered_paths.is_empty());
+        let mut documented_paths = api_router()
+            .into_openapi()
+            .paths
+            .paths
+            .into_keys()
+            .collect::<BTreeSet<_>>();
+        documented_paths.insert(OPENAPI_PATH.to_owned());
+        assert_eq!(
+            registered_paths, documented_paths,
+            "every route must be registered with `routes!` to be documented"
+        );
+    }
+}
@@ -22,8 +22,9 @@ use crate::{
 };
 use iggy_connector_sdk::transforms::TransformType;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct SinkInfoResponse {
     pub id: u32,
     pub key: String,
@@ -34,14 +35,14 @@ pub struct SinkInfoResponse {
     pub config_format: Option<ConfigFormat>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct SinkDetailsResponse {
     #[serde(flatten)]
     pub info: SinkInfoResponse,
     pub streams: Vec<StreamConsumerConfig>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct SourceInfoResponse {
     pub id: u32,
     pub key: String,
@@ -52,15 +53,16 @@ pub struct SourceInfoResponse {
     pub config_format: Option<ConfigFormat>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct SourceDetailsResponse {
     #[serde(flatten)]
     pub info: SourceInfoResponse,
     pub streams: Vec<StreamProducerConfig>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct TransformResponse {
+    #[schema(value_type = String, example = "add_fields")]
     pub r#type: TransformType,
     pub config: serde_json::Value,
 }
@@ -23,24 +23,30 @@ use super::{
 };
 use crate::{configs::ConfigFormat, context::RuntimeContext, error::RuntimeError};
 use axum::{
-    Json, Router,
+    Json,
     extract::{Path, Query, State},
     http::{HeaderMap, StatusCode, header},
     response::IntoResponse,
-    routing::get,
 };
 use serde::Deserialize;
 use std::sync::Arc;
+use utoipa::IntoParams;
+use utoipa_axum::{router::OpenApiRouter, routes};
 
-pub fn router(state: Arc<RuntimeContext>) -> Router {
-    Router::new()
-        .route("/sinks", get(get_sinks))
-        .route("/sinks/{key}", get(get_sink))
-        .route("/sinks/{key}/config", get(get_sink_config))
-        .route("/sinks/{key}/transforms", get(get_sink_transforms))
-        .with_state(state)
+pub fn router() -> OpenApiRouter<Arc<RuntimeContext>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_sinks))
+        .routes(routes!(get_sink))
+        .routes(routes!(get_sink_config))
+        .routes(routes!(get_sink_transforms))
 }
 
+#[utoipa::path(
+    get,
+    path = "/sinks",
+    tag = "Sinks",
+    responses((status = 200, description = "The sinks.", body = Vec<SinkInfoResponse>))
+)]
 async fn get_sinks(
     State(context): State<Arc<RuntimeContext>>,
 ) -> Result<Json<Vec<SinkInfoResponse>>, ApiError> {
@@ -54,6 +60,13 @@ async fn get_sinks(
     Ok(Json(sinks))
 }
 
+#[utoipa::path(
+    get,
+    path = "/sinks/{key}",
+    tag = "Sinks",
+    params(("key" = String, Path, description = "The key of the sink.")),
+    responses((status = 200, description = "The details of the sink.", body = SinkDetailsResponse))
+)]
 async fn get_sink(
     State(context): State<Arc<RuntimeContext>>,
     Path(key): Path<String>,
@@ -68,6 +81,16 @@ async fn get_sink(
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/sinks/{key}/config",
+    tag = "Sinks",
+    params(("key" = String, Path, description = "The key of the sink."), GetSinkConfig),
+    responses(
+        (status = 200, description = "The configuration of the sink, in the requested format.", body = String),
+        (status = 404, description = "The sink has no configuration.")
+    )
+)]
 async fn get_sink_config(
     State(context): State<Arc<RuntimeContext>>,
     Path(key): Path<String>,
@@ -90,11 +113,21 @@ async fn get_sink_config(
     Ok((headers, config).into_response())
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 struct GetSinkConfig {
+    /// The format of the returned configuration, the configured one by default.
+    #[param(inline)]
     format: Option<ConfigFormat>,
 }
 
+#[utoipa::path(
+    get,
+    path = "/sinks/{key}/transforms",
+    tag = "Sinks",
+    params(("key" = String, Path, description = "The key of the sink.")),
+    responses((status = 200, description = "The transforms of the sink.", body = Vec<TransformResponse>))
+)]
 async fn get_sink_transforms(
     State(context): State<Arc<RuntimeContext>>,
     Path(key): Path<String>,
@@ -23,24 +23,30 @@ use super::{
 };
 use crate::{configs::ConfigFormat, context::RuntimeContext, error::RuntimeError};
 use axum::{
-    Json, Router,
+    Json,
     extract::{Path, Query, State},
     http::{HeaderMap, StatusCode, header},
     response::IntoResponse,
-    routing::get,
 };
 use serde::Deserialize;
 use std::sync::Arc;
+use utoipa::IntoParams;
+use utoipa_axum::{router::OpenApiRouter, routes};
 
-pub fn router(state: Arc<RuntimeContext>) -> Router {
-    Router::new()
-        .route("/sources", get(get_sources))
-        .route("/sources/{key}", get(get_source))
-        .route("/sources/{key}/config", get(get_source_config))
-        .route("/sources/{key}/transforms", get(get_source_transforms))
-        .with_state(state)
+pub fn router() -> OpenApiRouter<Arc<RuntimeContext>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_sources))
+        .routes(routes!(get_source))
+        .routes(routes!(get_source_config))
+        .routes(routes!(get_source_transforms))
 }
 
+#[utoipa::path(
+    get,
+    path = "/sources",
+    tag = "Sources",
+    responses((status = 200, description = "The sources.", body = Vec<SourceInfoResponse>))
+)]
 async fn get_sources(
     State(context): State<Arc<RuntimeContext>>,
 ) -> Result<Json<Vec<SourceInfoResponse>>, ApiError> {
@@ -54,6 +60,13 @@ async fn get_sources(
     Ok(Json(sources))
 }
 
+#[utoipa::path(
+    get,
+    path = "/sources/{key}",
+    tag = "Sources",
+    params(("key" = String, Path, description = "The key of the source.")),
+    responses((status = 200, description = "The details of the source.", body = SourceDetailsResponse))
+)]
 async fn get_source(
     State(context): State<Arc<RuntimeContext>>,
     Path(key): Path<String>,
@@ -68,6 +81,16 @@ async fn get_source(
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/sources/{key}/config",
+    tag = "Sources",
+    params(("key" = String, Path, description = "The key of the source."), GetSourceConfig),
+    responses(
+        (status = 200, description = "The configuration of the source, in the requested format.", body = String),
+        (status = 404, description = "The source has no configuration.")
+    )
+)]
 async fn get_source_config(
     State(context): State<Arc<RuntimeContext>>,
     Path(key): Path<String>,
@@ -90,11 +113,21 @@ async fn get_source_config(
     Ok((headers, config).into_response())
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 struct GetSourceConfig {
+    /// The format of the returned configuration, the configured one by default.
+    #[param(inline)]
     format: Option<ConfigFormat>,
 }
 
+#[utoipa::path(
+    get,
+    path = "/sources/{key}/transforms",
+    tag = "Sources",
+    params(("key" = String, Path, description = "The key of the source.")),
+    responses((status = 200, description = "The transforms of the source.", body = Vec<TransformResponse>))
+)]
 async fn get_source_transforms(
     State(context): State<Arc<RuntimeContext>>,
     Path(key): Path<String>,
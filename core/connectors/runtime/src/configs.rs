@@ -21,9 +21,21 @@ use iggy_connector_sdk::{Schema, transforms::TransformType};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use strum::Display;
+use utoipa::ToSchema;
 
 #[derive(
-    Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize, Display,
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Deserialize,
+    Serialize,
+    Display,
+    ToSchema,
 )]
 #[serde(rename_all = "lowercase")]
 pub enum ConfigFormat {
@@ -66,20 +78,22 @@ pub struct SinkConfig {
     pub config: Option<serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct StreamConsumerConfig {
     pub stream: String,
     pub topics: Vec<String>,
+    #[schema(value_type = String, example = "json")]
     pub schema: Schema,
     pub batch_length: Option<u32>,
     pub poll_interval: Option<String>,
     pub consumer_group: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct StreamProducerConfig {
     pub stream: String,
     pub topic: String,
+    #[schema(value_type = String, example = "json")]
     pub schema: Schema,
     pub batch_length: Option<u32>,
     pub linger_time: Option<String>,
@@ -29,3 +29,5 @@ pub mod test_mcp_server;
 #[allow(deprecated)]
 pub mod test_server;
 pub mod test_tls_utils;
+pub mod unix_client;
+pub mod websocket_client;
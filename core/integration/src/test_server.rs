@@ -39,6 +39,12 @@ use server::configs::config_provider::{ConfigProvider, FileConfigProvider};
 pub const SYSTEM_PATH_ENV_VAR: &str = "IGGY_SYSTEM_PATH";
 pub const TEST_VERBOSITY_ENV_VAR: &str = "IGGY_TEST_VERBOSE";
 pub const IPV6_ENV_VAR: &str = "IGGY_TCP_IPV6";
+pub const UNIX_ENABLED_ENV_VAR: &str = "IGGY_UNIX_ENABLED";
+pub const UNIX_PATH_ENV_VAR: &str = "IGGY_UNIX_PATH";
+pub const KAFKA_ENABLED_ENV_VAR: &str = "IGGY_KAFKA_ENABLED";
+pub const KAFKA_ADDRESS_ENV_VAR: &str = "IGGY_KAFKA_ADDRESS";
+pub const MQTT_ENABLED_ENV_VAR: &str = "IGGY_MQTT_ENABLED";
+pub const MQTT_ADDRESS_ENV_VAR: &str = "IGGY_MQTT_ADDRESS";
 const USER_PASSWORD: &str = "secret";
 const SLEEP_INTERVAL_MS: u64 = 20;
 const LOCAL_DATA_PREFIX: &str = "local_data_";
@@ -68,6 +74,12 @@ pub enum Transport {
 
     #[display("tcp")]
     Tcp,
+
+    #[display("unix")]
+    Unix,
+
+    #[display("websocket")]
+    WebSocket,
 }
 
 #[derive(Display, Debug)]
@@ -80,6 +92,12 @@ enum ServerProtocolAddr {
 
     #[display("QUIC_UDP:{_0}")]
     QuicUdp(SocketAddr),
+
+    #[display("KAFKA_TCP:{_0}")]
+    KafkaTcp(SocketAddr),
+
+    #[display("MQTT_TCP:{_0}")]
+    MqttTcp(SocketAddr),
 }
 
 #[derive(Debug)]
@@ -265,6 +283,10 @@ impl TestServer {
         if fs::metadata(&self.local_data_path).is_ok() {
             fs::remove_dir_all(&self.local_data_path).unwrap();
         }
+
+        if let Some(socket_path) = self.get_unix_socket_path() {
+            let _ = fs::remove_file(socket_path);
+        }
     }
 
     fn get_server_ipv4_addrs_with_random_port() -> Vec<ServerProtocolAddr> {
@@ -297,6 +319,12 @@ impl TestServer {
                 ServerProtocolAddr::QuicUdp(addr) => {
                     ("IGGY_QUIC_ADDRESS".to_string(), addr.to_string())
                 }
+                ServerProtocolAddr::KafkaTcp(addr) => {
+                    (KAFKA_ADDRESS_ENV_VAR.to_string(), addr.to_string())
+                }
+                ServerProtocolAddr::MqttTcp(addr) => {
+                    (MQTT_ADDRESS_ENV_VAR.to_string(), addr.to_string())
+                }
             };
 
             self.envs.entry(key.0).or_insert(key.1);
@@ -346,6 +374,18 @@ impl TestServer {
             self.server_addrs.push(ServerProtocolAddr::HttpTcp(
                 config.http.address.parse().unwrap(),
             ));
+
+            if config.kafka.enabled {
+                self.server_addrs.push(ServerProtocolAddr::KafkaTcp(
+                    config.kafka.address.parse().unwrap(),
+                ));
+            }
+
+            if config.mqtt.enabled {
+                self.server_addrs.push(ServerProtocolAddr::MqttTcp(
+                    config.mqtt.address.parse().unwrap(),
+                ));
+            }
         } else {
             panic!(
                 "Failed to load config from file {config_path} in {MAX_PORT_WAIT_DURATION_S} s!"
@@ -381,6 +421,19 @@ impl TestServer {
         format!("{}{}", LOCAL_DATA_PREFIX, Uuid::now_v7().to_u128_le())
     }
 
+    /// Returns a random path of the Unix domain socket, short enough to fit the platform limits.
+    pub fn get_random_unix_socket_path() -> String {
+        format!("/tmp/iggy_{}.sock", Uuid::now_v7().simple())
+    }
+
+    pub fn get_unix_socket_path(&self) -> Option<String> {
+        if self.envs.get(UNIX_ENABLED_ENV_VAR).map(String::as_str) != Some("true") {
+            return None;
+        }
+
+        self.envs.get(UNIX_PATH_ENV_VAR).cloned()
+    }
+
     pub fn get_http_api_addr(&self) -> Option<String> {
         for server_protocol_addr in &self.server_addrs {
             if let ServerProtocolAddr::HttpTcp(a) = server_protocol_addr {
@@ -408,6 +461,24 @@ impl TestServer {
         None
     }
 
+    pub fn get_kafka_addr(&self) -> Option<String> {
+        for server_protocol_addr in &self.server_addrs {
+            if let ServerProtocolAddr::KafkaTcp(a) = server_protocol_addr {
+                return Some(a.to_string());
+            }
+        }
+        None
+    }
+
+    pub fn get_mqtt_addr(&self) -> Option<String> {
+        for server_protocol_addr in &self.server_addrs {
+            if let ServerProtocolAddr::MqttTcp(a) = server_protocol_addr {
+                return Some(a.to_string());
+            }
+        }
+        None
+    }
+
     pub fn get_server_ip_addr(&self) -> Option<String> {
         if let Some(server_address) = self
             .get_raw_tcp_addr()
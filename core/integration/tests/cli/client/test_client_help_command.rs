@@ -35,6 +35,7 @@ pub async fn should_help_match() {
 Commands:
   get   Get details of a single client with given ID [aliases: g]
   list  List all currently connected clients to iggy server [aliases: l]
+  kill  Forcibly disconnect a client or all the clients of a user [aliases: k]
   help  Print this message or the help of the given subcommand(s)
 
 Options:
@@ -41,6 +41,7 @@ Commands:
   me               get current client info
   stats            get iggy server statistics
   snapshot         collect iggy server troubleshooting data
+  system           server administration operations
   pat              personal access token operations
   user             user operations [aliases: u]
   client           client operations [aliases: c]
@@ -22,4 +22,5 @@ mod test_stream_get_command;
 mod test_stream_help_command;
 mod test_stream_list_command;
 mod test_stream_purge_command;
+mod test_stream_retention_command;
 mod test_stream_update_command;
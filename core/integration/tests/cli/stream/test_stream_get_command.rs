@@ -80,7 +80,10 @@ impl IggyCmdTestCase for TestStreamGetCmd {
             .stdout(contains(format!("Stream name          | {}", self.name)))
             .stdout(contains("Stream size          | 0"))
             .stdout(contains("Stream message count | 0"))
-            .stdout(contains("Stream topics count  | 0"));
+            .stdout(contains("Stream topics count  | 0"))
+            .stdout(contains("Stream max size      | unlimited"))
+            .stdout(contains("Stream expiry        | never_expire"))
+            .stdout(contains("Stream size policy   | reject_sends"));
     }
 
     async fn verify_server_state(&self, _client: &dyn Client) {}
@@ -0,0 +1,252 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{
+    CLAP_INDENT, IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId,
+    USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::{Client, IggyExpiry, MaxTopicSize, StreamSizePolicy};
+use predicates::str::diff;
+use serial_test::parallel;
+use std::str::FromStr;
+
+struct TestStreamRetentionCmd {
+    stream_id: u32,
+    name: String,
+    max_size: MaxTopicSize,
+    message_expiry: Option<Vec<String>>,
+    size_policy: StreamSizePolicy,
+    using_identifier: TestStreamId,
+}
+
+impl TestStreamRetentionCmd {
+    fn new(
+        stream_id: u32,
+        name: String,
+        max_size: MaxTopicSize,
+        message_expiry: Option<Vec<String>>,
+        size_policy: StreamSizePolicy,
+        using_identifier: TestStreamId,
+    ) -> Self {
+        Self {
+            stream_id,
+            name,
+            max_size,
+            message_expiry,
+            size_policy,
+            using_identifier,
+        }
+    }
+
+    fn message_expiry(&self) -> IggyExpiry {
+        match &self.message_expiry {
+            Some(value) => IggyExpiry::from_str(&value.join(" ")).unwrap(),
+            None => IggyExpiry::NeverExpire,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut args = match self.using_identifier {
+            TestStreamId::Named => vec![self.name.clone()],
+            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
+        };
+
+        args.push("--max-size".to_string());
+        args.push(format!("{}", self.max_size));
+        args.push("--size-policy".to_string());
+        args.push(match self.size_policy {
+            StreamSizePolicy::RejectSends => "reject-sends".to_string(),
+            StreamSizePolicy::DeleteOldestSegments => "delete-oldest-segments".to_string(),
+        });
+        args.extend(self.message_expiry.clone().unwrap_or_default());
+
+        args
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestStreamRetentionCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client.create_stream(&self.name, Some(self.stream_id)).await;
+        assert!(stream.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("stream")
+            .arg("retention")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_identifier {
+            TestStreamId::Named => self.name.clone(),
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+        };
+        let message = format!(
+            "Executing update retention for stream with ID: {stream_id}, max size: {}, message expiry: {}, size policy: {}\nRetention for stream with ID: {stream_id} updated\n",
+            self.max_size,
+            self.message_expiry(),
+            self.size_policy
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let stream = client.get_stream(&self.stream_id.try_into().unwrap()).await;
+        assert!(stream.is_ok());
+        let stream = stream.unwrap().expect("Stream not found");
+        assert_eq!(stream.max_size, self.max_size);
+        assert_eq!(stream.message_expiry, self.message_expiry());
+        assert_eq!(stream.size_policy, self.size_policy);
+
+        let delete = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(delete.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestStreamRetentionCmd::new(
+            1,
+            String::from("testing"),
+            MaxTopicSize::from_str("10GB").unwrap(),
+            None,
+            StreamSizePolicy::RejectSends,
+            TestStreamId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestStreamRetentionCmd::new(
+            2,
+            String::from("production"),
+            MaxTopicSize::from_str("5GB").unwrap(),
+            Some(vec![String::from("7days")]),
+            StreamSizePolicy::DeleteOldestSegments,
+            TestStreamId::Named,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestStreamRetentionCmd::new(
+            3,
+            String::from("development"),
+            MaxTopicSize::Unlimited,
+            Some(vec![String::from("1day"), String::from("2h")]),
+            StreamSizePolicy::RejectSends,
+            TestStreamId::Numeric,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["stream", "retention", "--help"],
+            format!(
+                r#"Set storage limits for stream with given ID
+
+Max size is shared by all topics in the stream, message expiry is applied
+to the topics which do not define their own expiry.
+Stream ID can be specified as a stream name or ID
+
+Examples:
+ iggy stream retention 1 --max-size 10GB
+ iggy stream retention test -m 5GB -p delete-oldest-segments 7days
+ iggy stream retention test
+
+{USAGE_PREFIX} stream retention [OPTIONS] <STREAM_ID> [MESSAGE_EXPIRY]...
+
+Arguments:
+  <STREAM_ID>
+          Stream ID to update
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  [MESSAGE_EXPIRY]...
+          Default message expiry time in human-readable format like "unlimited" or "15days 2min 2s"
+{CLAP_INDENT}
+          Applied to the topics in the stream which do not define their own expiry time.
+{CLAP_INDENT}
+          [default: unlimited]
+
+Options:
+  -m, --max-size <MAX_SIZE>
+          Max stream size in human-readable format like "unlimited" or "15GB"
+{CLAP_INDENT}
+          Can't be lower than segment size in the config.
+{CLAP_INDENT}
+          [default: unlimited]
+
+  -p, --size-policy <SIZE_POLICY>
+          Policy applied when the stream exceeds its max size
+{CLAP_INDENT}
+          [default: reject-sends]
+
+          Possible values:
+          - reject-sends:           Reject new messages until the stream size drops below the max size
+          - delete-oldest-segments: Delete the oldest segments across all topics in the stream
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["stream", "retention", "-h"],
+            format!(
+                r#"Set storage limits for stream with given ID
+
+{USAGE_PREFIX} stream retention [OPTIONS] <STREAM_ID> [MESSAGE_EXPIRY]...
+
+Arguments:
+  <STREAM_ID>          Stream ID to update
+  [MESSAGE_EXPIRY]...  Default message expiry time in human-readable format like "unlimited" or "15days 2min 2s" [default: unlimited]
+
+Options:
+  -m, --max-size <MAX_SIZE>        Max stream size in human-readable format like "unlimited" or "15GB" [default: unlimited]
+  -p, --size-policy <SIZE_POLICY>  Policy applied when the stream exceeds its max size [default: reject-sends] [possible values: reject-sends, delete-oldest-segments]
+  -h, --help                       Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
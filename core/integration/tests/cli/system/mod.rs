@@ -20,6 +20,8 @@
 // due to missing keyring support while running tests under cross
 #[cfg(not(any(target_os = "macos", target_env = "musl")))]
 mod test_cli_session_scenario;
+mod test_health_command;
+mod test_log_level_command;
 #[cfg(not(any(target_os = "macos", target_env = "musl")))]
 mod test_login_cmd;
 mod test_login_command;
@@ -29,5 +31,7 @@ mod test_logout_command;
 #[cfg(not(any(target_os = "macos", target_env = "musl")))]
 mod test_me_command;
 mod test_ping_command;
+mod test_profile_command;
 mod test_snapshot_cmd;
 mod test_stats_command;
+mod test_stats_history_command;
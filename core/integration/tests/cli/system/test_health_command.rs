@@ -0,0 +1,101 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::{Client, HealthProbe};
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestHealthCmd {
+    probe: HealthProbe,
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestHealthCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("system")
+            .arg("health")
+            .arg("--probe")
+            .arg(self.probe.to_string())
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let command_state = command_state
+            .success()
+            .stdout(starts_with(format!(
+                "Executing check server {}\n",
+                self.probe
+            )))
+            .stdout(contains(format!(
+                "Server {} check passed, status: up",
+                self.probe
+            )));
+        if self.probe == HealthProbe::Readiness {
+            command_state
+                .stdout(contains("partitions"))
+                .stdout(contains("persister"));
+        }
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {}
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestHealthCmd {
+            probe: HealthProbe::Liveness,
+        })
+        .await;
+    iggy_cmd_test
+        .execute_test(TestHealthCmd {
+            probe: HealthProbe::Readiness,
+        })
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["system", "health", "-h"],
+            format!(
+                r#"Check the server health
+
+{USAGE_PREFIX} system health [OPTIONS]
+
+Options:
+  -p, --probe <PROBE>  Kind of the probe, either liveness or readiness [default: liveness]
+  -h, --help           Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,117 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::Client;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+const FILTER: &str = "info,server::streaming=debug";
+
+enum TestLogLevelCmd {
+    Get,
+    Set,
+    Reset,
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestLogLevelCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        let command = IggyCmdCommand::new()
+            .arg("system")
+            .arg("log-level")
+            .with_env_credentials();
+        match self {
+            TestLogLevelCmd::Get => command,
+            TestLogLevelCmd::Set => command.arg(FILTER).arg("--ttl").arg("10m"),
+            TestLogLevelCmd::Reset => command.arg("--reset"),
+        }
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        match self {
+            TestLogLevelCmd::Get => command_state
+                .success()
+                .stdout(starts_with("Executing get server log level\n"))
+                .stdout(contains("Default log level:")),
+            TestLogLevelCmd::Set => command_state
+                .success()
+                .stdout(starts_with(format!(
+                    "Executing set server log level to: {FILTER} for: 10m\n"
+                )))
+                .stdout(contains(format!("Log level: {FILTER}")))
+                .stdout(contains("Reverts to default at:")),
+            TestLogLevelCmd::Reset => command_state
+                .success()
+                .stdout(starts_with("Executing reset server log level\n"))
+                .stdout(contains(format!("Log level: {FILTER}")).not()),
+        };
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let log_filter = client.get_log_filter().await.unwrap();
+        match self {
+            TestLogLevelCmd::Set => assert_eq!(log_filter.filter, FILTER),
+            TestLogLevelCmd::Get | TestLogLevelCmd::Reset => {
+                assert_eq!(log_filter.filter, log_filter.default_filter)
+            }
+        }
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test.execute_test(TestLogLevelCmd::Get).await;
+    iggy_cmd_test.execute_test(TestLogLevelCmd::Set).await;
+    iggy_cmd_test.execute_test(TestLogLevelCmd::Reset).await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["system", "log-level", "-h"],
+            format!(
+                r#"Get or change the server log level
+
+{USAGE_PREFIX} system log-level [OPTIONS] [FILTER]
+
+Arguments:
+  [FILTER]  Log filter in the RUST_LOG format, e.g. debug or info,server::streaming=trace
+
+Options:
+  -t, --ttl <TTL>  Time after which the default log filter is restored, e.g. 10m or 1h
+  -r, --reset      Restore the default log filter
+  -h, --help       Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
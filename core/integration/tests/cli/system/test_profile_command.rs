@@ -0,0 +1,115 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::Client;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::fs;
+use tempfile::tempdir;
+
+struct TestProfileCmd {
+    temp_out_dir: String,
+}
+
+impl TestProfileCmd {
+    fn new(temp_out_dir: String) -> Self {
+        TestProfileCmd { temp_out_dir }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestProfileCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("system")
+            .arg("profile")
+            .arg("--duration")
+            .arg("1s")
+            .arg("--out-dir")
+            .arg(self.temp_out_dir.as_str())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state
+            .success()
+            .stdout(starts_with(
+                "Executing profile command, capturing pprof CPU profile for 1s\n",
+            ))
+            .stdout(contains("File Path"));
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {}
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    let temp_out_dir = tempdir().unwrap();
+    iggy_cmd_test
+        .execute_test(TestProfileCmd::new(
+            temp_out_dir.path().to_str().unwrap().to_string(),
+        ))
+        .await;
+
+    let profile_file = fs::read_dir(&temp_out_dir)
+        .unwrap()
+        .filter_map(Result::ok)
+        .find(|entry| {
+            let file_name = entry.file_name();
+            let file_name = file_name.to_string_lossy();
+            file_name.starts_with("cpu_profile") && file_name.ends_with(".pb")
+        })
+        .unwrap();
+
+    let contents = fs::read(profile_file.path()).unwrap();
+    assert!(!contents.is_empty());
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["system", "profile", "-h"],
+            format!(
+                r#"Capture the server profile
+
+{USAGE_PREFIX} system profile [OPTIONS]
+
+Options:
+  -k, --kind <KIND>          Kind of the profile, either cpu or heap [default: cpu]
+  -f, --format <FORMAT>      Format of the CPU profile, either pprof or flamegraph [default: pprof]
+  -d, --duration <DURATION>  Time during which the CPU profile is sampled, from 1s to 5m [default: 10s]
+  -o, --out-dir <OUT_DIR>    Output directory for the profile file
+  -h, --help                 Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
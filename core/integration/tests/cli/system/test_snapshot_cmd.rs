@@ -151,6 +151,8 @@ Options:
           - `test`: Used for testing purposes.
           - `server_logs`: Server logs from the specified logging directory, useful for system diagnostics.
           - `server_config`: Server configuration.
+          - `clients`: Connected clients with their identification metadata.
+          - `cpu_profile`: CPU profile flamegraph, sampled for 5 seconds, not included in `all`.
           - `all`: Take all available snapshots.
 {CLAP_INDENT}
           Examples:
@@ -0,0 +1,101 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::Client;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestStatsHistoryCmd {
+    json: bool,
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestStatsHistoryCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        let command = IggyCmdCommand::new()
+            .arg("system")
+            .arg("stats-history")
+            .with_env_credentials();
+        if self.json {
+            command.arg("--json")
+        } else {
+            command
+        }
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let command_state = command_state
+            .success()
+            .stdout(starts_with("Executing stats history command\n"));
+        if self.json {
+            command_state
+                .stdout(contains("\"series\""))
+                .stdout(contains("\"samples\""));
+        } else {
+            command_state
+                .stdout(contains("Sampled every"))
+                .stdout(contains("CPU Usage"))
+                .stdout(contains("Appended Messages"));
+        }
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let stats_history = client.get_stats_history().await.unwrap();
+        assert_eq!(stats_history.series.len(), 2);
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestStatsHistoryCmd { json: false })
+        .await;
+    iggy_cmd_test
+        .execute_test(TestStatsHistoryCmd { json: true })
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["system", "stats-history", "-h"],
+            format!(
+                r#"Get the server stats history
+
+{USAGE_PREFIX} system stats-history [OPTIONS]
+
+Options:
+  -j, --json  Print the raw stats history as JSON instead of the sparklines
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,193 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestUserId, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::prelude::Client;
+use iggy::prelude::ThroughputQuota;
+use iggy::prelude::UserId;
+use iggy::prelude::UserStatus;
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestUserQuotaCmd {
+    username: String,
+    quota_args: Vec<String>,
+    expected_quota: Option<ThroughputQuota>,
+    using_identifier: TestUserId,
+    user_id: Option<UserId>,
+}
+
+impl TestUserQuotaCmd {
+    fn new(
+        username: String,
+        quota_args: Vec<String>,
+        expected_quota: Option<ThroughputQuota>,
+        using_identifier: TestUserId,
+    ) -> Self {
+        Self {
+            username,
+            quota_args,
+            expected_quota,
+            using_identifier,
+            user_id: None,
+        }
+    }
+
+    fn user_identifier(&self) -> String {
+        match self.using_identifier {
+            TestUserId::Named => self.username.clone(),
+            TestUserId::Numeric => format!("{}", self.user_id.unwrap()),
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut args = vec![self.user_identifier()];
+        args.extend(self.quota_args.clone());
+        args
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestUserQuotaCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let create_user = client
+            .create_user(&self.username, "secret", UserStatus::Active, None)
+            .await;
+        assert!(create_user.is_ok());
+        let user = client
+            .get_user(&self.username.clone().try_into().unwrap())
+            .await;
+        assert!(user.is_ok());
+        let user = user.unwrap().expect("User not found");
+        self.user_id = Some(user.id);
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("user")
+            .arg("quota")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let user_id = self.user_identifier();
+        let message = format!(
+            "Executing update quota for user with ID: {user_id}\nQuota for user with ID: {user_id} updated\n"
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let user = client
+            .get_user(&self.username.clone().try_into().unwrap())
+            .await;
+        assert!(user.is_ok());
+        let user = user.unwrap().expect("User not found");
+        assert_eq!(user.quota, self.expected_quota);
+
+        let delete_user = client
+            .delete_user(&self.username.clone().try_into().unwrap())
+            .await;
+        assert!(delete_user.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestUserQuotaCmd::new(
+            String::from("producer"),
+            vec![
+                String::from("--produce-bytes"),
+                String::from("10MB"),
+                String::from("--produce-messages"),
+                String::from("1000"),
+            ],
+            Some(ThroughputQuota {
+                produce_bytes_per_second: 10_000_000,
+                produce_messages_per_second: 1000,
+                ..Default::default()
+            }),
+            TestUserId::Named,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserQuotaCmd::new(
+            String::from("consumer"),
+            vec![String::from("--consume-messages"), String::from("500")],
+            Some(ThroughputQuota {
+                consume_messages_per_second: 500,
+                ..Default::default()
+            }),
+            TestUserId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserQuotaCmd::new(
+            String::from("unlimited"),
+            vec![],
+            None,
+            TestUserId::Named,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "quota", "-h"],
+            format!(
+                r#"Set throughput quota for user or personal access token with given ID
+
+{USAGE_PREFIX} user quota [OPTIONS] <USER_ID>
+
+Arguments:
+  <USER_ID>  User ID to update
+
+Options:
+  -t, --token <TOKEN>
+          Name of the personal access token to update
+      --produce-bytes <PRODUCE_BYTES>
+          Maximum number of bytes produced per second (0 means unlimited)
+      --produce-messages <PRODUCE_MESSAGES>
+          Maximum number of messages produced per second (0 means unlimited)
+      --consume-bytes <CONSUME_BYTES>
+          Maximum number of bytes consumed per second (0 means unlimited)
+      --consume-messages <CONSUME_MESSAGES>
+          Maximum number of messages consumed per second (0 means unlimited)
+  -h, --help
+          Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
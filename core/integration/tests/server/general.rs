@@ -17,20 +17,29 @@
 
 use crate::server::{
     ScenarioFn, bench_scenario, create_message_payload_scenario, message_headers_scenario,
-    run_scenario, stream_size_validation_scenario, system_scenario, user_scenario,
+    run_scenario, stream_retention_scenario, stream_size_validation_scenario, system_scenario,
+    throughput_quota_scenario, user_scenario,
 };
 use integration::test_server::Transport;
 use serial_test::parallel;
 use test_case::test_matrix;
 
 #[test_matrix(
-    [Transport::Tcp, Transport::Quic, Transport::Http],
+    [
+        Transport::Tcp,
+        Transport::Quic,
+        Transport::Http,
+        Transport::Unix,
+        Transport::WebSocket
+    ],
     [
         system_scenario(),
         user_scenario(),
         message_headers_scenario(),
         create_message_payload_scenario(),
         stream_size_validation_scenario(),
+        stream_retention_scenario(),
+        throughput_quota_scenario(),
         bench_scenario(),
     ]
 )]
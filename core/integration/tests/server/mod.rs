@@ -25,14 +25,20 @@ use integration::{
     http_client::HttpClientFactory,
     quic_client::QuicClientFactory,
     tcp_client::TcpClientFactory,
-    test_server::{ClientFactory, TestServer, Transport},
+    test_server::{
+        ClientFactory, IpAddrKind, TestServer, Transport, UNIX_ENABLED_ENV_VAR, UNIX_PATH_ENV_VAR,
+    },
+    unix_client::UnixClientFactory,
+    websocket_client::WebSocketClientFactory,
 };
 use scenarios::{
     bench_scenario, consumer_group_join_scenario,
     consumer_group_with_multiple_clients_polling_messages_scenario,
     consumer_group_with_single_client_polling_messages_scenario, create_message_payload,
-    message_headers_scenario, stream_size_validation_scenario, system_scenario, user_scenario,
+    message_headers_scenario, stream_retention_scenario, stream_size_validation_scenario,
+    system_scenario, throughput_quota_scenario, user_scenario,
 };
+use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
 
@@ -70,12 +76,31 @@ fn multiple_clients_scenario() -> ScenarioFn {
     |factory| Box::pin(consumer_group_with_multiple_clients_polling_messages_scenario::run(factory))
 }
 
+fn stream_retention_scenario() -> ScenarioFn {
+    |factory| Box::pin(stream_retention_scenario::run(factory))
+}
+
+fn throughput_quota_scenario() -> ScenarioFn {
+    |factory| Box::pin(throughput_quota_scenario::run(factory))
+}
+
 fn bench_scenario() -> ScenarioFn {
     |factory| Box::pin(bench_scenario::run(factory))
 }
 
 async fn run_scenario(transport: Transport, scenario: ScenarioFn) {
-    let mut test_server = TestServer::default();
+    let mut test_server = match transport {
+        Transport::Unix => {
+            let mut extra_envs = HashMap::new();
+            extra_envs.insert(UNIX_ENABLED_ENV_VAR.to_string(), "true".to_string());
+            extra_envs.insert(
+                UNIX_PATH_ENV_VAR.to_string(),
+                TestServer::get_random_unix_socket_path(),
+            );
+            TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4)
+        }
+        _ => TestServer::default(),
+    };
     test_server.start();
 
     let client_factory: Box<dyn ClientFactory> = match transport {
@@ -94,6 +119,14 @@ async fn run_scenario(transport: Transport, scenario: ScenarioFn) {
             let server_addr = test_server.get_http_api_addr().unwrap();
             Box::new(HttpClientFactory { server_addr })
         }
+        Transport::Unix => {
+            let socket_path = test_server.get_unix_socket_path().unwrap();
+            Box::new(UnixClientFactory { socket_path })
+        }
+        Transport::WebSocket => {
+            let server_addr = test_server.get_http_api_addr().unwrap();
+            Box::new(WebSocketClientFactory { server_addr })
+        }
     };
 
     scenario(&*client_factory).await;
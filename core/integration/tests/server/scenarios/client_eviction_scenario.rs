@@ -0,0 +1,146 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, login_root};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+pub const VERIFICATION_INTERVAL: &str = "100ms";
+pub const IDLE_TIMEOUT: Duration = Duration::from_secs(3);
+pub const PARTIAL_FRAME_TIMEOUT: Duration = Duration::from_millis(500);
+pub const LAGGING_CONSUMER_CHECKS: u32 = 3;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "client-eviction-stream";
+const TOPIC_NAME: &str = "client-eviction-topic";
+const CONSUMER_GROUP_NAME: &str = "client-eviction-group";
+
+pub async fn run(client_factory: &dyn ClientFactory, server_addr: &str, http_api_addr: &str) {
+    let client = IggyClient::create(client_factory.create_client().await, None, None);
+    login_root(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let group_id = Identifier::named(CONSUMER_GROUP_NAME).unwrap();
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::None,
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+    client
+        .create_consumer_group(&stream_id, &topic_id, CONSUMER_GROUP_NAME, None)
+        .await
+        .unwrap();
+
+    // 1. The consumer which doesn't poll the messages, while they keep being appended, leaves its group.
+    let consumer = IggyClient::create(client_factory.create_client().await, None, None);
+    login_root(&consumer).await;
+    consumer
+        .join_consumer_group(&stream_id, &topic_id, &group_id)
+        .await
+        .unwrap();
+    let mut left = false;
+    for _ in 0..50 {
+        let mut messages = vec![
+            IggyMessage::builder()
+                .payload(Bytes::from("message"))
+                .build()
+                .unwrap(),
+        ];
+        client
+            .send_messages(
+                &stream_id,
+                &topic_id,
+                &Partitioning::partition_id(1),
+                &mut messages,
+            )
+            .await
+            .unwrap();
+        let consumer_group = client
+            .get_consumer_group(&stream_id, &topic_id, &group_id)
+            .await
+            .unwrap()
+            .unwrap();
+        if consumer_group.members_count == 0 {
+            left = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert!(left, "Lagging consumer hasn't been removed from its group.");
+    client.delete_stream(&stream_id).await.unwrap();
+
+    // 2. The connection which has sent only the length of the request is closed.
+    let started_at = Instant::now();
+    let mut stream = TcpStream::connect(server_addr).await.unwrap();
+    stream.write_all(&100u32.to_le_bytes()).await.unwrap();
+    assert_connection_closed(&mut stream).await;
+    assert!(started_at.elapsed() >= PARTIAL_FRAME_TIMEOUT);
+    assert!(started_at.elapsed() < IDLE_TIMEOUT);
+
+    // 3. The connection which hasn't sent any request is closed.
+    let started_at = Instant::now();
+    let mut stream = TcpStream::connect(server_addr).await.unwrap();
+    assert_connection_closed(&mut stream).await;
+    assert!(started_at.elapsed() >= IDLE_TIMEOUT);
+
+    // 4. All the slow clients are counted in the metrics.
+    let metrics = reqwest::get(format!("http://{http_api_addr}/metrics"))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    for (reason, action) in [
+        ("lagging_consumer", "leave_consumer_group"),
+        ("partial_frame", "disconnect"),
+        ("idle", "disconnect"),
+    ] {
+        let series = format!("slow_clients_total{{reason=\"{reason}\",action=\"{action}\"}} ");
+        let count = metrics
+            .lines()
+            .find_map(|line| line.strip_prefix(&series))
+            .unwrap_or_else(|| panic!("Missing metric: {series}"))
+            .trim()
+            .parse::<u64>()
+            .unwrap();
+        assert!(count >= 1);
+    }
+}
+
+async fn assert_connection_closed(stream: &mut TcpStream) {
+    let mut buffer = [0u8; 1];
+    let read = tokio::time::timeout(IDLE_TIMEOUT * 2, stream.read(&mut buffer))
+        .await
+        .expect("Connection hasn't been closed by the server.");
+    assert!(matches!(read, Ok(0) | Err(_)));
+}
@@ -0,0 +1,104 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, TestServer, login_root};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+pub const INITIAL_CONFIG: &str = r#"
+[heartbeat]
+interval = "5 s"
+
+[message_saver]
+interval = "30 s"
+
+[tcp.socket]
+keepalive = false
+"#;
+
+const CHANGED_CONFIG: &str = r#"
+[heartbeat]
+interval = "10 s"
+
+[message_saver]
+interval = "45 s"
+
+[tcp.socket]
+keepalive = true
+"#;
+
+const CHANGED_AGAIN_CONFIG: &str = r#"
+[heartbeat]
+interval = "15 s"
+
+[message_saver]
+interval = "45 s"
+
+[tcp.socket]
+keepalive = true
+"#;
+
+const INVALID_CONFIG: &str = r#"
+[heartbeat]
+interval = "not a duration"
+"#;
+
+pub async fn run(client_factory: &dyn ClientFactory, test_server: &TestServer, config_path: &Path) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, None, None);
+
+    // 1. Reloading is allowed only for the authenticated users.
+    let result = client.reload_config().await;
+    assert!(result.is_err());
+
+    login_root(&client).await;
+
+    // 2. Reloading the unchanged config applies nothing.
+    let reload = client.reload_config().await.unwrap();
+    assert!(reload.is_unchanged());
+
+    // 3. The live settings are applied, the others are reported as requiring a restart.
+    fs::write(config_path, CHANGED_CONFIG).unwrap();
+    let reload = client.reload_config().await.unwrap();
+    assert_eq!(
+        reload.applied,
+        vec!["heartbeat.interval", "message_saver.interval"]
+    );
+    assert_eq!(reload.restart_required, vec!["tcp.socket.keepalive"]);
+
+    // 4. The invalid config is rejected and the running config is kept.
+    fs::write(config_path, INVALID_CONFIG).unwrap();
+    let result = client.reload_config().await;
+    assert!(result.is_err());
+
+    // 5. SIGHUP reloads the config as well, so the next reload finds nothing to apply,
+    // apart from the setting which still requires a restart.
+    #[cfg(unix)]
+    {
+        fs::write(config_path, CHANGED_AGAIN_CONFIG).unwrap();
+        unsafe {
+            libc::kill(test_server.pid() as libc::pid_t, libc::SIGHUP);
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        let reload = client.reload_config().await.unwrap();
+        assert!(reload.applied.is_empty());
+        assert_eq!(reload.restart_required, vec!["tcp.socket.keepalive"]);
+    }
+}
@@ -0,0 +1,158 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::test_server::{assert_clean_system, login_root};
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-frame-compression-stream";
+const TOPIC_NAME: &str = "test-frame-compression-topic";
+const MESSAGES_COUNT: u32 = 100;
+const PAYLOAD_SIZE: usize = 4096;
+
+pub async fn run(client: &IggyClient, http_api_addr: &str, algorithm: FrameCompressionAlgorithm) {
+    login_root(client).await;
+
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::None,
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+
+    // 1. Send the compressible messages, so that the request frames exceed the minimum size.
+    let mut messages = (1..=MESSAGES_COUNT)
+        .map(|id| {
+            IggyMessage::builder()
+                .id(id as u128)
+                .payload(create_payload(id))
+                .build()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(1),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+
+    // 2. Poll the messages, the response frame is compressed as well and must be restored intact.
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(1),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    for (index, message) in polled_messages.messages.iter().enumerate() {
+        let id = index as u32 + 1;
+        assert_eq!(message.header.id, id as u128);
+        assert_eq!(message.payload, create_payload(id));
+    }
+
+    // 3. Small frames, such as this one, are sent uncompressed on the same connection.
+    let stream = client.get_stream(&stream_id).await.unwrap().unwrap();
+    assert_eq!(stream.name, STREAM_NAME);
+
+    // 4. Validate that the server has recorded the compression of both, the requests and the responses.
+    let metrics = reqwest::get(format!("http://{http_api_addr}/metrics"))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    for direction in ["request", "response"] {
+        let compressed_bytes = get_metric(
+            &metrics,
+            "frame_compression_compressed_bytes_total",
+            algorithm,
+            direction,
+        );
+        let uncompressed_bytes = get_metric(
+            &metrics,
+            "frame_compression_uncompressed_bytes_total",
+            algorithm,
+            direction,
+        );
+        assert!(compressed_bytes > 0);
+        assert!(compressed_bytes < uncompressed_bytes);
+        assert!(
+            get_metric(
+                &metrics,
+                "frame_compression_ratio_percent",
+                algorithm,
+                direction
+            ) < 100
+        );
+    }
+
+    client.delete_stream(&stream_id).await.unwrap();
+    assert_clean_system(client).await;
+}
+
+fn create_payload(id: u32) -> Bytes {
+    Bytes::from(
+        format!("message-{id} ")
+            .repeat(PAYLOAD_SIZE)
+            .into_bytes()
+            .into_iter()
+            .take(PAYLOAD_SIZE)
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn get_metric(
+    metrics: &str,
+    name: &str,
+    algorithm: FrameCompressionAlgorithm,
+    direction: &str,
+) -> u64 {
+    let labels = format!("{name}{{algorithm=\"{algorithm}\",direction=\"{direction}\"}} ");
+    metrics
+        .lines()
+        .find_map(|line| line.strip_prefix(&labels))
+        .unwrap_or_else(|| panic!("Missing metric: {labels}"))
+        .trim()
+        .parse()
+        .unwrap()
+}
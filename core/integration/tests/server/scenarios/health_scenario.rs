@@ -0,0 +1,96 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::prelude::*;
+use reqwest::StatusCode;
+
+const READINESS_CHECKS: &[&str] = &[
+    "startup",
+    "state",
+    "partitions",
+    "index_rebuilding",
+    "disk",
+    "memory_pool",
+    "persister",
+];
+
+pub async fn run(client: &IggyClient, http_api_addr: &str) {
+    // 1. The probes don't require the authentication.
+    let health = client.get_health(HealthProbe::Liveness).await.unwrap();
+    assert_eq!(health.status, HealthStatus::Up);
+    assert!(health.checks.is_empty());
+
+    let health = client.get_health(HealthProbe::Readiness).await.unwrap();
+    assert_eq!(health.status, HealthStatus::Up);
+    assert_readiness_checks(&health);
+
+    // 2. The same probes are served by the HTTP API.
+    let (status, health) = get_health(http_api_addr, "live").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(health.status, HealthStatus::Up);
+
+    let (status, health) = get_health(http_api_addr, "ready").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(health.status, HealthStatus::Up);
+    assert_readiness_checks(&health);
+}
+
+/// Validates the readiness of the server, which requires more free disk space than available.
+pub async fn run_with_insufficient_disk_space(client: &IggyClient, http_api_addr: &str) {
+    let health = client.get_health(HealthProbe::Liveness).await.unwrap();
+    assert_eq!(health.status, HealthStatus::Up);
+
+    let health = client.get_health(HealthProbe::Readiness).await.unwrap();
+    assert_eq!(health.status, HealthStatus::Down);
+    assert_check_status(&health, "disk", HealthStatus::Down);
+    assert_check_status(&health, "state", HealthStatus::Up);
+
+    let (status, health) = get_health(http_api_addr, "ready").await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(health.status, HealthStatus::Down);
+    assert_check_status(&health, "disk", HealthStatus::Down);
+}
+
+fn assert_readiness_checks(health: &Health) {
+    let names = health
+        .checks
+        .iter()
+        .map(|check| check.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, READINESS_CHECKS);
+    for check in &health.checks {
+        assert_eq!(check.status, HealthStatus::Up, "{}", check.details);
+    }
+}
+
+fn assert_check_status(health: &Health, name: &str, status: HealthStatus) {
+    let check = health
+        .checks
+        .iter()
+        .find(|check| check.name == name)
+        .unwrap_or_else(|| panic!("Missing health check: {name}"));
+    assert_eq!(check.status, status, "{}", check.details);
+}
+
+async fn get_health(http_api_addr: &str, probe: &str) -> (StatusCode, Health) {
+    let response = reqwest::get(format!("http://{http_api_addr}/health/{probe}"))
+        .await
+        .unwrap();
+    let status = response.status();
+    (status, response.json().await.unwrap())
+}
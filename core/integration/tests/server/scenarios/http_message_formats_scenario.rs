@@ -0,0 +1,245 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::login_http_root;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, login_root};
+use reqwest::StatusCode;
+use std::collections::HashMap;
+
+const STREAM_NAME: &str = "formats-stream";
+const TOPIC_NAME: &str = "formats-topic";
+const PARTITION_ID: u32 = 1;
+const OCTET_STREAM: &str = "application/octet-stream";
+const BATCH: &str = "application/vnd.iggy.batch";
+const NDJSON: &str = "application/x-ndjson";
+const PROTOBUF_PAYLOAD: &[u8] = &[0x08, 0x96, 0x01, 0x12, 0x00, 0xff];
+
+pub async fn run(http_api_addr: &str, client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, None, None);
+    login_root(&client).await;
+    client.create_stream(STREAM_NAME, None).await.unwrap();
+    client
+        .create_topic(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+
+    let http = reqwest::Client::new();
+    let api_url = format!("http://{http_api_addr}");
+    let token = login_http_root(&http, &api_url).await;
+    let messages_url = format!("{api_url}/streams/{STREAM_NAME}/topics/{TOPIC_NAME}/messages");
+
+    // 1. The single message is sent as the raw payload, with the metadata in the headers.
+    let response = http
+        .post(&messages_url)
+        .bearer_auth(&token)
+        .header("content-type", OCTET_STREAM)
+        .header("iggy-partition-id", PARTITION_ID.to_string())
+        .header("iggy-message-id", "42")
+        .header("Iggy-Header-Content-Type", "application/protobuf")
+        .body(PROTOBUF_PAYLOAD)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    // 2. The batch of the length-prefixed messages is sent, with the binary user headers.
+    let user_headers = HashMap::from([(
+        HeaderKey::new("source").unwrap(),
+        HeaderValue::from_uint32(7).unwrap(),
+    )]);
+    let mut batch = BytesMut::new();
+    put_batch_message(&mut batch, 0, None, b"second");
+    put_batch_message(&mut batch, 3, Some(&user_headers), b"third");
+    let response = http
+        .post(&messages_url)
+        .bearer_auth(&token)
+        .header("content-type", BATCH)
+        .header("iggy-partition-id", PARTITION_ID.to_string())
+        .body(batch.freeze())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    // 3. The single message is polled as the raw payload, with the metadata in the headers.
+    let poll_url =
+        format!("{messages_url}?partition_id={PARTITION_ID}&kind=offset&value=0&count=10");
+    let response = http
+        .get(&poll_url)
+        .bearer_auth(&token)
+        .header("accept", OCTET_STREAM)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let headers = response.headers().clone();
+    assert_eq!(headers["content-type"], OCTET_STREAM);
+    assert_eq!(
+        headers["iggy-partition-id"],
+        PARTITION_ID.to_string().as_str()
+    );
+    assert_eq!(headers["iggy-offset"], "0");
+    assert_eq!(headers["iggy-message-id"], "42");
+    assert_eq!(headers["iggy-current-offset"], "2");
+    assert_eq!(headers["iggy-header-content-type"], "application/protobuf");
+    assert_eq!(response.bytes().await.unwrap(), PROTOBUF_PAYLOAD);
+
+    // 4. The messages are polled as the binary batch, which preserves the kinds of the user headers.
+    let response = http
+        .get(&poll_url)
+        .bearer_auth(&token)
+        .header("accept", BATCH)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["iggy-count"], "3");
+    let mut batch = response.bytes().await.unwrap();
+    let first = get_batch_message(&mut batch);
+    assert_eq!((first.0, first.1), (42, 0));
+    assert_eq!(first.3, PROTOBUF_PAYLOAD);
+    let second = get_batch_message(&mut batch);
+    assert_ne!(second.0, 0);
+    assert_eq!(second.1, 1);
+    assert!(second.2.is_none());
+    assert_eq!(second.3, "second");
+    let third = get_batch_message(&mut batch);
+    assert_eq!((third.0, third.1), (3, 2));
+    assert_eq!(third.2, Some(user_headers));
+    assert_eq!(third.3, "third");
+    assert!(!batch.has_remaining());
+
+    // 5. The messages are polled as the newline-delimited JSON.
+    let response = http
+        .get(&poll_url)
+        .bearer_auth(&token)
+        .header("accept", format!("{NDJSON}, application/json;q=0.5"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], NDJSON);
+    let body = response.text().await.unwrap();
+    let messages = body
+        .lines()
+        .map(|line| serde_json::from_str::<IggyMessage>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(messages.len(), 3);
+    assert_eq!(messages[2].payload, "third");
+
+    // 6. There's no content, if there's no message to return as the raw payload.
+    let response = http
+        .get(format!(
+            "{messages_url}?partition_id={PARTITION_ID}&kind=offset&value=3"
+        ))
+        .bearer_auth(&token)
+        .header("accept", OCTET_STREAM)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    // 7. The JSON is still the default format.
+    let response = http
+        .get(&poll_url)
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    let polled_messages = response.json::<PolledMessages>().await.unwrap();
+    assert_eq!(polled_messages.count, 3);
+    assert_eq!(polled_messages.messages[0].payload, PROTOBUF_PAYLOAD);
+
+    // 8. The unsupported formats and the invalid batches are rejected.
+    let response = http
+        .get(&poll_url)
+        .bearer_auth(&token)
+        .header("accept", "text/html")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+    let response = http
+        .post(&messages_url)
+        .bearer_auth(&token)
+        .header("content-type", "text/plain")
+        .body("test")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    let response = http
+        .post(&messages_url)
+        .bearer_auth(&token)
+        .header("content-type", BATCH)
+        .body(vec![1, 2, 3])
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    client
+        .delete_stream(&Identifier::named(STREAM_NAME).unwrap())
+        .await
+        .unwrap();
+}
+
+fn put_batch_message(
+    batch: &mut BytesMut,
+    id: u128,
+    user_headers: Option<&HashMap<HeaderKey, HeaderValue>>,
+    payload: &[u8],
+) {
+    let user_headers = user_headers
+        .map(|headers| headers.to_bytes())
+        .unwrap_or_default();
+    batch.put_u128_le(id);
+    batch.put_u64_le(0);
+    batch.put_u64_le(0);
+    batch.put_u32_le(user_headers.len() as u32);
+    batch.put_u32_le(payload.len() as u32);
+    batch.put_slice(&user_headers);
+    batch.put_slice(payload);
+}
+
+/// Returns the ID, offset, user headers and payload of the message in the batch.
+fn get_batch_message(
+    batch: &mut Bytes,
+) -> (u128, u64, Option<HashMap<HeaderKey, HeaderValue>>, Bytes) {
+    let id = batch.get_u128_le();
+    let offset = batch.get_u64_le();
+    let _timestamp = batch.get_u64_le();
+    let user_headers_length = batch.get_u32_le() as usize;
+    let payload_length = batch.get_u32_le() as usize;
+    let user_headers = batch.split_to(user_headers_length);
+    let user_headers =
+        (!user_headers.is_empty()).then(|| HashMap::from_bytes(user_headers).unwrap());
+    (id, offset, user_headers, batch.split_to(payload_length))
+}
@@ -0,0 +1,411 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{login_http, login_http_root};
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, login_root};
+use reqwest::StatusCode;
+use std::str::FromStr;
+use std::time::Duration;
+
+const STREAM_NAME: &str = "sse-stream";
+const TOPIC_NAME: &str = "events";
+const CONSUMER_GROUP_NAME: &str = "sse-group";
+const PARTITIONS_COUNT: u32 = 2;
+const PARTITION_ID: u32 = 1;
+const USERNAME: &str = "sse-user";
+const PASSWORD: &str = "secret";
+const RECEIVE_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug)]
+struct SseEvent {
+    id: Option<String>,
+    event: Option<String>,
+    data: String,
+}
+
+/// Reads the Server-Sent Events from the chunked response body.
+struct SseReader {
+    response: reqwest::Response,
+    buffer: String,
+}
+
+impl SseReader {
+    fn new(response: reqwest::Response) -> Self {
+        Self {
+            response,
+            buffer: String::new(),
+        }
+    }
+
+    /// Returns the next event, skipping the keep alive comments.
+    async fn next(&mut self) -> SseEvent {
+        loop {
+            if let Some(end) = self.buffer.find("\n\n") {
+                let block = self.buffer[..end].to_owned();
+                self.buffer.drain(..end + 2);
+                let mut event = SseEvent {
+                    id: None,
+                    event: None,
+                    data: String::new(),
+                };
+                for line in block.lines() {
+                    let Some((field, value)) = line.split_once(':') else {
+                        continue;
+                    };
+                    let value = value.strip_prefix(' ').unwrap_or(value).to_owned();
+                    match field {
+                        "id" => event.id = Some(value),
+                        "event" => event.event = Some(value),
+                        "data" => event.data.push_str(&value),
+                        _ => {}
+                    }
+                }
+                if event.event.is_none() && event.data.is_empty() {
+                    continue;
+                }
+                return event;
+            }
+
+            let chunk = tokio::time::timeout(RECEIVE_TIMEOUT, self.response.chunk())
+                .await
+                .expect("Timed out waiting for the event")
+                .unwrap()
+                .expect("The event stream has ended");
+            self.buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+    }
+
+    async fn next_error(&mut self) -> String {
+        let event = self.next().await;
+        assert_eq!(event.event.as_deref(), Some("error"));
+        event.data
+    }
+
+    /// Verifies that the server has ended the stream, there can only be the keep alive comments left.
+    async fn assert_ended(&mut self) {
+        loop {
+            assert!(
+                self.buffer
+                    .lines()
+                    .all(|line| line.is_empty() || line.starts_with(':'))
+            );
+            let chunk = tokio::time::timeout(RECEIVE_TIMEOUT, self.response.chunk())
+                .await
+                .expect("Timed out waiting for the end of the stream")
+                .unwrap();
+            let Some(chunk) = chunk else {
+                return;
+            };
+            self.buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+    }
+
+    async fn next_message(&mut self) -> (Option<String>, IggyMessage) {
+        let event = self.next().await;
+        assert_eq!(event.event.as_deref(), Some("message"));
+        (event.id, serde_json::from_str(&event.data).unwrap())
+    }
+}
+
+pub async fn run(http_api_addr: &str, client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, None, None);
+    login_root(&client).await;
+    init_system(&client).await;
+    send_messages(&client, 0..3).await;
+
+    let http = reqwest::Client::new();
+    let api_url = format!("http://{http_api_addr}");
+    let token = login_http_root(&http, &api_url).await;
+    let stream_url = format!("{api_url}/streams/{STREAM_NAME}/topics/{TOPIC_NAME}/messages/stream");
+
+    // 1. The partition is streamed from the requested offset.
+    let response = http
+        .get(format!(
+            "{stream_url}?partition_id={PARTITION_ID}&kind=offset&value=0&count=2"
+        ))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"].to_str().unwrap(),
+        "text/event-stream"
+    );
+    let mut events = SseReader::new(response);
+    for offset in 0..3 {
+        let (id, message) = events.next_message().await;
+        assert_eq!(id, Some(offset.to_string()));
+        assert_eq!(message.header.offset, offset);
+        assert_eq!(message.payload, payload(offset));
+    }
+
+    // 2. The messages appended later are pushed to the open stream.
+    send_messages(&client, 3..5).await;
+    for offset in 3..5 {
+        let (id, message) = events.next_message().await;
+        assert_eq!(id, Some(offset.to_string()));
+        assert_eq!(message.payload, payload(offset));
+    }
+    drop(events);
+
+    // 3. The stream is resumed after the offset of the last received message.
+    let response = http
+        .get(format!("{stream_url}?partition_id={PARTITION_ID}"))
+        .bearer_auth(&token)
+        .header("Last-Event-ID", "3")
+        .send()
+        .await
+        .unwrap();
+    let mut events = SseReader::new(response);
+    let (id, message) = events.next_message().await;
+    assert_eq!(id, Some("4".to_owned()));
+    assert_eq!(message.payload, payload(4));
+    drop(events);
+
+    // 4. The consumer group member joins the group for the lifetime of the connection.
+    let response = http
+        .get(format!(
+            "{stream_url}?consumer_kind=consumer_group&id={CONSUMER_GROUP_NAME}"
+        ))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut events = SseReader::new(response);
+    for offset in 0..5 {
+        let (id, message) = events.next_message().await;
+        assert!(id.is_none());
+        assert_eq!(message.payload, payload(offset));
+    }
+    let consumer_group = get_consumer_group(&client).await;
+    assert_eq!(consumer_group.members_count, 1);
+    let clients = client.get_clients().await.unwrap();
+    assert!(clients.iter().any(|client| client.transport == "HTTP"
+        && client.authentication_method.as_deref() == Some("JsonWebToken")
+        && client.consumer_groups_count == 1));
+
+    // 5. The member leaves the group, once the connection is closed.
+    drop(events);
+    let mut members_count = 1;
+    for _ in 0..50 {
+        members_count = get_consumer_group(&client).await.members_count;
+        if members_count == 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(members_count, 0);
+
+    // 6. The offsets are committed automatically, so the rejoining member receives only the new messages.
+    send_messages(&client, 5..6).await;
+    let response = http
+        .get(format!(
+            "{stream_url}?consumer_kind=consumer_group&id={CONSUMER_GROUP_NAME}"
+        ))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    let mut events = SseReader::new(response);
+    let (_, message) = events.next_message().await;
+    assert_eq!(message.payload, payload(5));
+    drop(events);
+
+    // 7. The invalid requests are rejected before streaming.
+    let response = http
+        .get(format!(
+            "{api_url}/streams/{STREAM_NAME}/topics/unknown/messages/stream?partition_id=1"
+        ))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = http
+        .get(format!("{stream_url}?partition_id={PARTITION_ID}"))
+        .bearer_auth(&token)
+        .header("Last-Event-ID", "invalid")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = http
+        .get(format!("{stream_url}?partition_id={PARTITION_ID}"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response = http
+        .get(format!("{stream_url}?partition_id={PARTITION_ID}"))
+        .bearer_auth(&token)
+        .header("Last-Event-ID", u64::MAX.to_string())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // 8. The stream of the consumer group member ends, once its client has been disconnected.
+    let response = http
+        .get(format!(
+            "{stream_url}?consumer_kind=consumer_group&id={CONSUMER_GROUP_NAME}"
+        ))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    let mut events = SseReader::new(response);
+    let member = client
+        .get_clients()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|client| client.transport == "HTTP" && client.consumer_groups_count == 1)
+        .unwrap();
+    client.disconnect_client(member.client_id).await.unwrap();
+    assert_eq!(events.next_error().await, "client has been disconnected");
+    events.assert_ended().await;
+
+    // 9. The stream ends, once its access token has been revoked by logging out.
+    let logout_token = login_http_root(&http, &api_url).await;
+    let response = http
+        .get(format!("{stream_url}?partition_id={PARTITION_ID}"))
+        .bearer_auth(&logout_token)
+        .send()
+        .await
+        .unwrap();
+    let mut events = SseReader::new(response);
+    for offset in 0..6 {
+        let (_, message) = events.next_message().await;
+        assert_eq!(message.payload, payload(offset));
+    }
+    let response = http
+        .delete(format!("{api_url}/users/logout"))
+        .bearer_auth(&logout_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(events.next_error().await, "access token has been revoked");
+    events.assert_ended().await;
+
+    // 10. The streams of the user end, once the sessions of the user have been revoked.
+    client
+        .create_user(
+            USERNAME,
+            PASSWORD,
+            UserStatus::Active,
+            Some(Permissions {
+                global: GlobalPermissions {
+                    read_streams: true,
+                    poll_messages: true,
+                    ..Default::default()
+                },
+                streams: None,
+            }),
+        )
+        .await
+        .unwrap();
+    let user_token = login_http(&http, &api_url, USERNAME, PASSWORD).await;
+    let response = http
+        .get(format!(
+            "{stream_url}?partition_id={PARTITION_ID}&kind=offset&value=6"
+        ))
+        .bearer_auth(&user_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut events = SseReader::new(response);
+    client
+        .revoke_user_sessions(&Identifier::named(USERNAME).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(events.next_error().await, "access token has been revoked");
+    events.assert_ended().await;
+    client
+        .delete_user(&Identifier::named(USERNAME).unwrap())
+        .await
+        .unwrap();
+
+    client
+        .delete_stream(&Identifier::named(STREAM_NAME).unwrap())
+        .await
+        .unwrap();
+}
+
+async fn init_system(client: &IggyClient) {
+    client.create_stream(STREAM_NAME, None).await.unwrap();
+    client
+        .create_topic(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+    client
+        .create_consumer_group(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            CONSUMER_GROUP_NAME,
+            None,
+        )
+        .await
+        .unwrap();
+}
+
+fn payload(offset: u64) -> Bytes {
+    Bytes::from(format!("event-{offset}"))
+}
+
+async fn send_messages(client: &IggyClient, offsets: std::ops::Range<u64>) {
+    let mut messages = offsets
+        .map(|offset| IggyMessage::from_str(&format!("event-{offset}")).unwrap())
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
+
+async fn get_consumer_group(client: &IggyClient) -> ConsumerGroupDetails {
+    client
+        .get_consumer_group(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &Identifier::named(TOPIC_NAME).unwrap(),
+            &Identifier::named(CONSUMER_GROUP_NAME).unwrap(),
+        )
+        .await
+        .unwrap()
+        .unwrap()
+}
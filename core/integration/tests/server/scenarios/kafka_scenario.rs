@@ -0,0 +1,896 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, login_root};
+use server::kafka::codec::{KafkaReader, KafkaWriter};
+use server::kafka::records::{self, KafkaHeader, KafkaRecord};
+use std::str::FromStr;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+pub const DEFAULT_STREAM: &str = "kafka-stream";
+const TOPIC_NAME: &str = "orders";
+const PREFIXED_STREAM_NAME: &str = "payments";
+const PREFIXED_TOPIC_NAME: &str = "events";
+const PARTITIONS_COUNT: u32 = 2;
+const GROUP_ID: &str = "kafka-group";
+
+const PRODUCE: i16 = 0;
+const FETCH: i16 = 1;
+const LIST_OFFSETS: i16 = 2;
+const METADATA: i16 = 3;
+const OFFSET_COMMIT: i16 = 8;
+const OFFSET_FETCH: i16 = 9;
+const FIND_COORDINATOR: i16 = 10;
+const JOIN_GROUP: i16 = 11;
+const HEARTBEAT: i16 = 12;
+const LEAVE_GROUP: i16 = 13;
+const SYNC_GROUP: i16 = 14;
+const SASL_HANDSHAKE: i16 = 17;
+const API_VERSIONS: i16 = 18;
+const SASL_AUTHENTICATE: i16 = 36;
+
+const NO_ERROR: i16 = 0;
+const UNKNOWN_TOPIC_OR_PARTITION: i16 = 3;
+const SASL_AUTHENTICATION_FAILED: i16 = 58;
+
+/// Minimal Kafka client, which sends the requests one by one over a plain TCP connection.
+struct KafkaClient {
+    stream: TcpStream,
+    correlation_id: i32,
+}
+
+impl KafkaClient {
+    async fn connect(server_addr: &str) -> Self {
+        Self {
+            stream: TcpStream::connect(server_addr).await.unwrap(),
+            correlation_id: 0,
+        }
+    }
+
+    async fn authenticate(server_addr: &str, username: &str, password: &str) -> Self {
+        let mut client = Self::connect(server_addr).await;
+        let mut request = KafkaWriter::new();
+        request.write_string("PLAIN");
+        let mut response = client.send(SASL_HANDSHAKE, 1, request).await;
+        assert_eq!(response.read_i16().unwrap(), NO_ERROR);
+
+        let mut request = KafkaWriter::new();
+        request.write_bytes(format!("\0{username}\0{password}").as_bytes());
+        let mut response = client.send(SASL_AUTHENTICATE, 1, request).await;
+        assert_eq!(response.read_i16().unwrap(), NO_ERROR);
+        client
+    }
+
+    async fn send(&mut self, api_key: i16, version: i16, body: KafkaWriter) -> KafkaReader {
+        self.write(api_key, version, body).await;
+        self.read().await.expect("Kafka connection has been closed")
+    }
+
+    async fn write(&mut self, api_key: i16, version: i16, body: KafkaWriter) {
+        self.correlation_id += 1;
+        let body = body.into_bytes();
+        let mut request = KafkaWriter::new();
+        request.write_i32(body.len() as i32 + 10 + "kafka-test".len() as i32);
+        request.write_i16(api_key);
+        request.write_i16(version);
+        request.write_i32(self.correlation_id);
+        request.write_nullable_string(Some("kafka-test"));
+        request.write_raw(&body);
+        self.stream.write_all(&request.into_bytes()).await.unwrap();
+    }
+
+    async fn read(&mut self) -> Option<KafkaReader> {
+        let size = self.stream.read_i32().await.ok()?;
+        let mut buffer = vec![0; size as usize];
+        self.stream.read_exact(&mut buffer).await.unwrap();
+        let mut response = KafkaReader::new(Bytes::from(buffer));
+        assert_eq!(response.read_i32().unwrap(), self.correlation_id);
+        Some(response)
+    }
+}
+
+pub async fn run(server_addr: &str, client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, None, None);
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. The supported versions are returned before the authentication.
+    let mut kafka = KafkaClient::connect(server_addr).await;
+    let mut response = kafka.send(API_VERSIONS, 2, KafkaWriter::new()).await;
+    assert_eq!(response.read_i16().unwrap(), NO_ERROR);
+    let versions = response
+        .read_array(|reader| Ok((reader.read_i16()?, reader.read_i16()?, reader.read_i16()?)))
+        .unwrap();
+    assert!(versions.contains(&(PRODUCE, 3, 7)));
+    assert!(versions.contains(&(FETCH, 4, 7)));
+
+    // 2. Any other request is rejected by closing the connection, until the client is authenticated.
+    kafka
+        .write(METADATA, 5, metadata_request(&[TOPIC_NAME]))
+        .await;
+    assert!(kafka.read().await.is_none());
+
+    // 3. The invalid credentials are rejected.
+    let mut kafka = KafkaClient::connect(server_addr).await;
+    let mut request = KafkaWriter::new();
+    request.write_string("PLAIN");
+    kafka.send(SASL_HANDSHAKE, 1, request).await;
+    let mut request = KafkaWriter::new();
+    request.write_bytes(format!("\0{DEFAULT_ROOT_USERNAME}\0invalid").as_bytes());
+    let mut response = kafka.send(SASL_AUTHENTICATE, 1, request).await;
+    assert_eq!(response.read_i16().unwrap(), SASL_AUTHENTICATION_FAILED);
+
+    // 4. The topics are mapped to the default stream, or to the stream given as the prefix.
+    let mut kafka =
+        KafkaClient::authenticate(server_addr, DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD).await;
+    let prefixed_topic = format!("{PREFIXED_STREAM_NAME}.{PREFIXED_TOPIC_NAME}");
+    let mut response = kafka
+        .send(
+            METADATA,
+            5,
+            metadata_request(&[TOPIC_NAME, &prefixed_topic, "missing"]),
+        )
+        .await;
+    let (broker_host, broker_port, topics) = read_metadata(&mut response);
+    assert_eq!(format!("{broker_host}:{broker_port}"), server_addr);
+    assert_eq!(
+        topics,
+        vec![
+            (NO_ERROR, TOPIC_NAME.to_owned(), PARTITIONS_COUNT as usize),
+            (NO_ERROR, prefixed_topic.clone(), 1),
+            (UNKNOWN_TOPIC_OR_PARTITION, "missing".to_owned(), 0),
+        ]
+    );
+
+    // 5. The records are appended to the partition, the offsets are the same as in Iggy.
+    let first_records = (0..3).map(kafka_record).collect::<Vec<_>>();
+    assert_eq!(
+        produce(&mut kafka, TOPIC_NAME, 0, &first_records).await,
+        (NO_ERROR, 0)
+    );
+    let second_records = (3..5).map(kafka_record).collect::<Vec<_>>();
+    assert_eq!(
+        produce(&mut kafka, TOPIC_NAME, 0, &second_records).await,
+        (NO_ERROR, 3)
+    );
+    assert_eq!(
+        produce(&mut kafka, "missing", 0, &first_records).await,
+        (UNKNOWN_TOPIC_OR_PARTITION, -1)
+    );
+
+    // 6. The records are fetched with the keys, values and headers.
+    let (error, high_watermark, fetched) = fetch(&mut kafka, TOPIC_NAME, 0, 1).await;
+    assert_eq!(error, NO_ERROR);
+    assert_eq!(high_watermark, 5);
+    assert_eq!(fetched.len(), 4);
+    for (index, record) in fetched.iter().enumerate() {
+        let expected = kafka_record(index as i64 + 1);
+        assert_eq!(record.offset, index as i64 + 1);
+        assert_eq!(record.key, expected.key);
+        assert_eq!(record.value, expected.value);
+        assert_eq!(record.headers, expected.headers);
+        assert_eq!(record.timestamp, expected.timestamp);
+    }
+
+    // 7. The messages produced via Kafka are available to the Iggy clients, and vice versa.
+    let stream_id = Identifier::named(DEFAULT_STREAM).unwrap();
+    let topic_id = Identifier::named(TOPIC_NAME).unwrap();
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(1),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            10,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 5);
+    let message = &polled_messages.messages[0];
+    assert_eq!(message.payload, Bytes::from("value-0"));
+    let headers = message.user_headers_map().unwrap().unwrap();
+    assert_eq!(
+        headers[&HeaderKey::new("kafka-key").unwrap()]
+            .as_raw()
+            .unwrap(),
+        b"key-0"
+    );
+
+    let mut messages = vec![IggyMessage::from_str("iggy-message").unwrap()];
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(2),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+    let (error, high_watermark, fetched) = fetch(&mut kafka, TOPIC_NAME, 1, 0).await;
+    assert_eq!((error, high_watermark, fetched.len()), (NO_ERROR, 1, 1));
+    assert_eq!(fetched[0].value, Some(Bytes::from("iggy-message")));
+    assert_eq!(fetched[0].key, None);
+
+    // 8. The earliest and the latest offsets are listed.
+    assert_eq!(list_offset(&mut kafka, TOPIC_NAME, 0, -2).await, 0);
+    assert_eq!(list_offset(&mut kafka, TOPIC_NAME, 0, -1).await, 5);
+    assert_eq!(list_offset(&mut kafka, TOPIC_NAME, 1, -1).await, 1);
+
+    // 9. The broker is the coordinator of the consumer group.
+    let mut request = KafkaWriter::new();
+    request.write_string(GROUP_ID);
+    request.write_i8(0);
+    let mut response = kafka.send(FIND_COORDINATOR, 2, request).await;
+    assert_eq!(response.read_i32().unwrap(), 0);
+    assert_eq!(response.read_i16().unwrap(), NO_ERROR);
+    response.read_nullable_string().unwrap();
+    assert_eq!(response.read_i32().unwrap(), 0);
+    let coordinator_host = response.read_string().unwrap();
+    let coordinator_port = response.read_i32().unwrap();
+    assert_eq!(
+        format!("{coordinator_host}:{coordinator_port}"),
+        server_addr
+    );
+
+    // 10. The single member joins the group, becomes the leader and receives its assignment.
+    let mut request = KafkaWriter::new();
+    request.write_string(GROUP_ID);
+    request.write_i32(10_000);
+    request.write_i32(10_000);
+    request.write_string("");
+    request.write_nullable_string(None);
+    request.write_string("consumer");
+    request.write_array(&["range"], |writer, protocol| {
+        writer.write_string(protocol);
+        writer.write_bytes(b"metadata");
+    });
+    let mut response = kafka.send(JOIN_GROUP, 5, request).await;
+    assert_eq!(response.read_i32().unwrap(), 0);
+    assert_eq!(response.read_i16().unwrap(), NO_ERROR);
+    let generation_id = response.read_i32().unwrap();
+    assert_eq!(response.read_string().unwrap(), "range");
+    let leader_id = response.read_string().unwrap();
+    let member_id = response.read_string().unwrap();
+    assert_eq!(leader_id, member_id);
+    assert!(member_id.starts_with("kafka-test-"));
+    let members = response
+        .read_array(|reader| {
+            let member_id = reader.read_string()?;
+            reader.read_nullable_string()?;
+            Ok((member_id, reader.read_bytes()?))
+        })
+        .unwrap();
+    assert_eq!(
+        members,
+        vec![(member_id.clone(), Bytes::from_static(b"metadata"))]
+    );
+
+    let mut request = group_request(generation_id, &member_id);
+    request.write_array(std::slice::from_ref(&member_id), |writer, member_id| {
+        writer.write_string(member_id);
+        writer.write_bytes(b"assignment");
+    });
+    let mut response = kafka.send(SYNC_GROUP, 3, request).await;
+    assert_eq!(response.read_i32().unwrap(), 0);
+    assert_eq!(response.read_i16().unwrap(), NO_ERROR);
+    assert_eq!(
+        response.read_bytes().unwrap(),
+        Bytes::from_static(b"assignment")
+    );
+
+    let mut response = kafka
+        .send(HEARTBEAT, 3, group_request(generation_id, &member_id))
+        .await;
+    assert_eq!(response.read_i32().unwrap(), 0);
+    assert_eq!(response.read_i16().unwrap(), NO_ERROR);
+
+    // 11. The committed offsets are stored as the offsets of the Iggy consumer named after the group.
+    let mut request = KafkaWriter::new();
+    request.write_string(GROUP_ID);
+    request.write_i32(generation_id);
+    request.write_string(&member_id);
+    request.write_array(&[TOPIC_NAME], |writer, topic| {
+        writer.write_string(topic);
+        writer.write_array(&[(0, 3i64)], |writer, (index, offset)| {
+            writer.write_i32(*index);
+            writer.write_i64(*offset);
+            writer.write_i32(-1);
+            writer.write_nullable_string(None);
+        });
+    });
+    let mut response = kafka.send(OFFSET_COMMIT, 6, request).await;
+    assert_eq!(response.read_i32().unwrap(), 0);
+    let errors = read_partition_errors(&mut response);
+    assert_eq!(errors, vec![(0, NO_ERROR)]);
+
+    let offset = client
+        .get_consumer_offset(
+            &Consumer::new(Identifier::named(GROUP_ID).unwrap()),
+            &stream_id,
+            &topic_id,
+            Some(1),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(offset.stored_offset, 2);
+
+    let mut request = KafkaWriter::new();
+    request.write_string(GROUP_ID);
+    request.write_array(&[TOPIC_NAME], |writer, topic| {
+        writer.write_string(topic);
+        writer.write_array(&[0, 1], |writer, index| writer.write_i32(*index));
+    });
+    let mut response = kafka.send(OFFSET_FETCH, 5, request).await;
+    assert_eq!(response.read_i32().unwrap(), 0);
+    let offsets = response
+        .read_array(|reader| {
+            reader.read_string()?;
+            reader.read_array(|reader| {
+                let index = reader.read_i32()?;
+                let offset = reader.read_i64()?;
+                reader.read_i32()?;
+                reader.read_nullable_string()?;
+                Ok((index, offset, reader.read_i16()?))
+            })
+        })
+        .unwrap();
+    assert_eq!(offsets, vec![vec![(0, 3, NO_ERROR), (1, -1, NO_ERROR)]]);
+
+    // 12. The member leaves the group, so the offsets can be committed without the generation.
+    let mut request = KafkaWriter::new();
+    request.write_string(GROUP_ID);
+    request.write_string(&member_id);
+    let mut response = kafka.send(LEAVE_GROUP, 2, request).await;
+    assert_eq!(response.read_i32().unwrap(), 0);
+    assert_eq!(response.read_i16().unwrap(), NO_ERROR);
+
+    // 13. The requests and the record batch laid out by hand following the protocol guide are handled as well.
+    run_spec_fixtures(server_addr).await;
+
+    cleanup_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    for (stream_name, topic_name, partitions_count) in [
+        (DEFAULT_STREAM, TOPIC_NAME, PARTITIONS_COUNT),
+        (PREFIXED_STREAM_NAME, PREFIXED_TOPIC_NAME, 1),
+    ] {
+        client.create_stream(stream_name, None).await.unwrap();
+        client
+            .create_topic(
+                &Identifier::named(stream_name).unwrap(),
+                topic_name,
+                partitions_count,
+                CompressionAlgorithm::default(),
+                None,
+                None,
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+            )
+            .await
+            .unwrap();
+    }
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    for stream_name in [DEFAULT_STREAM, PREFIXED_STREAM_NAME] {
+        client
+            .delete_stream(&Identifier::named(stream_name).unwrap())
+            .await
+            .unwrap();
+    }
+}
+
+fn kafka_record(index: i64) -> KafkaRecord {
+    KafkaRecord {
+        offset: index,
+        timestamp: 1_700_000_000_000 + index,
+        key: Some(Bytes::from(format!("key-{index}"))),
+        value: Some(Bytes::from(format!("value-{index}"))),
+        headers: vec![KafkaHeader {
+            key: "source".to_owned(),
+            value: Some(Bytes::from(format!("header-{index}"))),
+        }],
+    }
+}
+
+fn metadata_request(topics: &[&str]) -> KafkaWriter {
+    let mut request = KafkaWriter::new();
+    request.write_array(topics, |writer, topic| writer.write_string(topic));
+    request.write_bool(false);
+    request
+}
+
+/// Returns the broker host and port, and the error, name and partitions count of each topic.
+fn read_metadata(response: &mut KafkaReader) -> (String, i32, Vec<(i16, String, usize)>) {
+    response.read_i32().unwrap();
+    let brokers = response
+        .read_array(|reader| {
+            reader.read_i32()?;
+            let host = reader.read_string()?;
+            let port = reader.read_i32()?;
+            reader.read_nullable_string()?;
+            Ok((host, port))
+        })
+        .unwrap();
+    response.read_nullable_string().unwrap();
+    response.read_i32().unwrap();
+    let topics = response
+        .read_array(|reader| {
+            let error = reader.read_i16()?;
+            let name = reader.read_string()?;
+            reader.read_bool()?;
+            let partitions = reader.read_array(|reader| {
+                reader.read_i16()?;
+                reader.read_i32()?;
+                reader.read_i32()?;
+                reader.read_array(|reader| reader.read_i32())?;
+                reader.read_array(|reader| reader.read_i32())?;
+                reader.read_array(|reader| reader.read_i32())
+            })?;
+            Ok((error, name, partitions.len()))
+        })
+        .unwrap();
+    let (host, port) = brokers.into_iter().next().unwrap();
+    (host, port, topics)
+}
+
+/// Returns the error and the base offset of the produced records.
+async fn produce(
+    kafka: &mut KafkaClient,
+    topic: &str,
+    partition_index: i32,
+    records: &[KafkaRecord],
+) -> (i16, i64) {
+    let batch = records::encode_record_batch(records);
+    let mut request = KafkaWriter::new();
+    request.write_nullable_string(None);
+    request.write_i16(1);
+    request.write_i32(5000);
+    request.write_array(&[topic], |writer, topic| {
+        writer.write_string(topic);
+        writer.write_array(&[partition_index], |writer, index| {
+            writer.write_i32(*index);
+            writer.write_bytes(&batch);
+        });
+    });
+    let mut response = kafka.send(PRODUCE, 7, request).await;
+    let partitions = response
+        .read_array(|reader| {
+            reader.read_string()?;
+            reader.read_array(|reader| {
+                reader.read_i32()?;
+                let error = reader.read_i16()?;
+                let base_offset = reader.read_i64()?;
+                reader.read_i64()?;
+                reader.read_i64()?;
+                Ok((error, base_offset))
+            })
+        })
+        .unwrap();
+    assert_eq!(response.read_i32().unwrap(), 0);
+    partitions[0][0]
+}
+
+/// Returns the error, the high watermark and the records fetched from the offset.
+async fn fetch(
+    kafka: &mut KafkaClient,
+    topic: &str,
+    partition_index: i32,
+    offset: i64,
+) -> (i16, i64, Vec<KafkaRecord>) {
+    let mut request = KafkaWriter::new();
+    request.write_i32(-1);
+    request.write_i32(100);
+    request.write_i32(1);
+    request.write_i32(1024 * 1024);
+    request.write_i8(0);
+    request.write_i32(0);
+    request.write_i32(-1);
+    request.write_array(&[topic], |writer, topic| {
+        writer.write_string(topic);
+        writer.write_array(&[partition_index], |writer, index| {
+            writer.write_i32(*index);
+            writer.write_i64(offset);
+            writer.write_i64(-1);
+            writer.write_i32(1024 * 1024);
+        });
+    });
+    request.write_array(&[] as &[&str], |writer, topic| writer.write_string(topic));
+    let mut response = kafka.send(FETCH, 7, request).await;
+    response.read_i32().unwrap();
+    assert_eq!(response.read_i16().unwrap(), NO_ERROR);
+    response.read_i32().unwrap();
+    let mut partitions = response
+        .read_array(|reader| {
+            reader.read_string()?;
+            reader.read_array(|reader| {
+                reader.read_i32()?;
+                let error = reader.read_i16()?;
+                let high_watermark = reader.read_i64()?;
+                reader.read_i64()?;
+                reader.read_i64()?;
+                reader.read_nullable_array(|reader| {
+                    reader.read_i64()?;
+                    reader.read_i64()
+                })?;
+                let records = reader.read_nullable_bytes()?.unwrap_or_default();
+                Ok((error, high_watermark, records))
+            })
+        })
+        .unwrap();
+    let (error, high_watermark, records) = partitions.remove(0).remove(0);
+    let records = records::decode_record_batches(records).unwrap();
+    (error, high_watermark, records)
+}
+
+async fn list_offset(
+    kafka: &mut KafkaClient,
+    topic: &str,
+    partition_index: i32,
+    timestamp: i64,
+) -> i64 {
+    let mut request = KafkaWriter::new();
+    request.write_i32(-1);
+    request.write_i8(0);
+    request.write_array(&[topic], |writer, topic| {
+        writer.write_string(topic);
+        writer.write_array(&[partition_index], |writer, index| {
+            writer.write_i32(*index);
+            writer.write_i32(-1);
+            writer.write_i64(timestamp);
+        });
+    });
+    let mut response = kafka.send(LIST_OFFSETS, 4, request).await;
+    response.read_i32().unwrap();
+    let partitions = response
+        .read_array(|reader| {
+            reader.read_string()?;
+            reader.read_array(|reader| {
+                reader.read_i32()?;
+                let error = reader.read_i16()?;
+                reader.read_i64()?;
+                let offset = reader.read_i64()?;
+                reader.read_i32()?;
+                Ok((error, offset))
+            })
+        })
+        .unwrap();
+    let (error, offset) = partitions[0][0];
+    assert_eq!(error, NO_ERROR);
+    offset
+}
+
+/// Writes the group ID, the generation, the member ID and the group instance ID (v3+ requests).
+fn group_request(generation_id: i32, member_id: &str) -> KafkaWriter {
+    let mut request = KafkaWriter::new();
+    request.write_string(GROUP_ID);
+    request.write_i32(generation_id);
+    request.write_string(member_id);
+    request.write_nullable_string(None);
+    request
+}
+
+fn read_partition_errors(response: &mut KafkaReader) -> Vec<(i32, i16)> {
+    let mut topics = response
+        .read_array(|reader| {
+            reader.read_string()?;
+            reader.read_array(|reader| Ok((reader.read_i32()?, reader.read_i16()?)))
+        })
+        .unwrap();
+    topics.remove(0)
+}
+
+/// Client ID of the fixtures below: int16 length followed by "fixture".
+const FIXTURE_CLIENT_ID: &[u8] = b"\x00\x07fixture";
+/// The first timestamp of the record batch fixture.
+const FIXTURE_TIMESTAMP: i64 = 1_700_000_000_000;
+
+/// Sends the fixed requests, encoded field by field following the Kafka protocol guide
+/// (https://kafka.apache.org/protocol and https://kafka.apache.org/documentation/#recordbatch),
+/// and decodes the responses the same way, so that they don't depend on the codec of the server.
+async fn run_spec_fixtures(server_addr: &str) {
+    let mut stream = TcpStream::connect(server_addr).await.unwrap();
+
+    // ApiVersions v0: the header only, the body is empty.
+    let request = [
+        &[0x00, 0x00, 0x00, 0x11][..], // size: 17
+        &[0x00, 0x12],                 // api_key: 18
+        &[0x00, 0x00],                 // api_version: 0
+        &[0x00, 0x00, 0x00, 0x01],     // correlation_id: 1
+        FIXTURE_CLIENT_ID,
+    ]
+    .concat();
+    let mut response = send_fixture(&mut stream, &request, 1).await;
+    assert_eq!(response.i16(), NO_ERROR);
+    let mut versions = Vec::new();
+    for _ in 0..response.i32() {
+        versions.push((response.i16(), response.i16(), response.i16()));
+    }
+    assert!(versions.contains(&(PRODUCE, 3, 7)));
+    assert!(versions.contains(&(FETCH, 4, 7)));
+    assert!(versions.contains(&(API_VERSIONS, 0, 2)));
+    assert!(response.is_empty());
+
+    // SaslHandshake v1 with the PLAIN mechanism, followed by SaslAuthenticate v1 with "\0iggy\0iggy".
+    let request = [
+        &[0x00, 0x00, 0x00, 0x18][..], // size: 24
+        &[0x00, 0x11],                 // api_key: 17
+        &[0x00, 0x01],                 // api_version: 1
+        &[0x00, 0x00, 0x00, 0x02],     // correlation_id: 2
+        FIXTURE_CLIENT_ID,
+        b"\x00\x05PLAIN", // mechanism
+    ]
+    .concat();
+    let mut response = send_fixture(&mut stream, &request, 2).await;
+    assert_eq!(response.i16(), NO_ERROR);
+
+    let request = [
+        &[0x00, 0x00, 0x00, 0x1f][..], // size: 31
+        &[0x00, 0x24],                 // api_key: 36
+        &[0x00, 0x01],                 // api_version: 1
+        &[0x00, 0x00, 0x00, 0x03],     // correlation_id: 3
+        FIXTURE_CLIENT_ID,
+        b"\x00\x00\x00\x0a\x00iggy\x00iggy", // auth_bytes
+    ]
+    .concat();
+    let mut response = send_fixture(&mut stream, &request, 3).await;
+    assert_eq!(response.i16(), NO_ERROR);
+
+    // Produce v3 of the record batch fixture to the first partition of the prefixed topic.
+    let record_batch = record_batch_fixture();
+    assert_eq!(record_batch.len(), 130);
+    let request = [
+        &[0x00, 0x00, 0x00, 0xbc][..], // size: 188
+        &[0x00, 0x00],                 // api_key: 0
+        &[0x00, 0x03],                 // api_version: 3
+        &[0x00, 0x00, 0x00, 0x04],     // correlation_id: 4
+        FIXTURE_CLIENT_ID,
+        &[0xff, 0xff],              // transactional_id: null
+        &[0x00, 0x01],              // acks: 1
+        &[0x00, 0x00, 0x13, 0x88],  // timeout_ms: 5000
+        &[0x00, 0x00, 0x00, 0x01],  // topics: 1
+        b"\x00\x0fpayments.events", // name
+        &[0x00, 0x00, 0x00, 0x01],  // partitions: 1
+        &[0x00, 0x00, 0x00, 0x00],  // index: 0
+        &[0x00, 0x00, 0x00, 0x82],  // records size: 130
+        &record_batch,
+    ]
+    .concat();
+    let mut response = send_fixture(&mut stream, &request, 4).await;
+    assert_eq!(response.i32(), 1);
+    assert_eq!(response.string(), "payments.events");
+    assert_eq!(response.i32(), 1);
+    assert_eq!(response.i32(), 0);
+    assert_eq!(response.i16(), NO_ERROR);
+    assert_eq!(response.i64(), 0);
+    response.i64(); // log_append_time_ms
+    response.i32(); // throttle_time_ms
+    assert!(response.is_empty());
+
+    // Fetch v4 of the produced records from the first offset.
+    let request = [
+        &[0x00, 0x00, 0x00, 0x4b][..], // size: 75
+        &[0x00, 0x01],                 // api_key: 1
+        &[0x00, 0x04],                 // api_version: 4
+        &[0x00, 0x00, 0x00, 0x05],     // correlation_id: 5
+        FIXTURE_CLIENT_ID,
+        &[0xff, 0xff, 0xff, 0xff],                         // replica_id: -1
+        &[0x00, 0x00, 0x00, 0x64],                         // max_wait_ms: 100
+        &[0x00, 0x00, 0x00, 0x01],                         // min_bytes: 1
+        &[0x00, 0x10, 0x00, 0x00],                         // max_bytes: 1 MiB
+        &[0x00],                                           // isolation_level: 0
+        &[0x00, 0x00, 0x00, 0x01],                         // topics: 1
+        b"\x00\x0fpayments.events",                        // topic
+        &[0x00, 0x00, 0x00, 0x01],                         // partitions: 1
+        &[0x00, 0x00, 0x00, 0x00],                         // partition: 0
+        &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // fetch_offset: 0
+        &[0x00, 0x10, 0x00, 0x00],                         // partition_max_bytes: 1 MiB
+    ]
+    .concat();
+    let mut response = send_fixture(&mut stream, &request, 5).await;
+    response.i32(); // throttle_time_ms
+    assert_eq!(response.i32(), 1);
+    assert_eq!(response.string(), "payments.events");
+    assert_eq!(response.i32(), 1);
+    assert_eq!(response.i32(), 0);
+    assert_eq!(response.i16(), NO_ERROR);
+    assert_eq!(response.i64(), 2); // high_watermark
+    response.i64(); // last_stable_offset
+    let aborted_transactions = response.i32();
+    assert!(aborted_transactions <= 0);
+    let records_size = response.i32() as usize;
+    let mut records = SpecReader(Bytes::from(response.raw(records_size)));
+    assert!(response.is_empty());
+
+    // The fetched records are decoded following the record batch layout, verifying its CRC-32C.
+    let mut fetched = Vec::new();
+    while !records.is_empty() {
+        let base_offset = records.i64();
+        let batch_length = records.i32() as usize;
+        let mut batch = SpecReader(Bytes::from(records.raw(batch_length)));
+        batch.i32(); // partition_leader_epoch
+        assert_eq!(batch.i8(), 2); // magic
+        let crc = batch.i32() as u32;
+        assert_eq!(crc32c(&batch.0), crc);
+        assert_eq!(batch.i16() & 0x07, 0); // attributes: no compression
+        batch.i32(); // last_offset_delta
+        let first_timestamp = batch.i64();
+        batch.i64(); // max_timestamp
+        batch.i64(); // producer_id
+        batch.i16(); // producer_epoch
+        batch.i32(); // base_sequence
+        for _ in 0..batch.i32() {
+            let length = batch.varint() as usize;
+            let mut record = SpecReader(Bytes::from(batch.raw(length)));
+            record.i8(); // attributes
+            let timestamp = first_timestamp + record.varint();
+            let offset = base_offset + record.varint();
+            let key = record.varint_bytes();
+            let value = record.varint_bytes();
+            let mut headers = Vec::new();
+            for _ in 0..record.varint() {
+                let key = record.varint_bytes().unwrap();
+                headers.push((key, record.varint_bytes()));
+            }
+            assert!(record.is_empty());
+            fetched.push((offset, timestamp, key, value, headers));
+        }
+    }
+    assert_eq!(
+        fetched,
+        vec![
+            (
+                0,
+                FIXTURE_TIMESTAMP,
+                Some(b"fixture-key-0".to_vec()),
+                Some(b"fixture-value-0".to_vec()),
+                vec![(b"source".to_vec(), Some(b"spec".to_vec()))]
+            ),
+            (
+                1,
+                FIXTURE_TIMESTAMP + 5,
+                None,
+                Some(b"fixture-value-1".to_vec()),
+                vec![]
+            ),
+        ]
+    );
+}
+
+/// The record batch (magic v2) with two uncompressed records, the CRC-32C (Castagnoli) of the fields
+/// following it has been computed independently of the server.
+fn record_batch_fixture() -> Vec<u8> {
+    [
+        &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..], // base_offset: 0
+        &[0x00, 0x00, 0x00, 0x76],                             // batch_length: 118
+        &[0x00, 0x00, 0x00, 0x00],                             // partition_leader_epoch: 0
+        &[0x02],                                               // magic: 2
+        &[0x78, 0x03, 0x73, 0xe3],                             // crc
+        &[0x00, 0x00],                                         // attributes: none
+        &[0x00, 0x00, 0x00, 0x01],                             // last_offset_delta: 1
+        &[0x00, 0x00, 0x01, 0x8b, 0xcf, 0xe5, 0x68, 0x00],     // first_timestamp
+        &[0x00, 0x00, 0x01, 0x8b, 0xcf, 0xe5, 0x68, 0x05],     // max_timestamp
+        &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],     // producer_id: -1
+        &[0xff, 0xff],                                         // producer_epoch: -1
+        &[0xff, 0xff, 0xff, 0xff],                             // base_sequence: -1
+        &[0x00, 0x00, 0x00, 0x02],                             // records: 2
+        // The record fields are the zigzag encoded varints: length: 46, attributes,
+        // timestamp_delta: 0, offset_delta: 0, key length: 13.
+        &[0x5c, 0x00, 0x00, 0x00, 0x1a],
+        b"fixture-key-0",
+        &[0x1e], // value length: 15
+        b"fixture-value-0",
+        &[0x02, 0x0c], // headers: 1, key length: 6
+        b"source",
+        &[0x08], // value length: 4
+        b"spec",
+        // length: 21, attributes, timestamp_delta: 5, offset_delta: 1, key: null, value length: 15.
+        &[0x2a, 0x00, 0x0a, 0x02, 0x01, 0x1e],
+        b"fixture-value-1",
+        &[0x00], // headers: 0
+    ]
+    .concat()
+}
+
+async fn send_fixture(stream: &mut TcpStream, request: &[u8], correlation_id: i32) -> SpecReader {
+    assert_eq!(request[..4], (request.len() as u32 - 4).to_be_bytes());
+    stream.write_all(request).await.unwrap();
+    let size = stream.read_i32().await.unwrap();
+    let mut buffer = vec![0; size as usize];
+    stream.read_exact(&mut buffer).await.unwrap();
+    let mut response = SpecReader(Bytes::from(buffer));
+    assert_eq!(response.i32(), correlation_id);
+    response
+}
+
+/// Reads the big-endian primitives and the zigzag varints of the Kafka protocol.
+struct SpecReader(Bytes);
+
+impl SpecReader {
+    fn raw(&mut self, length: usize) -> Vec<u8> {
+        self.0.split_to(length).to_vec()
+    }
+
+    fn i8(&mut self) -> i8 {
+        self.raw(1)[0] as i8
+    }
+
+    fn i16(&mut self) -> i16 {
+        i16::from_be_bytes(self.raw(2).try_into().unwrap())
+    }
+
+    fn i32(&mut self) -> i32 {
+        i32::from_be_bytes(self.raw(4).try_into().unwrap())
+    }
+
+    fn i64(&mut self) -> i64 {
+        i64::from_be_bytes(self.raw(8).try_into().unwrap())
+    }
+
+    fn string(&mut self) -> String {
+        let length = self.i16() as usize;
+        String::from_utf8(self.raw(length)).unwrap()
+    }
+
+    fn varint(&mut self) -> i64 {
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = self.raw(1)[0];
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+        (value >> 1) as i64 ^ -((value & 1) as i64)
+    }
+
+    fn varint_bytes(&mut self) -> Option<Vec<u8>> {
+        let length = self.varint();
+        (length >= 0).then(|| self.raw(length as usize))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// CRC-32C (Castagnoli), computed bit by bit.
+fn crc32c(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0x82f6_3b78
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
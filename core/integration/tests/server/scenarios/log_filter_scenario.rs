@@ -0,0 +1,77 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, login_root};
+use std::time::Duration;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, None, None);
+
+    // 1. The log filter can't be accessed without the authentication.
+    let error = client.get_log_filter().await.unwrap_err();
+    assert_eq!(error.as_code(), IggyError::Unauthenticated.as_code());
+
+    login_root(&client).await;
+
+    // 2. By default, the active filter is the default one, without the expiry.
+    let log_filter = client.get_log_filter().await.unwrap();
+    assert_eq!(log_filter.filter, log_filter.default_filter);
+    assert!(log_filter.expires_at.is_none());
+    let default_filter = log_filter.default_filter;
+
+    // 3. The filter can be changed for the selected modules, and then reset.
+    let filter = "info,server::streaming=debug";
+    let log_filter = client.set_log_filter(Some(filter), None).await.unwrap();
+    assert_eq!(log_filter.filter, filter);
+    assert_eq!(log_filter.default_filter, default_filter);
+    assert!(log_filter.expires_at.is_none());
+    assert_eq!(client.get_log_filter().await.unwrap().filter, filter);
+
+    let log_filter = client.set_log_filter(None, None).await.unwrap();
+    assert_eq!(log_filter.filter, default_filter);
+
+    // 4. The invalid filter is rejected, and the active one is left unchanged.
+    let error = client
+        .set_log_filter(Some("server=verbose"), None)
+        .await
+        .unwrap_err();
+    // The HTTP API responds with 400 Bad Request, which isn't mapped back to the error code.
+    assert!(matches!(
+        error,
+        IggyError::InvalidLogFilter(_) | IggyError::HttpResponseError(400, _)
+    ));
+    assert_eq!(
+        client.get_log_filter().await.unwrap().filter,
+        default_filter
+    );
+
+    // 5. The filter set with the TTL is reverted to the default one once it elapses.
+    let log_filter = client
+        .set_log_filter(Some("debug"), Some(IggyDuration::from(500_000)))
+        .await
+        .unwrap();
+    assert_eq!(log_filter.filter, "debug");
+    assert!(log_filter.expires_at.is_some());
+
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+    let log_filter = client.get_log_filter().await.unwrap();
+    assert_eq!(log_filter.filter, default_filter);
+    assert!(log_filter.expires_at.is_none());
+}
@@ -0,0 +1,119 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, login_root};
+use serde_json::Value;
+use std::time::Duration;
+
+const LOGS_STREAM: &str = "$system";
+const LOGS_TOPIC: &str = "logs";
+const STREAM_NAME: &str = "log-shipping-stream";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, None, None);
+    login_root(&client).await;
+
+    // 1. The stream and the topic storing the logs are created on startup.
+    let logs_stream_id = Identifier::named(LOGS_STREAM).unwrap();
+    let logs_topic_id = Identifier::named(LOGS_TOPIC).unwrap();
+    let topic = wait_for_logs_topic(&client, &logs_stream_id, &logs_topic_id).await;
+    assert_eq!(topic.partitions_count, 1);
+
+    // 2. The logs buffered during the startup are shipped as well.
+    let record = poll_log_record(&client, &logs_stream_id, &logs_topic_id, |record| {
+        record["message"]
+            .as_str()
+            .is_some_and(|message| message.contains("server has started on"))
+    })
+    .await;
+    assert_eq!(record["level"], "INFO");
+
+    // 3. The logs produced while handling the requests are shipped in JSON format.
+    client.create_stream(STREAM_NAME, None).await.unwrap();
+    let record = poll_log_record(&client, &logs_stream_id, &logs_topic_id, |record| {
+        record["message"]
+            .as_str()
+            .is_some_and(|message| message.contains(STREAM_NAME))
+    })
+    .await;
+    assert_eq!(record["level"], "INFO");
+    assert!(
+        record["target"]
+            .as_str()
+            .unwrap()
+            .starts_with("server::streaming")
+    );
+    assert!(record["timestamp"].is_string());
+    assert!(record["fields"].is_object());
+
+    client
+        .delete_stream(&Identifier::named(STREAM_NAME).unwrap())
+        .await
+        .unwrap();
+}
+
+async fn wait_for_logs_topic(
+    client: &IggyClient,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+) -> TopicDetails {
+    // The topic is created in the background, once the server has loaded its data.
+    for _ in 0..50 {
+        if let Ok(Some(topic)) = client.get_topic(stream_id, topic_id).await {
+            return topic;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("Logs topic has not been created.");
+}
+
+async fn poll_log_record(
+    client: &IggyClient,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+    predicate: impl Fn(&Value) -> bool,
+) -> Value {
+    // The logs are appended in the background, thus they might not be available immediately.
+    let consumer = Consumer::new(Identifier::named("log-shipping-consumer").unwrap());
+    for _ in 0..50 {
+        let messages = client
+            .poll_messages(
+                stream_id,
+                topic_id,
+                Some(1),
+                &consumer,
+                &PollingStrategy::offset(0),
+                1000,
+                false,
+            )
+            .await
+            .unwrap()
+            .messages;
+        let record = messages
+            .iter()
+            .map(|message| serde_json::from_slice::<Value>(&message.payload).unwrap())
+            .find(|record| predicate(record));
+        if let Some(record) = record {
+            return record;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("Log record has not been shipped to the topic.");
+}
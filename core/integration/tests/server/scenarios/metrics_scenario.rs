@@ -0,0 +1,195 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::test_server::{assert_clean_system, login_root};
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-metrics-stream";
+const TOPIC_NAME: &str = "test-metrics-topic";
+const PARTITIONS_COUNT: u32 = 2;
+const MESSAGES_COUNT: u32 = 10;
+
+pub async fn run(client: &IggyClient, http_api_addr: &str) {
+    login_root(client).await;
+
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::None,
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+
+    // 1. Send and poll the messages using the first partition only.
+    let mut messages = (1..=MESSAGES_COUNT)
+        .map(|id| {
+            IggyMessage::builder()
+                .id(id as u128)
+                .payload(Bytes::from(format!("message-{id}")))
+                .build()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(1),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(1),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+
+    // 2. Poll the messages from the topic which doesn't exist.
+    let error = client
+        .poll_messages(
+            &stream_id,
+            &Identifier::numeric(TOPIC_ID + 1).unwrap(),
+            Some(1),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.as_code(),
+        IggyError::TopicIdNotFound(TOPIC_ID + 1, STREAM_ID).as_code()
+    );
+
+    // 3. Validate the metrics of both partitions, the requests and the error.
+    let metrics = get_metrics(http_api_addr).await;
+    let first_partition =
+        format!("stream_id=\"{STREAM_ID}\",topic_id=\"{TOPIC_ID}\",partition_id=\"1\"");
+    let second_partition =
+        format!("stream_id=\"{STREAM_ID}\",topic_id=\"{TOPIC_ID}\",partition_id=\"2\"");
+    assert_eq!(
+        get_metric(
+            &metrics,
+            &format!("messages_appended_total{{{first_partition}}}")
+        ),
+        MESSAGES_COUNT as u64
+    );
+    assert_eq!(
+        get_metric(
+            &metrics,
+            &format!("messages_polled_total{{{first_partition}}}")
+        ),
+        MESSAGES_COUNT as u64
+    );
+    assert!(
+        get_metric(
+            &metrics,
+            &format!("bytes_appended_total{{{first_partition}}}")
+        ) > 0
+    );
+    assert_eq!(
+        get_metric(&metrics, &format!("current_offset{{{first_partition}}}")),
+        MESSAGES_COUNT as u64 - 1
+    );
+    assert_eq!(
+        get_metric(&metrics, &format!("stored_segments{{{second_partition}}}")),
+        1
+    );
+    assert_eq!(
+        get_metric(&metrics, &format!("stored_bytes{{{second_partition}}}")),
+        0
+    );
+    assert_eq!(
+        get_metric(
+            &metrics,
+            "request_duration_seconds_count{transport=\"tcp\",command=\"message.send\"}"
+        ),
+        1
+    );
+    assert_eq!(
+        get_metric(
+            &metrics,
+            "request_duration_seconds_count{transport=\"tcp\",command=\"message.poll\"}"
+        ),
+        2
+    );
+    assert_eq!(
+        get_metric(
+            &metrics,
+            &format!(
+                "request_errors_total{{transport=\"tcp\",command=\"message.poll\",code=\"{}\",error=\"{}\"}}",
+                error.as_code(),
+                error.as_string()
+            )
+        ),
+        1
+    );
+
+    // 4. The series of the deleted stream are removed.
+    client.delete_stream(&stream_id).await.unwrap();
+    let metrics = get_metrics(http_api_addr).await;
+    assert!(!metrics.contains(&first_partition));
+    assert!(!metrics.contains(&second_partition));
+
+    assert_clean_system(client).await;
+}
+
+async fn get_metrics(http_api_addr: &str) -> String {
+    reqwest::get(format!("http://{http_api_addr}/metrics"))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap()
+}
+
+fn get_metric(metrics: &str, series: &str) -> u64 {
+    let prefix = format!("{series} ");
+    metrics
+        .lines()
+        .find_map(|line| line.strip_prefix(&prefix))
+        .unwrap_or_else(|| panic!("Missing metric: {series}"))
+        .trim()
+        .parse()
+        .unwrap()
+}
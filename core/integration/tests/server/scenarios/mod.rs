@@ -17,16 +17,35 @@
  */
 
 pub mod bench_scenario;
+pub mod client_eviction_scenario;
+pub mod config_reload_scenario;
 pub mod consumer_group_join_scenario;
 pub mod consumer_group_with_multiple_clients_polling_messages_scenario;
 pub mod consumer_group_with_single_client_polling_messages_scenario;
 pub mod create_message_payload;
 pub mod delete_segments_scenario;
+pub mod frame_compression_scenario;
+pub mod health_scenario;
+pub mod http_message_formats_scenario;
+pub mod http_stream_scenario;
+pub mod kafka_scenario;
+pub mod log_filter_scenario;
+pub mod log_shipping_scenario;
 pub mod message_headers_scenario;
 pub mod message_size_scenario;
+pub mod metrics_scenario;
+pub mod mqtt_scenario;
+pub mod profile_scenario;
+pub mod protocol_handshake_scenario;
+pub mod stats_history_scenario;
+pub mod stream_retention_scenario;
 pub mod stream_size_validation_scenario;
+pub mod system_events_scenario;
 pub mod system_scenario;
+pub mod tcp_pipelining_scenario;
 pub mod tcp_tls_scenario;
+pub mod telemetry_scenario;
+pub mod throughput_quota_scenario;
 pub mod user_scenario;
 
 use iggy::prelude::*;
@@ -52,6 +71,32 @@ async fn create_client(client_factory: &dyn ClientFactory) -> IggyClient {
     IggyClient::create(client, None, None)
 }
 
+/// Signs in the root user via the HTTP API, returning the access token.
+async fn login_http_root(http: &reqwest::Client, api_url: &str) -> String {
+    login_http(http, api_url, DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD).await
+}
+
+async fn login_http(
+    http: &reqwest::Client,
+    api_url: &str,
+    username: &str,
+    password: &str,
+) -> String {
+    let identity = http
+        .post(format!("{api_url}/users/login"))
+        .json(&serde_json::json!({
+            "username": username,
+            "password": password,
+        }))
+        .send()
+        .await
+        .unwrap()
+        .json::<IdentityInfo>()
+        .await
+        .unwrap();
+    identity.access_token.unwrap().token
+}
+
 async fn get_consumer_group(client: &IggyClient) -> ConsumerGroupDetails {
     client
         .get_consumer_group(
@@ -0,0 +1,431 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::{Bytes, BytesMut};
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, login_root};
+use server::mqtt::codec::{
+    Connect, Packet, Properties, Property, ProtocolVersion, Publish, decode_varint,
+};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+/// The mapping rules of the MQTT topics, the first one refers to the second level of the MQTT topic.
+pub const MAPPING_RULES: &str = "[sensors/+/#=sensors/{2},#=mqtt/messages]";
+const SENSORS_STREAM: &str = "sensors";
+const SENSORS_TOPIC: &str = "kitchen";
+const DEFAULT_STREAM: &str = "mqtt";
+const DEFAULT_TOPIC: &str = "messages";
+const TEMPERATURE_TOPIC: &str = "sensors/kitchen/temperature";
+const USERNAME: &str = "mqtt-user";
+const PASSWORD: &str = "secret";
+const RECEIVE_TIMEOUT: Duration = Duration::from_secs(5);
+
+const SUCCESS: u8 = 0x00;
+const V3_BAD_USERNAME_OR_PASSWORD: u8 = 0x04;
+const BAD_USERNAME_OR_PASSWORD: u8 = 0x86;
+const NOT_AUTHORIZED: u8 = 0x87;
+const PAYLOAD_FORMAT_INVALID: u8 = 0x99;
+const TOPIC_NAME_INVALID: u8 = 0x90;
+const TOPIC_FILTER_INVALID: u8 = 0x8F;
+const SHARED_SUBSCRIPTIONS_NOT_SUPPORTED: u8 = 0x9E;
+const SESSION_TAKEN_OVER: u8 = 0x8E;
+const SUBACK_FAILURE: u8 = 0x80;
+
+/// Minimal MQTT client, which exchanges the raw packets over a plain TCP connection.
+struct MqttClient {
+    stream: TcpStream,
+    version: ProtocolVersion,
+    next_packet_id: u16,
+}
+
+impl MqttClient {
+    async fn connect(
+        server_addr: &str,
+        version: ProtocolVersion,
+        client_id: &str,
+        username: &str,
+        password: &str,
+    ) -> (Self, u8) {
+        let mut client = Self {
+            stream: TcpStream::connect(server_addr).await.unwrap(),
+            version,
+            next_packet_id: 1,
+        };
+        client
+            .send(Packet::Connect(Connect {
+                protocol_level: version as u8,
+                clean_start: true,
+                keep_alive: 30,
+                client_id: client_id.to_owned(),
+                will: None,
+                username: Some(username.to_owned()),
+                password: Some(Bytes::from(password.to_owned())),
+                properties: Properties::default(),
+            }))
+            .await;
+        let Packet::ConnAck { reason_code, .. } = client.receive().await.unwrap() else {
+            panic!("Expected CONNACK");
+        };
+        (client, reason_code)
+    }
+
+    async fn connect_root(server_addr: &str, version: ProtocolVersion, client_id: &str) -> Self {
+        let (client, reason_code) = Self::connect(
+            server_addr,
+            version,
+            client_id,
+            DEFAULT_ROOT_USERNAME,
+            DEFAULT_ROOT_PASSWORD,
+        )
+        .await;
+        assert_eq!(reason_code, SUCCESS);
+        client
+    }
+
+    async fn send(&mut self, packet: Packet) {
+        self.stream
+            .write_all(&packet.encode(self.version))
+            .await
+            .unwrap();
+    }
+
+    /// Returns the next packet, or `None` if the connection has been closed.
+    async fn receive(&mut self) -> Option<Packet> {
+        tokio::time::timeout(RECEIVE_TIMEOUT, async {
+            let first_byte = self.stream.read_u8().await.ok()?;
+            let mut length_bytes = Vec::new();
+            let length = loop {
+                length_bytes.push(self.stream.read_u8().await.ok()?);
+                if let Some((length, _)) = decode_varint(&length_bytes).unwrap() {
+                    break length as usize;
+                }
+            };
+            let mut body = BytesMut::zeroed(length);
+            self.stream.read_exact(&mut body).await.ok()?;
+            Some(Packet::decode(first_byte, body.freeze(), self.version).unwrap())
+        })
+        .await
+        .expect("Timed out waiting for the MQTT packet")
+    }
+
+    async fn subscribe(&mut self, filter: &str, qos: u8) -> u8 {
+        let packet_id = self.next_packet_id();
+        self.send(Packet::Subscribe {
+            packet_id,
+            filters: vec![(filter.to_owned(), qos)],
+            properties: Properties::default(),
+        })
+        .await;
+        let Some(Packet::SubAck {
+            packet_id: acked_packet_id,
+            reason_codes,
+        }) = self.receive().await
+        else {
+            panic!("Expected SUBACK");
+        };
+        assert_eq!(acked_packet_id, packet_id);
+        reason_codes[0]
+    }
+
+    /// Publishes the message with QoS 1 and returns the reason code of PUBACK.
+    async fn publish(&mut self, mut publish: Publish) -> u8 {
+        let packet_id = self.next_packet_id();
+        publish.qos = 1;
+        publish.packet_id = Some(packet_id);
+        self.send(Packet::Publish(publish)).await;
+        let Some(Packet::PubAck {
+            packet_id: acked_packet_id,
+            reason_code,
+        }) = self.receive().await
+        else {
+            panic!("Expected PUBACK");
+        };
+        assert_eq!(acked_packet_id, packet_id);
+        reason_code
+    }
+
+    async fn receive_publish(&mut self) -> Publish {
+        let Some(Packet::Publish(publish)) = self.receive().await else {
+            panic!("Expected PUBLISH");
+        };
+        if let Some(packet_id) = publish.packet_id {
+            self.send(Packet::PubAck {
+                packet_id,
+                reason_code: SUCCESS,
+            })
+            .await;
+        }
+        publish
+    }
+
+    fn next_packet_id(&mut self) -> u16 {
+        let packet_id = self.next_packet_id;
+        self.next_packet_id += 1;
+        packet_id
+    }
+}
+
+fn message(topic: &str, payload: &str, retain: bool, properties: Vec<Property>) -> Publish {
+    Publish {
+        topic: topic.to_owned(),
+        packet_id: None,
+        qos: 0,
+        retain,
+        dup: false,
+        payload: Bytes::from(payload.to_owned()),
+        properties: Properties(properties),
+    }
+}
+
+pub async fn run(server_addr: &str, client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, None, None);
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. The invalid credentials are rejected with the reason code of the protocol version.
+    let (_, reason_code) = MqttClient::connect(
+        server_addr,
+        ProtocolVersion::V311,
+        "invalid",
+        DEFAULT_ROOT_USERNAME,
+        "invalid",
+    )
+    .await;
+    assert_eq!(reason_code, V3_BAD_USERNAME_OR_PASSWORD);
+    let (_, reason_code) = MqttClient::connect(
+        server_addr,
+        ProtocolVersion::V5,
+        "invalid",
+        DEFAULT_ROOT_USERNAME,
+        "invalid",
+    )
+    .await;
+    assert_eq!(reason_code, BAD_USERNAME_OR_PASSWORD);
+
+    // 2. The subscriptions are mapped to the Iggy topics, the unsupported ones are rejected.
+    let mut subscriber_v5 =
+        MqttClient::connect_root(server_addr, ProtocolVersion::V5, "subscriber-v5").await;
+    assert_eq!(subscriber_v5.subscribe("sensors/+/temperature", 1).await, 1);
+    assert_eq!(
+        subscriber_v5.subscribe("$share/group/sensors/#", 1).await,
+        SHARED_SUBSCRIPTIONS_NOT_SUPPORTED
+    );
+    assert_eq!(
+        subscriber_v5.subscribe("sensors/#/invalid", 1).await,
+        TOPIC_FILTER_INVALID
+    );
+    let mut subscriber_v3 =
+        MqttClient::connect_root(server_addr, ProtocolVersion::V311, "subscriber-v3").await;
+    // QoS 2 is downgraded to QoS 1.
+    assert_eq!(subscriber_v3.subscribe("sensors/#", 2).await, 1);
+    assert_eq!(
+        subscriber_v3.subscribe("sensors/#/invalid", 0).await,
+        SUBACK_FAILURE
+    );
+
+    // 3. The published message is appended to the mapped topic, and delivered to the subscribers.
+    let mut publisher =
+        MqttClient::connect_root(server_addr, ProtocolVersion::V5, "publisher").await;
+    let properties = vec![Property::User("unit".to_owned(), "celsius".to_owned())];
+    let reason_code = publisher
+        .publish(message(TEMPERATURE_TOPIC, "21.5", true, properties.clone()))
+        .await;
+    assert_eq!(reason_code, SUCCESS);
+
+    let publish = subscriber_v5.receive_publish().await;
+    assert_eq!(publish.topic, TEMPERATURE_TOPIC);
+    assert_eq!(publish.payload, Bytes::from("21.5"));
+    assert_eq!(publish.qos, 1);
+    assert!(!publish.retain);
+    assert_eq!(publish.properties, Properties(properties));
+    let publish = subscriber_v3.receive_publish().await;
+    assert_eq!(publish.topic, TEMPERATURE_TOPIC);
+    assert_eq!(publish.payload, Bytes::from("21.5"));
+    assert!(publish.properties.0.is_empty());
+
+    // 4. The messages published on the topics not matching the first rule use the next one.
+    let reason_code = publisher
+        .publish(message("devices/status", "online", false, Vec::new()))
+        .await;
+    assert_eq!(reason_code, SUCCESS);
+
+    // 5. The failures are reported with the reason codes of PUBACK in MQTT 5.
+    let reason_code = publisher
+        .publish(message(TEMPERATURE_TOPIC, "", false, Vec::new()))
+        .await;
+    assert_eq!(reason_code, PAYLOAD_FORMAT_INVALID);
+    let reason_code = publisher
+        .publish(message(
+            "sensors/missing/temperature",
+            "20",
+            false,
+            Vec::new(),
+        ))
+        .await;
+    assert_eq!(reason_code, TOPIC_NAME_INVALID);
+
+    // 6. The messages are available to the Iggy clients, with the MQTT topic and properties as headers.
+    let polled_messages = poll_messages(&client, SENSORS_STREAM, SENSORS_TOPIC).await;
+    assert_eq!(polled_messages.len(), 1);
+    let message_headers = polled_messages[0].user_headers_map().unwrap().unwrap();
+    assert_eq!(polled_messages[0].payload, Bytes::from("21.5"));
+    assert_eq!(
+        message_headers[&HeaderKey::new("mqtt-topic").unwrap()]
+            .as_str()
+            .unwrap(),
+        TEMPERATURE_TOPIC
+    );
+    assert_eq!(
+        message_headers[&HeaderKey::new("unit").unwrap()]
+            .as_str()
+            .unwrap(),
+        "celsius"
+    );
+    let polled_messages = poll_messages(&client, DEFAULT_STREAM, DEFAULT_TOPIC).await;
+    assert_eq!(polled_messages.len(), 1);
+    assert_eq!(polled_messages[0].payload, Bytes::from("online"));
+
+    // 7. The new subscription receives the retained message of the topic, with the retain flag.
+    let mut late_subscriber =
+        MqttClient::connect_root(server_addr, ProtocolVersion::V311, "late-subscriber").await;
+    assert_eq!(late_subscriber.subscribe(TEMPERATURE_TOPIC, 0).await, 0);
+    let publish = late_subscriber.receive_publish().await;
+    assert_eq!(publish.topic, TEMPERATURE_TOPIC);
+    assert_eq!(publish.payload, Bytes::from("21.5"));
+    assert!(publish.retain);
+
+    // 8. The retained messages are neither delivered to, nor cleared by the users without permissions.
+    client
+        .create_user(USERNAME, PASSWORD, UserStatus::Active, None)
+        .await
+        .unwrap();
+    let (mut unauthorized, reason_code) = MqttClient::connect(
+        server_addr,
+        ProtocolVersion::V5,
+        "unauthorized",
+        USERNAME,
+        PASSWORD,
+    )
+    .await;
+    assert_eq!(reason_code, SUCCESS);
+    assert_eq!(unauthorized.subscribe("sensors/#", 0).await, 0);
+    unauthorized.send(Packet::PingReq).await;
+    assert_eq!(unauthorized.receive().await, Some(Packet::PingResp));
+    let reason_code = unauthorized
+        .publish(message(TEMPERATURE_TOPIC, "", true, Vec::new()))
+        .await;
+    assert_eq!(reason_code, NOT_AUTHORIZED);
+    let mut authorized_subscriber =
+        MqttClient::connect_root(server_addr, ProtocolVersion::V311, "authorized-subscriber").await;
+    assert_eq!(
+        authorized_subscriber.subscribe(TEMPERATURE_TOPIC, 0).await,
+        0
+    );
+    let publish = authorized_subscriber.receive_publish().await;
+    assert_eq!(publish.payload, Bytes::from("21.5"));
+
+    // 9. The empty retained message clears the retained message of the topic.
+    let reason_code = publisher
+        .publish(message(TEMPERATURE_TOPIC, "", true, Vec::new()))
+        .await;
+    assert_eq!(reason_code, SUCCESS);
+    late_subscriber.send(Packet::PingReq).await;
+    assert_eq!(late_subscriber.receive().await, Some(Packet::PingResp));
+    let mut another_subscriber =
+        MqttClient::connect_root(server_addr, ProtocolVersion::V311, "another-subscriber").await;
+    assert_eq!(another_subscriber.subscribe(TEMPERATURE_TOPIC, 0).await, 0);
+    another_subscriber.send(Packet::PingReq).await;
+    assert_eq!(another_subscriber.receive().await, Some(Packet::PingResp));
+
+    // 10. The client connecting with the same client ID takes over the session.
+    let _subscriber_v5 =
+        MqttClient::connect_root(server_addr, ProtocolVersion::V5, "subscriber-v5").await;
+    assert_eq!(
+        subscriber_v5.receive().await,
+        Some(Packet::Disconnect {
+            reason_code: SESSION_TAKEN_OVER
+        })
+    );
+    assert_eq!(subscriber_v5.receive().await, None);
+
+    publisher
+        .send(Packet::Disconnect {
+            reason_code: SUCCESS,
+        })
+        .await;
+    assert_eq!(publisher.receive().await, None);
+    client
+        .delete_user(&Identifier::named(USERNAME).unwrap())
+        .await
+        .unwrap();
+    cleanup_system(&client).await;
+}
+
+async fn poll_messages(
+    client: &IggyClient,
+    stream_name: &str,
+    topic_name: &str,
+) -> Vec<IggyMessage> {
+    let polled_messages = client
+        .poll_messages(
+            &Identifier::named(stream_name).unwrap(),
+            &Identifier::named(topic_name).unwrap(),
+            Some(1),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            10,
+            false,
+        )
+        .await
+        .unwrap();
+    polled_messages.messages
+}
+
+async fn init_system(client: &IggyClient) {
+    for (stream_name, topic_name) in [
+        (SENSORS_STREAM, SENSORS_TOPIC),
+        (DEFAULT_STREAM, DEFAULT_TOPIC),
+    ] {
+        client.create_stream(stream_name, None).await.unwrap();
+        client
+            .create_topic(
+                &Identifier::named(stream_name).unwrap(),
+                topic_name,
+                1,
+                CompressionAlgorithm::default(),
+                None,
+                None,
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+            )
+            .await
+            .unwrap();
+    }
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    for stream_name in [SENSORS_STREAM, DEFAULT_STREAM] {
+        client
+            .delete_stream(&Identifier::named(stream_name).unwrap())
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,157 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, login_root};
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+const USERNAME: &str = "profile-reader";
+const PASSWORD: &str = "secret";
+const STREAM_NAME: &str = "profile-stream";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, None, None);
+    let duration = IggyDuration::new_from_secs(1);
+
+    // 1. The profile can't be captured without the authentication.
+    let error = client
+        .profile(ProfileKind::Cpu, ProfileFormat::Pprof, duration)
+        .await
+        .unwrap_err();
+    assert_eq!(error.as_code(), IggyError::Unauthenticated.as_code());
+
+    login_root(&client).await;
+
+    // 2. The CPU profile is captured as the pprof protobuf and as the flamegraph SVG.
+    let profile = client
+        .profile(ProfileKind::Cpu, ProfileFormat::Pprof, duration)
+        .await
+        .unwrap();
+    assert!(!profile.0.is_empty());
+
+    let profile = under_load(
+        client_factory,
+        client.profile(ProfileKind::Cpu, ProfileFormat::Flamegraph, duration),
+    )
+    .await
+    .unwrap();
+    assert!(String::from_utf8_lossy(&profile.0).contains("<svg"));
+
+    // 3. The heap profile is the jemalloc dump, available only if the server is built with the jemalloc feature.
+    match client
+        .profile(ProfileKind::Heap, ProfileFormat::Pprof, duration)
+        .await
+    {
+        Ok(profile) => assert!(profile.0.starts_with(b"heap_v2/")),
+        // The HTTP API responds with 400 Bad Request, which isn't mapped back to the error code.
+        Err(error) => assert!(matches!(
+            error,
+            IggyError::FeatureUnavailable | IggyError::HttpResponseError(400, _)
+        )),
+    }
+
+    // 4. The CPU profile can be included in the snapshot as the flamegraph.
+    let snapshot = under_load(
+        client_factory,
+        client.snapshot(
+            SnapshotCompression::Stored,
+            vec![SystemSnapshotType::CpuProfile],
+        ),
+    )
+    .await
+    .unwrap();
+    assert!(
+        snapshot
+            .0
+            .windows(b"cpu_profile.svg".len())
+            .any(|name| name == b"cpu_profile.svg")
+    );
+
+    // 5. The system isn't locked while the CPU profile is captured for the snapshot.
+    let writer = client_factory.create_client().await;
+    let writer = IggyClient::create(writer, None, None);
+    login_root(&writer).await;
+    let snapshot = async {
+        client
+            .snapshot(
+                SnapshotCompression::Stored,
+                vec![SystemSnapshotType::CpuProfile],
+            )
+            .await
+            .unwrap();
+        Instant::now()
+    };
+    let write = async {
+        sleep(Duration::from_millis(500)).await;
+        writer.create_stream(STREAM_NAME, None).await.unwrap();
+        Instant::now()
+    };
+    let (snapshot_completed_at, write_completed_at) = tokio::join!(snapshot, write);
+    assert!(write_completed_at < snapshot_completed_at);
+    writer
+        .delete_stream(&Identifier::named(STREAM_NAME).unwrap())
+        .await
+        .unwrap();
+
+    // 6. The profile can be captured only with the permission to manage the servers.
+    client
+        .create_user(
+            USERNAME,
+            PASSWORD,
+            UserStatus::Active,
+            Some(Permissions {
+                global: GlobalPermissions {
+                    read_servers: true,
+                    ..Default::default()
+                },
+                streams: None,
+            }),
+        )
+        .await
+        .unwrap();
+    let reader = client_factory.create_client().await;
+    let reader = IggyClient::create(reader, None, None);
+    reader.login_user(USERNAME, PASSWORD).await.unwrap();
+    let error = reader
+        .profile(ProfileKind::Cpu, ProfileFormat::Pprof, duration)
+        .await
+        .unwrap_err();
+    assert_eq!(error.as_code(), IggyError::Unauthorized.as_code());
+
+    client
+        .delete_user(&Identifier::named(USERNAME).unwrap())
+        .await
+        .unwrap();
+}
+
+// Only the CPU time is sampled, so the server is kept busy with the pings while the flamegraph is captured.
+async fn under_load<T>(client_factory: &dyn ClientFactory, action: impl Future<Output = T>) -> T {
+    let client = client_factory.create_client().await;
+    let load = async {
+        loop {
+            let _ = client.ping().await;
+        }
+    };
+    tokio::select! {
+        result = action => result,
+        _ = load => unreachable!(),
+    }
+}
@@ -0,0 +1,144 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::prelude::*;
+use iggy_common::hello::Hello;
+use iggy_common::{GET_STATS_CODE, HELLO_CODE, PING_CODE, PROTOCOL_VERSION, SEND_MESSAGES_CODE};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+pub const MIN_CLIENT_PROTOCOL_VERSION: u32 = 1;
+pub const MAX_FRAME_SIZE: u32 = 1024 * 1024;
+
+pub async fn run(server_addr: &str) {
+    let client = TcpClient::create(Arc::new(TcpClientConfig {
+        server_address: server_addr.to_string(),
+        ..Default::default()
+    }))
+    .unwrap();
+    client.connect().await.unwrap();
+
+    // 1. The capabilities of the server are negotiated on connect.
+    let server_hello = client.server_hello().await.unwrap();
+    assert_eq!(server_hello.protocol_version, PROTOCOL_VERSION);
+    assert_eq!(
+        server_hello.min_client_protocol_version,
+        MIN_CLIENT_PROTOCOL_VERSION
+    );
+    assert_eq!(server_hello.max_frame_size, MAX_FRAME_SIZE);
+    assert!(server_hello.supports_feature(ProtocolFeatures::PIPELINING));
+    assert!(server_hello.supports_command(PING_CODE));
+    assert!(server_hello.supports_command(HELLO_CODE));
+    assert!(!server_hello.supports_command(u32::MAX));
+    assert!(
+        server_hello
+            .compression_algorithms
+            .contains(&CompressionAlgorithm::Gzip)
+    );
+    client.ping().await.unwrap();
+
+    // 2. The frames exceeding the maximum size are rejected by the client before being sent.
+    client
+        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+        .await
+        .unwrap();
+    let mut messages = vec![
+        IggyMessage::builder()
+            .payload(Bytes::from(vec![0u8; MAX_FRAME_SIZE as usize]))
+            .build()
+            .unwrap(),
+    ];
+    let result = client
+        .send_messages(
+            &Identifier::numeric(1).unwrap(),
+            &Identifier::numeric(1).unwrap(),
+            &Partitioning::balanced(),
+            &mut messages,
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(IggyError::FrameTooLarge(_, MAX_FRAME_SIZE))
+    ));
+
+    // 3. The protocol version below the required minimum is rejected by the server.
+    let result = client
+        .hello(&Hello {
+            protocol_version: MIN_CLIENT_PROTOCOL_VERSION - 1,
+            ..Default::default()
+        })
+        .await;
+    // The error details are not sent over the wire, so only the error codes are compared.
+    assert_eq!(
+        result.unwrap_err(),
+        IggyError::UnsupportedProtocolVersion(0, MIN_CLIENT_PROTOCOL_VERSION)
+    );
+    client.ping().await.unwrap();
+
+    // 4. The legacy client which doesn't send `Hello` can only ping the server.
+    let mut stream = TcpStream::connect(server_addr).await.unwrap();
+    assert_eq!(send_raw(&mut stream, PING_CODE, 0).await, 0);
+    assert_eq!(
+        send_raw(&mut stream, GET_STATS_CODE, 0).await,
+        IggyError::UnsupportedProtocolVersion(0, 0).as_code()
+    );
+    assert_connection_closed(&mut stream).await;
+
+    // 5. The frames exceeding the maximum size are rejected by the server as well.
+    let mut stream = TcpStream::connect(server_addr).await.unwrap();
+    assert_eq!(
+        send_raw(&mut stream, SEND_MESSAGES_CODE, MAX_FRAME_SIZE).await,
+        IggyError::FrameTooLarge(0, 0).as_code()
+    );
+    assert_connection_closed(&mut stream).await;
+
+    // 6. The frames shorter than the command code are rejected by the server.
+    let mut stream = TcpStream::connect(server_addr).await.unwrap();
+    let mut request = Vec::with_capacity(8);
+    request.extend_from_slice(&0u32.to_le_bytes());
+    request.extend_from_slice(&PING_CODE.to_le_bytes());
+    stream.write_all(&request).await.unwrap();
+    assert_eq!(
+        stream.read_u32_le().await.unwrap(),
+        IggyError::InvalidFormat.as_code()
+    );
+    assert_eq!(stream.read_u32_le().await.unwrap(), 0);
+    assert_connection_closed(&mut stream).await;
+}
+
+/// Sends the request with the given code, declaring the given payload length without sending the payload,
+/// and returns the status of the response.
+async fn send_raw(stream: &mut TcpStream, code: u32, payload_length: u32) -> u32 {
+    let mut request = Vec::with_capacity(8);
+    request.extend_from_slice(&(payload_length + 4).to_le_bytes());
+    request.extend_from_slice(&code.to_le_bytes());
+    stream.write_all(&request).await.unwrap();
+
+    let status = stream.read_u32_le().await.unwrap();
+    let length = stream.read_u32_le().await.unwrap();
+    let mut payload = vec![0u8; length as usize];
+    stream.read_exact(&mut payload).await.unwrap();
+    status
+}
+
+async fn assert_connection_closed(stream: &mut TcpStream) {
+    let mut buffer = [0u8; 1];
+    assert_eq!(stream.read(&mut buffer).await.unwrap_or_default(), 0);
+}
@@ -0,0 +1,129 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, login_root};
+use std::str::FromStr;
+use std::time::Duration;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "stats-history-stream";
+const TOPIC_NAME: &str = "stats-history-topic";
+const MESSAGES_COUNT: u64 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, None, None);
+    login_root(&client).await;
+
+    let short_term_interval = IggyDuration::from_str("100ms").unwrap();
+    let long_term_interval = IggyDuration::from_str("500ms").unwrap();
+
+    // 1. Both series are returned, from the finest to the coarsest resolution.
+    let stats_history = client.get_stats_history().await.unwrap();
+    assert_eq!(stats_history.series.len(), 2);
+    assert_eq!(stats_history.series[0].interval, short_term_interval);
+    assert_eq!(stats_history.series[1].interval, long_term_interval);
+    assert_eq!(
+        stats_history.series[0].retention,
+        IggyDuration::from_str("10s").unwrap()
+    );
+
+    // 2. The messages appended to the topic are reflected in its throughput.
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::None,
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|id| {
+            IggyMessage::builder()
+                .payload(Bytes::from(format!("message-{id}")))
+                .build()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(1),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+
+    let stats_history = wait_for_topic_throughput(&client, short_term_interval).await;
+    let series = stats_history.get_series(short_term_interval).unwrap();
+    let samples = &series.samples;
+    assert!(
+        samples
+            .windows(2)
+            .all(|pair| pair[0].timestamp.as_micros() < pair[1].timestamp.as_micros())
+    );
+    let appended_messages = samples
+        .iter()
+        .flat_map(|sample| &sample.topics)
+        .filter(|topic| topic.stream_id == STREAM_ID && topic.topic_id == TOPIC_ID)
+        .map(|topic| topic.messages_count)
+        .sum::<u64>();
+    assert_eq!(appended_messages, MESSAGES_COUNT);
+    let appended_bytes = samples
+        .iter()
+        .map(|sample| sample.appended_bytes().as_bytes_u64())
+        .sum::<u64>();
+    assert!(appended_bytes > 0);
+    assert!(samples.last().unwrap().memory_usage.as_bytes_u64() > 0);
+
+    client.delete_stream(&stream_id).await.unwrap();
+}
+
+async fn wait_for_topic_throughput(client: &IggyClient, interval: IggyDuration) -> StatsHistory {
+    // The stats are sampled in the background, thus the throughput might not be available immediately.
+    for _ in 0..50 {
+        let stats_history = client.get_stats_history().await.unwrap();
+        let sampled = stats_history.get_series(interval).is_some_and(|series| {
+            series.samples.iter().any(|sample| {
+                sample
+                    .topics
+                    .iter()
+                    .any(|topic| topic.stream_id == STREAM_ID && topic.topic_id == TOPIC_ID)
+            })
+        });
+        if sampled {
+            return stats_history;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("Topic throughput has not been sampled.");
+}
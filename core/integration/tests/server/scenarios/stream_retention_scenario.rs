@@ -0,0 +1,116 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::server::scenarios::{
+    PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_NAME, cleanup, create_client,
+};
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, assert_clean_system, login_root};
+
+const DEFAULT_TOPIC_ID: u32 = 1;
+const NEVER_EXPIRE_TOPIC_ID: u32 = 2;
+const EXPIRE_TOPIC_ID: u32 = 3;
+const STREAM_MESSAGE_EXPIRY_SECS: u64 = 600;
+const TOPIC_MESSAGE_EXPIRY_SECS: u64 = 3600;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 1. The topic using the server default reports it, while the other ones report their own expiry
+    let topic_expiry =
+        IggyExpiry::ExpireDuration(IggyDuration::new_from_secs(TOPIC_MESSAGE_EXPIRY_SECS));
+    for (topic_id, message_expiry) in [
+        (DEFAULT_TOPIC_ID, IggyExpiry::ServerDefault),
+        (NEVER_EXPIRE_TOPIC_ID, IggyExpiry::NeverExpire),
+        (EXPIRE_TOPIC_ID, topic_expiry),
+    ] {
+        let topic = client
+            .create_topic(
+                &stream_id,
+                &format!("{TOPIC_NAME}-{topic_id}"),
+                PARTITIONS_COUNT,
+                CompressionAlgorithm::default(),
+                None,
+                Some(topic_id),
+                message_expiry,
+                MaxTopicSize::ServerDefault,
+            )
+            .await
+            .unwrap();
+        if topic_id == DEFAULT_TOPIC_ID {
+            // The server default message expiry of the test server is "none".
+            assert_eq!(topic.message_expiry, IggyExpiry::NeverExpire);
+        }
+    }
+    assert_topics_expiry(&client, &stream_id, IggyExpiry::NeverExpire, topic_expiry).await;
+
+    // 2. The topic using the server default inherits the message expiry of its stream
+    let stream_expiry =
+        IggyExpiry::ExpireDuration(IggyDuration::new_from_secs(STREAM_MESSAGE_EXPIRY_SECS));
+    client
+        .update_stream_retention(
+            &stream_id,
+            MaxTopicSize::Unlimited,
+            stream_expiry,
+            StreamSizePolicy::default(),
+        )
+        .await
+        .unwrap();
+    assert_topics_expiry(&client, &stream_id, stream_expiry, topic_expiry).await;
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+/// Validates the message expiry of the topics reported by GetTopic, GetTopics and GetStream.
+async fn assert_topics_expiry(
+    client: &IggyClient,
+    stream_id: &Identifier,
+    default_topic_expiry: IggyExpiry,
+    topic_expiry: IggyExpiry,
+) {
+    let expected = [
+        (DEFAULT_TOPIC_ID, default_topic_expiry),
+        (NEVER_EXPIRE_TOPIC_ID, IggyExpiry::NeverExpire),
+        (EXPIRE_TOPIC_ID, topic_expiry),
+    ];
+    let topics = client.get_topics(stream_id).await.unwrap();
+    let stream = client.get_stream(stream_id).await.unwrap().unwrap();
+    for (topic_id, message_expiry) in expected {
+        let topic = client
+            .get_topic(stream_id, &Identifier::numeric(topic_id).unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(topic.message_expiry, message_expiry);
+        let topic = topics.iter().find(|topic| topic.id == topic_id).unwrap();
+        assert_eq!(topic.message_expiry, message_expiry);
+        let topic = stream
+            .topics
+            .iter()
+            .find(|topic| topic.id == topic_id)
+            .unwrap();
+        assert_eq!(topic.message_expiry, message_expiry);
+    }
+}
@@ -0,0 +1,316 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::prelude::*;
+use integration::test_server::{ClientFactory, login_root};
+use std::str::FromStr;
+use std::time::Duration;
+
+pub const SEGMENT_SIZE: &str = "1MiB";
+const EVENTS_STREAM: &str = "$system";
+const EVENTS_TOPIC: &str = "events";
+const STREAM_NAME: &str = "system-events-stream";
+const TOPIC_NAME: &str = "system-events-topic";
+const CONSUMER_GROUP_NAME: &str = "system-events-group";
+const USERNAME: &str = "system-events-user";
+const PASSWORD: &str = "secret";
+const LARGE_MESSAGE_SIZE: usize = 300 * 1024;
+
+pub async fn run(client_factory: &dyn ClientFactory, format: SystemEventFormat) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, None, None);
+    login_root(&client).await;
+
+    // 1. The stream and the topic storing the events are created on startup.
+    let events_stream_id = Identifier::named(EVENTS_STREAM).unwrap();
+    let events_topic_id = Identifier::named(EVENTS_TOPIC).unwrap();
+    let topic = wait_for_events_topic(&client, &events_stream_id, &events_topic_id).await;
+    assert_eq!(topic.partitions_count, 1);
+
+    // 2. The metadata changes are published in the order they've been applied.
+    let stream = client.create_stream(STREAM_NAME, None).await.unwrap();
+    let stream_id = Identifier::named(STREAM_NAME).unwrap();
+    let topic_id = Identifier::named(TOPIC_NAME).unwrap();
+    let topic = client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+    let group = client
+        .create_consumer_group(&stream_id, &topic_id, CONSUMER_GROUP_NAME, None)
+        .await
+        .unwrap();
+    let group_id = Identifier::named(CONSUMER_GROUP_NAME).unwrap();
+    client
+        .join_consumer_group(&stream_id, &topic_id, &group_id)
+        .await
+        .unwrap();
+    client
+        .leave_consumer_group(&stream_id, &topic_id, &group_id)
+        .await
+        .unwrap();
+    client
+        .create_partitions(&stream_id, &topic_id, 2)
+        .await
+        .unwrap();
+    let me = client.get_me().await.unwrap();
+
+    // 3. The segment rollover is published once the closed segment is followed by the new one.
+    for _ in 0..5 {
+        let mut messages = vec![
+            IggyMessage::builder()
+                .payload(Bytes::from(vec![0u8; LARGE_MESSAGE_SIZE]))
+                .build()
+                .unwrap(),
+        ];
+        client
+            .send_messages(
+                &stream_id,
+                &topic_id,
+                &Partitioning::partition_id(1),
+                &mut messages,
+            )
+            .await
+            .unwrap();
+    }
+
+    client
+        .create_user(USERNAME, PASSWORD, UserStatus::Active, None)
+        .await
+        .unwrap();
+    let user = client
+        .get_user(&Identifier::named(USERNAME).unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    client
+        .delete_user(&Identifier::named(USERNAME).unwrap())
+        .await
+        .unwrap();
+    client.delete_topic(&stream_id, &topic_id).await.unwrap();
+    client.delete_stream(&stream_id).await.unwrap();
+
+    let numeric = |id| Identifier::numeric(id).unwrap();
+    let expected_events = vec![
+        (
+            Some(DEFAULT_ROOT_USER_ID),
+            SystemEventKind::StreamCreated {
+                stream_id: numeric(stream.id),
+                name: STREAM_NAME.to_owned(),
+            },
+        ),
+        (
+            Some(DEFAULT_ROOT_USER_ID),
+            SystemEventKind::TopicCreated {
+                stream_id: stream_id.clone(),
+                topic_id: numeric(topic.id),
+                name: TOPIC_NAME.to_owned(),
+                partitions_count: 1,
+            },
+        ),
+        (
+            Some(DEFAULT_ROOT_USER_ID),
+            SystemEventKind::ConsumerGroupCreated {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                group_id: numeric(group.id),
+                name: CONSUMER_GROUP_NAME.to_owned(),
+            },
+        ),
+        (
+            Some(DEFAULT_ROOT_USER_ID),
+            SystemEventKind::ConsumerGroupMemberJoined {
+                stream_id: numeric(stream.id),
+                topic_id: numeric(topic.id),
+                group_id: numeric(group.id),
+                client_id: me.client_id,
+            },
+        ),
+        (
+            Some(DEFAULT_ROOT_USER_ID),
+            SystemEventKind::ConsumerGroupMemberLeft {
+                stream_id: numeric(stream.id),
+                topic_id: numeric(topic.id),
+                group_id: numeric(group.id),
+                client_id: me.client_id,
+            },
+        ),
+        (
+            Some(DEFAULT_ROOT_USER_ID),
+            SystemEventKind::PartitionsCreated {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partitions_count: 2,
+            },
+        ),
+        (
+            None,
+            SystemEventKind::SegmentRolledOver {
+                stream_id: numeric(stream.id),
+                topic_id: numeric(topic.id),
+                partition_id: 1,
+                start_offset: 4,
+            },
+        ),
+        (
+            Some(DEFAULT_ROOT_USER_ID),
+            SystemEventKind::UserCreated {
+                user_id: numeric(user.id),
+                username: USERNAME.to_owned(),
+            },
+        ),
+        (
+            Some(DEFAULT_ROOT_USER_ID),
+            SystemEventKind::UserDeleted {
+                user_id: Identifier::named(USERNAME).unwrap(),
+            },
+        ),
+        (
+            Some(DEFAULT_ROOT_USER_ID),
+            SystemEventKind::TopicDeleted {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+            },
+        ),
+        (
+            Some(DEFAULT_ROOT_USER_ID),
+            SystemEventKind::StreamDeleted {
+                stream_id: stream_id.clone(),
+            },
+        ),
+    ];
+
+    // 4. The events can be consumed as the regular messages, decoded using the configured format.
+    let events = poll_events(
+        &client,
+        &events_stream_id,
+        &events_topic_id,
+        format,
+        expected_events.len(),
+    )
+    .await;
+    let events = events
+        .into_iter()
+        .map(|event| {
+            assert_eq!(event.version, SYSTEM_EVENT_VERSION);
+            (event.initiator_id, event.kind)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(events, expected_events);
+
+    // 5. The stream storing the events can't be modified by the users, including the root.
+    let mut messages = vec![IggyMessage::from_str("event").unwrap()];
+    let errors = [
+        client
+            .send_messages(
+                &events_stream_id,
+                &events_topic_id,
+                &Partitioning::partition_id(1),
+                &mut messages,
+            )
+            .await
+            .unwrap_err(),
+        client
+            .purge_topic(&events_stream_id, &events_topic_id)
+            .await
+            .unwrap_err(),
+        client
+            .delete_topic(&events_stream_id, &events_topic_id)
+            .await
+            .unwrap_err(),
+        client
+            .update_stream(&events_stream_id, "renamed")
+            .await
+            .unwrap_err(),
+        client.purge_stream(&events_stream_id).await.unwrap_err(),
+        client.delete_stream(&events_stream_id).await.unwrap_err(),
+    ];
+    for error in errors {
+        assert_eq!(
+            error.as_code(),
+            IggyError::InternalStream(0).as_code(),
+            "unexpected error: {error}"
+        );
+    }
+    assert!(
+        client
+            .get_topic(&events_stream_id, &events_topic_id)
+            .await
+            .unwrap()
+            .is_some()
+    );
+}
+
+async fn wait_for_events_topic(
+    client: &IggyClient,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+) -> TopicDetails {
+    // The topic is created in the background, right after the server has started.
+    for _ in 0..50 {
+        if let Ok(Some(topic)) = client.get_topic(stream_id, topic_id).await {
+            return topic;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("System events topic has not been created.");
+}
+
+async fn poll_events(
+    client: &IggyClient,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+    format: SystemEventFormat,
+    count: usize,
+) -> Vec<SystemEvent> {
+    // The events are appended in the background, thus they might not be available immediately.
+    let mut messages = Vec::new();
+    for _ in 0..50 {
+        messages = client
+            .poll_messages(
+                stream_id,
+                topic_id,
+                Some(1),
+                &Consumer::default(),
+                &PollingStrategy::offset(0),
+                100,
+                false,
+            )
+            .await
+            .unwrap()
+            .messages;
+        if messages.len() >= count {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    messages
+        .iter()
+        .map(|message| format.decode(&message.payload).unwrap())
+        .collect()
+}
@@ -504,6 +504,14 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
             let me = client.get_me().await.unwrap();
             assert!(me.client_id > 0);
+            assert_eq!(me.authentication_method.as_deref(), Some("Password"));
+            assert!(me.bytes_received.as_bytes_u64() > 0);
+            assert!(me.bytes_sent.as_bytes_u64() > 0);
+            assert!(me.last_activity_at.as_micros() >= me.connected_at.as_micros());
+            let metadata = me.metadata.as_ref().unwrap();
+            assert_eq!(metadata.sdk_language, "rust");
+            assert!(!metadata.sdk_version.is_empty());
+            assert!(!metadata.application_name.is_empty());
             assert_eq!(me.consumer_groups_count, 1);
             assert_eq!(me.consumer_groups.len(), 1);
             let consumer_group = &me.consumer_groups[0];
@@ -0,0 +1,138 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use futures::future::join_all;
+use iggy::prelude::*;
+use integration::test_server::{assert_clean_system, login_root};
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-pipelining-stream";
+const TOPIC_NAME: &str = "test-pipelining-topic";
+const PARTITIONS_COUNT: u32 = 3;
+const REQUESTS_COUNT: u32 = 300;
+
+pub async fn run(client: &IggyClient) {
+    login_root(client).await;
+
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+
+    // 1. Send the messages concurrently, so that many requests are in flight on the same connection.
+    let sends = (1..=REQUESTS_COUNT).map(|id| {
+        let stream_id = stream_id.clone();
+        let topic_id = topic_id.clone();
+        async move {
+            let mut messages = vec![
+                IggyMessage::builder()
+                    .id(id as u128)
+                    .payload(Bytes::from(format!("message-{id}")))
+                    .build()
+                    .unwrap(),
+            ];
+            let partitioning = Partitioning::partition_id(id % PARTITIONS_COUNT + 1);
+            client
+                .send_messages(&stream_id, &topic_id, &partitioning, &mut messages)
+                .await
+        }
+    });
+    for result in join_all(sends).await {
+        assert!(result.is_ok());
+    }
+
+    // 2. Mix the successful and the failing requests, each caller must receive its own response.
+    let requests = (1..=REQUESTS_COUNT).map(|id| {
+        let stream_id = stream_id.clone();
+        async move {
+            if id % 2 == 0 {
+                let stream = client.get_stream(&stream_id).await.unwrap().unwrap();
+                assert_eq!(stream.name, STREAM_NAME);
+            } else {
+                let result = client.create_stream(STREAM_NAME, Some(STREAM_ID)).await;
+                assert!(result.is_err());
+            }
+        }
+    });
+    join_all(requests).await;
+
+    // 3. The slow request doesn't hold up the requests sent after it, as they're completed out of order.
+    let slow_request = async {
+        client
+            .profile(
+                ProfileKind::Cpu,
+                ProfileFormat::Pprof,
+                IggyDuration::new_from_secs(2),
+            )
+            .await
+            .unwrap();
+        Instant::now()
+    };
+    let fast_request = async {
+        sleep(Duration::from_millis(200)).await;
+        client.ping().await.unwrap();
+        Instant::now()
+    };
+    let (slow_completed_at, fast_completed_at) = tokio::join!(slow_request, fast_request);
+    assert!(fast_completed_at < slow_completed_at);
+
+    // 4. Validate that all the messages have been appended.
+    let mut polled_messages_count = 0;
+    for partition_id in 1..=PARTITIONS_COUNT {
+        let polled_messages = client
+            .poll_messages(
+                &stream_id,
+                &topic_id,
+                Some(partition_id),
+                &Consumer::default(),
+                &PollingStrategy::offset(0),
+                REQUESTS_COUNT,
+                false,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            polled_messages.messages.len() as u32,
+            REQUESTS_COUNT / PARTITIONS_COUNT
+        );
+        polled_messages_count += polled_messages.messages.len() as u32;
+    }
+    assert_eq!(polled_messages_count, REQUESTS_COUNT);
+
+    client.delete_stream(&stream_id).await.unwrap();
+    assert_clean_system(client).await;
+}
@@ -0,0 +1,199 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::Bytes;
+use iggy::clients::consumer::ReceivedMessage;
+use iggy::prelude::*;
+use integration::test_server::{assert_clean_system, login_root};
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-telemetry-stream";
+const TOPIC_NAME: &str = "test-telemetry-topic";
+const TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+const EXPORT_TIMEOUT: Duration = Duration::from_secs(30);
+
+type ExportRequests = Arc<Mutex<Vec<(String, Vec<u8>)>>>;
+
+/// In-process OTLP/HTTP collector, which accepts every export request and keeps its path and body.
+pub struct OtlpCollectorStub {
+    pub address: SocketAddr,
+    requests: ExportRequests,
+}
+
+impl OtlpCollectorStub {
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let requests = ExportRequests::default();
+        let collected_requests = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(handle_connection(stream, collected_requests.clone()));
+            }
+        });
+
+        Self { address, requests }
+    }
+
+    pub fn endpoint(&self, path: &str) -> String {
+        format!("http://{}{path}", self.address)
+    }
+
+    fn has_request(&self, path: &str, content: &[u8]) -> bool {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|(request_path, body)| {
+                request_path == path && body.windows(content.len()).any(|window| window == content)
+            })
+    }
+}
+
+pub async fn run(client: &IggyClient, collector: &OtlpCollectorStub) {
+    login_root(client).await;
+
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::None,
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+
+    // 1. Send the message carrying the producer trace context.
+    let trace_context = TraceContext::from_str(TRACEPARENT).unwrap();
+    let mut message = IggyMessage::builder()
+        .payload(Bytes::from("traced message"))
+        .build()
+        .unwrap();
+    message.set_trace_context(&trace_context).unwrap();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(1),
+            &mut [message],
+        )
+        .await
+        .unwrap();
+
+    // 2. The trace context is exposed to the consumer.
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(1),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            1,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 1);
+    let message = polled_messages.messages.into_iter().next().unwrap();
+    let received_message = ReceivedMessage::new(message, polled_messages.current_offset, 1);
+    assert_eq!(received_message.trace_context, Some(trace_context));
+
+    // 3. The server spans linked to the producer trace and the metrics are exported over OTLP.
+    let started_at = tokio::time::Instant::now();
+    loop {
+        let traces_exported = collector.has_request("/v1/traces", &trace_context.trace_id);
+        let metrics_exported = collector.has_request("/v1/metrics", b"messages_appended");
+        if traces_exported && metrics_exported {
+            break;
+        }
+
+        assert!(
+            started_at.elapsed() < EXPORT_TIMEOUT,
+            "OTLP export timed out, traces exported: {traces_exported}, metrics exported: {metrics_exported}"
+        );
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+
+    client.delete_stream(&stream_id).await.unwrap();
+    assert_clean_system(client).await;
+}
+
+async fn handle_connection(mut stream: TcpStream, requests: ExportRequests) {
+    let mut buffer = Vec::new();
+    loop {
+        let Some(headers_end) = buffer.windows(4).position(|window| window == b"\r\n\r\n") else {
+            let mut chunk = [0u8; 4096];
+            match stream.read(&mut chunk).await {
+                Ok(0) | Err(_) => return,
+                Ok(read) => buffer.extend_from_slice(&chunk[..read]),
+            }
+            continue;
+        };
+
+        let headers = String::from_utf8_lossy(&buffer[..headers_end]).to_string();
+        let path = headers
+            .split_whitespace()
+            .nth(1)
+            .unwrap_or_default()
+            .to_owned();
+        let content_length = headers
+            .lines()
+            .find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                name.eq_ignore_ascii_case("content-length")
+                    .then(|| value.trim().parse::<usize>().ok())?
+            })
+            .unwrap_or(0);
+
+        let body_start = headers_end + 4;
+        while buffer.len() < body_start + content_length {
+            let mut chunk = [0u8; 4096];
+            match stream.read(&mut chunk).await {
+                Ok(0) | Err(_) => return,
+                Ok(read) => buffer.extend_from_slice(&chunk[..read]),
+            }
+        }
+
+        let body = buffer[body_start..body_start + content_length].to_vec();
+        buffer.drain(..body_start + content_length);
+        requests.lock().unwrap().push((path, body));
+
+        let response =
+            b"HTTP/1.1 200 OK\r\ncontent-type: application/x-protobuf\r\ncontent-length: 0\r\n\r\n";
+        if stream.write_all(response).await.is_err() {
+            return;
+        }
+    }
+}
@@ -0,0 +1,190 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::server::scenarios::{
+    PARTITION_ID, PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME, USERNAME_1,
+    cleanup, create_client,
+};
+use bytes::Bytes;
+use iggy::prelude::*;
+use iggy_common::hello::Hello;
+use integration::test_server::{
+    ClientFactory, assert_clean_system, create_user, delete_user, login_root, login_user,
+};
+
+const MESSAGES_PER_SECOND: u64 = 10;
+const MESSAGES_COUNT: u32 = 20;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let root_client = create_client(client_factory).await;
+    login_root(&root_client).await;
+    init_system(&root_client).await;
+    let user_client = create_client(client_factory).await;
+    login_user(&user_client, USERNAME_1).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. Sending the messages beyond the produce quota should report the throttle time
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|offset| {
+            IggyMessage::builder()
+                .payload(Bytes::from(format!("message {offset}")))
+                .build()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let throttle_time = user_client
+        .send_messages_with_throttle_time(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+    assert!(throttle_time.is_some_and(|throttle_time| throttle_time.as_micros() > 0));
+
+    // 2. Polling the messages beyond the consume quota should report the throttle time
+    let polled_messages = user_client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    assert!(
+        polled_messages
+            .throttle_time
+            .is_some_and(|throttle_time| throttle_time.as_micros() > 0)
+    );
+
+    // 3. Sending and polling the messages without the quota shouldn't report any throttle time
+    let mut messages = vec![
+        IggyMessage::builder()
+            .payload(Bytes::from("message"))
+            .build()
+            .unwrap(),
+    ];
+    let throttle_time = root_client
+        .send_messages_with_throttle_time(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+    assert!(throttle_time.is_none());
+    let polled_messages = root_client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            1,
+            false,
+        )
+        .await
+        .unwrap();
+    assert!(polled_messages.throttle_time.is_none());
+
+    // 4. The clients which didn't negotiate the throttle time shouldn't receive it (Hello is not supported via HTTP)
+    let hello = Hello {
+        features: ProtocolFeatures::all().without(ProtocolFeatures::THROTTLE_TIME),
+        ..Default::default()
+    };
+    if user_client.hello(&hello).await.is_ok() {
+        let mut messages = (0..MESSAGES_COUNT)
+            .map(|offset| {
+                IggyMessage::builder()
+                    .payload(Bytes::from(format!("message {offset}")))
+                    .build()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let throttle_time = user_client
+            .send_messages_with_throttle_time(
+                &stream_id,
+                &topic_id,
+                &Partitioning::partition_id(PARTITION_ID),
+                &mut messages,
+            )
+            .await
+            .unwrap();
+        assert!(throttle_time.is_none());
+        let polled_messages = user_client
+            .poll_messages(
+                &stream_id,
+                &topic_id,
+                Some(PARTITION_ID),
+                &Consumer::default(),
+                &PollingStrategy::offset(0),
+                MESSAGES_COUNT,
+                false,
+            )
+            .await
+            .unwrap();
+        assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+        assert!(polled_messages.throttle_time.is_none());
+    }
+
+    delete_user(&root_client, USERNAME_1).await;
+    cleanup(&root_client, false).await;
+    assert_clean_system(&root_client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream and the topic
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+        )
+        .await
+        .unwrap();
+
+    // 2. Create the user with the throughput quota
+    create_user(client, USERNAME_1).await;
+    client
+        .update_user_quota(
+            &Identifier::named(USERNAME_1).unwrap(),
+            Some(ThroughputQuota {
+                produce_messages_per_second: MESSAGES_PER_SECOND,
+                consume_messages_per_second: MESSAGES_PER_SECOND,
+                ..Default::default()
+            }),
+        )
+        .await
+        .unwrap();
+}
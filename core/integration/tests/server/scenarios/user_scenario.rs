@@ -237,13 +237,19 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .await
         .unwrap();
 
-    // 23. Deleting another user should be allowed
+    // 23. Revoking the sessions of another user should be allowed
+    client
+        .revoke_user_sessions(&Identifier::named(updated_test_user).unwrap())
+        .await
+        .unwrap();
+
+    // 24. Deleting another user should be allowed
     client
         .delete_user(&Identifier::named(updated_test_user).unwrap())
         .await
         .unwrap();
 
-    // 24. Trying to delete the root user should fail
+    // 25. Trying to delete the root user should fail
     let delete_root_user = client
         .delete_user(&Identifier::named(DEFAULT_ROOT_USERNAME).unwrap())
         .await;
@@ -252,10 +258,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_clean_system(&client).await;
 
-    // 25. Logout
+    // 26. Logout
     client.logout_user().await.unwrap();
 
-    // 26. Trying to perform any secured operation after logout should fail
+    // 27. Trying to perform any secured operation after logout should fail
     let get_users = client.get_users().await;
     assert!(get_users.is_err());
 }
@@ -16,11 +16,23 @@
  * under the License.
  */
 
-use crate::server::scenarios::{delete_segments_scenario, message_size_scenario, tcp_tls_scenario};
+use crate::server::scenarios::{
+    client_eviction_scenario, config_reload_scenario, delete_segments_scenario,
+    frame_compression_scenario, health_scenario, http_message_formats_scenario,
+    http_stream_scenario, kafka_scenario, log_filter_scenario, log_shipping_scenario,
+    message_size_scenario, metrics_scenario, mqtt_scenario, profile_scenario,
+    protocol_handshake_scenario, stats_history_scenario, system_events_scenario,
+    tcp_pipelining_scenario, tcp_tls_scenario, telemetry_scenario,
+};
 use iggy::prelude::*;
 use integration::{
+    http_client::HttpClientFactory,
     tcp_client::TcpClientFactory,
-    test_server::{IpAddrKind, TestServer},
+    test_server::{
+        ClientFactory, IpAddrKind, KAFKA_ADDRESS_ENV_VAR, KAFKA_ENABLED_ENV_VAR,
+        MQTT_ADDRESS_ENV_VAR, MQTT_ENABLED_ENV_VAR, TestServer, UNIX_ENABLED_ENV_VAR,
+        UNIX_PATH_ENV_VAR,
+    },
     test_tls_utils::generate_test_certificates,
 };
 use serial_test::parallel;
@@ -46,6 +58,33 @@ async fn should_delete_segments_and_validate_filesystem() {
     delete_segments_scenario::run(&client_factory, &test_server).await;
 }
 
+// Config reload scenario requires the server to read the config from a file
+// modified by the test, so it's not included in the matrix.
+#[tokio::test]
+#[parallel]
+async fn config_reload_scenario_should_be_valid() {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+    let config_path = temp_dir.path().join("server.toml");
+    std::fs::write(&config_path, config_reload_scenario::INITIAL_CONFIG).unwrap();
+
+    let mut extra_envs = HashMap::new();
+    extra_envs.insert(
+        "IGGY_CONFIG_PATH".to_string(),
+        config_path.to_str().unwrap().to_string(),
+    );
+
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+
+    config_reload_scenario::run(&client_factory, &test_server, &config_path).await;
+}
+
 // TCP TLS scenario is obviously specific to TCP transport, and requires special
 // setup so it's not included in the matrix.
 #[tokio::test]
@@ -128,6 +167,277 @@ async fn tcp_tls_self_signed_scenario_should_be_valid() {
     tcp_tls_scenario::run(&client).await;
 }
 
+// Pipelining is specific to TCP transport, as QUIC multiplexes the requests using separate streams.
+#[tokio::test]
+#[parallel]
+async fn tcp_pipelining_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+
+    let client = IggyClientBuilder::new()
+        .with_tcp()
+        .with_server_address(server_addr)
+        .with_pipelining()
+        .build()
+        .expect("Failed to create pipelined TCP client");
+
+    client
+        .connect()
+        .await
+        .expect("Failed to connect pipelined TCP client");
+
+    let client = IggyClient::create(ClientWrapper::Iggy(client), None, None);
+
+    tcp_pipelining_scenario::run(&client).await;
+}
+
+// The metrics are served by the HTTP API, while the scenario uses the TCP transport.
+#[tokio::test]
+#[parallel]
+async fn tcp_metrics_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let http_api_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, None, None);
+
+    metrics_scenario::run(&client, &http_api_addr).await;
+}
+
+// The health probes are served by the HTTP API as well, while the scenario uses the TCP transport.
+#[tokio::test]
+#[parallel]
+async fn tcp_health_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let http_api_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, None, None);
+
+    health_scenario::run(&client, &http_api_addr).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn tcp_health_scenario_should_report_insufficient_disk_space() {
+    let mut extra_envs = HashMap::new();
+    extra_envs.insert(
+        "IGGY_HEALTH_MIN_FREE_DISK_SPACE".to_string(),
+        "1000 PB".to_string(),
+    );
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let http_api_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, None, None);
+
+    health_scenario::run_with_insufficient_disk_space(&client, &http_api_addr).await;
+}
+
+// The log filter is global for the whole server process, so the scenario runs against a dedicated server.
+#[tokio::test]
+#[parallel]
+async fn tcp_log_filter_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let client_factory = TcpClientFactory {
+        server_addr: test_server.get_raw_tcp_addr().unwrap(),
+        ..Default::default()
+    };
+
+    log_filter_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn http_log_filter_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let client_factory = HttpClientFactory {
+        server_addr: test_server.get_http_api_addr().unwrap(),
+    };
+
+    log_filter_scenario::run(&client_factory).await;
+}
+
+// The CPU profiler is global for the whole server process, so the scenario runs against a dedicated server.
+#[tokio::test]
+#[parallel]
+async fn tcp_profile_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let client_factory = TcpClientFactory {
+        server_addr: test_server.get_raw_tcp_addr().unwrap(),
+        ..Default::default()
+    };
+
+    profile_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn http_profile_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let client_factory = HttpClientFactory {
+        server_addr: test_server.get_http_api_addr().unwrap(),
+    };
+
+    profile_scenario::run(&client_factory).await;
+}
+
+// The telemetry is exported to the in-process OTLP collector, while the scenario uses the TCP transport.
+#[tokio::test]
+#[parallel]
+async fn tcp_telemetry_scenario_should_be_valid() {
+    let collector = telemetry_scenario::OtlpCollectorStub::start().await;
+    let mut extra_envs = HashMap::new();
+    extra_envs.insert("IGGY_TELEMETRY_ENABLED".to_string(), "true".to_string());
+    for (signal, path) in [
+        ("LOGS", "/v1/logs"),
+        ("TRACES", "/v1/traces"),
+        ("METRICS", "/v1/metrics"),
+    ] {
+        extra_envs.insert(
+            format!("IGGY_TELEMETRY_{signal}_TRANSPORT"),
+            "http".to_string(),
+        );
+        extra_envs.insert(
+            format!("IGGY_TELEMETRY_{signal}_ENDPOINT"),
+            collector.endpoint(path),
+        );
+    }
+    extra_envs.insert(
+        "IGGY_TELEMETRY_METRICS_INTERVAL".to_string(),
+        "1s".to_string(),
+    );
+
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr,
+        ..Default::default()
+    };
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, None, None);
+
+    telemetry_scenario::run(&client, &collector).await;
+}
+
+// Frame compression is specific to TCP and QUIC transports, which negotiate it on connect.
+#[tokio::test]
+#[parallel]
+async fn tcp_frame_compression_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let http_api_addr = test_server.get_http_api_addr().unwrap();
+
+    let client = IggyClientBuilder::new()
+        .with_tcp()
+        .with_server_address(server_addr)
+        .with_frame_compression(FrameCompressionAlgorithm::Zstd, 1024)
+        .build()
+        .expect("Failed to create TCP client with frame compression");
+
+    client
+        .connect()
+        .await
+        .expect("Failed to connect TCP client with frame compression");
+
+    let client = IggyClient::create(ClientWrapper::Iggy(client), None, None);
+
+    frame_compression_scenario::run(&client, &http_api_addr, FrameCompressionAlgorithm::Zstd).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn tcp_pipelined_frame_compression_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let http_api_addr = test_server.get_http_api_addr().unwrap();
+
+    let client = IggyClientBuilder::new()
+        .with_tcp()
+        .with_server_address(server_addr)
+        .with_pipelining()
+        .with_frame_compression(FrameCompressionAlgorithm::Lz4, 1024)
+        .build()
+        .expect("Failed to create pipelined TCP client with frame compression");
+
+    client
+        .connect()
+        .await
+        .expect("Failed to connect pipelined TCP client with frame compression");
+
+    let client = IggyClient::create(ClientWrapper::Iggy(client), None, None);
+
+    frame_compression_scenario::run(&client, &http_api_addr, FrameCompressionAlgorithm::Lz4).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn quic_frame_compression_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let http_api_addr = test_server.get_http_api_addr().unwrap();
+
+    let client = IggyClientBuilder::new()
+        .with_quic()
+        .with_server_address(server_addr)
+        .with_frame_compression(FrameCompressionAlgorithm::Zstd, 1024)
+        .build()
+        .expect("Failed to create QUIC client with frame compression");
+
+    client
+        .connect()
+        .await
+        .expect("Failed to connect QUIC client with frame compression");
+
+    let client = IggyClient::create(ClientWrapper::Iggy(client), None, None);
+
+    frame_compression_scenario::run(&client, &http_api_addr, FrameCompressionAlgorithm::Zstd).await;
+}
+
+// The handshake is validated using the raw TCP connections, as they allow skipping it like the legacy clients do.
+#[tokio::test]
+#[parallel]
+async fn protocol_handshake_scenario_should_be_valid() {
+    let mut extra_envs = HashMap::new();
+    extra_envs.insert(
+        "IGGY_SYSTEM_PROTOCOL_MIN_CLIENT_PROTOCOL_VERSION".to_string(),
+        protocol_handshake_scenario::MIN_CLIENT_PROTOCOL_VERSION.to_string(),
+    );
+    extra_envs.insert(
+        "IGGY_SYSTEM_PROTOCOL_MAX_FRAME_SIZE".to_string(),
+        "1MiB".to_string(),
+    );
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+
+    protocol_handshake_scenario::run(&server_addr).await;
+}
+
 // Message size scenario is specific to TCP transport to test the behavior around the maximum message size.
 // When run on other transports, it will fail because both QUIC and HTTP have different message size limits.
 #[tokio::test]
@@ -143,3 +453,259 @@ async fn message_size_scenario() {
 
     message_size_scenario::run(&client_factory).await;
 }
+
+// Peer credentials are specific to Unix domain sockets, as the kernel provides the UID of the connecting process.
+#[tokio::test]
+#[parallel]
+async fn unix_peer_credentials_should_sign_in_without_password() {
+    let uid = unsafe { libc::getuid() };
+    let mut extra_envs = HashMap::new();
+    extra_envs.insert(UNIX_ENABLED_ENV_VAR.to_string(), "true".to_string());
+    extra_envs.insert(
+        UNIX_PATH_ENV_VAR.to_string(),
+        TestServer::get_random_unix_socket_path(),
+    );
+    extra_envs.insert(
+        "IGGY_UNIX_PEER_CREDENTIALS_ENABLED".to_string(),
+        "true".to_string(),
+    );
+    extra_envs.insert(
+        "IGGY_UNIX_PEER_CREDENTIALS_USERS".to_string(),
+        format!("[{uid}:{DEFAULT_ROOT_USERNAME}]"),
+    );
+
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let socket_path = test_server.get_unix_socket_path().unwrap();
+
+    let client = IggyClientBuilder::new()
+        .with_unix()
+        .with_socket_path(socket_path)
+        .with_peer_credentials()
+        .build()
+        .expect("Failed to create Unix client");
+    client
+        .connect()
+        .await
+        .expect("Failed to connect Unix client");
+
+    let me = client.get_me().await.unwrap();
+    assert_eq!(me.user_id, Some(DEFAULT_ROOT_USER_ID));
+    assert_eq!(me.transport, "Unix");
+    assert_eq!(me.authentication_method.as_deref(), Some("PeerCredentials"));
+    client.get_streams().await.unwrap();
+}
+
+// The Kafka listener is a separate wire protocol, exercised with the raw Kafka requests,
+// and requires enabling it along with the default stream to which the Kafka topics are mapped.
+#[tokio::test]
+#[parallel]
+async fn kafka_scenario_should_be_valid() {
+    let mut extra_envs = HashMap::new();
+    extra_envs.insert(KAFKA_ENABLED_ENV_VAR.to_string(), "true".to_string());
+    extra_envs.insert(KAFKA_ADDRESS_ENV_VAR.to_string(), "127.0.0.1:0".to_string());
+    extra_envs.insert(
+        "IGGY_KAFKA_DEFAULT_STREAM".to_string(),
+        kafka_scenario::DEFAULT_STREAM.to_string(),
+    );
+
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let kafka_addr = test_server.get_kafka_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr: test_server.get_raw_tcp_addr().unwrap(),
+        ..Default::default()
+    };
+
+    kafka_scenario::run(&kafka_addr, &client_factory).await;
+}
+
+// The MQTT listener is a separate wire protocol, exercised with the raw MQTT packets,
+// and requires enabling it along with the rules mapping the MQTT topics to the Iggy topics.
+#[tokio::test]
+#[parallel]
+async fn mqtt_scenario_should_be_valid() {
+    let mut extra_envs = HashMap::new();
+    extra_envs.insert(MQTT_ENABLED_ENV_VAR.to_string(), "true".to_string());
+    extra_envs.insert(MQTT_ADDRESS_ENV_VAR.to_string(), "127.0.0.1:0".to_string());
+    extra_envs.insert(
+        "IGGY_MQTT_RULES".to_string(),
+        mqtt_scenario::MAPPING_RULES.to_string(),
+    );
+
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let mqtt_addr = test_server.get_mqtt_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr: test_server.get_raw_tcp_addr().unwrap(),
+        ..Default::default()
+    };
+
+    mqtt_scenario::run(&mqtt_addr, &client_factory).await;
+}
+
+// The messages are streamed over HTTP as the Server-Sent Events, read from the raw chunked response.
+#[tokio::test]
+#[parallel]
+async fn http_stream_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let http_api_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr: test_server.get_raw_tcp_addr().unwrap(),
+        ..Default::default()
+    };
+
+    http_stream_scenario::run(&http_api_addr, &client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn http_message_formats_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let http_api_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr: test_server.get_raw_tcp_addr().unwrap(),
+        ..Default::default()
+    };
+
+    http_message_formats_scenario::run(&http_api_addr, &client_factory).await;
+}
+
+// The system events are published only when enabled in the config, and the scenario expects
+// the exact sequence of the events, thus it runs against a dedicated server.
+#[tokio::test]
+#[parallel]
+async fn tcp_system_events_scenario_should_be_valid() {
+    run_system_events_scenario(SystemEventFormat::Json).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn tcp_binary_system_events_scenario_should_be_valid() {
+    run_system_events_scenario(SystemEventFormat::Binary).await;
+}
+
+async fn run_system_events_scenario(format: SystemEventFormat) {
+    let mut extra_envs = HashMap::new();
+    extra_envs.insert("IGGY_EVENTS_ENABLED".to_string(), "true".to_string());
+    extra_envs.insert("IGGY_EVENTS_FORMAT".to_string(), format.to_string());
+    extra_envs.insert(
+        "IGGY_SYSTEM_SEGMENT_SIZE".to_string(),
+        system_events_scenario::SEGMENT_SIZE.to_string(),
+    );
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let client_factory = TcpClientFactory {
+        server_addr: test_server.get_raw_tcp_addr().unwrap(),
+        ..Default::default()
+    };
+
+    system_events_scenario::run(&client_factory, format).await;
+}
+
+// Log shipping scenario requires the logs topic to be enabled, thus it runs against a dedicated server.
+#[tokio::test]
+#[parallel]
+async fn tcp_log_shipping_scenario_should_be_valid() {
+    let mut extra_envs = HashMap::new();
+    extra_envs.insert(
+        "IGGY_SYSTEM_LOGGING_TOPIC_ENABLED".to_string(),
+        "true".to_string(),
+    );
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let client_factory = TcpClientFactory {
+        server_addr: test_server.get_raw_tcp_addr().unwrap(),
+        ..Default::default()
+    };
+
+    log_shipping_scenario::run(&client_factory).await;
+}
+
+fn stats_history_envs() -> HashMap<String, String> {
+    let mut extra_envs = HashMap::new();
+    for (key, value) in [
+        ("IGGY_SYSTEM_STATS_HISTORY_SHORT_TERM_INTERVAL", "100ms"),
+        ("IGGY_SYSTEM_STATS_HISTORY_SHORT_TERM_RETENTION", "10s"),
+        ("IGGY_SYSTEM_STATS_HISTORY_LONG_TERM_INTERVAL", "500ms"),
+        ("IGGY_SYSTEM_STATS_HISTORY_LONG_TERM_RETENTION", "1m"),
+    ] {
+        extra_envs.insert(key.to_string(), value.to_string());
+    }
+    extra_envs
+}
+
+// Stats history scenario requires the short sampling intervals, thus it runs against a dedicated server.
+#[tokio::test]
+#[parallel]
+async fn tcp_stats_history_scenario_should_be_valid() {
+    let mut test_server = TestServer::new(Some(stats_history_envs()), true, None, IpAddrKind::V4);
+    test_server.start();
+    let client_factory = TcpClientFactory {
+        server_addr: test_server.get_raw_tcp_addr().unwrap(),
+        ..Default::default()
+    };
+
+    stats_history_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn http_stats_history_scenario_should_be_valid() {
+    let mut test_server = TestServer::new(Some(stats_history_envs()), true, None, IpAddrKind::V4);
+    test_server.start();
+    let client_factory = HttpClientFactory {
+        server_addr: test_server.get_http_api_addr().unwrap(),
+    };
+
+    stats_history_scenario::run(&client_factory).await;
+}
+
+// Client eviction scenario requires the short timeouts, thus it runs against a dedicated server.
+#[tokio::test]
+#[parallel]
+async fn tcp_client_eviction_scenario_should_be_valid() {
+    let mut extra_envs = HashMap::new();
+    for (key, value) in [
+        ("IGGY_CLIENT_EVICTION_ENABLED", "true".to_string()),
+        (
+            "IGGY_CLIENT_EVICTION_INTERVAL",
+            client_eviction_scenario::VERIFICATION_INTERVAL.to_string(),
+        ),
+        (
+            "IGGY_CLIENT_EVICTION_IDLE_TIMEOUT",
+            IggyDuration::from(client_eviction_scenario::IDLE_TIMEOUT).to_string(),
+        ),
+        ("IGGY_CLIENT_EVICTION_IDLE_ACTION", "disconnect".to_string()),
+        (
+            "IGGY_CLIENT_EVICTION_PARTIAL_FRAME_TIMEOUT",
+            IggyDuration::from(client_eviction_scenario::PARTIAL_FRAME_TIMEOUT).to_string(),
+        ),
+        (
+            "IGGY_CLIENT_EVICTION_LAGGING_CONSUMER_CHECKS",
+            client_eviction_scenario::LAGGING_CONSUMER_CHECKS.to_string(),
+        ),
+        (
+            "IGGY_CLIENT_EVICTION_LAGGING_CONSUMER_MIN_LAG",
+            "1".to_string(),
+        ),
+        (
+            "IGGY_CLIENT_EVICTION_LAGGING_CONSUMER_ACTION",
+            "leave_consumer_group".to_string(),
+        ),
+    ] {
+        extra_envs.insert(key.to_string(), value);
+    }
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let http_api_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = TcpClientFactory {
+        server_addr: server_addr.clone(),
+        ..Default::default()
+    };
+
+    client_eviction_scenario::run(&client_factory, &server_addr, &http_api_addr).await;
+}
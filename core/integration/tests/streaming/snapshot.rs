@@ -38,12 +38,11 @@ async fn should_create_snapshot_file() {
 
     let session = Session::new(1, 1, SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 1234));
 
+    let snapshot_types = system
+        .ensure_can_get_snapshot(&session, &[SystemSnapshotType::Test])
+        .unwrap();
     let snapshot = system
-        .get_snapshot(
-            &session,
-            SnapshotCompression::Deflated,
-            &vec![SystemSnapshotType::Test],
-        )
+        .get_snapshot(SnapshotCompression::Deflated, &snapshot_types, None)
         .await
         .unwrap();
     assert!(!snapshot.0.is_empty());
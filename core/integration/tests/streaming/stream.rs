@@ -72,6 +72,9 @@ async fn should_load_existing_stream_from_disk() {
             id: stream_id,
             name: name.clone(),
             created_at: IggyTimestamp::now(),
+            max_size: MaxTopicSize::Unlimited,
+            message_expiry: IggyExpiry::NeverExpire,
+            size_policy: StreamSizePolicy::default(),
             topics: AHashMap::new(),
         };
         loaded_stream.load(state).await.unwrap();
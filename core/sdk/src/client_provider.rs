@@ -22,28 +22,35 @@ use crate::clients::client::IggyClient;
 use crate::http::http_client::HttpClient;
 use crate::prelude::{
     ClientError, HttpClientConfig, IggyDuration, QuicClientConfig, QuicClientReconnectionConfig,
-    TcpClientConfig, TcpClientReconnectionConfig,
+    TcpClientConfig, TcpClientReconnectionConfig, UnixClientConfig, WebSocketClientConfig,
 };
 use crate::quic::quic_client::QuicClient;
 use crate::tcp::tcp_client::TcpClient;
+use crate::unix::unix_client::UnixClient;
+use crate::websocket::websocket_client::WebSocketClient;
 use iggy_binary_protocol::Client;
-use iggy_common::{AutoLogin, Credentials};
+use iggy_common::{AutoLogin, ClientMetadata, Credentials};
 use std::str::FromStr;
 use std::sync::Arc;
 
 const QUIC_TRANSPORT: &str = "quic";
 const HTTP_TRANSPORT: &str = "http";
 const TCP_TRANSPORT: &str = "tcp";
+const UNIX_TRANSPORT: &str = "unix";
+const WS_TRANSPORT: &str = "ws";
+const WSS_TRANSPORT: &str = "wss";
 
 /// Configuration for the `ClientProvider`.
 /// It consists of the following fields:
-/// - `transport`: the transport to use. Valid values are `quic`, `http` and `tcp`.
+/// - `transport`: the transport to use. Valid values are `quic`, `http`, `tcp`, `unix`, `ws` and `wss`.
 /// - `http`: the optional configuration for the HTTP transport.
 /// - `quic`: the optional configuration for the QUIC transport.
 /// - `tcp`: the optional configuration for the TCP transport.
+/// - `unix`: the optional configuration for the Unix domain socket transport.
+/// - `websocket`: the optional configuration for the WebSocket (`ws` and `wss`) transport.
 #[derive(Debug)]
 pub struct ClientProviderConfig {
-    /// The transport to use. Valid values are `quic`, `http` and `tcp`.
+    /// The transport to use. Valid values are `quic`, `http`, `tcp`, `unix`, `ws` and `wss`.
     pub transport: String,
     /// The optional configuration for the HTTP transport.
     pub http: Option<Arc<HttpClientConfig>>,
@@ -51,6 +58,10 @@ pub struct ClientProviderConfig {
     pub quic: Option<Arc<QuicClientConfig>>,
     /// The optional configuration for the TCP transport.
     pub tcp: Option<Arc<TcpClientConfig>>,
+    /// The optional configuration for the Unix domain socket transport.
+    pub unix: Option<Arc<UnixClientConfig>>,
+    /// The optional configuration for the WebSocket (`ws` and `wss`) transport.
+    pub websocket: Option<Arc<WebSocketClientConfig>>,
 }
 
 impl Default for ClientProviderConfig {
@@ -60,6 +71,8 @@ impl Default for ClientProviderConfig {
             http: Some(Arc::new(HttpClientConfig::default())),
             quic: Some(Arc::new(QuicClientConfig::default())),
             tcp: Some(Arc::new(TcpClientConfig::default())),
+            unix: Some(Arc::new(UnixClientConfig::default())),
+            websocket: Some(Arc::new(WebSocketClientConfig::default())),
         }
     }
 }
@@ -71,17 +84,19 @@ impl ClientProviderConfig {
     }
 
     /// Create a new `ClientProviderConfig` from the provided `Args` with possibility to enable or disable
-    /// auto login option for TCP or QUIC protocols.
+    /// auto login option for TCP, QUIC, Unix or WebSocket protocols.
     pub fn from_args_set_autologin(
         args: crate::prelude::Args,
         auto_login: bool,
     ) -> Result<Self, ClientError> {
-        let transport = args.transport;
+        let transport = args.transport.clone();
         let mut config = Self {
             transport,
             http: None,
             quic: None,
             tcp: None,
+            unix: None,
+            websocket: None,
         };
         match config.transport.as_str() {
             QUIC_TRANSPORT => {
@@ -117,6 +132,8 @@ impl ClientProviderConfig {
                     keep_alive_interval: args.quic_keep_alive_interval,
                     max_idle_timeout: args.quic_max_idle_timeout,
                     validate_certificate: args.quic_validate_certificate,
+                    frame_compression: args.frame_compression,
+                    client_metadata: ClientMetadata::default(),
                 }));
             }
             HTTP_TRANSPORT => {
@@ -133,6 +150,8 @@ impl ClientProviderConfig {
                     tls_ca_file: args.tcp_tls_ca_file,
                     tls_validate_certificate: true,
                     nodelay: args.tcp_nodelay,
+                    pipelining: args.tcp_pipelining,
+                    frame_compression: args.frame_compression,
                     heartbeat_interval: IggyDuration::from_str(&args.tcp_heartbeat_interval)
                         .unwrap(),
                     reconnection: TcpClientReconnectionConfig {
@@ -152,6 +171,66 @@ impl ClientProviderConfig {
                     } else {
                         AutoLogin::Disabled
                     },
+                    client_metadata: ClientMetadata::default(),
+                }));
+            }
+            UNIX_TRANSPORT => {
+                config.unix = Some(Arc::new(UnixClientConfig {
+                    socket_path: args.unix_socket_path,
+                    pipelining: args.tcp_pipelining,
+                    peer_credentials: false,
+                    heartbeat_interval: IggyDuration::from_str(&args.tcp_heartbeat_interval)
+                        .unwrap(),
+                    reconnection: TcpClientReconnectionConfig {
+                        enabled: args.tcp_reconnection_enabled,
+                        max_retries: args.tcp_reconnection_max_retries,
+                        interval: IggyDuration::from_str(&args.tcp_reconnection_interval).unwrap(),
+                        reestablish_after: IggyDuration::from_str(
+                            &args.tcp_reconnection_reestablish_after,
+                        )
+                        .unwrap(),
+                    },
+                    auto_login: if auto_login {
+                        AutoLogin::Enabled(Credentials::UsernamePassword(
+                            args.username,
+                            args.password,
+                        ))
+                    } else {
+                        AutoLogin::Disabled
+                    },
+                    client_metadata: ClientMetadata::default(),
+                }));
+            }
+            WS_TRANSPORT | WSS_TRANSPORT => {
+                let (server_address, path) = args.get_websocket_address_and_path();
+                config.websocket = Some(Arc::new(WebSocketClientConfig {
+                    server_address,
+                    path,
+                    tls_enabled: config.transport == WSS_TRANSPORT,
+                    tls_domain: args.tcp_tls_domain,
+                    tls_ca_file: args.tcp_tls_ca_file,
+                    tls_validate_certificate: true,
+                    pipelining: args.tcp_pipelining,
+                    heartbeat_interval: IggyDuration::from_str(&args.tcp_heartbeat_interval)
+                        .unwrap(),
+                    reconnection: TcpClientReconnectionConfig {
+                        enabled: args.tcp_reconnection_enabled,
+                        max_retries: args.tcp_reconnection_max_retries,
+                        interval: IggyDuration::from_str(&args.tcp_reconnection_interval).unwrap(),
+                        reestablish_after: IggyDuration::from_str(
+                            &args.tcp_reconnection_reestablish_after,
+                        )
+                        .unwrap(),
+                    },
+                    auto_login: if auto_login {
+                        AutoLogin::Enabled(Credentials::UsernamePassword(
+                            args.username,
+                            args.password,
+                        ))
+                    } else {
+                        AutoLogin::Disabled
+                    },
+                    client_metadata: ClientMetadata::default(),
                 }));
             }
             _ => return Err(ClientError::InvalidTransport(config.transport.clone())),
@@ -207,6 +286,22 @@ pub async fn get_raw_client(
             };
             Ok(ClientWrapper::Tcp(client))
         }
+        UNIX_TRANSPORT => {
+            let unix_config = config.unix.as_ref().unwrap();
+            let client = UnixClient::create(unix_config.clone())?;
+            if establish_connection {
+                Client::connect(&client).await?
+            };
+            Ok(ClientWrapper::Unix(client))
+        }
+        WS_TRANSPORT | WSS_TRANSPORT => {
+            let websocket_config = config.websocket.as_ref().unwrap();
+            let client = WebSocketClient::create(websocket_config.clone())?;
+            if establish_connection {
+                Client::connect(&client).await?
+            };
+            Ok(ClientWrapper::WebSocket(client))
+        }
         _ => Err(ClientError::InvalidTransport(transport)),
     }
 }
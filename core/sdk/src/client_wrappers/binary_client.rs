@@ -30,6 +30,8 @@ impl Client for ClientWrapper {
             ClientWrapper::Http(client) => client.connect().await,
             ClientWrapper::Tcp(client) => client.connect().await,
             ClientWrapper::Quic(client) => client.connect().await,
+            ClientWrapper::Unix(client) => client.connect().await,
+            ClientWrapper::WebSocket(client) => client.connect().await,
         }
     }
 
@@ -39,6 +41,8 @@ impl Client for ClientWrapper {
             ClientWrapper::Http(client) => client.disconnect().await,
             ClientWrapper::Tcp(client) => client.disconnect().await,
             ClientWrapper::Quic(client) => client.disconnect().await,
+            ClientWrapper::Unix(client) => client.disconnect().await,
+            ClientWrapper::WebSocket(client) => client.disconnect().await,
         }
     }
 
@@ -48,6 +52,8 @@ impl Client for ClientWrapper {
             ClientWrapper::Http(client) => client.shutdown().await,
             ClientWrapper::Tcp(client) => client.shutdown().await,
             ClientWrapper::Quic(client) => client.shutdown().await,
+            ClientWrapper::Unix(client) => client.shutdown().await,
+            ClientWrapper::WebSocket(client) => client.shutdown().await,
         }
     }
 
@@ -57,6 +63,8 @@ impl Client for ClientWrapper {
             ClientWrapper::Http(client) => client.subscribe_events().await,
             ClientWrapper::Tcp(client) => client.subscribe_events().await,
             ClientWrapper::Quic(client) => client.subscribe_events().await,
+            ClientWrapper::Unix(client) => client.subscribe_events().await,
+            ClientWrapper::WebSocket(client) => client.subscribe_events().await,
         }
     }
 }
@@ -20,7 +20,8 @@ use crate::client_wrappers::client_wrapper::ClientWrapper;
 use async_trait::async_trait;
 use iggy_binary_protocol::MessageClient;
 use iggy_common::{
-    Consumer, Identifier, IggyError, IggyMessage, Partitioning, PolledMessages, PollingStrategy,
+    Consumer, Identifier, IggyDuration, IggyError, IggyMessage, Partitioning, PolledMessages,
+    PollingStrategy,
 };
 
 #[async_trait]
@@ -88,6 +89,32 @@ impl MessageClient for ClientWrapper {
                     )
                     .await
             }
+            ClientWrapper::Unix(client) => {
+                client
+                    .poll_messages(
+                        stream_id,
+                        topic_id,
+                        partition_id,
+                        consumer,
+                        strategy,
+                        count,
+                        auto_commit,
+                    )
+                    .await
+            }
+            ClientWrapper::WebSocket(client) => {
+                client
+                    .poll_messages(
+                        stream_id,
+                        topic_id,
+                        partition_id,
+                        consumer,
+                        strategy,
+                        count,
+                        auto_commit,
+                    )
+                    .await
+            }
         }
     }
 
@@ -98,25 +125,47 @@ impl MessageClient for ClientWrapper {
         partitioning: &Partitioning,
         messages: &mut [IggyMessage],
     ) -> Result<(), IggyError> {
+        self.send_messages_with_throttle_time(stream_id, topic_id, partitioning, messages)
+            .await
+            .map(|_| ())
+    }
+
+    async fn send_messages_with_throttle_time(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [IggyMessage],
+    ) -> Result<Option<IggyDuration>, IggyError> {
         match self {
             ClientWrapper::Iggy(client) => {
                 client
-                    .send_messages(stream_id, topic_id, partitioning, messages)
+                    .send_messages_with_throttle_time(stream_id, topic_id, partitioning, messages)
                     .await
             }
             ClientWrapper::Http(client) => {
                 client
-                    .send_messages(stream_id, topic_id, partitioning, messages)
+                    .send_messages_with_throttle_time(stream_id, topic_id, partitioning, messages)
                     .await
             }
             ClientWrapper::Tcp(client) => {
                 client
-                    .send_messages(stream_id, topic_id, partitioning, messages)
+                    .send_messages_with_throttle_time(stream_id, topic_id, partitioning, messages)
                     .await
             }
             ClientWrapper::Quic(client) => {
                 client
-                    .send_messages(stream_id, topic_id, partitioning, messages)
+                    .send_messages_with_throttle_time(stream_id, topic_id, partitioning, messages)
+                    .await
+            }
+            ClientWrapper::Unix(client) => {
+                client
+                    .send_messages_with_throttle_time(stream_id, topic_id, partitioning, messages)
+                    .await
+            }
+            ClientWrapper::WebSocket(client) => {
+                client
+                    .send_messages_with_throttle_time(stream_id, topic_id, partitioning, messages)
                     .await
             }
         }
@@ -150,6 +199,16 @@ impl MessageClient for ClientWrapper {
                     .flush_unsaved_buffer(stream_id, topic_id, partitioning_id, fsync)
                     .await
             }
+            ClientWrapper::Unix(client) => {
+                client
+                    .flush_unsaved_buffer(stream_id, topic_id, partitioning_id, fsync)
+                    .await
+            }
+            ClientWrapper::WebSocket(client) => {
+                client
+                    .flush_unsaved_buffer(stream_id, topic_id, partitioning_id, fsync)
+                    .await
+            }
         }
     }
 }
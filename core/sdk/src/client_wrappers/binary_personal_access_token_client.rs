@@ -20,8 +20,8 @@ use crate::client_wrappers::client_wrapper::ClientWrapper;
 use async_trait::async_trait;
 use iggy_binary_protocol::PersonalAccessTokenClient;
 use iggy_common::{
-    IdentityInfo, IggyError, PersonalAccessTokenExpiry, PersonalAccessTokenInfo,
-    RawPersonalAccessToken,
+    Identifier, IdentityInfo, IggyError, PersonalAccessTokenExpiry, PersonalAccessTokenInfo,
+    RawPersonalAccessToken, ThroughputQuota,
 };
 
 #[async_trait]
@@ -32,6 +32,8 @@ impl PersonalAccessTokenClient for ClientWrapper {
             ClientWrapper::Http(client) => client.get_personal_access_tokens().await,
             ClientWrapper::Tcp(client) => client.get_personal_access_tokens().await,
             ClientWrapper::Quic(client) => client.get_personal_access_tokens().await,
+            ClientWrapper::Unix(client) => client.get_personal_access_tokens().await,
+            ClientWrapper::WebSocket(client) => client.get_personal_access_tokens().await,
         }
     }
 
@@ -45,6 +47,10 @@ impl PersonalAccessTokenClient for ClientWrapper {
             ClientWrapper::Http(client) => client.create_personal_access_token(name, expiry).await,
             ClientWrapper::Tcp(client) => client.create_personal_access_token(name, expiry).await,
             ClientWrapper::Quic(client) => client.create_personal_access_token(name, expiry).await,
+            ClientWrapper::Unix(client) => client.create_personal_access_token(name, expiry).await,
+            ClientWrapper::WebSocket(client) => {
+                client.create_personal_access_token(name, expiry).await
+            }
         }
     }
 
@@ -54,6 +60,48 @@ impl PersonalAccessTokenClient for ClientWrapper {
             ClientWrapper::Http(client) => client.delete_personal_access_token(name).await,
             ClientWrapper::Tcp(client) => client.delete_personal_access_token(name).await,
             ClientWrapper::Quic(client) => client.delete_personal_access_token(name).await,
+            ClientWrapper::Unix(client) => client.delete_personal_access_token(name).await,
+            ClientWrapper::WebSocket(client) => client.delete_personal_access_token(name).await,
+        }
+    }
+
+    async fn update_personal_access_token_quota(
+        &self,
+        user_id: &Identifier,
+        name: &str,
+        quota: Option<ThroughputQuota>,
+    ) -> Result<(), IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => {
+                client
+                    .update_personal_access_token_quota(user_id, name, quota)
+                    .await
+            }
+            ClientWrapper::Http(client) => {
+                client
+                    .update_personal_access_token_quota(user_id, name, quota)
+                    .await
+            }
+            ClientWrapper::Tcp(client) => {
+                client
+                    .update_personal_access_token_quota(user_id, name, quota)
+                    .await
+            }
+            ClientWrapper::Quic(client) => {
+                client
+                    .update_personal_access_token_quota(user_id, name, quota)
+                    .await
+            }
+            ClientWrapper::Unix(client) => {
+                client
+                    .update_personal_access_token_quota(user_id, name, quota)
+                    .await
+            }
+            ClientWrapper::WebSocket(client) => {
+                client
+                    .update_personal_access_token_quota(user_id, name, quota)
+                    .await
+            }
         }
     }
 
@@ -66,6 +114,10 @@ impl PersonalAccessTokenClient for ClientWrapper {
             ClientWrapper::Http(client) => client.login_with_personal_access_token(token).await,
             ClientWrapper::Tcp(client) => client.login_with_personal_access_token(token).await,
             ClientWrapper::Quic(client) => client.login_with_personal_access_token(token).await,
+            ClientWrapper::Unix(client) => client.login_with_personal_access_token(token).await,
+            ClientWrapper::WebSocket(client) => {
+                client.login_with_personal_access_token(token).await
+            }
         }
     }
 }
@@ -19,7 +19,9 @@
 use crate::client_wrappers::client_wrapper::ClientWrapper;
 use async_trait::async_trait;
 use iggy_binary_protocol::StreamClient;
-use iggy_common::{Identifier, IggyError, Stream, StreamDetails};
+use iggy_common::{
+    Identifier, IggyError, IggyExpiry, MaxTopicSize, Stream, StreamDetails, StreamSizePolicy,
+};
 
 #[async_trait]
 impl StreamClient for ClientWrapper {
@@ -29,6 +31,8 @@ impl StreamClient for ClientWrapper {
             ClientWrapper::Http(client) => client.get_stream(stream_id).await,
             ClientWrapper::Tcp(client) => client.get_stream(stream_id).await,
             ClientWrapper::Quic(client) => client.get_stream(stream_id).await,
+            ClientWrapper::Unix(client) => client.get_stream(stream_id).await,
+            ClientWrapper::WebSocket(client) => client.get_stream(stream_id).await,
         }
     }
 
@@ -38,6 +42,8 @@ impl StreamClient for ClientWrapper {
             ClientWrapper::Http(client) => client.get_streams().await,
             ClientWrapper::Tcp(client) => client.get_streams().await,
             ClientWrapper::Quic(client) => client.get_streams().await,
+            ClientWrapper::Unix(client) => client.get_streams().await,
+            ClientWrapper::WebSocket(client) => client.get_streams().await,
         }
     }
 
@@ -51,6 +57,8 @@ impl StreamClient for ClientWrapper {
             ClientWrapper::Http(client) => client.create_stream(name, stream_id).await,
             ClientWrapper::Tcp(client) => client.create_stream(name, stream_id).await,
             ClientWrapper::Quic(client) => client.create_stream(name, stream_id).await,
+            ClientWrapper::Unix(client) => client.create_stream(name, stream_id).await,
+            ClientWrapper::WebSocket(client) => client.create_stream(name, stream_id).await,
         }
     }
 
@@ -60,6 +68,8 @@ impl StreamClient for ClientWrapper {
             ClientWrapper::Http(client) => client.update_stream(stream_id, name).await,
             ClientWrapper::Tcp(client) => client.update_stream(stream_id, name).await,
             ClientWrapper::Quic(client) => client.update_stream(stream_id, name).await,
+            ClientWrapper::Unix(client) => client.update_stream(stream_id, name).await,
+            ClientWrapper::WebSocket(client) => client.update_stream(stream_id, name).await,
         }
     }
 
@@ -69,6 +79,8 @@ impl StreamClient for ClientWrapper {
             ClientWrapper::Http(client) => client.delete_stream(stream_id).await,
             ClientWrapper::Tcp(client) => client.delete_stream(stream_id).await,
             ClientWrapper::Quic(client) => client.delete_stream(stream_id).await,
+            ClientWrapper::Unix(client) => client.delete_stream(stream_id).await,
+            ClientWrapper::WebSocket(client) => client.delete_stream(stream_id).await,
         }
     }
 
@@ -78,6 +90,49 @@ impl StreamClient for ClientWrapper {
             ClientWrapper::Http(client) => client.purge_stream(stream_id).await,
             ClientWrapper::Tcp(client) => client.purge_stream(stream_id).await,
             ClientWrapper::Quic(client) => client.purge_stream(stream_id).await,
+            ClientWrapper::Unix(client) => client.purge_stream(stream_id).await,
+            ClientWrapper::WebSocket(client) => client.purge_stream(stream_id).await,
+        }
+    }
+
+    async fn update_stream_retention(
+        &self,
+        stream_id: &Identifier,
+        max_size: MaxTopicSize,
+        message_expiry: IggyExpiry,
+        size_policy: StreamSizePolicy,
+    ) -> Result<(), IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => {
+                client
+                    .update_stream_retention(stream_id, max_size, message_expiry, size_policy)
+                    .await
+            }
+            ClientWrapper::Http(client) => {
+                client
+                    .update_stream_retention(stream_id, max_size, message_expiry, size_policy)
+                    .await
+            }
+            ClientWrapper::Tcp(client) => {
+                client
+                    .update_stream_retention(stream_id, max_size, message_expiry, size_policy)
+                    .await
+            }
+            ClientWrapper::Quic(client) => {
+                client
+                    .update_stream_retention(stream_id, max_size, message_expiry, size_policy)
+                    .await
+            }
+            ClientWrapper::Unix(client) => {
+                client
+                    .update_stream_retention(stream_id, max_size, message_expiry, size_policy)
+                    .await
+            }
+            ClientWrapper::WebSocket(client) => {
+                client
+                    .update_stream_retention(stream_id, max_size, message_expiry, size_policy)
+                    .await
+            }
         }
     }
 }
@@ -19,9 +19,11 @@
 use crate::client_wrappers::client_wrapper::ClientWrapper;
 use async_trait::async_trait;
 use iggy_binary_protocol::SystemClient;
+use iggy_common::hello::Hello;
 use iggy_common::{
-    ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot, SnapshotCompression, Stats,
-    SystemSnapshotType,
+    ClientInfo, ClientInfoDetails, ClientMetadata, ConfigReload, Health, HealthProbe, IggyDuration,
+    IggyError, LogFilter, Profile, ProfileFormat, ProfileKind, ServerHello, Snapshot,
+    SnapshotCompression, Stats, StatsHistory, SystemSnapshotType,
 };
 
 #[async_trait]
@@ -32,6 +34,19 @@ impl SystemClient for ClientWrapper {
             ClientWrapper::Http(client) => client.get_stats().await,
             ClientWrapper::Tcp(client) => client.get_stats().await,
             ClientWrapper::Quic(client) => client.get_stats().await,
+            ClientWrapper::Unix(client) => client.get_stats().await,
+            ClientWrapper::WebSocket(client) => client.get_stats().await,
+        }
+    }
+
+    async fn get_stats_history(&self) -> Result<StatsHistory, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.get_stats_history().await,
+            ClientWrapper::Http(client) => client.get_stats_history().await,
+            ClientWrapper::Tcp(client) => client.get_stats_history().await,
+            ClientWrapper::Quic(client) => client.get_stats_history().await,
+            ClientWrapper::Unix(client) => client.get_stats_history().await,
+            ClientWrapper::WebSocket(client) => client.get_stats_history().await,
         }
     }
 
@@ -41,6 +56,8 @@ impl SystemClient for ClientWrapper {
             ClientWrapper::Http(client) => client.get_me().await,
             ClientWrapper::Tcp(client) => client.get_me().await,
             ClientWrapper::Quic(client) => client.get_me().await,
+            ClientWrapper::Unix(client) => client.get_me().await,
+            ClientWrapper::WebSocket(client) => client.get_me().await,
         }
     }
 
@@ -50,6 +67,8 @@ impl SystemClient for ClientWrapper {
             ClientWrapper::Http(client) => client.get_client(client_id).await,
             ClientWrapper::Tcp(client) => client.get_client(client_id).await,
             ClientWrapper::Quic(client) => client.get_client(client_id).await,
+            ClientWrapper::Unix(client) => client.get_client(client_id).await,
+            ClientWrapper::WebSocket(client) => client.get_client(client_id).await,
         }
     }
 
@@ -59,6 +78,41 @@ impl SystemClient for ClientWrapper {
             ClientWrapper::Http(client) => client.get_clients().await,
             ClientWrapper::Tcp(client) => client.get_clients().await,
             ClientWrapper::Quic(client) => client.get_clients().await,
+            ClientWrapper::Unix(client) => client.get_clients().await,
+            ClientWrapper::WebSocket(client) => client.get_clients().await,
+        }
+    }
+
+    async fn disconnect_client(&self, client_id: u32) -> Result<(), IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.disconnect_client(client_id).await,
+            ClientWrapper::Http(client) => client.disconnect_client(client_id).await,
+            ClientWrapper::Tcp(client) => client.disconnect_client(client_id).await,
+            ClientWrapper::Quic(client) => client.disconnect_client(client_id).await,
+            ClientWrapper::Unix(client) => client.disconnect_client(client_id).await,
+            ClientWrapper::WebSocket(client) => client.disconnect_client(client_id).await,
+        }
+    }
+
+    async fn hello(&self, hello: &Hello) -> Result<ServerHello, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.hello(hello).await,
+            ClientWrapper::Http(client) => client.hello(hello).await,
+            ClientWrapper::Tcp(client) => client.hello(hello).await,
+            ClientWrapper::Quic(client) => client.hello(hello).await,
+            ClientWrapper::Unix(client) => client.hello(hello).await,
+            ClientWrapper::WebSocket(client) => client.hello(hello).await,
+        }
+    }
+
+    async fn identify_client(&self, metadata: &ClientMetadata) -> Result<(), IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.identify_client(metadata).await,
+            ClientWrapper::Http(client) => client.identify_client(metadata).await,
+            ClientWrapper::Tcp(client) => client.identify_client(metadata).await,
+            ClientWrapper::Quic(client) => client.identify_client(metadata).await,
+            ClientWrapper::Unix(client) => client.identify_client(metadata).await,
+            ClientWrapper::WebSocket(client) => client.identify_client(metadata).await,
         }
     }
 
@@ -68,6 +122,8 @@ impl SystemClient for ClientWrapper {
             ClientWrapper::Http(client) => client.ping().await,
             ClientWrapper::Tcp(client) => client.ping().await,
             ClientWrapper::Quic(client) => client.ping().await,
+            ClientWrapper::Unix(client) => client.ping().await,
+            ClientWrapper::WebSocket(client) => client.ping().await,
         }
     }
 
@@ -77,6 +133,8 @@ impl SystemClient for ClientWrapper {
             ClientWrapper::Http(client) => client.heartbeat_interval().await,
             ClientWrapper::Tcp(client) => client.heartbeat_interval().await,
             ClientWrapper::Quic(client) => client.heartbeat_interval().await,
+            ClientWrapper::Unix(client) => client.heartbeat_interval().await,
+            ClientWrapper::WebSocket(client) => client.heartbeat_interval().await,
         }
     }
 
@@ -90,6 +148,72 @@ impl SystemClient for ClientWrapper {
             ClientWrapper::Http(client) => client.snapshot(compression, snapshot_types).await,
             ClientWrapper::Tcp(client) => client.snapshot(compression, snapshot_types).await,
             ClientWrapper::Quic(client) => client.snapshot(compression, snapshot_types).await,
+            ClientWrapper::Unix(client) => client.snapshot(compression, snapshot_types).await,
+            ClientWrapper::WebSocket(client) => client.snapshot(compression, snapshot_types).await,
+        }
+    }
+
+    async fn reload_config(&self) -> Result<ConfigReload, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.reload_config().await,
+            ClientWrapper::Http(client) => client.reload_config().await,
+            ClientWrapper::Tcp(client) => client.reload_config().await,
+            ClientWrapper::Quic(client) => client.reload_config().await,
+            ClientWrapper::Unix(client) => client.reload_config().await,
+            ClientWrapper::WebSocket(client) => client.reload_config().await,
+        }
+    }
+
+    async fn get_health(&self, probe: HealthProbe) -> Result<Health, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.get_health(probe).await,
+            ClientWrapper::Http(client) => client.get_health(probe).await,
+            ClientWrapper::Tcp(client) => client.get_health(probe).await,
+            ClientWrapper::Quic(client) => client.get_health(probe).await,
+            ClientWrapper::Unix(client) => client.get_health(probe).await,
+            ClientWrapper::WebSocket(client) => client.get_health(probe).await,
+        }
+    }
+
+    async fn get_log_filter(&self) -> Result<LogFilter, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.get_log_filter().await,
+            ClientWrapper::Http(client) => client.get_log_filter().await,
+            ClientWrapper::Tcp(client) => client.get_log_filter().await,
+            ClientWrapper::Quic(client) => client.get_log_filter().await,
+            ClientWrapper::Unix(client) => client.get_log_filter().await,
+            ClientWrapper::WebSocket(client) => client.get_log_filter().await,
+        }
+    }
+
+    async fn set_log_filter(
+        &self,
+        filter: Option<&str>,
+        ttl: Option<IggyDuration>,
+    ) -> Result<LogFilter, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.set_log_filter(filter, ttl).await,
+            ClientWrapper::Http(client) => client.set_log_filter(filter, ttl).await,
+            ClientWrapper::Tcp(client) => client.set_log_filter(filter, ttl).await,
+            ClientWrapper::Quic(client) => client.set_log_filter(filter, ttl).await,
+            ClientWrapper::Unix(client) => client.set_log_filter(filter, ttl).await,
+            ClientWrapper::WebSocket(client) => client.set_log_filter(filter, ttl).await,
+        }
+    }
+
+    async fn profile(
+        &self,
+        kind: ProfileKind,
+        format: ProfileFormat,
+        duration: IggyDuration,
+    ) -> Result<Profile, IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.profile(kind, format, duration).await,
+            ClientWrapper::Http(client) => client.profile(kind, format, duration).await,
+            ClientWrapper::Tcp(client) => client.profile(kind, format, duration).await,
+            ClientWrapper::Quic(client) => client.profile(kind, format, duration).await,
+            ClientWrapper::Unix(client) => client.profile(kind, format, duration).await,
+            ClientWrapper::WebSocket(client) => client.profile(kind, format, duration).await,
         }
     }
 }
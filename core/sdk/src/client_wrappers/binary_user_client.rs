@@ -20,7 +20,8 @@ use crate::client_wrappers::client_wrapper::ClientWrapper;
 use async_trait::async_trait;
 use iggy_binary_protocol::UserClient;
 use iggy_common::{
-    Identifier, IdentityInfo, IggyError, Permissions, UserInfo, UserInfoDetails, UserStatus,
+    Identifier, IdentityInfo, IggyError, Permissions, ThroughputQuota, UserInfo, UserInfoDetails,
+    UserStatus,
 };
 
 #[async_trait]
@@ -31,6 +32,8 @@ impl UserClient for ClientWrapper {
             ClientWrapper::Http(client) => client.get_user(user_id).await,
             ClientWrapper::Tcp(client) => client.get_user(user_id).await,
             ClientWrapper::Quic(client) => client.get_user(user_id).await,
+            ClientWrapper::Unix(client) => client.get_user(user_id).await,
+            ClientWrapper::WebSocket(client) => client.get_user(user_id).await,
         }
     }
 
@@ -40,6 +43,8 @@ impl UserClient for ClientWrapper {
             ClientWrapper::Http(client) => client.get_users().await,
             ClientWrapper::Tcp(client) => client.get_users().await,
             ClientWrapper::Quic(client) => client.get_users().await,
+            ClientWrapper::Unix(client) => client.get_users().await,
+            ClientWrapper::WebSocket(client) => client.get_users().await,
         }
     }
 
@@ -66,6 +71,16 @@ impl UserClient for ClientWrapper {
                     .create_user(username, password, status, permissions)
                     .await
             }
+            ClientWrapper::Unix(client) => {
+                client
+                    .create_user(username, password, status, permissions)
+                    .await
+            }
+            ClientWrapper::WebSocket(client) => {
+                client
+                    .create_user(username, password, status, permissions)
+                    .await
+            }
             ClientWrapper::Iggy(client) => {
                 client
                     .create_user(username, password, status, permissions)
@@ -79,6 +94,8 @@ impl UserClient for ClientWrapper {
             ClientWrapper::Http(client) => client.delete_user(user_id).await,
             ClientWrapper::Tcp(client) => client.delete_user(user_id).await,
             ClientWrapper::Quic(client) => client.delete_user(user_id).await,
+            ClientWrapper::Unix(client) => client.delete_user(user_id).await,
+            ClientWrapper::WebSocket(client) => client.delete_user(user_id).await,
             ClientWrapper::Iggy(client) => client.delete_user(user_id).await,
         }
     }
@@ -93,6 +110,8 @@ impl UserClient for ClientWrapper {
             ClientWrapper::Http(client) => client.update_user(user_id, username, status).await,
             ClientWrapper::Tcp(client) => client.update_user(user_id, username, status).await,
             ClientWrapper::Quic(client) => client.update_user(user_id, username, status).await,
+            ClientWrapper::Unix(client) => client.update_user(user_id, username, status).await,
+            ClientWrapper::WebSocket(client) => client.update_user(user_id, username, status).await,
             ClientWrapper::Iggy(client) => client.update_user(user_id, username, status).await,
         }
     }
@@ -107,6 +126,25 @@ impl UserClient for ClientWrapper {
             ClientWrapper::Http(client) => client.update_permissions(user_id, permissions).await,
             ClientWrapper::Tcp(client) => client.update_permissions(user_id, permissions).await,
             ClientWrapper::Quic(client) => client.update_permissions(user_id, permissions).await,
+            ClientWrapper::Unix(client) => client.update_permissions(user_id, permissions).await,
+            ClientWrapper::WebSocket(client) => {
+                client.update_permissions(user_id, permissions).await
+            }
+        }
+    }
+
+    async fn update_user_quota(
+        &self,
+        user_id: &Identifier,
+        quota: Option<ThroughputQuota>,
+    ) -> Result<(), IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.update_user_quota(user_id, quota).await,
+            ClientWrapper::Http(client) => client.update_user_quota(user_id, quota).await,
+            ClientWrapper::Tcp(client) => client.update_user_quota(user_id, quota).await,
+            ClientWrapper::Quic(client) => client.update_user_quota(user_id, quota).await,
+            ClientWrapper::Unix(client) => client.update_user_quota(user_id, quota).await,
+            ClientWrapper::WebSocket(client) => client.update_user_quota(user_id, quota).await,
         }
     }
 
@@ -132,6 +170,16 @@ impl UserClient for ClientWrapper {
                     .change_password(user_id, current_password, new_password)
                     .await
             }
+            ClientWrapper::Unix(client) => {
+                client
+                    .change_password(user_id, current_password, new_password)
+                    .await
+            }
+            ClientWrapper::WebSocket(client) => {
+                client
+                    .change_password(user_id, current_password, new_password)
+                    .await
+            }
             ClientWrapper::Iggy(client) => {
                 client
                     .change_password(user_id, current_password, new_password)
@@ -146,6 +194,8 @@ impl UserClient for ClientWrapper {
             ClientWrapper::Http(client) => client.login_user(username, password).await,
             ClientWrapper::Tcp(client) => client.login_user(username, password).await,
             ClientWrapper::Quic(client) => client.login_user(username, password).await,
+            ClientWrapper::Unix(client) => client.login_user(username, password).await,
+            ClientWrapper::WebSocket(client) => client.login_user(username, password).await,
         }
     }
 
@@ -155,6 +205,19 @@ impl UserClient for ClientWrapper {
             ClientWrapper::Http(client) => client.logout_user().await,
             ClientWrapper::Tcp(client) => client.logout_user().await,
             ClientWrapper::Quic(client) => client.logout_user().await,
+            ClientWrapper::Unix(client) => client.logout_user().await,
+            ClientWrapper::WebSocket(client) => client.logout_user().await,
+        }
+    }
+
+    async fn revoke_user_sessions(&self, user_id: &Identifier) -> Result<(), IggyError> {
+        match self {
+            ClientWrapper::Iggy(client) => client.revoke_user_sessions(user_id).await,
+            ClientWrapper::Http(client) => client.revoke_user_sessions(user_id).await,
+            ClientWrapper::Tcp(client) => client.revoke_user_sessions(user_id).await,
+            ClientWrapper::Quic(client) => client.revoke_user_sessions(user_id).await,
+            ClientWrapper::Unix(client) => client.revoke_user_sessions(user_id).await,
+            ClientWrapper::WebSocket(client) => client.revoke_user_sessions(user_id).await,
         }
     }
 }
@@ -20,6 +20,8 @@ use crate::clients::client::IggyClient;
 use crate::http::http_client::HttpClient;
 use crate::quic::quic_client::QuicClient;
 use crate::tcp::tcp_client::TcpClient;
+use crate::unix::unix_client::UnixClient;
+use crate::websocket::websocket_client::WebSocketClient;
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug)]
@@ -28,4 +30,6 @@ pub enum ClientWrapper {
     Http(HttpClient),
     Tcp(TcpClient),
     Quic(QuicClient),
+    Unix(UnixClient),
+    WebSocket(WebSocketClient),
 }
@@ -22,7 +22,8 @@ use bytes::Bytes;
 use iggy_binary_protocol::MessageClient;
 use iggy_common::locking::IggySharedMutFn;
 use iggy_common::{
-    Consumer, Identifier, IggyError, IggyMessage, Partitioning, PolledMessages, PollingStrategy,
+    Consumer, Identifier, IggyDuration, IggyError, IggyMessage, Partitioning, PolledMessages,
+    PollingStrategy,
 };
 
 #[async_trait]
@@ -74,6 +75,18 @@ impl MessageClient for IggyClient {
         partitioning: &Partitioning,
         messages: &mut [IggyMessage],
     ) -> Result<(), IggyError> {
+        self.send_messages_with_throttle_time(stream_id, topic_id, partitioning, messages)
+            .await
+            .map(|_| ())
+    }
+
+    async fn send_messages_with_throttle_time(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [IggyMessage],
+    ) -> Result<Option<IggyDuration>, IggyError> {
         if messages.is_empty() {
             return Err(IggyError::InvalidMessagesCount);
         }
@@ -88,7 +101,7 @@ impl MessageClient for IggyClient {
         self.client
             .read()
             .await
-            .send_messages(stream_id, topic_id, partitioning, messages)
+            .send_messages_with_throttle_time(stream_id, topic_id, partitioning, messages)
             .await
     }
 
@@ -21,8 +21,8 @@ use async_trait::async_trait;
 use iggy_binary_protocol::PersonalAccessTokenClient;
 use iggy_common::locking::IggySharedMutFn;
 use iggy_common::{
-    IdentityInfo, IggyError, PersonalAccessTokenExpiry, PersonalAccessTokenInfo,
-    RawPersonalAccessToken,
+    Identifier, IdentityInfo, IggyError, PersonalAccessTokenExpiry, PersonalAccessTokenInfo,
+    RawPersonalAccessToken, ThroughputQuota,
 };
 
 #[async_trait]
@@ -51,6 +51,19 @@ impl PersonalAccessTokenClient for IggyClient {
             .await
     }
 
+    async fn update_personal_access_token_quota(
+        &self,
+        user_id: &Identifier,
+        name: &str,
+        quota: Option<ThroughputQuota>,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_personal_access_token_quota(user_id, name, quota)
+            .await
+    }
+
     async fn login_with_personal_access_token(
         &self,
         token: &str,
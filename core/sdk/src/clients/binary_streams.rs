@@ -20,7 +20,9 @@ use crate::prelude::IggyClient;
 use async_trait::async_trait;
 use iggy_binary_protocol::StreamClient;
 use iggy_common::locking::IggySharedMutFn;
-use iggy_common::{Identifier, IggyError, Stream, StreamDetails};
+use iggy_common::{
+    Identifier, IggyError, IggyExpiry, MaxTopicSize, Stream, StreamDetails, StreamSizePolicy,
+};
 
 #[async_trait]
 impl StreamClient for IggyClient {
@@ -59,4 +61,18 @@ impl StreamClient for IggyClient {
     async fn purge_stream(&self, stream_id: &Identifier) -> Result<(), IggyError> {
         self.client.read().await.purge_stream(stream_id).await
     }
+
+    async fn update_stream_retention(
+        &self,
+        stream_id: &Identifier,
+        max_size: MaxTopicSize,
+        message_expiry: IggyExpiry,
+        size_policy: StreamSizePolicy,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_stream_retention(stream_id, max_size, message_expiry, size_policy)
+            .await
+    }
 }
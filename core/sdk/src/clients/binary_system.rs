@@ -19,10 +19,12 @@
 use crate::prelude::IggyClient;
 use async_trait::async_trait;
 use iggy_binary_protocol::SystemClient;
+use iggy_common::hello::Hello;
 use iggy_common::locking::IggySharedMutFn;
 use iggy_common::{
-    ClientInfo, ClientInfoDetails, IggyDuration, IggyError, Snapshot, SnapshotCompression, Stats,
-    SystemSnapshotType,
+    ClientInfo, ClientInfoDetails, ClientMetadata, ConfigReload, Health, HealthProbe, IggyDuration,
+    IggyError, LogFilter, Profile, ProfileFormat, ProfileKind, ServerHello, Snapshot,
+    SnapshotCompression, Stats, StatsHistory, SystemSnapshotType,
 };
 
 #[async_trait]
@@ -31,6 +33,10 @@ impl SystemClient for IggyClient {
         self.client.read().await.get_stats().await
     }
 
+    async fn get_stats_history(&self) -> Result<StatsHistory, IggyError> {
+        self.client.read().await.get_stats_history().await
+    }
+
     async fn get_me(&self) -> Result<ClientInfoDetails, IggyError> {
         self.client.read().await.get_me().await
     }
@@ -43,6 +49,18 @@ impl SystemClient for IggyClient {
         self.client.read().await.get_clients().await
     }
 
+    async fn disconnect_client(&self, client_id: u32) -> Result<(), IggyError> {
+        self.client.read().await.disconnect_client(client_id).await
+    }
+
+    async fn hello(&self, hello: &Hello) -> Result<ServerHello, IggyError> {
+        self.client.read().await.hello(hello).await
+    }
+
+    async fn identify_client(&self, metadata: &ClientMetadata) -> Result<(), IggyError> {
+        self.client.read().await.identify_client(metadata).await
+    }
+
     async fn ping(&self) -> Result<(), IggyError> {
         self.client.read().await.ping().await
     }
@@ -62,4 +80,37 @@ impl SystemClient for IggyClient {
             .snapshot(compression, snapshot_types)
             .await
     }
+
+    async fn reload_config(&self) -> Result<ConfigReload, IggyError> {
+        self.client.read().await.reload_config().await
+    }
+
+    async fn get_health(&self, probe: HealthProbe) -> Result<Health, IggyError> {
+        self.client.read().await.get_health(probe).await
+    }
+
+    async fn get_log_filter(&self) -> Result<LogFilter, IggyError> {
+        self.client.read().await.get_log_filter().await
+    }
+
+    async fn set_log_filter(
+        &self,
+        filter: Option<&str>,
+        ttl: Option<IggyDuration>,
+    ) -> Result<LogFilter, IggyError> {
+        self.client.read().await.set_log_filter(filter, ttl).await
+    }
+
+    async fn profile(
+        &self,
+        kind: ProfileKind,
+        format: ProfileFormat,
+        duration: IggyDuration,
+    ) -> Result<Profile, IggyError> {
+        self.client
+            .read()
+            .await
+            .profile(kind, format, duration)
+            .await
+    }
 }
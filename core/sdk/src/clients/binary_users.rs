@@ -21,7 +21,8 @@ use async_trait::async_trait;
 use iggy_binary_protocol::UserClient;
 use iggy_common::locking::IggySharedMutFn;
 use iggy_common::{
-    Identifier, IdentityInfo, IggyError, Permissions, UserInfo, UserInfoDetails, UserStatus,
+    Identifier, IdentityInfo, IggyError, Permissions, ThroughputQuota, UserInfo, UserInfoDetails,
+    UserStatus,
 };
 
 #[async_trait]
@@ -77,6 +78,18 @@ impl UserClient for IggyClient {
             .await
     }
 
+    async fn update_user_quota(
+        &self,
+        user_id: &Identifier,
+        quota: Option<ThroughputQuota>,
+    ) -> Result<(), IggyError> {
+        self.client
+            .read()
+            .await
+            .update_user_quota(user_id, quota)
+            .await
+    }
+
     async fn change_password(
         &self,
         user_id: &Identifier,
@@ -101,4 +114,8 @@ impl UserClient for IggyClient {
     async fn logout_user(&self) -> Result<(), IggyError> {
         self.client.read().await.logout_user().await
     }
+
+    async fn revoke_user_sessions(&self, user_id: &Identifier) -> Result<(), IggyError> {
+        self.client.read().await.revoke_user_sessions(user_id).await
+    }
 }
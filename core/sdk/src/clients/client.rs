@@ -27,6 +27,8 @@ use crate::prelude::IggyError;
 use crate::prelude::IggyProducerBuilder;
 use crate::quic::quic_client::QuicClient;
 use crate::tcp::tcp_client::TcpClient;
+use crate::unix::unix_client::UnixClient;
+use crate::websocket::websocket_client::WebSocketClient;
 use async_broadcast::Receiver;
 use async_trait::async_trait;
 use iggy_binary_protocol::{Client, SystemClient};
@@ -91,6 +93,14 @@ impl IggyClient {
             TransportProtocol::Http => Ok(IggyClient::new(ClientWrapper::Http(
                 HttpClient::from_connection_string(connection_string)?,
             ))),
+            TransportProtocol::Unix => Ok(IggyClient::new(ClientWrapper::Unix(
+                UnixClient::from_connection_string(connection_string)?,
+            ))),
+            TransportProtocol::Ws | TransportProtocol::Wss => {
+                Ok(IggyClient::new(ClientWrapper::WebSocket(
+                    WebSocketClient::from_connection_string(connection_string)?,
+                )))
+            }
         }
     }
 
@@ -21,11 +21,14 @@ use crate::clients::client::IggyClient;
 use crate::http::http_client::HttpClient;
 use crate::prelude::{
     AutoLogin, EncryptorKind, HttpClientConfigBuilder, IggyDuration, IggyError, Partitioner,
-    QuicClientConfigBuilder, TcpClientConfigBuilder,
+    QuicClientConfigBuilder, TcpClientConfigBuilder, UnixClientConfigBuilder,
+    WebSocketClientConfigBuilder,
 };
 use crate::quic::quic_client::QuicClient;
 use crate::tcp::tcp_client::TcpClient;
-use iggy_common::{ConnectionStringUtils, TransportProtocol};
+use crate::unix::unix_client::UnixClient;
+use crate::websocket::websocket_client::WebSocketClient;
+use iggy_common::{ConnectionStringUtils, FrameCompressionAlgorithm, TransportProtocol};
 use std::sync::Arc;
 use tracing::error;
 
@@ -63,6 +66,16 @@ impl IggyClientBuilder {
                     connection_string,
                 )?));
             }
+            TransportProtocol::Unix => {
+                builder.client = Some(ClientWrapper::Unix(UnixClient::from_connection_string(
+                    connection_string,
+                )?));
+            }
+            TransportProtocol::Ws | TransportProtocol::Wss => {
+                builder.client = Some(ClientWrapper::WebSocket(
+                    WebSocketClient::from_connection_string(connection_string)?,
+                ));
+            }
         }
 
         Ok(builder)
@@ -106,6 +119,26 @@ impl IggyClientBuilder {
         }
     }
 
+    /// This method provides fluent API for the Unix domain socket client configuration.
+    /// It returns the `UnixClientBuilder` instance, which allows to configure the Unix client with custom settings or using defaults.
+    /// This should be called after the non-protocol specific methods, such as `with_partitioner`, `with_encryptor` or `with_message_handler`.
+    pub fn with_unix(self) -> UnixClientBuilder {
+        UnixClientBuilder {
+            config: UnixClientConfigBuilder::default(),
+            parent_builder: self,
+        }
+    }
+
+    /// This method provides fluent API for the WebSocket client configuration.
+    /// It returns the `WebSocketClientBuilder` instance, which allows to configure the WebSocket client with custom settings or using defaults.
+    /// This should be called after the non-protocol specific methods, such as `with_partitioner`, `with_encryptor` or `with_message_handler`.
+    pub fn with_websocket(self) -> WebSocketClientBuilder {
+        WebSocketClientBuilder {
+            config: WebSocketClientConfigBuilder::default(),
+            parent_builder: self,
+        }
+    }
+
     /// This method provides fluent API for the HTTP client configuration.
     /// It returns the `HttpClientBuilder` instance, which allows to configure the HTTP client with custom settings or using defaults.
     /// This should be called after the non-protocol specific methods, such as `with_partitioner`, `with_encryptor` or `with_message_handler`.
@@ -197,6 +230,34 @@ impl TcpClientBuilder {
         self
     }
 
+    /// Enables pipelining of the requests of the concurrent callers on a single connection.
+    pub fn with_pipelining(mut self) -> Self {
+        self.config = self.config.with_pipelining();
+        self
+    }
+
+    /// Enables the transport-level compression of the frames of at least the given size, if supported by the server.
+    pub fn with_frame_compression(
+        mut self,
+        algorithm: FrameCompressionAlgorithm,
+        min_size: u32,
+    ) -> Self {
+        self.config = self.config.with_frame_compression(algorithm, min_size);
+        self
+    }
+
+    /// Sets the application name sent to the server as part of the client identification metadata.
+    pub fn with_application_name(mut self, application_name: String) -> Self {
+        self.config = self.config.with_application_name(application_name);
+        self
+    }
+
+    /// Adds the label sent to the server as part of the client identification metadata.
+    pub fn with_client_label(mut self, key: String, value: String) -> Self {
+        self.config = self.config.with_client_label(key, value);
+        self
+    }
+
     /// Builds the parent `IggyClient` with TCP configuration.
     pub fn build(self) -> Result<IggyClient, IggyError> {
         let client = TcpClient::create(Arc::new(self.config.build()?))?;
@@ -208,6 +269,172 @@ impl TcpClientBuilder {
     }
 }
 
+#[derive(Debug, Default)]
+pub struct UnixClientBuilder {
+    config: UnixClientConfigBuilder,
+    parent_builder: IggyClientBuilder,
+}
+
+impl UnixClientBuilder {
+    /// Sets the path to the server socket for the Unix client.
+    pub fn with_socket_path(mut self, socket_path: String) -> Self {
+        self.config = self.config.with_socket_path(socket_path);
+        self
+    }
+
+    /// Sets the auto sign in during connection.
+    pub fn with_auto_sign_in(mut self, auto_sign_in: AutoLogin) -> Self {
+        self.config = self.config.with_auto_sign_in(auto_sign_in);
+        self
+    }
+
+    /// Sets the number of max retries when connecting to the server.
+    pub fn with_reconnection_max_retries(mut self, reconnection_retries: Option<u32>) -> Self {
+        self.config = self
+            .config
+            .with_reconnection_max_retries(reconnection_retries);
+        self
+    }
+
+    /// Sets the interval between retries when connecting to the server.
+    pub fn with_reconnection_interval(mut self, reconnection_interval: IggyDuration) -> Self {
+        self.config = self
+            .config
+            .with_reconnection_interval(reconnection_interval);
+        self
+    }
+
+    /// Enables pipelining of the requests of the concurrent callers on a single connection.
+    pub fn with_pipelining(mut self) -> Self {
+        self.config = self.config.with_pipelining();
+        self
+    }
+
+    /// Expects the server to sign in the client based on the peer credentials of the connection.
+    pub fn with_peer_credentials(mut self) -> Self {
+        self.config = self.config.with_peer_credentials();
+        self
+    }
+
+    /// Sets the application name sent to the server as part of the client identification metadata.
+    pub fn with_application_name(mut self, application_name: String) -> Self {
+        self.config = self.config.with_application_name(application_name);
+        self
+    }
+
+    /// Adds the label sent to the server as part of the client identification metadata.
+    pub fn with_client_label(mut self, key: String, value: String) -> Self {
+        self.config = self.config.with_client_label(key, value);
+        self
+    }
+
+    /// Builds the parent `IggyClient` with Unix configuration.
+    pub fn build(self) -> Result<IggyClient, IggyError> {
+        let client = UnixClient::create(Arc::new(self.config.build()?))?;
+        let client = self
+            .parent_builder
+            .with_client(ClientWrapper::Unix(client))
+            .build()?;
+        Ok(client)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct WebSocketClientBuilder {
+    config: WebSocketClientConfigBuilder,
+    parent_builder: IggyClientBuilder,
+}
+
+impl WebSocketClientBuilder {
+    /// Sets the server address (of the HTTP API) for the WebSocket client.
+    pub fn with_server_address(mut self, server_address: String) -> Self {
+        self.config = self.config.with_server_address(server_address);
+        self
+    }
+
+    /// Sets the path of the WebSocket endpoint.
+    pub fn with_path(mut self, path: String) -> Self {
+        self.config = self.config.with_path(path);
+        self
+    }
+
+    /// Sets the auto sign in during connection.
+    pub fn with_auto_sign_in(mut self, auto_sign_in: AutoLogin) -> Self {
+        self.config = self.config.with_auto_sign_in(auto_sign_in);
+        self
+    }
+
+    /// Sets the number of max retries when connecting to the server.
+    pub fn with_reconnection_max_retries(mut self, reconnection_retries: Option<u32>) -> Self {
+        self.config = self
+            .config
+            .with_reconnection_max_retries(reconnection_retries);
+        self
+    }
+
+    /// Sets the interval between retries when connecting to the server.
+    pub fn with_reconnection_interval(mut self, reconnection_interval: IggyDuration) -> Self {
+        self.config = self
+            .config
+            .with_reconnection_interval(reconnection_interval);
+        self
+    }
+
+    /// Sets whether to use TLS (`wss://`) when connecting to the server.
+    pub fn with_tls_enabled(mut self, tls_enabled: bool) -> Self {
+        self.config = self.config.with_tls_enabled(tls_enabled);
+        self
+    }
+
+    /// Sets the domain to use for TLS when connecting to the server.
+    pub fn with_tls_domain(mut self, tls_domain: String) -> Self {
+        self.config = self.config.with_tls_domain(tls_domain);
+        self
+    }
+
+    /// Sets the path to the CA file for TLS.
+    pub fn with_tls_ca_file(mut self, tls_ca_file: String) -> Self {
+        self.config = self.config.with_tls_ca_file(tls_ca_file);
+        self
+    }
+
+    /// Sets whether to validate the TLS certificate.
+    pub fn with_tls_validate_certificate(mut self, tls_validate_certificate: bool) -> Self {
+        self.config = self
+            .config
+            .with_tls_validate_certificate(tls_validate_certificate);
+        self
+    }
+
+    /// Enables pipelining of the requests of the concurrent callers on a single connection.
+    pub fn with_pipelining(mut self) -> Self {
+        self.config = self.config.with_pipelining();
+        self
+    }
+
+    /// Sets the application name sent to the server as part of the client identification metadata.
+    pub fn with_application_name(mut self, application_name: String) -> Self {
+        self.config = self.config.with_application_name(application_name);
+        self
+    }
+
+    /// Adds the label sent to the server as part of the client identification metadata.
+    pub fn with_client_label(mut self, key: String, value: String) -> Self {
+        self.config = self.config.with_client_label(key, value);
+        self
+    }
+
+    /// Builds the parent `IggyClient` with WebSocket configuration.
+    pub fn build(self) -> Result<IggyClient, IggyError> {
+        let client = WebSocketClient::create(Arc::new(self.config.build()?))?;
+        let client = self
+            .parent_builder
+            .with_client(ClientWrapper::WebSocket(client))
+            .build()?;
+        Ok(client)
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct QuicClientBuilder {
     config: QuicClientConfigBuilder,
@@ -249,6 +476,28 @@ impl QuicClientBuilder {
         self
     }
 
+    /// Enables the transport-level compression of the frames of at least the given size, if supported by the server.
+    pub fn with_frame_compression(
+        mut self,
+        algorithm: FrameCompressionAlgorithm,
+        min_size: u32,
+    ) -> Self {
+        self.config = self.config.with_frame_compression(algorithm, min_size);
+        self
+    }
+
+    /// Sets the application name sent to the server as part of the client identification metadata.
+    pub fn with_application_name(mut self, application_name: String) -> Self {
+        self.config = self.config.with_application_name(application_name);
+        self
+    }
+
+    /// Adds the label sent to the server as part of the client identification metadata.
+    pub fn with_client_label(mut self, key: String, value: String) -> Self {
+        self.config = self.config.with_client_label(key, value);
+        self
+    }
+
     /// Builds the parent `IggyClient` with QUIC configuration.
     pub fn build(self) -> Result<IggyClient, IggyError> {
         let client = QuicClient::create(Arc::new(self.config.build()))?;
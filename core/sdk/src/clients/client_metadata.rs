@@ -0,0 +1,66 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy_common::ClientMetadata;
+
+const SDK_LANGUAGE: &str = "rust";
+const SDK_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Returns the client identification metadata with the SDK language and version set,
+/// and with the application name and hostname detected from the environment if not provided.
+pub(crate) fn with_sdk_defaults(metadata: &ClientMetadata) -> ClientMetadata {
+    let mut metadata = metadata.clone();
+    metadata.sdk_language = SDK_LANGUAGE.to_owned();
+    metadata.sdk_version = SDK_VERSION.to_owned();
+    if metadata.application_name.is_empty() {
+        metadata.application_name = std::env::current_exe()
+            .ok()
+            .and_then(|path| {
+                path.file_stem()
+                    .map(|name| name.to_string_lossy().into_owned())
+            })
+            .unwrap_or_default();
+    }
+    if metadata.hostname.is_empty() {
+        metadata.hostname = hostname::get()
+            .map(|hostname| hostname.to_string_lossy().into_owned())
+            .unwrap_or_default();
+    }
+    metadata
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_fill_sdk_details_and_keep_provided_values() {
+        let metadata = ClientMetadata {
+            application_name: "orders".to_owned(),
+            hostname: "orders-1".to_owned(),
+            ..Default::default()
+        };
+
+        let metadata = with_sdk_defaults(&metadata);
+
+        assert_eq!(metadata.application_name, "orders");
+        assert_eq!(metadata.hostname, "orders-1");
+        assert_eq!(metadata.sdk_language, SDK_LANGUAGE);
+        assert_eq!(metadata.sdk_version, SDK_VERSION);
+    }
+}
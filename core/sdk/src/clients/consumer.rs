@@ -28,6 +28,7 @@ use iggy_common::locking::{IggySharedMut, IggySharedMutFn};
 use iggy_common::{
     Consumer, ConsumerKind, DiagnosticEvent, EncryptorKind, IdKind, Identifier, IggyDuration,
     IggyError, IggyMessage, IggyTimestamp, PolledMessages, PollingKind, PollingStrategy,
+    TraceContext,
 };
 use std::collections::VecDeque;
 use std::future::Future;
@@ -745,6 +746,7 @@ impl IggyConsumer {
                         current_offset: polled_messages.current_offset,
                         partition_id,
                         count: 0,
+                        throttle_time: polled_messages.throttle_time,
                     });
                 }
 
@@ -863,16 +865,29 @@ pub struct ReceivedMessage {
     pub message: IggyMessage,
     pub current_offset: u64,
     pub partition_id: u32,
+    /// The W3C trace context propagated by the producer in the `traceparent` user header.
+    pub trace_context: Option<TraceContext>,
 }
 
 impl ReceivedMessage {
     pub fn new(message: IggyMessage, current_offset: u64, partition_id: u32) -> Self {
+        let trace_context = message.trace_context();
         Self {
             message,
             current_offset,
             partition_id,
+            trace_context,
         }
     }
+
+    /// Returns the OpenTelemetry context of the producer span, which can be set as the parent
+    /// of the consumer span (`OpenTelemetrySpanExt::set_parent`) to continue the trace.
+    #[cfg(feature = "opentelemetry")]
+    pub fn otel_context(&self) -> Option<opentelemetry::Context> {
+        self.trace_context
+            .as_ref()
+            .map(crate::clients::trace_context::to_otel_context)
+    }
 }
 
 impl Stream for IggyConsumer {
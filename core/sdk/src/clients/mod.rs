@@ -28,6 +28,7 @@ mod binary_topics;
 mod binary_users;
 pub mod client;
 pub mod client_builder;
+pub(crate) mod client_metadata;
 pub mod consumer;
 pub mod consumer_builder;
 pub mod producer;
@@ -36,6 +37,8 @@ pub mod producer_config;
 pub mod producer_dispatcher;
 pub mod producer_error_callback;
 pub mod producer_sharding;
+#[cfg(feature = "opentelemetry")]
+pub mod trace_context;
 
 const ORDERING: std::sync::atomic::Ordering = std::sync::atomic::Ordering::SeqCst;
 const MAX_BATCH_LENGTH: usize = 1000000;
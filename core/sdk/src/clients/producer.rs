@@ -505,6 +505,8 @@ impl IggyProducer {
             return Ok(());
         }
 
+        let messages = propagate_trace_context(messages);
+
         let stream_id = self.core.stream_id.clone();
         let topic_id = self.core.topic_id.clone();
 
@@ -532,6 +534,8 @@ impl IggyProducer {
             return Ok(());
         }
 
+        let messages = propagate_trace_context(messages);
+
         let stream_id = self.core.stream_id.clone();
         let topic_id = self.core.topic_id.clone();
 
@@ -560,6 +564,8 @@ impl IggyProducer {
             return Ok(());
         }
 
+        let messages = propagate_trace_context(messages);
+
         match &self.dispatcher {
             Some(disp) => disp.dispatch(messages, stream, topic, partitioning).await,
             None => {
@@ -576,3 +582,16 @@ impl IggyProducer {
         }
     }
 }
+
+/// Injects the context of the current span into the messages, so that the server and consumers
+/// can continue the trace. It's a no-op unless built with the `opentelemetry` feature.
+fn propagate_trace_context(messages: Vec<IggyMessage>) -> Vec<IggyMessage> {
+    #[cfg(feature = "opentelemetry")]
+    {
+        let mut messages = messages;
+        super::trace_context::inject_current_trace_context(&mut messages);
+        messages
+    }
+    #[cfg(not(feature = "opentelemetry"))]
+    messages
+}
@@ -0,0 +1,71 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy_common::{IggyMessage, TraceContext};
+use opentelemetry::trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState};
+use tracing::warn;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Returns the W3C trace context of the current `tracing` span, if it's part of a valid trace.
+pub fn current_trace_context() -> Option<TraceContext> {
+    let context = tracing::Span::current().context();
+    let span = context.span();
+    let span_context = span.span_context();
+    if !span_context.is_valid() {
+        return None;
+    }
+
+    Some(TraceContext::new(
+        span_context.trace_id().to_bytes(),
+        span_context.span_id().to_bytes(),
+        span_context.trace_flags().to_u8(),
+    ))
+}
+
+/// Converts the propagated trace context into a remote OpenTelemetry parent context,
+/// which can be passed to `OpenTelemetrySpanExt::set_parent` to continue the trace.
+pub fn to_otel_context(trace_context: &TraceContext) -> opentelemetry::Context {
+    let span_context = SpanContext::new(
+        TraceId::from_bytes(trace_context.trace_id),
+        SpanId::from_bytes(trace_context.span_id),
+        TraceFlags::new(trace_context.flags),
+        true,
+        TraceState::default(),
+    );
+    opentelemetry::Context::new().with_remote_span_context(span_context)
+}
+
+/// Injects the trace context of the current span into the messages which don't carry one yet.
+pub(crate) fn inject_current_trace_context(messages: &mut [IggyMessage]) {
+    let Some(trace_context) = current_trace_context() else {
+        return;
+    };
+
+    for message in messages {
+        if message.trace_context().is_some() {
+            continue;
+        }
+
+        if let Err(error) = message.set_trace_context(&trace_context) {
+            warn!(
+                "Failed to inject trace context into message with ID: {}. {error}",
+                message.header.id
+            );
+        }
+    }
+}
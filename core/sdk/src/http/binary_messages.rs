@@ -24,7 +24,10 @@ use crate::prelude::{
 };
 use async_trait::async_trait;
 use iggy_binary_protocol::MessageClient;
-use iggy_common::IggyMessagesBatch;
+use iggy_common::{IggyDuration, IggyMessagesBatch};
+use reqwest::Response;
+
+const THROTTLE_TIME_HEADER: &str = "iggy-throttle-time";
 
 #[async_trait]
 impl MessageClient for HttpClient {
@@ -52,10 +55,12 @@ impl MessageClient for HttpClient {
                 },
             )
             .await?;
-        let messages = response
+        let throttle_time = get_throttle_time(&response);
+        let mut messages: PolledMessages = response
             .json()
             .await
             .map_err(|_| IggyError::InvalidJsonResponse)?;
+        messages.throttle_time = throttle_time;
         Ok(messages)
     }
 
@@ -66,19 +71,32 @@ impl MessageClient for HttpClient {
         partitioning: &Partitioning,
         messages: &mut [IggyMessage],
     ) -> Result<(), IggyError> {
+        self.send_messages_with_throttle_time(stream_id, topic_id, partitioning, messages)
+            .await
+            .map(|_| ())
+    }
+
+    async fn send_messages_with_throttle_time(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: &mut [IggyMessage],
+    ) -> Result<Option<IggyDuration>, IggyError> {
         let batch = IggyMessagesBatch::from(&*messages);
-        self.post(
-            &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
-            &SendMessages {
-                metadata_length: 0, // this field is used only for TCP/QUIC
-                stream_id: stream_id.clone(),
-                topic_id: topic_id.clone(),
-                partitioning: partitioning.clone(),
-                batch,
-            },
-        )
-        .await?;
-        Ok(())
+        let response = self
+            .post(
+                &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &SendMessages {
+                    metadata_length: 0, // this field is used only for TCP/QUIC
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partitioning: partitioning.clone(),
+                    batch,
+                },
+            )
+            .await?;
+        Ok(get_throttle_time(&response))
     }
 
     async fn flush_unsaved_buffer(
@@ -108,6 +126,16 @@ impl MessageClient for HttpClient {
     }
 }
 
+/// Returns the throttle time (in microseconds) sent by the server if the quota has been exceeded.
+fn get_throttle_time(response: &Response) -> Option<IggyDuration> {
+    response
+        .headers()
+        .get(THROTTLE_TIME_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok())
+        .map(IggyDuration::from)
+}
+
 fn get_path(stream_id: &str, topic_id: &str) -> String {
     format!("streams/{stream_id}/topics/{topic_id}/messages")
 }
@@ -25,7 +25,8 @@ use iggy_common::IdentityInfo;
 use iggy_common::PersonalAccessTokenExpiry;
 use iggy_common::create_personal_access_token::CreatePersonalAccessToken;
 use iggy_common::login_with_personal_access_token::LoginWithPersonalAccessToken;
-use iggy_common::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use iggy_common::update_personal_access_token_quota::UpdatePersonalAccessTokenQuota;
+use iggy_common::{Identifier, PersonalAccessTokenInfo, RawPersonalAccessToken, ThroughputQuota};
 
 const PATH: &str = "/personal-access-tokens";
 
@@ -66,6 +67,27 @@ impl PersonalAccessTokenClient for HttpClient {
         Ok(())
     }
 
+    async fn update_personal_access_token_quota(
+        &self,
+        user_id: &Identifier,
+        name: &str,
+        quota: Option<ThroughputQuota>,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "/users/{}/personal-access-tokens/{name}/quota",
+                &user_id.as_cow_str()
+            ),
+            &UpdatePersonalAccessTokenQuota {
+                user_id: user_id.clone(),
+                name: name.to_string(),
+                quota,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
     async fn login_with_personal_access_token(
         &self,
         token: &str,
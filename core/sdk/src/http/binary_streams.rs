@@ -24,7 +24,8 @@ use async_trait::async_trait;
 use iggy_binary_protocol::StreamClient;
 use iggy_common::create_stream::CreateStream;
 use iggy_common::update_stream::UpdateStream;
-use iggy_common::{Stream, StreamDetails};
+use iggy_common::update_stream_retention::UpdateStreamRetention;
+use iggy_common::{IggyExpiry, MaxTopicSize, Stream, StreamDetails, StreamSizePolicy};
 
 const PATH: &str = "/streams";
 
@@ -103,6 +104,26 @@ impl StreamClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn update_stream_retention(
+        &self,
+        stream_id: &Identifier,
+        max_size: MaxTopicSize,
+        message_expiry: IggyExpiry,
+        size_policy: StreamSizePolicy,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!("{}/retention", get_details_path(&stream_id.as_cow_str())),
+            &UpdateStreamRetention {
+                stream_id: stream_id.clone(),
+                max_size,
+                message_expiry,
+                size_policy,
+            },
+        )
+        .await?;
+        Ok(())
+    }
 }
 
 fn get_details_path(stream_id: &str) -> String {
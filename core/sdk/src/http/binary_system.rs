@@ -23,14 +23,28 @@ use async_trait::async_trait;
 use iggy_binary_protocol::SystemClient;
 use iggy_common::Snapshot;
 use iggy_common::Stats;
+use iggy_common::StatsHistory;
+use iggy_common::get_profile::GetProfile;
 use iggy_common::get_snapshot::GetSnapshot;
-use iggy_common::{ClientInfo, ClientInfoDetails};
+use iggy_common::hello::Hello;
+use iggy_common::reload_config::ReloadConfig;
+use iggy_common::set_log_filter::SetLogFilter;
+use iggy_common::{
+    ClientInfo, ClientInfoDetails, ClientMetadata, ConfigReload, Health, HealthProbe, LogFilter,
+    Profile, ProfileFormat, ProfileKind, ServerHello,
+};
 use iggy_common::{SnapshotCompression, SystemSnapshotType};
 
 const PING: &str = "/ping";
 const CLIENTS: &str = "/clients";
 const STATS: &str = "/stats";
+const STATS_HISTORY: &str = "/stats/history";
 const SNAPSHOT: &str = "/snapshot";
+const RELOAD_CONFIG: &str = "/config/reload";
+const HEALTH_LIVE: &str = "/health/live";
+const HEALTH_READY: &str = "/health/ready";
+const LOGGING: &str = "/system/logging";
+const PROFILE: &str = "/system/profile";
 
 #[async_trait]
 impl SystemClient for HttpClient {
@@ -43,6 +57,15 @@ impl SystemClient for HttpClient {
         Ok(stats)
     }
 
+    async fn get_stats_history(&self) -> Result<StatsHistory, IggyError> {
+        let response = self.get(STATS_HISTORY).await?;
+        let stats_history = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(stats_history)
+    }
+
     async fn get_me(&self) -> Result<ClientInfoDetails, IggyError> {
         Err(IggyError::FeatureUnavailable)
     }
@@ -73,6 +96,19 @@ impl SystemClient for HttpClient {
         Ok(clients)
     }
 
+    async fn disconnect_client(&self, client_id: u32) -> Result<(), IggyError> {
+        self.delete(&format!("{CLIENTS}/{client_id}")).await?;
+        Ok(())
+    }
+
+    async fn hello(&self, _hello: &Hello) -> Result<ServerHello, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn identify_client(&self, _metadata: &ClientMetadata) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
     async fn ping(&self) -> Result<(), IggyError> {
         self.get(PING).await?;
         Ok(())
@@ -103,4 +139,84 @@ impl SystemClient for HttpClient {
         let snapshot = Snapshot::new(file.to_vec());
         Ok(snapshot)
     }
+
+    async fn reload_config(&self) -> Result<ConfigReload, IggyError> {
+        let response = self.post(RELOAD_CONFIG, &ReloadConfig {}).await?;
+        let config_reload = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(config_reload)
+    }
+
+    async fn get_health(&self, probe: HealthProbe) -> Result<Health, IggyError> {
+        let path = match probe {
+            HealthProbe::Liveness => HEALTH_LIVE,
+            HealthProbe::Readiness => HEALTH_READY,
+        };
+        // The server responds with 503 Service Unavailable and the failed checks if it's not ready.
+        match self.get(path).await {
+            Ok(response) => response
+                .json()
+                .await
+                .map_err(|_| IggyError::InvalidJsonResponse),
+            Err(IggyError::HttpResponseError(503, body)) => {
+                serde_json::from_str(&body).map_err(|_| IggyError::InvalidJsonResponse)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn get_log_filter(&self) -> Result<LogFilter, IggyError> {
+        let response = self.get(LOGGING).await?;
+        let log_filter = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(log_filter)
+    }
+
+    async fn set_log_filter(
+        &self,
+        filter: Option<&str>,
+        ttl: Option<IggyDuration>,
+    ) -> Result<LogFilter, IggyError> {
+        let response = self
+            .put(
+                LOGGING,
+                &SetLogFilter {
+                    filter: filter.map(|filter| filter.to_owned()),
+                    ttl,
+                },
+            )
+            .await?;
+        let log_filter = response
+            .json()
+            .await
+            .map_err(|_| IggyError::InvalidJsonResponse)?;
+        Ok(log_filter)
+    }
+
+    async fn profile(
+        &self,
+        kind: ProfileKind,
+        format: ProfileFormat,
+        duration: IggyDuration,
+    ) -> Result<Profile, IggyError> {
+        let response = self
+            .post(
+                PROFILE,
+                &GetProfile {
+                    kind,
+                    format,
+                    duration,
+                },
+            )
+            .await?;
+        let file = response
+            .bytes()
+            .await
+            .map_err(|_| IggyError::InvalidBytesResponse)?;
+        Ok(Profile::new(file.to_vec()))
+    }
 }
@@ -26,7 +26,10 @@ use iggy_common::create_user::CreateUser;
 use iggy_common::login_user::LoginUser;
 use iggy_common::update_permissions::UpdatePermissions;
 use iggy_common::update_user::UpdateUser;
-use iggy_common::{IdentityInfo, Permissions, UserInfo, UserInfoDetails, UserStatus};
+use iggy_common::update_user_quota::UpdateUserQuota;
+use iggy_common::{
+    IdentityInfo, Permissions, ThroughputQuota, UserInfo, UserInfoDetails, UserStatus,
+};
 
 const PATH: &str = "/users";
 
@@ -123,6 +126,22 @@ impl UserClient for HttpClient {
         Ok(())
     }
 
+    async fn update_user_quota(
+        &self,
+        user_id: &Identifier,
+        quota: Option<ThroughputQuota>,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!("{PATH}/{}/quota", &user_id.as_cow_str()),
+            &UpdateUserQuota {
+                user_id: user_id.clone(),
+                quota,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
     async fn change_password(
         &self,
         user_id: &Identifier,
@@ -166,4 +185,10 @@ impl UserClient for HttpClient {
         self.set_access_token(None).await;
         Ok(())
     }
+
+    async fn revoke_user_sessions(&self, user_id: &Identifier) -> Result<(), IggyError> {
+        self.delete(&format!("{PATH}/{}/sessions", &user_id.as_cow_str()))
+            .await?;
+        Ok(())
+    }
 }
@@ -45,6 +45,8 @@ pub use crate::stream_builder::IggyStreamConsumer;
 pub use crate::stream_builder::{IggyProducerConfig, IggyStreamProducer};
 pub use crate::stream_builder::{IggyStream, IggyStreamConfig};
 pub use crate::tcp::tcp_client::TcpClient;
+pub use crate::unix::unix_client::UnixClient;
+pub use crate::websocket::websocket_client::WebSocketClient;
 pub use iggy_binary_protocol::{
     Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PartitionClient,
     PersonalAccessTokenClient, SegmentClient, StreamClient, SystemClient, TopicClient, UserClient,
@@ -52,22 +54,27 @@ pub use iggy_binary_protocol::{
 pub use iggy_common::{
     Aes256GcmEncryptor, Args, ArgsOptional, AutoLogin, BytesSerializable, CacheMetrics,
     CacheMetricsKey, ClientError, ClientInfoDetails, CompressionAlgorithm, Confirmation, Consumer,
-    ConsumerGroupDetails, ConsumerKind, EncryptorKind, FlushUnsavedBuffer, GlobalPermissions,
-    HeaderKey, HeaderValue, HttpClientConfig, HttpClientConfigBuilder, IdKind, Identifier,
+    ConsumerGroupDetails, ConsumerKind, EncryptorKind, FlushUnsavedBuffer, FrameCompression,
+    FrameCompressionAlgorithm, GlobalPermissions, HeaderKey, HeaderValue, Health, HealthCheck,
+    HealthProbe, HealthStatus, HttpClientConfig, HttpClientConfigBuilder, IdKind, Identifier,
     IdentityInfo, IggyByteSize, IggyDuration, IggyError, IggyExpiry, IggyIndexView, IggyMessage,
     IggyMessageHeader, IggyMessageHeaderView, IggyMessageView, IggyMessageViewIterator,
-    IggyTimestamp, MaxTopicSize, Partition, Partitioner, Partitioning, Permissions,
-    PersonalAccessTokenExpiry, PollMessages, PolledMessages, PollingKind, PollingStrategy,
-    QuicClientConfig, QuicClientConfigBuilder, QuicClientReconnectionConfig, SendMessages,
-    Sizeable, SnapshotCompression, Stats, Stream, StreamDetails, StreamPermissions,
+    IggyTimestamp, LogFilter, MaxTopicSize, Partition, Partitioner, Partitioning, Permissions,
+    PersonalAccessTokenExpiry, PollMessages, PolledMessages, PollingKind, PollingStrategy, Profile,
+    ProfileFormat, ProfileKind, ProtocolFeatures, QuicClientConfig, QuicClientConfigBuilder,
+    QuicClientReconnectionConfig, SYSTEM_EVENT_VERSION, SendMessages, Sizeable,
+    SnapshotCompression, Stats, StatsHistory, StatsSample, StatsSeries, Stream, StreamDetails,
+    StreamPermissions, StreamSizePolicy, SystemEvent, SystemEventFormat, SystemEventKind,
     SystemSnapshotType, TcpClientConfig, TcpClientConfigBuilder, TcpClientReconnectionConfig,
-    Topic, TopicDetails, TopicPermissions, UserId, UserStatus, Validatable, defaults, locking,
+    ThroughputQuota, Topic, TopicDetails, TopicPermissions, TopicThroughput, TraceContext,
+    UnixClientConfig, UnixClientConfigBuilder, UserId, UserStatus, Validatable,
+    WebSocketClientConfig, WebSocketClientConfigBuilder, defaults, locking,
 };
 pub use iggy_common::{
     IGGY_MESSAGE_CHECKSUM_OFFSET_RANGE, IGGY_MESSAGE_HEADER_SIZE,
     IGGY_MESSAGE_HEADERS_LENGTH_OFFSET_RANGE, IGGY_MESSAGE_ID_OFFSET_RANGE,
     IGGY_MESSAGE_OFFSET_OFFSET_RANGE, IGGY_MESSAGE_ORIGIN_TIMESTAMP_OFFSET_RANGE,
     IGGY_MESSAGE_PAYLOAD_LENGTH_OFFSET_RANGE, IGGY_MESSAGE_TIMESTAMP_OFFSET_RANGE, INDEX_SIZE,
-    MAX_PAYLOAD_SIZE, MAX_USER_HEADERS_SIZE, SEC_IN_MICRO,
+    MAX_PAYLOAD_SIZE, MAX_USER_HEADERS_SIZE, SEC_IN_MICRO, TRACEPARENT_HEADER,
     defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USER_ID, DEFAULT_ROOT_USERNAME},
 };
@@ -18,17 +18,21 @@
 
 use crate::prelude::AutoLogin;
 use iggy_binary_protocol::{
-    BinaryClient, BinaryTransport, Client, PersonalAccessTokenClient, UserClient,
+    BinaryClient, BinaryTransport, Client, PersonalAccessTokenClient, SystemClient, UserClient,
 };
 
+use crate::clients::client_metadata::with_sdk_defaults;
 use crate::prelude::{IggyDuration, IggyError, IggyTimestamp, QuicClientConfig};
 use crate::quic::skip_server_verification::SkipServerVerification;
 use async_broadcast::{Receiver, Sender, broadcast};
 use async_trait::async_trait;
 use bytes::Bytes;
+use iggy_common::enable_frame_compression::EnableFrameCompression;
+use iggy_common::hello::Hello;
 use iggy_common::{
-    ClientState, Command, ConnectionString, ConnectionStringUtils, Credentials, DiagnosticEvent,
-    QuicConnectionStringOptions, TransportProtocol,
+    COMPRESSED_FRAME_FLAG, ClientState, Command, ConnectionString, ConnectionStringUtils,
+    Credentials, DiagnosticEvent, FrameCompression, ProtocolFeatures, QuicConnectionStringOptions,
+    ServerHello, TransportProtocol,
 };
 use quinn::crypto::rustls::QuicClientConfig as QuinnQuicClientConfig;
 use quinn::{ClientConfig, Connection, Endpoint, IdleTimeout, RecvStream, VarInt};
@@ -55,6 +59,8 @@ pub struct QuicClient {
     pub(crate) state: Mutex<ClientState>,
     events: (Sender<DiagnosticEvent>, Receiver<DiagnosticEvent>),
     connected_at: Mutex<Option<IggyTimestamp>>,
+    server_hello: Mutex<Option<ServerHello>>,
+    frame_compression: Mutex<FrameCompression>,
 }
 
 unsafe impl Send for QuicClient {}
@@ -200,6 +206,8 @@ impl QuicClient {
             state: Mutex::new(ClientState::Disconnected),
             events: broadcast(1000),
             connected_at: Mutex::new(None),
+            server_hello: Mutex::new(None),
+            frame_compression: Mutex::new(FrameCompression::default()),
         })
     }
 
@@ -217,6 +225,7 @@ impl QuicClient {
     async fn handle_response(
         recv: &mut RecvStream,
         response_buffer_size: usize,
+        compression: FrameCompression,
     ) -> Result<Bytes, IggyError> {
         let buffer = recv
             .read_to_end(response_buffer_size)
@@ -244,19 +253,27 @@ impl QuicClient {
             return Err(IggyError::from_code(status));
         }
 
-        let length = u32::from_le_bytes(
+        let mut length = u32::from_le_bytes(
             buffer[4..RESPONSE_INITIAL_BYTES_LENGTH]
                 .try_into()
                 .map_err(|_| IggyError::InvalidNumberEncoding)?,
         );
+        let compressed = compression.is_enabled() && length & COMPRESSED_FRAME_FLAG != 0;
+        if compressed {
+            length &= !COMPRESSED_FRAME_FLAG;
+        }
         trace!("Status: OK. Response length: {}", length);
         if length <= 1 {
             return Ok(Bytes::new());
         }
 
-        Ok(Bytes::copy_from_slice(
-            &buffer[RESPONSE_INITIAL_BYTES_LENGTH..RESPONSE_INITIAL_BYTES_LENGTH + length as usize],
-        ))
+        let payload =
+            &buffer[RESPONSE_INITIAL_BYTES_LENGTH..RESPONSE_INITIAL_BYTES_LENGTH + length as usize];
+        if compressed {
+            return compression.decompress(payload, 0);
+        }
+
+        Ok(Bytes::copy_from_slice(payload))
     }
 
     async fn connect(&self) -> Result<(), IggyError> {
@@ -355,6 +372,17 @@ impl QuicClient {
         self.connection.lock().await.replace(connection);
         self.connected_at.lock().await.replace(now);
         self.publish_event(DiagnosticEvent::Connected).await;
+        self.negotiate_protocol().await?;
+        let metadata = with_sdk_defaults(&self.config.client_metadata);
+        if let Err(error) = self.identify_client(&metadata).await {
+            warn!(
+                "{NAME} client: {} could not send identification metadata to the server: {error}",
+                self.config.client_address
+            );
+        }
+        if self.config.frame_compression.is_enabled() {
+            self.enable_frame_compression().await;
+        }
 
         match &self.config.auto_login {
             AutoLogin::Disabled => {
@@ -430,6 +458,83 @@ impl QuicClient {
         Ok(())
     }
 
+    /// Returns the capabilities of the server negotiated on connect, if the server supports the handshake.
+    pub async fn server_hello(&self) -> Option<ServerHello> {
+        self.server_hello.lock().await.clone()
+    }
+
+    /// Negotiates the protocol version and capabilities with the server.
+    /// The legacy servers, which don't support the handshake, are used without validating the requests.
+    async fn negotiate_protocol(&self) -> Result<(), IggyError> {
+        self.server_hello.lock().await.take();
+        *self.frame_compression.lock().await = FrameCompression::default();
+        match self.hello(&Hello::default()).await {
+            Ok(server_hello) => {
+                info!(
+                    "{NAME} client: {} has negotiated protocol version: {}, features: {}",
+                    self.config.client_address,
+                    server_hello.protocol_version,
+                    server_hello.features
+                );
+                self.server_hello.lock().await.replace(server_hello);
+                Ok(())
+            }
+            Err(error @ IggyError::UnsupportedProtocolVersion(_, _)) => {
+                error!(
+                    "{NAME} client: {} uses the protocol version not supported by the server: {error}",
+                    self.config.client_address
+                );
+                self.disconnect().await?;
+                Err(error)
+            }
+            Err(error) => {
+                warn!(
+                    "{NAME} client: {} could not negotiate the protocol with the server: {error}",
+                    self.config.client_address
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// Enables the transport-level compression of the frames, if the server supports it.
+    /// Falls back to the uncompressed frames otherwise.
+    async fn enable_frame_compression(&self) {
+        let compression = self.config.frame_compression;
+        let supported = self
+            .server_hello
+            .lock()
+            .await
+            .as_ref()
+            .is_some_and(|server_hello| {
+                server_hello.supports_feature(ProtocolFeatures::FRAME_COMPRESSION)
+            });
+        if !supported {
+            warn!(
+                "{NAME} client: {} could not enable frame compression, the server doesn't support it.",
+                self.config.client_address
+            );
+            return;
+        }
+
+        if let Err(error) = self
+            .send_with_response(&EnableFrameCompression { compression })
+            .await
+        {
+            warn!(
+                "{NAME} client: {} could not enable frame compression, frames will be sent uncompressed: {error}",
+                self.config.client_address
+            );
+            return;
+        }
+
+        *self.frame_compression.lock().await = compression;
+        info!(
+            "{NAME} client: {} has enabled frame compression: {compression}.",
+            self.config.client_address
+        );
+    }
+
     async fn send_raw(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError> {
         match self.get_state().await {
             ClientState::Shutdown => {
@@ -453,19 +558,29 @@ impl QuicClient {
             _ => {}
         }
 
-        let connection = self.connection.clone();
+        if let Some(server_hello) = self.server_hello.lock().await.as_ref() {
+            server_hello.validate_request(code, REQUEST_INITIAL_BYTES_LENGTH + payload.len())?;
+        }
+
+        // The lock is released before the request is sent, as every request uses its own
+        // bidirectional stream, which allows the concurrent callers to share the connection.
+        let connection = self.connection.lock().await.clone();
         let response_buffer_size = self.config.response_buffer_size;
-        // SAFETY: we run code holding the `connection` lock in a task so we can't be cancelled while holding the lock.
+        let compression = *self.frame_compression.lock().await;
+        // SAFETY: we run the request in a task so we can't be cancelled in the middle of writing it.
         tokio::spawn(async move {
-            let connection = connection.lock().await;
             if let Some(connection) = connection.as_ref() {
+                let (payload, flag) = match compression.compress(&payload) {
+                    Some(compressed) => (compressed, COMPRESSED_FRAME_FLAG),
+                    None => (payload, 0),
+                };
                 let payload_length = payload.len() + REQUEST_INITIAL_BYTES_LENGTH;
                 let (mut send, mut recv) = connection.open_bi().await.map_err(|error| {
                     error!("Failed to open a bidirectional stream: {error}");
                     IggyError::QuicError
                 })?;
                 trace!("Sending a QUIC request with code: {code}");
-                send.write_all(&(payload_length as u32).to_le_bytes())
+                send.write_all(&(payload_length as u32 | flag).to_le_bytes())
                     .await
                     .map_err(|error| {
                         error!("Failed to write payload length: {error}");
@@ -484,7 +599,12 @@ impl QuicClient {
                     IggyError::QuicError
                 })?;
                 trace!("Sent a QUIC request with code: {code}, waiting for a response...");
-                return QuicClient::handle_response(&mut recv, response_buffer_size as usize).await;
+                return QuicClient::handle_response(
+                    &mut recv,
+                    response_buffer_size as usize,
+                    compression,
+                )
+                .await;
             }
 
             error!("Cannot send data. Client is not connected.");
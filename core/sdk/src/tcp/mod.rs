@@ -19,6 +19,7 @@
 pub(crate) mod tcp_client;
 pub(crate) mod tcp_connection_stream;
 pub(crate) mod tcp_connection_stream_kind;
+pub(crate) mod tcp_pipeline;
 pub(crate) mod tcp_stream;
 pub(crate) mod tcp_tls_connection_stream;
 pub(crate) mod tcp_tls_verifier;
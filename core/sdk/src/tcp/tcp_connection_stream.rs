@@ -41,6 +41,10 @@ impl TcpConnectionStream {
             writer: BufWriter::new(writer),
         }
     }
+
+    pub fn into_split(self) -> (BufReader<OwnedReadHalf>, BufWriter<OwnedWriteHalf>) {
+        (self.reader, self.writer)
+    }
 }
 
 #[async_trait]
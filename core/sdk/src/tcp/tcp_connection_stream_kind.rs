@@ -16,15 +16,23 @@
  * under the License.
  */
 use crate::tcp::tcp_connection_stream::TcpConnectionStream;
+use crate::tcp::tcp_pipeline::{PipelineReader, PipelineWriter};
 use crate::tcp::tcp_stream::ConnectionStream;
 use crate::tcp::tcp_tls_connection_stream::TcpTlsConnectionStream;
+#[cfg(unix)]
+use crate::unix::unix_connection_stream::UnixConnectionStream;
+use crate::websocket::websocket_connection_stream::WebSocketConnectionStream;
 use iggy_common::IggyError;
+use tokio::io::{BufReader, BufWriter};
 
 #[derive(Debug)]
 #[allow(clippy::large_enum_variant)] // TODO(hubcio): consider `Box`ing
 pub(crate) enum ConnectionStreamKind {
     Tcp(TcpConnectionStream),
     TcpTls(TcpTlsConnectionStream),
+    #[cfg(unix)]
+    Unix(UnixConnectionStream),
+    WebSocket(WebSocketConnectionStream),
 }
 
 impl ConnectionStreamKind {
@@ -32,6 +40,9 @@ impl ConnectionStreamKind {
         match self {
             Self::Tcp(c) => c.read(buf).await,
             Self::TcpTls(c) => c.read(buf).await,
+            #[cfg(unix)]
+            Self::Unix(c) => c.read(buf).await,
+            Self::WebSocket(c) => c.read(buf).await,
         }
     }
 
@@ -39,6 +50,9 @@ impl ConnectionStreamKind {
         match self {
             Self::Tcp(c) => c.write(buf).await,
             Self::TcpTls(c) => c.write(buf).await,
+            #[cfg(unix)]
+            Self::Unix(c) => c.write(buf).await,
+            Self::WebSocket(c) => c.write(buf).await,
         }
     }
 
@@ -46,6 +60,9 @@ impl ConnectionStreamKind {
         match self {
             Self::Tcp(c) => c.flush().await,
             Self::TcpTls(c) => c.flush().await,
+            #[cfg(unix)]
+            Self::Unix(c) => c.flush().await,
+            Self::WebSocket(c) => c.flush().await,
         }
     }
 
@@ -53,6 +70,35 @@ impl ConnectionStreamKind {
         match self {
             Self::Tcp(c) => c.shutdown().await,
             Self::TcpTls(c) => c.shutdown().await,
+            #[cfg(unix)]
+            Self::Unix(c) => c.shutdown().await,
+            Self::WebSocket(c) => c.shutdown().await,
+        }
+    }
+
+    /// Splits the connection into the independently owned read and write halves.
+    pub fn into_split(self) -> (PipelineReader, PipelineWriter) {
+        match self {
+            Self::Tcp(c) => {
+                let (reader, writer) = c.into_split();
+                (Box::new(reader), Box::new(writer))
+            }
+            Self::TcpTls(c) => {
+                let (reader, writer) = tokio::io::split(c.into_inner());
+                (
+                    Box::new(BufReader::new(reader)),
+                    Box::new(BufWriter::new(writer)),
+                )
+            }
+            #[cfg(unix)]
+            Self::Unix(c) => {
+                let (reader, writer) = c.into_split();
+                (Box::new(reader), Box::new(writer))
+            }
+            Self::WebSocket(c) => {
+                let (reader, writer) = c.into_split();
+                (Box::new(reader), Box::new(writer))
+            }
         }
     }
 }
@@ -0,0 +1,226 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::tcp::tcp_client::TcpClient;
+use bytes::{BufMut, Bytes, BytesMut};
+use iggy_common::{COMPRESSED_FRAME_FLAG, FrameCompression, IggyError};
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::{Mutex, oneshot};
+use tokio::task::JoinHandle;
+use tracing::{error, trace, warn};
+
+const REQUEST_INITIAL_BYTES_LENGTH: usize = 4;
+const REQUEST_HEADER_LENGTH: usize = 12;
+const RESPONSE_HEADER_LENGTH: usize = 12;
+
+pub(crate) type PipelineReader = Box<dyn AsyncRead + Send + Unpin>;
+pub(crate) type PipelineWriter = Box<dyn AsyncWrite + Send + Unpin>;
+type ResponseSender = oneshot::Sender<Result<Bytes, IggyError>>;
+
+/// The in-flight requests awaiting the response, by the request ID.
+/// Once the connection is closed, no more requests can be registered.
+#[derive(Default)]
+struct PendingRequests {
+    closed: bool,
+    requests: HashMap<u32, ResponseSender>,
+}
+
+/// Multiplexes the requests of the concurrent callers on a single TCP connection switched to the pipelined framing.
+///
+/// Every request is tagged with a request ID and written as soon as the writer is available,
+/// without waiting for the responses to the previous requests. The responses are read in the background
+/// and matched with the awaiting callers by the request ID, so they can arrive in any order.
+pub(crate) struct TcpPipeline {
+    writer: Arc<Mutex<PipelineWriter>>,
+    pending: Arc<StdMutex<PendingRequests>>,
+    next_request_id: AtomicU32,
+    compression: FrameCompression,
+    reader_task: JoinHandle<()>,
+}
+
+impl Debug for TcpPipeline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TcpPipeline")
+            .field("next_request_id", &self.next_request_id)
+            .field("compression", &self.compression)
+            .finish()
+    }
+}
+
+impl TcpPipeline {
+    pub fn new(reader: PipelineReader, writer: PipelineWriter) -> Self {
+        Self::with_frame_compression(reader, writer, FrameCompression::default())
+    }
+
+    /// Creates the pipeline for the connection with the frame compression already negotiated.
+    pub fn with_frame_compression(
+        reader: PipelineReader,
+        writer: PipelineWriter,
+        compression: FrameCompression,
+    ) -> Self {
+        let pending = Arc::new(StdMutex::new(PendingRequests::default()));
+        let reader_task = tokio::spawn(read_responses(reader, pending.clone(), compression));
+        Self {
+            writer: Arc::new(Mutex::new(writer)),
+            pending,
+            next_request_id: AtomicU32::new(1),
+            compression,
+            reader_task,
+        }
+    }
+
+    pub async fn send(&self, code: u32, payload: Bytes) -> Result<Bytes, IggyError> {
+        let request_id = self.next_request_id.fetch_add(1, Ordering::Relaxed);
+        let (response_sender, response_receiver) = oneshot::channel();
+        {
+            let mut pending = self.pending.lock().unwrap();
+            if pending.closed {
+                trace!("Cannot send data. The pipelined connection is closed.");
+                return Err(IggyError::Disconnected);
+            }
+            pending.requests.insert(request_id, response_sender);
+        }
+
+        let (payload, flag) = match self.compression.compress(&payload) {
+            Some(compressed) => (compressed, COMPRESSED_FRAME_FLAG),
+            None => (payload, 0),
+        };
+        let writer = self.writer.clone();
+        // SAFETY: we write the request in a task so we can't be cancelled in the middle of the frame.
+        let written = tokio::spawn(async move {
+            let mut writer = writer.lock().await;
+            let payload_length = payload.len() + REQUEST_INITIAL_BYTES_LENGTH;
+            trace!(
+                "Sending a pipelined TCP request with ID: {request_id}, size: {payload_length}, code: {code}"
+            );
+            let mut frame = BytesMut::with_capacity(REQUEST_HEADER_LENGTH + payload.len());
+            frame.put_u32_le(payload_length as u32 | flag);
+            frame.put_u32_le(request_id);
+            frame.put_u32_le(code);
+            frame.put_slice(&payload);
+            writer.write_all(&frame).await?;
+            writer.flush().await
+        })
+        .await
+        .map_err(|error| {
+            error!("Task execution failed during pipelined TCP request: {error}");
+            IggyError::TcpError
+        })?;
+
+        if let Err(error) = written {
+            error!("Failed to write the pipelined TCP request with ID: {request_id}: {error}");
+            self.pending.lock().unwrap().requests.remove(&request_id);
+            return Err(IggyError::Disconnected);
+        }
+
+        trace!("Sent a pipelined TCP request with ID: {request_id}, waiting for a response...");
+        response_receiver
+            .await
+            .map_err(|_| IggyError::Disconnected)?
+    }
+
+    pub async fn shutdown(&self) -> Result<(), IggyError> {
+        self.writer.lock().await.shutdown().await.map_err(|error| {
+            error!("Failed to shutdown the pipelined TCP connection: {error}");
+            IggyError::TcpError
+        })
+    }
+}
+
+impl Drop for TcpPipeline {
+    fn drop(&mut self) {
+        self.reader_task.abort();
+    }
+}
+
+async fn read_responses(
+    mut reader: PipelineReader,
+    pending: Arc<StdMutex<PendingRequests>>,
+    compression: FrameCompression,
+) {
+    let error = loop {
+        match read_response(&mut reader, compression).await {
+            Ok((request_id, response)) => {
+                let response_sender = pending.lock().unwrap().requests.remove(&request_id);
+                match response_sender {
+                    // The caller might have given up on the response, which is fine.
+                    Some(response_sender) => _ = response_sender.send(response),
+                    None => {
+                        warn!("Received a response for an unknown request with ID: {request_id}")
+                    }
+                }
+            }
+            Err(error) => break error,
+        }
+    };
+
+    let requests = {
+        let mut pending = pending.lock().unwrap();
+        pending.closed = true;
+        std::mem::take(&mut pending.requests)
+    };
+    warn!(
+        "The pipelined TCP connection is closed: {error}, failing {} in-flight request(s).",
+        requests.len()
+    );
+    for (_, response_sender) in requests {
+        let _ = response_sender.send(Err(IggyError::Disconnected));
+    }
+}
+
+/// Reads a single response and returns its request ID along with the payload or the error sent by the server.
+/// An error returned by this function means that the connection can no longer be used.
+async fn read_response(
+    reader: &mut PipelineReader,
+    compression: FrameCompression,
+) -> Result<(u32, Result<Bytes, IggyError>), std::io::Error> {
+    let mut header = [0u8; RESPONSE_HEADER_LENGTH];
+    reader.read_exact(&mut header).await?;
+    let request_id = u32::from_le_bytes(header[..4].try_into().unwrap());
+    let status = u32::from_le_bytes(header[4..8].try_into().unwrap());
+    let mut length = u32::from_le_bytes(header[8..].try_into().unwrap());
+    let compressed = compression.is_enabled() && length & COMPRESSED_FRAME_FLAG != 0;
+    if compressed {
+        length &= !COMPRESSED_FRAME_FLAG;
+    }
+    let length = length as usize;
+    trace!("Received a response for the pipelined TCP request with ID: {request_id}");
+
+    let mut payload = BytesMut::zeroed(length);
+    if length > 0 {
+        reader.read_exact(&mut payload).await?;
+    }
+
+    if let Err(error) = TcpClient::check_response_status(status) {
+        return Ok((request_id, Err(error)));
+    }
+
+    if compressed {
+        return Ok((request_id, compression.decompress(&payload, 0)));
+    }
+
+    if length <= 1 {
+        return Ok((request_id, Ok(Bytes::new())));
+    }
+
+    Ok((request_id, Ok(payload.freeze())))
+}
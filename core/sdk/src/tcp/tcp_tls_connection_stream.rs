@@ -38,6 +38,10 @@ impl TcpTlsConnectionStream {
             stream,
         }
     }
+
+    pub fn into_inner(self) -> TlsStream<TcpStream> {
+        self.stream
+    }
 }
 
 #[async_trait]
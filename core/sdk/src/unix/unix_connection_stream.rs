@@ -0,0 +1,90 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::tcp::tcp_stream::ConnectionStream;
+use async_trait::async_trait;
+use iggy_common::IggyError;
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
+use tokio::net::UnixStream;
+use tokio::net::unix::{OwnedReadHalf, OwnedWriteHalf};
+use tracing::error;
+
+#[derive(Debug)]
+pub struct UnixConnectionStream {
+    socket_path: String,
+    reader: BufReader<OwnedReadHalf>,
+    writer: BufWriter<OwnedWriteHalf>,
+}
+
+impl UnixConnectionStream {
+    pub fn new(socket_path: String, stream: UnixStream) -> Self {
+        let (reader, writer) = stream.into_split();
+        Self {
+            socket_path,
+            reader: BufReader::new(reader),
+            writer: BufWriter::new(writer),
+        }
+    }
+
+    pub fn into_split(self) -> (BufReader<OwnedReadHalf>, BufWriter<OwnedWriteHalf>) {
+        (self.reader, self.writer)
+    }
+}
+
+#[async_trait]
+impl ConnectionStream for UnixConnectionStream {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, IggyError> {
+        self.reader.read_exact(buf).await.map_err(|error| {
+            error!(
+                "Failed to read data from the Unix socket: {}: {error}",
+                self.socket_path
+            );
+            IggyError::TcpError
+        })
+    }
+
+    async fn write(&mut self, buf: &[u8]) -> Result<(), IggyError> {
+        self.writer.write_all(buf).await.map_err(|error| {
+            error!(
+                "Failed to write data to the Unix socket: {}: {error}",
+                self.socket_path
+            );
+            IggyError::TcpError
+        })
+    }
+
+    async fn flush(&mut self) -> Result<(), IggyError> {
+        self.writer.flush().await.map_err(|error| {
+            error!(
+                "Failed to flush data to the Unix socket: {}: {error}",
+                self.socket_path
+            );
+            IggyError::TcpError
+        })
+    }
+
+    async fn shutdown(&mut self) -> Result<(), IggyError> {
+        self.writer.shutdown().await.map_err(|error| {
+            error!(
+                "Failed to shutdown the connection to the Unix socket: {}: {error}",
+                self.socket_path
+            );
+            IggyError::TcpError
+        })
+    }
+}
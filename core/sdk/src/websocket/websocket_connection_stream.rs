@@ -0,0 +1,99 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::tcp::tcp_stream::ConnectionStream;
+use async_trait::async_trait;
+use iggy_common::IggyError;
+use iggy_common::websocket::WebSocketStream;
+use std::fmt::Debug;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadHalf, WriteHalf};
+use tracing::error;
+
+/// The plain or TLS connection, which has been upgraded to the WebSocket.
+pub(crate) trait WebSocketTransport: AsyncRead + AsyncWrite + Debug + Send + Unpin {}
+
+impl<T: AsyncRead + AsyncWrite + Debug + Send + Unpin> WebSocketTransport for T {}
+
+type WebSocketConnection = WebSocketStream<Box<dyn WebSocketTransport>>;
+
+#[derive(Debug)]
+pub(crate) struct WebSocketConnectionStream {
+    server_address: String,
+    stream: WebSocketConnection,
+}
+
+impl WebSocketConnectionStream {
+    pub fn new(server_address: String, stream: WebSocketConnection) -> Self {
+        Self {
+            server_address,
+            stream,
+        }
+    }
+
+    pub fn into_split(
+        self,
+    ) -> (
+        ReadHalf<WebSocketConnection>,
+        WriteHalf<WebSocketConnection>,
+    ) {
+        tokio::io::split(self.stream)
+    }
+}
+
+#[async_trait]
+impl ConnectionStream for WebSocketConnectionStream {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, IggyError> {
+        self.stream.read_exact(buf).await.map_err(|error| {
+            error!(
+                "Failed to read data from the WebSocket connection: {}: {error}",
+                self.server_address
+            );
+            IggyError::TcpError
+        })
+    }
+
+    async fn write(&mut self, buf: &[u8]) -> Result<(), IggyError> {
+        self.stream.write_all(buf).await.map_err(|error| {
+            error!(
+                "Failed to write data to the WebSocket connection: {}: {error}",
+                self.server_address
+            );
+            IggyError::TcpError
+        })
+    }
+
+    async fn flush(&mut self) -> Result<(), IggyError> {
+        self.stream.flush().await.map_err(|error| {
+            error!(
+                "Failed to flush data to the WebSocket connection: {}: {error}",
+                self.server_address
+            );
+            IggyError::TcpError
+        })
+    }
+
+    async fn shutdown(&mut self) -> Result<(), IggyError> {
+        self.stream.shutdown().await.map_err(|error| {
+            error!(
+                "Failed to shutdown the WebSocket connection: {}: {error}",
+                self.server_address
+            );
+            IggyError::TcpError
+        })
+    }
+}
@@ -0,0 +1,127 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::sender::Sender;
+use crate::server_error::ServerError;
+use bytes::{Buf, Bytes};
+use iggy_common::IggyError;
+use std::io::IoSlice;
+
+/// The sender of a single pipelined request, which is handled concurrently with the other requests
+/// on the same connection. The request payload is read upfront, while the responses are kept in memory
+/// until the connection sends them along with the ID of the request.
+#[derive(Debug, Default)]
+pub struct BufferedSender {
+    pub(crate) request: Bytes,
+    pub(crate) responses: Vec<BufferedResponse>,
+}
+
+#[derive(Debug)]
+pub enum BufferedResponse {
+    Ok(Bytes),
+    Error(IggyError),
+}
+
+impl Sender for BufferedSender {
+    async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError> {
+        if self.request.len() < buffer.len() {
+            return Err(IggyError::CommandLengthError(format!(
+                "Unable to read the pipelined request, expected: {} bytes, remaining: {} bytes.",
+                buffer.len(),
+                self.request.len()
+            )));
+        }
+
+        self.request.copy_to_slice(buffer);
+        Ok(buffer.len())
+    }
+
+    async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
+        self.responses.push(BufferedResponse::Ok(Bytes::new()));
+        Ok(())
+    }
+
+    async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        self.responses
+            .push(BufferedResponse::Ok(Bytes::copy_from_slice(payload)));
+        Ok(())
+    }
+
+    async fn send_ok_response_vectored(
+        &mut self,
+        _length: &[u8],
+        slices: Vec<IoSlice<'_>>,
+    ) -> Result<(), IggyError> {
+        let size = slices.iter().map(|slice| slice.len()).sum::<usize>();
+        let mut payload = Vec::with_capacity(size);
+        for slice in &slices {
+            payload.extend_from_slice(slice);
+        }
+        self.responses.push(BufferedResponse::Ok(payload.into()));
+        Ok(())
+    }
+
+    async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
+        self.responses.push(BufferedResponse::Error(error));
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<(), ServerError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn should_read_request_and_keep_responses_in_memory() {
+        let mut sender = BufferedSender {
+            request: Bytes::from_static(&[1, 2, 3, 4, 5]),
+            ..Default::default()
+        };
+        let mut buffer = [0u8; 3];
+        assert_eq!(sender.read(&mut buffer).await.unwrap(), 3);
+        assert_eq!(buffer, [1, 2, 3]);
+        assert!(sender.read(&mut buffer).await.is_err());
+
+        sender.send_empty_ok_response().await.unwrap();
+        sender
+            .send_ok_response_vectored(
+                &4u32.to_le_bytes(),
+                vec![IoSlice::new(&[6, 7]), IoSlice::new(&[8, 9])],
+            )
+            .await
+            .unwrap();
+        sender
+            .send_error_response(IggyError::InvalidCommand)
+            .await
+            .unwrap();
+        assert!(
+            matches!(&sender.responses[0], BufferedResponse::Ok(payload) if payload.is_empty())
+        );
+        assert!(
+            matches!(&sender.responses[1], BufferedResponse::Ok(payload) if payload[..] == [6, 7, 8, 9])
+        );
+        assert!(matches!(
+            &sender.responses[2],
+            BufferedResponse::Error(IggyError::InvalidCommand)
+        ));
+    }
+}
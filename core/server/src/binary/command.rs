@@ -37,21 +37,30 @@ use iggy_common::delete_segments::DeleteSegments;
 use iggy_common::delete_stream::DeleteStream;
 use iggy_common::delete_topic::DeleteTopic;
 use iggy_common::delete_user::DeleteUser;
+use iggy_common::disconnect_client::DisconnectClient;
+use iggy_common::enable_frame_compression::EnableFrameCompression;
+use iggy_common::enable_pipelining::EnablePipelining;
 use iggy_common::get_client::GetClient;
 use iggy_common::get_clients::GetClients;
 use iggy_common::get_consumer_group::GetConsumerGroup;
 use iggy_common::get_consumer_groups::GetConsumerGroups;
 use iggy_common::get_consumer_offset::GetConsumerOffset;
+use iggy_common::get_health::GetHealth;
+use iggy_common::get_log_filter::GetLogFilter;
 use iggy_common::get_me::GetMe;
 use iggy_common::get_personal_access_tokens::GetPersonalAccessTokens;
+use iggy_common::get_profile::GetProfile;
 use iggy_common::get_snapshot::GetSnapshot;
 use iggy_common::get_stats::GetStats;
+use iggy_common::get_stats_history::GetStatsHistory;
 use iggy_common::get_stream::GetStream;
 use iggy_common::get_streams::GetStreams;
 use iggy_common::get_topic::GetTopic;
 use iggy_common::get_topics::GetTopics;
 use iggy_common::get_user::GetUser;
 use iggy_common::get_users::GetUsers;
+use iggy_common::hello::Hello;
+use iggy_common::identify_client::IdentifyClient;
 use iggy_common::join_consumer_group::JoinConsumerGroup;
 use iggy_common::leave_consumer_group::LeaveConsumerGroup;
 use iggy_common::login_user::LoginUser;
@@ -60,22 +69,39 @@ use iggy_common::logout_user::LogoutUser;
 use iggy_common::ping::Ping;
 use iggy_common::purge_stream::PurgeStream;
 use iggy_common::purge_topic::PurgeTopic;
+use iggy_common::reload_config::ReloadConfig;
+use iggy_common::revoke_user_sessions::RevokeUserSessions;
+use iggy_common::set_log_filter::SetLogFilter;
 use iggy_common::store_consumer_offset::StoreConsumerOffset;
 use iggy_common::update_permissions::UpdatePermissions;
+use iggy_common::update_personal_access_token_quota::UpdatePersonalAccessTokenQuota;
 use iggy_common::update_stream::UpdateStream;
+use iggy_common::update_stream_retention::UpdateStreamRetention;
 use iggy_common::update_topic::UpdateTopic;
 use iggy_common::update_user::UpdateUser;
+use iggy_common::update_user_quota::UpdateUserQuota;
 use iggy_common::*;
 use strum::EnumString;
 use tracing::error;
 
 define_server_command_enum! {
     Ping(Ping), PING_CODE, PING, false;
+    Hello(Hello), HELLO_CODE, HELLO, true;
     GetStats(GetStats), GET_STATS_CODE, GET_STATS, false;
     GetMe(GetMe), GET_ME_CODE, GET_ME, false;
     GetClient(GetClient), GET_CLIENT_CODE, GET_CLIENT, true;
     GetClients(GetClients), GET_CLIENTS_CODE, GET_CLIENTS, false;
+    DisconnectClient(DisconnectClient), DISCONNECT_CLIENT_CODE, DISCONNECT_CLIENT, true;
+    IdentifyClient(IdentifyClient), IDENTIFY_CLIENT_CODE, IDENTIFY_CLIENT, true;
+    EnablePipelining(EnablePipelining), ENABLE_PIPELINING_CODE, ENABLE_PIPELINING, false;
+    EnableFrameCompression(EnableFrameCompression), ENABLE_FRAME_COMPRESSION_CODE, ENABLE_FRAME_COMPRESSION, true;
     GetSnapshot(GetSnapshot), GET_SNAPSHOT_FILE_CODE, GET_SNAPSHOT_FILE, false;
+    ReloadConfig(ReloadConfig), RELOAD_CONFIG_CODE, RELOAD_CONFIG, false;
+    GetHealth(GetHealth), GET_HEALTH_CODE, GET_HEALTH, true;
+    GetLogFilter(GetLogFilter), GET_LOG_FILTER_CODE, GET_LOG_FILTER, false;
+    SetLogFilter(SetLogFilter), SET_LOG_FILTER_CODE, SET_LOG_FILTER, true;
+    GetProfile(GetProfile), GET_PROFILE_CODE, GET_PROFILE, true;
+    GetStatsHistory(GetStatsHistory), GET_STATS_HISTORY_CODE, GET_STATS_HISTORY, false;
     PollMessages(PollMessages), POLL_MESSAGES_CODE, POLL_MESSAGES, true;
     FlushUnsavedBuffer(FlushUnsavedBuffer), FLUSH_UNSAVED_BUFFER_CODE, FLUSH_UNSAVED_BUFFER, true;
     GetUser(GetUser), GET_USER_CODE, GET_USER, true;
@@ -84,13 +110,16 @@ define_server_command_enum! {
     DeleteUser(DeleteUser), DELETE_USER_CODE, DELETE_USER, true;
     UpdateUser(UpdateUser), UPDATE_USER_CODE, UPDATE_USER, true;
     UpdatePermissions(UpdatePermissions), UPDATE_PERMISSIONS_CODE, UPDATE_PERMISSIONS, true;
+    UpdateUserQuota(UpdateUserQuota), UPDATE_USER_QUOTA_CODE, UPDATE_USER_QUOTA, true;
     ChangePassword(ChangePassword), CHANGE_PASSWORD_CODE, CHANGE_PASSWORD, true;
     LoginUser(LoginUser), LOGIN_USER_CODE, LOGIN_USER, true;
     LogoutUser(LogoutUser), LOGOUT_USER_CODE, LOGOUT_USER, false;
+    RevokeUserSessions(RevokeUserSessions), REVOKE_USER_SESSIONS_CODE, REVOKE_USER_SESSIONS, true;
     GetPersonalAccessTokens(GetPersonalAccessTokens), GET_PERSONAL_ACCESS_TOKENS_CODE, GET_PERSONAL_ACCESS_TOKENS, false;
     CreatePersonalAccessToken(CreatePersonalAccessToken), CREATE_PERSONAL_ACCESS_TOKEN_CODE, CREATE_PERSONAL_ACCESS_TOKEN, true;
     DeletePersonalAccessToken(DeletePersonalAccessToken), DELETE_PERSONAL_ACCESS_TOKEN_CODE, DELETE_PERSONAL_ACCESS_TOKEN, false;
     LoginWithPersonalAccessToken(LoginWithPersonalAccessToken), LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE, LOGIN_WITH_PERSONAL_ACCESS_TOKEN, true;
+    UpdatePersonalAccessTokenQuota(UpdatePersonalAccessTokenQuota), UPDATE_PERSONAL_ACCESS_TOKEN_QUOTA_CODE, UPDATE_PERSONAL_ACCESS_TOKEN_QUOTA, true;
     SendMessages(SendMessages), SEND_MESSAGES_CODE, SEND_MESSAGES, false;
     GetConsumerOffset(GetConsumerOffset), GET_CONSUMER_OFFSET_CODE, GET_CONSUMER_OFFSET, true;
     StoreConsumerOffset(StoreConsumerOffset), STORE_CONSUMER_OFFSET_CODE, STORE_CONSUMER_OFFSET, true;
@@ -101,6 +130,7 @@ define_server_command_enum! {
     DeleteStream(DeleteStream), DELETE_STREAM_CODE, DELETE_STREAM, true;
     UpdateStream(UpdateStream), UPDATE_STREAM_CODE, UPDATE_STREAM, true;
     PurgeStream(PurgeStream), PURGE_STREAM_CODE, PURGE_STREAM, true;
+    UpdateStreamRetention(UpdateStreamRetention), UPDATE_STREAM_RETENTION_CODE, UPDATE_STREAM_RETENTION, true;
     GetTopic(GetTopic), GET_TOPIC_CODE, GET_TOPIC, true;
     GetTopics(GetTopics), GET_TOPICS_CODE, GET_TOPICS, false;
     CreateTopic(CreateTopic), CREATE_TOPIC_CODE, CREATE_TOPIC, true;
@@ -165,6 +195,11 @@ mod tests {
             PING_CODE,
             &Ping::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::Hello(Hello::default()),
+            HELLO_CODE,
+            &Hello::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetStats(GetStats::default()),
             GET_STATS_CODE,
@@ -185,6 +220,56 @@ mod tests {
             GET_CLIENTS_CODE,
             &GetClients::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::ReloadConfig(ReloadConfig::default()),
+            RELOAD_CONFIG_CODE,
+            &ReloadConfig::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetHealth(GetHealth::default()),
+            GET_HEALTH_CODE,
+            &GetHealth::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetLogFilter(GetLogFilter::default()),
+            GET_LOG_FILTER_CODE,
+            &GetLogFilter::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::SetLogFilter(SetLogFilter::default()),
+            SET_LOG_FILTER_CODE,
+            &SetLogFilter::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetProfile(GetProfile::default()),
+            GET_PROFILE_CODE,
+            &GetProfile::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetStatsHistory(GetStatsHistory::default()),
+            GET_STATS_HISTORY_CODE,
+            &GetStatsHistory::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::DisconnectClient(DisconnectClient::default()),
+            DISCONNECT_CLIENT_CODE,
+            &DisconnectClient::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::IdentifyClient(IdentifyClient::default()),
+            IDENTIFY_CLIENT_CODE,
+            &IdentifyClient::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::EnablePipelining(EnablePipelining::default()),
+            ENABLE_PIPELINING_CODE,
+            &EnablePipelining::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::EnableFrameCompression(EnableFrameCompression::default()),
+            ENABLE_FRAME_COMPRESSION_CODE,
+            &EnableFrameCompression::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetUser(GetUser::default()),
             GET_USER_CODE,
@@ -215,6 +300,11 @@ mod tests {
             UPDATE_PERMISSIONS_CODE,
             &UpdatePermissions::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateUserQuota(UpdateUserQuota::default()),
+            UPDATE_USER_QUOTA_CODE,
+            &UpdateUserQuota::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::ChangePassword(ChangePassword::default()),
             CHANGE_PASSWORD_CODE,
@@ -230,6 +320,11 @@ mod tests {
             LOGOUT_USER_CODE,
             &LogoutUser::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::RevokeUserSessions(RevokeUserSessions::default()),
+            REVOKE_USER_SESSIONS_CODE,
+            &RevokeUserSessions::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetPersonalAccessTokens(GetPersonalAccessTokens::default()),
             GET_PERSONAL_ACCESS_TOKENS_CODE,
@@ -250,6 +345,13 @@ mod tests {
             LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE,
             &LoginWithPersonalAccessToken::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdatePersonalAccessTokenQuota(
+                UpdatePersonalAccessTokenQuota::default(),
+            ),
+            UPDATE_PERSONAL_ACCESS_TOKEN_QUOTA_CODE,
+            &UpdatePersonalAccessTokenQuota::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::PollMessages(PollMessages::default()),
             POLL_MESSAGES_CODE,
@@ -295,6 +397,11 @@ mod tests {
             PURGE_STREAM_CODE,
             &PurgeStream::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::UpdateStreamRetention(UpdateStreamRetention::default()),
+            UPDATE_STREAM_RETENTION_CODE,
+            &UpdateStreamRetention::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetTopic(GetTopic::default()),
             GET_TOPIC_CODE,
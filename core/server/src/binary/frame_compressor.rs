@@ -0,0 +1,164 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::{Buf, Bytes};
+use iggy_common::{FrameCompression, IggyError};
+use std::io::IoSlice;
+
+/// The number of bytes before and after the transport-level compression of the frames.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FrameCompressionStats {
+    pub request_uncompressed_bytes: u64,
+    pub request_compressed_bytes: u64,
+    pub response_uncompressed_bytes: u64,
+    pub response_compressed_bytes: u64,
+}
+
+impl FrameCompressionStats {
+    pub fn is_empty(&self) -> bool {
+        self.request_uncompressed_bytes == 0 && self.response_uncompressed_bytes == 0
+    }
+}
+
+/// Compresses the responses and decompresses the requests of a single connection (or QUIC stream),
+/// once the frame compression has been negotiated with the `EnableFrameCompression` command.
+/// The decompressed request payload is kept aside and served by the subsequent reads of the sender.
+#[derive(Debug, Default)]
+pub struct FrameCompressor {
+    compression: FrameCompression,
+    request: Bytes,
+    stats: FrameCompressionStats,
+}
+
+impl FrameCompressor {
+    pub fn new(compression: FrameCompression) -> Self {
+        FrameCompressor {
+            compression,
+            ..Default::default()
+        }
+    }
+
+    pub fn compression(&self) -> FrameCompression {
+        self.compression
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.compression.is_enabled()
+    }
+
+    pub fn enable(&mut self, compression: FrameCompression) {
+        self.compression = compression;
+    }
+
+    /// Decompresses the request payload, which is then served by `read`, and returns its length.
+    pub fn decompress_request(&mut self, payload: &[u8], max_size: u32) -> Result<u32, IggyError> {
+        let request = self.compression.decompress(payload, max_size)?;
+        self.stats.request_uncompressed_bytes += request.len() as u64;
+        self.stats.request_compressed_bytes += payload.len() as u64;
+        let length = request.len() as u32;
+        self.request = request;
+        Ok(length)
+    }
+
+    /// Fills the buffer with the decompressed request payload, if there's any left to read.
+    pub fn read(&mut self, buffer: &mut [u8]) -> Option<Result<usize, IggyError>> {
+        if self.request.is_empty() {
+            return None;
+        }
+
+        if self.request.len() < buffer.len() {
+            self.request.clear();
+            return Some(Err(IggyError::InvalidCompressedFrame));
+        }
+
+        self.request.copy_to_slice(buffer);
+        Some(Ok(buffer.len()))
+    }
+
+    /// Compresses the response payload, unless it's too small or incompressible.
+    pub fn compress_response(&mut self, payload: &[u8]) -> Option<Bytes> {
+        let compressed = self.compression.compress(payload)?;
+        self.stats.response_uncompressed_bytes += payload.len() as u64;
+        self.stats.response_compressed_bytes += compressed.len() as u64;
+        Some(compressed)
+    }
+
+    /// Compresses the vectored response payload, which has to be copied into a single buffer first.
+    pub fn compress_response_vectored(&mut self, slices: &[IoSlice<'_>]) -> Option<Bytes> {
+        let size = slices.iter().map(|slice| slice.len()).sum::<usize>();
+        if !self.is_enabled() || size < self.compression.min_size as usize {
+            return None;
+        }
+
+        let mut payload = Vec::with_capacity(size);
+        for slice in slices {
+            payload.extend_from_slice(slice);
+        }
+        self.compress_response(&payload)
+    }
+
+    /// Returns the compression stats since the previous call.
+    pub fn take_stats(&mut self) -> FrameCompressionStats {
+        std::mem::take(&mut self.stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::FrameCompressionAlgorithm;
+
+    #[test]
+    fn should_serve_decompressed_request_and_record_stats() {
+        let payload = vec![7u8; 4096];
+        let compression = FrameCompression::new(FrameCompressionAlgorithm::Lz4, 1024);
+        let compressed = compression.compress(&payload).unwrap();
+        let mut compressor = FrameCompressor::new(compression);
+
+        let length = compressor.decompress_request(&compressed, 0).unwrap();
+        assert_eq!(length, 4096);
+        let mut buffer = vec![0u8; 1000];
+        assert_eq!(compressor.read(&mut buffer), Some(Ok(1000)));
+        let mut buffer = vec![0u8; 3096];
+        assert_eq!(compressor.read(&mut buffer), Some(Ok(3096)));
+        assert!(buffer.iter().all(|byte| *byte == 7));
+        assert!(compressor.read(&mut buffer).is_none());
+
+        let stats = compressor.take_stats();
+        assert_eq!(stats.request_uncompressed_bytes, 4096);
+        assert_eq!(stats.request_compressed_bytes, compressed.len() as u64);
+        assert!(compressor.take_stats().is_empty());
+    }
+
+    #[test]
+    fn should_compress_only_responses_above_min_size() {
+        let mut compressor =
+            FrameCompressor::new(FrameCompression::new(FrameCompressionAlgorithm::Zstd, 1024));
+        assert!(compressor.compress_response(&[1u8; 100]).is_none());
+
+        let payload = [1u8; 2048];
+        let slices = vec![
+            IoSlice::new(&payload[..1024]),
+            IoSlice::new(&payload[1024..]),
+        ];
+        let compressed = compressor.compress_response_vectored(&slices).unwrap();
+        let stats = compressor.take_stats();
+        assert_eq!(stats.response_uncompressed_bytes, 2048);
+        assert_eq!(stats.response_compressed_bytes, compressed.len() as u64);
+    }
+}
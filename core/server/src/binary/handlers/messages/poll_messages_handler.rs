@@ -23,11 +23,12 @@ use crate::binary::sender::SenderKind;
 use crate::streaming::session::Session;
 use crate::streaming::systems::messages::PollingArgs;
 use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::users::quota_limiter::QuotaDirection;
 use anyhow::Result;
 use error_set::ErrContext;
-use iggy_common::{IggyError, PollMessages};
+use iggy_common::{IggyError, PollMessages, ProtocolFeatures};
 use std::io::IoSlice;
-use tracing::{debug, trace};
+use tracing::{debug, instrument, trace};
 
 #[derive(Debug)]
 pub struct IggyPollMetadata {
@@ -49,6 +50,14 @@ impl ServerCommandHandler for PollMessages {
         iggy_common::POLL_MESSAGES_CODE
     }
 
+    #[instrument(skip_all, name = "trace_poll_messages", fields(
+        iggy_user_id = session.get_user_id(),
+        iggy_client_id = session.client_id,
+        iggy_stream_id = self.stream_id.as_string(),
+        iggy_topic_id = self.topic_id.as_string(),
+        iggy_partition_id = self.partition_id,
+        strategy = %self.strategy
+    ))]
     async fn handle(
         self,
         sender: &mut SenderKind,
@@ -73,27 +82,52 @@ impl ServerCommandHandler for PollMessages {
                 "{COMPONENT} (error: {error}) - failed to poll messages for consumer: {}, stream_id: {}, topic_id: {}, partition_id: {:?}, session: {session}.",
                 self.consumer, self.stream_id, self.topic_id, self.partition_id
             ))?;
+        let throttle_time = system.record_throughput(
+            session,
+            QuotaDirection::Consume,
+            messages.size() as u64,
+            messages.count() as u64,
+        );
         drop(system);
 
+        if let Some(throttle_time) = throttle_time {
+            tokio::time::sleep(throttle_time).await;
+        }
+
         // Collect all chunks first into a Vec to extend their lifetimes.
         // This ensures the Bytes (in reality Arc<[u8]>) references from each IggyMessagesBatch stay alive
         // throughout the async vectored I/O operation, preventing "borrowed value does not live
         // long enough" errors while optimizing transmission by using larger chunks.
 
-        // 4 bytes for partition_id + 8 bytes for current_offset + 4 bytes for messages_count + size of all batches.
-        let response_length = 4 + 8 + 4 + messages.size();
+        // The throttle time (in microseconds) is appended after the messages if the quota has been exceeded,
+        // only for the clients which negotiated it, as the other ones read the messages until the end of the response.
+        let throttle_time = throttle_time
+            .filter(|_| {
+                session
+                    .get_protocol_features()
+                    .contains(ProtocolFeatures::THROTTLE_TIME)
+            })
+            .map(|throttle_time| (throttle_time.as_micros() as u64).to_le_bytes());
+
+        // 4 bytes for partition_id + 8 bytes for current_offset + 4 bytes for messages_count + size of all batches
+        // + 8 bytes for the optional throttle time.
+        let response_length =
+            4 + 8 + 4 + messages.size() + throttle_time.map_or(0, |bytes| bytes.len() as u32);
         let response_length_bytes = response_length.to_le_bytes();
 
         let partition_id = metadata.partition_id.to_le_bytes();
         let current_offset = metadata.current_offset.to_le_bytes();
         let count = messages.count().to_le_bytes();
 
-        let mut io_slices = Vec::with_capacity(messages.containers_count() + 3);
+        let mut io_slices = Vec::with_capacity(messages.containers_count() + 4);
         io_slices.push(IoSlice::new(&partition_id));
         io_slices.push(IoSlice::new(&current_offset));
         io_slices.push(IoSlice::new(&count));
 
         io_slices.extend(messages.iter().map(|m| IoSlice::new(m)));
+        if let Some(throttle_time) = &throttle_time {
+            io_slices.push(IoSlice::new(throttle_time));
+        }
 
         trace!(
             "Sending {} messages to client ({} bytes) to client",
@@ -21,12 +21,13 @@ use crate::binary::sender::SenderKind;
 use crate::streaming::segments::{IggyIndexesMut, IggyMessagesBatchMut};
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::users::quota_limiter::QuotaDirection;
 use crate::streaming::utils::PooledBuffer;
 use anyhow::Result;
 use iggy_common::INDEX_SIZE;
 use iggy_common::Identifier;
 use iggy_common::Sizeable;
-use iggy_common::{IggyError, Partitioning, SendMessages, Validatable};
+use iggy_common::{IggyError, Partitioning, ProtocolFeatures, SendMessages, Validatable};
 use tracing::instrument;
 
 impl ServerCommandHandler for SendMessages {
@@ -99,6 +100,7 @@ impl ServerCommandHandler for SendMessages {
 
         batch.validate()?;
 
+        let messages_size = batch.size() as u64;
         let system = system.read().await;
         system
             .append_messages(
@@ -110,8 +112,31 @@ impl ServerCommandHandler for SendMessages {
                 None,
             )
             .await?;
+        let throttle_time = system.record_throughput(
+            session,
+            QuotaDirection::Produce,
+            messages_size,
+            messages_count as u64,
+        );
         drop(system);
 
+        if let Some(throttle_time) = throttle_time {
+            tokio::time::sleep(throttle_time).await;
+            if !session
+                .get_protocol_features()
+                .contains(ProtocolFeatures::THROTTLE_TIME)
+            {
+                sender.send_empty_ok_response().await?;
+                return Ok(());
+            }
+
+            let throttle_time = throttle_time.as_micros() as u64;
+            sender
+                .send_ok_response(&throttle_time.to_le_bytes())
+                .await?;
+            return Ok(());
+        }
+
         sender.send_empty_ok_response().await?;
         Ok(())
     }
@@ -44,7 +44,7 @@ impl ServerCommandHandler for LoginWithPersonalAccessToken {
         debug!("session: {session}, command: {self}");
         let system = system.read().await;
         let user = system
-            .login_with_personal_access_token(&self.token, Some(session))
+            .login_with_personal_access_token(&self.token, session.login_source(), Some(session))
             .await
             .with_error_context(|error| {
                 format!(
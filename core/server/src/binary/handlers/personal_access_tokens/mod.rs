@@ -20,5 +20,6 @@ pub mod create_personal_access_token_handler;
 pub mod delete_personal_access_token_handler;
 pub mod get_personal_access_tokens_handler;
 pub mod login_with_personal_access_token_handler;
+pub mod update_personal_access_token_quota_handler;
 
 pub const COMPONENT: &str = "PERSONAL_ACCESS_TOKEN_HANDLER";
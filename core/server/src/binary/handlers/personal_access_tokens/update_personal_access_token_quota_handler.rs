@@ -0,0 +1,81 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::command::{BinaryServerCommand, ServerCommand, ServerCommandHandler};
+use crate::binary::handlers::personal_access_tokens::COMPONENT;
+use crate::binary::handlers::utils::receive_and_validate;
+use crate::binary::sender::SenderKind;
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy_common::IggyError;
+use iggy_common::update_personal_access_token_quota::UpdatePersonalAccessTokenQuota;
+use tracing::{debug, instrument};
+
+impl ServerCommandHandler for UpdatePersonalAccessTokenQuota {
+    fn code(&self) -> u32 {
+        iggy_common::UPDATE_PERSONAL_ACCESS_TOKEN_QUOTA_CODE
+    }
+
+    #[instrument(skip_all, name = "trace_update_personal_access_token_quota", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id))]
+    async fn handle(
+        self,
+        sender: &mut SenderKind,
+        _length: u32,
+        session: &Session,
+        system: &SharedSystem,
+    ) -> Result<(), IggyError> {
+        debug!("session: {session}, command: {self}");
+
+        let mut system = system.write().await;
+        system
+            .update_personal_access_token_quota(session, &self.user_id, &self.name, self.quota)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to update quota for personal access token: {}, user_id: {}, session: {session}",
+                    self.name, self.user_id
+                )
+            })?;
+
+        let system = system.downgrade();
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                &EntryCommand::UpdatePersonalAccessTokenQuota(self),
+            )
+            .await?;
+        sender.send_empty_ok_response().await?;
+        Ok(())
+    }
+}
+
+impl BinaryServerCommand for UpdatePersonalAccessTokenQuota {
+    async fn from_sender(sender: &mut SenderKind, code: u32, length: u32) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        match receive_and_validate(sender, code, length).await? {
+            ServerCommand::UpdatePersonalAccessTokenQuota(command) => Ok(command),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
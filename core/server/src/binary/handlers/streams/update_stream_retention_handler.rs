@@ -0,0 +1,98 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::command::{BinaryServerCommand, ServerCommand, ServerCommandHandler};
+use crate::binary::handlers::utils::receive_and_validate;
+use crate::binary::{handlers::streams::COMPONENT, sender::SenderKind};
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use error_set::ErrContext;
+use iggy_common::IggyError;
+use iggy_common::update_stream_retention::UpdateStreamRetention;
+use tracing::{debug, instrument};
+
+impl ServerCommandHandler for UpdateStreamRetention {
+    fn code(&self) -> u32 {
+        iggy_common::UPDATE_STREAM_RETENTION_CODE
+    }
+
+    #[instrument(skip_all, name = "trace_update_stream_retention", fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_stream_id = self.stream_id.as_string()))]
+    async fn handle(
+        self,
+        sender: &mut SenderKind,
+        _length: u32,
+        session: &Session,
+        system: &SharedSystem,
+    ) -> Result<(), IggyError> {
+        debug!("session: {session}, command: {self}");
+        let stream_id = self.stream_id.clone();
+
+        let mut system = system.write().await;
+        system
+                .update_stream_retention(
+                    session,
+                    &self.stream_id,
+                    self.max_size,
+                    self.message_expiry,
+                    self.size_policy,
+                )
+                .await
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to update retention for stream with id: {stream_id}, session: {session}")
+                })?;
+
+        let system = system.downgrade();
+        let stream = system
+            .get_stream(&self.stream_id)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get stream with id: {stream_id}")
+            })?;
+        let command = UpdateStreamRetention {
+            stream_id: self.stream_id,
+            max_size: stream.max_size,
+            message_expiry: stream.message_expiry,
+            size_policy: stream.size_policy,
+        };
+        system
+            .state
+            .apply(session.get_user_id(), &EntryCommand::UpdateStreamRetention(command))
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to apply update retention for stream with id: {stream_id}, session: {session}")
+            })?;
+        sender.send_empty_ok_response().await?;
+        Ok(())
+    }
+}
+
+impl BinaryServerCommand for UpdateStreamRetention {
+    async fn from_sender(
+        sender: &mut SenderKind,
+        code: u32,
+        length: u32,
+    ) -> Result<Self, IggyError> {
+        match receive_and_validate(sender, code, length).await? {
+            ServerCommand::UpdateStreamRetention(update_stream_retention) => {
+                Ok(update_stream_retention)
+            }
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::command::{BinaryServerCommand, ServerCommand, ServerCommandHandler};
+use crate::binary::handlers::system::COMPONENT;
+use crate::binary::handlers::utils::receive_and_validate;
+use crate::binary::sender::SenderKind;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use error_set::ErrContext;
+use iggy_common::enable_frame_compression::EnableFrameCompression;
+use iggy_common::{IggyError, ProtocolFeatures};
+use tracing::debug;
+
+impl ServerCommandHandler for EnableFrameCompression {
+    fn code(&self) -> u32 {
+        iggy_common::ENABLE_FRAME_COMPRESSION_CODE
+    }
+
+    async fn handle(
+        self,
+        sender: &mut SenderKind,
+        _length: u32,
+        session: &Session,
+        system: &SharedSystem,
+    ) -> Result<(), IggyError> {
+        debug!("session: {session}, command: {self}");
+        if !system
+            .read()
+            .await
+            .protocol_features()
+            .contains(ProtocolFeatures::FRAME_COMPRESSION)
+        {
+            return Err(IggyError::FeatureUnavailable);
+        }
+
+        sender
+            .enable_frame_compression(self.compression)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to enable frame compression, session: {session}"
+                )
+            })?;
+        session.set_frame_compression(self.compression);
+        sender.send_empty_ok_response().await?;
+        debug!(
+            "Enabled frame compression: {} for session: {session}",
+            self.compression
+        );
+        Ok(())
+    }
+}
+
+impl BinaryServerCommand for EnableFrameCompression {
+    async fn from_sender(sender: &mut SenderKind, code: u32, length: u32) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        match receive_and_validate(sender, code, length).await? {
+            ServerCommand::EnableFrameCompression(command) => Ok(command),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::command::{BinaryServerCommand, ServerCommandHandler};
+use crate::binary::handlers::system::COMPONENT;
+use crate::binary::sender::SenderKind;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use error_set::ErrContext;
+use iggy_common::IggyError;
+use iggy_common::enable_pipelining::EnablePipelining;
+use tracing::debug;
+
+impl ServerCommandHandler for EnablePipelining {
+    fn code(&self) -> u32 {
+        iggy_common::ENABLE_PIPELINING_CODE
+    }
+
+    async fn handle(
+        self,
+        sender: &mut SenderKind,
+        _length: u32,
+        session: &Session,
+        _system: &SharedSystem,
+    ) -> Result<(), IggyError> {
+        debug!("session: {session}, command: {self}");
+        sender.enable_pipelining().with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to enable pipelining, session: {session}"
+            )
+        })?;
+        sender.send_empty_ok_response().await?;
+        debug!("Enabled pipelining for session: {session}");
+        Ok(())
+    }
+}
+
+impl BinaryServerCommand for EnablePipelining {
+    async fn from_sender(
+        _sender: &mut SenderKind,
+        _code: u32,
+        _length: u32,
+    ) -> Result<Self, IggyError>
+    where
+        Self: Sized,
+    {
+        Ok(EnablePipelining {})
+    }
+}
@@ -20,6 +20,7 @@ use crate::binary::command::{BinaryServerCommand, ServerCommand, ServerCommandHa
 use crate::binary::handlers::utils::receive_and_validate;
 use crate::binary::sender::SenderKind;
 use crate::streaming::session::Session;
+use crate::streaming::systems::snapshot;
 use crate::streaming::systems::system::SharedSystem;
 use bytes::Bytes;
 use iggy_common::IggyError;
@@ -40,9 +41,16 @@ impl ServerCommandHandler for GetSnapshot {
     ) -> Result<(), IggyError> {
         debug!("session: {session}, command: {self}");
 
-        let system = system.read().await;
+        let snapshot_types = system
+            .read()
+            .await
+            .ensure_can_get_snapshot(session, &self.snapshot_types)?;
+        // The CPU is sampled for a few seconds, so the profile is captured without holding the system lock.
+        let cpu_profile = snapshot::capture_cpu_profile(&snapshot_types).await;
         let snapshot = system
-            .get_snapshot(session, self.compression, &self.snapshot_types)
+            .read()
+            .await
+            .get_snapshot(self.compression, &snapshot_types, cpu_profile)
             .await?;
         let bytes = Bytes::copy_from_slice(&snapshot.0);
         sender.send_ok_response(&bytes).await?;
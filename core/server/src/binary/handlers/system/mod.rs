@@ -16,11 +16,22 @@
  * under the License.
  */
 
+pub mod disconnect_client_handler;
+pub mod enable_frame_compression_handler;
+pub mod enable_pipelining_handler;
 pub mod get_client_handler;
 pub mod get_clients_handler;
+pub mod get_health_handler;
+pub mod get_log_filter_handler;
 pub mod get_me_handler;
+pub mod get_profile_handler;
 pub mod get_snapshot;
 pub mod get_stats_handler;
+pub mod get_stats_history_handler;
+pub mod hello_handler;
+pub mod identify_client_handler;
 pub mod ping_handler;
+pub mod reload_config_handler;
+pub mod set_log_filter_handler;
 
 pub const COMPONENT: &str = "SYSTEM_HANDLER";
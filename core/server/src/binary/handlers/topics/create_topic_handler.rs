@@ -26,8 +26,8 @@ use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
 use error_set::ErrContext;
-use iggy_common::IggyError;
 use iggy_common::create_topic::CreateTopic;
+use iggy_common::{Identifier, IggyError};
 use tracing::{debug, instrument};
 
 impl ServerCommandHandler for CreateTopic {
@@ -65,9 +65,12 @@ impl ServerCommandHandler for CreateTopic {
         self.message_expiry = topic.message_expiry;
         self.max_topic_size = topic.max_topic_size;
         let topic_id = topic.topic_id;
-        let response = mapper::map_topic(topic).await;
+        let topic_stream_id = topic.stream_id;
 
         let system = system.downgrade();
+        let stream = system.get_stream_by_id(topic_stream_id)?;
+        let topic = stream.get_topic(&Identifier::numeric(topic_id)?)?;
+        let response = mapper::map_topic(topic, stream.message_expiry).await;
         system
             .state
             .apply(session.get_user_id(), &EntryCommand::CreateTopic(CreateTopicWithId {
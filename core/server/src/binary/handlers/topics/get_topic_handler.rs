@@ -51,7 +51,8 @@ impl ServerCommandHandler for GetTopic {
             return Ok(());
         };
 
-        let topic = mapper::map_topic(topic).await;
+        let stream_message_expiry = system.get_stream_by_id(topic.stream_id)?.message_expiry;
+        let topic = mapper::map_topic(topic, stream_message_expiry).await;
         sender.send_ok_response(&topic).await?;
         Ok(())
     }
@@ -51,7 +51,8 @@ impl ServerCommandHandler for GetTopics {
                     self.stream_id
                 )
             })?;
-        let response = mapper::map_topics(&topics);
+        let stream_message_expiry = system.get_stream(&self.stream_id)?.message_expiry;
+        let response = mapper::map_topics(&topics, stream_message_expiry);
         sender.send_ok_response(&response).await?;
         Ok(())
     }
@@ -42,17 +42,29 @@ impl ServerCommandHandler for LoginUser {
         system: &SharedSystem,
     ) -> Result<(), IggyError> {
         debug!("session: {session}, command: {self}");
-        let system = system.read().await;
-        let user = system
-            .login_user(&self.username, &self.password, Some(session))
-            .await
-            .with_error_context(|error| {
-                format!(
-                    "{COMPONENT} (error: {error}) - failed to login user with name: {}, session: {session}",
-                    self.username
+        let (user_id, should_rehash_password) = {
+            let system = system.read().await;
+            let user = system
+                .login_user(
+                    &self.username,
+                    &self.password,
+                    session.login_source(),
+                    Some(session),
                 )
-            })?;
-        let identity_info = mapper::map_identity_info(user.id);
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to login user with name: {}, session: {session}",
+                        self.username
+                    )
+                })?;
+            (user.id, system.should_rehash_password(user))
+        };
+        if should_rehash_password {
+            let mut system = system.write().await;
+            system.rehash_password(user_id, &self.password).await;
+        }
+        let identity_info = mapper::map_identity_info(user_id);
         sender.send_ok_response(&identity_info).await?;
         Ok(())
     }
@@ -23,7 +23,9 @@ pub mod get_user_handler;
 pub mod get_users_handler;
 pub mod login_user_handler;
 pub mod logout_user_handler;
+pub mod revoke_user_sessions_handler;
 pub mod update_permissions_handler;
 pub mod update_user_handler;
+pub mod update_user_quota_handler;
 
 pub const COMPONENT: &str = "USER_HANDLER";
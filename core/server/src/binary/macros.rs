@@ -23,6 +23,7 @@
 /// 2) The `from_code_and_payload(code, payload)` function that matches each code.
 /// 3) The `to_bytes()` function that matches each variant.
 /// 4) The `validate()` function that matches each variant.
+/// 5) The `codes()` function that returns the codes of all the variants.
 #[macro_export]
 macro_rules! define_server_command_enum {
     (
@@ -68,10 +69,23 @@ macro_rules! define_server_command_enum {
                             <$ty as BinaryServerCommand>::from_sender(sender, code, length).await?
                         )),
                     )*
-                    _ => Err(IggyError::InvalidCommand),
+                    _ => {
+                        // The payload of the unknown command is skipped, so that the connection can be reused.
+                        let mut payload = BytesMut::zeroed(length as usize);
+                        if length > 0 {
+                            sender.read(&mut payload).await?;
+                        }
+                        error!("Invalid server command: {}", code);
+                        Err(IggyError::InvalidCommand)
+                    }
                 }
             }
 
+            /// Returns the codes of all the commands supported by the server.
+            pub fn codes() -> Vec<u32> {
+                vec![$($code,)*]
+            }
+
             /// Converts the command into raw bytes.
             pub fn to_bytes(&self) -> Bytes {
                 match self {
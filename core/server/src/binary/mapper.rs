@@ -16,7 +16,7 @@
  * under the License.
  */
 
-use crate::streaming::clients::client_manager::{Client, Transport};
+use crate::streaming::clients::client_manager::{AuthenticationMethod, Client, Transport};
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::streams::stream::Stream;
@@ -25,7 +25,10 @@ use crate::streaming::topics::topic::Topic;
 use crate::streaming::users::user::User;
 use bytes::{BufMut, Bytes, BytesMut};
 use iggy_common::locking::{IggySharedMut, IggySharedMutFn};
-use iggy_common::{BytesSerializable, ConsumerOffsetInfo, Sizeable, Stats, UserId};
+use iggy_common::{
+    BytesSerializable, ConfigReload, ConsumerOffsetInfo, Health, IggyExpiry, LogFilter,
+    ServerHello, Sizeable, Stats, StatsHistory, UserId,
+};
 use tokio::sync::RwLock;
 
 pub fn map_stats(stats: &Stats) -> Bytes {
@@ -73,6 +76,99 @@ pub fn map_stats(stats: &Stats) -> Bytes {
         bytes.put_f32_le(metrics.hit_ratio);
     }
 
+    bytes.put_u64_le(stats.throttled_requests_count);
+    bytes.put_u64_le(stats.throttle_time.as_micros());
+    bytes.freeze()
+}
+
+pub fn map_stats_history(stats_history: &StatsHistory) -> Bytes {
+    let mut bytes = BytesMut::new();
+    bytes.put_u32_le(stats_history.series.len() as u32);
+    for series in &stats_history.series {
+        bytes.put_u64_le(series.interval.as_micros());
+        bytes.put_u64_le(series.retention.as_micros());
+        bytes.put_u32_le(series.samples.len() as u32);
+        for sample in &series.samples {
+            bytes.put_u64_le(sample.timestamp.as_micros());
+            bytes.put_f32_le(sample.cpu_usage);
+            bytes.put_f32_le(sample.total_cpu_usage);
+            bytes.put_u64_le(sample.memory_usage.as_bytes_u64());
+            bytes.put_u64_le(sample.available_memory.as_bytes_u64());
+            bytes.put_u32_le(sample.clients_count);
+            bytes.put_u64_le(sample.messages_count);
+            bytes.put_u64_le(sample.messages_size_bytes.as_bytes_u64());
+            bytes.put_u64_le(sample.read_bytes.as_bytes_u64());
+            bytes.put_u64_le(sample.written_bytes.as_bytes_u64());
+            bytes.put_u32_le(sample.topics.len() as u32);
+            for topic in &sample.topics {
+                bytes.put_u32_le(topic.stream_id);
+                bytes.put_u32_le(topic.topic_id);
+                bytes.put_u64_le(topic.messages_count);
+                bytes.put_u64_le(topic.size_bytes.as_bytes_u64());
+            }
+        }
+    }
+    bytes.freeze()
+}
+
+pub fn map_server_hello(server_hello: &ServerHello) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(
+        25 + server_hello.compression_algorithms.len() + 4 * server_hello.commands.len(),
+    );
+    bytes.put_u32_le(server_hello.protocol_version);
+    bytes.put_u32_le(server_hello.min_client_protocol_version);
+    bytes.put_u32_le(server_hello.max_frame_size);
+    bytes.put_u64_le(server_hello.features.bits());
+    bytes.put_u8(server_hello.compression_algorithms.len() as u8);
+    for compression_algorithm in &server_hello.compression_algorithms {
+        bytes.put_u8(compression_algorithm.as_code());
+    }
+    bytes.put_u32_le(server_hello.commands.len() as u32);
+    for code in &server_hello.commands {
+        bytes.put_u32_le(*code);
+    }
+    bytes.freeze()
+}
+
+pub fn map_config_reload(config_reload: &ConfigReload) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for paths in [&config_reload.applied, &config_reload.restart_required] {
+        bytes.put_u32_le(paths.len() as u32);
+        for path in paths {
+            bytes.put_u8(path.len() as u8);
+            bytes.put_slice(path.as_bytes());
+        }
+    }
+    bytes.freeze()
+}
+
+pub fn map_health(health: &Health) -> Bytes {
+    let mut bytes = BytesMut::new();
+    bytes.put_u8(health.status.as_code());
+    bytes.put_u32_le(health.checks.len() as u32);
+    for check in &health.checks {
+        bytes.put_u8(check.name.len() as u8);
+        bytes.put_slice(check.name.as_bytes());
+        bytes.put_u8(check.status.as_code());
+        bytes.put_u32_le(check.details.len() as u32);
+        bytes.put_slice(check.details.as_bytes());
+    }
+    bytes.freeze()
+}
+
+pub fn map_log_filter(log_filter: &LogFilter) -> Bytes {
+    let mut bytes =
+        BytesMut::with_capacity(16 + log_filter.filter.len() + log_filter.default_filter.len());
+    for directives in [&log_filter.filter, &log_filter.default_filter] {
+        bytes.put_u32_le(directives.len() as u32);
+        bytes.put_slice(directives.as_bytes());
+    }
+    bytes.put_u64_le(
+        log_filter
+            .expires_at
+            .map(|expires_at| expires_at.as_micros())
+            .unwrap_or_default(),
+    );
     bytes.freeze()
 }
 
@@ -116,6 +212,12 @@ pub fn map_user(user: &User) -> Bytes {
     } else {
         bytes.put_u32_le(0);
     }
+    if let Some(quota) = &user.quota {
+        bytes.put_u8(1);
+        bytes.put_slice(&quota.to_bytes());
+    } else {
+        bytes.put_u8(0);
+    }
     bytes.freeze()
 }
 
@@ -151,8 +253,11 @@ pub fn map_personal_access_tokens(personal_access_tokens: &[PersonalAccessToken]
 pub fn map_stream(stream: &Stream) -> Bytes {
     let mut bytes = BytesMut::new();
     extend_stream(stream, &mut bytes);
+    bytes.put_u64_le(stream.max_size.into());
+    bytes.put_u64_le(stream.message_expiry.into());
+    bytes.put_u8(stream.size_policy.as_code());
     for topic in stream.get_topics() {
-        extend_topic(topic, &mut bytes);
+        extend_topic(topic, stream.message_expiry, &mut bytes);
     }
     bytes.freeze()
 }
@@ -165,17 +270,17 @@ pub fn map_streams(streams: &[&Stream]) -> Bytes {
     bytes.freeze()
 }
 
-pub fn map_topics(topics: &[&Topic]) -> Bytes {
+pub fn map_topics(topics: &[&Topic], stream_message_expiry: IggyExpiry) -> Bytes {
     let mut bytes = BytesMut::new();
     for topic in topics {
-        extend_topic(topic, &mut bytes);
+        extend_topic(topic, stream_message_expiry, &mut bytes);
     }
     bytes.freeze()
 }
 
-pub async fn map_topic(topic: &Topic) -> Bytes {
+pub async fn map_topic(topic: &Topic, stream_message_expiry: IggyExpiry) -> Bytes {
     let mut bytes = BytesMut::new();
-    extend_topic(topic, &mut bytes);
+    extend_topic(topic, stream_message_expiry, &mut bytes);
     for partition in topic.get_partitions() {
         let partition = partition.read().await;
         extend_partition(&partition, &mut bytes);
@@ -218,11 +323,16 @@ fn extend_stream(stream: &Stream, bytes: &mut BytesMut) {
     bytes.put_slice(stream.name.as_bytes());
 }
 
-fn extend_topic(topic: &Topic, bytes: &mut BytesMut) {
+/// The topic is mapped with its effective message expiry, including the one inherited from its stream.
+fn extend_topic(topic: &Topic, stream_message_expiry: IggyExpiry, bytes: &mut BytesMut) {
     bytes.put_u32_le(topic.topic_id);
     bytes.put_u64_le(topic.created_at.into());
     bytes.put_u32_le(topic.get_partitions().len() as u32);
-    bytes.put_u64_le(topic.message_expiry.into());
+    bytes.put_u64_le(
+        topic
+            .get_effective_message_expiry(stream_message_expiry)
+            .into(),
+    );
     bytes.put_u8(topic.compression_algorithm.as_code());
     bytes.put_u64_le(topic.max_topic_size.into());
     bytes.put_u8(topic.replication_factor);
@@ -255,12 +365,36 @@ fn extend_client(client: &Client, bytes: &mut BytesMut) {
     let transport: u8 = match client.transport {
         Transport::Tcp => 1,
         Transport::Quic => 2,
+        Transport::Unix => 3,
+        Transport::WebSocket => 4,
+        Transport::Kafka => 5,
+        Transport::Mqtt => 6,
+        Transport::Http => 7,
     };
     bytes.put_u8(transport);
     let address = client.session.ip_address.to_string();
     bytes.put_u32_le(address.len() as u32);
     bytes.put_slice(address.as_bytes());
     bytes.put_u32_le(client.consumer_groups.len() as u32);
+    bytes.put_u64_le(client.session.connected_at.into());
+    bytes.put_u64_le(client.session.get_last_activity_at().into());
+    bytes.put_u64_le(client.session.get_bytes_received());
+    bytes.put_u64_le(client.session.get_bytes_sent());
+    let authentication_method: u8 = match client.authentication_method {
+        None => 0,
+        Some(AuthenticationMethod::Password) => 1,
+        Some(AuthenticationMethod::PersonalAccessToken) => 2,
+        Some(AuthenticationMethod::PeerCredentials) => 3,
+        Some(AuthenticationMethod::JsonWebToken) => 4,
+    };
+    bytes.put_u8(authentication_method);
+    match &client.metadata {
+        Some(metadata) => {
+            bytes.put_u32_le(metadata.get_buffer_size() as u32);
+            metadata.write_to_buffer(bytes);
+        }
+        None => bytes.put_u32_le(0),
+    }
 }
 
 fn extend_user(user: &User, bytes: &mut BytesMut) {
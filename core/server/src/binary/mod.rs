@@ -16,7 +16,9 @@
  * under the License.
  */
 
+pub mod buffered_sender;
 pub mod command;
+pub mod frame_compressor;
 pub mod handlers;
 pub mod mapper;
 pub mod sender;
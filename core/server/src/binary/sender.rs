@@ -19,14 +19,26 @@
 use std::future::Future;
 use std::io::IoSlice;
 
+use crate::binary::buffered_sender::BufferedSender;
+use crate::binary::frame_compressor::{FrameCompressionStats, FrameCompressor};
+use crate::tcp::sender;
 use crate::tcp::tcp_sender::TcpSender;
 use crate::tcp::tcp_tls_sender::TcpTlsSender;
+use crate::unix::unix_sender::UnixSender;
+use crate::websocket::websocket_sender::{WebSocketConnection, WebSocketSender};
 use crate::{quic::quic_sender::QuicSender, server_error::ServerError};
-use iggy_common::IggyError;
+use bytes::Bytes;
+use iggy_common::{FrameCompression, IggyError};
 use quinn::{RecvStream, SendStream};
-use tokio::net::TcpStream;
+use tokio::net::{TcpStream, UnixStream};
 use tokio_rustls::server::TlsStream;
 
+/// The size of the status and the payload length preceding every response.
+pub const RESPONSE_HEADER_SIZE: usize = 8;
+
+/// The size of the request ID preceding every request and response on the pipelined connections.
+pub const REQUEST_ID_SIZE: usize = 4;
+
 macro_rules! forward_async_methods {
     (
         $(
@@ -41,6 +53,9 @@ macro_rules! forward_async_methods {
                     Self::Tcp(d) => d.$method_name($( $arg ),*).await,
                     Self::TcpTls(s) => s.$method_name($( $arg ),*).await,
                     Self::Quic(s) => s.$method_name($( $arg ),*).await,
+                    Self::Unix(s) => s.$method_name($( $arg ),*).await,
+                    Self::WebSocket(s) => s.$method_name($( $arg ),*).await,
+                    Self::Buffered(s) => s.$method_name($( $arg ),*).await,
                 }
             }
         )*
@@ -71,24 +86,218 @@ pub enum SenderKind {
     Tcp(TcpSender),
     TcpTls(TcpTlsSender),
     Quic(QuicSender),
+    Unix(UnixSender),
+    WebSocket(WebSocketSender),
+    Buffered(BufferedSender),
 }
 
 impl SenderKind {
     pub fn get_tcp_sender(stream: TcpStream) -> Self {
-        Self::Tcp(TcpSender { stream })
+        Self::Tcp(TcpSender {
+            sent_bytes: 0,
+            pipelining: false,
+            request_id: None,
+            compressor: FrameCompressor::default(),
+            stream,
+        })
     }
 
     pub fn get_tcp_tls_sender(stream: TlsStream<TcpStream>) -> Self {
-        Self::TcpTls(TcpTlsSender { stream })
+        Self::TcpTls(TcpTlsSender {
+            sent_bytes: 0,
+            pipelining: false,
+            request_id: None,
+            compressor: FrameCompressor::default(),
+            stream,
+        })
     }
 
-    pub fn get_quic_sender(send_stream: SendStream, recv_stream: RecvStream) -> Self {
+    /// Creates the sender for a single QUIC stream, using the frame compression negotiated on the connection.
+    pub fn get_quic_sender(
+        send_stream: SendStream,
+        recv_stream: RecvStream,
+        compression: FrameCompression,
+    ) -> Self {
         Self::Quic(QuicSender {
+            sent_bytes: 0,
             send: send_stream,
             recv: recv_stream,
+            compressor: FrameCompressor::new(compression),
+        })
+    }
+
+    pub fn get_unix_sender(stream: UnixStream) -> Self {
+        Self::Unix(UnixSender {
+            sent_bytes: 0,
+            pipelining: false,
+            request_id: None,
+            stream,
+        })
+    }
+
+    pub fn get_websocket_sender(stream: WebSocketConnection) -> Self {
+        Self::WebSocket(WebSocketSender {
+            sent_bytes: 0,
+            pipelining: false,
+            request_id: None,
+            stream,
         })
     }
 
+    /// Creates the sender for a single pipelined request, whose payload has been already read.
+    pub fn get_buffered_sender(request: Bytes) -> Self {
+        Self::Buffered(BufferedSender {
+            request,
+            responses: Vec::new(),
+        })
+    }
+
+    /// Returns the name of the transport, used as the metrics label.
+    pub fn transport(&self) -> &'static str {
+        match self {
+            Self::Tcp(_) => "tcp",
+            Self::TcpTls(_) => "tcp_tls",
+            Self::Quic(_) => "quic",
+            Self::Unix(_) => "unix",
+            Self::WebSocket(_) => "websocket",
+            Self::Buffered(_) => "buffered",
+        }
+    }
+
+    /// Returns the number of bytes sent to the client since the previous call.
+    pub fn take_sent_bytes(&mut self) -> u64 {
+        match self {
+            Self::Tcp(s) => std::mem::take(&mut s.sent_bytes),
+            Self::TcpTls(s) => std::mem::take(&mut s.sent_bytes),
+            Self::Quic(s) => std::mem::take(&mut s.sent_bytes),
+            Self::Unix(s) => std::mem::take(&mut s.sent_bytes),
+            Self::WebSocket(s) => std::mem::take(&mut s.sent_bytes),
+            // The buffered responses are counted once they're sent by the connection.
+            Self::Buffered(_) => 0,
+        }
+    }
+
+    /// Switches the connection to the pipelined framing, starting with the next request.
+    /// QUIC connections already multiplex the requests using separate streams.
+    pub fn enable_pipelining(&mut self) -> Result<(), IggyError> {
+        match self {
+            Self::Tcp(s) => s.pipelining = true,
+            Self::TcpTls(s) => s.pipelining = true,
+            Self::Unix(s) => s.pipelining = true,
+            Self::WebSocket(s) => s.pipelining = true,
+            Self::Quic(_) | Self::Buffered(_) => return Err(IggyError::FeatureUnavailable),
+        }
+        Ok(())
+    }
+
+    pub fn is_pipelining_enabled(&self) -> bool {
+        match self {
+            Self::Tcp(s) => s.pipelining,
+            Self::TcpTls(s) => s.pipelining,
+            Self::Unix(s) => s.pipelining,
+            Self::WebSocket(s) => s.pipelining,
+            Self::Buffered(_) => true,
+            Self::Quic(_) => false,
+        }
+    }
+
+    /// Enables the transport-level compression of the frames, starting with the next request.
+    /// Only TCP and QUIC connections support it.
+    pub fn enable_frame_compression(
+        &mut self,
+        compression: FrameCompression,
+    ) -> Result<(), IggyError> {
+        match self {
+            Self::Tcp(s) => s.compressor.enable(compression),
+            Self::TcpTls(s) => s.compressor.enable(compression),
+            Self::Quic(s) => s.compressor.enable(compression),
+            Self::Unix(_) | Self::WebSocket(_) | Self::Buffered(_) => {
+                return Err(IggyError::FeatureUnavailable);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn frame_compression(&self) -> FrameCompression {
+        match self {
+            Self::Tcp(s) => s.compressor.compression(),
+            Self::TcpTls(s) => s.compressor.compression(),
+            Self::Quic(s) => s.compressor.compression(),
+            Self::Unix(_) | Self::WebSocket(_) | Self::Buffered(_) => FrameCompression::default(),
+        }
+    }
+
+    /// Reads the compressed request payload of the given length and decompresses it,
+    /// so that it's served by the subsequent reads. Returns the length of the decompressed payload.
+    pub async fn read_compressed_request(
+        &mut self,
+        length: u32,
+        max_size: u32,
+    ) -> Result<u32, IggyError> {
+        if !self.frame_compression().is_enabled() {
+            return Err(IggyError::InvalidCompressedFrame);
+        }
+
+        let mut payload = vec![0u8; length as usize];
+        self.read(&mut payload).await?;
+        match self {
+            Self::Tcp(s) => s.compressor.decompress_request(&payload, max_size),
+            Self::TcpTls(s) => s.compressor.decompress_request(&payload, max_size),
+            Self::Quic(s) => s.compressor.decompress_request(&payload, max_size),
+            Self::Unix(_) | Self::WebSocket(_) | Self::Buffered(_) => {
+                Err(IggyError::InvalidCompressedFrame)
+            }
+        }
+    }
+
+    /// Returns the frame compression stats since the previous call.
+    pub fn take_frame_compression_stats(&mut self) -> FrameCompressionStats {
+        match self {
+            Self::Tcp(s) => s.compressor.take_stats(),
+            Self::TcpTls(s) => s.compressor.take_stats(),
+            Self::Quic(s) => s.compressor.take_stats(),
+            Self::Unix(_) | Self::WebSocket(_) | Self::Buffered(_) => {
+                FrameCompressionStats::default()
+            }
+        }
+    }
+
+    /// Sets the ID of the request being handled, which is then included in the response.
+    pub fn set_request_id(&mut self, request_id: u32) {
+        match self {
+            Self::Tcp(s) => s.request_id = Some(request_id),
+            Self::TcpTls(s) => s.request_id = Some(request_id),
+            Self::Unix(s) => s.request_id = Some(request_id),
+            Self::WebSocket(s) => s.request_id = Some(request_id),
+            Self::Quic(_) | Self::Buffered(_) => {}
+        }
+    }
+
+    /// Reads the bytes which are available, up to the size of the buffer. Unlike `read`, it's cancel-safe,
+    /// so the connection can wait for the next request and for the pipelined requests in flight at once.
+    pub async fn read_available(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError> {
+        match self {
+            Self::Tcp(s) => match s.compressor.read(buffer) {
+                Some(result) => result,
+                None => sender::read_available(&mut s.stream, buffer).await,
+            },
+            Self::TcpTls(s) => match s.compressor.read(buffer) {
+                Some(result) => result,
+                None => sender::read_available(&mut s.stream, buffer).await,
+            },
+            Self::Unix(s) => sender::read_available(&mut s.stream, buffer).await,
+            Self::WebSocket(s) => {
+                sender::read_available(&mut s.stream, buffer)
+                    .await
+                    .map_err(|error| match error {
+                        IggyError::TcpError => IggyError::ConnectionClosed,
+                        error => error,
+                    })
+            }
+            Self::Quic(_) | Self::Buffered(_) => self.read(buffer).await,
+        }
+    }
+
     forward_async_methods! {
         async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError>;
         async fn send_empty_ok_response(&mut self) -> Result<(), IggyError>;
@@ -16,13 +16,13 @@
  * under the License.
  */
 
+use crate::channels::reloadable_interval::ReloadableInterval;
 use crate::channels::server_command::BackgroundServerCommand;
 use crate::configs::server::StateMaintenanceConfig;
 use crate::streaming::systems::system::SharedSystem;
 use flume::Sender;
 use iggy_common::IggyDuration;
 use iggy_common::IggyTimestamp;
-use tokio::time;
 use tracing::{error, info, instrument, warn};
 
 pub struct StateArchiver {
@@ -50,7 +50,7 @@ impl StateArchiver {
         }
     }
 
-    pub fn start(&self) {
+    pub fn start(&self, system: SharedSystem) {
         if !self.enabled {
             info!("State archiver is disabled.");
             return;
@@ -61,7 +61,11 @@ impl StateArchiver {
         let sender = self.sender.clone();
         info!("State archiver is enabled, state will be archived every: {interval}.");
         tokio::spawn(async move {
-            let mut interval_timer = time::interval(interval.get_duration());
+            let mut interval_timer =
+                ReloadableInterval::new("state archiver", interval, &system, |config| {
+                    config.data_maintenance.state.interval
+                })
+                .await;
             loop {
                 interval_timer.tick().await;
                 sender
@@ -102,7 +106,7 @@ impl BackgroundServerCommand<ArchiveStateCommand> for ArchiveStateExecutor {
 
     fn start_command_sender(
         &mut self,
-        _system: SharedSystem,
+        system: SharedSystem,
         config: &crate::configs::server::ServerConfig,
         sender: Sender<ArchiveStateCommand>,
     ) {
@@ -113,7 +117,7 @@ impl BackgroundServerCommand<ArchiveStateCommand> for ArchiveStateExecutor {
         }
 
         let state_archiver = StateArchiver::new(&config.data_maintenance.state, sender);
-        state_archiver.start();
+        state_archiver.start(system);
     }
 
     fn start_command_consumer(
@@ -16,13 +16,13 @@
  * under the License.
  */
 
+use crate::channels::reloadable_interval::ReloadableInterval;
 use crate::channels::server_command::BackgroundServerCommand;
 use crate::configs::server::PersonalAccessTokenCleanerConfig;
 use crate::streaming::systems::system::SharedSystem;
 use flume::Sender;
 use iggy_common::IggyDuration;
 use iggy_common::IggyTimestamp;
-use tokio::time;
 use tracing::{debug, error, info, instrument};
 
 pub struct PersonalAccessTokenCleaner {
@@ -49,7 +49,7 @@ impl PersonalAccessTokenCleaner {
         }
     }
 
-    pub fn start(&self) {
+    pub fn start(&self, system: SharedSystem) {
         if !self.enabled {
             info!("Personal access token cleaner is disabled.");
             return;
@@ -61,7 +61,13 @@ impl PersonalAccessTokenCleaner {
             "Personal access token cleaner is enabled, expired tokens will be deleted every: {interval}."
         );
         tokio::spawn(async move {
-            let mut interval_timer = time::interval(interval.get_duration());
+            let mut interval_timer = ReloadableInterval::new(
+                "personal access token cleaner",
+                interval,
+                &system,
+                |config| config.personal_access_token.cleaner.interval,
+            )
+            .await;
             loop {
                 interval_timer.tick().await;
                 sender
@@ -111,13 +117,13 @@ impl BackgroundServerCommand<CleanPersonalAccessTokensCommand>
 
     fn start_command_sender(
         &mut self,
-        _system: SharedSystem,
+        system: SharedSystem,
         config: &crate::configs::server::ServerConfig,
         sender: Sender<CleanPersonalAccessTokensCommand>,
     ) {
         let personal_access_token_cleaner =
             PersonalAccessTokenCleaner::new(&config.personal_access_token.cleaner, sender);
-        personal_access_token_cleaner.start();
+        personal_access_token_cleaner.start(system);
     }
 
     fn start_command_consumer(
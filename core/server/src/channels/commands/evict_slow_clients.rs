@@ -0,0 +1,379 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::channels::reloadable_interval::ReloadableInterval;
+use crate::channels::server_command::BackgroundServerCommand;
+use crate::configs::server::{ClientEvictionAction, ClientEvictionConfig, ServerConfig};
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::systems::system::{SharedSystem, System};
+use ahash::{AHashMap, AHashSet};
+use flume::{Receiver, Sender};
+use iggy_common::locking::IggySharedMutFn;
+use iggy_common::{Identifier, IggyDuration, IggyTimestamp};
+use std::fmt::{Display, Formatter};
+use tracing::{debug, error, info, instrument, warn};
+
+pub struct SlowClientsVerifier {
+    interval: IggyDuration,
+    sender: Sender<EvictSlowClientsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct EvictSlowClientsCommand;
+
+/// Detects the idle clients, the ones stuck in a partial request and the lagging consumer group members,
+/// keeping the lag of each member between the verifications to find the continuously growing one.
+/// The idle and stuck clients are reported once, along with the time of their last activity.
+#[derive(Debug, Default)]
+pub struct EvictSlowClientsExecutor {
+    config: Option<ClientEvictionConfig>,
+    lags: AHashMap<GroupMember, MemberLag>,
+    reported: AHashMap<u32, u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct GroupMember {
+    client_id: u32,
+    stream_id: u32,
+    topic_id: u32,
+    group_id: u32,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct MemberLag {
+    lag: u64,
+    growths: u32,
+}
+
+#[derive(Debug)]
+enum SlowClientReason {
+    Idle { last_command_at: IggyTimestamp },
+    PartialFrame { frame_started_at: IggyTimestamp },
+    LaggingConsumer { member: GroupMember, lag: u64 },
+}
+
+struct SlowClient {
+    client_id: u32,
+    reason: SlowClientReason,
+    action: ClientEvictionAction,
+}
+
+impl SlowClientReason {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SlowClientReason::Idle { .. } => "idle",
+            SlowClientReason::PartialFrame { .. } => "partial_frame",
+            SlowClientReason::LaggingConsumer { .. } => "lagging_consumer",
+        }
+    }
+}
+
+impl Display for SlowClientReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SlowClientReason::Idle { last_command_at } => {
+                write!(f, "idle, last command at: {last_command_at}")
+            }
+            SlowClientReason::PartialFrame { frame_started_at } => {
+                write!(
+                    f,
+                    "stuck in a partial request started at: {frame_started_at}"
+                )
+            }
+            SlowClientReason::LaggingConsumer { member, lag } => write!(
+                f,
+                "lagging by {lag} messages in consumer group with ID: {} for stream with ID: {}, topic with ID: {}",
+                member.group_id, member.stream_id, member.topic_id
+            ),
+        }
+    }
+}
+
+impl MemberLag {
+    /// Records the current lag and returns the number of the consecutive verifications on which it has grown.
+    fn update(&mut self, lag: u64) -> u32 {
+        if lag > self.lag {
+            self.growths += 1;
+        } else {
+            self.growths = 0;
+        }
+        self.lag = lag;
+        self.growths
+    }
+}
+
+impl SlowClientsVerifier {
+    pub fn new(config: &ClientEvictionConfig, sender: Sender<EvictSlowClientsCommand>) -> Self {
+        Self {
+            interval: config.interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self, system: SharedSystem) {
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!("Slow and idle clients will be verified every: {interval}.");
+        tokio::spawn(async move {
+            let mut interval_timer =
+                ReloadableInterval::new("slow clients verifier", interval, &system, |config| {
+                    config.client_eviction.interval
+                })
+                .await;
+            loop {
+                interval_timer.tick().await;
+                debug!("Verifying slow and idle clients...");
+                sender
+                    .send(EvictSlowClientsCommand)
+                    .unwrap_or_else(|error| {
+                        error!("Failed to send EvictSlowClientsCommand. Error: {error}");
+                    });
+            }
+        });
+    }
+}
+
+impl EvictSlowClientsExecutor {
+    async fn find_slow_clients(
+        &mut self,
+        system: &System,
+        config: &ClientEvictionConfig,
+    ) -> Vec<SlowClient> {
+        let clients;
+        {
+            let client_manager = system.client_manager.read().await;
+            clients = client_manager.get_clients();
+        }
+
+        let now = IggyTimestamp::now();
+        let mut slow_clients = Vec::new();
+        let mut members = AHashSet::new();
+        let mut reported = AHashMap::new();
+        for client in clients {
+            let client = client.read().await;
+            let session = &client.session;
+            let client_id = session.client_id;
+            // Only the binary protocol transports record the requests of their sessions.
+            let binary_transport = matches!(
+                client.transport,
+                Transport::Tcp | Transport::Quic | Transport::Unix | Transport::WebSocket
+            );
+            let stuck = session
+                .get_frame_started_at()
+                .filter(|frame_started_at| {
+                    binary_transport
+                        && config.partial_frame.enabled
+                        && elapsed_micros(now, *frame_started_at)
+                            > config.partial_frame.timeout.as_micros()
+                })
+                .map(|frame_started_at| {
+                    (
+                        frame_started_at,
+                        SlowClientReason::PartialFrame { frame_started_at },
+                        config.partial_frame.action,
+                    )
+                });
+            let idle = || {
+                let last_command_at = session.get_last_command_at();
+                (binary_transport
+                    && config.idle.enabled
+                    && elapsed_micros(now, last_command_at) > config.idle.timeout.as_micros())
+                .then_some((
+                    last_command_at,
+                    SlowClientReason::Idle { last_command_at },
+                    config.idle.action,
+                ))
+            };
+            if let Some((activity_at, reason, action)) = stuck.or_else(idle) {
+                reported.insert(client_id, activity_at.as_micros());
+                if self.reported.get(&client_id) != Some(&activity_at.as_micros()) {
+                    slow_clients.push(SlowClient {
+                        client_id,
+                        reason,
+                        action,
+                    });
+                }
+                continue;
+            }
+
+            if !config.lagging_consumer.enabled {
+                continue;
+            }
+
+            for consumer_group in &client.consumer_groups {
+                let member = GroupMember {
+                    client_id,
+                    stream_id: consumer_group.stream_id,
+                    topic_id: consumer_group.topic_id,
+                    group_id: consumer_group.group_id,
+                };
+                let lag = match system
+                    .get_consumer_group_member_lag(
+                        member.stream_id,
+                        member.topic_id,
+                        member.group_id,
+                        member.client_id,
+                    )
+                    .await
+                {
+                    Ok(Some(lag)) => lag,
+                    Ok(None) => continue,
+                    Err(error) => {
+                        debug!(
+                            "Failed to get the lag of consumer group member: {member:?}. {error}"
+                        );
+                        continue;
+                    }
+                };
+
+                members.insert(member);
+                let member_lag = self
+                    .lags
+                    .entry(member)
+                    .or_insert(MemberLag { lag, growths: 0 });
+                let growths = member_lag.update(lag);
+                if growths < config.lagging_consumer.checks || lag < config.lagging_consumer.min_lag
+                {
+                    continue;
+                }
+
+                // The trend is tracked anew, so that the member isn't reported again on the next verification.
+                *member_lag = MemberLag { lag, growths: 0 };
+                slow_clients.push(SlowClient {
+                    client_id,
+                    reason: SlowClientReason::LaggingConsumer { member, lag },
+                    action: config.lagging_consumer.action,
+                });
+            }
+        }
+
+        self.lags.retain(|member, _| members.contains(member));
+        self.reported = reported;
+        slow_clients
+    }
+}
+
+fn elapsed_micros(now: IggyTimestamp, since: IggyTimestamp) -> u64 {
+    now.as_micros().saturating_sub(since.as_micros())
+}
+
+async fn evict(system: &System, slow_client: &SlowClient) {
+    let client_id = slow_client.client_id;
+    match (slow_client.action, &slow_client.reason) {
+        (ClientEvictionAction::None, _) => {}
+        (ClientEvictionAction::Disconnect, _) => {
+            let client = system.client_manager.read().await.try_get_client(client_id);
+            if let Some(client) = client {
+                client.read().await.session.disconnect();
+                info!("Disconnecting slow client with ID: {client_id}...");
+            }
+        }
+        (
+            ClientEvictionAction::LeaveConsumerGroup,
+            SlowClientReason::LaggingConsumer { member, .. },
+        ) => {
+            let (Ok(stream_id), Ok(topic_id), Ok(group_id)) = (
+                Identifier::numeric(member.stream_id),
+                Identifier::numeric(member.topic_id),
+                Identifier::numeric(member.group_id),
+            ) else {
+                return;
+            };
+            match system
+                .leave_consumer_group_by_client(&stream_id, &topic_id, &group_id, client_id, None)
+                .await
+            {
+                Ok(()) => info!(
+                    "Removed lagging client with ID: {client_id} from consumer group with ID: {group_id} for stream with ID: {stream_id}, topic with ID: {topic_id}."
+                ),
+                Err(error) => error!(
+                    "Failed to remove lagging client with ID: {client_id} from consumer group with ID: {group_id}. {error}"
+                ),
+            }
+        }
+        (ClientEvictionAction::LeaveConsumerGroup, _) => {}
+    }
+}
+
+impl BackgroundServerCommand<EvictSlowClientsCommand> for EvictSlowClientsExecutor {
+    #[instrument(skip_all, name = "trace_evict_slow_clients")]
+    async fn execute(&mut self, system: &SharedSystem, _command: EvictSlowClientsCommand) {
+        let Some(config) = self.config.clone() else {
+            return;
+        };
+
+        let system = system.read().await;
+        let slow_clients = self.find_slow_clients(&system, &config).await;
+        for slow_client in &slow_clients {
+            warn!(
+                "Slow client with ID: {} detected, {}, action: {}.",
+                slow_client.client_id, slow_client.reason, slow_client.action
+            );
+            system
+                .metrics
+                .increment_slow_clients(slow_client.reason.as_str(), slow_client.action.as_str());
+            evict(&system, slow_client).await;
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<EvictSlowClientsCommand>,
+    ) {
+        if !config.client_eviction.enabled {
+            info!("Slow and idle clients verification is disabled.");
+            return;
+        }
+
+        self.config = Some(config.client_eviction.clone());
+        let verifier = SlowClientsVerifier::new(&config.client_eviction, sender);
+        verifier.start(system);
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &ServerConfig,
+        receiver: Receiver<EvictSlowClientsCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Slow clients verifier receiver stopped.");
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn member_lag_should_count_consecutive_growths_and_reset_otherwise() {
+        let mut member_lag = MemberLag::default();
+        assert_eq!(member_lag.update(10), 1);
+        assert_eq!(member_lag.update(20), 2);
+        assert_eq!(member_lag.update(20), 0);
+        assert_eq!(member_lag.update(30), 1);
+        assert_eq!(member_lag.update(5), 0);
+    }
+}
@@ -17,19 +17,22 @@
  */
 
 use crate::archiver::ArchiverKind;
+use crate::channels::reloadable_interval::ReloadableInterval;
 use crate::channels::server_command::BackgroundServerCommand;
 use crate::configs::server::MessagesMaintenanceConfig;
 use crate::map_toggle_str;
+use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::system::SharedSystem;
 use crate::streaming::topics::topic::Topic;
 use error_set::ErrContext;
 use flume::Sender;
 use iggy_common::IggyDuration;
 use iggy_common::IggyError;
+use iggy_common::IggyExpiry;
 use iggy_common::IggyTimestamp;
+use iggy_common::StreamSizePolicy;
 use iggy_common::locking::IggySharedMutFn;
 use std::sync::Arc;
-use tokio::time;
 use tracing::{debug, error, info, instrument, trace};
 
 pub struct MessagesMaintainer {
@@ -61,7 +64,7 @@ impl MessagesMaintainer {
         }
     }
 
-    pub fn start(&self) {
+    pub fn start(&self, system: SharedSystem) {
         if !self.cleaner_enabled && !self.archiver_enabled {
             info!("Messages maintainer is disabled.");
             return;
@@ -77,7 +80,11 @@ impl MessagesMaintainer {
         let clean_messages = self.cleaner_enabled;
         let archive_messages = self.archiver_enabled;
         tokio::spawn(async move {
-            let mut interval_timer = time::interval(interval.get_duration());
+            let mut interval_timer =
+                ReloadableInterval::new("messages maintainer", interval, &system, |config| {
+                    config.data_maintenance.messages.interval
+                })
+                .await;
             loop {
                 interval_timer.tick().await;
                 sender
@@ -108,6 +115,7 @@ impl BackgroundServerCommand<MaintainMessagesCommand> for MaintainMessagesExecut
                 };
                 let expired_segments = handle_expired_segments(
                     topic,
+                    stream.message_expiry,
                     archiver.clone(),
                     system.config.segment.archive_expired,
                     command.clean_messages,
@@ -167,12 +175,42 @@ impl BackgroundServerCommand<MaintainMessagesCommand> for MaintainMessagesExecut
                     .metrics
                     .decrement_messages(deleted_segments.messages_count);
             }
+
+            let deleted_segments = match handle_stream_oldest_segments(stream).await {
+                Ok(deleted_segments) => deleted_segments,
+                Err(error) => {
+                    error!(
+                        "Failed to delete oldest segments for stream ID: {}. Error: {error}",
+                        stream.stream_id
+                    );
+                    continue;
+                }
+            };
+
+            if deleted_segments.segments_count == 0 {
+                continue;
+            }
+
+            info!(
+                "Deleted {} segments and {} messages for stream ID: {} to fit in its max size: {}",
+                deleted_segments.segments_count,
+                deleted_segments.messages_count,
+                stream.stream_id,
+                stream.max_size
+            );
+
+            system
+                .metrics
+                .decrement_segments(deleted_segments.segments_count);
+            system
+                .metrics
+                .decrement_messages(deleted_segments.messages_count);
         }
     }
 
     fn start_command_sender(
         &mut self,
-        _system: SharedSystem,
+        system: SharedSystem,
         config: &crate::configs::server::ServerConfig,
         sender: Sender<MaintainMessagesCommand>,
     ) {
@@ -185,7 +223,7 @@ impl BackgroundServerCommand<MaintainMessagesCommand> for MaintainMessagesExecut
 
         let messages_maintainer =
             MessagesMaintainer::new(&config.data_maintenance.messages, sender);
-        messages_maintainer.start();
+        messages_maintainer.start(system);
     }
 
     fn start_command_consumer(
@@ -213,11 +251,13 @@ impl BackgroundServerCommand<MaintainMessagesCommand> for MaintainMessagesExecut
 
 async fn handle_expired_segments(
     topic: &Topic,
+    default_message_expiry: IggyExpiry,
     archiver: Option<Arc<ArchiverKind>>,
     archive: bool,
     clean: bool,
 ) -> Result<HandledSegments, IggyError> {
-    let expired_segments = get_expired_segments(topic, IggyTimestamp::now()).await;
+    let expired_segments =
+        get_expired_segments(topic, default_message_expiry, IggyTimestamp::now()).await;
     if expired_segments.is_empty() {
         return Ok(HandledSegments::none());
     }
@@ -255,9 +295,13 @@ async fn handle_expired_segments(
     }
 }
 
-async fn get_expired_segments(topic: &Topic, now: IggyTimestamp) -> Vec<SegmentsToHandle> {
+async fn get_expired_segments(
+    topic: &Topic,
+    default_message_expiry: IggyExpiry,
+    now: IggyTimestamp,
+) -> Vec<SegmentsToHandle> {
     let expired_segments = topic
-        .get_expired_segments_start_offsets_per_partition(now)
+        .get_expired_segments_start_offsets_per_partition(default_message_expiry, now)
         .await;
     if expired_segments.is_empty() {
         debug!(
@@ -419,6 +463,82 @@ async fn get_oldest_segments(topic: &Topic) -> Vec<SegmentsToHandle> {
     oldest_segments
 }
 
+async fn handle_stream_oldest_segments(stream: &Stream) -> Result<HandledSegments, IggyError> {
+    if stream.size_policy != StreamSizePolicy::DeleteOldestSegments {
+        return Ok(HandledSegments::none());
+    }
+
+    if !stream.is_almost_full() {
+        debug!(
+            "Stream is not almost full, oldest segments will not be deleted for stream ID: {}",
+            stream.stream_id
+        );
+        return Ok(HandledSegments::none());
+    }
+
+    let mut handled_segments = HandledSegments::none();
+    while stream.is_almost_full() {
+        let Some((topic, oldest_segment)) = get_stream_oldest_segment(stream).await else {
+            debug!(
+                "No closed segments found to delete for stream ID: {}",
+                stream.stream_id
+            );
+            break;
+        };
+
+        let deleted_segments = delete_segments(topic, &[oldest_segment]).await?;
+        if deleted_segments.segments_count == 0 {
+            break;
+        }
+
+        handled_segments.segments_count += deleted_segments.segments_count;
+        handled_segments.messages_count += deleted_segments.messages_count;
+    }
+
+    Ok(handled_segments)
+}
+
+/// Finds the oldest closed segment across all the topics and partitions of the stream,
+/// based on the timestamp of its last message.
+async fn get_stream_oldest_segment(stream: &Stream) -> Option<(&Topic, SegmentsToHandle)> {
+    let mut oldest_segment: Option<(&Topic, u32, u64, u64)> = None;
+    for topic in stream.get_topics() {
+        for partition in topic.partitions.values() {
+            let partition = partition.read().await;
+            let Some(segment) = partition.get_segments().first() else {
+                continue;
+            };
+
+            if !segment.is_closed() {
+                continue;
+            }
+
+            let end_timestamp = segment.end_timestamp();
+            if oldest_segment
+                .as_ref()
+                .is_none_or(|(_, _, _, timestamp)| end_timestamp < *timestamp)
+            {
+                oldest_segment = Some((
+                    topic,
+                    partition.partition_id,
+                    segment.start_offset(),
+                    end_timestamp,
+                ));
+            }
+        }
+    }
+
+    oldest_segment.map(|(topic, partition_id, start_offset, _)| {
+        (
+            topic,
+            SegmentsToHandle {
+                partition_id,
+                start_offsets: vec![start_offset],
+            },
+        )
+    })
+}
+
 #[derive()]
 struct SegmentsToHandle {
     partition_id: u32,
@@ -18,7 +18,11 @@
 
 pub mod archive_state;
 pub mod clean_personal_access_tokens;
+pub mod evict_slow_clients;
+pub mod export_metrics;
 pub mod maintain_messages;
 pub mod print_sysinfo;
+pub mod publish_system_events;
+pub mod sample_stats_history;
 pub mod save_messages;
 pub mod verify_heartbeats;
@@ -0,0 +1,120 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::channels::server_command::BackgroundServerCommand;
+use crate::configs::server::{EventsConfig, ServerConfig};
+use crate::streaming::system_events;
+use crate::streaming::systems::system::SharedSystem;
+use flume::{Receiver, Sender};
+use iggy_common::{IggyMessage, SystemEvent};
+use tracing::{error, info, instrument, warn};
+
+#[derive(Default)]
+pub struct PublishSystemEventsExecutor {
+    config: Option<EventsConfig>,
+    sender: Option<Sender<SystemEvent>>,
+}
+
+impl BackgroundServerCommand<SystemEvent> for PublishSystemEventsExecutor {
+    #[instrument(skip_all, name = "trace_publish_system_event")]
+    async fn execute(&mut self, system: &SharedSystem, command: SystemEvent) {
+        let Some(config) = &self.config else {
+            return;
+        };
+
+        let message = config
+            .format
+            .encode(&command)
+            .and_then(|payload| IggyMessage::builder().payload(payload).build());
+        let message = match message {
+            Ok(message) => message,
+            Err(error) => {
+                error!("Failed to encode system event: {command:?}. {error}");
+                return;
+            }
+        };
+
+        let system = system.read().await;
+        if let Err(error) = system
+            .append_internal_messages(&config.stream, &config.topic, &[message])
+            .await
+        {
+            error!(
+                "Failed to append system event to: {}/{}. {error}",
+                config.stream, config.topic
+            );
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<SystemEvent>,
+    ) {
+        if !config.events.enabled {
+            info!("System events publisher is disabled.");
+            return;
+        }
+
+        // The sender is registered only once the stream and the topic are ready.
+        self.config = Some(config.events.clone());
+        self.sender = Some(sender);
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &ServerConfig,
+        receiver: Receiver<SystemEvent>,
+    ) {
+        let (Some(config), Some(sender)) = (self.config.clone(), self.sender.take()) else {
+            return;
+        };
+
+        tokio::spawn(async move {
+            let stream_id = match system
+                .write()
+                .await
+                .create_internal_topic(&config.stream, &config.topic, config.message_expiry)
+                .await
+            {
+                Ok(stream_id) => stream_id,
+                Err(error) => {
+                    error!(
+                        "Failed to initialize the system events topic: {}/{}, the events will not be published. {error}",
+                        config.stream, config.topic
+                    );
+                    return;
+                }
+            };
+
+            system_events::set_events_stream_id(stream_id);
+
+            system_events::register(sender);
+            info!(
+                "System events will be published to: {}/{} in {} format.",
+                config.stream, config.topic, config.format
+            );
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            warn!("System events publisher receiver stopped.");
+        });
+    }
+}
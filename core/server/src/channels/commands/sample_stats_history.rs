@@ -0,0 +1,100 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::channels::server_command::BackgroundServerCommand;
+use crate::configs::server::ServerConfig;
+use crate::streaming::systems::system::SharedSystem;
+use flume::{Receiver, Sender};
+use iggy_common::IggyDuration;
+use tokio::time;
+use tracing::{error, info, instrument, warn};
+
+#[derive(Debug, Default, Clone)]
+pub struct SampleStatsHistoryCommand;
+
+#[derive(Debug)]
+pub struct StatsHistorySampler {
+    interval: IggyDuration,
+    sender: Sender<SampleStatsHistoryCommand>,
+}
+
+pub struct SampleStatsHistoryExecutor;
+
+impl StatsHistorySampler {
+    pub fn new(interval: IggyDuration, sender: Sender<SampleStatsHistoryCommand>) -> Self {
+        Self { interval, sender }
+    }
+
+    pub fn start(&self) {
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!("Stats history sampler is enabled, the stats will be sampled every: {interval}.");
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(SampleStatsHistoryCommand)
+                    .unwrap_or_else(|error| {
+                        error!("Failed to send SampleStatsHistoryCommand. Error: {error}");
+                    });
+            }
+        });
+    }
+}
+
+impl BackgroundServerCommand<SampleStatsHistoryCommand> for SampleStatsHistoryExecutor {
+    #[instrument(skip_all, name = "trace_sample_stats_history")]
+    async fn execute(&mut self, system: &SharedSystem, _command: SampleStatsHistoryCommand) {
+        if let Err(error) = system.read().await.record_stats_history().await {
+            error!("Failed to sample the stats history. Error: {error}");
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<SampleStatsHistoryCommand>,
+    ) {
+        let config = &config.system.stats_history;
+        if !config.enabled {
+            info!("Stats history sampler is disabled.");
+            return;
+        }
+
+        // The long-term series is sampled on every n-th tick of the short-term one.
+        let sampler = StatsHistorySampler::new(config.short_term.interval, sender);
+        sampler.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &ServerConfig,
+        receiver: Receiver<SampleStatsHistoryCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            warn!("Stats history sampler stopped receiving commands.");
+        });
+    }
+}
@@ -16,13 +16,13 @@
  * under the License.
  */
 
+use crate::channels::reloadable_interval::ReloadableInterval;
 use crate::channels::server_command::BackgroundServerCommand;
 use crate::configs::server::MessageSaverConfig;
 use crate::configs::server::ServerConfig;
 use crate::streaming::systems::system::SharedSystem;
 use flume::{Receiver, Sender};
 use iggy_common::IggyDuration;
-use tokio::time;
 use tracing::{error, info, instrument, warn};
 
 pub struct MessagesSaver {
@@ -50,7 +50,7 @@ impl MessagesSaver {
         }
     }
 
-    pub fn start(&self) {
+    pub fn start(&self, system: SharedSystem) {
         if !self.enabled {
             info!("Message saver is disabled.");
             return;
@@ -63,7 +63,11 @@ impl MessagesSaver {
             "Message saver is enabled, buffered messages will be automatically saved every: {interval}, enforce fsync: {enforce_fsync}."
         );
         tokio::spawn(async move {
-            let mut interval_timer = time::interval(interval.get_duration());
+            let mut interval_timer =
+                ReloadableInterval::new("message saver", interval, &system, |config| {
+                    config.message_saver.interval
+                })
+                .await;
             loop {
                 interval_timer.tick().await;
                 let command = SaveMessagesCommand { enforce_fsync };
@@ -93,12 +97,12 @@ impl BackgroundServerCommand<SaveMessagesCommand> for SaveMessagesExecutor {
 
     fn start_command_sender(
         &mut self,
-        _system: SharedSystem,
+        system: SharedSystem,
         config: &ServerConfig,
         sender: Sender<SaveMessagesCommand>,
     ) {
         let messages_saver = MessagesSaver::new(&config.message_saver, sender);
-        messages_saver.start();
+        messages_saver.start(system);
     }
 
     fn start_command_consumer(
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::channels::reloadable_interval::ReloadableInterval;
 use crate::channels::server_command::BackgroundServerCommand;
 use crate::configs::server::HeartbeatConfig;
 use crate::streaming::systems::system::SharedSystem;
@@ -23,7 +24,6 @@ use flume::Sender;
 use iggy_common::IggyDuration;
 use iggy_common::IggyTimestamp;
 use iggy_common::locking::IggySharedMutFn;
-use tokio::time;
 use tracing::{debug, error, info, instrument, warn};
 
 const MAX_THRESHOLD: f64 = 1.2;
@@ -51,26 +51,30 @@ impl VerifyHeartbeats {
         }
     }
 
-    pub fn start(&self) {
+    pub fn start(&self, system: SharedSystem) {
         if !self.enabled {
             info!("Heartbeats verification is disabled.");
             return;
         }
 
         let interval = self.interval;
-        let max_interval = IggyDuration::from((MAX_THRESHOLD * interval.as_micros() as f64) as u64);
+        let max_interval = max_allowed_interval(interval);
         let sender = self.sender.clone();
         info!(
             "Heartbeats will be verified every: {interval}. Max allowed interval: {max_interval}."
         );
         tokio::spawn(async move {
-            let mut interval_timer = time::interval(interval.get_duration());
+            let mut interval_timer =
+                ReloadableInterval::new("heartbeats verifier", interval, &system, |config| {
+                    config.heartbeat.interval
+                })
+                .await;
             loop {
                 interval_timer.tick().await;
                 debug!("Verifying heartbeats...");
                 sender
                     .send(VerifyHeartbeatsCommand {
-                        interval: max_interval,
+                        interval: max_allowed_interval(interval_timer.interval()),
                     })
                     .unwrap_or_else(|error| {
                         error!("Failed to send VerifyHeartbeats. Error: {}", error);
@@ -80,6 +84,10 @@ impl VerifyHeartbeats {
     }
 }
 
+fn max_allowed_interval(interval: IggyDuration) -> IggyDuration {
+    IggyDuration::from((MAX_THRESHOLD * interval.as_micros() as f64) as u64)
+}
+
 impl BackgroundServerCommand<VerifyHeartbeatsCommand> for VerifyHeartbeatsExecutor {
     #[instrument(skip_all, name = "trace_verify_heartbeats")]
     async fn execute(&mut self, system: &SharedSystem, command: VerifyHeartbeatsCommand) {
@@ -125,12 +133,12 @@ impl BackgroundServerCommand<VerifyHeartbeatsCommand> for VerifyHeartbeatsExecut
 
     fn start_command_sender(
         &mut self,
-        _system: SharedSystem,
+        system: SharedSystem,
         config: &crate::configs::server::ServerConfig,
         sender: Sender<VerifyHeartbeatsCommand>,
     ) {
         let verify_heartbeats = VerifyHeartbeats::new(&config.heartbeat, sender);
-        verify_heartbeats.start();
+        verify_heartbeats.start(system);
     }
 
     fn start_command_consumer(
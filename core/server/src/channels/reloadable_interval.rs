@@ -0,0 +1,89 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::configs::server::ServerConfig;
+use crate::streaming::systems::system::SharedSystem;
+use iggy_common::IggyDuration;
+use std::sync::Arc;
+use tokio::sync::watch;
+use tokio::time::{self, Instant, Interval};
+use tracing::info;
+
+/// The interval timer of a background command sender, which picks up the interval
+/// changed by the configuration reload and restarts the timer with the new period.
+pub struct ReloadableInterval {
+    name: &'static str,
+    interval: IggyDuration,
+    timer: Interval,
+    config_changes: Option<watch::Receiver<Arc<ServerConfig>>>,
+    get_interval: fn(&ServerConfig) -> IggyDuration,
+}
+
+impl ReloadableInterval {
+    pub async fn new(
+        name: &'static str,
+        interval: IggyDuration,
+        system: &SharedSystem,
+        get_interval: fn(&ServerConfig) -> IggyDuration,
+    ) -> Self {
+        Self {
+            name,
+            interval,
+            timer: time::interval(interval.get_duration()),
+            config_changes: system.read().await.subscribe_config_changes(),
+            get_interval,
+        }
+    }
+
+    pub fn interval(&self) -> IggyDuration {
+        self.interval
+    }
+
+    /// Completes when the next tick of the current interval is reached.
+    pub async fn tick(&mut self) {
+        loop {
+            let Some(config_changes) = self.config_changes.as_mut() else {
+                self.timer.tick().await;
+                return;
+            };
+
+            let changed = tokio::select! {
+                _ = self.timer.tick() => return,
+                changed = config_changes.changed() => changed,
+            };
+            if changed.is_err() {
+                self.config_changes = None;
+                continue;
+            }
+
+            let interval = (self.get_interval)(&config_changes.borrow_and_update());
+            if interval.is_zero() || interval == self.interval {
+                continue;
+            }
+
+            info!(
+                "Changed {} interval from: {} to: {interval}.",
+                self.name, self.interval
+            );
+            self.interval = interval;
+            self.timer = time::interval_at(
+                Instant::now() + interval.get_duration(),
+                interval.get_duration(),
+            );
+        }
+    }
+}
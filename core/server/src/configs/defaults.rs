@@ -20,20 +20,27 @@ use super::system::MemoryPoolConfig;
 use super::tcp::TcpSocketConfig;
 use crate::configs::http::{
     HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig,
+    HttpWebSocketConfig,
 };
+use crate::configs::kafka::KafkaConfig;
+use crate::configs::mqtt::MqttConfig;
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
-    ArchiverConfig, DataMaintenanceConfig, HeartbeatConfig, MessageSaverConfig,
-    MessagesMaintenanceConfig, PersonalAccessTokenCleanerConfig, PersonalAccessTokenConfig,
-    ServerConfig, StateMaintenanceConfig, TelemetryConfig, TelemetryLogsConfig,
-    TelemetryTracesConfig,
+    ArchiverConfig, ClientEvictionConfig, DataMaintenanceConfig, EventsConfig, HealthConfig,
+    HeartbeatConfig, IdleClientsConfig, LaggingConsumerConfig, MessageSaverConfig,
+    MessagesMaintenanceConfig, PartialFrameConfig, PersonalAccessTokenCleanerConfig,
+    PersonalAccessTokenConfig, ServerConfig, StateMaintenanceConfig, TelemetryConfig,
+    TelemetryLogsConfig, TelemetryMetricsConfig, TelemetryTracesConfig,
 };
 use crate::configs::system::{
-    BackupConfig, CompatibilityConfig, CompressionConfig, EncryptionConfig, LoggingConfig,
-    MessageDeduplicationConfig, PartitionConfig, RecoveryConfig, RuntimeConfig, SegmentConfig,
-    StateConfig, StreamConfig, SystemConfig, TopicConfig,
+    AuthenticationConfig, BackupConfig, CompatibilityConfig, CompressionConfig, EncryptionConfig,
+    LoggingConfig, LoggingTopicConfig, LoginLockoutConfig, LoginRateLimitConfig,
+    MessageDeduplicationConfig, MetricsConfig, PartitionConfig, PasswordPolicyConfig,
+    ProtocolConfig, QuotaConfig, RecoveryConfig, RuntimeConfig, SegmentConfig, StateConfig,
+    StatsHistoryConfig, StatsHistorySeriesConfig, StreamConfig, SystemConfig, TopicConfig,
 };
 use crate::configs::tcp::{TcpConfig, TcpTlsConfig};
+use crate::configs::unix::{UnixConfig, UnixPeerCredentialsConfig};
 use iggy_common::IggyByteSize;
 use iggy_common::IggyDuration;
 use std::sync::Arc;
@@ -49,11 +56,17 @@ impl Default for ServerConfig {
         ServerConfig {
             data_maintenance: DataMaintenanceConfig::default(),
             heartbeat: HeartbeatConfig::default(),
+            client_eviction: ClientEvictionConfig::default(),
+            health: HealthConfig::default(),
+            events: EventsConfig::default(),
             message_saver: MessageSaverConfig::default(),
             personal_access_token: PersonalAccessTokenConfig::default(),
             system: Arc::new(SystemConfig::default()),
             quic: QuicConfig::default(),
             tcp: TcpConfig::default(),
+            unix: UnixConfig::default(),
+            kafka: KafkaConfig::default(),
+            mqtt: MqttConfig::default(),
             http: HttpConfig::default(),
             telemetry: TelemetryConfig::default(),
         }
@@ -149,6 +162,63 @@ impl Default for TcpConfig {
     }
 }
 
+impl Default for UnixConfig {
+    fn default() -> UnixConfig {
+        UnixConfig {
+            enabled: SERVER_CONFIG.unix.enabled,
+            path: SERVER_CONFIG.unix.path.parse().unwrap(),
+            permissions: SERVER_CONFIG.unix.permissions.parse().unwrap(),
+            peer_credentials: UnixPeerCredentialsConfig::default(),
+        }
+    }
+}
+
+impl Default for KafkaConfig {
+    fn default() -> KafkaConfig {
+        KafkaConfig {
+            enabled: SERVER_CONFIG.kafka.enabled,
+            address: SERVER_CONFIG.kafka.address.parse().unwrap(),
+            advertised_address: SERVER_CONFIG.kafka.advertised_address.parse().unwrap(),
+            default_stream: SERVER_CONFIG.kafka.default_stream.parse().unwrap(),
+            topic_separator: SERVER_CONFIG.kafka.topic_separator.parse().unwrap(),
+            max_request_size: SERVER_CONFIG.kafka.max_request_size.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for MqttConfig {
+    fn default() -> MqttConfig {
+        MqttConfig {
+            enabled: SERVER_CONFIG.mqtt.enabled,
+            address: SERVER_CONFIG.mqtt.address.parse().unwrap(),
+            max_packet_size: SERVER_CONFIG.mqtt.max_packet_size.parse().unwrap(),
+            poll_interval: SERVER_CONFIG.mqtt.poll_interval.parse().unwrap(),
+            rules: SERVER_CONFIG
+                .mqtt
+                .rules
+                .iter()
+                .map(|s| s.parse().unwrap())
+                .collect(),
+        }
+    }
+}
+
+impl Default for UnixPeerCredentialsConfig {
+    fn default() -> UnixPeerCredentialsConfig {
+        UnixPeerCredentialsConfig {
+            enabled: SERVER_CONFIG.unix.peer_credentials.enabled,
+            users: SERVER_CONFIG
+                .unix
+                .peer_credentials
+                .users
+                .iter()
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+                .collect(),
+        }
+    }
+}
+
 impl Default for TcpTlsConfig {
     fn default() -> TcpTlsConfig {
         TcpTlsConfig {
@@ -182,6 +252,7 @@ impl Default for HttpConfig {
             cors: HttpCorsConfig::default(),
             jwt: HttpJwtConfig::default(),
             metrics: HttpMetricsConfig::default(),
+            websocket: HttpWebSocketConfig::default(),
             tls: HttpTlsConfig::default(),
         }
     }
@@ -264,6 +335,15 @@ impl Default for HttpMetricsConfig {
     }
 }
 
+impl Default for HttpWebSocketConfig {
+    fn default() -> HttpWebSocketConfig {
+        HttpWebSocketConfig {
+            enabled: SERVER_CONFIG.http.websocket.enabled,
+            path: SERVER_CONFIG.http.websocket.path.parse().unwrap(),
+        }
+    }
+}
+
 impl Default for HttpTlsConfig {
     fn default() -> HttpTlsConfig {
         HttpTlsConfig {
@@ -324,6 +404,11 @@ impl Default for SystemConfig {
             message_deduplication: MessageDeduplicationConfig::default(),
             recovery: RecoveryConfig::default(),
             memory_pool: MemoryPoolConfig::default(),
+            authentication: AuthenticationConfig::default(),
+            quota: QuotaConfig::default(),
+            protocol: ProtocolConfig::default(),
+            metrics: MetricsConfig::default(),
+            stats_history: StatsHistoryConfig::default(),
         }
     }
 }
@@ -360,6 +445,90 @@ impl Default for HeartbeatConfig {
     }
 }
 
+impl Default for ClientEvictionConfig {
+    fn default() -> ClientEvictionConfig {
+        ClientEvictionConfig {
+            enabled: SERVER_CONFIG.client_eviction.enabled,
+            interval: SERVER_CONFIG.client_eviction.interval.parse().unwrap(),
+            idle: IdleClientsConfig::default(),
+            partial_frame: PartialFrameConfig::default(),
+            lagging_consumer: LaggingConsumerConfig::default(),
+        }
+    }
+}
+
+impl Default for IdleClientsConfig {
+    fn default() -> IdleClientsConfig {
+        IdleClientsConfig {
+            enabled: SERVER_CONFIG.client_eviction.idle.enabled,
+            timeout: SERVER_CONFIG.client_eviction.idle.timeout.parse().unwrap(),
+            action: SERVER_CONFIG.client_eviction.idle.action.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for PartialFrameConfig {
+    fn default() -> PartialFrameConfig {
+        PartialFrameConfig {
+            enabled: SERVER_CONFIG.client_eviction.partial_frame.enabled,
+            timeout: SERVER_CONFIG
+                .client_eviction
+                .partial_frame
+                .timeout
+                .parse()
+                .unwrap(),
+            action: SERVER_CONFIG
+                .client_eviction
+                .partial_frame
+                .action
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for LaggingConsumerConfig {
+    fn default() -> LaggingConsumerConfig {
+        LaggingConsumerConfig {
+            enabled: SERVER_CONFIG.client_eviction.lagging_consumer.enabled,
+            checks: SERVER_CONFIG.client_eviction.lagging_consumer.checks as u32,
+            min_lag: SERVER_CONFIG.client_eviction.lagging_consumer.min_lag as u64,
+            action: SERVER_CONFIG
+                .client_eviction
+                .lagging_consumer
+                .action
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for HealthConfig {
+    fn default() -> HealthConfig {
+        HealthConfig {
+            min_free_disk_space: SERVER_CONFIG.health.min_free_disk_space.parse().unwrap(),
+            max_memory_pool_usage: SERVER_CONFIG.health.max_memory_pool_usage as u8,
+            max_unsaved_messages_size: SERVER_CONFIG
+                .health
+                .max_unsaved_messages_size
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for EventsConfig {
+    fn default() -> EventsConfig {
+        EventsConfig {
+            enabled: SERVER_CONFIG.events.enabled,
+            stream: SERVER_CONFIG.events.stream.parse().unwrap(),
+            topic: SERVER_CONFIG.events.topic.parse().unwrap(),
+            format: SERVER_CONFIG.events.format.parse().unwrap(),
+            message_expiry: SERVER_CONFIG.events.message_expiry.parse().unwrap(),
+        }
+    }
+}
+
 impl Default for RuntimeConfig {
     fn default() -> RuntimeConfig {
         RuntimeConfig {
@@ -395,6 +564,26 @@ impl Default for LoggingConfig {
                 .sysinfo_print_interval
                 .parse()
                 .unwrap(),
+            topic: LoggingTopicConfig::default(),
+        }
+    }
+}
+
+impl Default for LoggingTopicConfig {
+    fn default() -> LoggingTopicConfig {
+        LoggingTopicConfig {
+            enabled: SERVER_CONFIG.system.logging.topic.enabled,
+            stream: SERVER_CONFIG.system.logging.topic.stream.parse().unwrap(),
+            topic: SERVER_CONFIG.system.logging.topic.topic.parse().unwrap(),
+            level: SERVER_CONFIG.system.logging.topic.level.parse().unwrap(),
+            buffer_size: SERVER_CONFIG.system.logging.topic.buffer_size as usize,
+            message_expiry: SERVER_CONFIG
+                .system
+                .logging
+                .topic
+                .message_expiry
+                .parse()
+                .unwrap(),
         }
     }
 }
@@ -505,6 +694,180 @@ impl Default for MemoryPoolConfig {
     }
 }
 
+impl Default for AuthenticationConfig {
+    fn default() -> AuthenticationConfig {
+        AuthenticationConfig {
+            rehash_on_login: SERVER_CONFIG.system.authentication.rehash_on_login,
+            lockout: LoginLockoutConfig::default(),
+            rate_limit: LoginRateLimitConfig::default(),
+            password_policy: PasswordPolicyConfig::default(),
+        }
+    }
+}
+
+impl Default for LoginLockoutConfig {
+    fn default() -> LoginLockoutConfig {
+        LoginLockoutConfig {
+            enabled: SERVER_CONFIG.system.authentication.lockout.enabled,
+            max_failed_attempts: SERVER_CONFIG
+                .system
+                .authentication
+                .lockout
+                .max_failed_attempts as u32,
+            window: SERVER_CONFIG
+                .system
+                .authentication
+                .lockout
+                .window
+                .parse()
+                .unwrap(),
+            duration: SERVER_CONFIG
+                .system
+                .authentication
+                .lockout
+                .duration
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for LoginRateLimitConfig {
+    fn default() -> LoginRateLimitConfig {
+        LoginRateLimitConfig {
+            enabled: SERVER_CONFIG.system.authentication.rate_limit.enabled,
+            max_attempts: SERVER_CONFIG.system.authentication.rate_limit.max_attempts as u32,
+            window: SERVER_CONFIG
+                .system
+                .authentication
+                .rate_limit
+                .window
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for QuotaConfig {
+    fn default() -> QuotaConfig {
+        QuotaConfig {
+            enabled: SERVER_CONFIG.system.quota.enabled,
+            max_throttle_time: SERVER_CONFIG
+                .system
+                .quota
+                .max_throttle_time
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for ProtocolConfig {
+    fn default() -> ProtocolConfig {
+        ProtocolConfig {
+            min_client_protocol_version: SERVER_CONFIG.system.protocol.min_client_protocol_version
+                as u32,
+            max_frame_size: SERVER_CONFIG
+                .system
+                .protocol
+                .max_frame_size
+                .parse()
+                .unwrap(),
+            frame_compression: SERVER_CONFIG.system.protocol.frame_compression,
+        }
+    }
+}
+
+impl Default for MetricsConfig {
+    fn default() -> MetricsConfig {
+        MetricsConfig {
+            granularity: SERVER_CONFIG.system.metrics.granularity.parse().unwrap(),
+            command_labels: SERVER_CONFIG.system.metrics.command_labels,
+        }
+    }
+}
+
+impl Default for StatsHistoryConfig {
+    fn default() -> StatsHistoryConfig {
+        StatsHistoryConfig {
+            enabled: SERVER_CONFIG.system.stats_history.enabled,
+            short_term: StatsHistorySeriesConfig {
+                interval: SERVER_CONFIG
+                    .system
+                    .stats_history
+                    .short_term
+                    .interval
+                    .parse()
+                    .unwrap(),
+                retention: SERVER_CONFIG
+                    .system
+                    .stats_history
+                    .short_term
+                    .retention
+                    .parse()
+                    .unwrap(),
+            },
+            long_term: StatsHistorySeriesConfig {
+                interval: SERVER_CONFIG
+                    .system
+                    .stats_history
+                    .long_term
+                    .interval
+                    .parse()
+                    .unwrap(),
+                retention: SERVER_CONFIG
+                    .system
+                    .stats_history
+                    .long_term
+                    .retention
+                    .parse()
+                    .unwrap(),
+            },
+        }
+    }
+}
+
+impl Default for PasswordPolicyConfig {
+    fn default() -> PasswordPolicyConfig {
+        PasswordPolicyConfig {
+            min_length: SERVER_CONFIG
+                .system
+                .authentication
+                .password_policy
+                .min_length as u32,
+            require_uppercase: SERVER_CONFIG
+                .system
+                .authentication
+                .password_policy
+                .require_uppercase,
+            require_lowercase: SERVER_CONFIG
+                .system
+                .authentication
+                .password_policy
+                .require_lowercase,
+            require_digit: SERVER_CONFIG
+                .system
+                .authentication
+                .password_policy
+                .require_digit,
+            require_special_character: SERVER_CONFIG
+                .system
+                .authentication
+                .password_policy
+                .require_special_character,
+            deny_list: SERVER_CONFIG
+                .system
+                .authentication
+                .password_policy
+                .deny_list
+                .iter()
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+                .collect(),
+        }
+    }
+}
+
 impl Default for TelemetryConfig {
     fn default() -> TelemetryConfig {
         TelemetryConfig {
@@ -512,6 +875,7 @@ impl Default for TelemetryConfig {
             service_name: SERVER_CONFIG.telemetry.service_name.parse().unwrap(),
             logs: TelemetryLogsConfig::default(),
             traces: TelemetryTracesConfig::default(),
+            metrics: TelemetryMetricsConfig::default(),
         }
     }
 }
@@ -533,3 +897,13 @@ impl Default for TelemetryTracesConfig {
         }
     }
 }
+
+impl Default for TelemetryMetricsConfig {
+    fn default() -> TelemetryMetricsConfig {
+        TelemetryMetricsConfig {
+            transport: SERVER_CONFIG.telemetry.metrics.transport.parse().unwrap(),
+            endpoint: SERVER_CONFIG.telemetry.metrics.endpoint.parse().unwrap(),
+            interval: SERVER_CONFIG.telemetry.metrics.interval.parse().unwrap(),
+        }
+    }
+}
@@ -18,19 +18,28 @@
 
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
-    ArchiverConfig, DataMaintenanceConfig, DiskArchiverConfig, HeartbeatConfig,
-    MessagesMaintenanceConfig, S3ArchiverConfig, StateMaintenanceConfig, TelemetryConfig,
-    TelemetryLogsConfig, TelemetryTracesConfig,
+    ArchiverConfig, ClientEvictionConfig, DataMaintenanceConfig, DiskArchiverConfig, EventsConfig,
+    HealthConfig, HeartbeatConfig, IdleClientsConfig, LaggingConsumerConfig,
+    MessagesMaintenanceConfig, PartialFrameConfig, S3ArchiverConfig, StateMaintenanceConfig,
+    TelemetryConfig, TelemetryLogsConfig, TelemetryMetricsConfig, TelemetryTracesConfig,
 };
 use crate::configs::system::MessageDeduplicationConfig;
 use crate::configs::{
-    http::{HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig},
+    http::{
+        HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig,
+        HttpWebSocketConfig,
+    },
+    kafka::KafkaConfig,
+    mqtt::MqttConfig,
     server::{MessageSaverConfig, ServerConfig},
     system::{
-        CompressionConfig, EncryptionConfig, LoggingConfig, PartitionConfig, SegmentConfig,
-        StateConfig, StreamConfig, SystemConfig, TopicConfig,
+        AuthenticationConfig, CompressionConfig, EncryptionConfig, LoggingConfig,
+        LoggingTopicConfig, MetricsConfig, PartitionConfig, ProtocolConfig, QuotaConfig,
+        SegmentConfig, StateConfig, StatsHistoryConfig, StatsHistorySeriesConfig, StreamConfig,
+        SystemConfig, TopicConfig,
     },
     tcp::{TcpConfig, TcpSocketConfig, TcpTlsConfig},
+    unix::{UnixConfig, UnixPeerCredentialsConfig},
 };
 use std::fmt::{Display, Formatter};
 
@@ -38,13 +47,14 @@ impl Display for HttpConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, address: {}, max_request_size: {}, cors: {}, jwt: {}, metrics: {}, tls: {} }}",
+            "{{ enabled: {}, address: {}, max_request_size: {}, cors: {}, jwt: {}, metrics: {}, websocket: {}, tls: {} }}",
             self.enabled,
             self.address,
             self.max_request_size,
             self.cors,
             self.jwt,
             self.metrics,
+            self.websocket,
             self.tls
         )
     }
@@ -86,6 +96,12 @@ impl Display for HttpMetricsConfig {
     }
 }
 
+impl Display for HttpWebSocketConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ enabled: {}, path: {} }}", self.enabled, self.path)
+    }
+}
+
 impl Display for HttpTlsConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -206,13 +222,19 @@ impl Display for ServerConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ data_maintenance: {}, message_saver: {}, heartbeat: {}, system: {}, quic: {}, tcp: {}, http: {}, telemetry: {} }}",
+            "{{ data_maintenance: {}, message_saver: {}, heartbeat: {}, client_eviction: {}, health: {}, events: {}, system: {}, quic: {}, tcp: {}, unix: {}, kafka: {}, mqtt: {}, http: {}, telemetry: {} }}",
             self.data_maintenance,
             self.message_saver,
             self.heartbeat,
+            self.client_eviction,
+            self.health,
+            self.events,
             self.system,
             self.quic,
             self.tcp,
+            self.unix,
+            self.kafka,
+            self.mqtt,
             self.http,
             self.telemetry
         )
@@ -239,12 +261,145 @@ impl Display for HeartbeatConfig {
     }
 }
 
+impl Display for HealthConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ min_free_disk_space: {}, max_memory_pool_usage: {}%, max_unsaved_messages_size: {} }}",
+            self.min_free_disk_space, self.max_memory_pool_usage, self.max_unsaved_messages_size
+        )
+    }
+}
+
+impl Display for ClientEvictionConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, interval: {}, idle: {}, partial_frame: {}, lagging_consumer: {} }}",
+            self.enabled, self.interval, self.idle, self.partial_frame, self.lagging_consumer
+        )
+    }
+}
+
+impl Display for IdleClientsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, timeout: {}, action: {} }}",
+            self.enabled, self.timeout, self.action
+        )
+    }
+}
+
+impl Display for PartialFrameConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, timeout: {}, action: {} }}",
+            self.enabled, self.timeout, self.action
+        )
+    }
+}
+
+impl Display for LaggingConsumerConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, checks: {}, min_lag: {}, action: {} }}",
+            self.enabled, self.checks, self.min_lag, self.action
+        )
+    }
+}
+
+impl Display for EventsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, stream: {}, topic: {}, format: {}, message_expiry: {} }}",
+            self.enabled, self.stream, self.topic, self.format, self.message_expiry
+        )
+    }
+}
+
 impl Display for EncryptionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{{ enabled: {} }}", self.enabled)
     }
 }
 
+impl Display for AuthenticationConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ rehash_on_login: {}, lockout: {{ enabled: {}, max_failed_attempts: {}, window: {}, duration: {} }}, rate_limit: {{ enabled: {}, max_attempts: {}, window: {} }}, password_policy: {{ min_length: {}, require_uppercase: {}, require_lowercase: {}, require_digit: {}, require_special_character: {}, deny_list_size: {} }} }}",
+            self.rehash_on_login,
+            self.lockout.enabled,
+            self.lockout.max_failed_attempts,
+            self.lockout.window,
+            self.lockout.duration,
+            self.rate_limit.enabled,
+            self.rate_limit.max_attempts,
+            self.rate_limit.window,
+            self.password_policy.min_length,
+            self.password_policy.require_uppercase,
+            self.password_policy.require_lowercase,
+            self.password_policy.require_digit,
+            self.password_policy.require_special_character,
+            self.password_policy.deny_list.len(),
+        )
+    }
+}
+
+impl Display for ProtocolConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ min_client_protocol_version: {}, max_frame_size: {}, frame_compression: {} }}",
+            self.min_client_protocol_version, self.max_frame_size, self.frame_compression
+        )
+    }
+}
+
+impl Display for MetricsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ granularity: {}, command_labels: {} }}",
+            self.granularity, self.command_labels
+        )
+    }
+}
+
+impl Display for StatsHistoryConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, short_term: {}, long_term: {} }}",
+            self.enabled, self.short_term, self.long_term
+        )
+    }
+}
+
+impl Display for StatsHistorySeriesConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ interval: {}, retention: {} }}",
+            self.interval, self.retention
+        )
+    }
+}
+
+impl Display for QuotaConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, max_throttle_time: {} }}",
+            self.enabled, self.max_throttle_time
+        )
+    }
+}
+
 impl Display for StreamConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{{ path: {} }}", self.path)
@@ -303,11 +458,27 @@ impl Display for LoggingConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ path: {}, level: {}, max_size: {}, retention: {} }}",
+            "{{ path: {}, level: {}, max_size: {}, retention: {}, topic: {} }}",
             self.path,
             self.level,
             self.max_size.as_human_string_with_zero_as_unlimited(),
-            self.retention
+            self.retention,
+            self.topic
+        )
+    }
+}
+
+impl Display for LoggingTopicConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, stream: {}, topic: {}, level: {}, buffer_size: {}, message_expiry: {} }}",
+            self.enabled,
+            self.stream,
+            self.topic,
+            self.level,
+            self.buffer_size,
+            self.message_expiry
         )
     }
 }
@@ -322,6 +493,52 @@ impl Display for TcpConfig {
     }
 }
 
+impl Display for UnixConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, path: {}, permissions: {}, peer_credentials: {} }}",
+            self.enabled, self.path, self.permissions, self.peer_credentials,
+        )
+    }
+}
+
+impl Display for KafkaConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, address: {}, advertised_address: {}, default_stream: {}, topic_separator: {}, max_request_size: {} }}",
+            self.enabled,
+            self.address,
+            self.advertised_address,
+            self.default_stream,
+            self.topic_separator,
+            self.max_request_size,
+        )
+    }
+}
+
+impl Display for MqttConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, address: {}, max_packet_size: {}, poll_interval: {}, rules: {:?} }}",
+            self.enabled, self.address, self.max_packet_size, self.poll_interval, self.rules,
+        )
+    }
+}
+
+impl Display for UnixPeerCredentialsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, users: {} }}",
+            self.enabled,
+            self.users.len()
+        )
+    }
+}
+
 impl Display for TcpTlsConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -351,8 +568,8 @@ impl Display for TelemetryConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, service_name: {}, logs: {}, traces: {} }}",
-            self.enabled, self.service_name, self.logs, self.traces
+            "{{ enabled: {}, service_name: {}, logs: {}, traces: {}, metrics: {} }}",
+            self.enabled, self.service_name, self.logs, self.traces, self.metrics
         )
     }
 }
@@ -391,7 +608,7 @@ impl Display for SystemConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ path: {}, logging: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {}, state: {} }}",
+            "{{ path: {}, logging: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {}, state: {}, authentication: {}, quota: {}, protocol: {}, metrics: {}, stats_history: {} }}",
             self.path,
             self.logging,
             self.stream,
@@ -400,6 +617,21 @@ impl Display for SystemConfig {
             self.segment,
             self.encryption,
             self.state,
+            self.authentication,
+            self.quota,
+            self.protocol,
+            self.metrics,
+            self.stats_history,
+        )
+    }
+}
+
+impl Display for TelemetryMetricsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ transport: {}, endpoint: {}, interval: {} }}",
+            self.transport, self.endpoint, self.interval
         )
     }
 }
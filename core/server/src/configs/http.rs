@@ -33,6 +33,7 @@ pub struct HttpConfig {
     pub cors: HttpCorsConfig,
     pub jwt: HttpJwtConfig,
     pub metrics: HttpMetricsConfig,
+    pub websocket: HttpWebSocketConfig,
     pub tls: HttpTlsConfig,
 }
 
@@ -72,6 +73,12 @@ pub struct HttpMetricsConfig {
     pub endpoint: String,
 }
 
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HttpWebSocketConfig {
+    pub enabled: bool,
+    pub path: String,
+}
+
 #[derive(Debug)]
 pub enum JwtSecret {
     Default(String),
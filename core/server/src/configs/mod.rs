@@ -21,10 +21,14 @@ pub mod config_provider;
 pub mod defaults;
 pub mod displays;
 pub mod http;
+pub mod kafka;
+pub mod mqtt;
 pub mod quic;
+pub mod reload;
 pub mod server;
 pub mod system;
 pub mod tcp;
+pub mod unix;
 pub mod validators;
 
 pub const COMPONENT: &str = "CONFIG";
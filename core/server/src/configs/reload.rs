@@ -0,0 +1,349 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::configs::COMPONENT;
+use crate::configs::config_provider::ConfigProviderKind;
+use crate::configs::server::ServerConfig;
+use crate::http::cors::configure_cors;
+use crate::streaming::utils::certificates::load_certificates;
+use error_set::ErrContext;
+use iggy_common::{ConfigReload, IggyError};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use tokio::sync::{Mutex, watch};
+use toml::{Table, Value};
+use tracing::{error, info, warn};
+
+/// The settings which are applied to the running server, either a single setting or a whole section.
+/// Any other changed setting is reported as requiring a restart.
+const LIVE_SETTINGS: [&str; 18] = [
+    "system.logging.level",
+    "system.quota",
+    "heartbeat.interval",
+    "client_eviction.interval",
+    "message_saver.interval",
+    "data_maintenance.messages.interval",
+    "data_maintenance.state.interval",
+    "personal_access_token.cleaner.interval",
+    "http.cors",
+    "http.jwt.valid_issuers",
+    "http.jwt.valid_audiences",
+    "http.jwt.clock_skew",
+    "http.tls.cert_file",
+    "http.tls.key_file",
+    "tcp.tls.cert_file",
+    "tcp.tls.key_file",
+    "quic.certificate.cert_file",
+    "quic.certificate.key_file",
+];
+
+/// Re-reads the server configuration on demand (SIGHUP or the `ReloadConfig` command)
+/// and publishes it to the components which apply the changed settings at runtime.
+pub struct ConfigReloader {
+    provider: ConfigProviderKind,
+    sender: watch::Sender<Arc<ServerConfig>>,
+    lock: Mutex<()>,
+}
+
+impl std::fmt::Debug for ConfigReloader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConfigReloader").finish_non_exhaustive()
+    }
+}
+
+impl ConfigReloader {
+    pub fn new(provider: ConfigProviderKind, config: ServerConfig) -> Self {
+        let (sender, _) = watch::channel(Arc::new(config));
+        Self {
+            provider,
+            sender,
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Returns the receiver notified each time the configuration is reloaded.
+    pub fn subscribe(&self) -> watch::Receiver<Arc<ServerConfig>> {
+        self.sender.subscribe()
+    }
+
+    pub fn current(&self) -> Arc<ServerConfig> {
+        self.sender.borrow().clone()
+    }
+
+    /// Loads and validates the configuration, then publishes it, unless it's invalid or
+    /// the TLS certificates can't be loaded, in which case the running configuration is kept.
+    /// The settings requiring a restart keep their running values in the published configuration,
+    /// so they are reported again on the next reload until the server is restarted.
+    /// The configuration is published even if unchanged, so that the certificates replaced
+    /// in place are reloaded as well.
+    pub async fn reload(&self) -> Result<(ConfigReload, Arc<ServerConfig>), IggyError> {
+        let _lock = self.lock.lock().await;
+        let config = ServerConfig::load(&self.provider)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to reload server config")
+            })
+            .map_err(|_| IggyError::InvalidConfiguration)?;
+        validate_reloadable_settings(&config)?;
+
+        let current = self.current();
+        let reload = diff(&current, &config);
+        for path in &reload.restart_required {
+            warn!("Changed setting: {path} requires a restart of the server to take effect.");
+        }
+        info!(
+            "Reloaded server config, applied settings: {:?}, settings requiring restart: {}.",
+            reload.applied,
+            reload.restart_required.len()
+        );
+
+        let config = Arc::new(keep_running_settings(&current, config)?);
+        self.sender.send_replace(config.clone());
+        Ok((reload, config))
+    }
+}
+
+fn validate_reloadable_settings(config: &ServerConfig) -> Result<(), IggyError> {
+    let mut certificates = Vec::new();
+    if config.http.enabled && config.http.tls.enabled {
+        certificates.push((&config.http.tls.cert_file, &config.http.tls.key_file));
+    }
+    if config.tcp.enabled
+        && config.tcp.tls.enabled
+        && (!config.tcp.tls.self_signed || std::path::Path::new(&config.tcp.tls.cert_file).exists())
+    {
+        certificates.push((&config.tcp.tls.cert_file, &config.tcp.tls.key_file));
+    }
+    if config.quic.enabled && !config.quic.certificate.self_signed {
+        certificates.push((
+            &config.quic.certificate.cert_file,
+            &config.quic.certificate.key_file,
+        ));
+    }
+    for (cert_file, key_file) in certificates {
+        load_certificates(cert_file, key_file).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to load reloaded certificate from cert_file: {cert_file}, key_file: {key_file}"
+            )
+        })?;
+    }
+
+    if config.http.enabled && config.http.cors.enabled {
+        // The layer is built only to validate the config, the HTTP server rebuilds its own.
+        configure_cors(&config.http.cors)
+            .map(|_| ())
+            .map_err(|error| {
+                error!("{COMPONENT} - invalid reloaded CORS config. {error}");
+                IggyError::InvalidConfiguration
+            })?;
+    }
+
+    if config.http.enabled {
+        config
+            .http
+            .jwt
+            .get_decoding_key()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get reloaded JWT decoding key")
+            })?;
+    }
+    Ok(())
+}
+
+/// Compares the configurations setting by setting and classifies the changed ones.
+fn diff(current: &ServerConfig, reloaded: &ServerConfig) -> ConfigReload {
+    let (current, reloaded) = match (Value::try_from(current), Value::try_from(reloaded)) {
+        (Ok(current), Ok(reloaded)) => (current, reloaded),
+        (Err(error), _) | (_, Err(error)) => {
+            error!("Failed to compare the reloaded server config. {error}");
+            return ConfigReload::default();
+        }
+    };
+
+    let mut changed = BTreeSet::new();
+    collect_changed_settings("", Some(&current), Some(&reloaded), &mut changed);
+    let (applied, restart_required) = changed.into_iter().partition(|path| is_live(path));
+    ConfigReload {
+        applied,
+        restart_required,
+    }
+}
+
+/// Returns the reloaded configuration with the settings requiring a restart reverted to their running values.
+fn keep_running_settings(
+    current: &ServerConfig,
+    reloaded: ServerConfig,
+) -> Result<ServerConfig, IggyError> {
+    let (current, mut published) = match (Value::try_from(current), Value::try_from(&reloaded)) {
+        (Ok(Value::Table(current)), Ok(Value::Table(published))) => (current, published),
+        (Err(error), _) | (_, Err(error)) => {
+            error!("{COMPONENT} - failed to compare the reloaded server config. {error}");
+            return Err(IggyError::InvalidConfiguration);
+        }
+        _ => return Ok(reloaded),
+    };
+    revert_restart_required_settings("", &current, &mut published);
+    Value::Table(published).try_into().map_err(|error| {
+        error!("{COMPONENT} - failed to build the published server config. {error}");
+        IggyError::InvalidConfiguration
+    })
+}
+
+fn revert_restart_required_settings(path: &str, current: &Table, published: &mut Table) {
+    let keys = current
+        .keys()
+        .chain(published.keys())
+        .cloned()
+        .collect::<BTreeSet<_>>();
+    for key in keys {
+        let path = match path.is_empty() {
+            true => key.clone(),
+            false => format!("{path}.{key}"),
+        };
+        match (current.get(&key), published.get_mut(&key)) {
+            (Some(Value::Table(current)), Some(Value::Table(published))) => {
+                revert_restart_required_settings(&path, current, published);
+            }
+            (current, published_value) if current != published_value.as_deref() => {
+                if is_live(&path) {
+                    continue;
+                }
+                match current {
+                    Some(current) => published.insert(key, current.clone()),
+                    None => published.remove(&key),
+                };
+            }
+            _ => {}
+        }
+    }
+}
+
+fn collect_changed_settings(
+    path: &str,
+    current: Option<&Value>,
+    reloaded: Option<&Value>,
+    changed: &mut BTreeSet<String>,
+) {
+    match (current, reloaded) {
+        (Some(Value::Table(current)), Some(Value::Table(reloaded))) => {
+            let keys = current
+                .keys()
+                .chain(reloaded.keys())
+                .collect::<BTreeSet<_>>();
+            for key in keys {
+                let path = match path.is_empty() {
+                    true => key.to_owned(),
+                    false => format!("{path}.{key}"),
+                };
+                collect_changed_settings(&path, current.get(key), reloaded.get(key), changed);
+            }
+        }
+        (current, reloaded) if current != reloaded => {
+            changed.insert(path.to_owned());
+        }
+        _ => {}
+    }
+}
+
+fn is_live(path: &str) -> bool {
+    LIVE_SETTINGS.iter().any(|setting| {
+        path == *setting
+            || path
+                .strip_prefix(setting)
+                .is_some_and(|rest| rest.starts_with('.'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::config_provider::FileConfigProvider;
+    use iggy_common::IggyDuration;
+    use std::str::FromStr;
+
+    fn config() -> ServerConfig {
+        toml::from_str(include_str!("../../../configs/server.toml")).unwrap()
+    }
+
+    #[test]
+    fn should_report_no_changes_for_identical_configs() {
+        assert!(diff(&config(), &config()).is_unchanged());
+    }
+
+    #[test]
+    fn should_classify_changed_settings() {
+        let current = config();
+        let mut reloaded = config();
+        reloaded.heartbeat.interval = IggyDuration::from_str("1s").unwrap();
+        reloaded.http.cors.allowed_origins = vec!["https://iggy.apache.org".to_owned()];
+        reloaded.tcp.address = "127.0.0.1:9999".to_owned();
+
+        let reload = diff(&current, &reloaded);
+
+        assert_eq!(
+            reload.applied,
+            vec!["heartbeat.interval", "http.cors.allowed_origins"]
+        );
+        assert_eq!(reload.restart_required, vec!["tcp.address"]);
+    }
+
+    #[test]
+    fn should_keep_running_values_of_settings_requiring_restart() {
+        let running = config();
+        let mut reloaded = config();
+        reloaded.heartbeat.interval = IggyDuration::from_str("1s").unwrap();
+        reloaded.tcp.address = "127.0.0.1:9999".to_owned();
+
+        let published = keep_running_settings(&running, reloaded).unwrap();
+
+        assert_eq!(published.heartbeat.interval.to_string(), "1s");
+        assert_eq!(published.tcp.address, running.tcp.address);
+    }
+
+    #[tokio::test]
+    async fn should_report_pending_restart_on_every_reload() {
+        let path =
+            std::env::temp_dir().join(format!("iggy_reload_test_{}.toml", std::process::id()));
+        let contents = include_str!("../../../configs/server.toml");
+        std::fs::write(&path, contents).unwrap();
+        let provider =
+            ConfigProviderKind::File(FileConfigProvider::new(path.to_string_lossy().into_owned()));
+        let running = ServerConfig::load(&provider).await.unwrap();
+        let reloader = ConfigReloader::new(provider, running.clone());
+
+        let mut changed: ServerConfig = toml::from_str(contents).unwrap();
+        changed.tcp.address = "127.0.0.1:9999".to_owned();
+        std::fs::write(&path, toml::to_string(&changed).unwrap()).unwrap();
+
+        for _ in 0..2 {
+            let (reload, published) = reloader.reload().await.unwrap();
+            assert_eq!(reload.restart_required, vec!["tcp.address"]);
+            assert_eq!(published.tcp.address, running.tcp.address);
+            assert_eq!(reloader.current().tcp.address, running.tcp.address);
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn should_not_treat_setting_with_common_prefix_as_live() {
+        assert!(is_live("system.quota.enabled"));
+        assert!(!is_live("http.jwt.decoding_secret"));
+        assert!(is_live("tcp.tls.cert_file"));
+        assert!(!is_live("tcp.tls.cert_file_backup"));
+        assert!(!is_live("system.logging.path"));
+    }
+}
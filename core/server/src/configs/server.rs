@@ -20,13 +20,19 @@ use crate::archiver::ArchiverKindType;
 use crate::configs::COMPONENT;
 use crate::configs::config_provider::ConfigProviderKind;
 use crate::configs::http::HttpConfig;
+use crate::configs::kafka::KafkaConfig;
+use crate::configs::mqtt::MqttConfig;
 use crate::configs::quic::QuicConfig;
 use crate::configs::system::SystemConfig;
 use crate::configs::tcp::TcpConfig;
+use crate::configs::unix::UnixConfig;
 use crate::server_error::ConfigError;
 use derive_more::Display;
 use error_set::ErrContext;
+use iggy_common::IggyByteSize;
 use iggy_common::IggyDuration;
+use iggy_common::IggyExpiry;
+use iggy_common::SystemEventFormat;
 use iggy_common::Validatable;
 use serde::{Deserialize, Serialize};
 use serde_with::DisplayFromStr;
@@ -40,9 +46,15 @@ pub struct ServerConfig {
     pub message_saver: MessageSaverConfig,
     pub personal_access_token: PersonalAccessTokenConfig,
     pub heartbeat: HeartbeatConfig,
+    pub client_eviction: ClientEvictionConfig,
+    pub health: HealthConfig,
+    pub events: EventsConfig,
     pub system: Arc<SystemConfig>,
     pub quic: QuicConfig,
     pub tcp: TcpConfig,
+    pub unix: UnixConfig,
+    pub kafka: KafkaConfig,
+    pub mqtt: MqttConfig,
     pub http: HttpConfig,
     pub telemetry: TelemetryConfig,
 }
@@ -127,12 +139,102 @@ pub struct HeartbeatConfig {
     pub interval: IggyDuration,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ClientEvictionConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+    pub idle: IdleClientsConfig,
+    pub partial_frame: PartialFrameConfig,
+    pub lagging_consumer: LaggingConsumerConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct IdleClientsConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub timeout: IggyDuration,
+    pub action: ClientEvictionAction,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PartialFrameConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub timeout: IggyDuration,
+    pub action: ClientEvictionAction,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LaggingConsumerConfig {
+    pub enabled: bool,
+    pub checks: u32,
+    pub min_lag: u64,
+    pub action: ClientEvictionAction,
+}
+
+/// The action taken on the client detected by the eviction policy, which is always logged and counted.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Display, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientEvictionAction {
+    #[display("none")]
+    None,
+    #[display("disconnect")]
+    Disconnect,
+    #[display("leave_consumer_group")]
+    LeaveConsumerGroup,
+}
+
+impl ClientEvictionAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClientEvictionAction::None => "none",
+            ClientEvictionAction::Disconnect => "disconnect",
+            ClientEvictionAction::LeaveConsumerGroup => "leave_consumer_group",
+        }
+    }
+}
+
+impl FromStr for ClientEvictionAction {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(ClientEvictionAction::None),
+            "disconnect" => Ok(ClientEvictionAction::Disconnect),
+            "leave_consumer_group" => Ok(ClientEvictionAction::LeaveConsumerGroup),
+            _ => Err(format!("Invalid client eviction action: {s}")),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HealthConfig {
+    pub min_free_disk_space: IggyByteSize,
+    pub max_memory_pool_usage: u8,
+    pub max_unsaved_messages_size: IggyByteSize,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct EventsConfig {
+    pub enabled: bool,
+    pub stream: String,
+    pub topic: String,
+    pub format: SystemEventFormat,
+    #[serde_as(as = "DisplayFromStr")]
+    pub message_expiry: IggyExpiry,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct TelemetryConfig {
     pub enabled: bool,
     pub service_name: String,
     pub logs: TelemetryLogsConfig,
     pub traces: TelemetryTracesConfig,
+    pub metrics: TelemetryMetricsConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -147,6 +249,15 @@ pub struct TelemetryTracesConfig {
     pub endpoint: String,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TelemetryMetricsConfig {
+    pub transport: TelemetryTransport,
+    pub endpoint: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Display, Copy, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum TelemetryTransport {
@@ -17,6 +17,7 @@
  */
 
 use super::cache_indexes::CacheIndexesConfig;
+use derive_more::Display;
 use iggy_common::Confirmation;
 use iggy_common::IggyByteSize;
 use iggy_common::IggyExpiry;
@@ -25,6 +26,7 @@ use iggy_common::{CompressionAlgorithm, IggyDuration};
 use serde::{Deserialize, Serialize};
 use serde_with::DisplayFromStr;
 use serde_with::serde_as;
+use std::str::FromStr;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SystemConfig {
@@ -42,6 +44,11 @@ pub struct SystemConfig {
     pub message_deduplication: MessageDeduplicationConfig,
     pub recovery: RecoveryConfig,
     pub memory_pool: MemoryPoolConfig,
+    pub authentication: AuthenticationConfig,
+    pub quota: QuotaConfig,
+    pub protocol: ProtocolConfig,
+    pub metrics: MetricsConfig,
+    pub stats_history: StatsHistoryConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -81,6 +88,19 @@ pub struct LoggingConfig {
     pub retention: IggyDuration,
     #[serde_as(as = "DisplayFromStr")]
     pub sysinfo_print_interval: IggyDuration,
+    pub topic: LoggingTopicConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LoggingTopicConfig {
+    pub enabled: bool,
+    pub stream: String,
+    pub topic: String,
+    pub level: String,
+    pub buffer_size: usize,
+    #[serde_as(as = "DisplayFromStr")]
+    pub message_expiry: IggyExpiry,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -133,6 +153,112 @@ pub struct MemoryPoolConfig {
     pub bucket_capacity: u32,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AuthenticationConfig {
+    pub rehash_on_login: bool,
+    pub lockout: LoginLockoutConfig,
+    pub rate_limit: LoginRateLimitConfig,
+    pub password_policy: PasswordPolicyConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LoginLockoutConfig {
+    pub enabled: bool,
+    pub max_failed_attempts: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub window: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub duration: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LoginRateLimitConfig {
+    pub enabled: bool,
+    pub max_attempts: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub window: IggyDuration,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PasswordPolicyConfig {
+    pub min_length: u32,
+    pub require_uppercase: bool,
+    pub require_lowercase: bool,
+    pub require_digit: bool,
+    pub require_special_character: bool,
+    pub deny_list: Vec<String>,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct QuotaConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_throttle_time: IggyDuration,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProtocolConfig {
+    pub min_client_protocol_version: u32,
+    pub max_frame_size: IggyByteSize,
+    pub frame_compression: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MetricsConfig {
+    pub granularity: MetricsGranularity,
+    pub command_labels: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StatsHistoryConfig {
+    pub enabled: bool,
+    pub short_term: StatsHistorySeriesConfig,
+    pub long_term: StatsHistorySeriesConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct StatsHistorySeriesConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub retention: IggyDuration,
+}
+
+/// The level of the stream, topic and partition labels attached to the storage metrics,
+/// ordered from the coarsest to the finest one.
+#[derive(
+    Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Display, Copy, Clone, Default,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricsGranularity {
+    #[display("none")]
+    None,
+    #[display("stream")]
+    Stream,
+    #[display("topic")]
+    Topic,
+    #[default]
+    #[display("partition")]
+    Partition,
+}
+
+impl FromStr for MetricsGranularity {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(MetricsGranularity::None),
+            "stream" => Ok(MetricsGranularity::Stream),
+            "topic" => Ok(MetricsGranularity::Topic),
+            "partition" => Ok(MetricsGranularity::Partition),
+            _ => Err(format!("Invalid metrics granularity: {s}")),
+        }
+    }
+}
+
 #[serde_as]
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SegmentConfig {
@@ -174,6 +300,10 @@ impl SystemConfig {
         format!("{}/tokens", self.get_state_path())
     }
 
+    pub fn get_state_revoked_users_path(&self) -> String {
+        format!("{}/revoked_users", self.get_state_path())
+    }
+
     pub fn get_backup_path(&self) -> String {
         format!("{}/{}", self.get_system_path(), self.backup.path)
     }
@@ -0,0 +1,53 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UnixConfig {
+    pub enabled: bool,
+    pub path: String,
+    pub permissions: String,
+    pub peer_credentials: UnixPeerCredentialsConfig,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UnixPeerCredentialsConfig {
+    pub enabled: bool,
+    pub users: Vec<String>,
+}
+
+impl UnixConfig {
+    /// Returns the file mode of the socket, configured as an octal number, e.g. "660".
+    pub fn get_permissions_mode(&self) -> Option<u32> {
+        u32::from_str_radix(self.permissions.trim_start_matches("0o"), 8)
+            .ok()
+            .filter(|mode| *mode <= 0o777)
+    }
+}
+
+impl UnixPeerCredentialsConfig {
+    /// Returns the username mapped to the given user ID (uid) of the peer process.
+    /// The mappings are configured in the `uid:username` format, e.g. "1000:iggy".
+    pub fn get_username(&self, uid: u32) -> Option<&str> {
+        self.users.iter().find_map(|user| {
+            let (user_uid, username) = user.split_once(':')?;
+            (user_uid.parse::<u32>().ok()? == uid).then_some(username)
+        })
+    }
+}
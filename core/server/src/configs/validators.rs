@@ -19,22 +19,41 @@
 extern crate sysinfo;
 
 use super::server::{
-    ArchiverConfig, DataMaintenanceConfig, MessageSaverConfig, MessagesMaintenanceConfig,
+    ArchiverConfig, ClientEvictionAction, ClientEvictionConfig, DataMaintenanceConfig,
+    EventsConfig, HealthConfig, MessageSaverConfig, MessagesMaintenanceConfig,
     StateMaintenanceConfig, TelemetryConfig,
 };
-use super::system::{CompressionConfig, MemoryPoolConfig, PartitionConfig};
+use super::system::{
+    AuthenticationConfig, CompressionConfig, LoggingTopicConfig, MemoryPoolConfig, PartitionConfig,
+    ProtocolConfig, QuotaConfig, StatsHistoryConfig,
+};
 use crate::archiver::ArchiverKindType;
 use crate::configs::COMPONENT;
+use crate::configs::http::HttpWebSocketConfig;
+use crate::configs::kafka::KafkaConfig;
+use crate::configs::mqtt::MqttConfig;
 use crate::configs::server::{PersonalAccessTokenConfig, ServerConfig};
 use crate::configs::system::SegmentConfig;
+use crate::configs::unix::UnixConfig;
+use crate::mqtt::codec::MAX_VARINT;
+use crate::mqtt::mapping::TopicMapping;
 use crate::server_error::ConfigError;
 use crate::streaming::segments::*;
 use error_set::ErrContext;
 use iggy_common::CompressionAlgorithm;
+use iggy_common::IggyByteSize;
 use iggy_common::IggyExpiry;
 use iggy_common::MaxTopicSize;
+use iggy_common::PROTOCOL_VERSION;
 use iggy_common::Validatable;
-use tracing::error;
+use iggy_common::create_stream::CreateStream;
+use iggy_common::create_topic::CreateTopic;
+use iggy_common::defaults::{MAX_PASSWORD_LENGTH, MIN_PASSWORD_LENGTH};
+use tracing::{error, warn};
+use tracing_subscriber::filter::LevelFilter;
+
+// Each sample takes around a hundred bytes (plus the throughput of the active topics).
+const MAX_STATS_HISTORY_SAMPLES: u64 = 100_000;
 
 impl Validatable<ConfigError> for ServerConfig {
     fn validate(&self) -> Result<(), ConfigError> {
@@ -68,6 +87,57 @@ impl Validatable<ConfigError> for ServerConfig {
         self.telemetry.validate().with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to validate telemetry config")
         })?;
+        self.http.websocket.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate websocket config")
+        })?;
+        self.unix.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate unix config")
+        })?;
+        self.kafka.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate kafka config")
+        })?;
+        self.mqtt.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate mqtt config")
+        })?;
+        self.system
+            .authentication
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate authentication config")
+            })?;
+        self.system.quota.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate quota config")
+        })?;
+        self.system
+            .protocol
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate protocol config")
+            })?;
+        self.health.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate health config")
+        })?;
+        self.events.validate().with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to validate events config")
+        })?;
+        self.client_eviction
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate client eviction config")
+            })?;
+        self.system
+            .logging
+            .topic
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate logging topic config")
+            })?;
+        self.system
+            .stats_history
+            .validate()
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to validate stats history config")
+            })?;
 
         let topic_size = match self.system.topic.max_size {
             MaxTopicSize::Custom(size) => Ok(size.as_bytes_u64()),
@@ -125,6 +195,10 @@ impl Validatable<ConfigError> for TelemetryConfig {
             return Err(ConfigError::InvalidConfiguration);
         }
 
+        if self.metrics.endpoint.is_empty() || self.metrics.interval.is_zero() {
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
         Ok(())
     }
 }
@@ -352,3 +426,404 @@ impl Validatable<ConfigError> for MemoryPoolConfig {
         Ok(())
     }
 }
+
+impl Validatable<ConfigError> for AuthenticationConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.lockout.enabled
+            && (self.lockout.max_failed_attempts == 0
+                || self.lockout.window.is_zero()
+                || self.lockout.duration.is_zero())
+        {
+            error!(
+                "Configured system.authentication.lockout requires non-zero max_failed_attempts, window and duration"
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.rate_limit.enabled
+            && (self.rate_limit.max_attempts == 0 || self.rate_limit.window.is_zero())
+        {
+            error!(
+                "Configured system.authentication.rate_limit requires non-zero max_attempts and window"
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.lockout.enabled && !self.rate_limit.enabled {
+            warn!(
+                "Configured system.authentication.lockout is enabled without the rate_limit, any client can keep the accounts locked"
+            );
+        }
+
+        let min_length = self.password_policy.min_length as usize;
+        if !(MIN_PASSWORD_LENGTH..=MAX_PASSWORD_LENGTH).contains(&min_length) {
+            error!(
+                "Configured system.authentication.password_policy.min_length {min_length} must be between {MIN_PASSWORD_LENGTH} and {MAX_PASSWORD_LENGTH}"
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for QuotaConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.enabled && self.max_throttle_time.is_zero() {
+            error!("Configured system.quota requires non-zero max_throttle_time");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for HealthConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.max_memory_pool_usage == 0 || self.max_memory_pool_usage > 100 {
+            error!(
+                "Configured health.max_memory_pool_usage: {} must be between 1 and 100",
+                self.max_memory_pool_usage
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for ClientEvictionConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.interval.as_micros() == 0 {
+            error!("Configured client_eviction.interval cannot be 0");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        for (name, enabled, timeout, action) in [
+            (
+                "idle",
+                self.idle.enabled,
+                self.idle.timeout,
+                self.idle.action,
+            ),
+            (
+                "partial_frame",
+                self.partial_frame.enabled,
+                self.partial_frame.timeout,
+                self.partial_frame.action,
+            ),
+        ] {
+            if !enabled {
+                continue;
+            }
+
+            if timeout.as_micros() == 0 {
+                error!("Configured client_eviction.{name}.timeout cannot be 0");
+                return Err(ConfigError::InvalidConfiguration);
+            }
+
+            // Only the lagging consumer is bound to the consumer group it could leave.
+            if action == ClientEvictionAction::LeaveConsumerGroup {
+                error!(
+                    "Configured client_eviction.{name}.action: {action} is supported only by the lagging consumer"
+                );
+                return Err(ConfigError::InvalidConfiguration);
+            }
+        }
+
+        if self.lagging_consumer.enabled && self.lagging_consumer.checks == 0 {
+            error!("Configured client_eviction.lagging_consumer.checks cannot be 0");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for EventsConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let create_stream = CreateStream {
+            stream_id: None,
+            name: self.stream.clone(),
+        };
+        if let Err(error) = create_stream.validate() {
+            error!(
+                "Configured events.stream: {} is invalid. {error}",
+                self.stream
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        let create_topic = CreateTopic {
+            topic_id: None,
+            name: self.topic.clone(),
+            ..Default::default()
+        };
+        if let Err(error) = create_topic.validate() {
+            error!(
+                "Configured events.topic: {} is invalid. {error}",
+                self.topic
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if let IggyExpiry::ServerDefault = self.message_expiry {
+            error!("Configured events.message_expiry cannot be the server default");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for LoggingTopicConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let create_stream = CreateStream {
+            stream_id: None,
+            name: self.stream.clone(),
+        };
+        if let Err(error) = create_stream.validate() {
+            error!(
+                "Configured system.logging.topic.stream: {} is invalid. {error}",
+                self.stream
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        let create_topic = CreateTopic {
+            topic_id: None,
+            name: self.topic.clone(),
+            ..Default::default()
+        };
+        if let Err(error) = create_topic.validate() {
+            error!(
+                "Configured system.logging.topic.topic: {} is invalid. {error}",
+                self.topic
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.level.parse::<LevelFilter>().is_err() {
+            error!(
+                "Configured system.logging.topic.level: {} is invalid",
+                self.level
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.buffer_size == 0 {
+            error!("Configured system.logging.topic.buffer_size cannot be 0");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if let IggyExpiry::ServerDefault = self.message_expiry {
+            error!("Configured system.logging.topic.message_expiry cannot be the server default");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for StatsHistoryConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        for (name, series) in [
+            ("short_term", &self.short_term),
+            ("long_term", &self.long_term),
+        ] {
+            if series.interval.as_micros() == 0 {
+                error!("Configured system.stats_history.{name}.interval cannot be 0");
+                return Err(ConfigError::InvalidConfiguration);
+            }
+
+            if series.retention.as_micros() < series.interval.as_micros() {
+                error!(
+                    "Configured system.stats_history.{name}.retention: {} cannot be lower than the interval: {}",
+                    series.retention, series.interval
+                );
+                return Err(ConfigError::InvalidConfiguration);
+            }
+
+            let samples_count = series.retention.as_micros() / series.interval.as_micros();
+            if samples_count > MAX_STATS_HISTORY_SAMPLES {
+                error!(
+                    "Configured system.stats_history.{name} would keep {samples_count} samples, which is more than the maximum: {MAX_STATS_HISTORY_SAMPLES}"
+                );
+                return Err(ConfigError::InvalidConfiguration);
+            }
+        }
+
+        if !self
+            .long_term
+            .interval
+            .as_micros()
+            .is_multiple_of(self.short_term.interval.as_micros())
+        {
+            error!(
+                "Configured system.stats_history.long_term.interval: {} must be a multiple of the short_term.interval: {}",
+                self.long_term.interval, self.short_term.interval
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for ProtocolConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.min_client_protocol_version > PROTOCOL_VERSION {
+            error!(
+                "Configured system.protocol.min_client_protocol_version: {} is greater than the server protocol version: {PROTOCOL_VERSION}",
+                self.min_client_protocol_version
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.max_frame_size.as_bytes_u64() > u32::MAX as u64 {
+            error!(
+                "Configured system.protocol.max_frame_size: {} cannot be greater than: {}",
+                self.max_frame_size,
+                IggyByteSize::from(u32::MAX as u64)
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for HttpWebSocketConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if !self.path.starts_with('/') {
+            error!(
+                "Configured http.websocket.path: {} must start with '/'",
+                self.path
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for UnixConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.path.is_empty() {
+            error!("Configured unix.path cannot be empty");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.get_permissions_mode().is_none() {
+            error!(
+                "Configured unix.permissions: {} is not a valid octal file mode, e.g. \"660\"",
+                self.permissions
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        // An empty entry is a placeholder for no mappings, as TOML arrays cannot be overridden with an empty value.
+        for user in self
+            .peer_credentials
+            .users
+            .iter()
+            .filter(|user| !user.is_empty())
+        {
+            let is_valid = user
+                .split_once(':')
+                .is_some_and(|(uid, username)| uid.parse::<u32>().is_ok() && !username.is_empty());
+            if !is_valid {
+                error!(
+                    "Configured unix.peer_credentials.users entry: {user} must be in the uid:username format"
+                );
+                return Err(ConfigError::InvalidConfiguration);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for KafkaConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.default_stream.is_empty() {
+            error!("Configured kafka.default_stream cannot be empty");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.topic_separator.is_empty() {
+            error!("Configured kafka.topic_separator cannot be empty");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.max_request_size.as_bytes_u64() == 0 {
+            error!("Configured kafka.max_request_size cannot be zero");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ConfigError> for MqttConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.max_packet_size.as_bytes_u64() == 0
+            || self.max_packet_size.as_bytes_u64() > MAX_VARINT as u64
+        {
+            error!(
+                "Configured mqtt.max_packet_size must be between 1 B and {}",
+                IggyByteSize::from(MAX_VARINT as u64)
+            );
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.poll_interval.get_duration().is_zero() {
+            error!("Configured mqtt.poll_interval cannot be zero");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if self.rules.iter().all(|rule| rule.is_empty()) {
+            error!("Configured mqtt.rules cannot be empty");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        if let Err(reason) = TopicMapping::parse(&self.rules) {
+            error!("Configured mqtt.rules are invalid: {reason}");
+            return Err(ConfigError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
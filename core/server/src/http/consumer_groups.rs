@@ -20,14 +20,14 @@ use crate::http::COMPONENT;
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
+use crate::http::openapi::TopicPath;
 use crate::http::shared::AppState;
 use crate::state::command::EntryCommand;
 use crate::state::models::CreateConsumerGroupWithId;
 use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::routing::get;
-use axum::{Extension, Json, Router};
+use axum::{Extension, Json};
 use error_set::ErrContext;
 use iggy_common::Identifier;
 use iggy_common::Validatable;
@@ -36,20 +36,25 @@ use iggy_common::delete_consumer_group::DeleteConsumerGroup;
 use iggy_common::{ConsumerGroup, ConsumerGroupDetails};
 use std::sync::Arc;
 use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
 
-pub fn router(state: Arc<AppState>) -> Router {
-    Router::new()
-        .route(
-            "/streams/{stream_id}/topics/{topic_id}/consumer-groups",
-            get(get_consumer_groups).post(create_consumer_group),
-        )
-        .route(
-            "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}",
-            get(get_consumer_group).delete(delete_consumer_group),
-        )
-        .with_state(state)
+pub fn router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_consumer_groups, create_consumer_group))
+        .routes(routes!(get_consumer_group, delete_consumer_group))
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}",
+    tag = "Consumer Groups",
+    params(
+        TopicPath,
+        ("group_id" = String, Path, description = "The numeric ID or the name of the consumer group."),
+    ),
+    responses((status = 200, description = "The consumer group details.", body = ConsumerGroupDetails))
+)]
 async fn get_consumer_group(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -76,6 +81,13 @@ async fn get_consumer_group(
     Ok(Json(consumer_group))
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups",
+    tag = "Consumer Groups",
+    params(TopicPath),
+    responses((status = 200, description = "The consumer groups of the topic.", body = Vec<ConsumerGroup>))
+)]
 async fn get_consumer_groups(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -93,6 +105,13 @@ async fn get_consumer_groups(
     Ok(Json(consumer_groups))
 }
 
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups",
+    tag = "Consumer Groups",
+    params(TopicPath),
+    responses((status = 201, description = "The created consumer group.", body = ConsumerGroupDetails))
+)]
 #[instrument(skip_all, name = "trace_create_consumer_group", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn create_consumer_group(
     State(state): State<Arc<AppState>>,
@@ -131,6 +150,16 @@ async fn create_consumer_group(
     Ok((StatusCode::CREATED, Json(consumer_group_details)))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-groups/{group_id}",
+    tag = "Consumer Groups",
+    params(
+        TopicPath,
+        ("group_id" = String, Path, description = "The numeric ID or the name of the consumer group."),
+    ),
+    responses((status = 204, description = "The consumer group has been deleted."))
+)]
 #[instrument(skip_all, name = "trace_delete_consumer_group", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id, iggy_group_id = group_id))]
 async fn delete_consumer_group(
     State(state): State<Arc<AppState>>,
@@ -19,12 +19,12 @@
 use crate::http::COMPONENT;
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
+use crate::http::openapi::TopicPath;
 use crate::http::shared::AppState;
 use crate::streaming::session::Session;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::{delete, get};
-use axum::{Extension, Json, Router};
+use axum::{Extension, Json};
 use error_set::ErrContext;
 use iggy_common::Consumer;
 use iggy_common::ConsumerOffsetInfo;
@@ -34,20 +34,26 @@ use iggy_common::delete_consumer_offset::DeleteConsumerOffset;
 use iggy_common::get_consumer_offset::GetConsumerOffset;
 use iggy_common::store_consumer_offset::StoreConsumerOffset;
 use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
 
-pub fn router(state: Arc<AppState>) -> Router {
-    Router::new()
-        .route(
-            "/streams/{stream_id}/topics/{topic_id}/consumer-offsets",
-            get(get_consumer_offset).put(store_consumer_offset),
-        )
-        .route(
-            "/streams/{stream_id}/topics/{topic_id}/consumer-offsets/{consumer_id}",
-            delete(delete_consumer_offset),
-        )
-        .with_state(state)
+pub fn router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_consumer_offset, store_consumer_offset))
+        .routes(routes!(delete_consumer_offset))
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-offsets",
+    tag = "Consumer Offsets",
+    params(
+        TopicPath,
+        ("id" = String, Query, description = "The numeric ID or the name of the consumer."),
+        ("partition_id" = Option<u32>, Query, description = "The partition ID, required for the consumer."),
+    ),
+    responses((status = 200, description = "The consumer offset.", body = ConsumerOffsetInfo))
+)]
 async fn get_consumer_offset(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -79,6 +85,13 @@ async fn get_consumer_offset(
     Ok(Json(offset))
 }
 
+#[utoipa::path(
+    put,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-offsets",
+    tag = "Consumer Offsets",
+    params(TopicPath),
+    responses((status = 204, description = "The consumer offset has been stored."))
+)]
 async fn store_consumer_offset(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -104,6 +117,17 @@ async fn store_consumer_offset(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}/consumer-offsets/{consumer_id}",
+    tag = "Consumer Offsets",
+    params(
+        TopicPath,
+        ("consumer_id" = String, Path, description = "The numeric ID or the name of the consumer."),
+        ("partition_id" = Option<u32>, Query, description = "The partition ID, required for the consumer."),
+    ),
+    responses((status = 204, description = "The consumer offset has been deleted."))
+)]
 async fn delete_consumer_offset(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -0,0 +1,137 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::configs::http::HttpCorsConfig;
+use crate::configs::server::ServerConfig;
+use axum::extract::{Request, State};
+use axum::http::{HeaderName, Method};
+use axum::middleware::Next;
+use axum::response::Response;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, RwLock};
+use tokio::sync::watch;
+use tower::{Layer, ServiceExt};
+use tower_http::cors::{AllowOrigin, CorsLayer};
+use tracing::{error, info};
+
+/// The CORS layer applied to the requests, replaced when the configuration is reloaded.
+/// `None` means that CORS is disabled.
+#[derive(Debug, Clone, Default)]
+pub struct ReloadableCors {
+    layer: Arc<RwLock<Option<CorsLayer>>>,
+}
+
+impl ReloadableCors {
+    pub fn new(config: &HttpCorsConfig) -> Self {
+        let cors = Self::default();
+        cors.configure(config)
+            .unwrap_or_else(|error| panic!("Invalid CORS configuration: {error}"));
+        cors
+    }
+
+    /// Replaces the CORS layer whenever the configuration is reloaded.
+    pub fn start_reloader(&self, mut config_changes: watch::Receiver<Arc<ServerConfig>>) {
+        let cors = self.clone();
+        tokio::spawn(async move {
+            while config_changes.changed().await.is_ok() {
+                let config = config_changes.borrow_and_update().http.cors.clone();
+                match cors.configure(&config) {
+                    Ok(()) => info!(
+                        "Reloaded CORS configuration, CORS is enabled: {}.",
+                        config.enabled
+                    ),
+                    Err(error) => error!("Failed to reload CORS configuration. {error}"),
+                }
+            }
+        });
+    }
+
+    fn configure(&self, config: &HttpCorsConfig) -> Result<(), String> {
+        let layer = match config.enabled {
+            true => Some(configure_cors(config)?),
+            false => None,
+        };
+        *self.layer.write().unwrap() = layer;
+        Ok(())
+    }
+}
+
+pub async fn cors(State(cors): State<ReloadableCors>, request: Request, next: Next) -> Response {
+    let layer = cors.layer.read().unwrap().clone();
+    match layer {
+        Some(layer) => match layer.layer(next).oneshot(request).await {
+            Ok(response) => response,
+            Err(error) => match error {},
+        },
+        None => next.run(request).await,
+    }
+}
+
+pub fn configure_cors(config: &HttpCorsConfig) -> Result<CorsLayer, String> {
+    let allowed_origins = match &config.allowed_origins {
+        origins if origins.is_empty() => AllowOrigin::default(),
+        origins if origins.first().unwrap() == "*" => AllowOrigin::any(),
+        origins => AllowOrigin::list(
+            origins
+                .iter()
+                .map(|s| s.parse().map_err(|_| format!("Invalid origin: {s}")))
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+    };
+
+    let allowed_headers = parse_headers(&config.allowed_headers)?;
+    let exposed_headers = parse_headers(&config.exposed_headers)?;
+    let allowed_methods = config
+        .allowed_methods
+        .iter()
+        .filter(|s| !s.is_empty())
+        .map(|s| match s.to_uppercase().as_str() {
+            "GET" => Ok(Method::GET),
+            "POST" => Ok(Method::POST),
+            "PUT" => Ok(Method::PUT),
+            "DELETE" => Ok(Method::DELETE),
+            "HEAD" => Ok(Method::HEAD),
+            "OPTIONS" => Ok(Method::OPTIONS),
+            "CONNECT" => Ok(Method::CONNECT),
+            "PATCH" => Ok(Method::PATCH),
+            "TRACE" => Ok(Method::TRACE),
+            _ => Err(format!("Invalid HTTP method: {s}")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let layer = CorsLayer::new()
+        .allow_methods(allowed_methods)
+        .allow_origin(allowed_origins)
+        .allow_headers(allowed_headers)
+        .expose_headers(exposed_headers)
+        .allow_credentials(config.allow_credentials)
+        .allow_private_network(config.allow_private_network);
+
+    // The conflicting rules (e.g. credentials allowed for any origin) are detected only when the layer is applied.
+    if std::panic::catch_unwind(AssertUnwindSafe(|| layer.layer(()))).is_err() {
+        return Err("Conflicting CORS rules".to_owned());
+    }
+    Ok(layer)
+}
+
+fn parse_headers(headers: &[String]) -> Result<Vec<HeaderName>, String> {
+    headers
+        .iter()
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse().map_err(|_| format!("Invalid header: {s}")))
+        .collect()
+}
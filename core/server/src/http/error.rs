@@ -23,6 +23,7 @@ use iggy_common::IggyError;
 use serde::Serialize;
 use thiserror::Error;
 use tracing::error;
+use utoipa::ToSchema;
 
 #[derive(Debug, Error)]
 pub enum CustomError {
@@ -32,7 +33,8 @@ pub enum CustomError {
     ResourceNotFound,
 }
 
-#[derive(Debug, Serialize)]
+/// The body of the failed request.
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ErrorResponse {
     pub id: u32,
     pub code: String,
@@ -61,6 +63,8 @@ impl IntoResponse for CustomError {
                     IggyError::InvalidAccessToken => StatusCode::UNAUTHORIZED,
                     IggyError::InvalidPersonalAccessToken => StatusCode::UNAUTHORIZED,
                     IggyError::Unauthorized => StatusCode::FORBIDDEN,
+                    IggyError::TooManyLoginAttempts => StatusCode::TOO_MANY_REQUESTS,
+                    IggyError::ProfilerAlreadyRunning => StatusCode::CONFLICT,
                     _ => StatusCode::BAD_REQUEST,
                 };
                 (status_code, Json(ErrorResponse::from_error(error)))
@@ -106,6 +110,7 @@ impl ErrorResponse {
                 }
                 IggyError::ConsumerGroupNameAlreadyExists(_, _) => Some("name".to_string()),
                 IggyError::UserAlreadyExists => Some("username".to_string()),
+                IggyError::PasswordPolicyViolation(_) => Some("password".to_string()),
                 IggyError::PersonalAccessTokenAlreadyExists(_, _) => Some("name".to_string()),
                 _ => None,
             },
@@ -0,0 +1,69 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::http::shared::AppState;
+use axum::Json;
+use axum::extract::State;
+use axum::http::StatusCode;
+use iggy_common::{Health, HealthProbe};
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+/// The paths of the health probes, served without the authentication and the system lock.
+pub const HEALTH_PATHS: &[&str] = &["/health/live", "/health/ready"];
+
+pub fn router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_liveness))
+        .routes(routes!(get_readiness))
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "Health",
+    security(()),
+    responses((status = 200, description = "The server is alive.", body = Health))
+)]
+async fn get_liveness(State(state): State<Arc<AppState>>) -> (StatusCode, Json<Health>) {
+    get_health(&state, HealthProbe::Liveness).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "Health",
+    security(()),
+    responses(
+        (status = 200, description = "The server is ready to serve the traffic.", body = Health),
+        (status = 503, description = "The server is not ready, e.g. it's still loading the data.", body = Health)
+    )
+)]
+async fn get_readiness(State(state): State<Arc<AppState>>) -> (StatusCode, Json<Health>) {
+    get_health(&state, HealthProbe::Readiness).await
+}
+
+async fn get_health(state: &AppState, probe: HealthProbe) -> (StatusCode, Json<Health>) {
+    let health = state.system.get_health(probe).await;
+    let status = match health.is_up() {
+        true => StatusCode::OK,
+        false => StatusCode::SERVICE_UNAVAILABLE,
+    };
+    (status, Json(health))
+}
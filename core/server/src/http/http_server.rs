@@ -16,7 +16,9 @@
  * under the License.
  */
 
-use crate::configs::http::{HttpConfig, HttpCorsConfig};
+use crate::configs::http::HttpConfig;
+use crate::configs::server::ServerConfig;
+use crate::http::cors::ReloadableCors;
 use crate::http::diagnostics::request_diagnostics;
 use crate::http::jwt::cleaner::start_expired_tokens_cleaner;
 use crate::http::jwt::jwt_manager::JwtManager;
@@ -26,13 +28,12 @@ use crate::http::shared::AppState;
 use crate::http::*;
 use crate::streaming::systems::system::SharedSystem;
 use axum::extract::DefaultBodyLimit;
-use axum::http::Method;
-use axum::{Router, middleware};
+use axum::middleware;
 use axum_server::tls_rustls::RustlsConfig;
 use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
-use tower_http::cors::{AllowOrigin, CorsLayer};
+use tokio::sync::watch;
 use tracing::{error, info};
 
 /// Starts the HTTP API server.
@@ -45,24 +46,30 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
     };
 
     let app_state = build_app_state(&config, system).await;
-    let mut app = Router::new()
-        .merge(system::router(app_state.clone(), &config.metrics))
-        .merge(personal_access_tokens::router(app_state.clone()))
-        .merge(users::router(app_state.clone()))
-        .merge(streams::router(app_state.clone()))
-        .merge(topics::router(app_state.clone()))
-        .merge(consumer_groups::router(app_state.clone()))
-        .merge(consumer_offsets::router(app_state.clone()))
-        .merge(partitions::router(app_state.clone()))
-        .merge(messages::router(app_state.clone()))
+    let mut app = openapi::router(&config.metrics)
+        .with_state(app_state.clone())
         .layer(DefaultBodyLimit::max(
             config.max_request_size.as_bytes_u64() as usize,
         ))
         .layer(middleware::from_fn_with_state(app_state.clone(), jwt_auth));
 
-    if config.cors.enabled {
-        app = app.layer(configure_cors(config.cors));
+    if config.websocket.enabled {
+        app = app.merge(websocket::router(
+            app_state.clone(),
+            &config.websocket,
+            config.max_request_size.as_bytes_u64() as usize,
+        ));
+        info!(
+            "WebSocket endpoint is available on path: {}",
+            config.websocket.path
+        );
+    }
+
+    let cors = ReloadableCors::new(&config.cors);
+    if let Some(config_changes) = app_state.system.read().await.subscribe_config_changes() {
+        cors.start_reloader(config_changes);
     }
+    app = app.layer(middleware::from_fn_with_state(cors, cors::cors));
 
     if config.metrics.enabled {
         app = app.layer(middleware::from_fn_with_state(app_state.clone(), metrics));
@@ -98,6 +105,9 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         )
         .await
         .unwrap();
+        if let Some(config_changes) = app_state.system.read().await.subscribe_config_changes() {
+            start_certificate_reloader(tls_config.clone(), config_changes);
+        }
 
         let listener = std::net::TcpListener::bind(config.address).unwrap();
         let address = listener
@@ -121,73 +131,54 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
 
 async fn build_app_state(config: &HttpConfig, system: SharedSystem) -> Arc<AppState> {
     let tokens_path;
+    let revoked_users_path;
     let persister;
     {
         let system = system.read().await;
         tokens_path = system.config.get_state_tokens_path();
+        revoked_users_path = system.config.get_state_revoked_users_path();
         persister = system.storage.persister.clone();
     }
 
-    let jwt_manager = JwtManager::from_config(persister, &tokens_path, &config.jwt);
+    let jwt_manager =
+        JwtManager::from_config(persister, &tokens_path, &revoked_users_path, &config.jwt);
     if let Err(error) = jwt_manager {
         panic!("Failed to initialize JWT manager: {error}");
     }
 
-    let jwt_manager = jwt_manager.unwrap();
+    let jwt_manager = Arc::new(jwt_manager.unwrap());
     if jwt_manager.load_revoked_tokens().await.is_err() {
         panic!("Failed to load revoked access tokens");
     }
 
+    // Revoking the user sessions via the binary protocol invalidates the issued access tokens as well.
+    system.write().await.jwt_manager = Some(jwt_manager.clone());
+
     Arc::new(AppState {
         jwt_manager,
         system,
     })
 }
 
-fn configure_cors(config: HttpCorsConfig) -> CorsLayer {
-    let allowed_origins = match config.allowed_origins {
-        origins if origins.is_empty() => AllowOrigin::default(),
-        origins if origins.first().unwrap() == "*" => AllowOrigin::any(),
-        origins => AllowOrigin::list(origins.iter().map(|s| s.parse().unwrap())),
-    };
-
-    let allowed_headers = config
-        .allowed_headers
-        .iter()
-        .filter(|s| !s.is_empty())
-        .map(|s| s.parse().unwrap())
-        .collect::<Vec<_>>();
-
-    let exposed_headers = config
-        .exposed_headers
-        .iter()
-        .filter(|s| !s.is_empty())
-        .map(|s| s.parse().unwrap())
-        .collect::<Vec<_>>();
-
-    let allowed_methods = config
-        .allowed_methods
-        .iter()
-        .filter(|s| !s.is_empty())
-        .map(|s| match s.to_uppercase().as_str() {
-            "GET" => Method::GET,
-            "POST" => Method::POST,
-            "PUT" => Method::PUT,
-            "DELETE" => Method::DELETE,
-            "HEAD" => Method::HEAD,
-            "OPTIONS" => Method::OPTIONS,
-            "CONNECT" => Method::CONNECT,
-            "PATCH" => Method::PATCH,
-            "TRACE" => Method::TRACE,
-            _ => panic!("Invalid HTTP method: {s}"),
-        })
-        .collect::<Vec<_>>();
-
-    CorsLayer::new()
-        .allow_methods(allowed_methods)
-        .allow_origin(allowed_origins)
-        .allow_headers(allowed_headers)
-        .expose_headers(exposed_headers)
-        .allow_credentials(config.allow_credentials)
-        .allow_private_network(config.allow_private_network)
+/// Reloads the certificate each time the server configuration is reloaded,
+/// the files are re-read even if their paths didn't change.
+fn start_certificate_reloader(
+    tls_config: RustlsConfig,
+    mut config_changes: watch::Receiver<Arc<ServerConfig>>,
+) {
+    tokio::spawn(async move {
+        while config_changes.changed().await.is_ok() {
+            let tls = config_changes.borrow_and_update().http.tls.clone();
+            match tls_config
+                .reload_from_pem_file(PathBuf::from(&tls.cert_file), PathBuf::from(&tls.key_file))
+                .await
+            {
+                Ok(()) => info!(
+                    "Reloaded HTTP API certificate from cert_file: {}, key_file: {}",
+                    tls.cert_file, tls.key_file
+                ),
+                Err(error) => error!("Failed to reload HTTP API certificate. {error}"),
+            }
+        }
+    });
 }
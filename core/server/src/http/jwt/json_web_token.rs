@@ -24,6 +24,8 @@ use std::net::SocketAddr;
 pub struct Identity {
     pub token_id: String,
     pub token_expiry: u64,
+    /// The issue time of the token in microseconds, compared with the revocation of the user tokens.
+    pub token_issued_at: u64,
     pub user_id: UserId,
     pub ip_address: SocketAddr,
 }
@@ -37,6 +39,21 @@ pub struct JwtClaims {
     pub iat: u64,
     pub exp: u64,
     pub nbf: u64,
+    /// The issue time in microseconds, which tells apart the tokens issued within the same second
+    /// as the revocation of the user tokens, as the registered claims have a precision of seconds.
+    #[serde(default)]
+    pub iat_micros: u64,
+}
+
+impl JwtClaims {
+    /// Returns the issue time in microseconds, the tokens lacking the precise issue time
+    /// are compared with the precision of seconds.
+    pub fn issued_at_micros(&self) -> u64 {
+        match self.iat_micros {
+            0 => self.iat * 1_000_000,
+            iat_micros => iat_micros,
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
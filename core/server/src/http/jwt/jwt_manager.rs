@@ -18,10 +18,11 @@
 
 use crate::configs::http::HttpJwtConfig;
 use crate::http::jwt::COMPONENT;
-use crate::http::jwt::json_web_token::{GeneratedToken, JwtClaims, RevokedAccessToken};
+use crate::http::jwt::json_web_token::{GeneratedToken, Identity, JwtClaims, RevokedAccessToken};
 use crate::http::jwt::storage::TokenStorage;
 use crate::streaming::persistence::persister::PersisterKind;
 use ahash::AHashMap;
+use dashmap::DashMap;
 use error_set::ErrContext;
 use iggy_common::IggyDuration;
 use iggy_common::IggyError;
@@ -31,7 +32,7 @@ use iggy_common::UserId;
 use iggy_common::locking::IggySharedMut;
 use iggy_common::locking::IggySharedMutFn;
 use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, TokenData, Validation, encode};
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use tracing::{debug, error, info};
 
 pub struct IssuerOptions {
@@ -52,38 +53,64 @@ pub struct ValidatorOptions {
 
 pub struct JwtManager {
     issuer: IssuerOptions,
-    validator: ValidatorOptions,
+    validator: RwLock<TokenValidator>,
     tokens_storage: TokenStorage,
     revoked_tokens: IggySharedMut<AHashMap<String, u64>>,
+    /// The time of the revocation of all the user tokens, in microseconds.
+    revoked_user_tokens: DashMap<UserId, u64>,
+}
+
+/// The decoding key along with the validations, swapped as a whole when the configuration is reloaded.
+struct TokenValidator {
+    key: DecodingKey,
     validations: AHashMap<Algorithm, Validation>,
 }
 
+impl TokenValidator {
+    fn new(algorithm: Algorithm, options: ValidatorOptions) -> Self {
+        let validation = JwtManager::create_validation(
+            algorithm,
+            &options.valid_issuers,
+            &options.valid_audiences,
+            options.clock_skew,
+        );
+        Self {
+            key: options.key,
+            validations: vec![(algorithm, validation)].into_iter().collect(),
+        }
+    }
+}
+
+impl std::fmt::Debug for JwtManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtManager")
+            .field("issuer", &self.issuer.issuer)
+            .field("tokens_storage", &self.tokens_storage)
+            .finish_non_exhaustive()
+    }
+}
+
 impl JwtManager {
     pub fn new(
         persister: Arc<PersisterKind>,
         path: &str,
+        revoked_users_path: &str,
         issuer: IssuerOptions,
         validator: ValidatorOptions,
     ) -> Result<Self, IggyError> {
-        let validation = JwtManager::create_validation(
-            issuer.algorithm,
-            &validator.valid_issuers,
-            &validator.valid_audiences,
-            validator.clock_skew,
-        );
-
         Ok(Self {
-            validations: vec![(issuer.algorithm, validation)].into_iter().collect(),
+            validator: RwLock::new(TokenValidator::new(issuer.algorithm, validator)),
             issuer,
-            validator,
-            tokens_storage: TokenStorage::new(persister, path),
+            tokens_storage: TokenStorage::new(persister, path, revoked_users_path),
             revoked_tokens: IggySharedMut::new(AHashMap::new()),
+            revoked_user_tokens: DashMap::new(),
         })
     }
 
     pub fn from_config(
         persister: Arc<PersisterKind>,
         path: &str,
+        revoked_users_path: &str,
         config: &HttpJwtConfig,
     ) -> Result<Self, IggyError> {
         let algorithm = config.get_algorithm()?;
@@ -97,15 +124,31 @@ impl JwtManager {
             })?,
             algorithm,
         };
-        let validator = ValidatorOptions {
+        let validator = Self::create_validator_options(config)?;
+        JwtManager::new(persister, path, revoked_users_path, issuer, validator)
+    }
+
+    /// Replaces the valid issuers and audiences and the clock skew used to validate the access tokens.
+    /// The keys and the issuer options require a restart, as the decoding key must match the encoding one.
+    pub fn reload_validator(&self, config: &HttpJwtConfig) -> Result<(), IggyError> {
+        let validator = TokenValidator::new(
+            self.issuer.algorithm,
+            Self::create_validator_options(config)?,
+        );
+        *self.validator.write().unwrap() = validator;
+        info!("Reloaded JWT validator.");
+        Ok(())
+    }
+
+    fn create_validator_options(config: &HttpJwtConfig) -> Result<ValidatorOptions, IggyError> {
+        Ok(ValidatorOptions {
             valid_audiences: config.valid_audiences.clone(),
             valid_issuers: config.valid_issuers.clone(),
             clock_skew: config.clock_skew,
             key: config.get_decoding_key().with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to get decoding key")
             })?,
-        };
-        JwtManager::new(persister, path, issuer, validator)
+        })
     }
 
     fn create_validation(
@@ -127,6 +170,12 @@ impl JwtManager {
         for token in revoked_tokens {
             tokens.insert(token.id, token.expiry);
         }
+        drop(tokens);
+
+        let revoked_user_tokens = self.tokens_storage.load_all_revoked_user_tokens().await?;
+        for (user_id, revoked_at) in revoked_user_tokens {
+            self.revoked_user_tokens.insert(user_id, revoked_at);
+        }
         Ok(())
     }
 
@@ -140,6 +189,14 @@ impl JwtManager {
         }
         drop(revoked_tokens);
 
+        self.delete_expired_revoked_user_tokens(now)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to delete expired revoked user tokens"
+                )
+            })?;
+
         debug!(
             "Found {} expired revoked access tokens to delete.",
             tokens_to_delete.len()
@@ -168,16 +225,42 @@ impl JwtManager {
         Ok(())
     }
 
+    // Once every token issued before the revocation has expired, the entry is no longer needed.
+    async fn delete_expired_revoked_user_tokens(&self, now: u64) -> Result<(), IggyError> {
+        let max_expiry = self.get_access_token_expiry_secs();
+        let users_to_delete = self
+            .revoked_user_tokens
+            .iter()
+            .filter(|entry| *entry.value() / 1_000_000 + max_expiry <= now)
+            .map(|entry| *entry.key())
+            .collect::<Vec<_>>();
+        if users_to_delete.is_empty() {
+            return Ok(());
+        }
+
+        self.tokens_storage
+            .delete_revoked_user_tokens(&users_to_delete)
+            .await?;
+        for user_id in users_to_delete {
+            self.revoked_user_tokens.remove(&user_id);
+            info!("Deleted expired revoked access tokens entry for user with ID: {user_id}")
+        }
+        Ok(())
+    }
+
+    fn get_access_token_expiry_secs(&self) -> u64 {
+        (match self.issuer.access_token_expiry {
+            IggyExpiry::NeverExpire => 1_000_000_000,
+            IggyExpiry::ServerDefault => 0, // This is not a case, as the server default is not allowed here
+            IggyExpiry::ExpireDuration(duration) => duration.as_secs(),
+        }) as u64
+    }
+
     pub fn generate(&self, user_id: UserId) -> Result<GeneratedToken, IggyError> {
         let header = Header::new(self.issuer.algorithm);
-        let now = IggyTimestamp::now().to_secs();
-        let iat = now;
-        let exp = iat
-            + (match self.issuer.access_token_expiry {
-                IggyExpiry::NeverExpire => 1_000_000_000,
-                IggyExpiry::ServerDefault => 0, // This is not a case, as the server default is not allowed here
-                IggyExpiry::ExpireDuration(duration) => duration.as_secs(),
-            }) as u64;
+        let now = IggyTimestamp::now();
+        let iat = now.to_secs();
+        let exp = iat + self.get_access_token_expiry_secs();
         let nbf = iat + self.issuer.not_before.as_secs() as u64;
         let claims = JwtClaims {
             jti: uuid::Uuid::now_v7().to_string(),
@@ -187,6 +270,7 @@ impl JwtManager {
             iat,
             exp,
             nbf,
+            iat_micros: now.as_micros(),
         };
 
         let access_token = encode::<JwtClaims>(&header, &claims, &self.issuer.key);
@@ -211,6 +295,13 @@ impl JwtManager {
         let token_header =
             jsonwebtoken::decode_header(token).map_err(|_| IggyError::InvalidAccessToken)?;
         let jwt_claims = self.decode(token, token_header.alg)?;
+        if self.is_issued_before_revocation(
+            jwt_claims.claims.sub,
+            jwt_claims.claims.issued_at_micros(),
+        ) {
+            return Err(IggyError::InvalidAccessToken);
+        }
+
         let id = jwt_claims.claims.jti;
         let expiry = jwt_claims.claims.exp;
         if self
@@ -240,7 +331,8 @@ impl JwtManager {
         token: &str,
         algorithm: Algorithm,
     ) -> Result<TokenData<JwtClaims>, IggyError> {
-        let validation = self.validations.get(&algorithm);
+        let validator = self.validator.read().unwrap();
+        let validation = validator.validations.get(&algorithm);
         if validation.is_none() {
             return Err(IggyError::InvalidJwtAlgorithm(
                 Self::map_algorithm_to_string(algorithm),
@@ -248,7 +340,7 @@ impl JwtManager {
         }
 
         let validation = validation.unwrap();
-        match jsonwebtoken::decode::<JwtClaims>(token, &self.validator.key, validation) {
+        match jsonwebtoken::decode::<JwtClaims>(token, &validator.key, validation) {
             Ok(claims) => Ok(claims),
             _ => Err(IggyError::Unauthenticated),
         }
@@ -285,8 +377,90 @@ impl JwtManager {
         Ok(())
     }
 
-    pub async fn is_token_revoked(&self, token_id: &str) -> bool {
+    /// Invalidates all the access tokens issued to the user so far.
+    pub async fn revoke_user_tokens(&self, user_id: UserId) -> Result<(), IggyError> {
+        let revoked_at = IggyTimestamp::now().as_micros();
+        self.revoked_user_tokens.insert(user_id, revoked_at);
+        self.tokens_storage
+            .save_revoked_user_tokens(user_id, revoked_at)
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to save revoked access tokens for user with ID: {user_id}"
+                )
+            })?;
+        info!("Revoked all access tokens issued to user with ID: {user_id}");
+        Ok(())
+    }
+
+    pub async fn is_token_revoked(&self, claims: &JwtClaims) -> bool {
+        self.is_revoked(&claims.jti, claims.sub, claims.issued_at_micros())
+            .await
+    }
+
+    /// Checks if the token of the already authenticated request has been revoked since,
+    /// e.g. to end the long-lived responses.
+    pub async fn is_identity_revoked(&self, identity: &Identity) -> bool {
+        self.is_revoked(
+            &identity.token_id,
+            identity.user_id,
+            identity.token_issued_at,
+        )
+        .await
+    }
+
+    async fn is_revoked(&self, token_id: &str, user_id: UserId, issued_at: u64) -> bool {
         let revoked_tokens = self.revoked_tokens.read().await;
-        revoked_tokens.contains_key(token_id)
+        if revoked_tokens.contains_key(token_id) {
+            return true;
+        }
+        drop(revoked_tokens);
+
+        self.is_issued_before_revocation(user_id, issued_at)
+    }
+
+    fn is_issued_before_revocation(&self, user_id: UserId, issued_at: u64) -> bool {
+        self.revoked_user_tokens
+            .get(&user_id)
+            .is_some_and(|revoked_at| issued_at <= *revoked_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::persistence::persister::FilePersister;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn token_issued_right_after_revocation_should_be_valid_and_not_issued_in_future() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("revoked_access_tokens");
+        let users_path = temp_dir.path().join("revoked_user_tokens");
+        let jwt_manager = JwtManager::from_config(
+            Arc::new(PersisterKind::File(FilePersister)),
+            path.to_str().unwrap(),
+            users_path.to_str().unwrap(),
+            &HttpJwtConfig::default(),
+        )
+        .unwrap();
+        let user_id = 1;
+        let revoked_token = jwt_manager.generate(user_id).unwrap();
+
+        jwt_manager.revoke_user_tokens(user_id).await.unwrap();
+        let token = jwt_manager.generate(user_id).unwrap();
+
+        let algorithm = jwt_manager.issuer.algorithm;
+        let revoked_claims = jwt_manager
+            .decode(&revoked_token.access_token, algorithm)
+            .unwrap()
+            .claims;
+        let claims = jwt_manager
+            .decode(&token.access_token, algorithm)
+            .unwrap()
+            .claims;
+        assert!(jwt_manager.is_token_revoked(&revoked_claims).await);
+        assert!(!jwt_manager.is_token_revoked(&claims).await);
+        assert!(claims.iat <= IggyTimestamp::now().to_secs());
     }
 }
@@ -36,7 +36,10 @@ const UNAUTHORIZED: StatusCode = StatusCode::UNAUTHORIZED;
 const PUBLIC_PATHS: &[&str] = &[
     "/",
     "/metrics",
+    "/openapi.json",
     "/ping",
+    "/health/live",
+    "/health/ready",
     "/stats",
     "/users/login",
     "/users/refresh-token",
@@ -82,16 +85,13 @@ pub async fn jwt_auth(
             format!("{COMPONENT} (error: {error}) - failed to decode JWT with provided algorithm")
         })
         .map_err(|_| UNAUTHORIZED)?;
-    if state
-        .jwt_manager
-        .is_token_revoked(&jwt_claims.claims.jti)
-        .await
-    {
+    if state.jwt_manager.is_token_revoked(&jwt_claims.claims).await {
         return Err(StatusCode::UNAUTHORIZED);
     }
 
     let request_details = request.extensions().get::<RequestDetails>().unwrap();
     let identity = Identity {
+        token_issued_at: jwt_claims.claims.issued_at_micros(),
         token_id: jwt_claims.claims.jti,
         token_expiry: jwt_claims.claims.exp,
         user_id: jwt_claims.claims.sub,
@@ -25,67 +25,40 @@ use crate::{
 use ahash::AHashMap;
 use anyhow::Context;
 use error_set::ErrContext;
-use iggy_common::IggyError;
+use iggy_common::{IggyError, UserId};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::hash::Hash;
 use std::sync::Arc;
 use tokio::io::AsyncReadExt;
-use tracing::{error, info};
+use tracing::info;
 
 #[derive(Debug)]
 pub struct TokenStorage {
     persister: Arc<PersisterKind>,
     path: String,
+    revoked_users_path: String,
 }
 
 impl TokenStorage {
-    pub fn new(persister: Arc<PersisterKind>, path: &str) -> Self {
+    pub fn new(persister: Arc<PersisterKind>, path: &str, revoked_users_path: &str) -> Self {
         Self {
             persister,
             path: path.to_owned(),
+            revoked_users_path: revoked_users_path.to_owned(),
         }
     }
 
     pub async fn load_all_revoked_access_tokens(
         &self,
     ) -> Result<Vec<RevokedAccessToken>, IggyError> {
-        let file = file::open(&self.path).await;
-        if file.is_err() {
+        let Some(tokens) = self
+            .load_map::<String, u64>(&self.path, "revoked access tokens")
+            .await?
+        else {
             info!("No revoked access tokens found to load.");
             return Ok(vec![]);
-        }
-
-        info!("Loading revoked access tokens from: {}", self.path);
-        let mut file = file.map_err(|error| {
-            error!("Cannot open revoked access tokens file: {error}");
-            IggyError::CannotReadFile
-        })?;
-        let file_size = file
-            .metadata()
-            .await
-            .with_error_context(|error| {
-                format!(
-                    "{COMPONENT} (error: {error}) - failed to read file metadata, path: {}",
-                    self.path
-                )
-            })
-            .map_err(|_| IggyError::CannotReadFileMetadata)?
-            .len() as usize;
-        let mut buffer = PooledBuffer::with_capacity(file_size);
-        buffer.put_bytes(0, file_size);
-        file.read_exact(&mut buffer)
-            .await
-            .with_error_context(|error| {
-                format!(
-                    "{COMPONENT} (error: {error}) - failed to read file into buffer, path: {}",
-                    self.path
-                )
-            })
-            .map_err(|_| IggyError::CannotReadFile)?;
-
-        let tokens: AHashMap<String, u64> =
-            bincode::serde::decode_from_slice(&buffer, bincode::config::standard())
-                .with_context(|| "Failed to deserialize revoked access tokens")
-                .map_err(|_| IggyError::CannotDeserializeResource)?
-                .0;
+        };
 
         let tokens = tokens
             .into_iter()
@@ -106,19 +79,8 @@ impl TokenStorage {
             .map(|token| (token.id, token.expiry))
             .collect::<AHashMap<_, _>>();
         map.insert(token.id.to_owned(), token.expiry);
-        let bytes = bincode::serde::encode_to_vec(&map, bincode::config::standard())
-            .with_context(|| "Failed to serialize revoked access tokens")
-            .map_err(|_| IggyError::CannotSerializeResource)?;
-        self.persister
-            .overwrite(&self.path, &bytes)
+        self.save_map(&self.path, &map, "revoked access tokens")
             .await
-            .with_error_context(|error| {
-                format!(
-                    "{COMPONENT} (error: {error}) - failed to overwrite file, path: {}",
-                    self.path
-                )
-            })?;
-        Ok(())
     }
 
     pub async fn delete_revoked_access_tokens(&self, id: &[String]) -> Result<(), IggyError> {
@@ -140,17 +102,116 @@ impl TokenStorage {
             map.remove(id);
         }
 
-        let bytes = bincode::serde::encode_to_vec(&map, bincode::config::standard())
-            .with_context(|| "Failed to serialize revoked access tokens")
-            .map_err(|_| IggyError::CannotSerializeResource)?;
-        self.persister
-            .overwrite(&self.path, &bytes)
+        self.save_map(&self.path, &map, "revoked access tokens")
+            .await
+    }
+
+    /// Loads the timestamps (in seconds) before which all the access tokens issued to the given users are revoked.
+    pub async fn load_all_revoked_user_tokens(&self) -> Result<AHashMap<UserId, u64>, IggyError> {
+        let Some(users) = self
+            .load_map::<UserId, u64>(&self.revoked_users_path, "revoked user access tokens")
+            .await?
+        else {
+            info!("No revoked user access tokens found to load.");
+            return Ok(AHashMap::new());
+        };
+
+        info!("Loaded revoked access tokens for {} users", users.len());
+        Ok(users)
+    }
+
+    pub async fn save_revoked_user_tokens(
+        &self,
+        user_id: UserId,
+        revoked_at: u64,
+    ) -> Result<(), IggyError> {
+        let mut users = self.load_all_revoked_user_tokens().await?;
+        users.insert(user_id, revoked_at);
+        self.save_map(
+            &self.revoked_users_path,
+            &users,
+            "revoked user access tokens",
+        )
+        .await
+    }
+
+    pub async fn delete_revoked_user_tokens(&self, user_ids: &[UserId]) -> Result<(), IggyError> {
+        let mut users = self.load_all_revoked_user_tokens().await?;
+        if users.is_empty() {
+            return Ok(());
+        }
+
+        for user_id in user_ids {
+            users.remove(user_id);
+        }
+
+        self.save_map(
+            &self.revoked_users_path,
+            &users,
+            "revoked user access tokens",
+        )
+        .await
+    }
+
+    async fn load_map<K, V>(
+        &self,
+        path: &str,
+        resource: &str,
+    ) -> Result<Option<AHashMap<K, V>>, IggyError>
+    where
+        K: DeserializeOwned + Eq + Hash,
+        V: DeserializeOwned,
+    {
+        let Ok(mut file) = file::open(path).await else {
+            return Ok(None);
+        };
+
+        info!("Loading {resource} from: {path}");
+        let file_size = file
+            .metadata()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to read file metadata, path: {path}")
+            })
+            .map_err(|_| IggyError::CannotReadFileMetadata)?
+            .len() as usize;
+        let mut buffer = PooledBuffer::with_capacity(file_size);
+        buffer.put_bytes(0, file_size);
+        file.read_exact(&mut buffer)
             .await
             .with_error_context(|error| {
                 format!(
-                    "{COMPONENT} (error: {error}) - failed to overwrite file, path: {}",
-                    self.path
+                    "{COMPONENT} (error: {error}) - failed to read file into buffer, path: {path}"
                 )
+            })
+            .map_err(|_| IggyError::CannotReadFile)?;
+
+        let map: AHashMap<K, V> =
+            bincode::serde::decode_from_slice(&buffer, bincode::config::standard())
+                .with_context(|| format!("Failed to deserialize {resource}"))
+                .map_err(|_| IggyError::CannotDeserializeResource)?
+                .0;
+        Ok(Some(map))
+    }
+
+    async fn save_map<K, V>(
+        &self,
+        path: &str,
+        map: &AHashMap<K, V>,
+        resource: &str,
+    ) -> Result<(), IggyError>
+    where
+        K: Serialize + Eq + Hash,
+        V: Serialize,
+    {
+        let bytes = bincode::serde::encode_to_vec(map, bincode::config::standard())
+            .with_context(|| format!("Failed to serialize {resource}"))
+            .map_err(|_| IggyError::CannotSerializeResource)?;
+        self.persister
+            .overwrite(path, &bytes)
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to overwrite file, path: {path}")
             })?;
         Ok(())
     }
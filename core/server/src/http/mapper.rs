@@ -24,6 +24,7 @@ use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::users::user::User;
 use iggy_common::ConsumerGroupInfo;
+use iggy_common::IggyExpiry;
 use iggy_common::PersonalAccessTokenInfo;
 use iggy_common::Sizeable;
 use iggy_common::StreamDetails;
@@ -36,7 +37,7 @@ use iggy_common::{UserInfo, UserInfoDetails};
 use tokio::sync::RwLock;
 
 pub fn map_stream(stream: &Stream) -> StreamDetails {
-    let topics = map_topics(&stream.get_topics());
+    let topics = map_topics(&stream.get_topics(), stream.message_expiry);
     let mut stream_details = StreamDetails {
         id: stream.stream_id,
         created_at: stream.created_at,
@@ -44,6 +45,9 @@ pub fn map_stream(stream: &Stream) -> StreamDetails {
         topics_count: topics.len() as u32,
         size: stream.get_size(),
         messages_count: stream.get_messages_count(),
+        max_size: stream.max_size,
+        message_expiry: stream.message_expiry,
+        size_policy: stream.size_policy,
         topics,
     };
     stream_details.topics.sort_by(|a, b| a.id.cmp(&b.id));
@@ -68,7 +72,8 @@ pub fn map_streams(streams: &[&Stream]) -> Vec<iggy_common::Stream> {
     streams_data
 }
 
-pub fn map_topics(topics: &[&Topic]) -> Vec<iggy_common::Topic> {
+/// The topics are mapped with their effective message expiry, including the one inherited from their stream.
+pub fn map_topics(topics: &[&Topic], stream_message_expiry: IggyExpiry) -> Vec<iggy_common::Topic> {
     let mut topics_data = Vec::with_capacity(topics.len());
     for topic in topics {
         let topic = iggy_common::Topic {
@@ -78,7 +83,7 @@ pub fn map_topics(topics: &[&Topic]) -> Vec<iggy_common::Topic> {
             size: topic.get_size_bytes(),
             partitions_count: topic.get_partitions().len() as u32,
             messages_count: topic.get_messages_count(),
-            message_expiry: topic.message_expiry,
+            message_expiry: topic.get_effective_message_expiry(stream_message_expiry),
             compression_algorithm: topic.compression_algorithm,
             max_topic_size: topic.max_topic_size,
             replication_factor: topic.replication_factor,
@@ -89,7 +94,7 @@ pub fn map_topics(topics: &[&Topic]) -> Vec<iggy_common::Topic> {
     topics_data
 }
 
-pub async fn map_topic(topic: &Topic) -> TopicDetails {
+pub async fn map_topic(topic: &Topic, stream_message_expiry: IggyExpiry) -> TopicDetails {
     let mut topic_details = TopicDetails {
         id: topic.topic_id,
         created_at: topic.created_at,
@@ -98,7 +103,7 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
         messages_count: topic.get_messages_count(),
         partitions_count: topic.get_partitions().len() as u32,
         partitions: Vec::new(),
-        message_expiry: topic.message_expiry,
+        message_expiry: topic.get_effective_message_expiry(stream_message_expiry),
         compression_algorithm: topic.compression_algorithm,
         max_topic_size: topic.max_topic_size,
         replication_factor: topic.replication_factor,
@@ -125,6 +130,7 @@ pub fn map_user(user: &User) -> UserInfoDetails {
         created_at: user.created_at,
         status: user.status,
         permissions: user.permissions.clone(),
+        quota: user.quota,
     }
 }
 
@@ -165,6 +171,14 @@ pub fn map_client(client: &Client) -> iggy_common::ClientInfoDetails {
         transport: client.transport.to_string(),
         address: client.session.ip_address.to_string(),
         consumer_groups_count: client.consumer_groups.len() as u32,
+        connected_at: client.session.connected_at,
+        last_activity_at: client.session.get_last_activity_at(),
+        bytes_received: client.session.get_bytes_received().into(),
+        bytes_sent: client.session.get_bytes_sent().into(),
+        authentication_method: client
+            .authentication_method
+            .map(|method| method.to_string()),
+        metadata: client.metadata.clone(),
         consumer_groups: client
             .consumer_groups
             .iter()
@@ -187,6 +201,14 @@ pub async fn map_clients(clients: &[IggySharedMut<Client>]) -> Vec<iggy_common::
             transport: client.transport.to_string(),
             address: client.session.ip_address.to_string(),
             consumer_groups_count: client.consumer_groups.len() as u32,
+            connected_at: client.session.connected_at,
+            last_activity_at: client.session.get_last_activity_at(),
+            bytes_received: client.session.get_bytes_received().into(),
+            bytes_sent: client.session.get_bytes_sent().into(),
+            authentication_method: client
+                .authentication_method
+                .map(|method| method.to_string()),
+            metadata: client.metadata.clone(),
         };
         all_clients.push(client);
     }
@@ -0,0 +1,409 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use axum::Json;
+use axum::http::{HeaderMap, HeaderName, HeaderValue, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use iggy_common::{
+    BytesSerializable, HeaderKey, IggyError, IggyMessage, Partitioning, PolledMessages,
+};
+use std::collections::HashMap;
+
+pub const JSON_CONTENT_TYPE: &str = "application/json";
+/// The single message, with the raw payload as the body and the metadata in the headers.
+pub const OCTET_STREAM_CONTENT_TYPE: &str = "application/octet-stream";
+/// The compact binary batch of the length-prefixed messages.
+pub const BATCH_CONTENT_TYPE: &str = "application/vnd.iggy.batch";
+/// The newline-delimited JSON messages, available only for polling.
+pub const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+pub const PARTITION_ID_HEADER: &str = "iggy-partition-id";
+pub const PARTITION_KEY_HEADER: &str = "iggy-partition-key";
+pub const CURRENT_OFFSET_HEADER: &str = "iggy-current-offset";
+pub const COUNT_HEADER: &str = "iggy-count";
+pub const MESSAGE_ID_HEADER: &str = "iggy-message-id";
+pub const OFFSET_HEADER: &str = "iggy-offset";
+pub const TIMESTAMP_HEADER: &str = "iggy-timestamp";
+/// The prefix of the HTTP headers mapped to the user headers of the message.
+pub const USER_HEADER_PREFIX: &str = "iggy-header-";
+
+/// The size of the message metadata preceding the user headers and the payload in the batch:
+/// ID (u128), offset (u64), timestamp (u64), user headers length (u32) and payload length (u32).
+const BATCH_MESSAGE_METADATA_SIZE: usize = 16 + 8 + 8 + 4 + 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessagesFormat {
+    Json,
+    Single,
+    Batch,
+    NdJson,
+}
+
+impl MessagesFormat {
+    fn from_media_type(media_type: &str) -> Option<Self> {
+        match media_type {
+            JSON_CONTENT_TYPE => Some(MessagesFormat::Json),
+            OCTET_STREAM_CONTENT_TYPE => Some(MessagesFormat::Single),
+            BATCH_CONTENT_TYPE => Some(MessagesFormat::Batch),
+            NDJSON_CONTENT_TYPE => Some(MessagesFormat::NdJson),
+            _ => None,
+        }
+    }
+
+    fn content_type(&self) -> &'static str {
+        match self {
+            MessagesFormat::Json => JSON_CONTENT_TYPE,
+            MessagesFormat::Single => OCTET_STREAM_CONTENT_TYPE,
+            MessagesFormat::Batch => BATCH_CONTENT_TYPE,
+            MessagesFormat::NdJson => NDJSON_CONTENT_TYPE,
+        }
+    }
+
+    /// Returns the format of the sent messages, JSON if the content type is missing.
+    pub fn from_content_type(headers: &HeaderMap) -> Option<Self> {
+        let Some(content_type) = headers.get(header::CONTENT_TYPE) else {
+            return Some(MessagesFormat::Json);
+        };
+        let media_type = content_type.to_str().ok()?.split(';').next()?.trim();
+        match Self::from_media_type(&media_type.to_ascii_lowercase())? {
+            MessagesFormat::NdJson => None,
+            format => Some(format),
+        }
+    }
+
+    /// Returns the first supported format of the polled messages, in the order of the accepted
+    /// media types (the quality values are not taken into account), JSON if any is accepted.
+    pub fn from_accept(headers: &HeaderMap) -> Option<Self> {
+        let media_types = headers
+            .get_all(header::ACCEPT)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .filter_map(|value| value.split(';').next())
+            .map(|media_type| media_type.trim().to_ascii_lowercase())
+            .filter(|media_type| !media_type.is_empty())
+            .collect::<Vec<_>>();
+        if media_types.is_empty() {
+            return Some(MessagesFormat::Json);
+        }
+
+        media_types
+            .iter()
+            .find_map(|media_type| match media_type.as_str() {
+                "*/*" | "application/*" => Some(MessagesFormat::Json),
+                media_type => Self::from_media_type(media_type),
+            })
+    }
+}
+
+/// Returns the partitioning given by the partition ID or key header, balanced if neither is set.
+pub fn partitioning_from_headers(headers: &HeaderMap) -> Result<Partitioning, IggyError> {
+    if let Some(partition_id) = headers.get(PARTITION_ID_HEADER) {
+        let partition_id = parse_header(partition_id)?;
+        return Ok(Partitioning::partition_id(partition_id));
+    }
+
+    if let Some(key) = headers.get(PARTITION_KEY_HEADER) {
+        return Partitioning::messages_key(key.as_bytes());
+    }
+
+    Ok(Partitioning::balanced())
+}
+
+/// Creates the message from the raw payload, with the ID and the user headers given by the headers.
+/// The names of the user headers are lowercase, as the HTTP header names are case-insensitive.
+pub fn message_from_headers(headers: &HeaderMap, payload: Bytes) -> Result<IggyMessage, IggyError> {
+    let id = headers
+        .get(MESSAGE_ID_HEADER)
+        .map(parse_header::<u128>)
+        .transpose()?;
+    let mut user_headers = HashMap::new();
+    for (name, value) in headers {
+        let Some(key) = name.as_str().strip_prefix(USER_HEADER_PREFIX) else {
+            continue;
+        };
+        let value = value.to_str().map_err(|_| IggyError::InvalidHeaderValue)?;
+        user_headers.insert(HeaderKey::new(key)?, value.parse()?);
+    }
+
+    IggyMessage::builder()
+        .maybe_id(id)
+        .payload(payload)
+        .maybe_user_headers((!user_headers.is_empty()).then_some(user_headers))
+        .build()
+}
+
+/// Decodes the batch of the messages, each one consisting of the metadata (the offset and the
+/// timestamp are assigned by the server, so they're ignored), the binary user headers and the payload.
+pub fn decode_batch(mut bytes: Bytes) -> Result<Vec<IggyMessage>, IggyError> {
+    let mut messages = Vec::new();
+    while bytes.has_remaining() {
+        if bytes.remaining() < BATCH_MESSAGE_METADATA_SIZE {
+            return Err(IggyError::InvalidFormat);
+        }
+
+        let id = bytes.get_u128_le();
+        bytes.advance(16);
+        let user_headers_length = bytes.get_u32_le() as usize;
+        let payload_length = bytes.get_u32_le() as usize;
+        if bytes.remaining() < user_headers_length + payload_length {
+            return Err(IggyError::InvalidFormat);
+        }
+
+        let user_headers = bytes.split_to(user_headers_length);
+        let user_headers = if user_headers.is_empty() {
+            None
+        } else {
+            Some(HashMap::from_bytes(user_headers)?)
+        };
+        let payload = bytes.split_to(payload_length);
+        messages.push(
+            IggyMessage::builder()
+                .id(id)
+                .payload(payload)
+                .maybe_user_headers(user_headers)
+                .build()?,
+        );
+    }
+    Ok(messages)
+}
+
+pub fn encode_batch(messages: &[IggyMessage]) -> Bytes {
+    let size = messages
+        .iter()
+        .map(|message| {
+            BATCH_MESSAGE_METADATA_SIZE
+                + message
+                    .user_headers
+                    .as_ref()
+                    .map_or(0, |headers| headers.len())
+                + message.payload.len()
+        })
+        .sum();
+    let mut bytes = BytesMut::with_capacity(size);
+    for message in messages {
+        let user_headers = message.user_headers.as_deref().unwrap_or_default();
+        bytes.put_u128_le(message.header.id);
+        bytes.put_u64_le(message.header.offset);
+        bytes.put_u64_le(message.header.timestamp);
+        bytes.put_u32_le(user_headers.len() as u32);
+        bytes.put_u32_le(message.payload.len() as u32);
+        bytes.put_slice(user_headers);
+        bytes.put_slice(&message.payload);
+    }
+    bytes.freeze()
+}
+
+/// Returns the polled messages in the format. The single message is returned as the raw payload
+/// with the metadata and the user headers in the headers, or with no content, if there's none.
+pub fn polled_messages_response(
+    format: MessagesFormat,
+    mut headers: HeaderMap,
+    polled_messages: PolledMessages,
+) -> Result<Response, IggyError> {
+    if format == MessagesFormat::Json {
+        return Ok((headers, Json(polled_messages)).into_response());
+    }
+
+    insert_header(
+        &mut headers,
+        PARTITION_ID_HEADER,
+        polled_messages.partition_id,
+    );
+    insert_header(
+        &mut headers,
+        CURRENT_OFFSET_HEADER,
+        polled_messages.current_offset,
+    );
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static(format.content_type()),
+    );
+    let body = match format {
+        MessagesFormat::Json => unreachable!(),
+        MessagesFormat::Single => {
+            let Some(message) = polled_messages.messages.into_iter().next() else {
+                return Ok((StatusCode::NO_CONTENT, headers).into_response());
+            };
+            insert_header(&mut headers, MESSAGE_ID_HEADER, message.header.id);
+            insert_header(&mut headers, OFFSET_HEADER, message.header.offset);
+            insert_header(&mut headers, TIMESTAMP_HEADER, message.header.timestamp);
+            for (key, value) in message.user_headers_map()?.unwrap_or_default() {
+                let value = value
+                    .as_str()
+                    .map(str::to_owned)
+                    .unwrap_or_else(|_| value.value_only_to_string());
+                let (Ok(name), Ok(value)) = (
+                    HeaderName::try_from(format!("{USER_HEADER_PREFIX}{key}")),
+                    HeaderValue::try_from(value),
+                ) else {
+                    return Err(IggyError::InvalidHeaderValue);
+                };
+                headers.insert(name, value);
+            }
+            message.payload
+        }
+        MessagesFormat::Batch => {
+            insert_header(&mut headers, COUNT_HEADER, polled_messages.count);
+            encode_batch(&polled_messages.messages)
+        }
+        MessagesFormat::NdJson => {
+            insert_header(&mut headers, COUNT_HEADER, polled_messages.count);
+            let mut body = Vec::new();
+            for message in &polled_messages.messages {
+                serde_json::to_writer(&mut body, message).map_err(|_| IggyError::InvalidFormat)?;
+                body.push(b'\n');
+            }
+            Bytes::from(body)
+        }
+    };
+    Ok((headers, body).into_response())
+}
+
+fn insert_header(headers: &mut HeaderMap, name: &'static str, value: impl ToString) {
+    headers.insert(
+        name,
+        HeaderValue::try_from(value.to_string()).expect("Number is a valid header value"),
+    );
+}
+
+fn parse_header<T: std::str::FromStr>(value: &HeaderValue) -> Result<T, IggyError> {
+    value
+        .to_str()
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .ok_or(IggyError::InvalidFormat)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::HeaderValue as IggyHeaderValue;
+    use std::str::FromStr;
+
+    #[test]
+    fn should_encode_and_decode_batch() {
+        let user_headers = HashMap::from([(
+            HeaderKey::new("content-type").unwrap(),
+            IggyHeaderValue::from_str("application/protobuf").unwrap(),
+        )]);
+        let messages = vec![
+            IggyMessage::builder()
+                .id(1)
+                .payload(Bytes::from_static(&[0, 1, 2, 3]))
+                .user_headers(user_headers.clone())
+                .build()
+                .unwrap(),
+            IggyMessage::builder()
+                .payload(Bytes::from_static(b"second"))
+                .build()
+                .unwrap(),
+        ];
+
+        let decoded = decode_batch(encode_batch(&messages)).unwrap();
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].header.id, 1);
+        assert_eq!(decoded[0].payload, messages[0].payload);
+        assert_eq!(decoded[0].user_headers_map().unwrap(), Some(user_headers));
+        assert_eq!(decoded[1].header.id, 0);
+        assert_eq!(decoded[1].payload, messages[1].payload);
+        assert!(decoded[1].user_headers.is_none());
+
+        let truncated = encode_batch(&messages).slice(..BATCH_MESSAGE_METADATA_SIZE + 1);
+        assert!(decode_batch(truncated).is_err());
+    }
+
+    #[test]
+    fn should_not_decode_batch_with_truncated_user_headers() {
+        // The header key length is declared, but the key and the rest of the header are missing.
+        let user_headers = 5u32.to_le_bytes();
+        let mut bytes = BytesMut::new();
+        bytes.put_u128_le(1);
+        bytes.put_bytes(0, 16);
+        bytes.put_u32_le(user_headers.len() as u32);
+        bytes.put_u32_le(1);
+        bytes.put_slice(&user_headers);
+        bytes.put_u8(0);
+
+        let result = decode_batch(bytes.freeze());
+        assert_eq!(
+            result.unwrap_err().as_code(),
+            IggyError::InvalidFormat.as_code()
+        );
+    }
+
+    #[test]
+    fn should_negotiate_messages_format() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(
+            MessagesFormat::from_accept(&headers),
+            Some(MessagesFormat::Json)
+        );
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("text/html, application/x-ndjson;q=0.9, */*;q=0.1"),
+        );
+        assert_eq!(
+            MessagesFormat::from_accept(&headers),
+            Some(MessagesFormat::NdJson)
+        );
+        headers.insert(header::ACCEPT, HeaderValue::from_static("text/html"));
+        assert_eq!(MessagesFormat::from_accept(&headers), None);
+
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/octet-stream"),
+        );
+        assert_eq!(
+            MessagesFormat::from_content_type(&headers),
+            Some(MessagesFormat::Single)
+        );
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(NDJSON_CONTENT_TYPE),
+        );
+        assert_eq!(MessagesFormat::from_content_type(&headers), None);
+    }
+
+    #[test]
+    fn should_map_headers_to_message() {
+        let mut headers = HeaderMap::new();
+        headers.insert(MESSAGE_ID_HEADER, HeaderValue::from_static("42"));
+        headers.insert("Iggy-Header-Trace-Id", HeaderValue::from_static("abc"));
+        headers.insert(header::CONTENT_LENGTH, HeaderValue::from_static("4"));
+        let message = message_from_headers(&headers, Bytes::from_static(b"test")).unwrap();
+        assert_eq!(message.header.id, 42);
+        let user_headers = message.user_headers_map().unwrap().unwrap();
+        assert_eq!(user_headers.len(), 1);
+        assert_eq!(
+            user_headers[&HeaderKey::new("trace-id").unwrap()]
+                .as_str()
+                .unwrap(),
+            "abc"
+        );
+
+        headers.insert(PARTITION_ID_HEADER, HeaderValue::from_static("invalid"));
+        assert!(partitioning_from_headers(&headers).is_err());
+        headers.insert(PARTITION_ID_HEADER, HeaderValue::from_static("2"));
+        assert_eq!(
+            partitioning_from_headers(&headers).unwrap(),
+            Partitioning::partition_id(2)
+        );
+    }
+}
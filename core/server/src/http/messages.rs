@@ -19,51 +19,97 @@
 use crate::http::COMPONENT;
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
+use crate::http::jwt::jwt_manager::JwtManager;
+use crate::http::message_formats::{self, MessagesFormat};
+use crate::http::openapi::TopicPath;
 use crate::http::shared::AppState;
+use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::segments::{IggyIndexesMut, IggyMessagesBatchMut};
 use crate::streaming::session::Session;
 use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::users::quota_limiter::QuotaDirection;
 use crate::streaming::utils::PooledBuffer;
-use axum::extract::{Path, Query, State};
-use axum::http::StatusCode;
-use axum::routing::get;
-use axum::{Extension, Json, Router};
+use axum::extract::{FromRequest, Path, Query, Request, State};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::{Extension, Json};
+use bytes::Bytes;
 use error_set::ErrContext;
-use iggy_common::Identifier;
+use futures::Stream;
 use iggy_common::IggyMessagesBatch;
 use iggy_common::Validatable;
-use iggy_common::{Consumer, PollMessages, PolledMessages, SendMessages};
+use iggy_common::{
+    Consumer, ConsumerKind, Identifier, IggyError, IggyTimestamp, PollMessages, PolledMessages,
+    PollingKind, PollingStrategy, SendMessages, Sizeable,
+};
+use serde::Deserialize;
+use std::collections::VecDeque;
+use std::convert::Infallible;
 use std::sync::Arc;
-use tracing::instrument;
+use std::time::Duration;
+use tracing::{error, info, instrument};
+use utoipa::IntoParams;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
 
-pub fn router(state: Arc<AppState>) -> Router {
-    Router::new()
-        .route(
-            "/streams/{stream_id}/topics/{topic_id}/messages",
-            get(poll_messages).post(send_messages),
-        )
-        .route(
-            "/streams/{stream_id}/topics/{topic_id}/messages/flush/{partition_id}/{fsync}",
-            get(flush_unsaved_buffer),
-        )
-        .with_state(state)
+/// The header containing the time (in microseconds) by which the response was delayed due to the exceeded quota.
+const THROTTLE_TIME_HEADER: &str = "iggy-throttle-time";
+/// The header sent by the reconnecting SSE client, containing the offset of the last received message.
+const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+/// The interval of polling the partition for the new messages, once all the messages have been streamed.
+const STREAM_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// The interval of the SSE comments, which keep the idle connection open.
+const STREAM_KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+pub fn router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(poll_messages, send_messages))
+        .routes(routes!(stream_messages))
+        .routes(routes!(flush_unsaved_buffer))
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages",
+    tag = "Messages",
+    params(TopicPath, PollMessagesQuery),
+    responses(
+        (status = 200, description = "The polled messages, in the format given by the accepted media type.", content(
+            (PolledMessages = "application/json"),
+            ([u8] = "application/octet-stream"),
+            ([u8] = "application/vnd.iggy.batch"),
+            (String = "application/x-ndjson")
+        )),
+        (status = 204, description = "There's no message to return as the raw payload."),
+        (status = 406, description = "None of the accepted media types is supported.")
+    )
+)]
 async fn poll_messages(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
+    headers: HeaderMap,
     mut query: Query<PollMessages>,
-) -> Result<Json<PolledMessages>, CustomError> {
+) -> Result<Response, CustomError> {
+    let Some(format) = MessagesFormat::from_accept(&headers) else {
+        return Ok(StatusCode::NOT_ACCEPTABLE.into_response());
+    };
     query.stream_id = Identifier::from_str_value(&stream_id)?;
     query.topic_id = Identifier::from_str_value(&topic_id)?;
+    // Only the single message is returned as the raw payload, so no more is polled (and committed).
+    if format == MessagesFormat::Single {
+        query.count = 1;
+    }
     query.validate()?;
 
     let consumer = Consumer::new(query.0.consumer.id);
+    let session = Session::stateless(identity.user_id, identity.ip_address);
     let system = state.system.read().await;
     let (metadata, messages) = system
         .poll_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &session,
             &consumer,
             &query.0.stream_id,
             &query.0.topic_id,
@@ -77,30 +123,394 @@ async fn poll_messages(
                 stream_id, topic_id, query.0.partition_id
             )
         })?;
+    let throttle_time = system.record_throughput(
+        &session,
+        QuotaDirection::Consume,
+        messages.size() as u64,
+        messages.count() as u64,
+    );
+    drop(system);
+
+    let headers = throttle(throttle_time).await;
     let polled_messages = messages.into_polled_messages(metadata);
-    Ok(Json(polled_messages))
+    Ok(message_formats::polled_messages_response(
+        format,
+        headers,
+        polled_messages,
+    )?)
+}
+
+/// Streams the messages as the Server-Sent Events, pushing the new messages as they're appended.
+/// The query is the same as for polling the messages, and the `Last-Event-ID` header (the offset
+/// of the last received message) resumes the stream of the partition after reconnecting.
+/// The consumer group members join the group for the lifetime of the connection, polling the next
+/// messages of the assigned partitions and committing the offsets automatically.
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages/stream",
+    tag = "Messages",
+    params(TopicPath, PollMessagesQuery, StreamMessagesOptions),
+    responses(
+        (status = 200, description = "The Server-Sent Events with the messages.", content_type = "text/event-stream", body = String)
+    )
+)]
+async fn stream_messages(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    headers: HeaderMap,
+    Query(options): Query<StreamMessagesOptions>,
+    mut query: Query<PollMessages>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, CustomError> {
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.consumer.kind = options.consumer_kind;
+    query.validate()?;
+
+    let mut messages_stream = match query.0.consumer.kind {
+        ConsumerKind::ConsumerGroup => {
+            MessagesStream::join_consumer_group(&state, identity, query.0).await?
+        }
+        ConsumerKind::Consumer => {
+            let mut query = query.0;
+            if let Some(last_event_id) = headers.get(LAST_EVENT_ID_HEADER) {
+                let offset = last_event_id
+                    .to_str()
+                    .ok()
+                    .and_then(|offset| offset.trim().parse::<u64>().ok())
+                    .ok_or(IggyError::InvalidFormat)?;
+                let offset = offset.checked_add(1).ok_or(IggyError::InvalidFormat)?;
+                query.strategy = PollingStrategy::offset(offset);
+            }
+            MessagesStream {
+                system: state.system.clone(),
+                jwt_manager: state.jwt_manager.clone(),
+                session: Arc::new(Session::stateless(identity.user_id, identity.ip_address)),
+                consumer: Consumer::new(query.consumer.id),
+                stream_id: query.stream_id,
+                topic_id: query.topic_id,
+                partition_id: query.partition_id,
+                strategy: query.strategy,
+                count: query.count,
+                auto_commit: query.auto_commit,
+                identity,
+                events: VecDeque::new(),
+                is_member: false,
+                is_finished: false,
+            }
+        }
+    };
+
+    // The first poll is done upfront, so that the invalid request is rejected with the error status.
+    messages_stream
+        .poll()
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to stream messages, stream ID: {stream_id}, topic ID: {topic_id}"
+            )
+        })?;
+    let stream = futures::stream::unfold(messages_stream, |mut messages_stream| async move {
+        messages_stream
+            .next_event()
+            .await
+            .map(|event| (Ok(event), messages_stream))
+    });
+    Ok(Sse::new(stream).keep_alive(KeepAlive::new().interval(STREAM_KEEP_ALIVE_INTERVAL)))
+}
+
+/// The options of streaming the messages, complementing the polling query, in which the consumer
+/// kind is not available.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct StreamMessagesOptions {
+    /// The kind of the consumer, the consumer group members poll the assigned partitions.
+    #[serde(default)]
+    #[param(inline)]
+    consumer_kind: ConsumerKind,
 }
 
+/// State of the SSE connection streaming the messages, polled once all the events have been sent.
+struct MessagesStream {
+    system: SharedSystem,
+    jwt_manager: Arc<JwtManager>,
+    session: Arc<Session>,
+    consumer: Consumer,
+    stream_id: Identifier,
+    topic_id: Identifier,
+    partition_id: Option<u32>,
+    strategy: PollingStrategy,
+    count: u32,
+    auto_commit: bool,
+    /// The stream ends once the access token of the request has expired or has been revoked.
+    identity: Identity,
+    events: VecDeque<Event>,
+    /// The consumer group member is registered as the client with its own ID, so that the streams sharing
+    /// the same connection don't collide, and it's deleted when the stream is closed.
+    is_member: bool,
+    is_finished: bool,
+}
+
+impl MessagesStream {
+    async fn join_consumer_group(
+        state: &AppState,
+        identity: Identity,
+        query: PollMessages,
+    ) -> Result<Self, IggyError> {
+        let system = &state.system;
+        let session = system
+            .read()
+            .await
+            .add_unique_client(&identity.ip_address, Transport::Http)
+            .await;
+        let result = async {
+            let system = system.read().await;
+            system
+                .login_user_with_jwt(identity.user_id, &session)
+                .await?;
+            system
+                .join_consumer_group(
+                    &session,
+                    &query.stream_id,
+                    &query.topic_id,
+                    &query.consumer.id,
+                )
+                .await
+        }
+        .await;
+        if let Err(error) = result {
+            system.read().await.delete_client_session(&session).await;
+            return Err(error);
+        }
+
+        info!(
+            "{COMPONENT} - client: {} has joined consumer group: {} for streaming messages.",
+            session.client_id, query.consumer.id
+        );
+        Ok(Self {
+            system: system.clone(),
+            jwt_manager: state.jwt_manager.clone(),
+            session,
+            consumer: query.consumer,
+            stream_id: query.stream_id,
+            topic_id: query.topic_id,
+            partition_id: None,
+            strategy: PollingStrategy::next(),
+            count: query.count,
+            auto_commit: true,
+            identity,
+            events: VecDeque::new(),
+            is_member: true,
+            is_finished: false,
+        })
+    }
+
+    /// Returns the next event, polling the messages until there are any. The stream ends after
+    /// sending the error event, e.g. once the access token has expired or has been revoked,
+    /// or the client has been disconnected.
+    async fn next_event(&mut self) -> Option<Event> {
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Some(event);
+            }
+            if self.is_finished {
+                return None;
+            }
+            if let Some(reason) = self.closing_reason().await {
+                self.is_finished = true;
+                return Some(Event::default().event("error").data(reason));
+            }
+
+            match self.poll().await {
+                Ok(true) => {}
+                Ok(false) => {
+                    tokio::select! {
+                        _ = tokio::time::sleep(STREAM_POLL_INTERVAL) => {}
+                        _ = self.session.disconnected() => {}
+                    }
+                }
+                Err(error) => {
+                    error!(
+                        "{COMPONENT} (error: {error}) - failed to stream messages, stream ID: {}, topic ID: {}",
+                        self.stream_id, self.topic_id
+                    );
+                    self.is_finished = true;
+                    return Some(Event::default().event("error").data(error.to_string()));
+                }
+            }
+        }
+    }
+
+    /// Returns the reason to end the stream, the group members are registered as the clients,
+    /// which can be disconnected, also by revoking the sessions of the user.
+    async fn closing_reason(&self) -> Option<&'static str> {
+        if !self.session.is_active() {
+            return Some("client has been disconnected");
+        }
+        if IggyTimestamp::now().to_secs() >= self.identity.token_expiry {
+            return Some("access token has expired");
+        }
+        if self.jwt_manager.is_identity_revoked(&self.identity).await {
+            return Some("access token has been revoked");
+        }
+        None
+    }
+
+    /// Polls the messages and queues them as the events, returns `false` if there were none.
+    async fn poll(&mut self) -> Result<bool, IggyError> {
+        let system = self.system.read().await;
+        let (metadata, messages) = system
+            .poll_messages(
+                &self.session,
+                &self.consumer,
+                &self.stream_id,
+                &self.topic_id,
+                self.partition_id,
+                PollingArgs::new(self.strategy, self.count, self.auto_commit),
+            )
+            .await?;
+        let throttle_time = system.record_throughput(
+            &self.session,
+            QuotaDirection::Consume,
+            messages.size() as u64,
+            messages.count() as u64,
+        );
+        drop(system);
+
+        let polled_messages = messages.into_polled_messages(metadata);
+        let Some(last_message) = polled_messages.messages.last() else {
+            return Ok(false);
+        };
+        // The group members poll the next messages, while the partition is read from the next offset.
+        if !self.is_member {
+            self.strategy = PollingStrategy::offset(last_message.header.offset + 1);
+        }
+        for message in &polled_messages.messages {
+            let event = Event::default()
+                .event("message")
+                .json_data(message)
+                .map_err(|_| IggyError::InvalidFormat)?;
+            // The offsets of the group members refer to the different partitions, so they're not resumable.
+            let event = if self.is_member {
+                event
+            } else {
+                event.id(message.header.offset.to_string())
+            };
+            self.events.push_back(event);
+        }
+        if let Some(throttle_time) = throttle_time {
+            tokio::time::sleep(throttle_time).await;
+        }
+        Ok(true)
+    }
+}
+
+impl Drop for MessagesStream {
+    fn drop(&mut self) {
+        if !self.is_member {
+            return;
+        }
+
+        let system = self.system.clone();
+        let session = self.session.clone();
+        tokio::spawn(async move {
+            system.read().await.delete_client_session(&session).await;
+        });
+    }
+}
+
+/// The query parameters of `PollMessages`, which can't be derived, as they're flattened.
+#[derive(IntoParams)]
+#[into_params(parameter_in = Query)]
+#[allow(dead_code)]
+struct PollMessagesQuery {
+    /// The numeric ID or the name of the consumer (or the consumer group).
+    id: Option<String>,
+    /// The ID of the partition, required for the consumer.
+    partition_id: Option<u32>,
+    /// The kind of the polling strategy.
+    #[param(inline)]
+    kind: Option<PollingKind>,
+    /// The value of the polling strategy, i.e. the offset or the timestamp.
+    value: Option<u64>,
+    /// The maximum number of the messages to poll.
+    count: Option<u32>,
+    /// Whether to store the offset of the last polled message.
+    auto_commit: Option<bool>,
+}
+
+/// Sends the messages in the format given by the content type: JSON, the single message with the
+/// raw payload, or the binary batch. The binary messages are partitioned by the headers.
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages",
+    tag = "Messages",
+    params(TopicPath),
+    request_body(description = "The messages, in the format given by the content type.", content(
+        (SendMessages = "application/json"),
+        ([u8] = "application/octet-stream"),
+        ([u8] = "application/vnd.iggy.batch")
+    )),
+    responses(
+        (status = 201, description = "The messages have been sent."),
+        (status = 415, description = "The content type is not supported.")
+    )
+)]
 async fn send_messages(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
-    Json(mut command): Json<SendMessages>,
-) -> Result<StatusCode, CustomError> {
-    command.stream_id = Identifier::from_str_value(&stream_id)?;
-    command.topic_id = Identifier::from_str_value(&topic_id)?;
-    command.partitioning.length = command.partitioning.value.len() as u8;
-    command.validate()?;
-
-    let batch = make_mutable(command.batch);
-    let command_stream_id = command.stream_id;
-    let command_topic_id = command.topic_id;
-    let partitioning = command.partitioning;
+    request: Request,
+) -> Result<Response, CustomError> {
+    let Some(format) = MessagesFormat::from_content_type(request.headers()) else {
+        return Ok(StatusCode::UNSUPPORTED_MEDIA_TYPE.into_response());
+    };
+    let command_stream_id = Identifier::from_str_value(&stream_id)?;
+    let command_topic_id = Identifier::from_str_value(&topic_id)?;
+    let (partitioning, batch) = match format {
+        MessagesFormat::Json => {
+            let mut command = match Json::<SendMessages>::from_request(request, &()).await {
+                Ok(Json(command)) => command,
+                Err(rejection) => return Ok(rejection.into_response()),
+            };
+            command.partitioning.length = command.partitioning.value.len() as u8;
+            command.validate()?;
+            (command.partitioning, make_mutable(command.batch))
+        }
+        format => {
+            let headers = request.headers().clone();
+            let partitioning = message_formats::partitioning_from_headers(&headers)?;
+            let body = match Bytes::from_request(request, &()).await {
+                Ok(body) => body,
+                Err(rejection) => return Ok(rejection.into_response()),
+            };
+            let messages = if format == MessagesFormat::Single {
+                vec![message_formats::message_from_headers(&headers, body)?]
+            } else {
+                message_formats::decode_batch(body)?
+            };
+            if messages.is_empty() {
+                return Err(IggyError::InvalidMessagesCount.into());
+            }
+            let messages_size = messages
+                .iter()
+                .map(|message| message.get_size_bytes().as_bytes_u32())
+                .sum();
+            (
+                partitioning,
+                IggyMessagesBatchMut::from_messages(&messages, messages_size),
+            )
+        }
+    };
+
+    let messages_size = batch.size() as u64;
+    let messages_count = batch.count() as u64;
+    let session = Session::stateless(identity.user_id, identity.ip_address);
     let system = state.system.read().await;
 
     system
         .append_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
+            &session,
             &command_stream_id,
             &command_topic_id,
             &partitioning,
@@ -113,9 +523,29 @@ async fn send_messages(
                 "{COMPONENT} (error: {error}) - failed to append messages, stream ID: {stream_id}, topic ID: {topic_id}"
             )
         })?;
-    Ok(StatusCode::CREATED)
+    let throttle_time = system.record_throughput(
+        &session,
+        QuotaDirection::Produce,
+        messages_size,
+        messages_count,
+    );
+    drop(system);
+
+    let headers = throttle(throttle_time).await;
+    Ok((StatusCode::CREATED, headers).into_response())
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}/messages/flush/{partition_id}/{fsync}",
+    tag = "Messages",
+    params(
+        TopicPath,
+        ("partition_id" = u32, Path, description = "The ID of the partition."),
+        ("fsync" = bool, Path, description = "Whether to synchronize the flushed data to the disk.")
+    ),
+    responses((status = 200, description = "The unsaved buffer has been flushed."))
+)]
 #[instrument(skip_all, name = "trace_flush_unsaved_buffer", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id, iggy_partition_id = partition_id, iggy_fsync = fsync))]
 async fn flush_unsaved_buffer(
     State(state): State<Arc<AppState>>,
@@ -137,6 +567,18 @@ async fn flush_unsaved_buffer(
     Ok(StatusCode::OK)
 }
 
+async fn throttle(throttle_time: Option<Duration>) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if let Some(throttle_time) = throttle_time {
+        tokio::time::sleep(throttle_time).await;
+        headers.insert(
+            THROTTLE_TIME_HEADER,
+            HeaderValue::from(throttle_time.as_micros() as u64),
+        );
+    }
+    headers
+}
+
 fn make_mutable(batch: IggyMessagesBatch) -> IggyMessagesBatchMut {
     let (_, indexes, messages) = batch.decompose();
     let (_, indexes_buffer) = indexes.decompose();
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::http::health::HEALTH_PATHS;
 use crate::http::shared::AppState;
 use axum::body::Body;
 use axum::{
@@ -31,6 +32,11 @@ pub async fn metrics(
     request: Request<Body>,
     next: Next,
 ) -> Result<Response, StatusCode> {
+    // The health probes are served without waiting for the system lock held during the startup.
+    if HEALTH_PATHS.contains(&request.uri().path()) {
+        return Ok(next.run(request).await);
+    }
+
     state.system.read().await.metrics.increment_http_requests();
     Ok(next.run(request).await)
 }
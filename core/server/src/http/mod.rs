@@ -18,13 +18,17 @@
 
 pub mod consumer_groups;
 pub mod consumer_offsets;
+pub mod cors;
 pub mod diagnostics;
 pub mod error;
+pub mod health;
 pub mod http_server;
 pub mod jwt;
 mod mapper;
+mod message_formats;
 pub mod messages;
 pub mod metrics;
+pub mod openapi;
 pub mod partitions;
 pub mod personal_access_tokens;
 mod shared;
@@ -32,5 +36,6 @@ pub mod streams;
 pub mod system;
 pub mod topics;
 pub mod users;
+pub mod websocket;
 
 pub const COMPONENT: &str = "HTTP";
@@ -0,0 +1,277 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::http::HttpMetricsConfig;
+use crate::http::error::ErrorResponse;
+use crate::http::shared::AppState;
+use crate::http::*;
+use axum::Router;
+use axum::http::header;
+use axum::routing::get;
+use std::sync::Arc;
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::openapi::{ContentBuilder, Ref, ResponseBuilder};
+use utoipa::{IntoParams, Modify, OpenApi};
+use utoipa_axum::router::{OpenApiRouter, UtoipaMethodRouter};
+
+/// The path serving the OpenAPI document of the HTTP API.
+pub const OPENAPI_PATH: &str = "/openapi.json";
+pub const BEARER_SECURITY_SCHEME: &str = "bearer";
+const ERROR_RESPONSE: &str = "Error";
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Iggy HTTP API"),
+    modifiers(&SecurityAddon, &ErrorResponseAddon),
+    security(("bearer" = [])),
+    components(schemas(ErrorResponse))
+)]
+struct ApiDoc;
+
+/// Adds the JWT bearer authentication, required by all the operations, except for the ones
+/// overriding the security requirements.
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            BEARER_SECURITY_SCHEME,
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
+
+/// Adds the error response returned by the handlers as `CustomError` to all the operations.
+struct ErrorResponseAddon;
+
+impl Modify for ErrorResponseAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.responses.insert(
+            ERROR_RESPONSE.to_owned(),
+            ResponseBuilder::new()
+                .description("The request has failed.")
+                .content(
+                    "application/json",
+                    ContentBuilder::new()
+                        .schema(Some(Ref::from_schema_name("ErrorResponse")))
+                        .build(),
+                )
+                .build()
+                .into(),
+        );
+        for path_item in openapi.paths.paths.values_mut() {
+            let operations = [
+                &mut path_item.get,
+                &mut path_item.put,
+                &mut path_item.post,
+                &mut path_item.delete,
+                &mut path_item.patch,
+            ];
+            for operation in operations.into_iter().flatten() {
+                operation
+                    .responses
+                    .responses
+                    .entry("4XX".to_owned())
+                    .or_insert_with(|| Ref::from_response_name(ERROR_RESPONSE).into());
+            }
+        }
+    }
+}
+
+/// The path parameters identifying the stream.
+#[derive(IntoParams)]
+#[into_params(parameter_in = Path)]
+#[allow(dead_code)]
+pub struct StreamPath {
+    /// The numeric ID or the name of the stream.
+    stream_id: String,
+}
+
+/// The path parameters identifying the topic.
+#[derive(IntoParams)]
+#[into_params(parameter_in = Path)]
+#[allow(dead_code)]
+pub struct TopicPath {
+    /// The numeric ID or the name of the stream.
+    stream_id: String,
+    /// The numeric ID or the name of the topic.
+    topic_id: String,
+}
+
+/// The path parameters identifying the user.
+#[derive(IntoParams)]
+#[into_params(parameter_in = Path)]
+#[allow(dead_code)]
+pub struct UserPath {
+    /// The numeric ID or the username of the user.
+    user_id: String,
+}
+
+/// Returns the router of the HTTP API, along with the route serving its OpenAPI document.
+pub fn router(metrics_config: &HttpMetricsConfig) -> Router<Arc<AppState>> {
+    let (router, openapi) = api_router(metrics_config).split_for_parts();
+    let openapi = openapi
+        .to_json()
+        .expect("Failed to serialize the OpenAPI document");
+    router.route(
+        OPENAPI_PATH,
+        get(|| async move { ([(header::CONTENT_TYPE, "application/json")], openapi) }),
+    )
+}
+
+fn api_router(metrics_config: &HttpMetricsConfig) -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::with_openapi(ApiDoc::openapi())
+        .merge(system::router(metrics_config))
+        .merge(health::router())
+        .merge(personal_access_tokens::router())
+        .merge(users::router())
+        .merge(streams::router())
+        .merge(topics::router())
+        .merge(consumer_groups::router())
+        .merge(consumer_offsets::router())
+        .merge(partitions::router())
+        .merge(messages::router())
+}
+
+/// Registers the documented handler at the configurable path,
+/// instead of the one given by its `#[utoipa::path]` attribute.
+pub fn route_at<S>(
+    router: OpenApiRouter<S>,
+    path: &str,
+    (_, paths, method_router): UtoipaMethodRouter<S>,
+) -> OpenApiRouter<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let mut router = router.route(path, method_router);
+    let document = router.get_openapi_mut();
+    for (_, path_item) in paths.paths {
+        document.paths.paths.insert(path.to_owned(), path_item);
+    }
+    router
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn document() -> utoipa::openapi::OpenApi {
+        api_router(&HttpMetricsConfig {
+            enabled: true,
+            endpoint: "/metrics".to_owned(),
+        })
+        .into_openapi()
+    }
+
+    /// Returns the paths registered in the router, which axum exposes only through its debug representation.
+    fn registered_paths<S>(router: &Router<S>) -> BTreeSet<String> {
+        let debug = format!("{router:?}");
+        let (path_router, _) = debug
+            .split_once("fallback_router")
+            .expect("Unexpected debug representation of the router");
+        path_router
+            .split("RouteId(")
+            .skip(1)
+            .filter_map(|entry| {
+                let (_, path) = entry.split_once("): \"")?;
+                Some(path[..path.find('"')?].to_owned())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_route_should_be_documented() {
+        // The routes registered with `routes!` are documented by construction, any other
+        // registration bypasses the document, apart from the one serving the document itself.
+        let registered_paths = registered_paths(&router(&HttpMetricsConfig {
+            enabled: true,
+            endpoint: "/metrics".to_owned(),
+        }));
+        assert!(!registered_paths.is_empty());
+        let mut documented_paths = document().paths.paths.into_keys().collect::<BTreeSet<_>>();
+        documented_paths.insert(OPENAPI_PATH.to_owned());
+        assert_eq!(
+            registered_paths, documented_paths,
+            "every route must be registered with `routes!` to be documented"
+        );
+    }
+
+    #[test]
+    fn document_should_contain_api_operations() {
+        let document = document();
+        let paths = &document.paths.paths;
+        let streams = &paths["/streams"];
+        assert!(streams.get.is_some() && streams.post.is_some());
+        let messages = &paths["/streams/{stream_id}/topics/{topic_id}/messages"];
+        assert!(messages.get.is_some() && messages.post.is_some());
+        assert!(paths["/metrics"].get.is_some());
+        assert!(paths["/health/ready"].get.is_some());
+        assert!(paths["/stats/history"].get.is_some());
+        assert!(paths["/system/logging"].get.is_some());
+        assert!(paths["/system/logging"].put.is_some());
+        assert!(paths["/system/profile"].post.is_some());
+
+        let login = paths["/users/login"].post.as_ref().unwrap();
+        assert_eq!(login.security.as_ref().map(Vec::len), Some(1));
+        assert!(login.request_body.is_some());
+    }
+
+    #[test]
+    fn document_should_define_every_referenced_schema() {
+        let document = document();
+        let json = serde_json::to_value(&document).unwrap();
+        let schemas = &json["components"]["schemas"];
+        let mut references = Vec::new();
+        collect_references(&json, &mut references);
+        assert!(!references.is_empty());
+        for reference in references {
+            if let Some(name) = reference.strip_prefix("#/components/schemas/") {
+                assert!(schemas.get(name).is_some(), "Missing schema: {name}");
+            }
+        }
+    }
+
+    fn collect_references(value: &serde_json::Value, references: &mut Vec<String>) {
+        match value {
+            serde_json::Value::Object(object) => {
+                for (key, value) in object {
+                    match value {
+                        serde_json::Value::String(reference) if key == "$ref" => {
+                            references.push(reference.clone())
+                        }
+                        value => collect_references(value, references),
+                    }
+                }
+            }
+            serde_json::Value::Array(values) => {
+                for value in values {
+                    collect_references(value, references);
+                }
+            }
+            _ => {}
+        }
+    }
+}
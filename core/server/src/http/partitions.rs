@@ -19,13 +19,13 @@
 use crate::http::COMPONENT;
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
+use crate::http::openapi::TopicPath;
 use crate::http::shared::AppState;
 use crate::state::command::EntryCommand;
 use crate::streaming::session::Session;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::post;
-use axum::{Extension, Json, Router};
+use axum::{Extension, Json};
 use error_set::ErrContext;
 use iggy_common::Identifier;
 use iggy_common::Validatable;
@@ -33,16 +33,20 @@ use iggy_common::create_partitions::CreatePartitions;
 use iggy_common::delete_partitions::DeletePartitions;
 use std::sync::Arc;
 use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
 
-pub fn router(state: Arc<AppState>) -> Router {
-    Router::new()
-        .route(
-            "/streams/{stream_id}/topics/{topic_id}/partitions",
-            post(create_partitions).delete(delete_partitions),
-        )
-        .with_state(state)
+pub fn router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new().routes(routes!(create_partitions, delete_partitions))
 }
 
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics/{topic_id}/partitions",
+    tag = "Partitions",
+    params(TopicPath),
+    responses((status = 201, description = "The partitions have been created."))
+)]
 #[instrument(skip_all, name = "trace_create_partitions", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn create_partitions(
     State(state): State<Arc<AppState>>,
@@ -82,6 +86,16 @@ async fn create_partitions(
     Ok(StatusCode::CREATED)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}/partitions",
+    tag = "Partitions",
+    params(
+        TopicPath,
+        ("partitions_count" = u32, Query, description = "The number of the last partitions to delete."),
+    ),
+    responses((status = 204, description = "The partitions have been deleted."))
+)]
 #[instrument(skip_all, name = "trace_delete_partitions", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn delete_partitions(
     State(state): State<Arc<AppState>>,
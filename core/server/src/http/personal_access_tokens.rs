@@ -21,15 +21,15 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::mapper::map_generated_access_token_to_identity_info;
-use crate::http::shared::AppState;
+use crate::http::shared::{AppState, RequestDetails};
 use crate::state::command::EntryCommand;
 use crate::state::models::CreatePersonalAccessTokenWithHash;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::session::Session;
+use crate::streaming::users::login_limiter::LoginSource;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::routing::{delete, get, post};
-use axum::{Extension, Json, Router};
+use axum::{Extension, Json};
 use error_set::ErrContext;
 use iggy_common::IdentityInfo;
 use iggy_common::Validatable;
@@ -39,24 +39,25 @@ use iggy_common::login_with_personal_access_token::LoginWithPersonalAccessToken;
 use iggy_common::{PersonalAccessTokenInfo, RawPersonalAccessToken};
 use std::sync::Arc;
 use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
 
-pub fn router(state: Arc<AppState>) -> Router {
-    Router::new()
-        .route(
-            "/personal-access-tokens",
-            get(get_personal_access_tokens).post(create_personal_access_token),
-        )
-        .route(
-            "/personal-access-tokens/{name}",
-            delete(delete_personal_access_token),
-        )
-        .route(
-            "/personal-access-tokens/login",
-            post(login_with_personal_access_token),
-        )
-        .with_state(state)
+pub fn router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(
+            get_personal_access_tokens,
+            create_personal_access_token
+        ))
+        .routes(routes!(delete_personal_access_token))
+        .routes(routes!(login_with_personal_access_token))
 }
 
+#[utoipa::path(
+    get,
+    path = "/personal-access-tokens",
+    tag = "Personal Access Tokens",
+    responses((status = 200, description = "The personal access tokens of the user.", body = Vec<PersonalAccessTokenInfo>))
+)]
 async fn get_personal_access_tokens(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -75,6 +76,12 @@ async fn get_personal_access_tokens(
     Ok(Json(personal_access_tokens))
 }
 
+#[utoipa::path(
+    post,
+    path = "/personal-access-tokens",
+    tag = "Personal Access Tokens",
+    responses((status = 200, description = "The created personal access token.", body = RawPersonalAccessToken))
+)]
 #[instrument(skip_all, name = "trace_create_personal_access_token", fields(iggy_user_id = identity.user_id))]
 async fn create_personal_access_token(
     State(state): State<Arc<AppState>>,
@@ -117,6 +124,13 @@ async fn create_personal_access_token(
     Ok(Json(RawPersonalAccessToken { token }))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/personal-access-tokens/{name}",
+    tag = "Personal Access Tokens",
+    params(("name" = String, Path, description = "The name of the personal access token.")),
+    responses((status = 204, description = "The personal access token has been deleted."))
+)]
 #[instrument(skip_all, name = "trace_delete_personal_access_token", fields(iggy_user_id = identity.user_id))]
 async fn delete_personal_access_token(
     State(state): State<Arc<AppState>>,
@@ -154,15 +168,27 @@ async fn delete_personal_access_token(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/personal-access-tokens/login",
+    tag = "Personal Access Tokens",
+    security(()),
+    responses((status = 200, description = "The identity of the user, with the access token.", body = IdentityInfo))
+)]
 #[instrument(skip_all, name = "trace_login_with_personal_access_token")]
 async fn login_with_personal_access_token(
     State(state): State<Arc<AppState>>,
+    Extension(request_details): Extension<RequestDetails>,
     Json(command): Json<LoginWithPersonalAccessToken>,
 ) -> Result<Json<IdentityInfo>, CustomError> {
     command.validate()?;
     let system = state.system.read().await;
     let user = system
-        .login_with_personal_access_token(&command.token, None)
+        .login_with_personal_access_token(
+            &command.token,
+            LoginSource::Ip(request_details.ip_address.ip()),
+            None,
+        )
         .await
         .with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to login with personal access token")
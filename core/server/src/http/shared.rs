@@ -19,10 +19,11 @@
 use crate::http::jwt::jwt_manager::JwtManager;
 use crate::streaming::systems::system::SharedSystem;
 use std::net::SocketAddr;
+use std::sync::Arc;
 use ulid::Ulid;
 
 pub struct AppState {
-    pub jwt_manager: JwtManager,
+    pub jwt_manager: Arc<JwtManager>,
     pub system: SharedSystem,
 }
 
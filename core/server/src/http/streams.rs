@@ -20,12 +20,12 @@ use crate::http::COMPONENT;
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
+use crate::http::openapi::StreamPath;
 use crate::http::shared::AppState;
 use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::routing::{delete, get};
-use axum::{Extension, Json, Router};
+use axum::{Extension, Json};
 use error_set::ErrContext;
 use iggy_common::Identifier;
 use iggy_common::Validatable;
@@ -33,24 +33,31 @@ use iggy_common::create_stream::CreateStream;
 use iggy_common::delete_stream::DeleteStream;
 use iggy_common::purge_stream::PurgeStream;
 use iggy_common::update_stream::UpdateStream;
+use iggy_common::update_stream_retention::UpdateStreamRetention;
 use iggy_common::{Stream, StreamDetails};
 
 use crate::state::command::EntryCommand;
 use crate::state::models::CreateStreamWithId;
 use std::sync::Arc;
 use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
 
-pub fn router(state: Arc<AppState>) -> Router {
-    Router::new()
-        .route("/streams", get(get_streams).post(create_stream))
-        .route(
-            "/streams/{stream_id}",
-            get(get_stream).put(update_stream).delete(delete_stream),
-        )
-        .route("/streams/{stream_id}/purge", delete(purge_stream))
-        .with_state(state)
+pub fn router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_streams, create_stream))
+        .routes(routes!(get_stream, update_stream, delete_stream))
+        .routes(routes!(purge_stream))
+        .routes(routes!(update_stream_retention))
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}",
+    tag = "Streams",
+    params(StreamPath),
+    responses((status = 200, description = "The stream details.", body = StreamDetails))
+)]
 async fn get_stream(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -72,6 +79,12 @@ async fn get_stream(
     Ok(Json(stream))
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams",
+    tag = "Streams",
+    responses((status = 200, description = "The streams.", body = Vec<Stream>))
+)]
 async fn get_streams(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -89,6 +102,12 @@ async fn get_streams(
     Ok(Json(streams))
 }
 
+#[utoipa::path(
+    post,
+    path = "/streams",
+    tag = "Streams",
+    responses((status = 200, description = "The created stream.", body = StreamDetails))
+)]
 #[instrument(skip_all, name = "trace_create_stream", fields(iggy_user_id = identity.user_id))]
 async fn create_stream(
     State(state): State<Arc<AppState>>,
@@ -130,6 +149,13 @@ async fn create_stream(
     Ok(response)
 }
 
+#[utoipa::path(
+    put,
+    path = "/streams/{stream_id}",
+    tag = "Streams",
+    params(StreamPath),
+    responses((status = 204, description = "The stream has been updated."))
+)]
 #[instrument(skip_all, name = "trace_update_stream", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
 async fn update_stream(
     State(state): State<Arc<AppState>>,
@@ -167,6 +193,63 @@ async fn update_stream(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    put,
+    path = "/streams/{stream_id}/retention",
+    tag = "Streams",
+    params(StreamPath),
+    responses((status = 204, description = "The stream retention has been updated."))
+)]
+#[instrument(skip_all, name = "trace_update_stream_retention", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
+async fn update_stream_retention(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(stream_id): Path<String>,
+    Json(mut command): Json<UpdateStreamRetention>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    system
+        .update_stream_retention(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            command.max_size,
+            command.message_expiry,
+            command.size_policy,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to update stream retention, stream ID: {stream_id}"
+            )
+        })?;
+
+    let system = system.downgrade();
+    let stream = system.get_stream(&command.stream_id)?;
+    command.max_size = stream.max_size;
+    command.message_expiry = stream.message_expiry;
+    command.size_policy = stream.size_policy;
+    system
+        .state
+        .apply(identity.user_id, &EntryCommand::UpdateStreamRetention(command))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply update stream retention, stream ID: {stream_id}"
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}",
+    tag = "Streams",
+    params(StreamPath),
+    responses((status = 204, description = "The stream has been deleted."))
+)]
 #[instrument(skip_all, name = "trace_delete_stream", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
 async fn delete_stream(
     State(state): State<Arc<AppState>>,
@@ -204,6 +287,13 @@ async fn delete_stream(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/purge",
+    tag = "Streams",
+    params(StreamPath),
+    responses((status = 204, description = "The stream has been purged."))
+)]
 #[instrument(skip_all, name = "trace_purge_stream", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
 async fn purge_stream(
     State(state): State<Arc<AppState>>,
@@ -21,47 +21,96 @@ use crate::http::COMPONENT;
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
+use crate::http::openapi;
 use crate::http::shared::AppState;
+use crate::streaming::diagnostics::profiler;
 use crate::streaming::session::Session;
+use crate::streaming::systems::snapshot;
 use axum::body::Body;
 use axum::extract::{Path, State};
-use axum::http::{HeaderMap, header};
+use axum::http::{HeaderMap, StatusCode, header};
 use axum::response::IntoResponse;
-use axum::routing::{get, post};
-use axum::{Extension, Json, Router};
+use axum::{Extension, Json};
 use bytes::Bytes;
 use chrono::Local;
 use error_set::ErrContext;
 use iggy_common::Stats;
 use iggy_common::Validatable;
+use iggy_common::get_profile::GetProfile;
 use iggy_common::get_snapshot::GetSnapshot;
 use iggy_common::locking::IggySharedMutFn;
-use iggy_common::{ClientInfo, ClientInfoDetails};
+use iggy_common::set_log_filter::SetLogFilter;
+use iggy_common::{
+    ClientInfo, ClientInfoDetails, ConfigReload, LogFilter, ProfileFormat, ProfileKind,
+    StatsHistory,
+};
 use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
 
 const NAME: &str = "Iggy API";
 const PONG: &str = "pong";
 
-pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Router {
-    let mut router = Router::new()
-        .route("/", get(|| async { NAME }))
-        .route("/ping", get(|| async { PONG }))
-        .route("/stats", get(get_stats))
-        .route("/clients", get(get_clients))
-        .route("/clients/{client_id}", get(get_client))
-        .route("/snapshot", post(get_snapshot));
+pub fn router(metrics_config: &HttpMetricsConfig) -> OpenApiRouter<Arc<AppState>> {
+    let mut router = OpenApiRouter::new()
+        .routes(routes!(get_name))
+        .routes(routes!(ping))
+        .routes(routes!(get_stats))
+        .routes(routes!(get_stats_history))
+        .routes(routes!(get_clients))
+        .routes(routes!(get_client, disconnect_client))
+        .routes(routes!(get_snapshot))
+        .routes(routes!(reload_config))
+        .routes(routes!(get_log_filter, set_log_filter))
+        .routes(routes!(get_profile));
     if metrics_config.enabled {
-        router = router.route(&metrics_config.endpoint, get(get_metrics));
+        router = openapi::route_at(router, &metrics_config.endpoint, routes!(get_metrics));
     }
+    router
+}
+
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "System",
+    security(()),
+    responses((status = 200, description = "The name of the API.", body = String))
+)]
+async fn get_name() -> &'static str {
+    NAME
+}
 
-    router.with_state(state)
+#[utoipa::path(
+    get,
+    path = "/ping",
+    tag = "System",
+    security(()),
+    responses((status = 200, description = "The server is running.", body = String))
+)]
+async fn ping() -> &'static str {
+    PONG
 }
 
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "System",
+    security(()),
+    responses((status = 200, description = "The metrics in the Prometheus text format.", body = String))
+)]
 async fn get_metrics(State(state): State<Arc<AppState>>) -> Result<String, CustomError> {
     let system = state.system.read().await;
+    system.refresh_storage_metrics().await;
     Ok(system.metrics.get_formatted_output())
 }
 
+#[utoipa::path(
+    get,
+    path = "/stats",
+    tag = "System",
+    security(()),
+    responses((status = 200, description = "The server statistics.", body = Stats))
+)]
 async fn get_stats(State(state): State<Arc<AppState>>) -> Result<Json<Stats>, CustomError> {
     let system = state.system.read().await;
     let stats = system.get_stats().await.with_error_context(|error| {
@@ -70,6 +119,35 @@ async fn get_stats(State(state): State<Arc<AppState>>) -> Result<Json<Stats>, Cu
     Ok(Json(stats))
 }
 
+#[utoipa::path(
+    get,
+    path = "/stats/history",
+    tag = "System",
+    responses((status = 200, description = "The short and long term series of the sampled server statistics.", body = StatsHistory))
+)]
+async fn get_stats_history(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<StatsHistory>, CustomError> {
+    let system = state.system.read().await;
+    let stats_history = system
+        .get_stats_history(&Session::stateless(identity.user_id, identity.ip_address))
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get stats history, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(stats_history))
+}
+
+#[utoipa::path(
+    get,
+    path = "/clients/{client_id}",
+    tag = "System",
+    params(("client_id" = u32, Path, description = "The ID of the client.")),
+    responses((status = 200, description = "The client details.", body = ClientInfoDetails))
+)]
 async fn get_client(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -100,6 +178,12 @@ async fn get_client(
     Ok(Json(client))
 }
 
+#[utoipa::path(
+    get,
+    path = "/clients",
+    tag = "System",
+    responses((status = 200, description = "The connected clients.", body = Vec<ClientInfo>))
+)]
 async fn get_clients(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -118,6 +202,42 @@ async fn get_clients(
     Ok(Json(clients))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/clients/{client_id}",
+    tag = "System",
+    params(("client_id" = u32, Path, description = "The ID of the client.")),
+    responses((status = 204, description = "The client has been disconnected."))
+)]
+async fn disconnect_client(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(client_id): Path<u32>,
+) -> Result<StatusCode, CustomError> {
+    let system = state.system.read().await;
+    system
+        .disconnect_client(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            client_id,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to disconnect client with ID: {client_id}, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/snapshot",
+    tag = "System",
+    responses(
+        (status = 200, description = "The ZIP archive with the system snapshot.", content_type = "application/zip", body = [u8])
+    )
+)]
 async fn get_snapshot(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -126,10 +246,18 @@ async fn get_snapshot(
     command.validate()?;
 
     let session = Session::stateless(identity.user_id, identity.ip_address);
-    let system = state.system.read().await;
-
-    let snapshot = system
-        .get_snapshot(&session, command.compression, &command.snapshot_types)
+    let snapshot_types = state
+        .system
+        .read()
+        .await
+        .ensure_can_get_snapshot(&session, &command.snapshot_types)?;
+    // The CPU is sampled for a few seconds, so the profile is captured without holding the system lock.
+    let cpu_profile = snapshot::capture_cpu_profile(&snapshot_types).await;
+    let snapshot = state
+        .system
+        .read()
+        .await
+        .get_snapshot(command.compression, &snapshot_types, cpu_profile)
         .await?;
 
     let zip_data = Bytes::from(snapshot.0);
@@ -146,3 +274,127 @@ async fn get_snapshot(
     );
     Ok((headers, Body::from(zip_data)))
 }
+
+#[utoipa::path(
+    post,
+    path = "/config/reload",
+    tag = "System",
+    responses((status = 200, description = "The changed settings, applied and requiring a restart.", body = ConfigReload))
+)]
+async fn reload_config(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<ConfigReload>, CustomError> {
+    let system = state.system.read().await;
+    let config_reload = system
+        .reload_config(&Session::stateless(identity.user_id, identity.ip_address))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to reload config, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(config_reload))
+}
+
+#[utoipa::path(
+    get,
+    path = "/system/logging",
+    tag = "System",
+    responses((status = 200, description = "The active log filter, along with the default one.", body = LogFilter))
+)]
+async fn get_log_filter(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<LogFilter>, CustomError> {
+    let system = state.system.read().await;
+    let log_filter = system
+        .get_log_filter(&Session::stateless(identity.user_id, identity.ip_address))
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get log filter, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(log_filter))
+}
+
+#[utoipa::path(
+    put,
+    path = "/system/logging",
+    tag = "System",
+    responses((status = 200, description = "The changed log filter, along with the default one.", body = LogFilter))
+)]
+async fn set_log_filter(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<SetLogFilter>,
+) -> Result<Json<LogFilter>, CustomError> {
+    command.validate()?;
+    let system = state.system.read().await;
+    let log_filter = system
+        .set_log_filter(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            command.filter.as_deref(),
+            command.ttl,
+        )
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to set log filter, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    Ok(Json(log_filter))
+}
+
+#[utoipa::path(
+    post,
+    path = "/system/profile",
+    tag = "System",
+    responses(
+        (status = 200, description = "The captured profile, pprof protobuf or flamegraph SVG for the CPU, jemalloc dump for the heap.", content_type = "application/octet-stream", body = [u8])
+    )
+)]
+async fn get_profile(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<GetProfile>,
+) -> Result<impl IntoResponse, CustomError> {
+    command.validate()?;
+    state
+        .system
+        .read()
+        .await
+        .ensure_can_get_profile(&Session::stateless(identity.user_id, identity.ip_address))?;
+
+    let profile = profiler::capture(command.kind, command.format, command.duration)
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get profile, user ID: {}",
+                identity.user_id
+            )
+        })?;
+    let filename = format!(
+        "iggy_{}_profile_{}.{}",
+        command.kind,
+        Local::now().format("%Y%m%d_%H%M%S"),
+        command.format.file_extension(command.kind)
+    );
+    let content_type = match (command.kind, command.format) {
+        (ProfileKind::Cpu, ProfileFormat::Flamegraph) => "image/svg+xml",
+        _ => "application/octet-stream",
+    };
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static(content_type),
+    );
+    headers.insert(
+        header::CONTENT_DISPOSITION,
+        header::HeaderValue::from_str(&format!("attachment; filename=\"{filename}\"")).unwrap(),
+    );
+    Ok((headers, Body::from(profile.0)))
+}
@@ -20,14 +20,14 @@ use crate::http::COMPONENT;
 use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
+use crate::http::openapi::{StreamPath, TopicPath};
 use crate::http::shared::AppState;
 use crate::state::command::EntryCommand;
 use crate::state::models::CreateTopicWithId;
 use crate::streaming::session::Session;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::routing::{delete, get};
-use axum::{Extension, Json, Router};
+use axum::{Extension, Json};
 use error_set::ErrContext;
 use iggy_common::Identifier;
 use iggy_common::Validatable;
@@ -38,24 +38,23 @@ use iggy_common::update_topic::UpdateTopic;
 use iggy_common::{Topic, TopicDetails};
 use std::sync::Arc;
 use tracing::instrument;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
 
-pub fn router(state: Arc<AppState>) -> Router {
-    Router::new()
-        .route(
-            "/streams/{stream_id}/topics",
-            get(get_topics).post(create_topic),
-        )
-        .route(
-            "/streams/{stream_id}/topics/{topic_id}",
-            get(get_topic).put(update_topic).delete(delete_topic),
-        )
-        .route(
-            "/streams/{stream_id}/topics/{topic_id}/purge",
-            delete(purge_topic),
-        )
-        .with_state(state)
+pub fn router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_topics, create_topic))
+        .routes(routes!(get_topic, update_topic, delete_topic))
+        .routes(routes!(purge_topic))
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics/{topic_id}",
+    tag = "Topics",
+    params(TopicPath),
+    responses((status = 200, description = "The topic details.", body = TopicDetails))
+)]
 async fn get_topic(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -75,10 +74,18 @@ async fn get_topic(
         return Err(CustomError::ResourceNotFound);
     };
 
-    let topic = mapper::map_topic(topic).await;
+    let stream_message_expiry = system.get_stream_by_id(topic.stream_id)?.message_expiry;
+    let topic = mapper::map_topic(topic, stream_message_expiry).await;
     Ok(Json(topic))
 }
 
+#[utoipa::path(
+    get,
+    path = "/streams/{stream_id}/topics",
+    tag = "Topics",
+    params(StreamPath),
+    responses((status = 200, description = "The topics of the stream.", body = Vec<Topic>))
+)]
 async fn get_topics(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -96,10 +103,18 @@ async fn get_topics(
                 "{COMPONENT} (error: {error}) - failed to find topics for stream with ID: {stream_id}"
             )
         })?;
-    let topics = mapper::map_topics(&topics);
+    let stream_message_expiry = system.get_stream(&stream_id)?.message_expiry;
+    let topics = mapper::map_topics(&topics, stream_message_expiry);
     Ok(Json(topics))
 }
 
+#[utoipa::path(
+    post,
+    path = "/streams/{stream_id}/topics",
+    tag = "Topics",
+    params(StreamPath),
+    responses((status = 200, description = "The created topic.", body = TopicDetails))
+)]
 #[instrument(skip_all, name = "trace_create_topic", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id))]
 async fn create_topic(
     State(state): State<Arc<AppState>>,
@@ -130,9 +145,12 @@ async fn create_topic(
     command.message_expiry = topic.message_expiry;
     command.max_topic_size = topic.max_topic_size;
     let topic_id = topic.topic_id;
-    let response = Json(mapper::map_topic(topic).await);
+    let topic_stream_id = topic.stream_id;
 
     let system = system.downgrade();
+    let stream = system.get_stream_by_id(topic_stream_id)?;
+    let topic = stream.get_topic(&Identifier::numeric(topic_id)?)?;
+    let response = Json(mapper::map_topic(topic, stream.message_expiry).await);
     system
         .state
         .apply(identity.user_id, &EntryCommand::CreateTopic(CreateTopicWithId {
@@ -148,6 +166,13 @@ async fn create_topic(
     Ok(response)
 }
 
+#[utoipa::path(
+    put,
+    path = "/streams/{stream_id}/topics/{topic_id}",
+    tag = "Topics",
+    params(TopicPath),
+    responses((status = 204, description = "The topic has been updated."))
+)]
 #[instrument(skip_all, name = "trace_update_topic", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn update_topic(
     State(state): State<Arc<AppState>>,
@@ -193,6 +218,13 @@ async fn update_topic(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}",
+    tag = "Topics",
+    params(TopicPath),
+    responses((status = 204, description = "The topic has been deleted."))
+)]
 #[instrument(skip_all, name = "trace_delete_topic", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn delete_topic(
     State(state): State<Arc<AppState>>,
@@ -235,6 +267,13 @@ async fn delete_topic(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/streams/{stream_id}/topics/{topic_id}/purge",
+    tag = "Topics",
+    params(TopicPath),
+    responses((status = 204, description = "The topic has been purged."))
+)]
 #[instrument(skip_all, name = "trace_purge_topic", fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn purge_topic(
     State(state): State<Arc<AppState>>,
@@ -21,21 +21,24 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::mapper::map_generated_access_token_to_identity_info;
-use crate::http::shared::AppState;
+use crate::http::openapi::UserPath;
+use crate::http::shared::{AppState, RequestDetails};
 use crate::state::command::EntryCommand;
 use crate::state::models::CreateUserWithId;
 use crate::streaming::session::Session;
+use crate::streaming::users::login_limiter::LoginSource;
 use crate::streaming::utils::crypto;
 use ::iggy_common::change_password::ChangePassword;
 use ::iggy_common::create_user::CreateUser;
 use ::iggy_common::delete_user::DeleteUser;
 use ::iggy_common::login_user::LoginUser;
 use ::iggy_common::update_permissions::UpdatePermissions;
+use ::iggy_common::update_personal_access_token_quota::UpdatePersonalAccessTokenQuota;
 use ::iggy_common::update_user::UpdateUser;
+use ::iggy_common::update_user_quota::UpdateUserQuota;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::routing::{delete, get, post, put};
-use axum::{Extension, Json, Router};
+use axum::{Extension, Json};
 use error_set::ErrContext;
 use iggy_common::Identifier;
 use iggy_common::IdentityInfo;
@@ -44,22 +47,31 @@ use iggy_common::{UserInfo, UserInfoDetails};
 use serde::Deserialize;
 use std::sync::Arc;
 use tracing::instrument;
+use utoipa::ToSchema;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
 
-pub fn router(state: Arc<AppState>) -> Router {
-    Router::new()
-        .route("/users", get(get_users).post(create_user))
-        .route(
-            "/users/{user_id}",
-            get(get_user).put(update_user).delete(delete_user),
-        )
-        .route("/users/{user_id}/permissions", put(update_permissions))
-        .route("/users/{user_id}/password", put(change_password))
-        .route("/users/login", post(login_user))
-        .route("/users/logout", delete(logout_user))
-        .route("/users/refresh-token", post(refresh_token))
-        .with_state(state)
+pub fn router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_users, create_user))
+        .routes(routes!(get_user, update_user, delete_user))
+        .routes(routes!(update_permissions))
+        .routes(routes!(update_user_quota))
+        .routes(routes!(update_personal_access_token_quota))
+        .routes(routes!(change_password))
+        .routes(routes!(revoke_user_sessions))
+        .routes(routes!(login_user))
+        .routes(routes!(logout_user))
+        .routes(routes!(refresh_token))
 }
 
+#[utoipa::path(
+    get,
+    path = "/users/{user_id}",
+    tag = "Users",
+    params(UserPath),
+    responses((status = 200, description = "The user details.", body = UserInfoDetails))
+)]
 async fn get_user(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -81,6 +93,12 @@ async fn get_user(
     Ok(Json(user))
 }
 
+#[utoipa::path(
+    get,
+    path = "/users",
+    tag = "Users",
+    responses((status = 200, description = "The users.", body = Vec<UserInfo>))
+)]
 async fn get_users(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -99,6 +117,12 @@ async fn get_users(
     Ok(Json(users))
 }
 
+#[utoipa::path(
+    post,
+    path = "/users",
+    tag = "Users",
+    responses((status = 200, description = "The created user.", body = UserInfoDetails))
+)]
 #[instrument(skip_all, name = "trace_create_user", fields(iggy_user_id = identity.user_id))]
 async fn create_user(
     State(state): State<Arc<AppState>>,
@@ -153,6 +177,13 @@ async fn create_user(
     Ok(response)
 }
 
+#[utoipa::path(
+    put,
+    path = "/users/{user_id}",
+    tag = "Users",
+    params(UserPath),
+    responses((status = 204, description = "The user has been updated."))
+)]
 #[instrument(skip_all, name = "trace_update_user", fields(iggy_user_id = identity.user_id, iggy_updated_user_id = user_id))]
 async fn update_user(
     State(state): State<Arc<AppState>>,
@@ -189,6 +220,13 @@ async fn update_user(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    put,
+    path = "/users/{user_id}/permissions",
+    tag = "Users",
+    params(UserPath),
+    responses((status = 204, description = "The user permissions have been updated."))
+)]
 #[instrument(skip_all, name = "trace_update_permissions", fields(iggy_user_id = identity.user_id, iggy_updated_user_id = user_id))]
 async fn update_permissions(
     State(state): State<Arc<AppState>>,
@@ -226,6 +264,107 @@ async fn update_permissions(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    put,
+    path = "/users/{user_id}/quota",
+    tag = "Users",
+    params(UserPath),
+    responses((status = 204, description = "The user quota has been updated."))
+)]
+#[instrument(skip_all, name = "trace_update_user_quota", fields(iggy_user_id = identity.user_id, iggy_updated_user_id = user_id))]
+async fn update_user_quota(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(user_id): Path<String>,
+    Json(mut command): Json<UpdateUserQuota>,
+) -> Result<StatusCode, CustomError> {
+    command.user_id = Identifier::from_str_value(&user_id)?;
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    system
+        .update_user_quota(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.user_id,
+            command.quota,
+        )
+        .await
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to update quota, user ID: {user_id}")
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(identity.user_id, &EntryCommand::UpdateUserQuota(command))
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply update quota, user ID: {user_id}"
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    put,
+    path = "/users/{user_id}/personal-access-tokens/{name}/quota",
+    tag = "Users",
+    params(
+        UserPath,
+        ("name" = String, Path, description = "The name of the personal access token."),
+    ),
+    responses((status = 204, description = "The personal access token quota has been updated."))
+)]
+#[instrument(skip_all, name = "trace_update_personal_access_token_quota", fields(iggy_user_id = identity.user_id, iggy_updated_user_id = user_id))]
+async fn update_personal_access_token_quota(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((user_id, name)): Path<(String, String)>,
+    Json(mut command): Json<UpdatePersonalAccessTokenQuota>,
+) -> Result<StatusCode, CustomError> {
+    command.user_id = Identifier::from_str_value(&user_id)?;
+    command.name = name;
+    command.validate()?;
+
+    let mut system = state.system.write().await;
+    system
+        .update_personal_access_token_quota(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.user_id,
+            &command.name,
+            command.quota,
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to update personal access token quota, user ID: {user_id}"
+            )
+        })?;
+
+    let system = system.downgrade();
+    system
+        .state
+        .apply(
+            identity.user_id,
+            &EntryCommand::UpdatePersonalAccessTokenQuota(command),
+        )
+        .await
+        .with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to apply update personal access token quota, user ID: {user_id}"
+            )
+        })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    put,
+    path = "/users/{user_id}/password",
+    tag = "Users",
+    params(UserPath),
+    responses((status = 204, description = "The password has been changed."))
+)]
 #[instrument(skip_all, name = "trace_change_password", fields(iggy_user_id = identity.user_id, iggy_updated_user_id = user_id))]
 async fn change_password(
     State(state): State<Arc<AppState>>,
@@ -270,6 +409,13 @@ async fn change_password(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    delete,
+    path = "/users/{user_id}",
+    tag = "Users",
+    params(UserPath),
+    responses((status = 204, description = "The user has been deleted."))
+)]
 #[instrument(skip_all, name = "trace_delete_user", fields(iggy_user_id = identity.user_id, iggy_deleted_user_id = user_id))]
 async fn delete_user(
     State(state): State<Arc<AppState>>,
@@ -305,26 +451,82 @@ async fn delete_user(
     Ok(StatusCode::NO_CONTENT)
 }
 
-#[instrument(skip_all, name = "trace_login_user")]
-async fn login_user(
+#[utoipa::path(
+    delete,
+    path = "/users/{user_id}/sessions",
+    tag = "Users",
+    params(UserPath),
+    responses((status = 204, description = "The user sessions have been revoked."))
+)]
+#[instrument(skip_all, name = "trace_revoke_user_sessions", fields(iggy_user_id = identity.user_id, iggy_revoked_user_id = user_id))]
+async fn revoke_user_sessions(
     State(state): State<Arc<AppState>>,
-    Json(command): Json<LoginUser>,
-) -> Result<Json<IdentityInfo>, CustomError> {
-    command.validate()?;
+    Extension(identity): Extension<Identity>,
+    Path(user_id): Path<String>,
+) -> Result<StatusCode, CustomError> {
+    let identifier_user_id = Identifier::from_str_value(&user_id)?;
+
     let system = state.system.read().await;
-    let user = system
-        .login_user(&command.username, &command.password, None)
+    system
+        .revoke_user_sessions(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &identifier_user_id,
+        )
         .await
         .with_error_context(|error| {
             format!(
-                "{COMPONENT} (error: {error}) - failed to login, username: {}",
-                command.username
+                "{COMPONENT} (error: {error}) - failed to revoke sessions of user with ID: {user_id}"
             )
         })?;
-    let tokens = state.jwt_manager.generate(user.id)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/users/login",
+    tag = "Users",
+    security(()),
+    responses((status = 200, description = "The identity of the user, with the access token.", body = IdentityInfo))
+)]
+#[instrument(skip_all, name = "trace_login_user")]
+async fn login_user(
+    State(state): State<Arc<AppState>>,
+    Extension(request_details): Extension<RequestDetails>,
+    Json(command): Json<LoginUser>,
+) -> Result<Json<IdentityInfo>, CustomError> {
+    command.validate()?;
+    let (user_id, should_rehash_password) = {
+        let system = state.system.read().await;
+        let user = system
+            .login_user(
+                &command.username,
+                &command.password,
+                LoginSource::Ip(request_details.ip_address.ip()),
+                None,
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to login, username: {}",
+                    command.username
+                )
+            })?;
+        (user.id, system.should_rehash_password(user))
+    };
+    if should_rehash_password {
+        let mut system = state.system.write().await;
+        system.rehash_password(user_id, &command.password).await;
+    }
+    let tokens = state.jwt_manager.generate(user_id)?;
     Ok(Json(map_generated_access_token_to_identity_info(tokens)))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/users/logout",
+    tag = "Users",
+    responses((status = 204, description = "The user has been logged out."))
+)]
 #[instrument(skip_all, name = "trace_logout_user", fields(iggy_user_id = identity.user_id))]
 async fn logout_user(
     State(state): State<Arc<AppState>>,
@@ -353,6 +555,13 @@ async fn logout_user(
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/users/refresh-token",
+    tag = "Users",
+    security(()),
+    responses((status = 200, description = "The identity of the user, with the new access token.", body = IdentityInfo))
+)]
 async fn refresh_token(
     State(state): State<Arc<AppState>>,
     Json(command): Json<RefreshToken>,
@@ -367,7 +576,7 @@ async fn refresh_token(
     Ok(Json(map_generated_access_token_to_identity_info(token)))
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 struct RefreshToken {
     token: String,
 }
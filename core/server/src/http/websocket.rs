@@ -0,0 +1,100 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::http::HttpWebSocketConfig;
+use crate::http::COMPONENT;
+use crate::http::shared::AppState;
+use crate::websocket::websocket_listener;
+use axum::Router;
+use axum::body::Body;
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::{HeaderMap, HeaderName, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use iggy_common::websocket::{WEBSOCKET_VERSION, accept_key};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tracing::warn;
+
+/// The WebSocket endpoint is registered separately from the REST API, as the binary protocol
+/// has its own authentication and doesn't use the JWT.
+pub fn router(state: Arc<AppState>, config: &HttpWebSocketConfig, max_frame_size: usize) -> Router {
+    Router::new()
+        .route(
+            &config.path,
+            get(
+                move |State(state): State<Arc<AppState>>,
+                      ConnectInfo(address): ConnectInfo<SocketAddr>,
+                      request: Request| async move {
+                    upgrade(state, address, request, max_frame_size)
+                },
+            ),
+        )
+        .with_state(state)
+}
+
+fn upgrade(
+    state: Arc<AppState>,
+    address: SocketAddr,
+    mut request: Request,
+    max_frame_size: usize,
+) -> Response {
+    let headers = request.headers();
+    if !header_contains(headers, header::CONNECTION, "upgrade")
+        || !header_contains(headers, header::UPGRADE, "websocket")
+    {
+        warn!("{COMPONENT} - invalid WebSocket upgrade request from: {address}");
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    if !header_contains(headers, header::SEC_WEBSOCKET_VERSION, WEBSOCKET_VERSION) {
+        return (
+            StatusCode::UPGRADE_REQUIRED,
+            [(header::SEC_WEBSOCKET_VERSION, WEBSOCKET_VERSION)],
+        )
+            .into_response();
+    }
+
+    let Some(key) = headers
+        .get(header::SEC_WEBSOCKET_KEY)
+        .and_then(|key| key.to_str().ok())
+    else {
+        warn!("{COMPONENT} - missing WebSocket key in the upgrade request from: {address}");
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+
+    let accept = accept_key(key);
+    let upgrade = hyper::upgrade::on(&mut request);
+    websocket_listener::start(state.system.clone(), address, upgrade, max_frame_size);
+    Response::builder()
+        .status(StatusCode::SWITCHING_PROTOCOLS)
+        .header(header::CONNECTION, "Upgrade")
+        .header(header::UPGRADE, "websocket")
+        .header(header::SEC_WEBSOCKET_ACCEPT, accept)
+        .body(Body::empty())
+        .unwrap()
+}
+
+fn header_contains(headers: &HeaderMap, name: HeaderName, expected: &str) -> bool {
+    headers
+        .get_all(name)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|value| value.trim().eq_ignore_ascii_case(expected))
+}
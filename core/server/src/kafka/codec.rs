@@ -0,0 +1,305 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use iggy_common::IggyError;
+
+/// Reader of the primitive types of the Kafka protocol (non-flexible versions), all big-endian.
+#[derive(Debug)]
+pub struct KafkaReader {
+    buffer: Bytes,
+}
+
+impl KafkaReader {
+    pub fn new(buffer: Bytes) -> Self {
+        Self { buffer }
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.buffer.remaining()
+    }
+
+    fn ensure(&self, length: usize) -> Result<(), IggyError> {
+        if self.buffer.remaining() < length {
+            return Err(IggyError::InvalidFormat);
+        }
+        Ok(())
+    }
+
+    pub fn read_i8(&mut self) -> Result<i8, IggyError> {
+        self.ensure(1)?;
+        Ok(self.buffer.get_i8())
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool, IggyError> {
+        Ok(self.read_i8()? != 0)
+    }
+
+    pub fn read_i16(&mut self) -> Result<i16, IggyError> {
+        self.ensure(2)?;
+        Ok(self.buffer.get_i16())
+    }
+
+    pub fn read_i32(&mut self) -> Result<i32, IggyError> {
+        self.ensure(4)?;
+        Ok(self.buffer.get_i32())
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, IggyError> {
+        self.ensure(4)?;
+        Ok(self.buffer.get_u32())
+    }
+
+    pub fn read_i64(&mut self) -> Result<i64, IggyError> {
+        self.ensure(8)?;
+        Ok(self.buffer.get_i64())
+    }
+
+    pub fn read_string(&mut self) -> Result<String, IggyError> {
+        self.read_nullable_string()?.ok_or(IggyError::InvalidFormat)
+    }
+
+    pub fn read_nullable_string(&mut self) -> Result<Option<String>, IggyError> {
+        let length = self.read_i16()?;
+        if length < 0 {
+            return Ok(None);
+        }
+
+        let bytes = self.read_raw(length as usize)?;
+        String::from_utf8(bytes.to_vec())
+            .map(Some)
+            .map_err(|_| IggyError::InvalidFormat)
+    }
+
+    pub fn read_bytes(&mut self) -> Result<Bytes, IggyError> {
+        Ok(self.read_nullable_bytes()?.unwrap_or_default())
+    }
+
+    pub fn read_nullable_bytes(&mut self) -> Result<Option<Bytes>, IggyError> {
+        let length = self.read_i32()?;
+        if length < 0 {
+            return Ok(None);
+        }
+
+        self.read_raw(length as usize).map(Some)
+    }
+
+    pub fn read_raw(&mut self, length: usize) -> Result<Bytes, IggyError> {
+        self.ensure(length)?;
+        Ok(self.buffer.split_to(length))
+    }
+
+    pub fn read_array<T>(
+        &mut self,
+        read: impl FnMut(&mut Self) -> Result<T, IggyError>,
+    ) -> Result<Vec<T>, IggyError> {
+        Ok(self.read_nullable_array(read)?.unwrap_or_default())
+    }
+
+    pub fn read_nullable_array<T>(
+        &mut self,
+        mut read: impl FnMut(&mut Self) -> Result<T, IggyError>,
+    ) -> Result<Option<Vec<T>>, IggyError> {
+        let length = self.read_i32()?;
+        if length < 0 {
+            return Ok(None);
+        }
+
+        // Every element takes at least a single byte, which protects from the huge allocations.
+        self.ensure(length as usize)?;
+        let mut items = Vec::with_capacity(length as usize);
+        for _ in 0..length {
+            items.push(read(self)?);
+        }
+        Ok(Some(items))
+    }
+
+    pub fn read_varint(&mut self) -> Result<i32, IggyError> {
+        let value = self.read_unsigned_varint(5)?;
+        Ok(((value >> 1) as i32) ^ -((value & 1) as i32))
+    }
+
+    pub fn read_varlong(&mut self) -> Result<i64, IggyError> {
+        let value = self.read_unsigned_varint(10)?;
+        Ok(((value >> 1) as i64) ^ -((value & 1) as i64))
+    }
+
+    fn read_unsigned_varint(&mut self, max_bytes: usize) -> Result<u64, IggyError> {
+        let mut value = 0u64;
+        for index in 0..max_bytes {
+            let byte = self.read_i8()? as u8;
+            value |= ((byte & 0x7f) as u64) << (index * 7);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(IggyError::InvalidFormat)
+    }
+}
+
+/// Writer of the primitive types of the Kafka protocol (non-flexible versions), all big-endian.
+#[derive(Debug, Default)]
+pub struct KafkaWriter {
+    buffer: BytesMut,
+}
+
+impl KafkaWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    pub fn into_bytes(self) -> Bytes {
+        self.buffer.freeze()
+    }
+
+    pub fn write_i8(&mut self, value: i8) {
+        self.buffer.put_i8(value);
+    }
+
+    pub fn write_bool(&mut self, value: bool) {
+        self.buffer.put_i8(value as i8);
+    }
+
+    pub fn write_i16(&mut self, value: i16) {
+        self.buffer.put_i16(value);
+    }
+
+    pub fn write_i32(&mut self, value: i32) {
+        self.buffer.put_i32(value);
+    }
+
+    pub fn write_u32(&mut self, value: u32) {
+        self.buffer.put_u32(value);
+    }
+
+    pub fn write_i64(&mut self, value: i64) {
+        self.buffer.put_i64(value);
+    }
+
+    pub fn write_string(&mut self, value: &str) {
+        self.buffer.put_i16(value.len() as i16);
+        self.buffer.put_slice(value.as_bytes());
+    }
+
+    pub fn write_nullable_string(&mut self, value: Option<&str>) {
+        match value {
+            Some(value) => self.write_string(value),
+            None => self.buffer.put_i16(-1),
+        }
+    }
+
+    pub fn write_bytes(&mut self, value: &[u8]) {
+        self.buffer.put_i32(value.len() as i32);
+        self.buffer.put_slice(value);
+    }
+
+    pub fn write_nullable_bytes(&mut self, value: Option<&[u8]>) {
+        match value {
+            Some(value) => self.write_bytes(value),
+            None => self.buffer.put_i32(-1),
+        }
+    }
+
+    pub fn write_raw(&mut self, value: &[u8]) {
+        self.buffer.put_slice(value);
+    }
+
+    pub fn write_array<T>(&mut self, items: &[T], mut write: impl FnMut(&mut Self, &T)) {
+        self.buffer.put_i32(items.len() as i32);
+        for item in items {
+            write(self, item);
+        }
+    }
+
+    pub fn write_varint(&mut self, value: i32) {
+        self.write_unsigned_varint(((value << 1) ^ (value >> 31)) as u32 as u64);
+    }
+
+    pub fn write_varlong(&mut self, value: i64) {
+        self.write_unsigned_varint(((value << 1) ^ (value >> 63)) as u64);
+    }
+
+    fn write_unsigned_varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buffer.put_u8((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+        self.buffer.put_u8(value as u8);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_encode_and_decode_primitives() {
+        let mut writer = KafkaWriter::new();
+        writer.write_i16(-2);
+        writer.write_i64(i64::MAX);
+        writer.write_string("iggy");
+        writer.write_nullable_string(None);
+        writer.write_nullable_bytes(Some(b"payload"));
+        writer.write_array(&[1, 2, 3], |writer, value| writer.write_i32(*value));
+        writer.write_varint(-1);
+        writer.write_varint(300);
+        writer.write_varlong(i64::MIN);
+
+        let mut reader = KafkaReader::new(writer.into_bytes());
+        assert_eq!(reader.read_i16().unwrap(), -2);
+        assert_eq!(reader.read_i64().unwrap(), i64::MAX);
+        assert_eq!(reader.read_string().unwrap(), "iggy");
+        assert_eq!(reader.read_nullable_string().unwrap(), None);
+        assert_eq!(reader.read_bytes().unwrap().as_ref(), b"payload");
+        assert_eq!(
+            reader.read_array(|reader| reader.read_i32()).unwrap(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(reader.read_varint().unwrap(), -1);
+        assert_eq!(reader.read_varint().unwrap(), 300);
+        assert_eq!(reader.read_varlong().unwrap(), i64::MIN);
+        assert_eq!(reader.remaining(), 0);
+    }
+
+    #[test]
+    fn should_fail_for_truncated_input() {
+        let mut writer = KafkaWriter::new();
+        writer.write_i16(10);
+        writer.write_raw(b"iggy");
+
+        let mut reader = KafkaReader::new(writer.into_bytes());
+        assert!(reader.read_string().is_err());
+    }
+
+    #[test]
+    fn should_encode_varint_as_zigzag() {
+        let mut writer = KafkaWriter::new();
+        writer.write_varint(1);
+        writer.write_varint(-2);
+        writer.write_varint(64);
+        assert_eq!(writer.into_bytes().as_ref(), &[2, 3, 0x80, 1]);
+    }
+}
@@ -0,0 +1,512 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::protocol::ErrorCode;
+use bytes::Bytes;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+use tracing::info;
+
+const SESSION_EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+const MIN_SESSION_TIMEOUT_MS: i32 = 1_000;
+const MAX_SESSION_TIMEOUT_MS: i32 = 1_800_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GroupState {
+    Empty,
+    PreparingRebalance,
+    CompletingRebalance,
+    Stable,
+}
+
+#[derive(Debug)]
+pub struct JoinGroupRequest {
+    pub group_id: String,
+    pub member_id: String,
+    pub client_id: String,
+    pub session_timeout_ms: i32,
+    pub rebalance_timeout_ms: i32,
+    pub protocol_type: String,
+    pub protocols: Vec<(String, Bytes)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct JoinGroupResult {
+    pub error: ErrorCode,
+    pub generation_id: i32,
+    pub protocol_name: String,
+    pub leader_id: String,
+    pub member_id: String,
+    /// Metadata of all the members, only returned to the leader, which computes the assignments.
+    pub members: Vec<(String, Bytes)>,
+}
+
+impl JoinGroupResult {
+    fn error(error: ErrorCode, member_id: &str) -> Self {
+        Self {
+            error,
+            generation_id: -1,
+            protocol_name: String::new(),
+            leader_id: String::new(),
+            member_id: member_id.to_owned(),
+            members: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Member {
+    session_timeout: Duration,
+    rebalance_timeout: Duration,
+    protocols: Vec<(String, Bytes)>,
+    last_heartbeat: Instant,
+    assignment: Bytes,
+    join_waiter: Option<oneshot::Sender<JoinGroupResult>>,
+    sync_waiter: Option<oneshot::Sender<(ErrorCode, Bytes)>>,
+}
+
+#[derive(Debug)]
+struct Group {
+    state: GroupState,
+    generation_id: i32,
+    protocol_type: Option<String>,
+    protocol_name: Option<String>,
+    leader_id: Option<String>,
+    members: BTreeMap<String, Member>,
+}
+
+impl Group {
+    fn new() -> Self {
+        Self {
+            state: GroupState::Empty,
+            generation_id: 0,
+            protocol_type: None,
+            protocol_name: None,
+            leader_id: None,
+            members: BTreeMap::new(),
+        }
+    }
+
+    fn join_result(&self, member_id: &str) -> JoinGroupResult {
+        let protocol_name = self.protocol_name.clone().unwrap_or_default();
+        let leader_id = self.leader_id.clone().unwrap_or_default();
+        let members = if leader_id == member_id {
+            self.members
+                .iter()
+                .map(|(id, member)| (id.clone(), member.metadata(&protocol_name)))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        JoinGroupResult {
+            error: ErrorCode::None,
+            generation_id: self.generation_id,
+            protocol_name,
+            leader_id,
+            member_id: member_id.to_owned(),
+            members,
+        }
+    }
+
+    /// Selects the first protocol of the leader, which is supported by all the members.
+    fn select_protocol(&self, leader_id: &str) -> Option<String> {
+        let leader = self.members.get(leader_id)?;
+        leader
+            .protocols
+            .iter()
+            .map(|(name, _)| name)
+            .find(|name| {
+                self.members
+                    .values()
+                    .all(|member| member.protocols.iter().any(|(other, _)| other == *name))
+            })
+            .cloned()
+    }
+
+    fn try_complete_join(&mut self) {
+        if self.state == GroupState::PreparingRebalance
+            && self
+                .members
+                .values()
+                .all(|member| member.join_waiter.is_some())
+        {
+            self.complete_join();
+        }
+    }
+
+    /// Completes the rebalance with the members, which have rejoined the group,
+    /// the remaining ones are removed from the group.
+    fn complete_join(&mut self) {
+        self.members
+            .retain(|_, member| member.join_waiter.is_some());
+        self.generation_id += 1;
+        let Some(leader_id) = self
+            .leader_id
+            .clone()
+            .filter(|leader_id| self.members.contains_key(leader_id))
+            .or_else(|| self.members.keys().next().cloned())
+        else {
+            self.reset();
+            return;
+        };
+
+        let Some(protocol_name) = self.select_protocol(&leader_id) else {
+            for member in self.members.values_mut() {
+                if let Some(waiter) = member.join_waiter.take() {
+                    let _ = waiter.send(JoinGroupResult::error(
+                        ErrorCode::InconsistentGroupProtocol,
+                        "",
+                    ));
+                }
+            }
+            self.members.clear();
+            self.reset();
+            return;
+        };
+
+        self.leader_id = Some(leader_id);
+        self.protocol_name = Some(protocol_name);
+        self.state = GroupState::CompletingRebalance;
+        let member_ids = self.members.keys().cloned().collect::<Vec<_>>();
+        for member_id in member_ids {
+            let result = self.join_result(&member_id);
+            let member = self.members.get_mut(&member_id).expect("Member must exist");
+            member.assignment = Bytes::new();
+            member.last_heartbeat = Instant::now();
+            if let Some(waiter) = member.join_waiter.take() {
+                let _ = waiter.send(result);
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.state = GroupState::Empty;
+        self.protocol_type = None;
+        self.protocol_name = None;
+        self.leader_id = None;
+    }
+
+    fn remove_member(&mut self, member_id: &str) -> bool {
+        if self.members.remove(member_id).is_none() {
+            return false;
+        }
+
+        if self.members.is_empty() {
+            self.generation_id += 1;
+            self.reset();
+        }
+        true
+    }
+}
+
+impl Member {
+    fn metadata(&self, protocol_name: &str) -> Bytes {
+        self.protocols
+            .iter()
+            .find(|(name, _)| name == protocol_name)
+            .map(|(_, metadata)| metadata.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// Coordinator of the Kafka consumer groups, which implements the group membership protocol,
+/// in which the leader of the group computes the partition assignments of all the members.
+/// The groups are kept in memory, while the committed offsets are stored as the Iggy consumer offsets.
+#[derive(Debug, Default)]
+pub struct GroupCoordinator {
+    groups: Mutex<HashMap<String, Group>>,
+}
+
+impl GroupCoordinator {
+    pub fn start(self: &Arc<Self>) {
+        let coordinator = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(SESSION_EXPIRY_CHECK_INTERVAL);
+            loop {
+                interval.tick().await;
+                coordinator.expire_members();
+            }
+        });
+    }
+
+    pub async fn join_group(self: &Arc<Self>, request: JoinGroupRequest) -> JoinGroupResult {
+        if !(MIN_SESSION_TIMEOUT_MS..=MAX_SESSION_TIMEOUT_MS).contains(&request.session_timeout_ms)
+        {
+            return JoinGroupResult::error(ErrorCode::InvalidSessionTimeout, &request.member_id);
+        }
+
+        let receiver = {
+            let mut groups = self.groups.lock().unwrap();
+            let group = groups
+                .entry(request.group_id.clone())
+                .or_insert_with(Group::new);
+            if group
+                .protocol_type
+                .as_ref()
+                .is_some_and(|protocol_type| *protocol_type != request.protocol_type)
+            {
+                return JoinGroupResult::error(
+                    ErrorCode::InconsistentGroupProtocol,
+                    &request.member_id,
+                );
+            }
+
+            let member_id = if request.member_id.is_empty() {
+                format!("{}-{}", request.client_id, uuid::Uuid::now_v7())
+            } else if group.members.contains_key(&request.member_id) {
+                request.member_id.clone()
+            } else {
+                return JoinGroupResult::error(ErrorCode::UnknownMemberId, &request.member_id);
+            };
+
+            // The member rejoining with the same metadata doesn't trigger the rebalance.
+            if matches!(
+                group.state,
+                GroupState::Stable | GroupState::CompletingRebalance
+            ) && group
+                .members
+                .get(&member_id)
+                .is_some_and(|member| member.protocols == request.protocols)
+            {
+                return group.join_result(&member_id);
+            }
+
+            let (sender, receiver) = oneshot::channel();
+            let rebalance_timeout_ms = request.rebalance_timeout_ms.max(request.session_timeout_ms);
+            let member = group.members.entry(member_id.clone()).or_insert(Member {
+                session_timeout: Duration::ZERO,
+                rebalance_timeout: Duration::ZERO,
+                protocols: Vec::new(),
+                last_heartbeat: Instant::now(),
+                assignment: Bytes::new(),
+                join_waiter: None,
+                sync_waiter: None,
+            });
+            member.session_timeout = Duration::from_millis(request.session_timeout_ms as u64);
+            member.rebalance_timeout = Duration::from_millis(rebalance_timeout_ms as u64);
+            member.protocols = request.protocols;
+            member.last_heartbeat = Instant::now();
+            member.join_waiter = Some(sender);
+            group.protocol_type = Some(request.protocol_type);
+            if group.state != GroupState::PreparingRebalance {
+                self.prepare_rebalance(&request.group_id, group);
+            }
+            group.try_complete_join();
+            receiver
+        };
+
+        receiver
+            .await
+            .unwrap_or_else(|_| JoinGroupResult::error(ErrorCode::UnknownMemberId, ""))
+    }
+
+    pub async fn sync_group(
+        &self,
+        group_id: &str,
+        generation_id: i32,
+        member_id: &str,
+        assignments: Vec<(String, Bytes)>,
+    ) -> (ErrorCode, Bytes) {
+        let receiver = {
+            let mut groups = self.groups.lock().unwrap();
+            let Some(group) = groups.get_mut(group_id) else {
+                return (ErrorCode::UnknownMemberId, Bytes::new());
+            };
+            if !group.members.contains_key(member_id) {
+                return (ErrorCode::UnknownMemberId, Bytes::new());
+            }
+            if group.generation_id != generation_id {
+                return (ErrorCode::IllegalGeneration, Bytes::new());
+            }
+
+            match group.state {
+                GroupState::Empty => return (ErrorCode::UnknownMemberId, Bytes::new()),
+                GroupState::PreparingRebalance => {
+                    return (ErrorCode::RebalanceInProgress, Bytes::new());
+                }
+                GroupState::Stable => {
+                    return (ErrorCode::None, group.members[member_id].assignment.clone());
+                }
+                GroupState::CompletingRebalance => {}
+            }
+
+            if group.leader_id.as_deref() == Some(member_id) {
+                for (id, assignment) in assignments {
+                    if let Some(member) = group.members.get_mut(&id) {
+                        member.assignment = assignment;
+                    }
+                }
+                group.state = GroupState::Stable;
+                info!(
+                    "Kafka consumer group: {group_id} is stable with {} member(s), generation: {generation_id}.",
+                    group.members.len()
+                );
+                for member in group.members.values_mut() {
+                    if let Some(waiter) = member.sync_waiter.take() {
+                        let _ = waiter.send((ErrorCode::None, member.assignment.clone()));
+                    }
+                }
+                return (ErrorCode::None, group.members[member_id].assignment.clone());
+            }
+
+            let (sender, receiver) = oneshot::channel();
+            let member = group.members.get_mut(member_id).expect("Member must exist");
+            member.sync_waiter = Some(sender);
+            receiver
+        };
+
+        receiver
+            .await
+            .unwrap_or((ErrorCode::RebalanceInProgress, Bytes::new()))
+    }
+
+    pub fn heartbeat(&self, group_id: &str, generation_id: i32, member_id: &str) -> ErrorCode {
+        let mut groups = self.groups.lock().unwrap();
+        let Some(group) = groups.get_mut(group_id) else {
+            return ErrorCode::UnknownMemberId;
+        };
+        let state = group.state;
+        let current_generation_id = group.generation_id;
+        let Some(member) = group.members.get_mut(member_id) else {
+            return ErrorCode::UnknownMemberId;
+        };
+        if current_generation_id != generation_id {
+            return ErrorCode::IllegalGeneration;
+        }
+
+        member.last_heartbeat = Instant::now();
+        if state == GroupState::PreparingRebalance {
+            return ErrorCode::RebalanceInProgress;
+        }
+        ErrorCode::None
+    }
+
+    pub fn leave_group(self: &Arc<Self>, group_id: &str, member_id: &str) -> ErrorCode {
+        let mut groups = self.groups.lock().unwrap();
+        let Some(group) = groups.get_mut(group_id) else {
+            return ErrorCode::UnknownMemberId;
+        };
+        if !group.remove_member(member_id) {
+            return ErrorCode::UnknownMemberId;
+        }
+
+        info!("Member: {member_id} has left the Kafka consumer group: {group_id}.");
+        self.rebalance_after_removal(group_id, group);
+        ErrorCode::None
+    }
+
+    /// Validates the member committing the offsets, the generation -1 is used by the consumers,
+    /// which assign the partitions manually, and is allowed only for the groups without members.
+    pub fn validate_offset_commit(
+        &self,
+        group_id: &str,
+        generation_id: i32,
+        member_id: &str,
+    ) -> ErrorCode {
+        let groups = self.groups.lock().unwrap();
+        let group = groups.get(group_id);
+        if generation_id < 0 {
+            return match group {
+                Some(group) if group.state != GroupState::Empty => ErrorCode::IllegalGeneration,
+                _ => ErrorCode::None,
+            };
+        }
+
+        let Some(group) = group else {
+            return ErrorCode::IllegalGeneration;
+        };
+        if !group.members.contains_key(member_id) {
+            return ErrorCode::UnknownMemberId;
+        }
+        if group.generation_id != generation_id {
+            return ErrorCode::IllegalGeneration;
+        }
+        if group.state == GroupState::PreparingRebalance {
+            return ErrorCode::RebalanceInProgress;
+        }
+        ErrorCode::None
+    }
+
+    fn prepare_rebalance(self: &Arc<Self>, group_id: &str, group: &mut Group) {
+        group.state = GroupState::PreparingRebalance;
+        for member in group.members.values_mut() {
+            if let Some(waiter) = member.sync_waiter.take() {
+                let _ = waiter.send((ErrorCode::RebalanceInProgress, Bytes::new()));
+            }
+        }
+
+        // The members which haven't rejoined within the rebalance timeout are removed from the group.
+        let rebalance_timeout = group
+            .members
+            .values()
+            .map(|member| member.rebalance_timeout)
+            .max()
+            .unwrap_or_default();
+        let generation_id = group.generation_id;
+        let coordinator = self.clone();
+        let group_id = group_id.to_owned();
+        tokio::spawn(async move {
+            tokio::time::sleep(rebalance_timeout).await;
+            let mut groups = coordinator.groups.lock().unwrap();
+            if let Some(group) = groups.get_mut(&group_id)
+                && group.state == GroupState::PreparingRebalance
+                && group.generation_id == generation_id
+            {
+                group.complete_join();
+            }
+        });
+    }
+
+    fn rebalance_after_removal(self: &Arc<Self>, group_id: &str, group: &mut Group) {
+        if group.state == GroupState::Empty {
+            return;
+        }
+        if group.state != GroupState::PreparingRebalance {
+            self.prepare_rebalance(group_id, group);
+        }
+        group.try_complete_join();
+    }
+
+    fn expire_members(self: &Arc<Self>) {
+        let mut groups = self.groups.lock().unwrap();
+        for (group_id, group) in groups.iter_mut() {
+            let expired = group
+                .members
+                .iter()
+                .filter(|(_, member)| {
+                    member.join_waiter.is_none()
+                        && member.last_heartbeat.elapsed() > member.session_timeout
+                })
+                .map(|(member_id, _)| member_id.clone())
+                .collect::<Vec<_>>();
+            if expired.is_empty() {
+                continue;
+            }
+
+            for member_id in expired {
+                info!("Member: {member_id} of the Kafka consumer group: {group_id} has expired.");
+                group.remove_member(&member_id);
+            }
+            self.rebalance_after_removal(group_id, group);
+        }
+        groups.retain(|_, group| group.state != GroupState::Empty || !group.members.is_empty());
+    }
+}
@@ -0,0 +1,34 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::codec::KafkaWriter;
+use crate::kafka::protocol::{ApiKey, ErrorCode};
+
+/// Writes the supported APIs, the response is also sent in the v0 format for the unsupported versions.
+pub fn handle(version: i16, error: ErrorCode, writer: &mut KafkaWriter) {
+    writer.write_i16(error.code());
+    writer.write_array(&ApiKey::ALL, |writer, api_key| {
+        let (min_version, max_version) = api_key.versions();
+        writer.write_i16(*api_key as i16);
+        writer.write_i16(min_version);
+        writer.write_i16(max_version);
+    });
+    if version >= 1 {
+        writer.write_i32(0);
+    }
+}
@@ -0,0 +1,274 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::codec::{KafkaReader, KafkaWriter};
+use crate::kafka::handlers::{
+    KEY_HEADER, KafkaConnection, KafkaContext, get_partition_offsets, resolve_topic_ids,
+    to_partition_id,
+};
+use crate::kafka::protocol::ErrorCode;
+use crate::kafka::records::{self, KafkaHeader, KafkaRecord};
+use crate::streaming::session::Session;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::System;
+use bytes::Bytes;
+use iggy_common::{Consumer, IggyError, IggyMessage, PollingStrategy};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// The maximum number of messages polled from a single partition in one fetch.
+const MAX_MESSAGES_PER_POLL: u32 = 1000;
+/// The interval of polling the partitions, while waiting for the minimum amount of data.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+/// The approximate size of the record metadata (offset, timestamp, lengths) in the batch.
+const RECORD_OVERHEAD: usize = 32;
+
+struct FetchPartition {
+    index: i32,
+    fetch_offset: i64,
+    max_bytes: i32,
+}
+
+struct FetchedPartition {
+    index: i32,
+    error: ErrorCode,
+    high_watermark: i64,
+    log_start_offset: i64,
+    records: Bytes,
+}
+
+/// Returns the records of the requested partitions. The request waits up to `max_wait_ms`
+/// for at least `min_bytes` of data, the fetch sessions are not supported (full fetches only).
+pub async fn handle(
+    context: &Arc<KafkaContext>,
+    connection: &KafkaConnection,
+    version: i16,
+    reader: &mut KafkaReader,
+    writer: &mut KafkaWriter,
+) -> Result<(), IggyError> {
+    let _replica_id = reader.read_i32()?;
+    let max_wait_ms = reader.read_i32()?;
+    let min_bytes = reader.read_i32()?;
+    let _max_bytes = reader.read_i32()?;
+    let _isolation_level = reader.read_i8()?;
+    if version >= 7 {
+        let _session_id = reader.read_i32()?;
+        let _session_epoch = reader.read_i32()?;
+    }
+    let topics = reader.read_array(|reader| {
+        let name = reader.read_string()?;
+        let partitions = reader.read_array(|reader| {
+            let index = reader.read_i32()?;
+            let fetch_offset = reader.read_i64()?;
+            if version >= 5 {
+                let _log_start_offset = reader.read_i64()?;
+            }
+            let max_bytes = reader.read_i32()?;
+            Ok(FetchPartition {
+                index,
+                fetch_offset,
+                max_bytes,
+            })
+        })?;
+        Ok((name, partitions))
+    })?;
+    if version >= 7 {
+        let _forgotten_topics = reader.read_array(|reader| {
+            let _name = reader.read_string()?;
+            reader.read_array(|reader| reader.read_i32())
+        })?;
+    }
+
+    let deadline = Instant::now() + Duration::from_millis(max_wait_ms.max(0) as u64);
+    let responses = loop {
+        // The lock is not held while waiting, so that the producers can append the messages.
+        let system = context.system.read().await;
+        let mut fetched_bytes = 0;
+        let mut responses = Vec::with_capacity(topics.len());
+        for (name, partitions) in &topics {
+            let mut fetched = Vec::with_capacity(partitions.len());
+            for partition in partitions {
+                let partition =
+                    fetch_partition(&system, context, &connection.session, name, partition).await;
+                fetched_bytes += partition.records.len();
+                fetched.push(partition);
+            }
+            responses.push((name, fetched));
+        }
+        drop(system);
+
+        let now = Instant::now();
+        if fetched_bytes >= min_bytes.max(1) as usize || now >= deadline {
+            break responses;
+        }
+        tokio::time::sleep(POLL_INTERVAL.min(deadline - now)).await;
+    };
+
+    writer.write_i32(0);
+    if version >= 7 {
+        writer.write_i16(ErrorCode::None.code());
+        writer.write_i32(0);
+    }
+    writer.write_array(&responses, |writer, (name, partitions)| {
+        writer.write_string(name);
+        writer.write_array(partitions, |writer, partition| {
+            writer.write_i32(partition.index);
+            writer.write_i16(partition.error.code());
+            writer.write_i64(partition.high_watermark);
+            writer.write_i64(partition.high_watermark);
+            if version >= 5 {
+                writer.write_i64(partition.log_start_offset);
+            }
+            writer.write_i32(0);
+            writer.write_bytes(&partition.records);
+        });
+    });
+    Ok(())
+}
+
+async fn fetch_partition(
+    system: &System,
+    context: &KafkaContext,
+    session: &Session,
+    kafka_topic: &str,
+    partition: &FetchPartition,
+) -> FetchedPartition {
+    let mut fetched = FetchedPartition {
+        index: partition.index,
+        error: ErrorCode::None,
+        high_watermark: -1,
+        log_start_offset: -1,
+        records: Bytes::new(),
+    };
+    if let Err(error) = poll_records(
+        system,
+        context,
+        session,
+        kafka_topic,
+        partition,
+        &mut fetched,
+    )
+    .await
+    {
+        fetched.error = error;
+    }
+    fetched
+}
+
+async fn poll_records(
+    system: &System,
+    context: &KafkaContext,
+    session: &Session,
+    kafka_topic: &str,
+    partition: &FetchPartition,
+    fetched: &mut FetchedPartition,
+) -> Result<(), ErrorCode> {
+    let (stream_id, topic_id) =
+        resolve_topic_ids(context, kafka_topic).map_err(|error| ErrorCode::from(&error))?;
+    let topic = system
+        .find_topic(session, &stream_id, &topic_id)
+        .map_err(|error| ErrorCode::from(&error))?;
+    let (log_start_offset, high_watermark) = get_partition_offsets(topic, partition.index).await?;
+    fetched.log_start_offset = log_start_offset;
+    fetched.high_watermark = high_watermark;
+    if partition.fetch_offset < log_start_offset || partition.fetch_offset > high_watermark {
+        return Err(ErrorCode::OffsetOutOfRange);
+    }
+    if partition.fetch_offset == high_watermark {
+        return Ok(());
+    }
+
+    let partition_id =
+        to_partition_id(partition.index).ok_or(ErrorCode::UnknownTopicOrPartition)?;
+    let args = PollingArgs::new(
+        PollingStrategy::offset(partition.fetch_offset as u64),
+        MAX_MESSAGES_PER_POLL,
+        false,
+    );
+    let (metadata, batch_set) = system
+        .poll_messages(
+            session,
+            &Consumer::default(),
+            &stream_id,
+            &topic_id,
+            Some(partition_id),
+            args,
+        )
+        .await
+        .map_err(|error| ErrorCode::from(&error))?;
+
+    // At least one record is returned, even if it exceeds the limit, so that the consumer can progress.
+    let max_bytes = partition.max_bytes.max(0) as usize;
+    let mut size = 0;
+    let mut records = Vec::new();
+    for message in batch_set.into_polled_messages(metadata).messages {
+        let record = to_kafka_record(message);
+        size += record_size(&record);
+        if size > max_bytes && !records.is_empty() {
+            break;
+        }
+        records.push(record);
+    }
+    fetched.records = records::encode_record_batch(&records);
+    Ok(())
+}
+
+/// Converts the message to the record, restoring the key from the user headers.
+fn to_kafka_record(message: IggyMessage) -> KafkaRecord {
+    let mut key = None;
+    let mut headers = Vec::new();
+    if let Ok(Some(user_headers)) = message.user_headers_map() {
+        for (header_key, header_value) in user_headers {
+            let value = header_value.as_raw().ok().map(Bytes::copy_from_slice);
+            if header_key.as_str() == KEY_HEADER {
+                key = value;
+            } else {
+                headers.push(KafkaHeader {
+                    key: header_key.as_str().to_owned(),
+                    value,
+                });
+            }
+        }
+        headers.sort_by(|left, right| left.key.cmp(&right.key));
+    }
+
+    let timestamp = if message.header.origin_timestamp > 0 {
+        message.header.origin_timestamp
+    } else {
+        message.header.timestamp
+    };
+    KafkaRecord {
+        offset: message.header.offset as i64,
+        timestamp: (timestamp / 1000) as i64,
+        key,
+        value: Some(message.payload),
+        headers,
+    }
+}
+
+fn record_size(record: &KafkaRecord) -> usize {
+    RECORD_OVERHEAD
+        + record.key.as_ref().map_or(0, Bytes::len)
+        + record.value.as_ref().map_or(0, Bytes::len)
+        + record
+            .headers
+            .iter()
+            .map(|header| header.key.len() + header.value.as_ref().map_or(0, Bytes::len))
+            .sum::<usize>()
+}
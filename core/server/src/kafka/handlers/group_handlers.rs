@@ -0,0 +1,187 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::codec::{KafkaReader, KafkaWriter};
+use crate::kafka::coordinator::JoinGroupRequest;
+use crate::kafka::handlers::{BROKER_NODE_ID, KafkaConnection, KafkaContext};
+use crate::kafka::protocol::ErrorCode;
+use iggy_common::IggyError;
+use std::sync::Arc;
+
+const GROUP_KEY_TYPE: i8 = 0;
+
+/// The broker is the coordinator of all the groups, the transaction coordinator is not available.
+pub fn handle_find_coordinator(
+    context: &KafkaContext,
+    version: i16,
+    reader: &mut KafkaReader,
+    writer: &mut KafkaWriter,
+) -> Result<(), IggyError> {
+    let _key = reader.read_string()?;
+    let key_type = if version >= 1 {
+        reader.read_i8()?
+    } else {
+        GROUP_KEY_TYPE
+    };
+
+    let error = if key_type == GROUP_KEY_TYPE {
+        ErrorCode::None
+    } else {
+        ErrorCode::CoordinatorNotAvailable
+    };
+    if version >= 1 {
+        writer.write_i32(0);
+    }
+    writer.write_i16(error.code());
+    if version >= 1 {
+        writer.write_nullable_string(None);
+    }
+    if error == ErrorCode::None {
+        writer.write_i32(BROKER_NODE_ID);
+        writer.write_string(&context.broker_host);
+        writer.write_i32(context.broker_port);
+    } else {
+        writer.write_i32(-1);
+        writer.write_string("");
+        writer.write_i32(-1);
+    }
+    Ok(())
+}
+
+/// Joins the group, the response is sent once all the members have (re)joined the group.
+/// The static membership (group instance ID) is not supported, such members are treated as dynamic.
+pub async fn handle_join(
+    context: &KafkaContext,
+    connection: &KafkaConnection,
+    version: i16,
+    reader: &mut KafkaReader,
+    writer: &mut KafkaWriter,
+) -> Result<(), IggyError> {
+    let group_id = reader.read_string()?;
+    let session_timeout_ms = reader.read_i32()?;
+    let rebalance_timeout_ms = if version >= 1 {
+        reader.read_i32()?
+    } else {
+        session_timeout_ms
+    };
+    let member_id = reader.read_string()?;
+    if version >= 5 {
+        let _group_instance_id = reader.read_nullable_string()?;
+    }
+    let protocol_type = reader.read_string()?;
+    let protocols =
+        reader.read_array(|reader| Ok((reader.read_string()?, reader.read_bytes()?)))?;
+
+    let result = context
+        .coordinator
+        .join_group(JoinGroupRequest {
+            group_id,
+            member_id,
+            client_id: connection.client_id.clone(),
+            session_timeout_ms,
+            rebalance_timeout_ms,
+            protocol_type,
+            protocols,
+        })
+        .await;
+
+    if version >= 2 {
+        writer.write_i32(0);
+    }
+    writer.write_i16(result.error.code());
+    writer.write_i32(result.generation_id);
+    writer.write_string(&result.protocol_name);
+    writer.write_string(&result.leader_id);
+    writer.write_string(&result.member_id);
+    writer.write_array(&result.members, |writer, (member_id, metadata)| {
+        writer.write_string(member_id);
+        if version >= 5 {
+            writer.write_nullable_string(None);
+        }
+        writer.write_bytes(metadata);
+    });
+    Ok(())
+}
+
+/// Returns the partitions assigned to the member, the assignments are sent by the group leader.
+pub async fn handle_sync(
+    context: &KafkaContext,
+    version: i16,
+    reader: &mut KafkaReader,
+    writer: &mut KafkaWriter,
+) -> Result<(), IggyError> {
+    let group_id = reader.read_string()?;
+    let generation_id = reader.read_i32()?;
+    let member_id = reader.read_string()?;
+    if version >= 3 {
+        let _group_instance_id = reader.read_nullable_string()?;
+    }
+    let assignments =
+        reader.read_array(|reader| Ok((reader.read_string()?, reader.read_bytes()?)))?;
+
+    let (error, assignment) = context
+        .coordinator
+        .sync_group(&group_id, generation_id, &member_id, assignments)
+        .await;
+    if version >= 1 {
+        writer.write_i32(0);
+    }
+    writer.write_i16(error.code());
+    writer.write_bytes(&assignment);
+    Ok(())
+}
+
+pub fn handle_heartbeat(
+    context: &KafkaContext,
+    version: i16,
+    reader: &mut KafkaReader,
+    writer: &mut KafkaWriter,
+) -> Result<(), IggyError> {
+    let group_id = reader.read_string()?;
+    let generation_id = reader.read_i32()?;
+    let member_id = reader.read_string()?;
+    if version >= 3 {
+        let _group_instance_id = reader.read_nullable_string()?;
+    }
+
+    let error = context
+        .coordinator
+        .heartbeat(&group_id, generation_id, &member_id);
+    if version >= 1 {
+        writer.write_i32(0);
+    }
+    writer.write_i16(error.code());
+    Ok(())
+}
+
+pub fn handle_leave(
+    context: &Arc<KafkaContext>,
+    version: i16,
+    reader: &mut KafkaReader,
+    writer: &mut KafkaWriter,
+) -> Result<(), IggyError> {
+    let group_id = reader.read_string()?;
+    let member_id = reader.read_string()?;
+
+    let error = context.coordinator.leave_group(&group_id, &member_id);
+    if version >= 1 {
+        writer.write_i32(0);
+    }
+    writer.write_i16(error.code());
+    Ok(())
+}
@@ -0,0 +1,164 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::codec::{KafkaReader, KafkaWriter};
+use crate::kafka::handlers::{
+    KafkaConnection, KafkaContext, get_partition_offsets, resolve_topic_ids, to_partition_id,
+};
+use crate::kafka::protocol::ErrorCode;
+use crate::streaming::session::Session;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::System;
+use iggy_common::{Consumer, IggyError, IggyTimestamp, PollingStrategy};
+use std::sync::Arc;
+
+const LATEST_TIMESTAMP: i64 = -1;
+const EARLIEST_TIMESTAMP: i64 = -2;
+
+struct ListedOffset {
+    index: i32,
+    error: ErrorCode,
+    timestamp: i64,
+    offset: i64,
+}
+
+/// Returns the earliest or latest offsets of the partitions, or the offsets of the first messages
+/// appended at or after the given timestamps.
+pub async fn handle(
+    context: &Arc<KafkaContext>,
+    connection: &KafkaConnection,
+    version: i16,
+    reader: &mut KafkaReader,
+    writer: &mut KafkaWriter,
+) -> Result<(), IggyError> {
+    let _replica_id = reader.read_i32()?;
+    if version >= 2 {
+        let _isolation_level = reader.read_i8()?;
+    }
+    let topics = reader.read_array(|reader| {
+        let name = reader.read_string()?;
+        let partitions = reader.read_array(|reader| {
+            let index = reader.read_i32()?;
+            if version >= 4 {
+                let _current_leader_epoch = reader.read_i32()?;
+            }
+            Ok((index, reader.read_i64()?))
+        })?;
+        Ok((name, partitions))
+    })?;
+
+    let system = context.system.read().await;
+    let mut responses = Vec::with_capacity(topics.len());
+    for (name, partitions) in topics {
+        let mut listed = Vec::with_capacity(partitions.len());
+        for (index, timestamp) in partitions {
+            let offset = match list_offset(
+                &system,
+                context,
+                &connection.session,
+                &name,
+                index,
+                timestamp,
+            )
+            .await
+            {
+                Ok((timestamp, offset)) => ListedOffset {
+                    index,
+                    error: ErrorCode::None,
+                    timestamp,
+                    offset,
+                },
+                Err(error) => ListedOffset {
+                    index,
+                    error,
+                    timestamp: -1,
+                    offset: -1,
+                },
+            };
+            listed.push(offset);
+        }
+        responses.push((name, listed));
+    }
+    drop(system);
+
+    if version >= 2 {
+        writer.write_i32(0);
+    }
+    writer.write_array(&responses, |writer, (name, partitions)| {
+        writer.write_string(name);
+        writer.write_array(partitions, |writer, partition| {
+            writer.write_i32(partition.index);
+            writer.write_i16(partition.error.code());
+            writer.write_i64(partition.timestamp);
+            writer.write_i64(partition.offset);
+            if version >= 4 {
+                writer.write_i32(0);
+            }
+        });
+    });
+    Ok(())
+}
+
+/// Returns the timestamp and the offset, both are -1 if there's no message at or after the timestamp.
+async fn list_offset(
+    system: &System,
+    context: &KafkaContext,
+    session: &Session,
+    kafka_topic: &str,
+    partition_index: i32,
+    timestamp: i64,
+) -> Result<(i64, i64), ErrorCode> {
+    let (stream_id, topic_id) =
+        resolve_topic_ids(context, kafka_topic).map_err(|error| ErrorCode::from(&error))?;
+    let topic = system
+        .find_topic(session, &stream_id, &topic_id)
+        .map_err(|error| ErrorCode::from(&error))?;
+    let (log_start_offset, high_watermark) = get_partition_offsets(topic, partition_index).await?;
+    match timestamp {
+        LATEST_TIMESTAMP => return Ok((-1, high_watermark)),
+        EARLIEST_TIMESTAMP => return Ok((-1, log_start_offset)),
+        timestamp if timestamp < 0 => return Err(ErrorCode::InvalidRequest),
+        _ => {}
+    }
+
+    let partition_id =
+        to_partition_id(partition_index).ok_or(ErrorCode::UnknownTopicOrPartition)?;
+    let args = PollingArgs::new(
+        PollingStrategy::timestamp(IggyTimestamp::from(timestamp as u64 * 1000)),
+        1,
+        false,
+    );
+    let (metadata, batch_set) = system
+        .poll_messages(
+            session,
+            &Consumer::default(),
+            &stream_id,
+            &topic_id,
+            Some(partition_id),
+            args,
+        )
+        .await
+        .map_err(|error| ErrorCode::from(&error))?;
+    let messages = batch_set.into_polled_messages(metadata).messages;
+    Ok(messages.first().map_or((-1, -1), |message| {
+        (
+            (message.header.timestamp / 1000) as i64,
+            message.header.offset as i64,
+        )
+    }))
+}
@@ -0,0 +1,135 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::codec::{KafkaReader, KafkaWriter};
+use crate::kafka::handlers::{BROKER_NODE_ID, KafkaConnection, KafkaContext, find_topic};
+use crate::kafka::protocol::ErrorCode;
+use iggy_common::{Identifier, IggyError};
+use std::sync::Arc;
+
+const CLUSTER_ID: &str = "iggy";
+
+struct TopicMetadata {
+    error: ErrorCode,
+    name: String,
+    partitions_count: u32,
+}
+
+pub async fn handle(
+    context: &Arc<KafkaContext>,
+    connection: &KafkaConnection,
+    version: i16,
+    reader: &mut KafkaReader,
+    writer: &mut KafkaWriter,
+) -> Result<(), IggyError> {
+    // In v0 the empty array means all the topics, since v1 it's the null one.
+    let requested_topics = reader
+        .read_nullable_array(|reader| reader.read_string())?
+        .filter(|topics| version >= 1 || !topics.is_empty());
+    if version >= 4 {
+        // The topics are never created automatically, as they require the partitions count.
+        let _allow_auto_topic_creation = reader.read_bool()?;
+    }
+
+    let session = &connection.session;
+    let system = context.system.read().await;
+    let topics = match requested_topics {
+        Some(names) => names
+            .into_iter()
+            .map(|name| match find_topic(&system, context, session, &name) {
+                Ok(topic) => TopicMetadata {
+                    error: ErrorCode::None,
+                    name,
+                    partitions_count: topic.get_partitions_count(),
+                },
+                Err(error) => TopicMetadata {
+                    error: ErrorCode::from(&error),
+                    name,
+                    partitions_count: 0,
+                },
+            })
+            .collect::<Vec<_>>(),
+        None => {
+            let mut topics = Vec::new();
+            for stream in system.get_streams() {
+                for topic in stream.get_topics() {
+                    let Some(name) = context.naming.to_kafka(&stream.name, &topic.name) else {
+                        continue;
+                    };
+                    let (Ok(stream_id), Ok(topic_id)) = (
+                        Identifier::numeric(stream.stream_id),
+                        Identifier::numeric(topic.topic_id),
+                    ) else {
+                        continue;
+                    };
+                    // Only the topics, which the user has access to, are listed.
+                    if system.find_topic(session, &stream_id, &topic_id).is_ok() {
+                        topics.push(TopicMetadata {
+                            error: ErrorCode::None,
+                            name,
+                            partitions_count: topic.get_partitions_count(),
+                        });
+                    }
+                }
+            }
+            topics
+        }
+    };
+    drop(system);
+
+    if version >= 3 {
+        writer.write_i32(0);
+    }
+    writer.write_array(&[BROKER_NODE_ID], |writer, node_id| {
+        writer.write_i32(*node_id);
+        writer.write_string(&context.broker_host);
+        writer.write_i32(context.broker_port);
+        if version >= 1 {
+            writer.write_nullable_string(None);
+        }
+    });
+    if version >= 2 {
+        writer.write_nullable_string(Some(CLUSTER_ID));
+    }
+    if version >= 1 {
+        writer.write_i32(BROKER_NODE_ID);
+    }
+    writer.write_array(&topics, |writer, topic| {
+        writer.write_i16(topic.error.code());
+        writer.write_string(&topic.name);
+        if version >= 1 {
+            writer.write_bool(false);
+        }
+        let partitions = (0..topic.partitions_count as i32).collect::<Vec<_>>();
+        writer.write_array(&partitions, |writer, partition_index| {
+            writer.write_i16(ErrorCode::None.code());
+            writer.write_i32(*partition_index);
+            writer.write_i32(BROKER_NODE_ID);
+            writer.write_array(&[BROKER_NODE_ID], |writer, node_id| {
+                writer.write_i32(*node_id)
+            });
+            writer.write_array(&[BROKER_NODE_ID], |writer, node_id| {
+                writer.write_i32(*node_id)
+            });
+            if version >= 5 {
+                writer.write_array(&[] as &[i32], |writer, node_id| writer.write_i32(*node_id));
+            }
+        });
+    });
+    Ok(())
+}
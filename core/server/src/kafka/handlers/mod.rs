@@ -0,0 +1,223 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+mod api_versions_handler;
+mod fetch_handler;
+mod group_handlers;
+mod list_offsets_handler;
+mod metadata_handler;
+mod offset_handlers;
+mod produce_handler;
+mod sasl_handlers;
+
+use crate::kafka::codec::{KafkaReader, KafkaWriter};
+use crate::kafka::coordinator::GroupCoordinator;
+use crate::kafka::naming::TopicNaming;
+use crate::kafka::protocol::{ApiKey, ErrorCode, RequestHeader};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::{SharedSystem, System};
+use crate::streaming::topics::topic::Topic;
+use bytes::Bytes;
+use iggy_common::locking::IggySharedMutFn;
+use iggy_common::{Identifier, IggyError};
+use std::sync::Arc;
+use std::sync::atomic::AtomicI64;
+use tracing::warn;
+
+/// The single broker exposed by the listener, which leads all the partitions.
+pub const BROKER_NODE_ID: i32 = 0;
+/// The user header, in which the key of the Kafka record is stored.
+pub const KEY_HEADER: &str = "kafka-key";
+
+/// Shared state of the Kafka listener.
+#[derive(Debug)]
+pub struct KafkaContext {
+    pub system: SharedSystem,
+    pub coordinator: Arc<GroupCoordinator>,
+    pub naming: TopicNaming,
+    pub broker_host: String,
+    pub broker_port: i32,
+    pub next_producer_id: AtomicI64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaslState {
+    Initial,
+    Handshake,
+    Completed,
+}
+
+/// State of a single Kafka connection, bound to the Iggy client session.
+#[derive(Debug)]
+pub struct KafkaConnection {
+    pub session: Arc<Session>,
+    pub client_id: String,
+    pub sasl_state: SaslState,
+    /// The connection is closed once the response is sent, e.g. after the failed authentication.
+    pub close: bool,
+}
+
+impl KafkaConnection {
+    pub fn new(session: Arc<Session>) -> Self {
+        Self {
+            session,
+            client_id: String::new(),
+            sasl_state: SaslState::Initial,
+            close: false,
+        }
+    }
+}
+
+/// Handles the request and returns the response body (without the header), if any is expected.
+/// The error means that the connection must be closed, as Kafka does for the unexpected requests.
+pub async fn handle_request(
+    context: &Arc<KafkaContext>,
+    connection: &mut KafkaConnection,
+    header: &RequestHeader,
+    reader: &mut KafkaReader,
+) -> Result<Option<Bytes>, IggyError> {
+    let Some(api_key) = ApiKey::from_code(header.api_key) else {
+        warn!("Unsupported Kafka API key: {}", header.api_key);
+        return Err(IggyError::InvalidCommand);
+    };
+
+    let version = header.api_version;
+    if !api_key.supports_version(version) {
+        // The clients use the error to fall back to the version supported by the broker.
+        if api_key == ApiKey::ApiVersions {
+            let mut writer = KafkaWriter::new();
+            api_versions_handler::handle(0, ErrorCode::UnsupportedVersion, &mut writer);
+            return Ok(Some(writer.into_bytes()));
+        }
+
+        warn!("Unsupported version: {version} of Kafka API: {api_key:?}");
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let is_authentication_api = matches!(
+        api_key,
+        ApiKey::ApiVersions | ApiKey::SaslHandshake | ApiKey::SaslAuthenticate
+    );
+    if !is_authentication_api && !connection.session.is_authenticated() {
+        warn!("Unexpected Kafka API: {api_key:?} before the SASL authentication.");
+        return Err(IggyError::Unauthenticated);
+    }
+
+    if let Some(client_id) = &header.client_id {
+        connection.client_id.clone_from(client_id);
+    }
+
+    let mut writer = KafkaWriter::new();
+    match api_key {
+        ApiKey::ApiVersions => api_versions_handler::handle(version, ErrorCode::None, &mut writer),
+        ApiKey::SaslHandshake => sasl_handlers::handle_handshake(connection, reader, &mut writer)?,
+        ApiKey::SaslAuthenticate => {
+            sasl_handlers::handle_authenticate(context, connection, version, reader, &mut writer)
+                .await?
+        }
+        ApiKey::Metadata => {
+            metadata_handler::handle(context, connection, version, reader, &mut writer).await?
+        }
+        ApiKey::Produce => {
+            let expects_response =
+                produce_handler::handle(context, connection, version, reader, &mut writer).await?;
+            if !expects_response {
+                return Ok(None);
+            }
+        }
+        ApiKey::Fetch => {
+            fetch_handler::handle(context, connection, version, reader, &mut writer).await?
+        }
+        ApiKey::ListOffsets => {
+            list_offsets_handler::handle(context, connection, version, reader, &mut writer).await?
+        }
+        ApiKey::OffsetCommit => {
+            offset_handlers::handle_commit(context, connection, version, reader, &mut writer)
+                .await?
+        }
+        ApiKey::OffsetFetch => {
+            offset_handlers::handle_fetch(context, connection, version, reader, &mut writer).await?
+        }
+        ApiKey::FindCoordinator => {
+            group_handlers::handle_find_coordinator(context, version, reader, &mut writer)?
+        }
+        ApiKey::JoinGroup => {
+            group_handlers::handle_join(context, connection, version, reader, &mut writer).await?
+        }
+        ApiKey::SyncGroup => {
+            group_handlers::handle_sync(context, version, reader, &mut writer).await?
+        }
+        ApiKey::Heartbeat => {
+            group_handlers::handle_heartbeat(context, version, reader, &mut writer)?
+        }
+        ApiKey::LeaveGroup => group_handlers::handle_leave(context, version, reader, &mut writer)?,
+        ApiKey::InitProducerId => {
+            produce_handler::handle_init_producer_id(context, reader, &mut writer)?
+        }
+    }
+    Ok(Some(writer.into_bytes()))
+}
+
+/// Returns the identifiers of the stream and topic, to which the Kafka topic is mapped.
+fn resolve_topic_ids(
+    context: &KafkaContext,
+    kafka_topic: &str,
+) -> Result<(Identifier, Identifier), IggyError> {
+    let (stream, topic) = context.naming.to_iggy(kafka_topic);
+    Ok((Identifier::named(stream)?, Identifier::named(topic)?))
+}
+
+/// Finds the topic, to which the Kafka topic is mapped, if the user has access to it.
+fn find_topic<'a>(
+    system: &'a System,
+    context: &KafkaContext,
+    session: &Session,
+    kafka_topic: &str,
+) -> Result<&'a Topic, IggyError> {
+    let (stream_id, topic_id) = resolve_topic_ids(context, kafka_topic)?;
+    system.find_topic(session, &stream_id, &topic_id)
+}
+
+/// Iggy partition IDs start from 1, while Kafka partition indexes start from 0.
+fn to_partition_id(partition_index: i32) -> Option<u32> {
+    u32::try_from(partition_index).ok().map(|index| index + 1)
+}
+
+/// Returns the log start offset and the high watermark (the offset of the next message) of the partition.
+async fn get_partition_offsets(
+    topic: &Topic,
+    partition_index: i32,
+) -> Result<(i64, i64), ErrorCode> {
+    let partition_id =
+        to_partition_id(partition_index).ok_or(ErrorCode::UnknownTopicOrPartition)?;
+    let partition = topic
+        .get_partition(partition_id)
+        .map_err(|_| ErrorCode::UnknownTopicOrPartition)?;
+    let partition = partition.read().await;
+    let high_watermark = if partition.should_increment_offset {
+        partition.current_offset as i64 + 1
+    } else {
+        0
+    };
+    let log_start_offset = partition
+        .segments
+        .first()
+        .map_or(0, |segment| segment.start_offset() as i64)
+        .min(high_watermark);
+    Ok((log_start_offset, high_watermark))
+}
@@ -0,0 +1,269 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::codec::{KafkaReader, KafkaWriter};
+use crate::kafka::handlers::{KafkaConnection, KafkaContext, resolve_topic_ids, to_partition_id};
+use crate::kafka::protocol::ErrorCode;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy_common::{Consumer, Identifier, IggyError};
+use std::sync::Arc;
+
+/// The offset returned for the partitions without the committed offset.
+const NO_OFFSET: i64 = -1;
+
+struct FetchedOffset {
+    index: i32,
+    offset: i64,
+    error: ErrorCode,
+}
+
+/// Stores the committed offsets as the offsets of the Iggy consumer named after the group.
+/// Kafka commits the offset of the next message to consume, while Iggy stores the last consumed one,
+/// thus the offset is decremented, and the commit of offset 0 deletes the stored offset.
+pub async fn handle_commit(
+    context: &Arc<KafkaContext>,
+    connection: &KafkaConnection,
+    version: i16,
+    reader: &mut KafkaReader,
+    writer: &mut KafkaWriter,
+) -> Result<(), IggyError> {
+    let group_id = reader.read_string()?;
+    let generation_id = reader.read_i32()?;
+    let member_id = reader.read_string()?;
+    if version <= 4 {
+        let _retention_time_ms = reader.read_i64()?;
+    }
+    let topics = reader.read_array(|reader| {
+        let name = reader.read_string()?;
+        let partitions = reader.read_array(|reader| {
+            let index = reader.read_i32()?;
+            let offset = reader.read_i64()?;
+            if version >= 6 {
+                let _leader_epoch = reader.read_i32()?;
+            }
+            let _metadata = reader.read_nullable_string()?;
+            Ok((index, offset))
+        })?;
+        Ok((name, partitions))
+    })?;
+
+    let group_error =
+        context
+            .coordinator
+            .validate_offset_commit(&group_id, generation_id, &member_id);
+    let system = context.system.read().await;
+    let mut responses = Vec::with_capacity(topics.len());
+    for (name, partitions) in topics {
+        let mut committed = Vec::with_capacity(partitions.len());
+        for (index, offset) in partitions {
+            let error = if group_error != ErrorCode::None {
+                group_error
+            } else {
+                commit_offset(
+                    &system,
+                    context,
+                    &connection.session,
+                    &group_id,
+                    &name,
+                    index,
+                    offset,
+                )
+                .await
+                .map_or_else(|error| ErrorCode::from(&error), |_| ErrorCode::None)
+            };
+            committed.push((index, error));
+        }
+        responses.push((name, committed));
+    }
+    drop(system);
+
+    if version >= 3 {
+        writer.write_i32(0);
+    }
+    writer.write_array(&responses, |writer, (name, partitions)| {
+        writer.write_string(name);
+        writer.write_array(partitions, |writer, (index, error)| {
+            writer.write_i32(*index);
+            writer.write_i16(error.code());
+        });
+    });
+    Ok(())
+}
+
+async fn commit_offset(
+    system: &System,
+    context: &KafkaContext,
+    session: &Session,
+    group_id: &str,
+    kafka_topic: &str,
+    partition_index: i32,
+    offset: i64,
+) -> Result<(), IggyError> {
+    let (stream_id, topic_id) = resolve_topic_ids(context, kafka_topic)?;
+    let partition_id = to_partition_id(partition_index).ok_or(IggyError::InvalidIdentifier)?;
+    let consumer = group_consumer(group_id)?;
+    if offset > 0 {
+        return system
+            .store_consumer_offset(
+                session,
+                consumer,
+                &stream_id,
+                &topic_id,
+                Some(partition_id),
+                offset as u64 - 1,
+            )
+            .await;
+    }
+
+    // There's nothing to delete, if the offset has never been committed.
+    match system
+        .delete_consumer_offset(session, consumer, &stream_id, &topic_id, Some(partition_id))
+        .await
+    {
+        Ok(()) | Err(IggyError::ConsumerOffsetNotFound(_)) => Ok(()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Returns the committed offsets of the partitions. All the committed offsets of the group
+/// are returned, if no topics are specified (v2+).
+pub async fn handle_fetch(
+    context: &Arc<KafkaContext>,
+    connection: &KafkaConnection,
+    version: i16,
+    reader: &mut KafkaReader,
+    writer: &mut KafkaWriter,
+) -> Result<(), IggyError> {
+    let group_id = reader.read_string()?;
+    let read_topic = |reader: &mut KafkaReader| {
+        let name = reader.read_string()?;
+        let partitions = reader.read_array(|reader| reader.read_i32())?;
+        Ok((name, partitions))
+    };
+    let topics = if version >= 2 {
+        reader.read_nullable_array(read_topic)?
+    } else {
+        Some(reader.read_array(read_topic)?)
+    };
+
+    let session = &connection.session;
+    let system = context.system.read().await;
+    let topics = topics.unwrap_or_else(|| list_topics(&system, context, session));
+    let mut responses = Vec::with_capacity(topics.len());
+    for (name, partitions) in topics {
+        let mut fetched = Vec::with_capacity(partitions.len());
+        for index in partitions {
+            let offset =
+                match fetch_offset(&system, context, session, &group_id, &name, index).await {
+                    Ok(offset) => FetchedOffset {
+                        index,
+                        offset: offset.unwrap_or(NO_OFFSET),
+                        error: ErrorCode::None,
+                    },
+                    Err(error) => FetchedOffset {
+                        index,
+                        offset: NO_OFFSET,
+                        error: ErrorCode::from(&error),
+                    },
+                };
+            fetched.push(offset);
+        }
+        responses.push((name, fetched));
+    }
+    drop(system);
+
+    if version >= 3 {
+        writer.write_i32(0);
+    }
+    writer.write_array(&responses, |writer, (name, partitions)| {
+        writer.write_string(name);
+        writer.write_array(partitions, |writer, partition| {
+            writer.write_i32(partition.index);
+            writer.write_i64(partition.offset);
+            if version >= 5 {
+                writer.write_i32(-1);
+            }
+            writer.write_nullable_string(Some(""));
+            writer.write_i16(partition.error.code());
+        });
+    });
+    if version >= 2 {
+        writer.write_i16(ErrorCode::None.code());
+    }
+    Ok(())
+}
+
+/// Returns the Kafka offset (the next message to consume) committed by the group, if any.
+async fn fetch_offset(
+    system: &System,
+    context: &KafkaContext,
+    session: &Session,
+    group_id: &str,
+    kafka_topic: &str,
+    partition_index: i32,
+) -> Result<Option<i64>, IggyError> {
+    let (stream_id, topic_id) = resolve_topic_ids(context, kafka_topic)?;
+    let Some(partition_id) = to_partition_id(partition_index) else {
+        return Ok(None);
+    };
+    let offset = system
+        .get_consumer_offset(
+            session,
+            &group_consumer(group_id)?,
+            &stream_id,
+            &topic_id,
+            Some(partition_id),
+        )
+        .await?;
+    Ok(offset.map(|offset| offset.stored_offset as i64 + 1))
+}
+
+/// Returns all the topics (with their partitions), which are accessible by the user.
+fn list_topics(
+    system: &System,
+    context: &KafkaContext,
+    session: &Session,
+) -> Vec<(String, Vec<i32>)> {
+    let mut topics = Vec::new();
+    for stream in system.get_streams() {
+        for topic in stream.get_topics() {
+            let Some(name) = context.naming.to_kafka(&stream.name, &topic.name) else {
+                continue;
+            };
+            let Ok(stream_id) = Identifier::numeric(stream.stream_id) else {
+                continue;
+            };
+            let Ok(topic_id) = Identifier::numeric(topic.topic_id) else {
+                continue;
+            };
+            if system.find_topic(session, &stream_id, &topic_id).is_err() {
+                continue;
+            }
+
+            let partitions = (0..topic.get_partitions_count() as i32).collect();
+            topics.push((name, partitions));
+        }
+    }
+    topics
+}
+
+/// The offsets of the Kafka consumer group are stored by the Iggy consumer with the same name.
+fn group_consumer(group_id: &str) -> Result<Consumer, IggyError> {
+    Ok(Consumer::new(Identifier::named(group_id)?))
+}
@@ -0,0 +1,220 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::codec::{KafkaReader, KafkaWriter};
+use crate::kafka::handlers::{
+    KEY_HEADER, KafkaConnection, KafkaContext, get_partition_offsets, resolve_topic_ids,
+    to_partition_id,
+};
+use crate::kafka::protocol::ErrorCode;
+use crate::kafka::records::{self, KafkaRecord};
+use crate::streaming::segments::IggyMessagesBatchMut;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::quota_limiter::QuotaDirection;
+use bytes::Bytes;
+use iggy_common::{HeaderKey, HeaderValue, IggyError, IggyMessage, Partitioning, Sizeable};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+struct ProducedPartition {
+    index: i32,
+    error: ErrorCode,
+    base_offset: i64,
+    log_start_offset: i64,
+}
+
+/// Appends the records to the partitions, the response is not sent if the producer doesn't expect
+/// any acknowledgement (acks = 0).
+pub async fn handle(
+    context: &Arc<KafkaContext>,
+    connection: &KafkaConnection,
+    version: i16,
+    reader: &mut KafkaReader,
+    writer: &mut KafkaWriter,
+) -> Result<bool, IggyError> {
+    let _transactional_id = reader.read_nullable_string()?;
+    let acks = reader.read_i16()?;
+    let _timeout_ms = reader.read_i32()?;
+    let topics = reader.read_array(|reader| {
+        let name = reader.read_string()?;
+        let partitions =
+            reader.read_array(|reader| Ok((reader.read_i32()?, reader.read_nullable_bytes()?)))?;
+        Ok((name, partitions))
+    })?;
+
+    let session = &connection.session;
+    let system = context.system.read().await;
+    let mut throttle_time = Duration::ZERO;
+    let mut responses = Vec::with_capacity(topics.len());
+    for (name, partitions) in topics {
+        let mut produced = Vec::with_capacity(partitions.len());
+        for (index, records) in partitions {
+            let partition =
+                match append_records(&system, context, session, &name, index, records).await {
+                    Ok((base_offset, log_start_offset, throttle)) => {
+                        throttle_time = throttle_time.max(throttle);
+                        ProducedPartition {
+                            index,
+                            error: ErrorCode::None,
+                            base_offset,
+                            log_start_offset,
+                        }
+                    }
+                    Err(error) => ProducedPartition {
+                        index,
+                        error,
+                        base_offset: -1,
+                        log_start_offset: -1,
+                    },
+                };
+            produced.push(partition);
+        }
+        responses.push((name, produced));
+    }
+    drop(system);
+
+    if acks == 0 {
+        return Ok(false);
+    }
+
+    writer.write_array(&responses, |writer, (name, partitions)| {
+        writer.write_string(name);
+        writer.write_array(partitions, |writer, partition| {
+            writer.write_i32(partition.index);
+            writer.write_i16(partition.error.code());
+            writer.write_i64(partition.base_offset);
+            writer.write_i64(-1);
+            if version >= 5 {
+                writer.write_i64(partition.log_start_offset);
+            }
+        });
+    });
+    // The clients delay the next requests by the throttle time, when the produce quota is exceeded.
+    writer.write_i32(throttle_time.as_millis() as i32);
+    Ok(true)
+}
+
+/// Returns the base offset of the appended records, the log start offset and the throttle time.
+async fn append_records(
+    system: &System,
+    context: &KafkaContext,
+    session: &Session,
+    kafka_topic: &str,
+    partition_index: i32,
+    records: Option<Bytes>,
+) -> Result<(i64, i64, Duration), ErrorCode> {
+    let (stream_id, topic_id) =
+        resolve_topic_ids(context, kafka_topic).map_err(|error| ErrorCode::from(&error))?;
+    let partition_id =
+        to_partition_id(partition_index).ok_or(ErrorCode::UnknownTopicOrPartition)?;
+    let records = records::decode_record_batches(records.unwrap_or_default())?;
+    if records.is_empty() {
+        return Err(ErrorCode::InvalidRecord);
+    }
+
+    let messages = records
+        .into_iter()
+        .map(to_iggy_message)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| ErrorCode::from(&error))?;
+    let messages_count = messages.len();
+    let messages_size = messages
+        .iter()
+        .map(|message| message.get_size_bytes().as_bytes_u32())
+        .sum();
+    let batch = IggyMessagesBatchMut::from_messages(&messages, messages_size);
+    system
+        .append_messages(
+            session,
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(partition_id),
+            batch,
+            None,
+        )
+        .await
+        .map_err(|error| ErrorCode::from(&error))?;
+    let throttle_time = system
+        .record_throughput(
+            session,
+            QuotaDirection::Produce,
+            messages_size as u64,
+            messages_count as u64,
+        )
+        .unwrap_or_default();
+
+    let topic = system
+        .find_topic(session, &stream_id, &topic_id)
+        .map_err(|error| ErrorCode::from(&error))?;
+    let (log_start_offset, high_watermark) = get_partition_offsets(topic, partition_index).await?;
+    Ok((
+        high_watermark - messages_count as i64,
+        log_start_offset,
+        throttle_time,
+    ))
+}
+
+/// Converts the record to the message, the key and headers are stored as the user headers.
+/// The empty header values are skipped, as they're not supported by Iggy.
+fn to_iggy_message(record: KafkaRecord) -> Result<IggyMessage, IggyError> {
+    let mut user_headers = HashMap::new();
+    if let Some(key) = record.key.filter(|key| !key.is_empty()) {
+        user_headers.insert(HeaderKey::new(KEY_HEADER)?, HeaderValue::from_raw(&key)?);
+    }
+    for header in record.headers {
+        let Some(value) = header.value.filter(|value| !value.is_empty()) else {
+            continue;
+        };
+        user_headers.insert(HeaderKey::new(&header.key)?, HeaderValue::from_raw(&value)?);
+    }
+
+    let mut message = IggyMessage::builder()
+        .payload(record.value.unwrap_or_default())
+        .maybe_user_headers((!user_headers.is_empty()).then_some(user_headers))
+        .build()?;
+    if record.timestamp > 0 {
+        message.header.origin_timestamp = record.timestamp as u64 * 1000;
+    }
+    Ok(message)
+}
+
+/// Assigns the ID to the idempotent producer. The sequence numbers are not validated,
+/// and the transactional producers are not supported.
+pub fn handle_init_producer_id(
+    context: &KafkaContext,
+    reader: &mut KafkaReader,
+    writer: &mut KafkaWriter,
+) -> Result<(), IggyError> {
+    let transactional_id = reader.read_nullable_string()?;
+    let _transaction_timeout_ms = reader.read_i32()?;
+    writer.write_i32(0);
+    if transactional_id.is_some() {
+        writer.write_i16(ErrorCode::InvalidRequest.code());
+        writer.write_i64(-1);
+        writer.write_i16(-1);
+        return Ok(());
+    }
+
+    writer.write_i16(ErrorCode::None.code());
+    writer.write_i64(context.next_producer_id.fetch_add(1, Ordering::Relaxed));
+    writer.write_i16(0);
+    Ok(())
+}
@@ -0,0 +1,134 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::codec::{KafkaReader, KafkaWriter};
+use crate::kafka::handlers::{KafkaConnection, KafkaContext, SaslState};
+use crate::kafka::protocol::ErrorCode;
+use iggy_common::IggyError;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+const PLAIN_MECHANISM: &str = "PLAIN";
+
+pub fn handle_handshake(
+    connection: &mut KafkaConnection,
+    reader: &mut KafkaReader,
+    writer: &mut KafkaWriter,
+) -> Result<(), IggyError> {
+    let mechanism = reader.read_string()?;
+    let error = if connection.sasl_state != SaslState::Initial {
+        ErrorCode::IllegalSaslState
+    } else if mechanism != PLAIN_MECHANISM {
+        ErrorCode::UnsupportedSaslMechanism
+    } else {
+        connection.sasl_state = SaslState::Handshake;
+        ErrorCode::None
+    };
+
+    writer.write_i16(error.code());
+    writer.write_array(&[PLAIN_MECHANISM], |writer, mechanism| {
+        writer.write_string(mechanism)
+    });
+    Ok(())
+}
+
+/// Signs in the user with the credentials sent using the `PLAIN` mechanism (RFC 4616),
+/// the connection is closed after the failed attempt.
+pub async fn handle_authenticate(
+    context: &Arc<KafkaContext>,
+    connection: &mut KafkaConnection,
+    version: i16,
+    reader: &mut KafkaReader,
+    writer: &mut KafkaWriter,
+) -> Result<(), IggyError> {
+    let auth_bytes = reader.read_bytes()?;
+    let result = if connection.sasl_state != SaslState::Handshake {
+        Err((
+            ErrorCode::IllegalSaslState,
+            "Unexpected SASL authentication request.".to_owned(),
+        ))
+    } else {
+        login_user(context, connection, &auth_bytes).await
+    };
+
+    let error_message = match result {
+        Ok(()) => {
+            connection.sasl_state = SaslState::Completed;
+            writer.write_i16(ErrorCode::None.code());
+            None
+        }
+        Err((error, message)) => {
+            connection.close = true;
+            writer.write_i16(error.code());
+            Some(message)
+        }
+    };
+    writer.write_nullable_string(error_message.as_deref());
+    writer.write_bytes(&[]);
+    if version >= 1 {
+        writer.write_i64(0);
+    }
+    Ok(())
+}
+
+async fn login_user(
+    context: &KafkaContext,
+    connection: &KafkaConnection,
+    auth_bytes: &[u8],
+) -> Result<(), (ErrorCode, String)> {
+    let invalid_credentials = || {
+        (
+            ErrorCode::SaslAuthenticationFailed,
+            "Invalid username or password.".to_owned(),
+        )
+    };
+    // The message consists of the authorization ID, username and password separated by NUL.
+    let mut parts = auth_bytes.split(|byte| *byte == 0);
+    let (Some(_), Some(username), Some(password), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid_credentials());
+    };
+    let (Ok(username), Ok(password)) =
+        (std::str::from_utf8(username), std::str::from_utf8(password))
+    else {
+        return Err(invalid_credentials());
+    };
+
+    let session = &connection.session;
+    let system = context.system.read().await;
+    match system
+        .login_user(username, password, session.login_source(), Some(session))
+        .await
+    {
+        Ok(user) => {
+            info!(
+                "Kafka client: {} has signed in as user: {username} with ID: {}.",
+                session.client_id, user.id
+            );
+            Ok(())
+        }
+        Err(error) => {
+            warn!(
+                "Kafka client: {} has failed to sign in as user: {username}. {error}",
+                session.client_id
+            );
+            Err(invalid_credentials())
+        }
+    }
+}
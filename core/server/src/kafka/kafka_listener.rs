@@ -0,0 +1,173 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::kafka::KafkaConfig;
+use crate::kafka::COMPONENT;
+use crate::kafka::codec::KafkaReader;
+use crate::kafka::coordinator::GroupCoordinator;
+use crate::kafka::handlers::{KafkaConnection, KafkaContext, handle_request};
+use crate::kafka::naming::TopicNaming;
+use crate::kafka::protocol::RequestHeader;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use bytes::BytesMut;
+use iggy_common::IggyError;
+use std::io::ErrorKind;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use std::sync::atomic::AtomicI64;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{debug, error, info};
+
+pub async fn start(config: KafkaConfig, system: SharedSystem) -> SocketAddr {
+    let listener = TcpListener::bind(&config.address)
+        .await
+        .unwrap_or_else(|error| {
+            panic!(
+                "Unable to bind Kafka listener to: {}. {error}",
+                config.address
+            )
+        });
+    let local_addr = listener
+        .local_addr()
+        .expect("Failed to get local address for Kafka listener");
+
+    let (broker_host, broker_port) = get_broker_address(&config, local_addr);
+    let coordinator = Arc::new(GroupCoordinator::default());
+    coordinator.start();
+    let context = Arc::new(KafkaContext {
+        system,
+        coordinator,
+        naming: TopicNaming::new(&config.default_stream, &config.topic_separator),
+        broker_host,
+        broker_port,
+        next_producer_id: AtomicI64::new(0),
+    });
+    let max_request_size = config.max_request_size.as_bytes_u64() as usize;
+
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, address)) => {
+                    info!("Accepted new Kafka connection: {address}");
+                    if let Err(error) = stream.set_nodelay(true) {
+                        error!(
+                            "{COMPONENT} (error: {error}) - failed to set TCP_NODELAY for: {address}"
+                        );
+                    }
+
+                    let session = context
+                        .system
+                        .read()
+                        .await
+                        .add_client(&address, Transport::Kafka)
+                        .await;
+                    let client_id = session.client_id;
+                    info!("Created new session: {session}");
+                    let context = context.clone();
+                    tokio::spawn(async move {
+                        let mut stream = stream;
+                        if let Err(error) =
+                            handle_connection(&context, session, &mut stream, max_request_size)
+                                .await
+                        {
+                            error!(
+                                "{COMPONENT} (error: {error}) - connection with client: {client_id}, address: {address} has failed."
+                            );
+                        }
+                        context.system.read().await.delete_client(client_id).await;
+                        let _ = stream.shutdown().await;
+                        info!(
+                            "Closed Kafka connection for client: {client_id}, address: {address}."
+                        );
+                    });
+                }
+                Err(error) => error!("Unable to accept Kafka socket. {error}"),
+            }
+        }
+    });
+    local_addr
+}
+
+/// Handles the requests one by one, so the responses are sent in the order of the requests,
+/// as expected by the Kafka clients which pipeline the requests.
+async fn handle_connection(
+    context: &Arc<KafkaContext>,
+    session: Arc<Session>,
+    stream: &mut TcpStream,
+    max_request_size: usize,
+) -> Result<(), IggyError> {
+    let mut connection = KafkaConnection::new(session);
+    loop {
+        let size = match stream.read_i32().await {
+            Ok(size) => size,
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(()),
+            Err(_) => return Err(IggyError::TcpError),
+        };
+        if size < 0 || size as usize > max_request_size {
+            error!(
+                "{COMPONENT} - invalid request size: {size}, the maximum is: {max_request_size}."
+            );
+            return Err(IggyError::InvalidFormat);
+        }
+
+        let mut buffer = BytesMut::zeroed(size as usize);
+        stream
+            .read_exact(&mut buffer)
+            .await
+            .map_err(|_| IggyError::ConnectionClosed)?;
+        let mut reader = KafkaReader::new(buffer.freeze());
+        let header = RequestHeader::read(&mut reader)?;
+        debug!(
+            "Received Kafka request, API key: {}, version: {}, correlation ID: {}",
+            header.api_key, header.api_version, header.correlation_id
+        );
+        if let Some(body) = handle_request(context, &mut connection, &header, &mut reader).await? {
+            let mut response = Vec::with_capacity(8 + body.len());
+            response.extend_from_slice(&(body.len() as i32 + 4).to_be_bytes());
+            response.extend_from_slice(&header.correlation_id.to_be_bytes());
+            response.extend_from_slice(&body);
+            stream
+                .write_all(&response)
+                .await
+                .map_err(|_| IggyError::TcpError)?;
+        }
+        if connection.close {
+            return Ok(());
+        }
+    }
+}
+
+/// Returns the host and port returned to the clients in the metadata, which is either
+/// the configured advertised address or the bound one (with the loopback for the unspecified IP).
+fn get_broker_address(config: &KafkaConfig, local_addr: SocketAddr) -> (String, i32) {
+    if let Some((host, port)) = config.advertised_address.rsplit_once(':')
+        && let Ok(port) = port.parse::<u16>()
+    {
+        return (host.to_owned(), port as i32);
+    }
+
+    let ip = if local_addr.ip().is_unspecified() {
+        IpAddr::V4(Ipv4Addr::LOCALHOST)
+    } else {
+        local_addr.ip()
+    };
+    (ip.to_string(), local_addr.port() as i32)
+}
@@ -0,0 +1,94 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+/// Maps the Kafka topic names onto the Iggy streams and topics.
+/// The name is split on the first separator into the stream and topic names, e.g. `orders.created`
+/// is the topic `created` in the stream `orders`. The names without the separator (or all of them,
+/// if the separator is empty) refer to the topics in the default stream.
+#[derive(Debug, Clone)]
+pub struct TopicNaming {
+    default_stream: String,
+    separator: String,
+}
+
+impl TopicNaming {
+    pub fn new(default_stream: &str, separator: &str) -> Self {
+        Self {
+            default_stream: default_stream.to_owned(),
+            separator: separator.to_owned(),
+        }
+    }
+
+    /// Returns the stream and topic names for the Kafka topic.
+    pub fn to_iggy<'a>(&'a self, kafka_topic: &'a str) -> (&'a str, &'a str) {
+        if !self.separator.is_empty()
+            && let Some((stream, topic)) = kafka_topic.split_once(&self.separator)
+            && !stream.is_empty()
+            && !topic.is_empty()
+        {
+            return (stream, topic);
+        }
+
+        (&self.default_stream, kafka_topic)
+    }
+
+    /// Returns the Kafka topic name for the Iggy topic, if it can be addressed by the naming scheme.
+    pub fn to_kafka(&self, stream: &str, topic: &str) -> Option<String> {
+        let kafka_topic = if stream == self.default_stream
+            && (self.separator.is_empty() || !topic.contains(&self.separator))
+        {
+            topic.to_owned()
+        } else if self.separator.is_empty() {
+            return None;
+        } else {
+            format!("{stream}{}{topic}", self.separator)
+        };
+
+        (self.to_iggy(&kafka_topic) == (stream, topic)).then_some(kafka_topic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_map_topic_names_with_separator() {
+        let naming = TopicNaming::new("kafka", ".");
+        assert_eq!(naming.to_iggy("orders"), ("kafka", "orders"));
+        assert_eq!(naming.to_iggy("shop.orders"), ("shop", "orders"));
+        assert_eq!(naming.to_iggy("shop.orders.v2"), ("shop", "orders.v2"));
+        assert_eq!(naming.to_iggy(".orders"), ("kafka", ".orders"));
+
+        assert_eq!(naming.to_kafka("kafka", "orders").unwrap(), "orders");
+        assert_eq!(
+            naming.to_kafka("kafka", "orders.v2").unwrap(),
+            "kafka.orders.v2"
+        );
+        assert_eq!(naming.to_kafka("shop", "orders").unwrap(), "shop.orders");
+        assert!(naming.to_kafka("shop.eu", "orders").is_none());
+    }
+
+    #[test]
+    fn should_map_only_default_stream_without_separator() {
+        let naming = TopicNaming::new("kafka", "");
+        assert_eq!(naming.to_iggy("shop.orders"), ("kafka", "shop.orders"));
+        assert_eq!(naming.to_kafka("kafka", "orders").unwrap(), "orders");
+        assert!(naming.to_kafka("shop", "orders").is_none());
+    }
+}
@@ -0,0 +1,167 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::codec::KafkaReader;
+use iggy_common::IggyError;
+
+/// Kafka APIs served by the listener, along with the supported (non-flexible) versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiKey {
+    Produce = 0,
+    Fetch = 1,
+    ListOffsets = 2,
+    Metadata = 3,
+    OffsetCommit = 8,
+    OffsetFetch = 9,
+    FindCoordinator = 10,
+    JoinGroup = 11,
+    Heartbeat = 12,
+    LeaveGroup = 13,
+    SyncGroup = 14,
+    SaslHandshake = 17,
+    ApiVersions = 18,
+    InitProducerId = 22,
+    SaslAuthenticate = 36,
+}
+
+impl ApiKey {
+    pub const ALL: [ApiKey; 15] = [
+        ApiKey::Produce,
+        ApiKey::Fetch,
+        ApiKey::ListOffsets,
+        ApiKey::Metadata,
+        ApiKey::OffsetCommit,
+        ApiKey::OffsetFetch,
+        ApiKey::FindCoordinator,
+        ApiKey::JoinGroup,
+        ApiKey::Heartbeat,
+        ApiKey::LeaveGroup,
+        ApiKey::SyncGroup,
+        ApiKey::SaslHandshake,
+        ApiKey::ApiVersions,
+        ApiKey::InitProducerId,
+        ApiKey::SaslAuthenticate,
+    ];
+
+    pub fn from_code(code: i16) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|api_key| *api_key as i16 == code)
+    }
+
+    /// Returns the minimum and maximum supported version of the API.
+    /// The flexible versions (compact encoding with the tagged fields) are not supported,
+    /// and the clients fall back to the highest version advertised by `ApiVersions`.
+    pub fn versions(&self) -> (i16, i16) {
+        match self {
+            ApiKey::Produce => (3, 7),
+            ApiKey::Fetch => (4, 7),
+            ApiKey::ListOffsets => (1, 4),
+            ApiKey::Metadata => (0, 5),
+            ApiKey::OffsetCommit => (2, 6),
+            ApiKey::OffsetFetch => (1, 5),
+            ApiKey::FindCoordinator => (0, 2),
+            ApiKey::JoinGroup => (0, 5),
+            ApiKey::Heartbeat => (0, 3),
+            ApiKey::LeaveGroup => (0, 2),
+            ApiKey::SyncGroup => (0, 3),
+            ApiKey::SaslHandshake => (1, 1),
+            ApiKey::ApiVersions => (0, 2),
+            ApiKey::InitProducerId => (0, 1),
+            ApiKey::SaslAuthenticate => (0, 1),
+        }
+    }
+
+    pub fn supports_version(&self, version: i16) -> bool {
+        let (min, max) = self.versions();
+        (min..=max).contains(&version)
+    }
+}
+
+/// Kafka error codes returned by the listener.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    UnknownServerError = -1,
+    None = 0,
+    OffsetOutOfRange = 1,
+    CorruptMessage = 2,
+    UnknownTopicOrPartition = 3,
+    CoordinatorNotAvailable = 15,
+    IllegalGeneration = 22,
+    InconsistentGroupProtocol = 23,
+    UnknownMemberId = 25,
+    InvalidSessionTimeout = 26,
+    RebalanceInProgress = 27,
+    TopicAuthorizationFailed = 29,
+    GroupAuthorizationFailed = 30,
+    UnsupportedSaslMechanism = 33,
+    IllegalSaslState = 34,
+    UnsupportedVersion = 35,
+    InvalidRequest = 42,
+    UnsupportedForMessageFormat = 43,
+    SaslAuthenticationFailed = 58,
+    UnsupportedCompressionType = 76,
+    InvalidRecord = 87,
+}
+
+impl ErrorCode {
+    pub fn code(&self) -> i16 {
+        *self as i16
+    }
+}
+
+impl From<&IggyError> for ErrorCode {
+    fn from(error: &IggyError) -> Self {
+        match error {
+            IggyError::StreamIdNotFound(_)
+            | IggyError::StreamNameNotFound(_)
+            | IggyError::TopicIdNotFound(_, _)
+            | IggyError::TopicNameNotFound(_, _)
+            | IggyError::PartitionNotFound(_, _, _)
+            | IggyError::NoPartitions(_, _)
+            | IggyError::InvalidIdentifier => ErrorCode::UnknownTopicOrPartition,
+            IggyError::Unauthorized => ErrorCode::TopicAuthorizationFailed,
+            IggyError::InvalidOffset(_) => ErrorCode::OffsetOutOfRange,
+            IggyError::InvalidMessagePayloadLength
+            | IggyError::TooBigMessagePayload
+            | IggyError::TooBigUserHeaders
+            | IggyError::InvalidHeaderKey => ErrorCode::InvalidRecord,
+            _ => ErrorCode::UnknownServerError,
+        }
+    }
+}
+
+/// Header of the request (v1), the client ID is used to identify the group members.
+#[derive(Debug)]
+pub struct RequestHeader {
+    pub api_key: i16,
+    pub api_version: i16,
+    pub correlation_id: i32,
+    pub client_id: Option<String>,
+}
+
+impl RequestHeader {
+    pub fn read(reader: &mut KafkaReader) -> Result<Self, IggyError> {
+        Ok(Self {
+            api_key: reader.read_i16()?,
+            api_version: reader.read_i16()?,
+            correlation_id: reader.read_i32()?,
+            client_id: reader.read_nullable_string()?,
+        })
+    }
+}
@@ -0,0 +1,303 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::kafka::codec::{KafkaReader, KafkaWriter};
+use crate::kafka::protocol::ErrorCode;
+use bytes::Bytes;
+
+/// The only supported version of the record batches (Kafka 0.11+).
+const RECORD_BATCH_MAGIC: i8 = 2;
+/// Size of the fields preceding the CRC, which are not covered by the checksum.
+const BATCH_CRC_OFFSET: usize = 21;
+/// Size of the base offset and batch length fields, which are not included in the batch length.
+const BATCH_LOG_OVERHEAD: usize = 12;
+const COMPRESSION_CODEC_MASK: i16 = 0x07;
+const CONTROL_BATCH_FLAG: i16 = 0x20;
+const NO_PRODUCER_ID: i64 = -1;
+const NO_PRODUCER_EPOCH: i16 = -1;
+const NO_SEQUENCE: i32 = -1;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KafkaRecord {
+    pub offset: i64,
+    pub timestamp: i64,
+    pub key: Option<Bytes>,
+    pub value: Option<Bytes>,
+    pub headers: Vec<KafkaHeader>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KafkaHeader {
+    pub key: String,
+    pub value: Option<Bytes>,
+}
+
+/// Decodes the records of all the batches sent by the producer.
+/// Only the uncompressed batches in the v2 format are supported, the control batches are skipped.
+pub fn decode_record_batches(data: Bytes) -> Result<Vec<KafkaRecord>, ErrorCode> {
+    let mut reader = KafkaReader::new(data);
+    let mut records = Vec::new();
+    while reader.remaining() > 0 {
+        let base_offset = reader.read_i64().map_err(|_| ErrorCode::CorruptMessage)?;
+        let batch_length = reader.read_i32().map_err(|_| ErrorCode::CorruptMessage)?;
+        if batch_length < 0 {
+            return Err(ErrorCode::CorruptMessage);
+        }
+
+        let batch = reader
+            .read_raw(batch_length as usize)
+            .map_err(|_| ErrorCode::CorruptMessage)?;
+        decode_record_batch(base_offset, batch, &mut records)?;
+    }
+    Ok(records)
+}
+
+fn decode_record_batch(
+    base_offset: i64,
+    batch: Bytes,
+    records: &mut Vec<KafkaRecord>,
+) -> Result<(), ErrorCode> {
+    let crc_covered_offset = BATCH_CRC_OFFSET - BATCH_LOG_OVERHEAD;
+    if batch.len() < crc_covered_offset {
+        return Err(ErrorCode::CorruptMessage);
+    }
+
+    let mut reader = KafkaReader::new(batch.clone());
+    let corrupt = |_| ErrorCode::CorruptMessage;
+    let _partition_leader_epoch = reader.read_i32().map_err(corrupt)?;
+    if reader.read_i8().map_err(corrupt)? != RECORD_BATCH_MAGIC {
+        return Err(ErrorCode::UnsupportedForMessageFormat);
+    }
+
+    let crc = reader.read_u32().map_err(corrupt)?;
+    if crc32c(&batch[crc_covered_offset..]) != crc {
+        return Err(ErrorCode::CorruptMessage);
+    }
+
+    let attributes = reader.read_i16().map_err(corrupt)?;
+    if attributes & COMPRESSION_CODEC_MASK != 0 {
+        return Err(ErrorCode::UnsupportedCompressionType);
+    }
+
+    let _last_offset_delta = reader.read_i32().map_err(corrupt)?;
+    let base_timestamp = reader.read_i64().map_err(corrupt)?;
+    let _max_timestamp = reader.read_i64().map_err(corrupt)?;
+    let _producer_id = reader.read_i64().map_err(corrupt)?;
+    let _producer_epoch = reader.read_i16().map_err(corrupt)?;
+    let _base_sequence = reader.read_i32().map_err(corrupt)?;
+    let records_count = reader.read_i32().map_err(corrupt)?;
+    if attributes & CONTROL_BATCH_FLAG != 0 {
+        return Ok(());
+    }
+
+    for _ in 0..records_count {
+        let record = decode_record(&mut reader, base_offset, base_timestamp).map_err(corrupt)?;
+        records.push(record);
+    }
+    Ok(())
+}
+
+fn decode_record(
+    reader: &mut KafkaReader,
+    base_offset: i64,
+    base_timestamp: i64,
+) -> Result<KafkaRecord, iggy_common::IggyError> {
+    let length = reader.read_varint()?;
+    let mut reader = KafkaReader::new(reader.read_raw(length.max(0) as usize)?);
+    let _attributes = reader.read_i8()?;
+    let timestamp_delta = reader.read_varlong()?;
+    let offset_delta = reader.read_varint()?;
+    let key = read_varint_bytes(&mut reader)?;
+    let value = read_varint_bytes(&mut reader)?;
+    let headers_count = reader.read_varint()?;
+    let mut headers = Vec::with_capacity(headers_count.clamp(0, 64) as usize);
+    for _ in 0..headers_count {
+        let key = read_varint_bytes(&mut reader)?.unwrap_or_default();
+        let key =
+            String::from_utf8(key.to_vec()).map_err(|_| iggy_common::IggyError::InvalidFormat)?;
+        let value = read_varint_bytes(&mut reader)?;
+        headers.push(KafkaHeader { key, value });
+    }
+
+    Ok(KafkaRecord {
+        offset: base_offset + offset_delta as i64,
+        timestamp: base_timestamp + timestamp_delta,
+        key,
+        value,
+        headers,
+    })
+}
+
+fn read_varint_bytes(reader: &mut KafkaReader) -> Result<Option<Bytes>, iggy_common::IggyError> {
+    let length = reader.read_varint()?;
+    if length < 0 {
+        return Ok(None);
+    }
+    reader.read_raw(length as usize).map(Some)
+}
+
+fn write_varint_bytes(writer: &mut KafkaWriter, value: Option<&[u8]>) {
+    match value {
+        Some(value) => {
+            writer.write_varint(value.len() as i32);
+            writer.write_raw(value);
+        }
+        None => writer.write_varint(-1),
+    }
+}
+
+/// Encodes the records (ordered by offset) as a single uncompressed batch in the v2 format.
+pub fn encode_record_batch(records: &[KafkaRecord]) -> Bytes {
+    let Some(first) = records.first() else {
+        return Bytes::new();
+    };
+
+    let base_offset = first.offset;
+    let base_timestamp = records
+        .iter()
+        .map(|record| record.timestamp)
+        .min()
+        .unwrap_or_default();
+    let max_timestamp = records
+        .iter()
+        .map(|record| record.timestamp)
+        .max()
+        .unwrap_or_default();
+    let last_offset_delta = records
+        .last()
+        .map_or(0, |record| record.offset - base_offset);
+
+    let mut body = KafkaWriter::new();
+    body.write_i16(0);
+    body.write_i32(last_offset_delta as i32);
+    body.write_i64(base_timestamp);
+    body.write_i64(max_timestamp);
+    body.write_i64(NO_PRODUCER_ID);
+    body.write_i16(NO_PRODUCER_EPOCH);
+    body.write_i32(NO_SEQUENCE);
+    body.write_i32(records.len() as i32);
+    for record in records {
+        let mut encoded = KafkaWriter::new();
+        encoded.write_i8(0);
+        encoded.write_varlong(record.timestamp - base_timestamp);
+        encoded.write_varint((record.offset - base_offset) as i32);
+        write_varint_bytes(&mut encoded, record.key.as_deref());
+        write_varint_bytes(&mut encoded, record.value.as_deref());
+        encoded.write_varint(record.headers.len() as i32);
+        for header in &record.headers {
+            write_varint_bytes(&mut encoded, Some(header.key.as_bytes()));
+            write_varint_bytes(&mut encoded, header.value.as_deref());
+        }
+
+        body.write_varint(encoded.len() as i32);
+        body.write_raw(&encoded.into_bytes());
+    }
+
+    let body = body.into_bytes();
+    let mut batch = KafkaWriter::new();
+    batch.write_i64(base_offset);
+    batch.write_i32((BATCH_CRC_OFFSET - BATCH_LOG_OVERHEAD + body.len()) as i32);
+    batch.write_i32(0);
+    batch.write_i8(RECORD_BATCH_MAGIC);
+    batch.write_u32(crc32c(&body));
+    batch.write_raw(&body);
+    batch.into_bytes()
+}
+
+const CRC32C_TABLE: [u32; 256] = crc32c_table();
+
+const fn crc32c_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut index = 0;
+    while index < 256 {
+        let mut crc = index as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0x82f6_3b78
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[index] = crc;
+        index += 1;
+    }
+    table
+}
+
+/// CRC-32C (Castagnoli) checksum used by the record batches.
+pub fn crc32c(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, byte| {
+        CRC32C_TABLE[((crc ^ *byte as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_compute_crc32c() {
+        assert_eq!(crc32c(b"123456789"), 0xe306_9283);
+    }
+
+    #[test]
+    fn should_encode_and_decode_record_batch() {
+        let records = vec![
+            KafkaRecord {
+                offset: 10,
+                timestamp: 1_000,
+                key: Some(Bytes::from_static(b"key")),
+                value: Some(Bytes::from_static(b"value-1")),
+                headers: vec![KafkaHeader {
+                    key: "trace".to_owned(),
+                    value: Some(Bytes::from_static(b"1")),
+                }],
+            },
+            KafkaRecord {
+                offset: 11,
+                timestamp: 1_500,
+                key: None,
+                value: Some(Bytes::from_static(b"value-2")),
+                headers: vec![],
+            },
+        ];
+
+        let batch = encode_record_batch(&records);
+        let decoded = decode_record_batches(batch).unwrap();
+        assert_eq!(decoded, records);
+    }
+
+    #[test]
+    fn should_reject_batch_with_invalid_crc() {
+        let records = vec![KafkaRecord {
+            offset: 0,
+            timestamp: 0,
+            key: None,
+            value: Some(Bytes::from_static(b"value")),
+            headers: vec![],
+        }];
+        let mut batch = encode_record_batch(&records).to_vec();
+        let last = batch.len() - 1;
+        batch[last] ^= 0xff;
+
+        let result = decode_record_batches(Bytes::from(batch));
+        assert_eq!(result, Err(ErrorCode::CorruptMessage));
+    }
+}
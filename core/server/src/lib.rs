@@ -23,6 +23,16 @@ use mimalloc::MiMalloc;
 #[global_allocator]
 static GLOBAL: MiMalloc = MiMalloc;
 
+#[cfg(feature = "jemalloc")]
+#[global_allocator]
+static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
+
+// Enables the heap profiling sampling (every 512 KiB on average), can be overridden via `_RJEM_MALLOC_CONF`.
+#[cfg(feature = "jemalloc")]
+#[allow(non_upper_case_globals)]
+#[unsafe(export_name = "_rjem_malloc_conf")]
+pub static malloc_conf: &[u8] = b"prof:true,prof_active:true,lg_prof_sample:19\0";
+
 #[cfg(windows)]
 compile_error!("iggy-server doesn't support windows.");
 
@@ -33,13 +43,17 @@ pub mod channels;
 pub(crate) mod compat;
 pub mod configs;
 pub mod http;
+pub mod kafka;
 pub mod log;
+pub mod mqtt;
 pub mod quic;
 pub mod server_error;
 pub mod state;
 pub mod streaming;
 pub mod tcp;
+pub mod unix;
 pub mod versioning;
+pub mod websocket;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 const IGGY_ROOT_USERNAME_ENV: &str = "IGGY_ROOT_USERNAME";
@@ -17,9 +17,13 @@
  */
 
 use crate::VERSION;
-use crate::configs::server::{TelemetryConfig, TelemetryTransport};
+use crate::configs::server::{ServerConfig, TelemetryConfig, TelemetryTransport};
 use crate::configs::system::LoggingConfig;
+use crate::log::runtime_filter::{EnvFilterReloader, RuntimeLogFilter};
+use crate::log::topic_sink::{LogShipper, TopicLogLayer};
 use crate::server_error::LogError;
+use crate::streaming::diagnostics::trace_links;
+use crate::streaming::systems::system::SharedSystem;
 use opentelemetry::KeyValue;
 use opentelemetry::global;
 use opentelemetry::trace::TracerProvider;
@@ -27,6 +31,7 @@ use opentelemetry_appender_tracing::layer::OpenTelemetryTracingBridge;
 use opentelemetry_otlp::{WithExportConfig, WithHttpConfig};
 use opentelemetry_sdk::Resource;
 use opentelemetry_sdk::logs::log_processor_with_async_runtime;
+use opentelemetry_sdk::metrics::periodic_reader_with_async_runtime;
 use opentelemetry_sdk::propagation::TraceContextPropagator;
 use opentelemetry_sdk::runtime;
 use opentelemetry_sdk::trace::span_processor_with_async_runtime;
@@ -34,6 +39,7 @@ use std::io::{self, Write};
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
+use tokio::sync::watch;
 use tracing::{Level, event, info, trace};
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_opentelemetry::OpenTelemetryLayer;
@@ -122,8 +128,17 @@ pub struct Logging {
     filtering_stdout_reload_handle: Option<ReloadHandle>,
     filtering_file_reload_handle: Option<ReloadHandle>,
 
+    // Set during early_init, while the runtime filter is created during late_init, once the configured level is known.
+    default_filter: String,
+    env_filter_reloader: Option<EnvFilterReloader>,
+    runtime_filter: Option<Arc<RuntimeLogFilter>>,
+
     early_logs_buffer: Arc<Mutex<Vec<String>>>,
 
+    // Activated during late_init if the logs should be shipped to the topic, the shipper is started once the system is loaded.
+    topic_layer: TopicLogLayer,
+    log_shipper: Option<LogShipper>,
+
     telemetry_config: TelemetryConfig,
 }
 
@@ -136,7 +151,12 @@ impl Logging {
             file_reload_handle: None,
             filtering_stdout_reload_handle: None,
             filtering_file_reload_handle: None,
+            default_filter: String::new(),
+            env_filter_reloader: None,
+            runtime_filter: None,
             early_logs_buffer: Arc::new(Mutex::new(vec![])),
+            topic_layer: TopicLogLayer::default(),
+            log_shipper: None,
             telemetry_config,
         }
     }
@@ -174,12 +194,21 @@ impl Logging {
         self.file_reload_handle = Some(file_layer_reload_handle);
         layers.push(file_layer.and_then(filtering_file_layer));
 
+        // The global filter can be changed at runtime, e.g. to enable the debug logs of a single module.
+        let env_filter = EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("INFO"));
+        self.default_filter = env_filter.to_string();
+
         if !self.telemetry_config.enabled {
+            let (env_filter, env_filter_reload_handle) = reload::Layer::new(env_filter);
             // This is moment when we can start logging something and not worry about losing it.
             Registry::default()
                 .with(layers)
-                .with(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("INFO")))
+                .with(self.topic_layer.clone())
+                .with(env_filter)
                 .init();
+            self.env_filter_reloader = Some(Box::new(move |filter| {
+                env_filter_reload_handle.reload(filter)
+            }));
             Self::print_build_info();
             return;
         }
@@ -257,16 +286,61 @@ impl Logging {
             }
         };
 
+        let metrics_interval = self.telemetry_config.metrics.interval.get_duration();
+        let meter_provider = match self.telemetry_config.metrics.transport {
+            TelemetryTransport::GRPC => opentelemetry_sdk::metrics::SdkMeterProvider::builder()
+                .with_resource(resource.clone())
+                .with_reader(
+                    opentelemetry_sdk::metrics::PeriodicReader::builder(
+                        opentelemetry_otlp::MetricExporter::builder()
+                            .with_tonic()
+                            .with_endpoint(self.telemetry_config.metrics.endpoint.clone())
+                            .build()
+                            .expect("Failed to initialize gRPC metrics exporter."),
+                    )
+                    .with_interval(metrics_interval)
+                    .build(),
+                )
+                .build(),
+            TelemetryTransport::HTTP => {
+                let metric_exporter = opentelemetry_otlp::MetricExporter::builder()
+                    .with_http()
+                    .with_http_client(reqwest::Client::new())
+                    .with_endpoint(self.telemetry_config.metrics.endpoint.clone())
+                    .with_protocol(opentelemetry_otlp::Protocol::HttpBinary)
+                    .build()
+                    .expect("Failed to initialize HTTP metrics exporter.");
+                opentelemetry_sdk::metrics::SdkMeterProvider::builder()
+                    .with_resource(resource.clone())
+                    .with_reader(
+                        periodic_reader_with_async_runtime::PeriodicReader::builder(
+                            metric_exporter,
+                            runtime::Tokio,
+                        )
+                        .with_interval(metrics_interval)
+                        .build(),
+                    )
+                    .build()
+            }
+        };
+
         let tracer = tracer_provider.tracer(service_name);
         global::set_tracer_provider(tracer_provider.clone());
+        global::set_meter_provider(meter_provider);
         global::set_text_map_propagator(TraceContextPropagator::new());
 
+        let (env_filter, env_filter_reload_handle) = reload::Layer::new(env_filter);
         Registry::default()
             .with(layers)
+            .with(self.topic_layer.clone())
             .with(OpenTelemetryTracingBridge::new(&logger_provider))
             .with(OpenTelemetryLayer::new(tracer))
-            .with(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("INFO")))
+            .with(env_filter)
             .init();
+        self.env_filter_reloader = Some(Box::new(move |filter| {
+            env_filter_reload_handle.reload(filter)
+        }));
+        trace_links::enable();
         Self::print_build_info();
     }
 
@@ -337,6 +411,27 @@ impl Logging {
             .ok_or(LogError::FileReloadFailure)?
             .modify(|layer| *layer = file_layer)
             .expect("Failed to modify file layer");
+
+        if let (Some(reload_filter), Some(stdout_handle), Some(file_handle)) = (
+            self.env_filter_reloader.take(),
+            self.filtering_stdout_reload_handle.clone(),
+            self.filtering_file_reload_handle.clone(),
+        ) {
+            self.runtime_filter = Some(Arc::new(RuntimeLogFilter::new(
+                std::mem::take(&mut self.default_filter),
+                filtering_level,
+                reload_filter,
+                Box::new(move |level| {
+                    stdout_handle.modify(|layer| *layer = level.boxed())?;
+                    file_handle.modify(|layer| *layer = level.boxed())
+                }),
+            )));
+        }
+
+        if config.topic.enabled {
+            self.log_shipper = self.topic_layer.activate(&config.topic);
+        }
+
         let level = filtering_level.to_string();
 
         let print = format!(
@@ -355,6 +450,41 @@ impl Logging {
         Ok(())
     }
 
+    /// Applies the log level changed by the configuration reload, unless it's overridden by RUST_LOG.
+    pub fn start_filter_reloader(&self, mut config_changes: watch::Receiver<Arc<ServerConfig>>) {
+        let Some(runtime_filter) = self.runtime_filter.clone() else {
+            return;
+        };
+
+        let mut current_level =
+            Self::get_filtering_level(Some(&config_changes.borrow().system.logging));
+        tokio::spawn(async move {
+            while config_changes.changed().await.is_ok() {
+                let config = config_changes.borrow_and_update().clone();
+                let level = Self::get_filtering_level(Some(&config.system.logging));
+                if level == current_level {
+                    continue;
+                }
+
+                if runtime_filter.set_level(level).is_ok() {
+                    current_level = level;
+                }
+            }
+        });
+    }
+
+    /// Starts appending the buffered logs to the topic, if it's enabled.
+    pub fn start_log_shipper(&mut self, system: SharedSystem) {
+        if let Some(log_shipper) = self.log_shipper.take() {
+            log_shipper.start(system);
+        }
+    }
+
+    /// Returns the filter which can be changed at runtime, available once the logging is initialized.
+    pub fn runtime_filter(&self) -> Option<Arc<RuntimeLogFilter>> {
+        self.runtime_filter.clone()
+    }
+
     // RUST_LOG always takes precedence over config
     fn get_filtering_level(config: Option<&LoggingConfig>) -> LevelFilter {
         if let Ok(rust_log) = std::env::var("RUST_LOG") {
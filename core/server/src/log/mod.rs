@@ -21,3 +21,6 @@ pub mod logger;
 
 #[cfg(feature = "tokio-console")]
 pub mod tokio_console;
+
+pub mod runtime_filter;
+pub mod topic_sink;
@@ -0,0 +1,276 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy_common::{IggyDuration, IggyError, IggyTimestamp, LogFilter};
+use std::sync::{Arc, Mutex};
+use tracing::{error, info};
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::reload;
+
+// The reload handles are generic over the whole subscriber stack, which differs
+// depending on whether the telemetry is enabled, so they're captured by the closures.
+pub type EnvFilterReloader = Box<dyn Fn(EnvFilter) -> Result<(), reload::Error> + Send + Sync>;
+pub type LevelFilterReloader = Box<dyn Fn(LevelFilter) -> Result<(), reload::Error> + Send + Sync>;
+
+/// Changes the tracing filter of the running server (e.g. on behalf of the API), on top of
+/// the default filter based on `RUST_LOG`, and the level filter of the outputs based on the logging config.
+/// While the runtime filter is set, the level filter doesn't restrict it, and the changes of
+/// the configured level are applied only once the runtime filter is reset or expires.
+pub struct RuntimeLogFilter {
+    default_filter: String,
+    reload_filter: EnvFilterReloader,
+    reload_level: LevelFilterReloader,
+    state: Mutex<RuntimeLogFilterState>,
+}
+
+#[derive(Debug)]
+struct RuntimeLogFilterState {
+    level: LevelFilter,
+    filter: Option<String>,
+    expires_at: Option<IggyTimestamp>,
+    // Incremented on each change, so that the scheduled revert doesn't reset the newer filter.
+    generation: u64,
+}
+
+impl std::fmt::Debug for RuntimeLogFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RuntimeLogFilter")
+            .field("default_filter", &self.default_filter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RuntimeLogFilter {
+    pub fn new(
+        default_filter: String,
+        level: LevelFilter,
+        reload_filter: EnvFilterReloader,
+        reload_level: LevelFilterReloader,
+    ) -> Self {
+        Self {
+            default_filter,
+            reload_filter,
+            reload_level,
+            state: Mutex::new(RuntimeLogFilterState {
+                level,
+                filter: None,
+                expires_at: None,
+                generation: 0,
+            }),
+        }
+    }
+
+    pub fn get(&self) -> LogFilter {
+        let state = self.state.lock().unwrap();
+        self.map_log_filter(&state)
+    }
+
+    /// Sets the runtime filter, or resets it to the default one if not provided.
+    /// If the TTL is provided, the default filter is restored once it elapses.
+    pub fn set(
+        self: &Arc<Self>,
+        filter: Option<&str>,
+        ttl: Option<IggyDuration>,
+    ) -> Result<LogFilter, IggyError> {
+        let mut state = self.state.lock().unwrap();
+        let Some(filter) = filter else {
+            state.generation += 1;
+            self.reset(&mut state)?;
+            return Ok(self.map_log_filter(&state));
+        };
+
+        let env_filter = EnvFilter::builder()
+            .parse(filter)
+            .map_err(|error| IggyError::InvalidLogFilter(format!("{filter}, {error}")))?;
+        (self.reload_filter)(env_filter).map_err(|error| {
+            error!("Failed to change log filter to: {filter}. {error}");
+            IggyError::CannotReloadLogFilter
+        })?;
+        (self.reload_level)(LevelFilter::TRACE).map_err(|error| {
+            error!(
+                "Failed to change log level to: {}. {error}",
+                LevelFilter::TRACE
+            );
+            IggyError::CannotReloadLogFilter
+        })?;
+
+        state.generation += 1;
+        state.filter = Some(filter.to_owned());
+        state.expires_at =
+            ttl.map(|ttl| IggyTimestamp::from(IggyTimestamp::now().as_micros() + ttl.as_micros()));
+        if let Some(ttl) = ttl {
+            let generation = state.generation;
+            let runtime_filter = self.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(ttl.get_duration()).await;
+                runtime_filter.expire(generation);
+            });
+        }
+
+        info!(
+            "Changed log filter to: {filter}, expires at: {}.",
+            state
+                .expires_at
+                .map(|expires_at| expires_at.to_string())
+                .unwrap_or_else(|| "never".to_owned())
+        );
+        Ok(self.map_log_filter(&state))
+    }
+
+    /// Changes the configured level of the outputs, applied immediately unless the runtime filter is set.
+    pub fn set_level(&self, level: LevelFilter) -> Result<(), IggyError> {
+        let mut state = self.state.lock().unwrap();
+        let previous_level = state.level;
+        state.level = level;
+        if state.filter.is_some() {
+            info!(
+                "Changed log level from: {previous_level} to: {level}, it will be applied once the runtime log filter is reset."
+            );
+            return Ok(());
+        }
+
+        (self.reload_level)(level).map_err(|error| {
+            error!("Failed to change log level to: {level}. {error}");
+            IggyError::CannotReloadLogFilter
+        })?;
+        info!("Changed log level from: {previous_level} to: {level}.");
+        Ok(())
+    }
+
+    fn expire(&self, generation: u64) {
+        let mut state = self.state.lock().unwrap();
+        if state.generation != generation {
+            return;
+        }
+
+        if let Err(error) = self.reset(&mut state) {
+            error!("Failed to revert the expired log filter. {error}");
+        }
+    }
+
+    fn reset(&self, state: &mut RuntimeLogFilterState) -> Result<(), IggyError> {
+        if state.filter.is_none() {
+            return Ok(());
+        }
+
+        (self.reload_filter)(EnvFilter::new(&self.default_filter)).map_err(|error| {
+            error!(
+                "Failed to change log filter to: {}. {error}",
+                self.default_filter
+            );
+            IggyError::CannotReloadLogFilter
+        })?;
+        (self.reload_level)(state.level).map_err(|error| {
+            error!("Failed to change log level to: {}. {error}", state.level);
+            IggyError::CannotReloadLogFilter
+        })?;
+        state.filter = None;
+        state.expires_at = None;
+        info!(
+            "Reverted log filter to: {}, log level: {}.",
+            self.default_filter, state.level
+        );
+        Ok(())
+    }
+
+    fn map_log_filter(&self, state: &RuntimeLogFilterState) -> LogFilter {
+        LogFilter {
+            filter: state
+                .filter
+                .clone()
+                .unwrap_or_else(|| self.default_filter.clone()),
+            default_filter: self.default_filter.clone(),
+            expires_at: state.expires_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn runtime_log_filter(levels: Arc<Mutex<Vec<LevelFilter>>>) -> Arc<RuntimeLogFilter> {
+        Arc::new(RuntimeLogFilter::new(
+            "info".to_owned(),
+            LevelFilter::INFO,
+            Box::new(|_| Ok(())),
+            Box::new(move |level| {
+                levels.lock().unwrap().push(level);
+                Ok(())
+            }),
+        ))
+    }
+
+    #[tokio::test]
+    async fn should_set_and_reset_filter() {
+        let levels = Arc::new(Mutex::new(Vec::new()));
+        let runtime_filter = runtime_log_filter(levels.clone());
+
+        let log_filter = runtime_filter
+            .set(Some("warn,server::streaming=debug"), None)
+            .unwrap();
+        assert_eq!(log_filter.filter, "warn,server::streaming=debug");
+        assert_eq!(log_filter.default_filter, "info");
+        assert!(log_filter.expires_at.is_none());
+
+        let log_filter = runtime_filter.set(None, None).unwrap();
+        assert_eq!(log_filter.filter, "info");
+        assert_eq!(
+            *levels.lock().unwrap(),
+            vec![LevelFilter::TRACE, LevelFilter::INFO]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_set_invalid_filter() {
+        let runtime_filter = runtime_log_filter(Arc::new(Mutex::new(Vec::new())));
+        let result = runtime_filter.set(Some("server=verbose"), None);
+        assert!(matches!(result, Err(IggyError::InvalidLogFilter(_))));
+        assert_eq!(runtime_filter.get().filter, "info");
+    }
+
+    #[tokio::test]
+    async fn should_revert_filter_once_ttl_elapses() {
+        let runtime_filter = runtime_log_filter(Arc::new(Mutex::new(Vec::new())));
+        let log_filter = runtime_filter
+            .set(Some("debug"), Some(IggyDuration::from(100_000)))
+            .unwrap();
+        assert!(log_filter.expires_at.is_some());
+        assert_eq!(runtime_filter.get().filter, "debug");
+
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+        let log_filter = runtime_filter.get();
+        assert_eq!(log_filter.filter, "info");
+        assert!(log_filter.expires_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_defer_configured_level_while_filter_is_set() {
+        let levels = Arc::new(Mutex::new(Vec::new()));
+        let runtime_filter = runtime_log_filter(levels.clone());
+        runtime_filter.set(Some("debug"), None).unwrap();
+        runtime_filter.set_level(LevelFilter::WARN).unwrap();
+        runtime_filter.set(None, None).unwrap();
+        assert_eq!(
+            *levels.lock().unwrap(),
+            vec![LevelFilter::TRACE, LevelFilter::WARN]
+        );
+    }
+}
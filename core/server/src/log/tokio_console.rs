@@ -16,9 +16,13 @@
  * under the License.
  */
 
-use crate::configs::server::TelemetryConfig;
+use crate::configs::server::{ServerConfig, TelemetryConfig};
 use crate::configs::system::LoggingConfig;
+use crate::log::runtime_filter::RuntimeLogFilter;
 use crate::server_error::ServerError;
+use crate::streaming::systems::system::SharedSystem;
+use std::sync::Arc;
+use tokio::sync::watch;
 use tracing_subscriber::prelude::*;
 
 pub struct Logging {}
@@ -44,6 +48,14 @@ impl Logging {
     ) -> Result<(), ServerError> {
         Ok(())
     }
+
+    pub fn start_filter_reloader(&self, _config_changes: watch::Receiver<Arc<ServerConfig>>) {}
+
+    pub fn start_log_shipper(&mut self, _system: SharedSystem) {}
+
+    pub fn runtime_filter(&self) -> Option<Arc<RuntimeLogFilter>> {
+        None
+    }
 }
 
 impl Default for Logging {
@@ -0,0 +1,314 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::LoggingTopicConfig;
+use crate::streaming::systems::system::SharedSystem;
+use bytes::Bytes;
+use flume::{Receiver, Sender, TrySendError};
+use iggy_common::{IggyMessage, IggyTimestamp};
+use serde_json::{Map, Value};
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::task::{Context, Poll};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber, error, info, warn};
+use tracing_subscriber::Layer;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer;
+
+const MAX_BATCH_SIZE: usize = 1000;
+
+thread_local! {
+    // Set while the logs are being appended to the topic, as the write path logs on its own,
+    // and shipping these logs as well would feed the topic with its own records.
+    static SHIPPING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Layer buffering the log records (in JSON format) which are appended to the internal topic
+/// by the `LogShipper`. It doesn't do anything until it's activated.
+#[derive(Clone, Default)]
+pub struct TopicLogLayer {
+    sink: Arc<OnceLock<TopicLogSink>>,
+}
+
+struct TopicLogSink {
+    sender: Sender<Bytes>,
+    level: LevelFilter,
+    dropped: Arc<AtomicU64>,
+}
+
+impl TopicLogLayer {
+    /// Starts buffering the log records and returns the shipper, which appends them to the topic once started.
+    pub fn activate(&self, config: &LoggingTopicConfig) -> Option<LogShipper> {
+        let (sender, receiver) = flume::bounded(config.buffer_size);
+        let dropped = Arc::new(AtomicU64::new(0));
+        let sink = TopicLogSink {
+            sender,
+            level: LevelFilter::from_str(&config.level).unwrap_or(LevelFilter::INFO),
+            dropped: dropped.clone(),
+        };
+        if self.sink.set(sink).is_err() {
+            return None;
+        }
+
+        Some(LogShipper {
+            config: config.clone(),
+            receiver,
+            dropped,
+        })
+    }
+}
+
+impl<S: Subscriber> Layer<S> for TopicLogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: layer::Context<'_, S>) {
+        let Some(sink) = self.sink.get() else {
+            return;
+        };
+
+        let metadata = event.metadata();
+        if *metadata.level() > sink.level || SHIPPING.get() {
+            return;
+        }
+
+        let mut visitor = JsonVisitor::default();
+        event.record(&mut visitor);
+        let mut record = Map::new();
+        record.insert(
+            "timestamp".to_owned(),
+            IggyTimestamp::now().to_rfc3339_string().into(),
+        );
+        record.insert("level".to_owned(), metadata.level().as_str().into());
+        record.insert("target".to_owned(), metadata.target().into());
+        record.insert(
+            "message".to_owned(),
+            visitor.message.unwrap_or_default().into(),
+        );
+        record.insert("fields".to_owned(), Value::Object(visitor.fields));
+        let Ok(payload) = serde_json::to_vec(&record) else {
+            return;
+        };
+
+        if let Err(TrySendError::Full(_)) = sink.sender.try_send(Bytes::from(payload)) {
+            sink.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+#[derive(Default)]
+struct JsonVisitor {
+    message: Option<String>,
+    fields: Map<String, Value>,
+}
+
+impl JsonVisitor {
+    fn insert(&mut self, field: &Field, value: Value) {
+        if field.name() == "message" {
+            self.message = Some(match value {
+                Value::String(message) => message,
+                value => value.to_string(),
+            });
+            return;
+        }
+
+        self.fields.insert(field.name().to_owned(), value);
+    }
+}
+
+impl Visit for JsonVisitor {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.insert(field, value.into());
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.insert(field, value.into());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.insert(field, value.into());
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.insert(field, value.into());
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.insert(field, value.into());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.insert(field, format!("{value:?}").into());
+    }
+}
+
+/// Appends the buffered log records to the internal topic, in batches.
+pub struct LogShipper {
+    config: LoggingTopicConfig,
+    receiver: Receiver<Bytes>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl LogShipper {
+    pub fn start(self, system: SharedSystem) {
+        tokio::spawn(WithoutShipping::new(async move {
+            let config = &self.config;
+            if let Err(error) = system
+                .write()
+                .await
+                .create_internal_topic(&config.stream, &config.topic, config.message_expiry)
+                .await
+            {
+                error!(
+                    "Failed to initialize the logs topic: {}/{}, the logs will not be shipped. {error}",
+                    config.stream, config.topic
+                );
+                return;
+            }
+
+            info!(
+                "Logs will be shipped to: {}/{} with level: {}.",
+                config.stream, config.topic, config.level
+            );
+            while let Ok(record) = self.receiver.recv_async().await {
+                let messages = std::iter::once(record)
+                    .chain(self.receiver.try_iter().take(MAX_BATCH_SIZE - 1))
+                    .filter_map(|payload| IggyMessage::builder().payload(payload).build().ok())
+                    .collect::<Vec<_>>();
+                if let Err(error) = system
+                    .read()
+                    .await
+                    .append_internal_messages(&config.stream, &config.topic, &messages)
+                    .await
+                {
+                    error!(
+                        "Failed to append {} log records to: {}/{}. {error}",
+                        messages.len(),
+                        config.stream,
+                        config.topic
+                    );
+                }
+
+                let dropped = self.dropped.swap(0, Ordering::Relaxed);
+                if dropped > 0 {
+                    warn!(
+                        "Dropped {dropped} log records, as the buffer of the logs topic: {}/{} was full.",
+                        config.stream, config.topic
+                    );
+                }
+            }
+        }));
+    }
+}
+
+// Marks the logs produced while polling the future, so that they're not shipped to the topic.
+struct WithoutShipping<F> {
+    future: Pin<Box<F>>,
+}
+
+impl<F> WithoutShipping<F> {
+    fn new(future: F) -> Self {
+        Self {
+            future: Box::pin(future),
+        }
+    }
+}
+
+impl<F: Future> Future for WithoutShipping<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let shipping = SHIPPING.replace(true);
+        let poll = self.future.as_mut().poll(cx);
+        SHIPPING.set(shipping);
+        poll
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::future::ready;
+    use tracing_subscriber::Registry;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    fn config(buffer_size: usize) -> LoggingTopicConfig {
+        LoggingTopicConfig {
+            enabled: true,
+            buffer_size,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn should_buffer_log_records_in_json_format_once_activated() {
+        let layer = TopicLogLayer::default();
+        let subscriber = Registry::default().with(layer.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            info!("Not buffered.");
+            let shipper = layer.activate(&config(10)).unwrap();
+            info!(stream_id = 1, "Created stream.");
+            tracing::debug!("Below the level.");
+
+            let records = shipper.receiver.try_iter().collect::<Vec<_>>();
+            assert_eq!(records.len(), 1);
+            let record: Value = serde_json::from_slice(&records[0]).unwrap();
+            assert_eq!(record["level"], "INFO");
+            assert_eq!(record["message"], "Created stream.");
+            assert_eq!(record["fields"]["stream_id"], 1);
+            assert_eq!(record["target"], module_path!());
+            assert!(record["timestamp"].is_string());
+        });
+    }
+
+    #[test]
+    fn should_drop_log_records_when_buffer_is_full() {
+        let layer = TopicLogLayer::default();
+        let subscriber = Registry::default().with(layer.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            let shipper = layer.activate(&config(2)).unwrap();
+            for i in 0..5 {
+                info!("Record {i}.");
+            }
+
+            assert_eq!(shipper.receiver.len(), 2);
+            assert_eq!(shipper.dropped.load(Ordering::Relaxed), 3);
+        });
+    }
+
+    #[tokio::test]
+    async fn should_not_buffer_log_records_produced_while_shipping() {
+        let layer = TopicLogLayer::default();
+        let subscriber = Registry::default().with(layer.clone());
+        let _guard = tracing::subscriber::set_default(subscriber);
+        let shipper = layer.activate(&config(10)).unwrap();
+        WithoutShipping::new(async {
+            info!("Shipping.");
+            ready(()).await
+        })
+        .await;
+        info!("Not shipping.");
+
+        let records = shipper.receiver.try_iter().collect::<Vec<_>>();
+        assert_eq!(records.len(), 1);
+        let record: Value = serde_json::from_slice(&records[0]).unwrap();
+        assert_eq!(record["message"], "Not shipping.");
+    }
+}
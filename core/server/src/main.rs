@@ -23,25 +23,35 @@ use figlet_rs::FIGfont;
 use server::args::Args;
 use server::channels::commands::archive_state::ArchiveStateExecutor;
 use server::channels::commands::clean_personal_access_tokens::CleanPersonalAccessTokensExecutor;
+use server::channels::commands::evict_slow_clients::EvictSlowClientsExecutor;
+use server::channels::commands::export_metrics::ExportMetricsExecutor;
 use server::channels::commands::maintain_messages::MaintainMessagesExecutor;
 use server::channels::commands::print_sysinfo::SysInfoPrintExecutor;
+use server::channels::commands::publish_system_events::PublishSystemEventsExecutor;
+use server::channels::commands::sample_stats_history::SampleStatsHistoryExecutor;
 use server::channels::commands::save_messages::SaveMessagesExecutor;
 use server::channels::commands::verify_heartbeats::VerifyHeartbeatsExecutor;
 use server::channels::handler::BackgroundServerCommandHandler;
 use server::configs::config_provider;
+use server::configs::reload::ConfigReloader;
 use server::configs::server::ServerConfig;
 use server::http::http_server;
+use server::kafka::kafka_server;
 #[cfg(not(feature = "tokio-console"))]
 use server::log::logger::Logging;
 #[cfg(feature = "tokio-console")]
 use server::log::tokio_console::Logging;
+use server::mqtt::mqtt_server;
 use server::quic::quic_server;
 use server::server_error::ServerError;
+use server::streaming::diagnostics::startup;
 use server::streaming::systems::system::{SharedSystem, System};
 use server::streaming::utils::MemoryPool;
 use server::tcp::tcp_server;
+use server::unix::unix_server;
+use std::sync::Arc;
 use tokio::time::Instant;
-use tracing::{info, instrument};
+use tracing::{error, info, instrument};
 
 #[tokio::main]
 #[instrument(skip_all, name = "trace_start_server")]
@@ -94,17 +104,33 @@ async fn main() -> Result<(), ServerError> {
 
     MemoryPool::init_pool(config.system.clone());
 
-    let system = SharedSystem::new(System::new(
+    let config_reloader = Arc::new(ConfigReloader::new(config_provider, config.clone()));
+    logging.start_filter_reloader(config_reloader.subscribe());
+
+    let mut system = System::new(
         config.system.clone(),
         config.data_maintenance.clone(),
         config.personal_access_token.clone(),
-    ));
+    );
+    system.config_reloader = Some(config_reloader);
+    system.log_filter = logging.runtime_filter();
+    let system = SharedSystem::new(system, config.health.clone());
+
+    let mut current_config = config.clone();
+
+    // The HTTP API is started before loading the data, so that the health probes
+    // can report the loading progress, while the other requests wait until it's done.
+    if config.http.enabled {
+        let http_addr = http_server::start(config.http.clone(), system.clone()).await;
+        current_config.http.address = http_addr.to_string();
+    }
 
     // Workaround to ensure that the statistics are initialized before the server
     // loads streams and starts accepting connections. This is necessary to
     // have the correct statistics when the server starts.
     system.write().await.get_stats().await?;
     system.write().await.init().await?;
+    logging.start_log_shipper(system.clone());
 
     let _command_handler = BackgroundServerCommandHandler::new(system.clone(), &config)
         .install_handler(SaveMessagesExecutor)
@@ -112,24 +138,22 @@ async fn main() -> Result<(), ServerError> {
         .install_handler(ArchiveStateExecutor)
         .install_handler(CleanPersonalAccessTokensExecutor)
         .install_handler(SysInfoPrintExecutor)
-        .install_handler(VerifyHeartbeatsExecutor);
+        .install_handler(SampleStatsHistoryExecutor)
+        .install_handler(VerifyHeartbeatsExecutor)
+        .install_handler(EvictSlowClientsExecutor::default())
+        .install_handler(ExportMetricsExecutor::default())
+        .install_handler(PublishSystemEventsExecutor::default());
 
     #[cfg(unix)]
-    let (mut ctrl_c, mut sigterm) = {
+    let (mut ctrl_c, mut sigterm, mut sighup) = {
         use tokio::signal::unix::{SignalKind, signal};
         (
             signal(SignalKind::interrupt())?,
             signal(SignalKind::terminate())?,
+            signal(SignalKind::hangup())?,
         )
     };
 
-    let mut current_config = config.clone();
-
-    if config.http.enabled {
-        let http_addr = http_server::start(config.http, system.clone()).await;
-        current_config.http.address = http_addr.to_string();
-    }
-
     if config.quic.enabled {
         let quic_addr = quic_server::start(config.quic, system.clone());
         current_config.quic.address = quic_addr.to_string();
@@ -140,12 +164,27 @@ async fn main() -> Result<(), ServerError> {
         current_config.tcp.address = tcp_addr.to_string();
     }
 
+    if config.unix.enabled {
+        unix_server::start(config.unix, system.clone()).await;
+    }
+
+    if config.kafka.enabled {
+        let kafka_addr = kafka_server::start(config.kafka, system.clone()).await;
+        current_config.kafka.address = kafka_addr.to_string();
+    }
+
+    if config.mqtt.enabled {
+        let mqtt_addr = mqtt_server::start(config.mqtt, system.clone()).await;
+        current_config.mqtt.address = mqtt_addr.to_string();
+    }
+
     let runtime_path = current_config.system.get_runtime_path();
     let current_config_path = format!("{runtime_path}/current_config.toml");
     let current_config_content =
         toml::to_string(&current_config).expect("Cannot serialize current_config");
     tokio::fs::write(current_config_path, current_config_content).await?;
 
+    startup::started();
     let elapsed_time = startup_timestamp.elapsed();
     info!(
         "Iggy server has started - overall startup took {} ms.",
@@ -153,12 +192,22 @@ async fn main() -> Result<(), ServerError> {
     );
 
     #[cfg(unix)]
-    tokio::select! {
-        _ = ctrl_c.recv() => {
-            info!("Received SIGINT. Shutting down Iggy server...");
-        },
-        _ = sigterm.recv() => {
-            info!("Received SIGTERM. Shutting down Iggy server...");
+    loop {
+        tokio::select! {
+            _ = ctrl_c.recv() => {
+                info!("Received SIGINT. Shutting down Iggy server...");
+                break;
+            },
+            _ = sigterm.recv() => {
+                info!("Received SIGTERM. Shutting down Iggy server...");
+                break;
+            },
+            _ = sighup.recv() => {
+                info!("Received SIGHUP. Reloading Iggy server config...");
+                if let Err(error) = system.read().await.apply_config_reload().await {
+                    error!("Failed to reload Iggy server config, the current one is kept. {error}");
+                }
+            }
         }
     }
 
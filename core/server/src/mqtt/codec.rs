@@ -0,0 +1,735 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use iggy_common::IggyError;
+
+/// The largest value of the variable byte integer (4 bytes).
+pub const MAX_VARINT: u32 = 268_435_455;
+
+const CONNECT: u8 = 1;
+const CONNACK: u8 = 2;
+const PUBLISH: u8 = 3;
+const PUBACK: u8 = 4;
+const SUBSCRIBE: u8 = 8;
+const SUBACK: u8 = 9;
+const UNSUBSCRIBE: u8 = 10;
+const UNSUBACK: u8 = 11;
+const PINGREQ: u8 = 12;
+const PINGRESP: u8 = 13;
+const DISCONNECT: u8 = 14;
+
+const PROTOCOL_NAME: &str = "MQTT";
+
+/// Version of the protocol negotiated on connect, the value is the protocol level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProtocolVersion {
+    V311 = 4,
+    V5 = 5,
+}
+
+impl ProtocolVersion {
+    pub fn from_level(level: u8) -> Option<Self> {
+        match level {
+            4 => Some(ProtocolVersion::V311),
+            5 => Some(ProtocolVersion::V5),
+            _ => None,
+        }
+    }
+}
+
+/// Property of the MQTT 5 packets, the identifier determines the type of the value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Property {
+    Byte(u8, u8),
+    U16(u8, u16),
+    U32(u8, u32),
+    VarInt(u8, u32),
+    String(u8, String),
+    Binary(u8, Bytes),
+    User(String, String),
+}
+
+pub const SESSION_EXPIRY_INTERVAL: u8 = 0x11;
+pub const ASSIGNED_CLIENT_IDENTIFIER: u8 = 0x12;
+pub const SUBSCRIPTION_IDENTIFIER: u8 = 0x0B;
+pub const TOPIC_ALIAS: u8 = 0x23;
+pub const TOPIC_ALIAS_MAXIMUM: u8 = 0x22;
+pub const MAXIMUM_QOS: u8 = 0x24;
+pub const RETAIN_AVAILABLE: u8 = 0x25;
+pub const MAXIMUM_PACKET_SIZE: u8 = 0x27;
+pub const WILDCARD_SUBSCRIPTION_AVAILABLE: u8 = 0x28;
+pub const SUBSCRIPTION_IDENTIFIERS_AVAILABLE: u8 = 0x29;
+pub const SHARED_SUBSCRIPTION_AVAILABLE: u8 = 0x2A;
+const USER_PROPERTY: u8 = 0x26;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Properties(pub Vec<Property>);
+
+impl Properties {
+    pub fn user_properties(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().filter_map(|property| match property {
+            Property::User(key, value) => Some((key.as_str(), value.as_str())),
+            _ => None,
+        })
+    }
+
+    pub fn contains(&self, id: u8) -> bool {
+        self.0.iter().any(|property| property_id(property) == id)
+    }
+
+    pub fn get_u32(&self, id: u8) -> Option<u32> {
+        self.0.iter().find_map(|property| match property {
+            Property::U32(property_id, value) if *property_id == id => Some(*value),
+            _ => None,
+        })
+    }
+}
+
+fn property_id(property: &Property) -> u8 {
+    match property {
+        Property::Byte(id, _)
+        | Property::U16(id, _)
+        | Property::U32(id, _)
+        | Property::VarInt(id, _)
+        | Property::String(id, _)
+        | Property::Binary(id, _) => *id,
+        Property::User(_, _) => USER_PROPERTY,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Connect {
+    pub protocol_level: u8,
+    pub clean_start: bool,
+    pub keep_alive: u16,
+    pub client_id: String,
+    pub will: Option<Publish>,
+    pub username: Option<String>,
+    pub password: Option<Bytes>,
+    pub properties: Properties,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Publish {
+    pub topic: String,
+    pub packet_id: Option<u16>,
+    pub qos: u8,
+    pub retain: bool,
+    pub dup: bool,
+    pub payload: Bytes,
+    pub properties: Properties,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Packet {
+    Connect(Connect),
+    ConnAck {
+        session_present: bool,
+        reason_code: u8,
+        properties: Properties,
+    },
+    Publish(Publish),
+    PubAck {
+        packet_id: u16,
+        reason_code: u8,
+    },
+    Subscribe {
+        packet_id: u16,
+        /// The topic filters with the subscription options (the QoS in the lowest 2 bits).
+        filters: Vec<(String, u8)>,
+        properties: Properties,
+    },
+    SubAck {
+        packet_id: u16,
+        reason_codes: Vec<u8>,
+    },
+    Unsubscribe {
+        packet_id: u16,
+        filters: Vec<String>,
+    },
+    UnsubAck {
+        packet_id: u16,
+        reason_codes: Vec<u8>,
+    },
+    PingReq,
+    PingResp,
+    Disconnect {
+        reason_code: u8,
+    },
+}
+
+impl Packet {
+    /// Decodes the packet from the first byte of the fixed header and the remaining bytes.
+    /// The CONNECT packet is decoded regardless of the version, the unsupported protocol levels
+    /// are returned without the payload, so that the server can respond with the proper CONNACK.
+    pub fn decode(
+        first_byte: u8,
+        body: Bytes,
+        version: ProtocolVersion,
+    ) -> Result<Self, IggyError> {
+        let mut reader = MqttReader::new(body);
+        let flags = first_byte & 0x0F;
+        let packet = match first_byte >> 4 {
+            CONNECT => Packet::Connect(decode_connect(&mut reader)?),
+            CONNACK => {
+                let session_present = reader.read_u8()? & 1 == 1;
+                let reason_code = reader.read_u8()?;
+                let properties = read_properties_if(&mut reader, version)?;
+                Packet::ConnAck {
+                    session_present,
+                    reason_code,
+                    properties,
+                }
+            }
+            PUBLISH => {
+                let qos = (flags >> 1) & 0b11;
+                if qos > 2 {
+                    return Err(IggyError::InvalidFormat);
+                }
+                let topic = reader.read_string()?;
+                let packet_id = if qos > 0 {
+                    Some(reader.read_u16()?)
+                } else {
+                    None
+                };
+                let properties = read_properties_if(&mut reader, version)?;
+                Packet::Publish(Publish {
+                    topic,
+                    packet_id,
+                    qos,
+                    retain: flags & 1 == 1,
+                    dup: flags & 0b1000 != 0,
+                    payload: reader.read_rest(),
+                    properties,
+                })
+            }
+            PUBACK => {
+                let packet_id = reader.read_u16()?;
+                let reason_code = if reader.remaining() > 0 {
+                    reader.read_u8()?
+                } else {
+                    0
+                };
+                Packet::PubAck {
+                    packet_id,
+                    reason_code,
+                }
+            }
+            SUBSCRIBE => {
+                ensure_flags(flags, 0b0010)?;
+                let packet_id = reader.read_u16()?;
+                let properties = read_properties_if(&mut reader, version)?;
+                let mut filters = Vec::new();
+                while reader.remaining() > 0 {
+                    filters.push((reader.read_string()?, reader.read_u8()?));
+                }
+                if filters.is_empty() {
+                    return Err(IggyError::InvalidFormat);
+                }
+                Packet::Subscribe {
+                    packet_id,
+                    filters,
+                    properties,
+                }
+            }
+            SUBACK => {
+                let packet_id = reader.read_u16()?;
+                read_properties_if(&mut reader, version)?;
+                Packet::SubAck {
+                    packet_id,
+                    reason_codes: reader.read_rest().to_vec(),
+                }
+            }
+            UNSUBSCRIBE => {
+                ensure_flags(flags, 0b0010)?;
+                let packet_id = reader.read_u16()?;
+                read_properties_if(&mut reader, version)?;
+                let mut filters = Vec::new();
+                while reader.remaining() > 0 {
+                    filters.push(reader.read_string()?);
+                }
+                if filters.is_empty() {
+                    return Err(IggyError::InvalidFormat);
+                }
+                Packet::Unsubscribe { packet_id, filters }
+            }
+            UNSUBACK => {
+                let packet_id = reader.read_u16()?;
+                read_properties_if(&mut reader, version)?;
+                Packet::UnsubAck {
+                    packet_id,
+                    reason_codes: reader.read_rest().to_vec(),
+                }
+            }
+            PINGREQ => Packet::PingReq,
+            PINGRESP => Packet::PingResp,
+            DISCONNECT => {
+                let reason_code = if reader.remaining() > 0 {
+                    reader.read_u8()?
+                } else {
+                    0
+                };
+                Packet::Disconnect { reason_code }
+            }
+            _ => return Err(IggyError::InvalidFormat),
+        };
+        Ok(packet)
+    }
+
+    /// Encodes the whole packet, including the fixed header.
+    pub fn encode(&self, version: ProtocolVersion) -> Bytes {
+        let is_v5 = version == ProtocolVersion::V5;
+        let mut body = BytesMut::new();
+        let first_byte = match self {
+            Packet::Connect(connect) => {
+                encode_connect(connect, &mut body);
+                CONNECT << 4
+            }
+            Packet::ConnAck {
+                session_present,
+                reason_code,
+                properties,
+            } => {
+                body.put_u8(*session_present as u8);
+                body.put_u8(*reason_code);
+                if is_v5 {
+                    write_properties(&mut body, properties);
+                }
+                CONNACK << 4
+            }
+            Packet::Publish(publish) => {
+                write_string(&mut body, &publish.topic);
+                if let Some(packet_id) = publish.packet_id {
+                    body.put_u16(packet_id);
+                }
+                if is_v5 {
+                    write_properties(&mut body, &publish.properties);
+                }
+                body.put_slice(&publish.payload);
+                (PUBLISH << 4)
+                    | ((publish.dup as u8) << 3)
+                    | (publish.qos << 1)
+                    | publish.retain as u8
+            }
+            Packet::PubAck {
+                packet_id,
+                reason_code,
+            } => {
+                body.put_u16(*packet_id);
+                if is_v5 && *reason_code != 0 {
+                    body.put_u8(*reason_code);
+                }
+                PUBACK << 4
+            }
+            Packet::Subscribe {
+                packet_id,
+                filters,
+                properties,
+            } => {
+                body.put_u16(*packet_id);
+                if is_v5 {
+                    write_properties(&mut body, properties);
+                }
+                for (filter, options) in filters {
+                    write_string(&mut body, filter);
+                    body.put_u8(*options);
+                }
+                (SUBSCRIBE << 4) | 0b0010
+            }
+            Packet::SubAck {
+                packet_id,
+                reason_codes,
+            } => {
+                body.put_u16(*packet_id);
+                if is_v5 {
+                    write_properties(&mut body, &Properties::default());
+                }
+                body.put_slice(reason_codes);
+                SUBACK << 4
+            }
+            Packet::Unsubscribe { packet_id, filters } => {
+                body.put_u16(*packet_id);
+                if is_v5 {
+                    write_properties(&mut body, &Properties::default());
+                }
+                for filter in filters {
+                    write_string(&mut body, filter);
+                }
+                (UNSUBSCRIBE << 4) | 0b0010
+            }
+            Packet::UnsubAck {
+                packet_id,
+                reason_codes,
+            } => {
+                body.put_u16(*packet_id);
+                // The reason codes were introduced in MQTT 5.
+                if is_v5 {
+                    write_properties(&mut body, &Properties::default());
+                    body.put_slice(reason_codes);
+                }
+                UNSUBACK << 4
+            }
+            Packet::PingReq => PINGREQ << 4,
+            Packet::PingResp => PINGRESP << 4,
+            Packet::Disconnect { reason_code } => {
+                if is_v5 && *reason_code != 0 {
+                    body.put_u8(*reason_code);
+                }
+                DISCONNECT << 4
+            }
+        };
+
+        let mut packet = BytesMut::with_capacity(body.len() + 5);
+        packet.put_u8(first_byte);
+        write_varint(&mut packet, body.len() as u32);
+        packet.put_slice(&body);
+        packet.freeze()
+    }
+}
+
+fn ensure_flags(flags: u8, expected: u8) -> Result<(), IggyError> {
+    if flags != expected {
+        return Err(IggyError::InvalidFormat);
+    }
+    Ok(())
+}
+
+fn decode_connect(reader: &mut MqttReader) -> Result<Connect, IggyError> {
+    let protocol_name = reader.read_string()?;
+    let protocol_level = reader.read_u8()?;
+    let mut connect = Connect {
+        protocol_level,
+        clean_start: false,
+        keep_alive: 0,
+        client_id: String::new(),
+        will: None,
+        username: None,
+        password: None,
+        properties: Properties::default(),
+    };
+    let Some(version) = ProtocolVersion::from_level(protocol_level) else {
+        return Ok(connect);
+    };
+    if protocol_name != PROTOCOL_NAME {
+        return Err(IggyError::InvalidFormat);
+    }
+
+    let flags = reader.read_u8()?;
+    if flags & 1 != 0 {
+        return Err(IggyError::InvalidFormat);
+    }
+    connect.clean_start = flags & 0b10 != 0;
+    connect.keep_alive = reader.read_u16()?;
+    connect.properties = read_properties_if(reader, version)?;
+    connect.client_id = reader.read_string()?;
+    if flags & 0b100 != 0 {
+        let properties = read_properties_if(reader, version)?;
+        let topic = reader.read_string()?;
+        let payload = reader.read_binary()?;
+        connect.will = Some(Publish {
+            topic,
+            packet_id: None,
+            qos: (flags >> 3) & 0b11,
+            retain: flags & 0b10_0000 != 0,
+            dup: false,
+            payload,
+            properties,
+        });
+    }
+    if flags & 0b1000_0000 != 0 {
+        connect.username = Some(reader.read_string()?);
+    }
+    if flags & 0b100_0000 != 0 {
+        connect.password = Some(reader.read_binary()?);
+    }
+    Ok(connect)
+}
+
+fn encode_connect(connect: &Connect, body: &mut BytesMut) {
+    let is_v5 = connect.protocol_level == ProtocolVersion::V5 as u8;
+    write_string(body, PROTOCOL_NAME);
+    body.put_u8(connect.protocol_level);
+    let mut flags = (connect.clean_start as u8) << 1;
+    if let Some(will) = &connect.will {
+        flags |= 0b100 | (will.qos << 3) | ((will.retain as u8) << 5);
+    }
+    if connect.password.is_some() {
+        flags |= 0b100_0000;
+    }
+    if connect.username.is_some() {
+        flags |= 0b1000_0000;
+    }
+    body.put_u8(flags);
+    body.put_u16(connect.keep_alive);
+    if is_v5 {
+        write_properties(body, &connect.properties);
+    }
+    write_string(body, &connect.client_id);
+    if let Some(will) = &connect.will {
+        if is_v5 {
+            write_properties(body, &will.properties);
+        }
+        write_string(body, &will.topic);
+        write_binary(body, &will.payload);
+    }
+    if let Some(username) = &connect.username {
+        write_string(body, username);
+    }
+    if let Some(password) = &connect.password {
+        write_binary(body, password);
+    }
+}
+
+fn read_properties_if(
+    reader: &mut MqttReader,
+    version: ProtocolVersion,
+) -> Result<Properties, IggyError> {
+    if version != ProtocolVersion::V5 {
+        return Ok(Properties::default());
+    }
+
+    let length = reader.read_varint()? as usize;
+    let mut reader = MqttReader::new(reader.read_raw(length)?);
+    let mut properties = Vec::new();
+    while reader.remaining() > 0 {
+        let id = reader.read_varint()? as u8;
+        let property = match id {
+            0x01 | 0x17 | 0x19 | 0x24 | 0x25 | 0x28 | 0x29 | 0x2A => {
+                Property::Byte(id, reader.read_u8()?)
+            }
+            0x13 | 0x21 | 0x22 | 0x23 => Property::U16(id, reader.read_u16()?),
+            0x02 | 0x11 | 0x18 | 0x27 => Property::U32(id, reader.read_u32()?),
+            0x0B => Property::VarInt(id, reader.read_varint()?),
+            0x03 | 0x08 | 0x12 | 0x15 | 0x1A | 0x1C | 0x1F => {
+                Property::String(id, reader.read_string()?)
+            }
+            0x09 | 0x16 => Property::Binary(id, reader.read_binary()?),
+            USER_PROPERTY => Property::User(reader.read_string()?, reader.read_string()?),
+            _ => return Err(IggyError::InvalidFormat),
+        };
+        properties.push(property);
+    }
+    Ok(Properties(properties))
+}
+
+fn write_properties(buffer: &mut BytesMut, properties: &Properties) {
+    let mut encoded = BytesMut::new();
+    for property in &properties.0 {
+        write_varint(&mut encoded, property_id(property) as u32);
+        match property {
+            Property::Byte(_, value) => encoded.put_u8(*value),
+            Property::U16(_, value) => encoded.put_u16(*value),
+            Property::U32(_, value) => encoded.put_u32(*value),
+            Property::VarInt(_, value) => write_varint(&mut encoded, *value),
+            Property::String(_, value) => write_string(&mut encoded, value),
+            Property::Binary(_, value) => write_binary(&mut encoded, value),
+            Property::User(key, value) => {
+                write_string(&mut encoded, key);
+                write_string(&mut encoded, value);
+            }
+        }
+    }
+    write_varint(buffer, encoded.len() as u32);
+    buffer.put_slice(&encoded);
+}
+
+fn write_string(buffer: &mut BytesMut, value: &str) {
+    write_binary(buffer, value.as_bytes());
+}
+
+fn write_binary(buffer: &mut BytesMut, value: &[u8]) {
+    buffer.put_u16(value.len() as u16);
+    buffer.put_slice(value);
+}
+
+/// Writes the variable byte integer, 7 bits per byte with the continuation bit.
+pub fn write_varint(buffer: &mut BytesMut, mut value: u32) {
+    loop {
+        let byte = (value % 128) as u8;
+        value /= 128;
+        if value == 0 {
+            buffer.put_u8(byte);
+            return;
+        }
+        buffer.put_u8(byte | 0x80);
+    }
+}
+
+/// Decodes the variable byte integer from the bytes, returns `None` if more bytes are needed.
+pub fn decode_varint(bytes: &[u8]) -> Result<Option<(u32, usize)>, IggyError> {
+    let mut value = 0u32;
+    for (index, byte) in bytes.iter().enumerate() {
+        if index == 4 {
+            return Err(IggyError::InvalidFormat);
+        }
+        value |= ((byte & 0x7F) as u32) << (7 * index);
+        if byte & 0x80 == 0 {
+            return Ok(Some((value, index + 1)));
+        }
+    }
+    if bytes.len() >= 4 {
+        return Err(IggyError::InvalidFormat);
+    }
+    Ok(None)
+}
+
+#[derive(Debug)]
+struct MqttReader {
+    buffer: Bytes,
+}
+
+impl MqttReader {
+    fn new(buffer: Bytes) -> Self {
+        Self { buffer }
+    }
+
+    fn remaining(&self) -> usize {
+        self.buffer.remaining()
+    }
+
+    fn ensure(&self, length: usize) -> Result<(), IggyError> {
+        if self.buffer.remaining() < length {
+            return Err(IggyError::InvalidFormat);
+        }
+        Ok(())
+    }
+
+    fn read_u8(&mut self) -> Result<u8, IggyError> {
+        self.ensure(1)?;
+        Ok(self.buffer.get_u8())
+    }
+
+    fn read_u16(&mut self) -> Result<u16, IggyError> {
+        self.ensure(2)?;
+        Ok(self.buffer.get_u16())
+    }
+
+    fn read_u32(&mut self) -> Result<u32, IggyError> {
+        self.ensure(4)?;
+        Ok(self.buffer.get_u32())
+    }
+
+    fn read_varint(&mut self) -> Result<u32, IggyError> {
+        let (value, length) = decode_varint(&self.buffer)?.ok_or(IggyError::InvalidFormat)?;
+        self.buffer.advance(length);
+        Ok(value)
+    }
+
+    fn read_raw(&mut self, length: usize) -> Result<Bytes, IggyError> {
+        self.ensure(length)?;
+        Ok(self.buffer.split_to(length))
+    }
+
+    fn read_binary(&mut self) -> Result<Bytes, IggyError> {
+        let length = self.read_u16()? as usize;
+        self.read_raw(length)
+    }
+
+    fn read_string(&mut self) -> Result<String, IggyError> {
+        let bytes = self.read_binary()?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| IggyError::InvalidFormat)
+    }
+
+    fn read_rest(&mut self) -> Bytes {
+        self.buffer.split_to(self.buffer.remaining())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(packet: Packet, version: ProtocolVersion) {
+        let encoded = packet.encode(version);
+        let (length, header_length) = decode_varint(&encoded[1..]).unwrap().unwrap();
+        assert_eq!(length as usize, encoded.len() - 1 - header_length);
+        let body = encoded.slice(1 + header_length..);
+        assert_eq!(Packet::decode(encoded[0], body, version).unwrap(), packet);
+    }
+
+    #[test]
+    fn should_encode_and_decode_varint() {
+        for value in [
+            0, 127, 128, 16_383, 16_384, 2_097_151, 2_097_152, MAX_VARINT,
+        ] {
+            let mut buffer = BytesMut::new();
+            write_varint(&mut buffer, value);
+            assert_eq!(decode_varint(&buffer).unwrap(), Some((value, buffer.len())));
+        }
+        assert_eq!(decode_varint(&[0x80, 0x80]).unwrap(), None);
+        assert!(decode_varint(&[0x80, 0x80, 0x80, 0x80, 0x01]).is_err());
+    }
+
+    #[test]
+    fn should_encode_and_decode_packets() {
+        let publish = Publish {
+            topic: "sensors/1/temperature".to_owned(),
+            packet_id: Some(7),
+            qos: 1,
+            retain: true,
+            dup: false,
+            payload: Bytes::from_static(b"21.5"),
+            properties: Properties(vec![Property::User("unit".to_owned(), "C".to_owned())]),
+        };
+        round_trip(Packet::Publish(publish.clone()), ProtocolVersion::V5);
+        round_trip(
+            Packet::Connect(Connect {
+                protocol_level: ProtocolVersion::V5 as u8,
+                clean_start: true,
+                keep_alive: 30,
+                client_id: "device-1".to_owned(),
+                will: Some(Publish {
+                    packet_id: None,
+                    ..publish
+                }),
+                username: Some("iggy".to_owned()),
+                password: Some(Bytes::from_static(b"secret")),
+                properties: Properties(vec![Property::U32(SESSION_EXPIRY_INTERVAL, 60)]),
+            }),
+            ProtocolVersion::V5,
+        );
+        round_trip(
+            Packet::Subscribe {
+                packet_id: 1,
+                filters: vec![("sensors/+/temperature".to_owned(), 1)],
+                properties: Properties::default(),
+            },
+            ProtocolVersion::V311,
+        );
+        round_trip(
+            Packet::SubAck {
+                packet_id: 1,
+                reason_codes: vec![1, 0x80],
+            },
+            ProtocolVersion::V311,
+        );
+    }
+
+    #[test]
+    fn should_return_unsupported_protocol_level_without_payload() {
+        let mut body = BytesMut::new();
+        write_string(&mut body, "MQIsdp");
+        body.put_u8(3);
+        let packet = Packet::decode(CONNECT << 4, body.freeze(), ProtocolVersion::V311).unwrap();
+        let Packet::Connect(connect) = packet else {
+            panic!("Expected CONNECT packet");
+        };
+        assert_eq!(connect.protocol_level, 3);
+    }
+}
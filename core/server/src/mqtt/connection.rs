@@ -0,0 +1,802 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::mqtt::codec::{
+    ASSIGNED_CLIENT_IDENTIFIER, Connect, MAXIMUM_PACKET_SIZE, MAXIMUM_QOS, Packet, Properties,
+    Property, ProtocolVersion, Publish, RETAIN_AVAILABLE, SHARED_SUBSCRIPTION_AVAILABLE,
+    SUBSCRIPTION_IDENTIFIER, SUBSCRIPTION_IDENTIFIERS_AVAILABLE, TOPIC_ALIAS,
+    WILDCARD_SUBSCRIPTION_AVAILABLE, decode_varint,
+};
+use crate::mqtt::context::MqttContext;
+use crate::mqtt::mapping::{TopicPattern, is_valid_filter, is_valid_topic};
+use crate::mqtt::{COMPONENT, MQTT_TOPIC_HEADER, delivery, reason_codes};
+use crate::streaming::segments::IggyMessagesBatchMut;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::quota_limiter::QuotaDirection;
+use bytes::{Bytes, BytesMut};
+use iggy_common::{
+    Consumer, HeaderKey, HeaderValue, Identifier, IggyError, IggyMessage, Partitioning, Sizeable,
+};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const OUTGOING_PACKETS_QUEUE_SIZE: usize = 1024;
+/// The maximum length of the key, by which the messages are assigned to the partitions.
+const MAX_PARTITIONING_KEY_LENGTH: usize = 255;
+
+#[derive(Debug, Clone)]
+pub struct Subscription {
+    pub filter: String,
+    pub qos: u8,
+    pub patterns: Vec<TopicPattern>,
+}
+
+impl Subscription {
+    pub fn matches_topic(&self, stream: &str, topic: &str) -> bool {
+        self.patterns
+            .iter()
+            .any(|pattern| pattern.stream.matches(stream) && pattern.topic.matches(topic))
+    }
+}
+
+/// The message delivered with QoS 1, whose offset is stored once it's acknowledged.
+#[derive(Debug, Clone, Copy)]
+pub struct DeliveredOffset {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub offset: u64,
+}
+
+/// State of the connected client, shared with the task delivering the messages to the subscriptions.
+#[derive(Debug)]
+pub struct ConnectionState {
+    pub version: ProtocolVersion,
+    pub client_id: String,
+    /// The offsets are stored (by the consumer named after the client ID) only for the persistent
+    /// sessions, so that the client resumes from the last acknowledged message after reconnecting.
+    pub persistent: bool,
+    pub max_outgoing_packet_size: usize,
+    pub subscriptions: Mutex<Vec<Subscription>>,
+    pub cursors: tokio::sync::Mutex<delivery::Cursors>,
+    inflight: Mutex<HashMap<u16, Option<DeliveredOffset>>>,
+    next_packet_id: Mutex<u16>,
+    sender: mpsc::Sender<Bytes>,
+}
+
+impl ConnectionState {
+    pub fn consumer(&self) -> Result<Consumer, IggyError> {
+        Ok(Consumer::new(Identifier::named(&self.client_id)?))
+    }
+
+    pub fn inflight_count(&self) -> usize {
+        self.inflight.lock().unwrap().len()
+    }
+
+    async fn send(&self, packet: Packet) -> Result<(), IggyError> {
+        self.sender
+            .send(packet.encode(self.version))
+            .await
+            .map_err(|_| IggyError::ConnectionClosed)
+    }
+
+    /// Sends the message to the client, the message with QoS 1 awaits the acknowledgement.
+    /// Returns `false` if the message has been skipped, as it exceeds the maximum packet size
+    /// accepted by the client, in which case it's never acknowledged.
+    pub async fn send_publish(
+        &self,
+        mut publish: Publish,
+        offset: Option<DeliveredOffset>,
+    ) -> Result<bool, IggyError> {
+        if self.version == ProtocolVersion::V311 {
+            publish.properties = Properties::default();
+        }
+        if publish.qos > 0 {
+            let mut next_packet_id = self.next_packet_id.lock().unwrap();
+            let mut inflight = self.inflight.lock().unwrap();
+            while *next_packet_id == 0 || inflight.contains_key(&next_packet_id) {
+                *next_packet_id = next_packet_id.wrapping_add(1);
+            }
+            publish.packet_id = Some(*next_packet_id);
+            inflight.insert(*next_packet_id, offset);
+            *next_packet_id = next_packet_id.wrapping_add(1);
+        }
+
+        let packet_id = publish.packet_id;
+        let packet = Packet::Publish(publish).encode(self.version);
+        if packet.len() > self.max_outgoing_packet_size {
+            warn!(
+                "{COMPONENT} - message of size: {} exceeds the maximum packet size of client: {}.",
+                packet.len(),
+                self.client_id
+            );
+            // The size is known only once the packet (including its ID) is encoded, so the reserved ID is released.
+            if let Some(packet_id) = packet_id {
+                self.inflight.lock().unwrap().remove(&packet_id);
+            }
+            return Ok(false);
+        }
+        self.sender
+            .send(packet)
+            .await
+            .map_err(|_| IggyError::ConnectionClosed)?;
+        Ok(true)
+    }
+}
+
+/// Handles the connection, the first packet must be CONNECT. The incoming packets are handled
+/// one by one, while the messages are delivered to the subscriptions by a separate task.
+pub async fn handle_connection(
+    context: Arc<MqttContext>,
+    session: Arc<Session>,
+    stream: TcpStream,
+) -> Result<(), IggyError> {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+    let (first_byte, body) = tokio::time::timeout(
+        CONNECT_TIMEOUT,
+        read_packet(&mut reader, context.max_packet_size),
+    )
+    .await
+    .map_err(|_| IggyError::ConnectionClosed)??;
+    let Packet::Connect(connect) = Packet::decode(first_byte, body, ProtocolVersion::V311)? else {
+        error!("{COMPONENT} - the first packet must be CONNECT.");
+        return Err(IggyError::InvalidCommand);
+    };
+
+    let Some(version) = ProtocolVersion::from_level(connect.protocol_level) else {
+        warn!(
+            "{COMPONENT} - unsupported protocol level: {}.",
+            connect.protocol_level
+        );
+        let connack = Packet::ConnAck {
+            session_present: false,
+            reason_code: reason_codes::V3_UNACCEPTABLE_PROTOCOL_VERSION,
+            properties: Properties::default(),
+        };
+        let _ = writer
+            .write_all(&connack.encode(ProtocolVersion::V311))
+            .await;
+        return Ok(());
+    };
+
+    let client_id = match authenticate(&context, &session, version, &connect).await {
+        Ok(client_id) => client_id,
+        Err(reason_code) => {
+            let connack = Packet::ConnAck {
+                session_present: false,
+                reason_code,
+                properties: Properties::default(),
+            };
+            let _ = writer.write_all(&connack.encode(version)).await;
+            return Ok(());
+        }
+    };
+
+    let mut properties = vec![
+        Property::Byte(MAXIMUM_QOS, 1),
+        Property::Byte(RETAIN_AVAILABLE, 1),
+        Property::Byte(WILDCARD_SUBSCRIPTION_AVAILABLE, 1),
+        Property::Byte(SUBSCRIPTION_IDENTIFIERS_AVAILABLE, 0),
+        Property::Byte(SHARED_SUBSCRIPTION_AVAILABLE, 0),
+        Property::U32(MAXIMUM_PACKET_SIZE, context.max_packet_size as u32),
+    ];
+    if connect.client_id.is_empty() {
+        properties.push(Property::String(
+            ASSIGNED_CLIENT_IDENTIFIER,
+            client_id.clone(),
+        ));
+    }
+    // The subscriptions are not kept after disconnecting, so the session is never present.
+    let connack = Packet::ConnAck {
+        session_present: false,
+        reason_code: reason_codes::SUCCESS,
+        properties: Properties(properties),
+    };
+    writer
+        .write_all(&connack.encode(version))
+        .await
+        .map_err(|_| IggyError::ConnectionClosed)?;
+
+    let (sender, mut receiver) = mpsc::channel::<Bytes>(OUTGOING_PACKETS_QUEUE_SIZE);
+    let writer_task = tokio::spawn(async move {
+        while let Some(packet) = receiver.recv().await {
+            if writer.write_all(&packet).await.is_err() {
+                break;
+            }
+        }
+        let _ = writer.shutdown().await;
+    });
+
+    let state = Arc::new(ConnectionState {
+        version,
+        client_id: client_id.clone(),
+        persistent: !connect.clean_start,
+        max_outgoing_packet_size: connect
+            .properties
+            .get_u32(MAXIMUM_PACKET_SIZE)
+            .map_or(usize::MAX, |size| size as usize),
+        subscriptions: Mutex::new(Vec::new()),
+        cursors: tokio::sync::Mutex::new(delivery::Cursors::default()),
+        inflight: Mutex::new(HashMap::new()),
+        next_packet_id: Mutex::new(1),
+        sender,
+    });
+    let takeover = context.register_session(&client_id);
+    let delivery_task = tokio::spawn(delivery::run(
+        context.clone(),
+        state.clone(),
+        session.clone(),
+    ));
+
+    let keep_alive = Duration::from_secs(connect.keep_alive as u64);
+    let result = tokio::select! {
+        result = handle_packets(&context, &state, &session, &mut reader, keep_alive) => result,
+        _ = takeover.notified() => {
+            info!("{COMPONENT} - session of client: {client_id} has been taken over.");
+            let _ = state
+                .send(Packet::Disconnect {
+                    reason_code: reason_codes::SESSION_TAKEN_OVER,
+                })
+                .await;
+            Ok(true)
+        }
+    };
+    delivery_task.abort();
+    context.unregister_session(&client_id, &takeover);
+
+    // The will message is published, unless the client has disconnected gracefully.
+    let publish_will = result.as_ref().map_or(true, |publish_will| *publish_will);
+    if publish_will
+        && let Some(will) = connect.will
+        && let Err(reason_code) = publish_message(&context, &session, &will).await
+    {
+        warn!(
+            "{COMPONENT} - failed to publish the will message of client: {client_id}, reason code: {reason_code}."
+        );
+    }
+
+    drop(state);
+    let _ = writer_task.await;
+    result.map(|_| ())
+}
+
+/// Signs in the user and returns the client ID (assigned by the server, if not provided).
+async fn authenticate(
+    context: &MqttContext,
+    session: &Session,
+    version: ProtocolVersion,
+    connect: &Connect,
+) -> Result<String, u8> {
+    let is_v5 = version == ProtocolVersion::V5;
+    let client_id = if connect.client_id.is_empty() {
+        // MQTT 3.1.1 requires the client ID for the persistent sessions.
+        if !connect.clean_start && !is_v5 {
+            return Err(reason_codes::V3_IDENTIFIER_REJECTED);
+        }
+        format!("iggy-{}", Uuid::now_v7().simple())
+    } else {
+        connect.client_id.clone()
+    };
+    if Identifier::named(&client_id).is_err() {
+        return Err(if is_v5 {
+            reason_codes::CLIENT_IDENTIFIER_NOT_VALID
+        } else {
+            reason_codes::V3_IDENTIFIER_REJECTED
+        });
+    }
+
+    let bad_credentials = if is_v5 {
+        reason_codes::BAD_USERNAME_OR_PASSWORD
+    } else {
+        reason_codes::V3_BAD_USERNAME_OR_PASSWORD
+    };
+    let (Some(username), Some(password)) = (&connect.username, &connect.password) else {
+        warn!("{COMPONENT} - client: {client_id} has connected without the credentials.");
+        return Err(bad_credentials);
+    };
+    let Ok(password) = std::str::from_utf8(password) else {
+        return Err(bad_credentials);
+    };
+
+    let system = context.system.read().await;
+    match system
+        .login_user(username, password, session.login_source(), Some(session))
+        .await
+    {
+        Ok(user) => {
+            info!(
+                "MQTT client: {client_id} has signed in as user: {username} with ID: {}.",
+                user.id
+            );
+            Ok(client_id)
+        }
+        Err(error) => {
+            warn!("MQTT client: {client_id} has failed to sign in as user: {username}. {error}");
+            Err(bad_credentials)
+        }
+    }
+}
+
+/// Handles the packets until the client disconnects, returns `true` if the will message
+/// should be published.
+async fn handle_packets(
+    context: &MqttContext,
+    state: &ConnectionState,
+    session: &Session,
+    reader: &mut (impl AsyncRead + Unpin),
+    keep_alive: Duration,
+) -> Result<bool, IggyError> {
+    loop {
+        let read = read_packet(reader, context.max_packet_size);
+        // The connection is closed, if no packet is received within one and a half keep alive.
+        let (first_byte, body) = if keep_alive.is_zero() {
+            read.await?
+        } else {
+            match tokio::time::timeout(keep_alive * 3 / 2, read).await {
+                Ok(result) => result?,
+                Err(_) => {
+                    warn!(
+                        "{COMPONENT} - client: {} has exceeded the keep alive.",
+                        state.client_id
+                    );
+                    let _ = state
+                        .send(Packet::Disconnect {
+                            reason_code: reason_codes::KEEP_ALIVE_TIMEOUT,
+                        })
+                        .await;
+                    return Ok(true);
+                }
+            }
+        };
+
+        let packet = Packet::decode(first_byte, body, state.version)?;
+        debug!(
+            "{COMPONENT} - received packet: {packet:?} from client: {}.",
+            state.client_id
+        );
+        match packet {
+            Packet::Publish(publish) => handle_publish(context, state, session, publish).await?,
+            Packet::PubAck { packet_id, .. } => {
+                handle_puback(context, state, session, packet_id).await
+            }
+            Packet::Subscribe {
+                packet_id,
+                filters,
+                properties,
+            } => handle_subscribe(context, state, session, packet_id, filters, properties).await?,
+            Packet::Unsubscribe { packet_id, filters } => {
+                let reason_codes = {
+                    let mut subscriptions = state.subscriptions.lock().unwrap();
+                    filters
+                        .iter()
+                        .map(|filter| {
+                            let count = subscriptions.len();
+                            subscriptions.retain(|subscription| subscription.filter != *filter);
+                            if subscriptions.len() < count {
+                                reason_codes::SUCCESS
+                            } else {
+                                reason_codes::NO_SUBSCRIPTION_EXISTED
+                            }
+                        })
+                        .collect()
+                };
+                state
+                    .send(Packet::UnsubAck {
+                        packet_id,
+                        reason_codes,
+                    })
+                    .await?;
+            }
+            Packet::PingReq => state.send(Packet::PingResp).await?,
+            Packet::Disconnect { reason_code } => {
+                return Ok(reason_code == reason_codes::DISCONNECT_WITH_WILL_MESSAGE);
+            }
+            packet => {
+                warn!(
+                    "{COMPONENT} - unexpected packet: {packet:?} from client: {}.",
+                    state.client_id
+                );
+                disconnect(state, reason_codes::PROTOCOL_ERROR).await;
+                return Err(IggyError::InvalidCommand);
+            }
+        }
+    }
+}
+
+/// Sends the DISCONNECT packet with the reason code (MQTT 5 only), before closing the connection.
+async fn disconnect(state: &ConnectionState, reason_code: u8) {
+    if state.version == ProtocolVersion::V5 {
+        let _ = state.send(Packet::Disconnect { reason_code }).await;
+    }
+}
+
+async fn handle_publish(
+    context: &MqttContext,
+    state: &ConnectionState,
+    session: &Session,
+    publish: Publish,
+) -> Result<(), IggyError> {
+    let protocol_error = if publish.qos > 1 {
+        Some(reason_codes::QOS_NOT_SUPPORTED)
+    } else if publish.properties.contains(TOPIC_ALIAS) {
+        Some(reason_codes::TOPIC_ALIAS_INVALID)
+    } else if !is_valid_topic(&publish.topic) {
+        Some(reason_codes::TOPIC_NAME_INVALID)
+    } else {
+        None
+    };
+    if let Some(reason_code) = protocol_error {
+        warn!(
+            "{COMPONENT} - invalid publish on topic: {} from client: {}, reason code: {reason_code}.",
+            publish.topic, state.client_id
+        );
+        disconnect(state, reason_code).await;
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let reason_code = match publish_message(context, session, &publish).await {
+        Ok(()) => reason_codes::SUCCESS,
+        Err(reason_code) => {
+            warn!(
+                "{COMPONENT} - failed to publish on topic: {} from client: {}, reason code: {reason_code}.",
+                publish.topic, state.client_id
+            );
+            // MQTT 3.1.1 has no means to reject the message, other than closing the connection.
+            if state.version == ProtocolVersion::V311 {
+                return Err(IggyError::InvalidCommand);
+            }
+            reason_code
+        }
+    };
+    if let Some(packet_id) = publish.packet_id {
+        state
+            .send(Packet::PubAck {
+                packet_id,
+                reason_code,
+            })
+            .await?;
+    }
+    Ok(())
+}
+
+/// Appends the message to the Iggy topic mapped from the MQTT topic, and updates the retained
+/// message of the topic. The empty retained message only clears the retained message, as the
+/// empty payloads are not supported by Iggy, which requires the permission to append to the topic.
+/// Returns the MQTT 5 reason code on failure.
+pub async fn publish_message(
+    context: &MqttContext,
+    session: &Session,
+    publish: &Publish,
+) -> Result<(), u8> {
+    if publish.payload.is_empty() && !publish.retain {
+        return Err(reason_codes::PAYLOAD_FORMAT_INVALID);
+    }
+
+    let (stream_id, topic_id) = resolve_topic(context, &publish.topic)?;
+    let to_reason_code = |error: IggyError| reason_codes::from_error(&error);
+    if publish.payload.is_empty() {
+        let system = context.system.read().await;
+        let topic = system
+            .find_topic(session, &stream_id, &topic_id)
+            .map_err(to_reason_code)?;
+        system
+            .permissioner
+            .append_messages(session.get_user_id(), topic.stream_id, topic.topic_id)
+            .map_err(to_reason_code)?;
+        drop(system);
+        context.retain(publish);
+        return Ok(());
+    }
+
+    let message = to_iggy_message(publish).map_err(to_reason_code)?;
+    let message_size = message.get_size_bytes().as_bytes_u32();
+    let key = &publish.topic.as_bytes()[..publish.topic.len().min(MAX_PARTITIONING_KEY_LENGTH)];
+    // The messages of the same MQTT topic are kept in order, by appending them to the same partition.
+    let partitioning = Partitioning::messages_key(key).map_err(to_reason_code)?;
+    let batch = IggyMessagesBatchMut::from_messages(&[message], message_size);
+
+    let system = context.system.read().await;
+    system
+        .append_messages(session, &stream_id, &topic_id, &partitioning, batch, None)
+        .await
+        .map_err(to_reason_code)?;
+    let throttle_time =
+        system.record_throughput(session, QuotaDirection::Produce, message_size as u64, 1);
+    drop(system);
+
+    if publish.retain {
+        context.retain(publish);
+    }
+    // The acknowledgement is delayed, when the produce quota is exceeded.
+    if let Some(throttle_time) = throttle_time {
+        tokio::time::sleep(throttle_time).await;
+    }
+    Ok(())
+}
+
+/// Returns the Iggy stream and topic, to which the MQTT topic is mapped.
+fn resolve_topic(context: &MqttContext, mqtt_topic: &str) -> Result<(Identifier, Identifier), u8> {
+    let (stream, topic) = context
+        .mapping
+        .resolve(mqtt_topic)
+        .ok_or(reason_codes::TOPIC_NAME_INVALID)?;
+    let to_reason_code = |error: IggyError| reason_codes::from_error(&error);
+    Ok((
+        Identifier::named(&stream).map_err(to_reason_code)?,
+        Identifier::named(&topic).map_err(to_reason_code)?,
+    ))
+}
+
+/// Checks whether the user is allowed to poll the messages from the Iggy topic, to which the retained
+/// message's MQTT topic is mapped, so that the retained messages don't bypass the permissions.
+fn can_read_retained(
+    system: &System,
+    context: &MqttContext,
+    session: &Session,
+    publish: &Publish,
+) -> bool {
+    let Ok((stream_id, topic_id)) = resolve_topic(context, &publish.topic) else {
+        return false;
+    };
+    system
+        .find_topic(session, &stream_id, &topic_id)
+        .and_then(|topic| {
+            system.permissioner.poll_messages(
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id,
+            )
+        })
+        .is_ok()
+}
+
+/// Converts the published message, the MQTT topic and the user properties are stored as the headers.
+fn to_iggy_message(publish: &Publish) -> Result<IggyMessage, IggyError> {
+    let mut user_headers = HashMap::new();
+    user_headers.insert(
+        HeaderKey::new(MQTT_TOPIC_HEADER)?,
+        HeaderValue::from_str(&publish.topic)?,
+    );
+    for (key, value) in publish.properties.user_properties() {
+        if value.is_empty() || key.eq_ignore_ascii_case(MQTT_TOPIC_HEADER) {
+            continue;
+        }
+        user_headers.insert(HeaderKey::new(key)?, HeaderValue::from_str(value)?);
+    }
+
+    IggyMessage::builder()
+        .payload(publish.payload.clone())
+        .user_headers(user_headers)
+        .build()
+}
+
+async fn handle_puback(
+    context: &MqttContext,
+    state: &ConnectionState,
+    session: &Session,
+    packet_id: u16,
+) {
+    let Some(Some(offset)) = state.inflight.lock().unwrap().remove(&packet_id) else {
+        return;
+    };
+    if state.persistent {
+        delivery::store_offset(context, state, session, offset).await;
+    }
+}
+
+async fn handle_subscribe(
+    context: &MqttContext,
+    state: &ConnectionState,
+    session: &Session,
+    packet_id: u16,
+    filters: Vec<(String, u8)>,
+    properties: Properties,
+) -> Result<(), IggyError> {
+    // The cursors are locked while subscribing, so that the delivery task doesn't start tracking
+    // the already existing topics from the start.
+    let mut cursors = state.cursors.lock().await;
+    let mut reason_codes = Vec::with_capacity(filters.len());
+    let mut retained = Vec::new();
+    for (filter, options) in filters {
+        // QoS 2 is downgraded to QoS 1.
+        let qos = (options & 0b11).min(reason_codes::GRANTED_QOS_1);
+        let retain_handling = (options >> 4) & 0b11;
+        let patterns = context.mapping.resolve_filter(&filter);
+        let error = if filter.starts_with("$share/") {
+            Some(reason_codes::SHARED_SUBSCRIPTIONS_NOT_SUPPORTED)
+        } else if properties.contains(SUBSCRIPTION_IDENTIFIER) {
+            Some(reason_codes::SUBSCRIPTION_IDENTIFIERS_NOT_SUPPORTED)
+        } else if !is_valid_filter(&filter) || patterns.is_empty() {
+            Some(reason_codes::TOPIC_FILTER_INVALID)
+        } else {
+            None
+        };
+        if let Some(error) = error {
+            warn!(
+                "{COMPONENT} - invalid subscription: {filter} of client: {}, reason code: {error}.",
+                state.client_id
+            );
+            reason_codes.push(match state.version {
+                ProtocolVersion::V5 => error,
+                ProtocolVersion::V311 => reason_codes::UNSPECIFIED_ERROR,
+            });
+            continue;
+        }
+
+        let is_new = {
+            let mut subscriptions = state.subscriptions.lock().unwrap();
+            let subscription = Subscription {
+                filter: filter.clone(),
+                qos,
+                patterns,
+            };
+            match subscriptions
+                .iter_mut()
+                .find(|subscription| subscription.filter == filter)
+            {
+                Some(existing) => {
+                    *existing = subscription;
+                    false
+                }
+                None => {
+                    subscriptions.push(subscription);
+                    true
+                }
+            }
+        };
+        if retain_handling == 0 || (retain_handling == 1 && is_new) {
+            retained.extend(
+                context
+                    .get_retained(&filter)
+                    .into_iter()
+                    .map(|publish| Publish {
+                        qos: publish.qos.min(qos),
+                        ..publish
+                    }),
+            );
+        }
+        reason_codes.push(qos);
+    }
+
+    // Only the messages appended after subscribing are delivered from the already existing topics.
+    delivery::track_topics(context, state, session, &mut cursors, true).await;
+    drop(cursors);
+    if !retained.is_empty() {
+        let system = context.system.read().await;
+        retained.retain(|publish| can_read_retained(&system, context, session, publish));
+    }
+    state
+        .send(Packet::SubAck {
+            packet_id,
+            reason_codes,
+        })
+        .await?;
+    for publish in retained {
+        state.send_publish(publish, None).await?;
+    }
+    Ok(())
+}
+
+/// Reads the packet, returns the first byte of the fixed header and the remaining bytes.
+async fn read_packet(
+    reader: &mut (impl AsyncRead + Unpin),
+    max_packet_size: usize,
+) -> Result<(u8, Bytes), IggyError> {
+    let first_byte = reader
+        .read_u8()
+        .await
+        .map_err(|_| IggyError::ConnectionClosed)?;
+    let mut length_bytes = Vec::with_capacity(4);
+    let length = loop {
+        length_bytes.push(
+            reader
+                .read_u8()
+                .await
+                .map_err(|_| IggyError::ConnectionClosed)?,
+        );
+        if let Some((length, _)) = decode_varint(&length_bytes)? {
+            break length as usize;
+        }
+    };
+    if length > max_packet_size {
+        error!(
+            "{COMPONENT} - packet of size: {length} exceeds the maximum size: {max_packet_size}."
+        );
+        return Err(IggyError::InvalidFormat);
+    }
+
+    let mut body = BytesMut::zeroed(length);
+    reader
+        .read_exact(&mut body)
+        .await
+        .map_err(|_| IggyError::ConnectionClosed)?;
+    Ok((first_byte, body.freeze()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connection_state(
+        max_outgoing_packet_size: usize,
+    ) -> (ConnectionState, mpsc::Receiver<Bytes>) {
+        let (sender, receiver) = mpsc::channel(OUTGOING_PACKETS_QUEUE_SIZE);
+        let state = ConnectionState {
+            version: ProtocolVersion::V5,
+            client_id: "device-1".to_owned(),
+            persistent: true,
+            max_outgoing_packet_size,
+            subscriptions: Mutex::new(Vec::new()),
+            cursors: tokio::sync::Mutex::new(delivery::Cursors::default()),
+            inflight: Mutex::new(HashMap::new()),
+            next_packet_id: Mutex::new(1),
+            sender,
+        };
+        (state, receiver)
+    }
+
+    fn publish(payload_size: usize) -> Publish {
+        Publish {
+            topic: "sensors/1/temperature".to_owned(),
+            packet_id: None,
+            qos: 1,
+            retain: false,
+            dup: false,
+            payload: Bytes::from(vec![0; payload_size]),
+            properties: Properties::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn oversized_message_should_be_skipped_without_keeping_it_in_flight() {
+        let (state, mut receiver) = connection_state(128);
+        let offset = DeliveredOffset {
+            stream_id: 1,
+            topic_id: 1,
+            partition_id: 1,
+            offset: 0,
+        };
+
+        let sent = state
+            .send_publish(publish(1024), Some(offset))
+            .await
+            .unwrap();
+        assert!(!sent);
+        assert_eq!(state.inflight_count(), 0);
+        assert!(receiver.try_recv().is_err());
+
+        let offset = DeliveredOffset {
+            offset: 1,
+            ..offset
+        };
+        let sent = state.send_publish(publish(16), Some(offset)).await.unwrap();
+        assert!(sent);
+        assert_eq!(state.inflight_count(), 1);
+        assert!(receiver.try_recv().is_ok());
+    }
+}
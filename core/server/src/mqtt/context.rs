@@ -0,0 +1,184 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::mqtt::COMPONENT;
+use crate::mqtt::codec::Publish;
+use crate::mqtt::mapping::{TopicMapping, filter_matches};
+use crate::streaming::systems::system::SharedSystem;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Notify;
+use tracing::warn;
+
+/// The maximum number of the retained messages kept in memory, shared by all the clients.
+const MAX_RETAINED_MESSAGES: usize = 10_000;
+
+/// Shared state of the MQTT listener.
+#[derive(Debug)]
+pub struct MqttContext {
+    pub system: SharedSystem,
+    pub mapping: TopicMapping,
+    pub poll_interval: Duration,
+    pub max_packet_size: usize,
+    retained: Mutex<RetainedMessages>,
+    /// The connected clients by their client IDs, notified when the session is taken over.
+    sessions: Mutex<HashMap<String, Arc<Notify>>>,
+}
+
+impl MqttContext {
+    pub fn new(
+        system: SharedSystem,
+        mapping: TopicMapping,
+        poll_interval: Duration,
+        max_packet_size: usize,
+    ) -> Self {
+        Self {
+            system,
+            mapping,
+            poll_interval,
+            max_packet_size,
+            retained: Mutex::new(RetainedMessages::new(MAX_RETAINED_MESSAGES)),
+            sessions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Stores the retained message of the topic, the message with the empty payload clears it.
+    pub fn retain(&self, publish: &Publish) {
+        self.retained.lock().unwrap().retain(publish);
+    }
+
+    /// Returns the retained messages of the topics matching the filter, regardless of the permissions.
+    pub fn get_retained(&self, filter: &str) -> Vec<Publish> {
+        self.retained.lock().unwrap().get(filter)
+    }
+
+    /// Registers the connected client, the existing connection with the same client ID is closed.
+    pub fn register_session(&self, client_id: &str) -> Arc<Notify> {
+        let notify = Arc::new(Notify::new());
+        if let Some(previous) = self
+            .sessions
+            .lock()
+            .unwrap()
+            .insert(client_id.to_owned(), notify.clone())
+        {
+            previous.notify_one();
+        }
+        notify
+    }
+
+    pub fn unregister_session(&self, client_id: &str, notify: &Arc<Notify>) {
+        let mut sessions = self.sessions.lock().unwrap();
+        if sessions
+            .get(client_id)
+            .is_some_and(|current| Arc::ptr_eq(current, notify))
+        {
+            sessions.remove(client_id);
+        }
+    }
+}
+
+/// The last retained message of each topic, kept in memory. Once the limit is reached,
+/// only the retained messages of the already known topics are replaced.
+#[derive(Debug)]
+struct RetainedMessages {
+    messages: BTreeMap<String, Publish>,
+    limit: usize,
+}
+
+impl RetainedMessages {
+    fn new(limit: usize) -> Self {
+        Self {
+            messages: BTreeMap::new(),
+            limit,
+        }
+    }
+
+    fn retain(&mut self, publish: &Publish) {
+        if publish.payload.is_empty() {
+            self.messages.remove(&publish.topic);
+            return;
+        }
+
+        if self.messages.len() >= self.limit && !self.messages.contains_key(&publish.topic) {
+            warn!(
+                "{COMPONENT} - limit of: {} retained messages has been reached, the retained message of topic: {} is ignored.",
+                self.limit, publish.topic
+            );
+            return;
+        }
+
+        self.messages.insert(
+            publish.topic.clone(),
+            Publish {
+                packet_id: None,
+                dup: false,
+                ..publish.clone()
+            },
+        );
+    }
+
+    fn get(&self, filter: &str) -> Vec<Publish> {
+        self.messages
+            .values()
+            .filter(|publish| filter_matches(filter, &publish.topic))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+
+    fn publish(topic: &str, payload: &'static [u8]) -> Publish {
+        Publish {
+            topic: topic.to_owned(),
+            packet_id: Some(1),
+            qos: 1,
+            retain: true,
+            dup: false,
+            payload: Bytes::from_static(payload),
+            properties: Default::default(),
+        }
+    }
+
+    #[test]
+    fn retained_messages_should_be_limited() {
+        let mut retained = RetainedMessages::new(2);
+        retained.retain(&publish("sensors/1", b"1"));
+        retained.retain(&publish("sensors/2", b"2"));
+        retained.retain(&publish("sensors/3", b"3"));
+        retained.retain(&publish("sensors/2", b"updated"));
+
+        let messages = retained.get("sensors/#");
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[1].payload, Bytes::from_static(b"updated"));
+        assert_eq!(messages[1].packet_id, None);
+
+        retained.retain(&publish("sensors/1", b""));
+        retained.retain(&publish("sensors/3", b"3"));
+        let topics = retained
+            .get("#")
+            .into_iter()
+            .map(|publish| publish.topic)
+            .collect::<Vec<_>>();
+        assert_eq!(topics, vec!["sensors/2", "sensors/3"]);
+    }
+}
@@ -0,0 +1,347 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::mqtt::codec::{Properties, Property, Publish};
+use crate::mqtt::connection::{ConnectionState, DeliveredOffset};
+use crate::mqtt::context::MqttContext;
+use crate::mqtt::mapping::filter_matches;
+use crate::mqtt::{COMPONENT, MQTT_TOPIC_HEADER};
+use crate::streaming::session::Session;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::System;
+use iggy_common::locking::IggySharedMutFn;
+use iggy_common::{Consumer, Identifier, IggyError, IggyMessage, PollingStrategy};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tracing::{debug, error, warn};
+
+/// The maximum number of messages polled from a single partition in one tick.
+const MAX_MESSAGES_PER_POLL: u32 = 100;
+/// The maximum number of QoS 1 messages awaiting the acknowledgement from the client.
+const MAX_INFLIGHT_MESSAGES: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PartitionKey {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+}
+
+#[derive(Debug)]
+pub struct Cursor {
+    pub stream_name: String,
+    pub topic_name: String,
+    pub next_offset: u64,
+}
+
+pub type Cursors = HashMap<PartitionKey, Cursor>;
+
+/// Delivers the messages appended to the partitions of the subscribed topics, until the
+/// connection is closed (the task is aborted by the connection handler).
+pub async fn run(context: Arc<MqttContext>, state: Arc<ConnectionState>, session: Arc<Session>) {
+    let mut interval = tokio::time::interval(context.poll_interval);
+    loop {
+        interval.tick().await;
+        match deliver(&context, &state, &session).await {
+            Ok(()) => {}
+            Err(IggyError::ConnectionClosed) => return,
+            Err(error) => {
+                error!(
+                    "{COMPONENT} - failed to deliver the messages to client: {}. {error}",
+                    state.client_id
+                );
+            }
+        }
+    }
+}
+
+/// Starts tracking the partitions of the topics matching the subscriptions, and stops tracking the
+/// ones that are no longer matched. The partitions of the topics existing at the time of subscribing
+/// are read from the end (`start_at_end`), while the ones of the topics created later from the start.
+/// The persistent session resumes from the offset stored for the client.
+pub async fn track_topics(
+    context: &MqttContext,
+    state: &ConnectionState,
+    session: &Session,
+    cursors: &mut Cursors,
+    start_at_end: bool,
+) {
+    let subscriptions = state.subscriptions.lock().unwrap().clone();
+    let system = context.system.read().await;
+    let mut tracked = HashSet::new();
+    for stream in system.get_streams() {
+        for topic in stream.get_topics() {
+            if !subscriptions
+                .iter()
+                .any(|subscription| subscription.matches_topic(&stream.name, &topic.name))
+            {
+                continue;
+            }
+            let (Ok(stream_id), Ok(topic_id)) = (
+                Identifier::numeric(stream.stream_id),
+                Identifier::numeric(topic.topic_id),
+            ) else {
+                continue;
+            };
+            if system.find_topic(session, &stream_id, &topic_id).is_err() {
+                continue;
+            }
+
+            for partition_id in 1..=topic.get_partitions_count() {
+                let key = PartitionKey {
+                    stream_id: stream.stream_id,
+                    topic_id: topic.topic_id,
+                    partition_id,
+                };
+                tracked.insert(key);
+                if cursors.contains_key(&key) {
+                    continue;
+                }
+
+                let stored_offset = if state.persistent {
+                    get_stored_offset(&system, state, session, &stream_id, &topic_id, partition_id)
+                        .await
+                } else {
+                    None
+                };
+                let next_offset = match stored_offset {
+                    Some(offset) => offset + 1,
+                    None if start_at_end => {
+                        let Ok(partition) = topic.get_partition(partition_id) else {
+                            continue;
+                        };
+                        let partition = partition.read().await;
+                        get_high_watermark(
+                            partition.current_offset,
+                            partition.should_increment_offset,
+                        )
+                    }
+                    None => 0,
+                };
+                debug!(
+                    "{COMPONENT} - client: {} is tracking partition: {partition_id} of topic: {}/{} from offset: {next_offset}.",
+                    state.client_id, stream.name, topic.name
+                );
+                cursors.insert(
+                    key,
+                    Cursor {
+                        stream_name: stream.name.clone(),
+                        topic_name: topic.name.clone(),
+                        next_offset,
+                    },
+                );
+            }
+        }
+    }
+    cursors.retain(|key, _| tracked.contains(key));
+}
+
+async fn get_stored_offset(
+    system: &System,
+    state: &ConnectionState,
+    session: &Session,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+    partition_id: u32,
+) -> Option<u64> {
+    let consumer = state.consumer().ok()?;
+    system
+        .get_consumer_offset(session, &consumer, stream_id, topic_id, Some(partition_id))
+        .await
+        .ok()
+        .flatten()
+        .map(|offset| offset.stored_offset)
+}
+
+/// Stores the offset of the delivered message, for the consumer named after the client ID.
+pub async fn store_offset(
+    context: &MqttContext,
+    state: &ConnectionState,
+    session: &Session,
+    offset: DeliveredOffset,
+) {
+    let (Ok(consumer), Ok(stream_id), Ok(topic_id)) = (
+        state.consumer(),
+        Identifier::numeric(offset.stream_id),
+        Identifier::numeric(offset.topic_id),
+    ) else {
+        return;
+    };
+    let system = context.system.read().await;
+    if let Err(error) = system
+        .store_consumer_offset(
+            session,
+            consumer,
+            &stream_id,
+            &topic_id,
+            Some(offset.partition_id),
+            offset.offset,
+        )
+        .await
+    {
+        warn!(
+            "{COMPONENT} - failed to store the offset: {} of client: {}. {error}",
+            offset.offset, state.client_id
+        );
+    }
+}
+
+/// The offset of the next message appended to the partition.
+fn get_high_watermark(current_offset: u64, should_increment_offset: bool) -> u64 {
+    if should_increment_offset {
+        current_offset + 1
+    } else {
+        0
+    }
+}
+
+async fn deliver(
+    context: &MqttContext,
+    state: &ConnectionState,
+    session: &Session,
+) -> Result<(), IggyError> {
+    let mut cursors = state.cursors.lock().await;
+    track_topics(context, state, session, &mut cursors, false).await;
+    if cursors.is_empty() {
+        return Ok(());
+    }
+
+    let subscriptions = state.subscriptions.lock().unwrap().clone();
+    let mut available = MAX_INFLIGHT_MESSAGES.saturating_sub(state.inflight_count());
+    let mut publishes = Vec::new();
+    let system = context.system.read().await;
+    for (key, cursor) in cursors.iter_mut() {
+        if available == 0 {
+            break;
+        }
+        let (Ok(stream_id), Ok(topic_id)) = (
+            Identifier::numeric(key.stream_id),
+            Identifier::numeric(key.topic_id),
+        ) else {
+            continue;
+        };
+        let Ok(topic) = system.find_topic(session, &stream_id, &topic_id) else {
+            continue;
+        };
+        let Ok(partition) = topic.get_partition(key.partition_id) else {
+            continue;
+        };
+        let high_watermark = {
+            let partition = partition.read().await;
+            get_high_watermark(partition.current_offset, partition.should_increment_offset)
+        };
+        if cursor.next_offset >= high_watermark {
+            continue;
+        }
+
+        let args = PollingArgs::new(
+            PollingStrategy::offset(cursor.next_offset),
+            MAX_MESSAGES_PER_POLL.min(available as u32),
+            false,
+        );
+        let (metadata, batch_set) = system
+            .poll_messages(
+                session,
+                &Consumer::default(),
+                &stream_id,
+                &topic_id,
+                Some(key.partition_id),
+                args,
+            )
+            .await?;
+        for message in batch_set.into_polled_messages(metadata).messages {
+            let offset = DeliveredOffset {
+                stream_id: key.stream_id,
+                topic_id: key.topic_id,
+                partition_id: key.partition_id,
+                offset: message.header.offset,
+            };
+            cursor.next_offset = offset.offset + 1;
+            let publish = to_publish(message, &cursor.stream_name, &cursor.topic_name);
+            // The message is delivered with the maximum QoS of the matching subscriptions.
+            let Some(qos) = subscriptions
+                .iter()
+                .filter(|subscription| {
+                    subscription.matches_topic(&cursor.stream_name, &cursor.topic_name)
+                        && filter_matches(&subscription.filter, &publish.topic)
+                })
+                .map(|subscription| subscription.qos)
+                .max()
+            else {
+                continue;
+            };
+            publishes.push((Publish { qos, ..publish }, offset));
+            available = available.saturating_sub(1);
+        }
+    }
+    drop(system);
+    drop(cursors);
+
+    let mut delivered_offsets = HashMap::new();
+    for (publish, offset) in publishes {
+        let awaits_ack = if publish.qos > 0 {
+            state.send_publish(publish, Some(offset)).await?
+        } else {
+            state.send_publish(publish, None).await?;
+            false
+        };
+        if !awaits_ack {
+            delivered_offsets.insert(
+                (offset.stream_id, offset.topic_id, offset.partition_id),
+                offset,
+            );
+        }
+    }
+    // QoS 0 and skipped messages are never acknowledged, so the offsets are stored once they're handled.
+    if state.persistent {
+        for offset in delivered_offsets.into_values() {
+            store_offset(context, state, session, offset).await;
+        }
+    }
+    Ok(())
+}
+
+/// Converts the message to PUBLISH, restoring the MQTT topic and the user properties from the headers.
+/// The messages appended by the other clients are published on the `stream/topic` MQTT topic.
+fn to_publish(message: IggyMessage, stream_name: &str, topic_name: &str) -> Publish {
+    let mut topic = None;
+    let mut properties = Vec::new();
+    if let Ok(Some(headers)) = message.user_headers_map() {
+        for (key, value) in headers {
+            let value = value
+                .as_str()
+                .map(str::to_owned)
+                .unwrap_or_else(|_| value.value_only_to_string());
+            if key.as_str() == MQTT_TOPIC_HEADER {
+                topic = Some(value);
+            } else {
+                properties.push(Property::User(key.as_str().to_owned(), value));
+            }
+        }
+    }
+
+    Publish {
+        topic: topic.unwrap_or_else(|| format!("{stream_name}/{topic_name}")),
+        packet_id: None,
+        qos: 0,
+        retain: false,
+        dup: false,
+        payload: message.payload,
+        properties: Properties(properties),
+    }
+}
@@ -0,0 +1,334 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+/// Maps the MQTT topics onto the Iggy streams and topics using the configured rules.
+/// Each rule has the `<topic filter>=<stream>/<topic>` format, e.g. `sensors/#=iot/sensors`,
+/// and the stream and topic names may refer to the levels of the MQTT topic using the `{n}`
+/// placeholders (1-based), e.g. `devices/+/telemetry=devices/{2}`. The first matching rule wins.
+#[derive(Debug, Clone, Default)]
+pub struct TopicMapping {
+    rules: Vec<MappingRule>,
+}
+
+#[derive(Debug, Clone)]
+struct MappingRule {
+    filter: String,
+    stream: Template,
+    topic: Template,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TemplatePart {
+    Literal(String),
+    Level(usize),
+}
+
+#[derive(Debug, Clone)]
+struct Template(Vec<TemplatePart>);
+
+/// Pattern of the stream or topic name, the unknown parts match any characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamePattern(Vec<Option<String>>);
+
+/// The streams and topics, to which the messages matching the subscription may have been sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopicPattern {
+    pub stream: NamePattern,
+    pub topic: NamePattern,
+}
+
+impl TopicMapping {
+    /// Parses the rules, the empty entries are skipped.
+    pub fn parse(rules: &[String]) -> Result<Self, String> {
+        let mut parsed = Vec::new();
+        for rule in rules.iter().filter(|rule| !rule.is_empty()) {
+            let Some((filter, target)) = rule.split_once('=') else {
+                return Err(format!(
+                    "rule: {rule} must be in the <filter>=<stream>/<topic> format"
+                ));
+            };
+            if !is_valid_filter(filter) {
+                return Err(format!("rule: {rule} has an invalid topic filter"));
+            }
+            let Some((stream, topic)) = target.split_once('/') else {
+                return Err(format!(
+                    "rule: {rule} must be in the <filter>=<stream>/<topic> format"
+                ));
+            };
+            let (Some(stream), Some(topic)) = (Template::parse(stream), Template::parse(topic))
+            else {
+                return Err(format!("rule: {rule} has an invalid stream or topic name"));
+            };
+            parsed.push(MappingRule {
+                filter: filter.to_owned(),
+                stream,
+                topic,
+            });
+        }
+        Ok(Self { rules: parsed })
+    }
+
+    /// Returns the stream and topic names, to which the messages published on the topic are sent.
+    pub fn resolve(&self, mqtt_topic: &str) -> Option<(String, String)> {
+        let levels = mqtt_topic.split('/').collect::<Vec<_>>();
+        self.rules
+            .iter()
+            .filter(|rule| filter_matches(&rule.filter, mqtt_topic))
+            .find_map(|rule| Some((rule.stream.render(&levels)?, rule.topic.render(&levels)?)))
+    }
+
+    /// Returns the patterns of the topics, to which the messages matching the filter may have been
+    /// sent, i.e. the targets of the rules which overlap the filter.
+    pub fn resolve_filter(&self, filter: &str) -> Vec<TopicPattern> {
+        let mut patterns = Vec::new();
+        for rule in &self.rules {
+            if !filters_overlap(&rule.filter, filter) {
+                continue;
+            }
+
+            // The level is known, if it's given by the subscription filter or by the rule filter.
+            let rule_levels = rule.filter.split('/').collect::<Vec<_>>();
+            let filter_levels = filter.split('/').collect::<Vec<_>>();
+            let concrete = |levels: &[&str], index: usize| {
+                levels
+                    .get(index)
+                    .filter(|level| !is_wildcard(level))
+                    .map(|level| level.to_string())
+            };
+            let level = |index: usize| {
+                concrete(&filter_levels, index).or_else(|| concrete(&rule_levels, index))
+            };
+            let pattern = TopicPattern {
+                stream: rule.stream.pattern(&level),
+                topic: rule.topic.pattern(&level),
+            };
+            if !patterns.contains(&pattern) {
+                patterns.push(pattern);
+            }
+        }
+        patterns
+    }
+}
+
+impl Template {
+    fn parse(value: &str) -> Option<Self> {
+        if value.is_empty() {
+            return None;
+        }
+
+        let mut parts = Vec::new();
+        let mut rest = value;
+        while let Some(start) = rest.find('{') {
+            if start > 0 {
+                parts.push(TemplatePart::Literal(rest[..start].to_owned()));
+            }
+            let end = rest[start..].find('}')? + start;
+            let level = rest[start + 1..end]
+                .parse::<usize>()
+                .ok()
+                .filter(|level| *level > 0)?;
+            parts.push(TemplatePart::Level(level - 1));
+            rest = &rest[end + 1..];
+        }
+        if !rest.is_empty() {
+            parts.push(TemplatePart::Literal(rest.to_owned()));
+        }
+        if parts.iter().any(
+            |part| matches!(part, TemplatePart::Literal(literal) if literal.contains(['{', '}'])),
+        ) {
+            return None;
+        }
+        Some(Self(parts))
+    }
+
+    fn render(&self, levels: &[&str]) -> Option<String> {
+        let mut name = String::new();
+        for part in &self.0 {
+            match part {
+                TemplatePart::Literal(literal) => name.push_str(literal),
+                TemplatePart::Level(index) => name.push_str(levels.get(*index)?),
+            }
+        }
+        (!name.is_empty()).then_some(name)
+    }
+
+    fn pattern(&self, level: &impl Fn(usize) -> Option<String>) -> NamePattern {
+        NamePattern(
+            self.0
+                .iter()
+                .map(|part| match part {
+                    TemplatePart::Literal(literal) => Some(literal.clone()),
+                    TemplatePart::Level(index) => level(*index),
+                })
+                .collect(),
+        )
+    }
+}
+
+impl NamePattern {
+    pub fn matches(&self, name: &str) -> bool {
+        fn matches_parts(parts: &[Option<String>], name: &str) -> bool {
+            match parts.split_first() {
+                None => name.is_empty(),
+                Some((Some(literal), rest)) => name
+                    .strip_prefix(literal.as_str())
+                    .is_some_and(|name| matches_parts(rest, name)),
+                // The unknown part is a single level of the MQTT topic, so it cannot be empty.
+                Some((None, rest)) => {
+                    !name.is_empty()
+                        && name
+                            .char_indices()
+                            .skip(1)
+                            .map(|(index, _)| index)
+                            .chain(std::iter::once(name.len()))
+                            .any(|index| matches_parts(rest, &name[index..]))
+                }
+            }
+        }
+
+        matches_parts(&self.0, name)
+    }
+}
+
+fn is_wildcard(level: &str) -> bool {
+    level == "+" || level == "#"
+}
+
+/// Returns `true` if the topic name is valid for publishing (non-empty, without wildcards).
+pub fn is_valid_topic(topic: &str) -> bool {
+    !topic.is_empty() && !topic.contains(['+', '#', '\0'])
+}
+
+/// Returns `true` if the topic filter is valid, i.e. the wildcards occupy the entire levels
+/// and the multi-level wildcard is the last one.
+pub fn is_valid_filter(filter: &str) -> bool {
+    if filter.is_empty() || filter.contains('\0') {
+        return false;
+    }
+
+    let levels = filter.split('/').collect::<Vec<_>>();
+    levels.iter().enumerate().all(|(index, level)| {
+        if *level == "#" {
+            return index == levels.len() - 1;
+        }
+        *level == "+" || !level.contains(['+', '#'])
+    })
+}
+
+/// Returns `true` if the topic matches the filter. The topics starting with '$' are not matched
+/// by the filters starting with a wildcard.
+pub fn filter_matches(filter: &str, topic: &str) -> bool {
+    if topic.starts_with('$') && filter.starts_with(['+', '#']) {
+        return false;
+    }
+
+    let mut filter_levels = filter.split('/');
+    let mut topic_levels = topic.split('/');
+    loop {
+        match (filter_levels.next(), topic_levels.next()) {
+            (Some("#"), _) => return true,
+            (Some("+"), Some(_)) => {}
+            (Some(filter_level), Some(topic_level)) if filter_level == topic_level => {}
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+/// Returns `true` if there is a topic matching both filters.
+pub fn filters_overlap(first: &str, second: &str) -> bool {
+    let mut first_levels = first.split('/');
+    let mut second_levels = second.split('/');
+    loop {
+        match (first_levels.next(), second_levels.next()) {
+            (Some("#"), _) | (_, Some("#")) => return true,
+            (Some(first), Some(second)) if first == "+" || second == "+" || first == second => {}
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mapping(rules: &[&str]) -> TopicMapping {
+        TopicMapping::parse(
+            &rules
+                .iter()
+                .map(|rule| rule.to_string())
+                .collect::<Vec<_>>(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn should_match_topic_filters() {
+        assert!(filter_matches(
+            "sensors/+/temperature",
+            "sensors/1/temperature"
+        ));
+        assert!(!filter_matches(
+            "sensors/+/temperature",
+            "sensors/1/humidity"
+        ));
+        assert!(filter_matches("sensors/#", "sensors"));
+        assert!(filter_matches("sensors/#", "sensors/1/temperature"));
+        assert!(!filter_matches("#", "$SYS/uptime"));
+        assert!(filter_matches("$SYS/#", "$SYS/uptime"));
+
+        assert!(is_valid_filter("sensors/+/#"));
+        assert!(!is_valid_filter("sensors/#/temperature"));
+        assert!(!is_valid_filter("sensors/temp+"));
+        assert!(!is_valid_topic("sensors/+"));
+
+        assert!(filters_overlap("sensors/+/temperature", "sensors/1/#"));
+        assert!(!filters_overlap("sensors/+/temperature", "devices/#"));
+    }
+
+    #[test]
+    fn should_resolve_topics_using_first_matching_rule() {
+        let mapping = mapping(&["devices/+/telemetry=devices/{2}", "#=mqtt/messages"]);
+        assert_eq!(
+            mapping.resolve("devices/d1/telemetry"),
+            Some(("devices".to_owned(), "d1".to_owned()))
+        );
+        assert_eq!(
+            mapping.resolve("devices/d1/status"),
+            Some(("mqtt".to_owned(), "messages".to_owned()))
+        );
+        assert!(TopicMapping::parse(&["sensors".to_owned()]).is_err());
+        assert!(TopicMapping::parse(&["sensors=iot/{0}".to_owned()]).is_err());
+    }
+
+    #[test]
+    fn should_resolve_subscription_filters_to_topic_patterns() {
+        let mapping = mapping(&["devices/+/telemetry=devices/device-{2}", "#=mqtt/messages"]);
+        let patterns = mapping.resolve_filter("devices/+/telemetry");
+        assert_eq!(patterns.len(), 2);
+        assert!(patterns[0].stream.matches("devices"));
+        assert!(patterns[0].topic.matches("device-d1"));
+        assert!(!patterns[0].topic.matches("device-"));
+        assert!(!patterns[0].topic.matches("sensor-d1"));
+
+        let patterns = mapping.resolve_filter("devices/d2/telemetry");
+        assert!(patterns[0].topic.matches("device-d2"));
+        assert!(!patterns[0].topic.matches("device-d1"));
+    }
+}
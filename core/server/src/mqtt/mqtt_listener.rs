@@ -0,0 +1,91 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::mqtt::MqttConfig;
+use crate::mqtt::COMPONENT;
+use crate::mqtt::connection::handle_connection;
+use crate::mqtt::context::MqttContext;
+use crate::mqtt::mapping::TopicMapping;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::systems::system::SharedSystem;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tracing::{error, info};
+
+pub async fn start(config: MqttConfig, system: SharedSystem) -> SocketAddr {
+    let listener = TcpListener::bind(&config.address)
+        .await
+        .unwrap_or_else(|error| {
+            panic!(
+                "Unable to bind MQTT listener to: {}. {error}",
+                config.address
+            )
+        });
+    let local_addr = listener
+        .local_addr()
+        .expect("Failed to get local address for MQTT listener");
+
+    let mapping = TopicMapping::parse(&config.rules).expect("Invalid MQTT topic mapping rules");
+    let context = Arc::new(MqttContext::new(
+        system,
+        mapping,
+        config.poll_interval.get_duration(),
+        config.max_packet_size.as_bytes_u64() as usize,
+    ));
+
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, address)) => {
+                    info!("Accepted new MQTT connection: {address}");
+                    if let Err(error) = stream.set_nodelay(true) {
+                        error!(
+                            "{COMPONENT} (error: {error}) - failed to set TCP_NODELAY for: {address}"
+                        );
+                    }
+
+                    let session = context
+                        .system
+                        .read()
+                        .await
+                        .add_client(&address, Transport::Mqtt)
+                        .await;
+                    let client_id = session.client_id;
+                    info!("Created new session: {session}");
+                    let context = context.clone();
+                    tokio::spawn(async move {
+                        if let Err(error) =
+                            handle_connection(context.clone(), session, stream).await
+                        {
+                            error!(
+                                "{COMPONENT} (error: {error}) - connection with client: {client_id}, address: {address} has failed."
+                            );
+                        }
+                        context.system.read().await.delete_client(client_id).await;
+                        info!(
+                            "Closed MQTT connection for client: {client_id}, address: {address}."
+                        );
+                    });
+                }
+                Err(error) => error!("Unable to accept MQTT socket. {error}"),
+            }
+        }
+    });
+    local_addr
+}
@@ -0,0 +1,62 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy_common::IggyError;
+
+pub const SUCCESS: u8 = 0x00;
+pub const GRANTED_QOS_1: u8 = 0x01;
+pub const DISCONNECT_WITH_WILL_MESSAGE: u8 = 0x04;
+pub const NO_SUBSCRIPTION_EXISTED: u8 = 0x11;
+
+// The return codes of the CONNACK packet in MQTT 3.1.1.
+pub const V3_UNACCEPTABLE_PROTOCOL_VERSION: u8 = 0x01;
+pub const V3_IDENTIFIER_REJECTED: u8 = 0x02;
+pub const V3_BAD_USERNAME_OR_PASSWORD: u8 = 0x04;
+
+pub const UNSPECIFIED_ERROR: u8 = 0x80;
+pub const PROTOCOL_ERROR: u8 = 0x82;
+pub const IMPLEMENTATION_SPECIFIC_ERROR: u8 = 0x83;
+pub const CLIENT_IDENTIFIER_NOT_VALID: u8 = 0x85;
+pub const BAD_USERNAME_OR_PASSWORD: u8 = 0x86;
+pub const NOT_AUTHORIZED: u8 = 0x87;
+pub const KEEP_ALIVE_TIMEOUT: u8 = 0x8D;
+pub const SESSION_TAKEN_OVER: u8 = 0x8E;
+pub const TOPIC_FILTER_INVALID: u8 = 0x8F;
+pub const TOPIC_NAME_INVALID: u8 = 0x90;
+pub const TOPIC_ALIAS_INVALID: u8 = 0x94;
+pub const PAYLOAD_FORMAT_INVALID: u8 = 0x99;
+pub const QUOTA_EXCEEDED: u8 = 0x97;
+pub const QOS_NOT_SUPPORTED: u8 = 0x9B;
+pub const SHARED_SUBSCRIPTIONS_NOT_SUPPORTED: u8 = 0x9E;
+pub const SUBSCRIPTION_IDENTIFIERS_NOT_SUPPORTED: u8 = 0xA1;
+
+/// Returns the MQTT 5 reason code of the failed publish.
+pub fn from_error(error: &IggyError) -> u8 {
+    match error {
+        IggyError::Unauthorized => NOT_AUTHORIZED,
+        IggyError::StreamNameNotFound(_)
+        | IggyError::TopicNameNotFound(_, _)
+        | IggyError::InvalidIdentifier => TOPIC_NAME_INVALID,
+        IggyError::InvalidHeaderKey
+        | IggyError::InvalidHeaderValue
+        | IggyError::TooBigUserHeaders
+        | IggyError::TooBigMessagePayload => PAYLOAD_FORMAT_INVALID,
+        IggyError::StreamFull(_) | IggyError::TopicFull(_, _) => QUOTA_EXCEEDED,
+        _ => IMPLEMENTATION_SPECIFIC_ERROR,
+    }
+}
@@ -22,9 +22,11 @@ use crate::server_error::ConnectionError;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
+use crate::tcp::connection_handler::{record_frame_compression, record_request};
 use anyhow::anyhow;
-use iggy_common::IggyError;
+use iggy_common::{COMPRESSED_FRAME_FLAG, IggyError};
 use quinn::{Connection, Endpoint, RecvStream, SendStream};
+use std::time::Instant;
 use tracing::{error, info, trace};
 
 const LISTENERS_COUNT: u32 = 10;
@@ -74,7 +76,20 @@ async fn handle_connection(
         .await;
 
     let client_id = session.client_id;
-    while let Some(stream) = accept_stream(&connection, &system, client_id).await? {
+    loop {
+        let stream = tokio::select! {
+            _ = session.disconnected() => {
+                info!("Session: {session} has been disconnected by the server.");
+                connection.close(0u32.into(), b"disconnected");
+                system.read().await.delete_client(client_id).await;
+                return Ok(());
+            }
+            stream = accept_stream(&connection, &system, client_id) => stream?,
+        };
+        let Some(stream) = stream else {
+            break;
+        };
+
         let system = system.clone();
         let session = session.clone();
 
@@ -123,12 +138,39 @@ async fn handle_stream(
     recv_stream.read_exact(&mut length_buffer).await?;
     recv_stream.read_exact(&mut code_buffer).await?;
 
-    let length = u32::from_le_bytes(length_buffer);
+    let mut length = u32::from_le_bytes(length_buffer);
     let code = u32::from_le_bytes(code_buffer);
 
     trace!("Received a QUIC request, length: {length}, code: {code}");
 
-    let mut sender = SenderKind::get_quic_sender(send_stream, recv_stream);
+    let compression = session.as_ref().get_frame_compression();
+    let compressed = compression.is_enabled() && length & COMPRESSED_FRAME_FLAG != 0;
+    if compressed {
+        length &= !COMPRESSED_FRAME_FLAG;
+    }
+    let mut sender = SenderKind::get_quic_sender(send_stream, recv_stream, compression);
+
+    if let Err(e) = system
+        .read()
+        .await
+        .validate_request(session.as_ref(), code, length)
+    {
+        sender.send_error_response(e.clone()).await?;
+        return Err(anyhow!("Request validation failed: {e}"));
+    }
+
+    let wire_length = length;
+    if compressed {
+        let max_size = system.read().await.max_frame_size();
+        length = match sender.read_compressed_request(length - 4, max_size).await {
+            Ok(decompressed_length) => decompressed_length + 4,
+            Err(e) => {
+                sender.send_error_response(e.clone()).await?;
+                return Err(anyhow!("Failed to decompress request: {e}"));
+            }
+        };
+        trace!("Decompressed a QUIC request, length: {wire_length} -> {length}");
+    }
 
     let command = match ServerCommand::from_code_and_reader(code, &mut sender, length - 4).await {
         Ok(cmd) => cmd,
@@ -145,10 +187,21 @@ async fn handle_stream(
 
     trace!("Received a QUIC command: {command}, payload size: {length}");
 
-    match command
-        .handle(&mut sender, length, session.as_ref(), &system)
-        .await
-    {
+    let session = session.as_ref();
+    session.record_request(code, INITIAL_BYTES_LENGTH as u64 + wire_length as u64);
+    let started_at = Instant::now();
+    let result = command.handle(&mut sender, length, session, &system).await;
+    session.record_response(sender.take_sent_bytes());
+    record_frame_compression(&mut sender, &system).await;
+    record_request(
+        sender.transport(),
+        &system,
+        code,
+        started_at.elapsed(),
+        &result,
+    )
+    .await;
+    match result {
         Ok(_) => {
             trace!(
                 "Command was handled successfully, session: {:?}. QUIC response was sent.",
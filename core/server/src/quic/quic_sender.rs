@@ -16,10 +16,12 @@
  * under the License.
  */
 
+use crate::binary::frame_compressor::FrameCompressor;
+use crate::binary::sender::{RESPONSE_HEADER_SIZE, Sender};
 use crate::quic::COMPONENT;
-use crate::{binary::sender::Sender, server_error::ServerError};
+use crate::server_error::ServerError;
 use error_set::ErrContext;
-use iggy_common::IggyError;
+use iggy_common::{COMPRESSED_FRAME_FLAG, IggyError};
 use quinn::{RecvStream, SendStream};
 use std::io::IoSlice;
 use tracing::{debug, error};
@@ -28,12 +30,18 @@ const STATUS_OK: &[u8] = &[0; 4];
 
 #[derive(Debug)]
 pub struct QuicSender {
+    pub(crate) sent_bytes: u64,
     pub(crate) send: SendStream,
     pub(crate) recv: RecvStream,
+    pub(crate) compressor: FrameCompressor,
 }
 
 impl Sender for QuicSender {
     async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError> {
+        if let Some(result) = self.compressor.read(buffer) {
+            return result;
+        }
+
         // Not-so-nice code because quinn recv stream has different API for read_exact
         let read_bytes = buffer.len();
         self.recv.read_exact(buffer).await.map_err(|error| {
@@ -49,6 +57,10 @@ impl Sender for QuicSender {
     }
 
     async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        if let Some(compressed) = self.compressor.compress_response(payload) {
+            let length = compressed.len() as u32 | COMPRESSED_FRAME_FLAG;
+            return self.send_frame(STATUS_OK, length, &compressed).await;
+        }
         self.send_response(STATUS_OK, payload).await
     }
 
@@ -66,7 +78,14 @@ impl Sender for QuicSender {
         length: &[u8],
         slices: Vec<IoSlice<'_>>,
     ) -> Result<(), IggyError> {
+        if let Some(compressed) = self.compressor.compress_response_vectored(&slices) {
+            let length = compressed.len() as u32 | COMPRESSED_FRAME_FLAG;
+            return self.send_frame(STATUS_OK, length, &compressed).await;
+        }
+
         debug!("Sending vectored response with status: {:?}...", STATUS_OK);
+        self.sent_bytes +=
+            (RESPONSE_HEADER_SIZE + slices.iter().map(|slice| slice.len()).sum::<usize>()) as u64;
 
         let headers = [STATUS_OK, length].concat();
         self.send
@@ -113,12 +132,22 @@ impl Sender for QuicSender {
 
 impl QuicSender {
     async fn send_response(&mut self, status: &[u8], payload: &[u8]) -> Result<(), IggyError> {
+        self.send_frame(status, payload.len() as u32, payload).await
+    }
+
+    async fn send_frame(
+        &mut self,
+        status: &[u8],
+        length: u32,
+        payload: &[u8],
+    ) -> Result<(), IggyError> {
         debug!(
             "Sending response of len: {} with status: {:?}...",
             payload.len(),
             status
         );
-        let length = (payload.len() as u32).to_le_bytes();
+        self.sent_bytes += (RESPONSE_HEADER_SIZE + payload.len()) as u64;
+        let length = length.to_le_bytes();
         self.send
             .write_all(&[status, &length, payload].as_slice().concat())
             .await
@@ -23,6 +23,7 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use error_set::ErrContext;
+use quinn::crypto::rustls::QuicServerConfig;
 use quinn::{Endpoint, IdleTimeout, VarInt};
 use rustls::pki_types::{CertificateDer, PrivateKeyDer};
 use tracing::info;
@@ -32,35 +33,74 @@ use crate::quic::COMPONENT;
 use crate::quic::listener;
 use crate::server_error::QuicError;
 use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::utils::certificates::{ReloadableCertResolver, start_certificate_reloader};
 
 /// Starts the QUIC server.
 /// Returns the address the server is listening on.
 pub fn start(config: QuicConfig, system: SharedSystem) -> SocketAddr {
     info!("Initializing Iggy QUIC server...");
     let address = config.address.parse().unwrap();
+    let self_signed = config.certificate.self_signed;
     let quic_config = configure_quic(config);
     if let Err(error) = quic_config {
         panic!("Error when configuring QUIC: {error:?}");
     }
 
-    let endpoint = Endpoint::server(quic_config.unwrap(), address).unwrap();
+    let (quic_config, resolver) = quic_config.unwrap();
+    if !self_signed {
+        let system = system.clone();
+        tokio::spawn(async move {
+            if let Some(config_changes) = system.read().await.subscribe_config_changes() {
+                start_certificate_reloader("QUIC", resolver, config_changes, |config| {
+                    (
+                        config.quic.certificate.cert_file.clone(),
+                        config.quic.certificate.key_file.clone(),
+                    )
+                });
+            }
+        });
+    }
+
+    let endpoint = Endpoint::server(quic_config, address).unwrap();
     let addr = endpoint.local_addr().unwrap();
     listener::start(endpoint, system);
     info!("Iggy QUIC server has started on: {:?}", addr);
     addr
 }
 
-fn configure_quic(config: QuicConfig) -> Result<quinn::ServerConfig, QuicError> {
+fn configure_quic(
+    config: QuicConfig,
+) -> Result<(quinn::ServerConfig, Arc<ReloadableCertResolver>), QuicError> {
     let (certificate, key) = match config.certificate.self_signed {
         true => generate_self_signed_cert()?,
         false => load_certificates(&config.certificate.cert_file, &config.certificate.key_file)?,
     };
 
-    let mut server_config = quinn::ServerConfig::with_single_cert(certificate, key)
+    // The certificate is served by the resolver, so that it can be rotated without restarting the endpoint.
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let resolver = Arc::new(
+        ReloadableCertResolver::new(certificate, key, provider.clone())
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to create certificate resolver")
+            })
+            .map_err(|_| QuicError::ConfigCreationError)?,
+    );
+    let mut crypto = rustls::ServerConfig::builder_with_provider(provider)
+        .with_protocol_versions(&[&rustls::version::TLS13])
+        .with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to create TLS config")
+        })
+        .map_err(|_| QuicError::ConfigCreationError)?
+        .with_no_client_auth()
+        .with_cert_resolver(resolver.clone());
+    crypto.max_early_data_size = u32::MAX;
+    let crypto = QuicServerConfig::try_from(crypto)
         .with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to create server config")
         })
         .map_err(|_| QuicError::ConfigCreationError)?;
+
+    let mut server_config = quinn::ServerConfig::with_crypto(Arc::new(crypto));
     let mut transport = quinn::TransportConfig::default();
     transport.initial_mtu(config.initial_mtu.as_bytes_u64() as u16);
     transport.send_window(config.send_window.as_bytes_u64());
@@ -92,7 +132,7 @@ fn configure_quic(config: QuicConfig) -> Result<quinn::ServerConfig, QuicError>
     }
 
     server_config.transport_config(Arc::new(transport));
-    Ok(server_config)
+    Ok((server_config, resolver))
 }
 
 fn generate_self_signed_cert<'a>() -> Result<(Vec<CertificateDer<'a>>, PrivateKeyDer<'a>), QuicError>
@@ -35,15 +35,19 @@ use iggy_common::delete_user::DeleteUser;
 use iggy_common::purge_stream::PurgeStream;
 use iggy_common::purge_topic::PurgeTopic;
 use iggy_common::update_permissions::UpdatePermissions;
+use iggy_common::update_personal_access_token_quota::UpdatePersonalAccessTokenQuota;
 use iggy_common::update_stream::UpdateStream;
+use iggy_common::update_stream_retention::UpdateStreamRetention;
 use iggy_common::update_topic::UpdateTopic;
 use iggy_common::update_user::UpdateUser;
+use iggy_common::update_user_quota::UpdateUserQuota;
 use iggy_common::{
     CHANGE_PASSWORD_CODE, CREATE_CONSUMER_GROUP_CODE, CREATE_PARTITIONS_CODE,
     CREATE_PERSONAL_ACCESS_TOKEN_CODE, CREATE_STREAM_CODE, CREATE_TOPIC_CODE, CREATE_USER_CODE,
     Command, DELETE_CONSUMER_GROUP_CODE, DELETE_PARTITIONS_CODE, DELETE_PERSONAL_ACCESS_TOKEN_CODE,
     DELETE_STREAM_CODE, DELETE_TOPIC_CODE, DELETE_USER_CODE, PURGE_STREAM_CODE, PURGE_TOPIC_CODE,
-    UPDATE_PERMISSIONS_CODE, UPDATE_STREAM_CODE, UPDATE_TOPIC_CODE, UPDATE_USER_CODE,
+    UPDATE_PERMISSIONS_CODE, UPDATE_PERSONAL_ACCESS_TOKEN_QUOTA_CODE, UPDATE_STREAM_CODE,
+    UPDATE_STREAM_RETENTION_CODE, UPDATE_TOPIC_CODE, UPDATE_USER_CODE, UPDATE_USER_QUOTA_CODE,
 };
 use std::fmt::{Display, Formatter};
 
@@ -53,6 +57,7 @@ pub enum EntryCommand {
     UpdateStream(UpdateStream),
     DeleteStream(DeleteStream),
     PurgeStream(PurgeStream),
+    UpdateStreamRetention(UpdateStreamRetention),
     CreateTopic(CreateTopicWithId),
     UpdateTopic(UpdateTopic),
     DeleteTopic(DeleteTopic),
@@ -67,8 +72,10 @@ pub enum EntryCommand {
     DeleteUser(DeleteUser),
     ChangePassword(ChangePassword),
     UpdatePermissions(UpdatePermissions),
+    UpdateUserQuota(UpdateUserQuota),
     CreatePersonalAccessToken(CreatePersonalAccessTokenWithHash),
     DeletePersonalAccessToken(DeletePersonalAccessToken),
+    UpdatePersonalAccessTokenQuota(UpdatePersonalAccessTokenQuota),
 }
 
 impl BytesSerializable for EntryCommand {
@@ -76,6 +83,7 @@ impl BytesSerializable for EntryCommand {
         let (code, command) = match self {
             EntryCommand::CreateStream(command) => (command.code(), command.to_bytes()),
             EntryCommand::UpdateStream(command) => (command.code(), command.to_bytes()),
+            EntryCommand::UpdateStreamRetention(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeleteStream(command) => (command.code(), command.to_bytes()),
             EntryCommand::PurgeStream(command) => (command.code(), command.to_bytes()),
             EntryCommand::CreateTopic(command) => (command.code(), command.to_bytes()),
@@ -92,12 +100,16 @@ impl BytesSerializable for EntryCommand {
             EntryCommand::DeleteUser(command) => (command.code(), command.to_bytes()),
             EntryCommand::ChangePassword(command) => (command.code(), command.to_bytes()),
             EntryCommand::UpdatePermissions(command) => (command.code(), command.to_bytes()),
+            EntryCommand::UpdateUserQuota(command) => (command.code(), command.to_bytes()),
             EntryCommand::CreatePersonalAccessToken(command) => {
                 (command.code(), command.to_bytes())
             }
             EntryCommand::DeletePersonalAccessToken(command) => {
                 (command.code(), command.to_bytes())
             }
+            EntryCommand::UpdatePersonalAccessTokenQuota(command) => {
+                (command.code(), command.to_bytes())
+            }
         };
 
         let mut bytes = BytesMut::with_capacity(4 + 4 + command.len());
@@ -125,6 +137,9 @@ impl BytesSerializable for EntryCommand {
                 payload,
             )?)),
             PURGE_STREAM_CODE => Ok(EntryCommand::PurgeStream(PurgeStream::from_bytes(payload)?)),
+            UPDATE_STREAM_RETENTION_CODE => Ok(EntryCommand::UpdateStreamRetention(
+                UpdateStreamRetention::from_bytes(payload)?,
+            )),
             CREATE_TOPIC_CODE => Ok(EntryCommand::CreateTopic(CreateTopicWithId::from_bytes(
                 payload,
             )?)),
@@ -154,12 +169,20 @@ impl BytesSerializable for EntryCommand {
             UPDATE_PERMISSIONS_CODE => Ok(EntryCommand::UpdatePermissions(
                 UpdatePermissions::from_bytes(payload)?,
             )),
+            UPDATE_USER_QUOTA_CODE => Ok(EntryCommand::UpdateUserQuota(
+                UpdateUserQuota::from_bytes(payload)?,
+            )),
             CREATE_PERSONAL_ACCESS_TOKEN_CODE => Ok(EntryCommand::CreatePersonalAccessToken(
                 CreatePersonalAccessTokenWithHash::from_bytes(payload)?,
             )),
             DELETE_PERSONAL_ACCESS_TOKEN_CODE => Ok(EntryCommand::DeletePersonalAccessToken(
                 DeletePersonalAccessToken::from_bytes(payload)?,
             )),
+            UPDATE_PERSONAL_ACCESS_TOKEN_QUOTA_CODE => {
+                Ok(EntryCommand::UpdatePersonalAccessTokenQuota(
+                    UpdatePersonalAccessTokenQuota::from_bytes(payload)?,
+                ))
+            }
             _ => Err(IggyError::InvalidCommand),
         }
     }
@@ -170,6 +193,9 @@ impl Display for EntryCommand {
         match self {
             EntryCommand::CreateStream(command) => write!(f, "CreateStream({command})"),
             EntryCommand::UpdateStream(command) => write!(f, "UpdateStream({command})"),
+            EntryCommand::UpdateStreamRetention(command) => {
+                write!(f, "UpdateStreamRetention({command})")
+            }
             EntryCommand::DeleteStream(command) => write!(f, "DeleteStream({command})"),
             EntryCommand::PurgeStream(command) => write!(f, "PurgeStream({command})"),
             EntryCommand::CreateTopic(command) => write!(f, "CreateTopic({command})"),
@@ -190,12 +216,16 @@ impl Display for EntryCommand {
             EntryCommand::DeleteUser(command) => write!(f, "DeleteUser({command})"),
             EntryCommand::ChangePassword(command) => write!(f, "ChangePassword({command})"),
             EntryCommand::UpdatePermissions(command) => write!(f, "UpdatePermissions({command})"),
+            EntryCommand::UpdateUserQuota(command) => write!(f, "UpdateUserQuota({command})"),
             EntryCommand::CreatePersonalAccessToken(command) => {
                 write!(f, "CreatePersonalAccessToken({command})")
             }
             EntryCommand::DeletePersonalAccessToken(command) => {
                 write!(f, "DeletePersonalAccessToken({command})")
             }
+            EntryCommand::UpdatePersonalAccessTokenQuota(command) => {
+                write!(f, "UpdatePersonalAccessTokenQuota({command})")
+            }
         }
     }
 }
@@ -18,6 +18,7 @@
 
 use crate::state::command::EntryCommand;
 use crate::state::entry::StateEntry;
+use crate::streaming::system_events;
 use iggy_common::IggyError;
 #[cfg(test)]
 use mockall::automock;
@@ -67,11 +68,14 @@ impl StateKind {
         }
     }
 
+    /// Persists the command and publishes the corresponding system event, if enabled.
     pub async fn apply(&self, user_id: u32, command: &EntryCommand) -> Result<(), IggyError> {
         match self {
             Self::File(s) => s.apply(user_id, command).await,
             #[cfg(test)]
             Self::Mock(s) => s.apply(user_id, command).await,
-        }
+        }?;
+        system_events::publish_entry_command(user_id, command);
+        Ok(())
     }
 }
@@ -25,7 +25,7 @@ use iggy_common::IggyError;
 use iggy_common::IggyExpiry;
 use iggy_common::IggyTimestamp;
 use iggy_common::MaxTopicSize;
-use iggy_common::{IdKind, Identifier, Permissions, UserStatus};
+use iggy_common::{IdKind, Identifier, Permissions, StreamSizePolicy, ThroughputQuota, UserStatus};
 use std::fmt::Display;
 use tracing::{debug, info};
 
@@ -40,6 +40,9 @@ pub struct StreamState {
     pub id: u32,
     pub name: String,
     pub created_at: IggyTimestamp,
+    pub max_size: MaxTopicSize,
+    pub message_expiry: IggyExpiry,
+    pub size_policy: StreamSizePolicy,
     pub topics: AHashMap<u32, TopicState>,
 }
 
@@ -67,6 +70,7 @@ pub struct PersonalAccessTokenState {
     pub name: String,
     pub token_hash: String,
     pub expiry_at: Option<IggyTimestamp>,
+    pub quota: Option<ThroughputQuota>,
 }
 
 #[derive(Debug)]
@@ -77,6 +81,7 @@ pub struct UserState {
     pub status: UserStatus,
     pub created_at: IggyTimestamp,
     pub permissions: Option<Permissions>,
+    pub quota: Option<ThroughputQuota>,
     pub personal_access_tokens: AHashMap<String, PersonalAccessTokenState>,
 }
 
@@ -106,6 +111,9 @@ impl SystemState {
                         name: command.name.clone(),
                         topics: AHashMap::new(),
                         created_at: entry.timestamp,
+                        max_size: MaxTopicSize::Unlimited,
+                        message_expiry: IggyExpiry::NeverExpire,
+                        size_policy: StreamSizePolicy::default(),
                     };
                     streams.insert(stream.id, stream);
                 }
@@ -116,6 +124,15 @@ impl SystemState {
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
                     stream.name = command.name;
                 }
+                EntryCommand::UpdateStreamRetention(command) => {
+                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    stream.max_size = command.max_size;
+                    stream.message_expiry = command.message_expiry;
+                    stream.size_policy = command.size_policy;
+                }
                 EntryCommand::DeleteStream(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
                     streams.remove(&stream_id);
@@ -321,6 +338,7 @@ impl SystemState {
                         status: command.status,
                         created_at: entry.timestamp,
                         permissions: command.permissions,
+                        quota: None,
                         personal_access_tokens: AHashMap::new(),
                     };
                     users.insert(user.id, user);
@@ -355,6 +373,13 @@ impl SystemState {
                         .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
                     user.permissions = command.permissions;
                 }
+                EntryCommand::UpdateUserQuota(command) => {
+                    let user_id = find_user_id(&users, &command.user_id);
+                    let user = users
+                        .get_mut(&user_id)
+                        .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
+                    user.quota = command.quota;
+                }
                 EntryCommand::CreatePersonalAccessToken(command) => {
                     let token_hash = command.hash;
                     let user_id = find_user_id(
@@ -386,6 +411,7 @@ impl SystemState {
                             name: command.command.name,
                             token_hash,
                             expiry_at,
+                            quota: None,
                         },
                     );
                 }
@@ -404,6 +430,17 @@ impl SystemState {
                         .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
                     user.personal_access_tokens.remove(&command.name);
                 }
+                EntryCommand::UpdatePersonalAccessTokenQuota(command) => {
+                    let user_id = find_user_id(&users, &command.user_id);
+                    let user = users
+                        .get_mut(&user_id)
+                        .unwrap_or_else(|| panic!("{}", format!("User: {user_id} not found")));
+                    if let Some(personal_access_token) =
+                        user.personal_access_tokens.get_mut(&command.name)
+                    {
+                        personal_access_token.quota = command.quota;
+                    }
+                }
             }
         }
 
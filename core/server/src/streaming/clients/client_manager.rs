@@ -19,18 +19,20 @@
 use crate::streaming::session::Session;
 use crate::streaming::utils::hash;
 use ahash::AHashMap;
+use iggy_common::ClientMetadata;
 use iggy_common::IggyError;
 use iggy_common::IggyTimestamp;
 use iggy_common::UserId;
 use iggy_common::locking::IggySharedMut;
 use iggy_common::locking::IggySharedMutFn;
 use std::fmt::{Display, Formatter};
-use std::net::SocketAddr;
+use std::net::{Ipv4Addr, SocketAddr};
 use std::sync::Arc;
 
 #[derive(Debug, Default)]
 pub struct ClientManager {
     clients: AHashMap<u32, IggySharedMut<Client>>,
+    next_client_id: u32,
 }
 
 #[derive(Debug)]
@@ -38,6 +40,8 @@ pub struct Client {
     pub user_id: Option<u32>,
     pub session: Arc<Session>,
     pub transport: Transport,
+    pub authentication_method: Option<AuthenticationMethod>,
+    pub metadata: Option<ClientMetadata>,
     pub consumer_groups: Vec<ConsumerGroup>,
     pub last_heartbeat: IggyTimestamp,
 }
@@ -53,6 +57,11 @@ pub struct ConsumerGroup {
 pub enum Transport {
     Tcp,
     Quic,
+    Unix,
+    WebSocket,
+    Kafka,
+    Mqtt,
+    Http,
 }
 
 impl Display for Transport {
@@ -60,6 +69,30 @@ impl Display for Transport {
         match self {
             Transport::Tcp => write!(f, "TCP"),
             Transport::Quic => write!(f, "QUIC"),
+            Transport::Unix => write!(f, "Unix"),
+            Transport::WebSocket => write!(f, "WebSocket"),
+            Transport::Kafka => write!(f, "Kafka"),
+            Transport::Mqtt => write!(f, "MQTT"),
+            Transport::Http => write!(f, "HTTP"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AuthenticationMethod {
+    Password,
+    PersonalAccessToken,
+    PeerCredentials,
+    JsonWebToken,
+}
+
+impl Display for AuthenticationMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthenticationMethod::Password => write!(f, "Password"),
+            AuthenticationMethod::PersonalAccessToken => write!(f, "PersonalAccessToken"),
+            AuthenticationMethod::PeerCredentials => write!(f, "PeerCredentials"),
+            AuthenticationMethod::JsonWebToken => write!(f, "JsonWebToken"),
         }
     }
 }
@@ -67,11 +100,49 @@ impl Display for Transport {
 impl ClientManager {
     pub fn add_client(&mut self, address: &SocketAddr, transport: Transport) -> Arc<Session> {
         let client_id = hash::calculate_32(address.to_string().as_bytes());
-        let session = Arc::new(Session::from_client_id(client_id, *address));
+        self.insert_client(Session::from_client_id(client_id, *address), transport)
+    }
+
+    /// Adds the client with the ID which isn't used by any other client, rather than the one derived
+    /// from its address, e.g. for the multiple SSE streams sharing the same HTTP connection.
+    pub fn add_unique_client(
+        &mut self,
+        address: &SocketAddr,
+        transport: Transport,
+    ) -> Arc<Session> {
+        let client_id = self.next_unique_client_id();
+        self.insert_client(Session::from_client_id(client_id, *address), transport)
+    }
+
+    /// Adds the Unix socket client, which has no network address that could identify it,
+    /// thus it gets the ID which isn't used by any other client.
+    pub fn add_unix_client(&mut self, peer_uid: Option<u32>) -> Arc<Session> {
+        let client_id = self.next_unique_client_id();
+        let address = SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), 0);
+        self.insert_client(
+            Session::from_peer(client_id, address, peer_uid),
+            Transport::Unix,
+        )
+    }
+
+    fn next_unique_client_id(&mut self) -> u32 {
+        loop {
+            self.next_client_id = self.next_client_id.wrapping_add(1);
+            if self.next_client_id != 0 && !self.clients.contains_key(&self.next_client_id) {
+                return self.next_client_id;
+            }
+        }
+    }
+
+    fn insert_client(&mut self, session: Session, transport: Transport) -> Arc<Session> {
+        let client_id = session.client_id;
+        let session = Arc::new(session);
         let client = Client {
             user_id: None,
             session: session.clone(),
             transport,
+            authentication_method: None,
+            metadata: None,
             consumer_groups: Vec::new(),
             last_heartbeat: IggyTimestamp::now(),
         };
@@ -79,7 +150,12 @@ impl ClientManager {
         session
     }
 
-    pub async fn set_user_id(&mut self, client_id: u32, user_id: UserId) -> Result<(), IggyError> {
+    pub async fn set_user_id(
+        &mut self,
+        client_id: u32,
+        user_id: UserId,
+        authentication_method: AuthenticationMethod,
+    ) -> Result<(), IggyError> {
         let client = self.clients.get(&client_id);
         if client.is_none() {
             return Err(IggyError::ClientNotFound(client_id));
@@ -87,6 +163,7 @@ impl ClientManager {
 
         let mut client = client.unwrap().write().await;
         client.user_id = Some(user_id);
+        client.authentication_method = Some(authentication_method);
         Ok(())
     }
 
@@ -98,9 +175,24 @@ impl ClientManager {
 
         let mut client = client.unwrap().write().await;
         client.user_id = None;
+        client.authentication_method = None;
         Ok(())
     }
 
+    pub async fn set_metadata(
+        &self,
+        client_id: u32,
+        metadata: ClientMetadata,
+    ) -> Result<Option<ClientMetadata>, IggyError> {
+        let client = self.clients.get(&client_id);
+        if client.is_none() {
+            return Err(IggyError::ClientNotFound(client_id));
+        }
+
+        let mut client = client.unwrap().write().await;
+        Ok(client.metadata.replace(metadata))
+    }
+
     pub fn try_get_client(&self, client_id: u32) -> Option<IggySharedMut<Client>> {
         self.clients.get(&client_id).cloned()
     }
@@ -109,6 +201,17 @@ impl ClientManager {
         self.clients.values().cloned().collect()
     }
 
+    pub async fn get_sessions_for_user(&self, user_id: UserId) -> Vec<Arc<Session>> {
+        let mut sessions = Vec::new();
+        for client in self.clients.values() {
+            let client = client.read().await;
+            if client.user_id == Some(user_id) {
+                sessions.push(client.session.clone());
+            }
+        }
+        sessions
+    }
+
     pub async fn delete_clients_for_user(&mut self, user_id: UserId) -> Result<(), IggyError> {
         let mut clients_to_remove = Vec::new();
         for client in self.clients.values() {
@@ -136,6 +239,19 @@ impl ClientManager {
         client
     }
 
+    /// Deletes the client only if it's registered with the given session, rather than with the one
+    /// which might have taken over its ID in the meantime.
+    pub async fn delete_client_session(
+        &mut self,
+        session: &Arc<Session>,
+    ) -> Option<IggySharedMut<Client>> {
+        let client = self.clients.get(&session.client_id)?;
+        if !Arc::ptr_eq(&client.read().await.session, session) {
+            return None;
+        }
+        self.delete_client(session.client_id).await
+    }
+
     pub async fn join_consumer_group(
         &self,
         client_id: u32,
@@ -232,3 +348,47 @@ impl ClientManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unix_clients_should_get_unique_ids_after_wrapping_around() {
+        let mut client_manager = ClientManager {
+            next_client_id: u32::MAX - 1,
+            ..Default::default()
+        };
+        let first = client_manager.add_unix_client(Some(1000));
+        let second = client_manager.add_unix_client(Some(1000));
+        assert_eq!(first.client_id, u32::MAX);
+        assert_eq!(second.client_id, 1);
+
+        client_manager.next_client_id = u32::MAX - 1;
+        let third = client_manager.add_unix_client(None);
+        assert_eq!(third.client_id, 2);
+        assert_eq!(client_manager.get_clients().len(), 3);
+        assert_eq!(third.peer_uid, None);
+        assert_eq!(first.peer_uid, Some(1000));
+    }
+
+    #[tokio::test]
+    async fn client_session_should_be_deleted_only_if_still_registered() {
+        let mut client_manager = ClientManager::default();
+        let address = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 8080);
+        let first = client_manager.add_unique_client(&address, Transport::Http);
+        let second = client_manager.add_unique_client(&address, Transport::Http);
+        assert_ne!(first.client_id, second.client_id);
+
+        let replaced = Arc::new(Session::from_client_id(first.client_id, address));
+        assert!(
+            client_manager
+                .delete_client_session(&replaced)
+                .await
+                .is_none()
+        );
+        assert!(client_manager.delete_client_session(&first).await.is_some());
+        assert!(client_manager.delete_client_session(&first).await.is_none());
+        assert!(client_manager.try_get_client(second.client_id).is_some());
+    }
+}
@@ -16,12 +16,34 @@
  * under the License.
  */
 
+use crate::binary::frame_compressor::FrameCompressionStats;
+use crate::configs::system::{MetricsConfig, MetricsGranularity};
+use crate::streaming::users::quota_limiter::{QuotaDirection, QuotaKey, QuotaUsage};
+use iggy_common::{ClientMetadata, FrameCompressionAlgorithm, IggyError, get_name_from_code};
 use prometheus_client::encoding::text::encode;
+use prometheus_client::encoding::{EncodeLabel, EncodeLabelSet, LabelSetEncoder};
 use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
 use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::metrics::histogram::{Histogram, exponential_buckets};
 use prometheus_client::registry::Registry;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
 use tracing::error;
 
+static STORAGE_METRICS: OnceLock<StorageMetrics> = OnceLock::new();
+
+/// The maximum number of the distinct series of the identified clients, as their labels are sent by the clients,
+/// the clients identified beyond it are reported with the `other` application name and SDK version.
+const MAX_IDENTIFIED_CLIENTS_SERIES: usize = 1000;
+/// The maximum length of the client label values sent by the clients.
+const MAX_CLIENT_LABEL_LENGTH: usize = 64;
+/// The SDK languages reported as they are, the other ones are reported as `other`.
+const SDK_LANGUAGES: [&str; 7] = ["rust", "java", "python", "go", "node", "csharp", "cpp"];
+const OTHER_LABEL: &str = "other";
+const UNKNOWN_LABEL: &str = "unknown";
+
 #[derive(Debug)]
 pub(crate) struct Metrics {
     registry: Registry,
@@ -33,10 +55,238 @@ pub(crate) struct Metrics {
     messages: Gauge,
     users: Gauge,
     clients: Gauge,
+    identified_clients: Family<ClientLabels, Gauge>,
+    identified_clients_counts: Mutex<HashMap<ClientLabels, i64>>,
+    throttled_requests: Family<QuotaLabels, Counter>,
+    throttle_time_microseconds: Family<QuotaLabels, Counter>,
+    quota_usage_percent: Family<QuotaLabels, Gauge>,
+    frame_compression_uncompressed_bytes: Family<FrameCompressionLabels, Counter>,
+    frame_compression_compressed_bytes: Family<FrameCompressionLabels, Counter>,
+    frame_compression_ratio_percent: Family<FrameCompressionLabels, Gauge>,
+    granularity: MetricsGranularity,
+    command_labels: bool,
+    messages_appended: Family<ResourceLabels, Counter>,
+    bytes_appended: Family<ResourceLabels, Counter>,
+    messages_polled: Family<ResourceLabels, Counter>,
+    bytes_polled: Family<ResourceLabels, Counter>,
+    current_offset: Family<ResourceLabels, Gauge>,
+    stored_segments: Family<ResourceLabels, Gauge>,
+    stored_bytes: Family<ResourceLabels, Gauge>,
+    request_duration_seconds: Family<RequestLabels, Histogram>,
+    request_errors: Family<ErrorLabels, Counter>,
+    slow_clients: Family<SlowClientLabels, Counter>,
+    storage: StorageMetrics,
+}
+
+/// The metrics recorded by the segments, which are shared by all the systems in the process,
+/// as the segments can't access the system they belong to.
+#[derive(Debug, Clone)]
+pub(crate) struct StorageMetrics {
+    granularity: MetricsGranularity,
+    persist_duration_seconds: Family<ResourceLabels, Histogram>,
+    fsync_duration_seconds: Family<ResourceLabels, Histogram>,
+}
+
+impl StorageMetrics {
+    fn new(granularity: MetricsGranularity) -> Self {
+        StorageMetrics {
+            granularity,
+            persist_duration_seconds: Family::new_with_constructor(duration_histogram),
+            fsync_duration_seconds: Family::new_with_constructor(duration_histogram),
+        }
+    }
+
+    /// Returns the storage metrics, unless there's no system initialized yet, e.g. in the unit tests.
+    pub fn get() -> Option<&'static StorageMetrics> {
+        STORAGE_METRICS.get()
+    }
+
+    pub fn persist_duration(&self, stream_id: u32, topic_id: u32, partition_id: u32) -> Histogram {
+        let labels = ResourceLabels::new(self.granularity, stream_id, topic_id, partition_id);
+        self.persist_duration_seconds.get_or_create(&labels).clone()
+    }
+
+    pub fn fsync_duration(&self, stream_id: u32, topic_id: u32, partition_id: u32) -> Histogram {
+        let labels = ResourceLabels::new(self.granularity, stream_id, topic_id, partition_id);
+        self.fsync_duration_seconds.get_or_create(&labels).clone()
+    }
+}
+
+/// The state of a single partition, exposed as the storage gauges when the metrics are scraped.
+#[derive(Debug)]
+pub(crate) struct PartitionStorage {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub current_offset: u64,
+    pub segments_count: u32,
+    pub size_bytes: u64,
+}
+
+fn duration_histogram() -> Histogram {
+    // From 50 µs up to 6.5 s.
+    Histogram::new(exponential_buckets(0.00005, 2.0, 18))
+}
+
+/// The stream, topic and partition labels, limited to the configured granularity.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+struct ResourceLabels {
+    stream_id: Option<u32>,
+    topic_id: Option<u32>,
+    partition_id: Option<u32>,
+}
+
+impl ResourceLabels {
+    fn new(
+        granularity: MetricsGranularity,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Self {
+        ResourceLabels {
+            stream_id: (granularity >= MetricsGranularity::Stream).then_some(stream_id),
+            topic_id: (granularity >= MetricsGranularity::Topic).then_some(topic_id),
+            partition_id: (granularity >= MetricsGranularity::Partition).then_some(partition_id),
+        }
+    }
+}
+
+impl EncodeLabelSet for ResourceLabels {
+    fn encode(&self, mut encoder: LabelSetEncoder) -> Result<(), std::fmt::Error> {
+        if let Some(stream_id) = self.stream_id {
+            ("stream_id", stream_id).encode(encoder.encode_label())?;
+        }
+        if let Some(topic_id) = self.topic_id {
+            ("topic_id", topic_id).encode(encoder.encode_label())?;
+        }
+        if let Some(partition_id) = self.partition_id {
+            ("partition_id", partition_id).encode(encoder.encode_label())?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+struct RequestLabels {
+    transport: &'static str,
+    command: Option<&'static str>,
+}
+
+impl EncodeLabelSet for RequestLabels {
+    fn encode(&self, mut encoder: LabelSetEncoder) -> Result<(), std::fmt::Error> {
+        ("transport", self.transport).encode(encoder.encode_label())?;
+        if let Some(command) = self.command {
+            ("command", command).encode(encoder.encode_label())?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+struct ErrorLabels {
+    request: RequestLabels,
+    code: u32,
+    error: &'static str,
+}
+
+impl EncodeLabelSet for ErrorLabels {
+    fn encode(&self, mut encoder: LabelSetEncoder) -> Result<(), std::fmt::Error> {
+        ("transport", self.request.transport).encode(encoder.encode_label())?;
+        if let Some(command) = self.request.command {
+            ("command", command).encode(encoder.encode_label())?;
+        }
+        ("code", self.code).encode(encoder.encode_label())?;
+        ("error", self.error).encode(encoder.encode_label())?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct QuotaLabels {
+    user_id: u32,
+    personal_access_token: String,
+    direction: &'static str,
+}
+
+impl QuotaLabels {
+    fn new(key: &QuotaKey, direction: QuotaDirection) -> Self {
+        QuotaLabels {
+            user_id: key.user_id(),
+            personal_access_token: key.personal_access_token().unwrap_or_default().to_owned(),
+            direction: direction.as_str(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct FrameCompressionLabels {
+    algorithm: &'static str,
+    direction: &'static str,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct SlowClientLabels {
+    reason: &'static str,
+    action: &'static str,
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct ClientLabels {
+    application_name: String,
+    sdk_language: String,
+    sdk_version: String,
+}
+
+impl From<&ClientMetadata> for ClientLabels {
+    fn from(metadata: &ClientMetadata) -> Self {
+        let sdk_language = metadata.sdk_language.to_lowercase();
+        let sdk_language = match SDK_LANGUAGES
+            .iter()
+            .find(|language| **language == sdk_language)
+        {
+            Some(language) => language,
+            None if sdk_language.is_empty() => UNKNOWN_LABEL,
+            None => OTHER_LABEL,
+        };
+        ClientLabels {
+            application_name: sanitize_client_label(&metadata.application_name),
+            sdk_language: sdk_language.to_string(),
+            sdk_version: sanitize_client_label(&metadata.sdk_version),
+        }
+    }
+}
+
+impl ClientLabels {
+    /// Returns the labels reported for the clients identified beyond the maximum number of the series.
+    fn other(&self) -> Self {
+        ClientLabels {
+            application_name: OTHER_LABEL.to_string(),
+            sdk_language: self.sdk_language.clone(),
+            sdk_version: OTHER_LABEL.to_string(),
+        }
+    }
+}
+
+/// Keeps only the alphanumeric characters, dots, dashes and underscores of the value sent by the client,
+/// up to the maximum length.
+fn sanitize_client_label(value: &str) -> String {
+    let value = value
+        .chars()
+        .filter(|char| char.is_ascii_alphanumeric() || matches!(char, '.' | '-' | '_'))
+        .take(MAX_CLIENT_LABEL_LENGTH)
+        .collect::<String>();
+    if value.is_empty() {
+        return UNKNOWN_LABEL.to_string();
+    }
+
+    value
 }
 
 impl Metrics {
-    pub fn init() -> Self {
+    pub fn init(config: &MetricsConfig) -> Self {
+        let storage = STORAGE_METRICS
+            .get_or_init(|| StorageMetrics::new(config.granularity))
+            .clone();
         let mut metrics = Metrics {
             registry: <Registry>::default(),
             http_requests: Counter::default(),
@@ -47,6 +297,27 @@ impl Metrics {
             messages: Gauge::default(),
             users: Gauge::default(),
             clients: Gauge::default(),
+            identified_clients: Family::default(),
+            identified_clients_counts: Mutex::new(HashMap::new()),
+            throttled_requests: Family::default(),
+            throttle_time_microseconds: Family::default(),
+            quota_usage_percent: Family::default(),
+            frame_compression_uncompressed_bytes: Family::default(),
+            frame_compression_compressed_bytes: Family::default(),
+            frame_compression_ratio_percent: Family::default(),
+            granularity: config.granularity,
+            command_labels: config.command_labels,
+            messages_appended: Family::default(),
+            bytes_appended: Family::default(),
+            messages_polled: Family::default(),
+            bytes_polled: Family::default(),
+            current_offset: Family::default(),
+            stored_segments: Family::default(),
+            stored_bytes: Family::default(),
+            request_duration_seconds: Family::new_with_constructor(duration_histogram),
+            request_errors: Family::default(),
+            slow_clients: Family::default(),
+            storage,
         };
 
         metrics.register_counter("http_requests", metrics.http_requests.clone());
@@ -57,6 +328,101 @@ impl Metrics {
         metrics.register_gauge("messages", metrics.messages.clone());
         metrics.register_gauge("users", metrics.users.clone());
         metrics.register_gauge("clients", metrics.clients.clone());
+        metrics.registry.register(
+            "identified_clients",
+            "total count of clients by application and SDK",
+            metrics.identified_clients.clone(),
+        );
+        metrics.registry.register(
+            "throttled_requests",
+            "total count of requests throttled due to the exceeded quota",
+            metrics.throttled_requests.clone(),
+        );
+        metrics.registry.register(
+            "throttle_time_microseconds",
+            "total time by which the responses were delayed due to the exceeded quota",
+            metrics.throttle_time_microseconds.clone(),
+        );
+        metrics.registry.register(
+            "quota_usage_percent",
+            "current usage of the throughput quota, above 100 when throttled",
+            metrics.quota_usage_percent.clone(),
+        );
+        metrics.registry.register(
+            "frame_compression_uncompressed_bytes",
+            "total size of the compressed frames before the transport-level compression",
+            metrics.frame_compression_uncompressed_bytes.clone(),
+        );
+        metrics.registry.register(
+            "frame_compression_compressed_bytes",
+            "total size of the compressed frames after the transport-level compression",
+            metrics.frame_compression_compressed_bytes.clone(),
+        );
+        metrics.registry.register(
+            "frame_compression_ratio_percent",
+            "overall size of the compressed frames relative to their uncompressed size",
+            metrics.frame_compression_ratio_percent.clone(),
+        );
+        metrics.registry.register(
+            "messages_appended",
+            "total count of messages appended to the partitions",
+            metrics.messages_appended.clone(),
+        );
+        metrics.registry.register(
+            "bytes_appended",
+            "total size of messages appended to the partitions",
+            metrics.bytes_appended.clone(),
+        );
+        metrics.registry.register(
+            "messages_polled",
+            "total count of messages polled from the partitions",
+            metrics.messages_polled.clone(),
+        );
+        metrics.registry.register(
+            "bytes_polled",
+            "total size of messages polled from the partitions",
+            metrics.bytes_polled.clone(),
+        );
+        metrics.registry.register(
+            "current_offset",
+            "current offset of the partition",
+            metrics.current_offset.clone(),
+        );
+        metrics.registry.register(
+            "stored_segments",
+            "current count of segments",
+            metrics.stored_segments.clone(),
+        );
+        metrics.registry.register(
+            "stored_bytes",
+            "current size of the messages on disk",
+            metrics.stored_bytes.clone(),
+        );
+        metrics.registry.register(
+            "persist_duration_seconds",
+            "time spent on persisting the messages to the segments",
+            metrics.storage.persist_duration_seconds.clone(),
+        );
+        metrics.registry.register(
+            "fsync_duration_seconds",
+            "time spent on the fsync of the segment files",
+            metrics.storage.fsync_duration_seconds.clone(),
+        );
+        metrics.registry.register(
+            "request_duration_seconds",
+            "time spent on handling the binary protocol requests, including sending the response",
+            metrics.request_duration_seconds.clone(),
+        );
+        metrics.registry.register(
+            "request_errors",
+            "total count of the binary protocol requests which failed with an error",
+            metrics.request_errors.clone(),
+        );
+        metrics.registry.register(
+            "slow_clients",
+            "total count of the clients detected as idle, stuck in a partial request or lagging, by the action taken",
+            metrics.slow_clients.clone(),
+        );
 
         metrics
     }
@@ -138,4 +504,377 @@ impl Metrics {
     pub fn decrement_clients(&self, count: u32) {
         self.clients.dec_by(count as i64);
     }
+
+    pub fn increment_identified_clients(&self, metadata: &ClientMetadata) {
+        let mut counts = self.identified_clients_counts.lock().unwrap();
+        let mut labels = ClientLabels::from(metadata);
+        if !counts.contains_key(&labels) && counts.len() >= MAX_IDENTIFIED_CLIENTS_SERIES {
+            labels = labels.other();
+        }
+        let count = counts.entry(labels.clone()).or_default();
+        *count += 1;
+        self.identified_clients.get_or_create(&labels).set(*count);
+    }
+
+    pub fn decrement_identified_clients(&self, metadata: &ClientMetadata) {
+        let mut counts = self.identified_clients_counts.lock().unwrap();
+        let mut labels = ClientLabels::from(metadata);
+        if !counts.contains_key(&labels) {
+            labels = labels.other();
+        }
+        let Some(count) = counts.get_mut(&labels) else {
+            return;
+        };
+        *count -= 1;
+        if *count > 0 {
+            self.identified_clients.get_or_create(&labels).set(*count);
+            return;
+        }
+
+        // The series of the clients which are gone are removed, rather than reported as 0 forever.
+        counts.remove(&labels);
+        self.identified_clients.remove(&labels);
+    }
+
+    pub fn increment_slow_clients(&self, reason: &'static str, action: &'static str) {
+        self.slow_clients
+            .get_or_create(&SlowClientLabels { reason, action })
+            .inc();
+    }
+
+    pub fn record_quota_usage(&self, direction: QuotaDirection, usage: &QuotaUsage) {
+        let labels = QuotaLabels::new(&usage.key, direction);
+        self.quota_usage_percent
+            .get_or_create(&labels)
+            .set(usage.usage_percent as i64);
+        if let Some(throttle_time) = usage.throttle_time {
+            self.throttled_requests.get_or_create(&labels).inc();
+            self.throttle_time_microseconds
+                .get_or_create(&labels)
+                .inc_by(throttle_time.as_micros() as u64);
+        }
+    }
+
+    pub fn record_frame_compression(
+        &self,
+        algorithm: FrameCompressionAlgorithm,
+        stats: &FrameCompressionStats,
+    ) {
+        self.record_frame_compression_direction(
+            algorithm,
+            "request",
+            stats.request_uncompressed_bytes,
+            stats.request_compressed_bytes,
+        );
+        self.record_frame_compression_direction(
+            algorithm,
+            "response",
+            stats.response_uncompressed_bytes,
+            stats.response_compressed_bytes,
+        );
+    }
+
+    fn record_frame_compression_direction(
+        &self,
+        algorithm: FrameCompressionAlgorithm,
+        direction: &'static str,
+        uncompressed_bytes: u64,
+        compressed_bytes: u64,
+    ) {
+        if uncompressed_bytes == 0 {
+            return;
+        }
+
+        let labels = FrameCompressionLabels {
+            algorithm: algorithm.as_str(),
+            direction,
+        };
+        let uncompressed_total = self
+            .frame_compression_uncompressed_bytes
+            .get_or_create(&labels)
+            .inc_by(uncompressed_bytes)
+            + uncompressed_bytes;
+        let compressed_total = self
+            .frame_compression_compressed_bytes
+            .get_or_create(&labels)
+            .inc_by(compressed_bytes)
+            + compressed_bytes;
+        self.frame_compression_ratio_percent
+            .get_or_create(&labels)
+            .set((compressed_total * 100 / uncompressed_total) as i64);
+    }
+
+    pub fn record_appended_messages(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        messages_count: u32,
+        size_bytes: u64,
+    ) {
+        let labels = ResourceLabels::new(self.granularity, stream_id, topic_id, partition_id);
+        self.messages_appended
+            .get_or_create(&labels)
+            .inc_by(messages_count as u64);
+        self.bytes_appended
+            .get_or_create(&labels)
+            .inc_by(size_bytes);
+    }
+
+    pub fn record_polled_messages(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        messages_count: u32,
+        size_bytes: u64,
+    ) {
+        let labels = ResourceLabels::new(self.granularity, stream_id, topic_id, partition_id);
+        self.messages_polled
+            .get_or_create(&labels)
+            .inc_by(messages_count as u64);
+        self.bytes_polled.get_or_create(&labels).inc_by(size_bytes);
+    }
+
+    /// Replaces the storage gauges with the current state of the partitions.
+    /// The current offset is exposed only for the partition granularity.
+    pub fn record_storage(&self, partitions: &[PartitionStorage]) {
+        let mut storage = HashMap::new();
+        for partition in partitions {
+            let labels = ResourceLabels::new(
+                self.granularity,
+                partition.stream_id,
+                partition.topic_id,
+                partition.partition_id,
+            );
+            let (segments_count, size_bytes, current_offset) =
+                storage.entry(labels).or_insert((0, 0, 0));
+            *segments_count += partition.segments_count as i64;
+            *size_bytes += partition.size_bytes as i64;
+            *current_offset = partition.current_offset as i64;
+        }
+
+        self.stored_segments.clear();
+        self.stored_bytes.clear();
+        self.current_offset.clear();
+        for (labels, (segments_count, size_bytes, current_offset)) in storage {
+            self.stored_segments
+                .get_or_create(&labels)
+                .set(segments_count);
+            self.stored_bytes.get_or_create(&labels).set(size_bytes);
+            if self.granularity == MetricsGranularity::Partition {
+                self.current_offset
+                    .get_or_create(&labels)
+                    .set(current_offset);
+            }
+        }
+    }
+
+    /// Removes the series of the deleted stream, topic or partition (the scope),
+    /// unless they're aggregated on a coarser level, which still exists.
+    pub fn remove_resource(
+        &self,
+        scope: MetricsGranularity,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) {
+        if self.granularity < scope {
+            return;
+        }
+
+        let labels = ResourceLabels::new(self.granularity, stream_id, topic_id, partition_id);
+        self.messages_appended.remove(&labels);
+        self.bytes_appended.remove(&labels);
+        self.messages_polled.remove(&labels);
+        self.bytes_polled.remove(&labels);
+        self.storage.persist_duration_seconds.remove(&labels);
+        self.storage.fsync_duration_seconds.remove(&labels);
+    }
+
+    pub fn record_request(
+        &self,
+        transport: &'static str,
+        code: u32,
+        duration: Duration,
+        error: Option<&IggyError>,
+    ) {
+        let labels = RequestLabels {
+            transport,
+            command: self
+                .command_labels
+                .then(|| get_name_from_code(code).unwrap_or("unknown")),
+        };
+        self.request_duration_seconds
+            .get_or_create(&labels)
+            .observe(duration.as_secs_f64());
+        if let Some(error) = error {
+            self.request_errors
+                .get_or_create(&ErrorLabels {
+                    request: labels,
+                    code: error.as_code(),
+                    error: error.as_string(),
+                })
+                .inc();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resource_metrics_should_be_labelled_with_the_configured_granularity() {
+        let metrics = Metrics::init(&MetricsConfig {
+            granularity: MetricsGranularity::Topic,
+            command_labels: true,
+        });
+
+        metrics.record_appended_messages(1, 2, 1, 3, 300);
+        metrics.record_appended_messages(1, 2, 2, 2, 200);
+        metrics.record_storage(&[PartitionStorage {
+            stream_id: 1,
+            topic_id: 2,
+            partition_id: 1,
+            current_offset: 2,
+            segments_count: 1,
+            size_bytes: 300,
+        }]);
+
+        let output = metrics.get_formatted_output();
+        assert!(output.contains("messages_appended_total{stream_id=\"1\",topic_id=\"2\"} 5"));
+        assert!(output.contains("bytes_appended_total{stream_id=\"1\",topic_id=\"2\"} 500"));
+        assert!(output.contains("stored_bytes{stream_id=\"1\",topic_id=\"2\"} 300"));
+        assert!(!output.contains("current_offset{"));
+
+        metrics.remove_resource(MetricsGranularity::Partition, 1, 2, 2);
+        assert!(
+            metrics
+                .get_formatted_output()
+                .contains("messages_appended_total{stream_id=\"1\",topic_id=\"2\"} 5")
+        );
+        metrics.remove_resource(MetricsGranularity::Topic, 1, 2, 1);
+        assert!(
+            !metrics
+                .get_formatted_output()
+                .contains("messages_appended_total{")
+        );
+    }
+
+    #[test]
+    fn request_metrics_should_be_labelled_with_the_transport_and_optionally_the_command() {
+        let metrics = Metrics::init(&MetricsConfig {
+            granularity: MetricsGranularity::None,
+            command_labels: false,
+        });
+
+        metrics.record_request(
+            "tcp",
+            iggy_common::SEND_MESSAGES_CODE,
+            Duration::from_millis(1),
+            None,
+        );
+        metrics.record_request(
+            "tcp",
+            iggy_common::SEND_MESSAGES_CODE,
+            Duration::from_millis(2),
+            Some(&IggyError::Unauthenticated),
+        );
+
+        let output = metrics.get_formatted_output();
+        assert!(output.contains("request_duration_seconds_count{transport=\"tcp\"} 2"));
+        assert!(output.contains(&format!(
+            "request_errors_total{{transport=\"tcp\",code=\"{}\",error=\"unauthenticated\"}} 1",
+            IggyError::Unauthenticated.as_code()
+        )));
+    }
+
+    #[test]
+    fn slow_clients_should_be_counted_by_reason_and_action() {
+        let metrics = Metrics::init(&MetricsConfig {
+            granularity: MetricsGranularity::None,
+            command_labels: false,
+        });
+
+        metrics.increment_slow_clients("idle", "none");
+        metrics.increment_slow_clients("idle", "none");
+        metrics.increment_slow_clients("lagging_consumer", "leave_consumer_group");
+
+        let output = metrics.get_formatted_output();
+        assert!(output.contains("slow_clients_total{reason=\"idle\",action=\"none\"} 2"));
+        assert!(output.contains(
+            "slow_clients_total{reason=\"lagging_consumer\",action=\"leave_consumer_group\"} 1"
+        ));
+    }
+
+    #[test]
+    fn identified_clients_should_be_labelled_with_the_sanitized_values_and_removed_when_gone() {
+        let metrics = Metrics::init(&MetricsConfig {
+            granularity: MetricsGranularity::None,
+            command_labels: false,
+        });
+        let metadata = ClientMetadata {
+            application_name: "orders\"} 1\nservice".to_string(),
+            sdk_language: "Rust".to_string(),
+            sdk_version: "0.7.0".to_string(),
+            ..Default::default()
+        };
+        let unknown_language = ClientMetadata {
+            sdk_language: "cobol".to_string(),
+            ..metadata.clone()
+        };
+
+        metrics.increment_identified_clients(&metadata);
+        metrics.increment_identified_clients(&metadata);
+        metrics.increment_identified_clients(&unknown_language);
+        metrics.decrement_identified_clients(&metadata);
+        let output = metrics.get_formatted_output();
+        assert!(output.contains(
+            "identified_clients{application_name=\"orders1service\",sdk_language=\"rust\",sdk_version=\"0.7.0\"} 1"
+        ));
+        assert!(output.contains(
+            "identified_clients{application_name=\"orders1service\",sdk_language=\"other\",sdk_version=\"0.7.0\"} 1"
+        ));
+
+        metrics.decrement_identified_clients(&metadata);
+        metrics.decrement_identified_clients(&unknown_language);
+        assert!(
+            !metrics
+                .get_formatted_output()
+                .contains("identified_clients{")
+        );
+    }
+
+    #[test]
+    fn identified_clients_beyond_the_maximum_number_of_series_should_be_reported_as_other() {
+        let metrics = Metrics::init(&MetricsConfig {
+            granularity: MetricsGranularity::None,
+            command_labels: false,
+        });
+        let metadata = |id: usize| ClientMetadata {
+            application_name: format!("app-{id}"),
+            sdk_language: "go".to_string(),
+            sdk_version: "1.0.0".to_string(),
+            ..Default::default()
+        };
+
+        for id in 0..=MAX_IDENTIFIED_CLIENTS_SERIES {
+            metrics.increment_identified_clients(&metadata(id));
+        }
+        let output = metrics.get_formatted_output();
+        assert!(output.contains(
+            "identified_clients{application_name=\"other\",sdk_language=\"go\",sdk_version=\"other\"} 1"
+        ));
+        assert!(!output.contains(&format!(
+            "application_name=\"app-{MAX_IDENTIFIED_CLIENTS_SERIES}\""
+        )));
+
+        metrics.decrement_identified_clients(&metadata(MAX_IDENTIFIED_CLIENTS_SERIES));
+        assert!(
+            !metrics
+                .get_formatted_output()
+                .contains("application_name=\"other\"")
+        );
+    }
 }
@@ -17,3 +17,8 @@
  */
 
 pub mod metrics;
+pub mod otlp;
+pub mod profiler;
+pub mod startup;
+pub mod stats_history;
+pub mod trace_links;
@@ -0,0 +1,269 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use opentelemetry::KeyValue;
+use opentelemetry::global;
+use opentelemetry::metrics::{AsyncInstrument, Meter};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+const METER_NAME: &str = "iggy";
+
+type Samples = Arc<RwLock<HashMap<String, Vec<(f64, Vec<KeyValue>)>>>>;
+type Labels = Vec<(String, String)>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum InstrumentKind {
+    Counter,
+    Gauge,
+}
+
+#[derive(Debug, PartialEq)]
+struct Sample {
+    instrument: String,
+    kind: InstrumentKind,
+    description: String,
+    labels: Labels,
+    value: f64,
+}
+
+/// Mirrors the series exposed by the Prometheus endpoint as OpenTelemetry observable instruments,
+/// which are then exported over OTLP by the globally registered meter provider.
+///
+/// Counters keep their family name (without the `_total` suffix), gauges are exported as is
+/// and histograms are exported as `_bucket` (labelled with `le`), `_sum` and `_count` counters.
+pub struct OtlpMetrics {
+    meter: Meter,
+    instruments: HashSet<String>,
+    samples: Samples,
+}
+
+impl Default for OtlpMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OtlpMetrics {
+    pub fn new() -> Self {
+        Self {
+            meter: global::meter(METER_NAME),
+            instruments: HashSet::new(),
+            samples: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Replaces the observed values with the ones from the Prometheus text output,
+    /// registering the instruments for the series that were not seen before.
+    pub fn update(&mut self, output: &str) {
+        let mut samples: HashMap<String, Vec<(f64, Vec<KeyValue>)>> = HashMap::new();
+        for sample in parse_samples(output) {
+            if !self.instruments.contains(&sample.instrument) {
+                self.register(&sample);
+            }
+
+            let attributes = sample
+                .labels
+                .into_iter()
+                .map(|(key, value)| KeyValue::new(key, value))
+                .collect();
+            samples
+                .entry(sample.instrument)
+                .or_default()
+                .push((sample.value, attributes));
+        }
+
+        match self.samples.write() {
+            Ok(mut current) => *current = samples,
+            Err(poisoned) => *poisoned.into_inner() = samples,
+        }
+    }
+
+    fn register(&mut self, sample: &Sample) {
+        let samples = self.samples.clone();
+        let instrument = sample.instrument.clone();
+        let callback = move |observer: &dyn AsyncInstrument<f64>| {
+            let Ok(samples) = samples.read() else {
+                return;
+            };
+            if let Some(values) = samples.get(&instrument) {
+                for (value, attributes) in values {
+                    observer.observe(*value, attributes);
+                }
+            }
+        };
+
+        match sample.kind {
+            InstrumentKind::Counter => {
+                self.meter
+                    .f64_observable_counter(sample.instrument.clone())
+                    .with_description(sample.description.clone())
+                    .with_callback(callback)
+                    .build();
+            }
+            InstrumentKind::Gauge => {
+                self.meter
+                    .f64_observable_gauge(sample.instrument.clone())
+                    .with_description(sample.description.clone())
+                    .with_callback(callback)
+                    .build();
+            }
+        }
+        self.instruments.insert(sample.instrument.clone());
+    }
+}
+
+fn parse_samples(output: &str) -> Vec<Sample> {
+    let mut samples = Vec::new();
+    let mut descriptions = HashMap::new();
+    let mut family: Option<(&str, &str)> = None;
+    for line in output.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(comment) = line.strip_prefix('#') {
+            let mut parts = comment.trim_start().splitn(3, ' ');
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some("HELP"), Some(name), Some(help)) => {
+                    descriptions.insert(name, help);
+                }
+                (Some("TYPE"), Some(name), Some(kind)) => family = Some((name, kind)),
+                _ => {}
+            }
+            continue;
+        }
+
+        let Some((family_name, family_kind)) = family else {
+            continue;
+        };
+        let Some((name, labels, value)) = parse_sample_line(line) else {
+            continue;
+        };
+
+        let (instrument, kind) = match family_kind {
+            "counter" if name == format!("{family_name}_total") => {
+                (family_name.to_owned(), InstrumentKind::Counter)
+            }
+            "gauge" if name == family_name => (family_name.to_owned(), InstrumentKind::Gauge),
+            "histogram"
+                if name == format!("{family_name}_bucket")
+                    || name == format!("{family_name}_sum")
+                    || name == format!("{family_name}_count") =>
+            {
+                (name.to_owned(), InstrumentKind::Counter)
+            }
+            _ => continue,
+        };
+
+        samples.push(Sample {
+            instrument,
+            kind,
+            description: descriptions
+                .get(family_name)
+                .map(|help| help.to_string())
+                .unwrap_or_default(),
+            labels,
+            value,
+        });
+    }
+    samples
+}
+
+fn parse_sample_line(line: &str) -> Option<(&str, Labels, f64)> {
+    let name_end = line.find(['{', ' '])?;
+    let name = &line[..name_end];
+    let mut rest = &line[name_end..];
+    let mut labels = Vec::new();
+    if let Some(stripped) = rest.strip_prefix('{') {
+        rest = stripped;
+        loop {
+            rest = rest.trim_start_matches(',');
+            if let Some(stripped) = rest.strip_prefix('}') {
+                rest = stripped;
+                break;
+            }
+
+            let (key, remaining) = rest.split_once("=\"")?;
+            let mut value = String::new();
+            let mut chars = remaining.char_indices();
+            let end = loop {
+                match chars.next()? {
+                    (_, '\\') => match chars.next()?.1 {
+                        'n' => value.push('\n'),
+                        escaped => value.push(escaped),
+                    },
+                    (index, '"') => break index,
+                    (_, character) => value.push(character),
+                }
+            };
+            labels.push((key.to_owned(), value));
+            rest = &remaining[end + 1..];
+        }
+    }
+
+    let value = rest.split_whitespace().next()?.parse().ok()?;
+    Some((name, labels, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prometheus_output_should_be_mapped_to_instruments() {
+        let output = r#"# HELP messages_appended total count of messages_appended.
+# TYPE messages_appended counter
+messages_appended_total{stream_id="1",topic_id="2"} 5
+# HELP stored_bytes total count of stored_bytes.
+# TYPE stored_bytes gauge
+stored_bytes{stream_id="1",topic_id="a \"quoted\" name"} 300
+# HELP request_duration_seconds request duration.
+# TYPE request_duration_seconds histogram
+request_duration_seconds_sum{transport="tcp"} 0.5
+request_duration_seconds_count{transport="tcp"} 2
+request_duration_seconds_bucket{le="+Inf",transport="tcp"} 2
+# EOF
+"#;
+
+        let samples = parse_samples(output);
+        assert_eq!(samples.len(), 5);
+
+        assert_eq!(samples[0].instrument, "messages_appended");
+        assert_eq!(samples[0].kind, InstrumentKind::Counter);
+        assert_eq!(samples[0].value, 5.0);
+        assert_eq!(samples[0].description, "total count of messages_appended.");
+
+        assert_eq!(samples[1].instrument, "stored_bytes");
+        assert_eq!(samples[1].kind, InstrumentKind::Gauge);
+        assert_eq!(
+            samples[1].labels,
+            vec![
+                ("stream_id".to_owned(), "1".to_owned()),
+                ("topic_id".to_owned(), "a \"quoted\" name".to_owned()),
+            ]
+        );
+
+        assert_eq!(samples[2].instrument, "request_duration_seconds_sum");
+        assert_eq!(samples[3].instrument, "request_duration_seconds_count");
+        assert_eq!(samples[4].instrument, "request_duration_seconds_bucket");
+        assert_eq!(samples[4].kind, InstrumentKind::Counter);
+        assert_eq!(samples[4].labels[0], ("le".to_owned(), "+Inf".to_owned()));
+    }
+}
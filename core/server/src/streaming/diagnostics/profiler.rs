@@ -0,0 +1,189 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy_common::{IggyDuration, IggyError, Profile, ProfileFormat, ProfileKind};
+use pprof::protos::Message;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::{error, info};
+
+const SAMPLING_FREQUENCY: i32 = 99;
+// The frames of the signal handler and the libraries which can't be unwound safely.
+const BLOCKLIST: [&str; 4] = ["libc", "libgcc", "pthread", "vdso"];
+
+// The CPU profiler is installed process-wide (the SIGPROF handler), so only one profile
+// can be captured at a time, no matter whether it's requested via the API or the snapshot.
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+struct RunningGuard;
+
+impl RunningGuard {
+    fn acquire() -> Result<Self, IggyError> {
+        RUNNING
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .map_err(|_| IggyError::ProfilerAlreadyRunning)?;
+        Ok(RunningGuard)
+    }
+}
+
+impl Drop for RunningGuard {
+    fn drop(&mut self) {
+        RUNNING.store(false, Ordering::Release);
+    }
+}
+
+/// Captures the profile of the running server, the CPU one is sampled for the provided duration.
+pub async fn capture(
+    kind: ProfileKind,
+    format: ProfileFormat,
+    duration: IggyDuration,
+) -> Result<Profile, IggyError> {
+    let _running = RunningGuard::acquire()?;
+    info!("Capturing {kind} profile in {format} format...");
+    let profile = match kind {
+        ProfileKind::Cpu => capture_cpu_profile(format, duration).await?,
+        ProfileKind::Heap => capture_heap_profile().await?,
+    };
+    info!(
+        "Captured {kind} profile in {format} format, size: {} bytes.",
+        profile.0.len()
+    );
+    Ok(profile)
+}
+
+async fn capture_cpu_profile(
+    format: ProfileFormat,
+    duration: IggyDuration,
+) -> Result<Profile, IggyError> {
+    let guard = pprof::ProfilerGuardBuilder::default()
+        .frequency(SAMPLING_FREQUENCY)
+        .blocklist(&BLOCKLIST)
+        .build()
+        .map_err(|error| {
+            error!("Failed to start CPU profiler. {error}");
+            IggyError::CannotCaptureProfile(error.to_string())
+        })?;
+    tokio::time::sleep(duration.get_duration()).await;
+
+    // Resolving the symbols might take a while, so it's not done on the runtime thread.
+    tokio::task::spawn_blocking(move || {
+        let report = guard.report().build().map_err(|error| error.to_string())?;
+        drop(guard);
+        let data = match format {
+            ProfileFormat::Pprof => report
+                .pprof()
+                .map_err(|error| error.to_string())?
+                .encode_to_vec(),
+            // Only the CPU time is sampled, so there's nothing to draw if the server was idle.
+            ProfileFormat::Flamegraph if report.data.is_empty() => {
+                return Err("no samples have been collected, the server was idle".to_owned());
+            }
+            ProfileFormat::Flamegraph => {
+                let mut data = Vec::new();
+                report
+                    .flamegraph(&mut data)
+                    .map_err(|error| error.to_string())?;
+                data
+            }
+        };
+        Ok(Profile::new(data))
+    })
+    .await
+    .map_err(|error| IggyError::CannotCaptureProfile(error.to_string()))?
+    .map_err(|error| {
+        error!("Failed to build CPU profile report. {error}");
+        IggyError::CannotCaptureProfile(error)
+    })
+}
+
+#[cfg(feature = "jemalloc")]
+async fn capture_heap_profile() -> Result<Profile, IggyError> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    tokio::task::spawn_blocking(|| {
+        // SAFETY: `opt.prof` is a read-only boolean option.
+        let enabled: bool = unsafe { tikv_jemalloc_ctl::raw::read(b"opt.prof\0") }
+            .map_err(|error| error.to_string())?;
+        if !enabled {
+            return Err("jemalloc profiling is disabled, it's enabled by `prof:true` in `_RJEM_MALLOC_CONF`".to_owned());
+        }
+
+        let file = tempfile::NamedTempFile::new().map_err(|error| error.to_string())?;
+        let path =
+            CString::new(file.path().as_os_str().as_bytes()).map_err(|error| error.to_string())?;
+        // SAFETY: `prof.dump` expects the pointer to the nul-terminated path, valid for the duration of the call.
+        unsafe { tikv_jemalloc_ctl::raw::write(b"prof.dump\0", path.as_ptr()) }
+            .map_err(|error| error.to_string())?;
+        std::fs::read(file.path())
+            .map(Profile::new)
+            .map_err(|error| error.to_string())
+    })
+    .await
+    .map_err(|error| IggyError::CannotCaptureProfile(error.to_string()))?
+    .map_err(|error| {
+        error!("Failed to dump heap profile. {error}");
+        IggyError::CannotCaptureProfile(error)
+    })
+}
+
+#[cfg(not(feature = "jemalloc"))]
+async fn capture_heap_profile() -> Result<Profile, IggyError> {
+    error!("Heap profile is available only when the server is built with the `jemalloc` feature.");
+    Err(IggyError::FeatureUnavailable)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn should_not_capture_profiles_concurrently() {
+        let running = RunningGuard::acquire().unwrap();
+        let result = capture(
+            ProfileKind::Cpu,
+            ProfileFormat::Pprof,
+            IggyDuration::new_from_secs(1),
+        )
+        .await;
+        assert!(matches!(result, Err(IggyError::ProfilerAlreadyRunning)));
+
+        drop(running);
+        // The CPU time is sampled, so the profiled process has to be busy.
+        let busy = Arc::new(AtomicBool::new(true));
+        let spinner = std::thread::spawn({
+            let busy = busy.clone();
+            move || {
+                let mut counter = 0u64;
+                while busy.load(Ordering::Relaxed) {
+                    counter = std::hint::black_box(counter.wrapping_add(1));
+                }
+            }
+        });
+        let profile = capture(
+            ProfileKind::Cpu,
+            ProfileFormat::Flamegraph,
+            IggyDuration::new_from_secs(1),
+        )
+        .await
+        .unwrap();
+        busy.store(false, Ordering::Relaxed);
+        spinner.join().unwrap();
+        assert!(String::from_utf8_lossy(&profile.0).contains("<svg"));
+    }
+}
@@ -0,0 +1,102 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use iggy_common::HealthCheck;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+// The startup progress is tracked outside of the system, as the system lock is held
+// for the whole loading, while the readiness has to be reported meanwhile.
+static STATE_LOADED: AtomicBool = AtomicBool::new(false);
+static STREAMS_LOADED: AtomicBool = AtomicBool::new(false);
+static PARTITIONS_TOTAL: AtomicU32 = AtomicU32::new(0);
+static PARTITIONS_LOADED: AtomicU32 = AtomicU32::new(0);
+static INDEXES_REBUILDING: AtomicU32 = AtomicU32::new(0);
+static INDEXES_REBUILT: AtomicU32 = AtomicU32::new(0);
+static STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Marks the state as loaded, with the number of partitions which are going to be loaded.
+pub fn state_loaded(partitions_count: u32) {
+    PARTITIONS_TOTAL.store(partitions_count, Ordering::Relaxed);
+    STATE_LOADED.store(true, Ordering::Release);
+}
+
+/// Marks the single partition as loaded, no matter whether successfully or not.
+pub fn partition_loaded() {
+    PARTITIONS_LOADED.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Marks all the streams, along with their topics and partitions, as loaded.
+pub fn streams_loaded() {
+    STREAMS_LOADED.store(true, Ordering::Release);
+}
+
+pub fn index_rebuild_started() {
+    INDEXES_REBUILDING.fetch_add(1, Ordering::Relaxed);
+}
+
+pub fn index_rebuild_finished() {
+    INDEXES_REBUILDING.fetch_sub(1, Ordering::Relaxed);
+    INDEXES_REBUILT.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Marks the server as started, once all the enabled transports accept the connections.
+pub fn started() {
+    STARTED.store(true, Ordering::Release);
+}
+
+pub fn is_started() -> bool {
+    STARTED.load(Ordering::Acquire)
+}
+
+pub fn check_startup() -> HealthCheck {
+    match is_started() {
+        true => HealthCheck::up("startup", "server has started".to_owned()),
+        false => HealthCheck::down("startup", "server is starting".to_owned()),
+    }
+}
+
+pub fn check_state() -> HealthCheck {
+    match STATE_LOADED.load(Ordering::Acquire) {
+        true => HealthCheck::up("state", "state has been loaded".to_owned()),
+        false => HealthCheck::down("state", "state is loading".to_owned()),
+    }
+}
+
+pub fn check_partitions() -> HealthCheck {
+    let details = format!(
+        "loaded {}/{} partitions",
+        PARTITIONS_LOADED.load(Ordering::Relaxed),
+        PARTITIONS_TOTAL.load(Ordering::Relaxed)
+    );
+    match STREAMS_LOADED.load(Ordering::Acquire) {
+        true => HealthCheck::up("partitions", details),
+        false => HealthCheck::down("partitions", details),
+    }
+}
+
+pub fn check_index_rebuilding() -> HealthCheck {
+    let rebuilding = INDEXES_REBUILDING.load(Ordering::Relaxed);
+    let details = format!(
+        "{rebuilding} indexes rebuilding, {} rebuilt",
+        INDEXES_REBUILT.load(Ordering::Relaxed)
+    );
+    match rebuilding {
+        0 => HealthCheck::up("index_rebuilding", details),
+        _ => HealthCheck::down("index_rebuilding", details),
+    }
+}
@@ -0,0 +1,309 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::{StatsHistoryConfig, StatsHistorySeriesConfig};
+use ahash::AHashMap;
+use iggy_common::{
+    IggyDuration, IggyTimestamp, Stats, StatsHistory, StatsSample, StatsSeries, TopicThroughput,
+};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The stats captured at the given time, along with the counters of the topics,
+/// used to compute the throughput since the previous sample of each series.
+#[derive(Debug)]
+pub struct StatsSnapshot {
+    pub timestamp: IggyTimestamp,
+    pub stats: Stats,
+    pub topics: Vec<TopicCounters>,
+}
+
+/// The total number of messages ever appended to the topic (based on the offsets),
+/// and the current size of the topic.
+#[derive(Debug, Clone, Copy)]
+pub struct TopicCounters {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub messages_count: u64,
+    pub size_bytes: u64,
+}
+
+/// Keeps the bounded series of the stats samples in memory, the short-term series is sampled
+/// on each snapshot, while the long-term one only on every n-th snapshot.
+#[derive(Debug)]
+pub struct StatsHistoryRecorder {
+    snapshots_count: AtomicU64,
+    series: Vec<Mutex<SeriesRecorder>>,
+}
+
+#[derive(Debug)]
+struct SeriesRecorder {
+    interval: IggyDuration,
+    retention: IggyDuration,
+    snapshots_per_sample: u64,
+    capacity: usize,
+    samples: VecDeque<StatsSample>,
+    previous: Option<Counters>,
+}
+
+#[derive(Debug)]
+struct Counters {
+    read_bytes: u64,
+    written_bytes: u64,
+    topics: AHashMap<(u32, u32), TopicCounters>,
+}
+
+impl StatsHistoryRecorder {
+    pub fn new(config: &StatsHistoryConfig) -> Self {
+        let base_interval = config.short_term.interval;
+        Self {
+            snapshots_count: AtomicU64::new(0),
+            series: [config.short_term, config.long_term]
+                .iter()
+                .map(|series| Mutex::new(SeriesRecorder::new(series, base_interval)))
+                .collect(),
+        }
+    }
+
+    /// Adds the sample to each series which is due, discarding the oldest samples beyond the retention.
+    pub fn record(&self, snapshot: &StatsSnapshot) {
+        let snapshot_number = self.snapshots_count.fetch_add(1, Ordering::Relaxed);
+        for series in &self.series {
+            let mut series = series.lock().unwrap();
+            if snapshot_number.is_multiple_of(series.snapshots_per_sample) {
+                series.record(snapshot);
+            }
+        }
+    }
+
+    pub fn get(&self) -> StatsHistory {
+        StatsHistory {
+            series: self
+                .series
+                .iter()
+                .map(|series| {
+                    let series = series.lock().unwrap();
+                    StatsSeries {
+                        interval: series.interval,
+                        retention: series.retention,
+                        samples: series.samples.iter().cloned().collect(),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl SeriesRecorder {
+    fn new(config: &StatsHistorySeriesConfig, base_interval: IggyDuration) -> Self {
+        let capacity = (config.retention.as_micros() / config.interval.as_micros()).max(1) as usize;
+        Self {
+            interval: config.interval,
+            retention: config.retention,
+            snapshots_per_sample: (config.interval.as_micros() / base_interval.as_micros()).max(1),
+            capacity,
+            samples: VecDeque::with_capacity(capacity),
+            previous: None,
+        }
+    }
+
+    fn record(&mut self, snapshot: &StatsSnapshot) {
+        let stats = &snapshot.stats;
+        let counters = Counters {
+            read_bytes: stats.read_bytes.as_bytes_u64(),
+            written_bytes: stats.written_bytes.as_bytes_u64(),
+            topics: snapshot
+                .topics
+                .iter()
+                .map(|topic| ((topic.stream_id, topic.topic_id), *topic))
+                .collect(),
+        };
+
+        // The first sample has no throughput, as there's nothing to compare it with.
+        let mut sample = StatsSample {
+            timestamp: snapshot.timestamp,
+            cpu_usage: stats.cpu_usage,
+            total_cpu_usage: stats.total_cpu_usage,
+            memory_usage: stats.memory_usage,
+            available_memory: stats.available_memory,
+            clients_count: stats.clients_count,
+            messages_count: stats.messages_count,
+            messages_size_bytes: stats.messages_size_bytes,
+            ..Default::default()
+        };
+        if let Some(previous) = &self.previous {
+            sample.read_bytes = counters
+                .read_bytes
+                .saturating_sub(previous.read_bytes)
+                .into();
+            sample.written_bytes = counters
+                .written_bytes
+                .saturating_sub(previous.written_bytes)
+                .into();
+            sample.topics = get_topics_throughput(&snapshot.topics, &previous.topics);
+        }
+
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+        self.previous = Some(counters);
+    }
+}
+
+// The topics created since the previous sample are compared with zero, while the size of the topic
+// can also decrease (e.g. once the expired segments are deleted), thus the throughput is never negative.
+fn get_topics_throughput(
+    topics: &[TopicCounters],
+    previous: &AHashMap<(u32, u32), TopicCounters>,
+) -> Vec<TopicThroughput> {
+    let mut throughput = topics
+        .iter()
+        .filter_map(|topic| {
+            let (previous_messages_count, previous_size_bytes) = previous
+                .get(&(topic.stream_id, topic.topic_id))
+                .map(|previous| (previous.messages_count, previous.size_bytes))
+                .unwrap_or_default();
+            let messages_count = topic.messages_count.saturating_sub(previous_messages_count);
+            if messages_count == 0 {
+                return None;
+            }
+
+            Some(TopicThroughput {
+                stream_id: topic.stream_id,
+                topic_id: topic.topic_id,
+                messages_count,
+                size_bytes: topic.size_bytes.saturating_sub(previous_size_bytes).into(),
+            })
+        })
+        .collect::<Vec<_>>();
+    throughput.sort_by_key(|topic| (topic.stream_id, topic.topic_id));
+    throughput
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> StatsHistoryConfig {
+        StatsHistoryConfig {
+            enabled: true,
+            short_term: StatsHistorySeriesConfig {
+                interval: "10s".parse().unwrap(),
+                retention: "30s".parse().unwrap(),
+            },
+            long_term: StatsHistorySeriesConfig {
+                interval: "20s".parse().unwrap(),
+                retention: "1m".parse().unwrap(),
+            },
+        }
+    }
+
+    fn snapshot(number: u64, topics: Vec<TopicCounters>) -> StatsSnapshot {
+        StatsSnapshot {
+            timestamp: (number * 10_000_000).into(),
+            stats: Stats {
+                written_bytes: (number * 100).into(),
+                clients_count: number as u32,
+                ..Default::default()
+            },
+            topics,
+        }
+    }
+
+    fn topic(topic_id: u32, messages_count: u64, size_bytes: u64) -> TopicCounters {
+        TopicCounters {
+            stream_id: 1,
+            topic_id,
+            messages_count,
+            size_bytes,
+        }
+    }
+
+    #[test]
+    fn should_keep_samples_within_retention_of_each_series() {
+        let recorder = StatsHistoryRecorder::new(&config());
+        for number in 0..5 {
+            recorder.record(&snapshot(number, vec![]));
+        }
+
+        let history = recorder.get();
+        assert_eq!(history.series.len(), 2);
+        let short_term = &history.series[0];
+        assert_eq!(short_term.interval, "10s".parse().unwrap());
+        let clients = short_term
+            .samples
+            .iter()
+            .map(|sample| sample.clients_count)
+            .collect::<Vec<_>>();
+        assert_eq!(clients, vec![2, 3, 4]);
+
+        let long_term = &history.series[1];
+        let clients = long_term
+            .samples
+            .iter()
+            .map(|sample| sample.clients_count)
+            .collect::<Vec<_>>();
+        assert_eq!(clients, vec![0, 2, 4]);
+        assert_eq!(long_term.samples[0].written_bytes.as_bytes_u64(), 0);
+        assert_eq!(long_term.samples[1].written_bytes.as_bytes_u64(), 200);
+    }
+
+    #[test]
+    fn should_compute_throughput_of_active_topics_since_previous_sample() {
+        let recorder = StatsHistoryRecorder::new(&config());
+        recorder.record(&snapshot(0, vec![topic(1, 10, 1000), topic(2, 5, 500)]));
+        recorder.record(&snapshot(
+            1,
+            vec![topic(1, 15, 1200), topic(2, 5, 500), topic(3, 2, 20)],
+        ));
+        recorder.record(&snapshot(2, vec![topic(1, 20, 100), topic(3, 2, 20)]));
+
+        let history = recorder.get();
+        let samples = &history.series[0].samples;
+        assert!(samples[0].topics.is_empty());
+        assert_eq!(
+            samples[1].topics,
+            vec![
+                TopicThroughput {
+                    stream_id: 1,
+                    topic_id: 1,
+                    messages_count: 5,
+                    size_bytes: 200.into(),
+                },
+                TopicThroughput {
+                    stream_id: 1,
+                    topic_id: 3,
+                    messages_count: 2,
+                    size_bytes: 20.into(),
+                },
+            ]
+        );
+        assert_eq!(samples[1].appended_messages(), 7);
+        assert_eq!(
+            samples[2].topics,
+            vec![TopicThroughput {
+                stream_id: 1,
+                topic_id: 1,
+                messages_count: 5,
+                size_bytes: 0.into(),
+            }]
+        );
+    }
+}
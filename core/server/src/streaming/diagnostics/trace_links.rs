@@ -0,0 +1,80 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::segments::IggyMessagesBatchMut;
+use iggy_common::TraceContext;
+use opentelemetry::trace::{SpanContext, SpanId, TraceFlags, TraceId, TraceState};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Same as the default span links limit of the OpenTelemetry SDK.
+const MAX_LINKS: usize = 128;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enables linking the server spans to the trace contexts propagated in the messages.
+/// It's invoked once the OpenTelemetry tracer is installed, so that the user headers
+/// are not parsed on the hot path when telemetry is disabled.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Links the current span to the distinct W3C trace contexts found in the `traceparent`
+/// user headers of the messages, so that the send/poll spans join the producer traces.
+pub fn link_messages<'a>(batches: impl IntoIterator<Item = &'a IggyMessagesBatchMut>) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let span = tracing::Span::current();
+    if span.is_disabled() {
+        return;
+    }
+
+    let mut linked = HashSet::new();
+    for batch in batches {
+        for message in batch.iter() {
+            if linked.len() == MAX_LINKS {
+                return;
+            }
+
+            if message.header().user_headers_length() == 0 {
+                continue;
+            }
+
+            let Some(trace_context) = message.trace_context() else {
+                continue;
+            };
+
+            if linked.insert(trace_context) {
+                span.add_link(to_span_context(&trace_context));
+            }
+        }
+    }
+}
+
+fn to_span_context(trace_context: &TraceContext) -> SpanContext {
+    SpanContext::new(
+        TraceId::from_bytes(trace_context.trace_id),
+        SpanId::from_bytes(trace_context.span_id),
+        TraceFlags::new(trace_context.flags),
+        true,
+        TraceState::default(),
+    )
+}
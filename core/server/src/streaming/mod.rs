@@ -28,6 +28,7 @@ pub mod segments;
 pub mod session;
 pub mod storage;
 pub mod streams;
+pub mod system_events;
 pub mod systems;
 pub mod topics;
 pub mod users;
@@ -20,8 +20,9 @@ use crate::streaming::partitions::COMPONENT;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::segments::*;
+use crate::streaming::system_events;
 use error_set::ErrContext;
-use iggy_common::{Confirmation, IggyError, IggyTimestamp, Sizeable};
+use iggy_common::{Confirmation, IggyError, IggyTimestamp, Sizeable, SystemEventKind};
 use std::sync::atomic::Ordering;
 use tracing::trace;
 
@@ -243,7 +244,18 @@ impl Partition {
             );
             self.add_persisted_segment(start_offset).await.with_error_context(|error| format!(
                     "{COMPONENT} (error: {error}) - failed to add persisted segment, partition: {self}, start offset: {start_offset}",
-                ))?
+                ))?;
+            if !system_events::is_events_stream(self.stream_id) {
+                system_events::publish(
+                    None,
+                    SystemEventKind::SegmentRolledOver {
+                        stream_id: system_events::numeric(self.stream_id),
+                        topic_id: system_events::numeric(self.topic_id),
+                        partition_id: self.partition_id,
+                        start_offset,
+                    },
+                );
+            }
         }
 
         let current_offset = if !self.should_increment_offset {
@@ -23,6 +23,7 @@ use crate::streaming::partitions::partition::Partition;
 use crate::streaming::segments::*;
 use error_set::ErrContext;
 use iggy_common::IggyError;
+use iggy_common::IggyExpiry;
 use iggy_common::IggyTimestamp;
 use tracing::info;
 
@@ -56,10 +57,14 @@ impl Partition {
             .find(|s| s.start_offset() == start_offset)
     }
 
-    pub async fn get_expired_segments_start_offsets(&self, now: IggyTimestamp) -> Vec<u64> {
+    pub async fn get_expired_segments_start_offsets(
+        &self,
+        message_expiry: IggyExpiry,
+        now: IggyTimestamp,
+    ) -> Vec<u64> {
         let mut expired_segments = Vec::new();
         for segment in &self.segments {
-            if segment.is_expired(now).await {
+            if segment.is_expired_with(message_expiry, now).await {
                 expired_segments.push(segment.start_offset());
             }
         }
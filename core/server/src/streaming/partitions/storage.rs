@@ -19,6 +19,7 @@
 use crate::compat::index_rebuilding::index_rebuilder::IndexRebuilder;
 use crate::configs::cache_indexes::CacheIndexesConfig;
 use crate::state::system::PartitionState;
+use crate::streaming::diagnostics::startup;
 use crate::streaming::partitions::COMPONENT;
 use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
 use crate::streaming::persistence::persister::PersisterKind;
@@ -137,6 +138,7 @@ impl PartitionStorage for FilePartitionStorage {
                     index_path.clone(),
                     start_offset,
                 );
+                startup::index_rebuild_started();
                 index_rebuilder.rebuild().await.unwrap_or_else(|e| {
                     panic!(
                         "Failed to rebuild index for partition with ID: {} for
@@ -144,6 +146,7 @@ impl PartitionStorage for FilePartitionStorage {
                         partition.partition_id, partition.stream_id, partition.topic_id,
                     )
                 });
+                startup::index_rebuild_finished();
                 info!(
                     "Rebuilding index for path {} finished, it took {} ms",
                     index_path,
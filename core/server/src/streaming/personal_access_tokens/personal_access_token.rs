@@ -18,6 +18,7 @@
 use crate::streaming::utils::hash;
 use iggy_common::IggyExpiry;
 use iggy_common::IggyTimestamp;
+use iggy_common::ThroughputQuota;
 use iggy_common::UserId;
 use iggy_common::text::as_base64;
 use ring::rand::SecureRandom;
@@ -31,6 +32,7 @@ pub struct PersonalAccessToken {
     pub name: Arc<String>,
     pub token: Arc<String>,
     pub expiry_at: Option<IggyTimestamp>,
+    pub quota: Option<ThroughputQuota>,
 }
 
 impl PersonalAccessToken {
@@ -52,6 +54,7 @@ impl PersonalAccessToken {
                 name: Arc::new(name.to_string()),
                 token: Arc::new(token_hash),
                 expiry_at: Self::calculate_expiry_at(now, expiry),
+                quota: None,
             },
             token,
         )
@@ -68,6 +71,7 @@ impl PersonalAccessToken {
             name: Arc::new(name.into()),
             token: Arc::new(token_hash.into()),
             expiry_at,
+            quota: None,
         }
     }
 
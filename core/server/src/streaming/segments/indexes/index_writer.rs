@@ -19,10 +19,12 @@
 use error_set::ErrContext;
 use iggy_common::INDEX_SIZE;
 use iggy_common::IggyError;
+use prometheus_client::metrics::histogram::Histogram;
 use std::sync::{
     Arc,
     atomic::{AtomicU64, Ordering},
 };
+use std::time::Instant;
 use tokio::{
     fs::{File, OpenOptions},
     io::AsyncWriteExt,
@@ -36,6 +38,7 @@ pub struct IndexWriter {
     file: File,
     index_size_bytes: Arc<AtomicU64>,
     fsync: bool,
+    fsync_duration: Option<Histogram>,
 }
 
 impl IndexWriter {
@@ -44,6 +47,7 @@ impl IndexWriter {
         file_path: &str,
         index_size_bytes: Arc<AtomicU64>,
         fsync: bool,
+        fsync_duration: Option<Histogram>,
         file_exists: bool,
     ) -> Result<Self, IggyError> {
         let file = OpenOptions::new()
@@ -82,6 +86,7 @@ impl IndexWriter {
             file,
             index_size_bytes,
             fsync,
+            fsync_duration,
         })
     }
 
@@ -120,6 +125,7 @@ impl IndexWriter {
     }
 
     pub async fn fsync(&self) -> Result<(), IggyError> {
+        let started_at = Instant::now();
         self.file
             .sync_all()
             .await
@@ -127,6 +133,9 @@ impl IndexWriter {
                 format!("Failed to fsync index file: {}. {error}", self.file_path)
             })
             .map_err(|_| IggyError::CannotWriteToFile)?;
+        if let Some(fsync_duration) = &self.fsync_duration {
+            fsync_duration.observe(started_at.elapsed().as_secs_f64());
+        }
         Ok(())
     }
 }
@@ -20,10 +20,12 @@ use super::PersisterTask;
 use crate::streaming::segments::{IggyMessagesBatchSet, messages::write_batch};
 use error_set::ErrContext;
 use iggy_common::{Confirmation, IggyByteSize, IggyError};
+use prometheus_client::metrics::histogram::Histogram;
 use std::sync::{
     Arc,
     atomic::{AtomicU64, Ordering},
 };
+use std::time::Instant;
 use tokio::fs::{File, OpenOptions};
 use tracing::{error, trace};
 
@@ -37,6 +39,7 @@ pub struct MessagesWriter {
     persister_task: Option<PersisterTask>,
     messages_size_bytes: Arc<AtomicU64>,
     fsync: bool,
+    fsync_duration: Option<Histogram>,
 }
 
 impl MessagesWriter {
@@ -49,6 +52,7 @@ impl MessagesWriter {
         file_path: &str,
         messages_size_bytes: Arc<AtomicU64>,
         fsync: bool,
+        fsync_duration: Option<Histogram>,
         server_confirmation: Confirmation,
         file_exists: bool,
     ) -> Result<Self, IggyError> {
@@ -88,6 +92,7 @@ impl MessagesWriter {
                     file,
                     file_path.to_string(),
                     fsync,
+                    fsync_duration.clone(),
                     messages_size_bytes.clone(),
                 );
                 (None, Some(persister))
@@ -101,6 +106,7 @@ impl MessagesWriter {
             persister_task,
             messages_size_bytes,
             fsync,
+            fsync_duration,
         })
     }
 
@@ -162,12 +168,16 @@ impl MessagesWriter {
 
     pub async fn fsync(&self) -> Result<(), IggyError> {
         if let Some(file) = self.file.as_ref() {
+            let started_at = Instant::now();
             file.sync_all()
                 .await
                 .with_error_context(|error| {
                     format!("Failed to fsync messages file: {}. {error}", self.file_path)
                 })
                 .map_err(|_| IggyError::CannotWriteToFile)?;
+            if let Some(fsync_duration) = &self.fsync_duration {
+                fsync_duration.observe(started_at.elapsed().as_secs_f64());
+            }
         }
 
         Ok(())
@@ -19,12 +19,13 @@
 use crate::streaming::segments::IggyMessagesBatchSet;
 use error_set::ErrContext;
 use flume::{Receiver, unbounded};
+use prometheus_client::metrics::histogram::Histogram;
 use std::{
     sync::{
         Arc,
         atomic::{AtomicU64, Ordering},
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tokio::{fs::File, select, time::sleep};
 use tracing::{error, trace, warn};
@@ -48,12 +49,26 @@ pub struct PersisterTask {
 
 impl PersisterTask {
     /// Creates a new persister task that takes ownership of `file`.
-    pub fn new(file: File, file_path: String, fsync: bool, log_file_size: Arc<AtomicU64>) -> Self {
+    pub fn new(
+        file: File,
+        file_path: String,
+        fsync: bool,
+        fsync_duration: Option<Histogram>,
+        log_file_size: Arc<AtomicU64>,
+    ) -> Self {
         let (sender, receiver) = unbounded();
         let log_file_size = log_file_size.clone();
         let file_path_clone = file_path.clone();
         let handle = tokio::spawn(async move {
-            Self::run(file, file_path_clone, receiver, fsync, log_file_size).await;
+            Self::run(
+                file,
+                file_path_clone,
+                receiver,
+                fsync,
+                fsync_duration,
+                log_file_size,
+            )
+            .await;
         });
         Self {
             sender,
@@ -170,6 +185,7 @@ impl PersisterTask {
         file_path: String,
         receiver: Receiver<PersisterTaskCommand>,
         fsync: bool,
+        fsync_duration: Option<Histogram>,
         log_file_size: Arc<AtomicU64>,
     ) {
         while let Ok(request) = receiver.recv_async().await {
@@ -178,6 +194,7 @@ impl PersisterTask {
                     match write_batch(&mut file, &file_path, messages).await {
                         Ok(bytes_written) => {
                             if fsync {
+                                let started_at = Instant::now();
                                 file.sync_all()
                                     .await
                                     .with_error_context(|error| {
@@ -186,6 +203,9 @@ impl PersisterTask {
                                         )
                                     })
                                     .expect("Failed to fsync messages file");
+                                if let Some(fsync_duration) = &fsync_duration {
+                                    fsync_duration.observe(started_at.elapsed().as_secs_f64());
+                                }
                             }
 
                             log_file_size.fetch_add(bytes_written as u64, Ordering::Acquire);
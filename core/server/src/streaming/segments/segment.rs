@@ -20,6 +20,7 @@ use super::indexes::*;
 use super::messages::*;
 use super::messages_accumulator::MessagesAccumulator;
 use crate::configs::system::SystemConfig;
+use crate::streaming::diagnostics::metrics::StorageMetrics;
 use crate::streaming::segments::*;
 use error_set::ErrContext;
 use iggy_common::INDEX_SIZE;
@@ -27,6 +28,7 @@ use iggy_common::IggyByteSize;
 use iggy_common::IggyError;
 use iggy_common::IggyExpiry;
 use iggy_common::IggyTimestamp;
+use prometheus_client::metrics::histogram::Histogram;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use tokio::fs::remove_file;
@@ -64,6 +66,7 @@ pub struct Segment {
     pub(super) indexes: IggyIndexesMut,
     pub(super) messages_size: Arc<AtomicU64>,
     pub(super) indexes_size: Arc<AtomicU64>,
+    pub(super) persist_duration: Option<Histogram>,
 }
 
 impl Segment {
@@ -124,6 +127,8 @@ impl Segment {
             config,
             messages_size: Arc::new(AtomicU64::new(0)),
             indexes_size: Arc::new(AtomicU64::new(0)),
+            persist_duration: StorageMetrics::get()
+                .map(|metrics| metrics.persist_duration(stream_id, topic_id, partition_id)),
         }
     }
 
@@ -224,11 +229,15 @@ impl Segment {
         let index_fsync = self.config.partition.enforce_fsync;
 
         let server_confirmation = self.config.segment.server_confirmation;
+        let fsync_duration = StorageMetrics::get().map(|metrics| {
+            metrics.fsync_duration(self.stream_id, self.topic_id, self.partition_id)
+        });
 
         let messages_writer = MessagesWriter::new(
             &self.messages_path,
             self.messages_size.clone(),
             log_fsync,
+            fsync_duration.clone(),
             server_confirmation,
             file_exists,
         )
@@ -238,6 +247,7 @@ impl Segment {
             &self.index_path,
             self.indexes_size.clone(),
             index_fsync,
+            fsync_duration,
             file_exists,
         )
         .await?;
@@ -267,11 +277,17 @@ impl Segment {
     }
 
     pub async fn is_expired(&self, now: IggyTimestamp) -> bool {
+        self.is_expired_with(self.message_expiry, now).await
+    }
+
+    /// Checks whether the segment is expired using the provided message expiry
+    /// instead of its own, e.g. the default expiry inherited from the stream.
+    pub async fn is_expired_with(&self, message_expiry: IggyExpiry, now: IggyTimestamp) -> bool {
         if !self.is_closed {
             return false;
         }
 
-        match self.message_expiry {
+        match message_expiry {
             IggyExpiry::NeverExpire => false,
             IggyExpiry::ServerDefault => false,
             IggyExpiry::ExpireDuration(expiry) => {
@@ -201,6 +201,7 @@ impl IggyMessagesBatchSet {
             current_offset: poll_metadata.current_offset,
             count: messages.len() as u32,
             messages,
+            throttle_time: None,
         }
     }
 
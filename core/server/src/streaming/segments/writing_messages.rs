@@ -26,6 +26,7 @@ use error_set::ErrContext;
 use iggy_common::Confirmation;
 use iggy_common::IggyError;
 use std::sync::atomic::Ordering;
+use std::time::Instant;
 use tracing::{info, trace};
 
 impl Segment {
@@ -74,6 +75,7 @@ impl Segment {
             return Ok(0);
         }
 
+        let started_at = Instant::now();
         let unsaved_messages_count = self.accumulator.unsaved_messages_count();
         trace!(
             "Saving {} messages on disk in segment with start offset: {} for partition with ID: {}...",
@@ -126,6 +128,10 @@ impl Segment {
             self.indexes.clear();
         }
 
+        if let Some(persist_duration) = &self.persist_duration {
+            persist_duration.observe(started_at.elapsed().as_secs_f64());
+        }
+
         self.check_and_handle_segment_full().await?;
 
         trace!(
@@ -16,27 +16,58 @@
  * under the License.
  */
 
-use iggy_common::{AtomicUserId, UserId};
+use crate::streaming::users::login_limiter::LoginSource;
+use iggy_common::{
+    AtomicUserId, FrameCompression, FrameCompressionAlgorithm, IggyTimestamp, PING_CODE,
+    ProtocolFeatures, UserId,
+};
 use std::fmt::Display;
 use std::net::SocketAddr;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU32, AtomicU64, Ordering};
+use tokio_util::sync::CancellationToken;
 
 // This might be extended with more fields in the future e.g. custom name, permissions etc.
 #[derive(Debug)]
 pub struct Session {
     user_id: AtomicUserId,
     active: AtomicBool,
+    last_activity_at: AtomicU64,
+    last_command_at: AtomicU64,
+    frame_started_at: AtomicU64,
+    bytes_received: AtomicU64,
+    bytes_sent: AtomicU64,
+    protocol_version: AtomicU32,
+    protocol_features: AtomicU64,
+    frame_compression_algorithm: AtomicU8,
+    frame_compression_min_size: AtomicU32,
+    disconnect: CancellationToken,
     pub client_id: u32,
     pub ip_address: SocketAddr,
+    /// The user ID of the Unix socket peer process, which identifies the client instead of its address.
+    pub peer_uid: Option<u32>,
+    pub connected_at: IggyTimestamp,
 }
 
 impl Session {
     pub fn new(client_id: u32, user_id: UserId, ip_address: SocketAddr) -> Self {
+        let now = IggyTimestamp::now();
         Self {
             client_id,
             active: AtomicBool::new(true),
             user_id: AtomicUserId::new(user_id),
+            last_activity_at: AtomicU64::new(now.as_micros()),
+            last_command_at: AtomicU64::new(now.as_micros()),
+            frame_started_at: AtomicU64::new(0),
+            bytes_received: AtomicU64::new(0),
+            bytes_sent: AtomicU64::new(0),
+            protocol_version: AtomicU32::new(0),
+            protocol_features: AtomicU64::new(0),
+            frame_compression_algorithm: AtomicU8::new(FrameCompressionAlgorithm::None.as_code()),
+            frame_compression_min_size: AtomicU32::new(0),
+            disconnect: CancellationToken::new(),
             ip_address,
+            peer_uid: None,
+            connected_at: now,
         }
     }
 
@@ -48,6 +79,21 @@ impl Session {
         Self::new(client_id, 0, ip_address)
     }
 
+    pub fn from_peer(client_id: u32, ip_address: SocketAddr, peer_uid: Option<u32>) -> Self {
+        Self {
+            peer_uid,
+            ..Self::new(client_id, 0, ip_address)
+        }
+    }
+
+    /// Returns the source of the login attempts made within this session, which are rate limited.
+    pub fn login_source(&self) -> LoginSource {
+        match self.peer_uid {
+            Some(uid) => LoginSource::PeerUid(uid),
+            None => LoginSource::Ip(self.ip_address.ip()),
+        }
+    }
+
     pub fn get_user_id(&self) -> UserId {
         self.user_id.load(Ordering::Acquire)
     }
@@ -71,6 +117,105 @@ impl Session {
     pub fn is_authenticated(&self) -> bool {
         self.get_user_id() > 0
     }
+
+    /// Records a request of the given size (including the length prefix) received from the client,
+    /// which completes the frame being read. The heartbeats don't count as the command activity.
+    pub fn record_request(&self, code: u32, bytes: u64) {
+        let now = IggyTimestamp::now().as_micros();
+        self.bytes_received.fetch_add(bytes, Ordering::Relaxed);
+        self.last_activity_at.store(now, Ordering::Relaxed);
+        if code != PING_CODE {
+            self.last_command_at.store(now, Ordering::Relaxed);
+        }
+        self.frame_started_at.store(0, Ordering::Relaxed);
+    }
+
+    /// Records the beginning of a request frame, which has been only partially received so far.
+    pub fn record_frame_start(&self) {
+        self.frame_started_at
+            .store(IggyTimestamp::now().as_micros(), Ordering::Relaxed);
+    }
+
+    /// Records the size of the responses sent to the client.
+    pub fn record_response(&self, bytes: u64) {
+        self.bytes_sent.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    pub fn get_last_activity_at(&self) -> IggyTimestamp {
+        self.last_activity_at.load(Ordering::Relaxed).into()
+    }
+
+    /// Returns the time of the last request other than the heartbeat, or the connection time.
+    pub fn get_last_command_at(&self) -> IggyTimestamp {
+        self.last_command_at.load(Ordering::Relaxed).into()
+    }
+
+    /// Returns the time when the frame which hasn't been fully received yet has started, if any.
+    pub fn get_frame_started_at(&self) -> Option<IggyTimestamp> {
+        match self.frame_started_at.load(Ordering::Relaxed) {
+            0 => None,
+            started_at => Some(started_at.into()),
+        }
+    }
+
+    pub fn get_bytes_received(&self) -> u64 {
+        self.bytes_received.load(Ordering::Relaxed)
+    }
+
+    pub fn get_bytes_sent(&self) -> u64 {
+        self.bytes_sent.load(Ordering::Relaxed)
+    }
+
+    /// Returns the protocol version negotiated with the `Hello` command, or 0 for the legacy clients.
+    pub fn get_protocol_version(&self) -> u32 {
+        self.protocol_version.load(Ordering::Acquire)
+    }
+
+    pub fn set_protocol_version(&self, protocol_version: u32) {
+        self.protocol_version
+            .store(protocol_version, Ordering::Release)
+    }
+
+    /// Returns the optional protocol features negotiated with the `Hello` command, none for the legacy clients.
+    pub fn get_protocol_features(&self) -> ProtocolFeatures {
+        ProtocolFeatures::from_bits(self.protocol_features.load(Ordering::Acquire))
+    }
+
+    pub fn set_protocol_features(&self, protocol_features: ProtocolFeatures) {
+        self.protocol_features
+            .store(protocol_features.bits(), Ordering::Release)
+    }
+
+    /// Returns the frame compression negotiated with the `EnableFrameCompression` command.
+    pub fn get_frame_compression(&self) -> FrameCompression {
+        let algorithm = FrameCompressionAlgorithm::from_code(
+            self.frame_compression_algorithm.load(Ordering::Acquire),
+        )
+        .unwrap_or_default();
+        FrameCompression::new(
+            algorithm,
+            self.frame_compression_min_size.load(Ordering::Acquire),
+        )
+    }
+
+    pub fn set_frame_compression(&self, compression: FrameCompression) {
+        self.frame_compression_min_size
+            .store(compression.min_size, Ordering::Release);
+        self.frame_compression_algorithm
+            .store(compression.algorithm.as_code(), Ordering::Release);
+    }
+
+    /// Requests the connection bound to this session to be closed by the server.
+    pub fn disconnect(&self) {
+        self.set_stale();
+        self.clear_user_id();
+        self.disconnect.cancel();
+    }
+
+    /// Completes once the session has been requested to disconnect.
+    pub async fn disconnected(&self) {
+        self.disconnect.cancelled().await
+    }
 }
 
 impl Display for Session {
@@ -22,10 +22,13 @@ use crate::streaming::topics::topic::Topic;
 use ahash::AHashMap;
 use iggy_common::IggyByteSize;
 use iggy_common::IggyTimestamp;
+use iggy_common::{IggyError, IggyExpiry, MaxTopicSize, StreamSizePolicy};
 use std::fmt::Display;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 
+const ALMOST_FULL_THRESHOLD: f64 = 0.9;
+
 #[derive(Debug)]
 pub struct Stream {
     pub stream_id: u32,
@@ -37,6 +40,9 @@ pub struct Stream {
     pub size_bytes: Arc<AtomicU64>,
     pub messages_count: Arc<AtomicU64>,
     pub segments_count: Arc<AtomicU32>,
+    pub max_size: MaxTopicSize,
+    pub message_expiry: IggyExpiry,
+    pub size_policy: StreamSizePolicy,
     pub(crate) topics: AHashMap<u32, Topic>,
     pub(crate) topics_ids: AHashMap<String, u32>,
     pub(crate) config: Arc<SystemConfig>,
@@ -72,6 +78,9 @@ impl Stream {
             size_bytes: Arc::new(AtomicU64::new(0)),
             messages_count: Arc::new(AtomicU64::new(0)),
             segments_count: Arc::new(AtomicU32::new(0)),
+            max_size: MaxTopicSize::Unlimited,
+            message_expiry: IggyExpiry::NeverExpire,
+            size_policy: StreamSizePolicy::default(),
             topics: AHashMap::new(),
             topics_ids: AHashMap::new(),
             storage,
@@ -82,14 +91,65 @@ impl Stream {
     pub fn get_size(&self) -> IggyByteSize {
         IggyByteSize::from(self.size_bytes.load(Ordering::SeqCst))
     }
+
+    pub fn is_full(&self) -> bool {
+        match self.max_size {
+            MaxTopicSize::Unlimited => false,
+            MaxTopicSize::ServerDefault => false,
+            MaxTopicSize::Custom(size) => {
+                self.size_bytes.load(Ordering::SeqCst) >= size.as_bytes_u64()
+            }
+        }
+    }
+
+    pub fn is_almost_full(&self) -> bool {
+        match self.max_size {
+            MaxTopicSize::Unlimited => false,
+            MaxTopicSize::ServerDefault => false,
+            MaxTopicSize::Custom(size) => {
+                self.size_bytes.load(Ordering::SeqCst)
+                    >= (size.as_bytes_u64() as f64 * ALMOST_FULL_THRESHOLD) as u64
+            }
+        }
+    }
+
+    pub fn get_max_size(
+        max_size: MaxTopicSize,
+        config: &SystemConfig,
+    ) -> Result<MaxTopicSize, IggyError> {
+        match max_size {
+            MaxTopicSize::ServerDefault | MaxTopicSize::Unlimited => Ok(MaxTopicSize::Unlimited),
+            MaxTopicSize::Custom(size) => {
+                if size.as_bytes_u64() < config.segment.size.as_bytes_u64() {
+                    Err(IggyError::InvalidTopicSize(max_size, config.segment.size))
+                } else {
+                    Ok(max_size)
+                }
+            }
+        }
+    }
+
+    pub fn get_message_expiry(message_expiry: IggyExpiry) -> IggyExpiry {
+        match message_expiry {
+            IggyExpiry::ServerDefault => IggyExpiry::NeverExpire,
+            _ => message_expiry,
+        }
+    }
 }
 
 impl Display for Stream {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Stream {{ stream ID: {}, name: {}, path: {}, topic_path: {}, created_at: {} }}",
-            self.stream_id, self.name, self.path, self.topics_path, self.created_at,
+            "Stream {{ stream ID: {}, name: {}, path: {}, topic_path: {}, created_at: {}, max_size: {}, message_expiry: {}, size_policy: {} }}",
+            self.stream_id,
+            self.name,
+            self.path,
+            self.topics_path,
+            self.created_at,
+            self.max_size,
+            self.message_expiry,
+            self.size_policy,
         )
     }
 }
@@ -108,7 +108,7 @@ impl Stream {
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
     ) -> Result<(), IggyError> {
-        let message_expiry = Topic::get_message_expiry(message_expiry, &self.config);
+        let segments_message_expiry = Topic::get_message_expiry(message_expiry, &self.config);
         let max_topic_size = Topic::get_max_topic_size(max_topic_size, &self.config)?;
         let topic_id;
         {
@@ -146,9 +146,9 @@ impl Stream {
             topic.compression_algorithm = compression_algorithm;
             for partition in topic.partitions.values_mut() {
                 let mut partition = partition.write().await;
-                partition.message_expiry = message_expiry;
+                partition.message_expiry = segments_message_expiry;
                 for segment in partition.segments.iter_mut() {
-                    segment.update_message_expiry(message_expiry);
+                    segment.update_message_expiry(segments_message_expiry);
                 }
             }
             topic.max_topic_size = max_topic_size;
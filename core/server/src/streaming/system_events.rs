@@ -0,0 +1,199 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::state::command::EntryCommand;
+use flume::Sender;
+use iggy_common::{Identifier, SystemEvent, SystemEventKind};
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tracing::warn;
+
+// The events are published from the places where the system lock is held (e.g. while applying
+// the state or appending the messages), so they're only queued here and appended in the background.
+static SENDER: OnceLock<Sender<SystemEvent>> = OnceLock::new();
+static EVENTS_STREAM_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Enables publishing of the system events, queued to the given sender.
+pub fn register(sender: Sender<SystemEvent>) {
+    if SENDER.set(sender).is_err() {
+        warn!("System events publisher has been already registered.");
+    }
+}
+
+/// Sets the ID of the stream storing the system events, so that its own changes
+/// such as the segment rollovers caused by the appended events don't feed it back.
+pub fn set_events_stream_id(stream_id: u32) {
+    EVENTS_STREAM_ID.store(stream_id, Ordering::Relaxed);
+}
+
+pub fn is_events_stream(stream_id: u32) -> bool {
+    EVENTS_STREAM_ID.load(Ordering::Relaxed) == stream_id
+}
+
+pub fn is_enabled() -> bool {
+    SENDER.get().is_some()
+}
+
+/// Publishes the event if the system events are enabled, otherwise does nothing.
+pub fn publish(initiator_id: Option<u32>, kind: SystemEventKind) {
+    let Some(sender) = SENDER.get() else {
+        return;
+    };
+
+    if let Err(error) = sender.send(SystemEvent::new(initiator_id, kind)) {
+        warn!("Failed to publish system event. {error}");
+    }
+}
+
+/// Publishes the event describing the applied state command, if it's a metadata change.
+pub fn publish_entry_command(user_id: u32, command: &EntryCommand) {
+    if !is_enabled() {
+        return;
+    }
+
+    if let Some(kind) = map_entry_command(command) {
+        publish(Some(user_id), kind);
+    }
+}
+
+fn map_entry_command(command: &EntryCommand) -> Option<SystemEventKind> {
+    let kind = match command {
+        EntryCommand::CreateStream(command) => SystemEventKind::StreamCreated {
+            stream_id: numeric(command.stream_id),
+            name: command.command.name.clone(),
+        },
+        EntryCommand::UpdateStream(command) => SystemEventKind::StreamUpdated {
+            stream_id: command.stream_id.clone(),
+        },
+        EntryCommand::UpdateStreamRetention(command) => SystemEventKind::StreamUpdated {
+            stream_id: command.stream_id.clone(),
+        },
+        EntryCommand::DeleteStream(command) => SystemEventKind::StreamDeleted {
+            stream_id: command.stream_id.clone(),
+        },
+        EntryCommand::PurgeStream(command) => SystemEventKind::StreamPurged {
+            stream_id: command.stream_id.clone(),
+        },
+        EntryCommand::CreateTopic(command) => SystemEventKind::TopicCreated {
+            stream_id: command.command.stream_id.clone(),
+            topic_id: numeric(command.topic_id),
+            name: command.command.name.clone(),
+            partitions_count: command.command.partitions_count,
+        },
+        EntryCommand::UpdateTopic(command) => SystemEventKind::TopicUpdated {
+            stream_id: command.stream_id.clone(),
+            topic_id: command.topic_id.clone(),
+        },
+        EntryCommand::DeleteTopic(command) => SystemEventKind::TopicDeleted {
+            stream_id: command.stream_id.clone(),
+            topic_id: command.topic_id.clone(),
+        },
+        EntryCommand::PurgeTopic(command) => SystemEventKind::TopicPurged {
+            stream_id: command.stream_id.clone(),
+            topic_id: command.topic_id.clone(),
+        },
+        EntryCommand::CreatePartitions(command) => SystemEventKind::PartitionsCreated {
+            stream_id: command.stream_id.clone(),
+            topic_id: command.topic_id.clone(),
+            partitions_count: command.partitions_count,
+        },
+        EntryCommand::DeletePartitions(command) => SystemEventKind::PartitionsDeleted {
+            stream_id: command.stream_id.clone(),
+            topic_id: command.topic_id.clone(),
+            partitions_count: command.partitions_count,
+        },
+        EntryCommand::DeleteSegments(command) => SystemEventKind::SegmentsDeleted {
+            stream_id: command.stream_id.clone(),
+            topic_id: command.topic_id.clone(),
+            partition_id: command.partition_id,
+            segments_count: command.segments_count,
+        },
+        EntryCommand::CreateConsumerGroup(command) => SystemEventKind::ConsumerGroupCreated {
+            stream_id: command.command.stream_id.clone(),
+            topic_id: command.command.topic_id.clone(),
+            group_id: numeric(command.group_id),
+            name: command.command.name.clone(),
+        },
+        EntryCommand::DeleteConsumerGroup(command) => SystemEventKind::ConsumerGroupDeleted {
+            stream_id: command.stream_id.clone(),
+            topic_id: command.topic_id.clone(),
+            group_id: command.group_id.clone(),
+        },
+        EntryCommand::CreateUser(command) => SystemEventKind::UserCreated {
+            user_id: numeric(command.user_id),
+            username: command.command.username.clone(),
+        },
+        EntryCommand::UpdateUser(command) => SystemEventKind::UserUpdated {
+            user_id: command.user_id.clone(),
+        },
+        EntryCommand::UpdateUserQuota(command) => SystemEventKind::UserUpdated {
+            user_id: command.user_id.clone(),
+        },
+        EntryCommand::DeleteUser(command) => SystemEventKind::UserDeleted {
+            user_id: command.user_id.clone(),
+        },
+        EntryCommand::ChangePassword(command) => SystemEventKind::UserPasswordChanged {
+            user_id: command.user_id.clone(),
+        },
+        EntryCommand::UpdatePermissions(command) => SystemEventKind::UserPermissionsUpdated {
+            user_id: command.user_id.clone(),
+        },
+        EntryCommand::CreatePersonalAccessToken(_)
+        | EntryCommand::DeletePersonalAccessToken(_)
+        | EntryCommand::UpdatePersonalAccessTokenQuota(_) => return None,
+    };
+    Some(kind)
+}
+
+pub fn numeric(id: u32) -> Identifier {
+    Identifier::numeric(id).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::models::CreateStreamWithId;
+    use iggy_common::create_stream::CreateStream;
+    use iggy_common::delete_personal_access_token::DeletePersonalAccessToken;
+
+    #[test]
+    fn should_map_metadata_commands_to_events() {
+        let command = EntryCommand::CreateStream(CreateStreamWithId {
+            stream_id: 5,
+            command: CreateStream {
+                stream_id: None,
+                name: "orders".to_owned(),
+            },
+        });
+        assert_eq!(
+            map_entry_command(&command),
+            Some(SystemEventKind::StreamCreated {
+                stream_id: numeric(5),
+                name: "orders".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn should_not_map_personal_access_token_commands() {
+        let command = EntryCommand::DeletePersonalAccessToken(DeletePersonalAccessToken {
+            name: "token".to_owned(),
+        });
+        assert!(map_entry_command(&command).is_none());
+    }
+}
@@ -21,13 +21,18 @@ use crate::streaming::session::Session;
 use crate::streaming::systems::COMPONENT;
 use crate::streaming::systems::system::System;
 use error_set::ErrContext;
+use iggy_common::ClientMetadata;
 use iggy_common::Identifier;
 use iggy_common::IggyError;
+use iggy_common::hello::Hello;
 use iggy_common::locking::IggySharedMut;
 use iggy_common::locking::IggySharedMutFn;
+use iggy_common::{
+    CompressionAlgorithm, HELLO_CODE, PING_CODE, PROTOCOL_VERSION, ProtocolFeatures, ServerHello,
+};
 use std::net::SocketAddr;
 use std::sync::Arc;
-use tracing::{error, info};
+use tracing::{debug, error, info};
 
 impl System {
     pub async fn add_client(&self, address: &SocketAddr, transport: Transport) -> Arc<Session> {
@@ -38,20 +43,59 @@ impl System {
         session
     }
 
+    /// Adds the Unix socket client, identified by the user ID of the peer process rather than by its address.
+    pub async fn add_unix_client(&self, peer_uid: Option<u32>) -> Arc<Session> {
+        let mut client_manager = self.client_manager.write().await;
+        let session = client_manager.add_unix_client(peer_uid);
+        info!("Added {} client with session: {session}", Transport::Unix);
+        self.metrics.increment_clients(1);
+        session
+    }
+
+    /// Adds the client with the ID not derived from its address, which is deleted by its session.
+    pub async fn add_unique_client(
+        &self,
+        address: &SocketAddr,
+        transport: Transport,
+    ) -> Arc<Session> {
+        let mut client_manager = self.client_manager.write().await;
+        let session = client_manager.add_unique_client(address, transport);
+        info!("Added {transport} client with session: {session} for IP address: {address}");
+        self.metrics.increment_clients(1);
+        session
+    }
+
     pub async fn delete_client(&self, client_id: u32) {
+        self.delete_client_with_session(client_id, None).await
+    }
+
+    /// Deletes the client only if it's still registered with the given session.
+    pub async fn delete_client_session(&self, session: &Arc<Session>) {
+        self.delete_client_with_session(session.client_id, Some(session))
+            .await
+    }
+
+    async fn delete_client_with_session(&self, client_id: u32, session: Option<&Arc<Session>>) {
         let consumer_groups: Vec<(u32, u32, u32)>;
 
         {
             let mut client_manager = self.client_manager.write().await;
-            let client = client_manager.delete_client(client_id).await;
+            let client = match session {
+                Some(session) => client_manager.delete_client_session(session).await,
+                None => client_manager.delete_client(client_id).await,
+            };
             if client.is_none() {
                 error!("Client with ID: {client_id} was not found in the client manager.",);
                 return;
             }
 
             self.metrics.decrement_clients(1);
+            self.quota_limiter.unbind_client(client_id);
             let client = client.unwrap();
             let client = client.read().await;
+            if let Some(metadata) = &client.metadata {
+                self.metrics.decrement_identified_clients(metadata);
+            }
             consumer_groups = client
                 .consumer_groups
                 .iter()
@@ -71,11 +115,121 @@ impl System {
                     &Identifier::numeric(topic_id).unwrap(),
                     &Identifier::numeric(consumer_group_id).unwrap(),
                     client_id,
+                    None,
                 )
                 .await
         }
     }
 
+    /// Negotiates the protocol version with the client and returns the capabilities of the server.
+    /// The list of the supported commands is left empty, as it's known only to the transport layer.
+    pub fn hello(&self, session: &Session, hello: &Hello) -> Result<ServerHello, IggyError> {
+        let min_client_protocol_version = self.config.protocol.min_client_protocol_version;
+        if hello.protocol_version < min_client_protocol_version {
+            error!(
+                "Client with ID: {} uses unsupported protocol version: {}, minimum supported version: {min_client_protocol_version}.",
+                session.client_id, hello.protocol_version
+            );
+            return Err(IggyError::UnsupportedProtocolVersion(
+                hello.protocol_version,
+                min_client_protocol_version,
+            ));
+        }
+
+        session.set_protocol_version(hello.protocol_version.min(PROTOCOL_VERSION));
+        session.set_protocol_features(hello.features.intersection(self.protocol_features()));
+        debug!(
+            "Client with ID: {} negotiated protocol version: {}, features: {}.",
+            session.client_id,
+            session.get_protocol_version(),
+            session.get_protocol_features()
+        );
+        Ok(ServerHello {
+            protocol_version: PROTOCOL_VERSION,
+            min_client_protocol_version,
+            max_frame_size: self.max_frame_size(),
+            features: self.protocol_features(),
+            compression_algorithms: CompressionAlgorithm::all(),
+            commands: Vec::new(),
+        })
+    }
+
+    /// Returns the maximum size of the request frame, 0 means unlimited.
+    pub fn max_frame_size(&self) -> u32 {
+        self.config.protocol.max_frame_size.as_bytes_u64() as u32
+    }
+
+    /// Returns the protocol features enabled in the server configuration.
+    pub fn protocol_features(&self) -> ProtocolFeatures {
+        if self.config.protocol.frame_compression {
+            return ProtocolFeatures::all();
+        }
+
+        ProtocolFeatures::all().without(ProtocolFeatures::FRAME_COMPRESSION)
+    }
+
+    /// Checks if the request with the given code and length (including the code) can be handled,
+    /// based on the negotiated protocol version and the maximum frame size.
+    pub fn validate_request(
+        &self,
+        session: &Session,
+        code: u32,
+        length: u32,
+    ) -> Result<(), IggyError> {
+        // The length includes the 4 bytes of the command code.
+        if length < 4 {
+            return Err(IggyError::InvalidFormat);
+        }
+
+        let max_frame_size = self.config.protocol.max_frame_size.as_bytes_u64();
+        if max_frame_size > 0 && length as u64 > max_frame_size {
+            return Err(IggyError::FrameTooLarge(length, max_frame_size as u32));
+        }
+
+        let min_client_protocol_version = self.config.protocol.min_client_protocol_version;
+        let protocol_version = session.get_protocol_version();
+        if protocol_version < min_client_protocol_version && code != HELLO_CODE && code != PING_CODE
+        {
+            return Err(IggyError::UnsupportedProtocolVersion(
+                protocol_version,
+                min_client_protocol_version,
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub async fn identify_client(
+        &self,
+        session: &Session,
+        metadata: ClientMetadata,
+    ) -> Result<(), IggyError> {
+        let client_id = session.client_id;
+        let previous_metadata = self
+            .client_manager
+            .read()
+            .await
+            .set_metadata(client_id, metadata.clone())
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to set metadata for client with ID: {client_id}"
+                )
+            })?;
+        if let Some(previous_metadata) = &previous_metadata {
+            self.metrics.decrement_identified_clients(previous_metadata);
+        }
+        self.metrics.increment_identified_clients(&metadata);
+        info!(
+            "Client with ID: {client_id} identified as application: {}, SDK: {} {}, hostname: {}.",
+            metadata.application_name,
+            metadata.sdk_language,
+            metadata.sdk_version,
+            metadata.hostname
+        );
+        Ok(())
+    }
+
     pub async fn get_client(
         &self,
         session: &Session,
@@ -112,4 +266,86 @@ impl System {
         let client_manager = self.client_manager.read().await;
         Ok(client_manager.get_clients())
     }
+
+    pub async fn disconnect_client(
+        &self,
+        session: &Session,
+        client_id: u32,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .disconnect_client(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to disconnect client with ID: {client_id} by user ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        let client = self
+            .client_manager
+            .read()
+            .await
+            .try_get_client(client_id)
+            .ok_or_else(|| IggyError::ResourceNotFound(format!("client with ID: {client_id}")))?;
+        let client = client.read().await;
+        client.session.disconnect();
+        info!(
+            "Disconnecting {} client with ID: {client_id} for IP address: {} by user ID: {}...",
+            client.transport,
+            client.session.ip_address,
+            session.get_user_id()
+        );
+        Ok(())
+    }
+
+    pub async fn revoke_user_sessions(
+        &self,
+        session: &Session,
+        user_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .revoke_user_sessions(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to revoke sessions of user with ID: {user_id} by user ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        let user_id = self
+            .get_user(user_id)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get user with ID: {user_id}")
+            })?
+            .id;
+        let sessions = self
+            .client_manager
+            .read()
+            .await
+            .get_sessions_for_user(user_id)
+            .await;
+        for user_session in &sessions {
+            user_session.disconnect();
+        }
+        info!(
+            "Disconnecting {} client(s) of user with ID: {user_id}...",
+            sessions.len()
+        );
+
+        if let Some(jwt_manager) = &self.jwt_manager {
+            jwt_manager
+                .revoke_user_tokens(user_id)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to revoke access tokens of user with ID: {user_id}"
+                    )
+                })?;
+        }
+
+        info!("Revoked sessions of user with ID: {user_id}.");
+        Ok(())
+    }
 }
@@ -0,0 +1,71 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::configs::server::ServerConfig;
+use crate::streaming::session::Session;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::systems::system::System;
+use error_set::ErrContext;
+use iggy_common::{ConfigReload, IggyError};
+use std::sync::Arc;
+use tokio::sync::watch;
+
+impl System {
+    pub async fn reload_config(&self, session: &Session) -> Result<ConfigReload, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .reload_config(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to reload config for user ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        self.apply_config_reload().await
+    }
+
+    /// Reloads the configuration on behalf of the server itself, e.g. on SIGHUP.
+    /// The quotas and the JWT validator are reconfigured here, the other components
+    /// are notified via `subscribe_config_changes`.
+    pub async fn apply_config_reload(&self) -> Result<ConfigReload, IggyError> {
+        let Some(config_reloader) = &self.config_reloader else {
+            return Err(IggyError::FeatureUnavailable);
+        };
+
+        let (reload, config) = config_reloader.reload().await?;
+        let changed = |prefix: &str| reload.applied.iter().any(|path| path.starts_with(prefix));
+        if changed("system.quota.") {
+            self.quota_limiter.reconfigure(&config.system.quota);
+        }
+        if let Some(jwt_manager) = self.jwt_manager.as_ref().filter(|_| changed("http.jwt.")) {
+            jwt_manager
+                .reload_validator(&config.http.jwt)
+                .with_error_context(|error| {
+                    format!("{COMPONENT} (error: {error}) - failed to reload JWT validator")
+                })?;
+        }
+        Ok(reload)
+    }
+
+    /// Returns the receiver notified each time the configuration is reloaded,
+    /// or `None` if the configuration can't be reloaded (e.g. in the tests).
+    pub fn subscribe_config_changes(&self) -> Option<watch::Receiver<Arc<ServerConfig>>> {
+        self.config_reloader
+            .as_ref()
+            .map(|config_reloader| config_reloader.subscribe())
+    }
+}
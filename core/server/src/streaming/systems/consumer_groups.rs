@@ -17,12 +17,14 @@
  */
 
 use crate::streaming::session::Session;
+use crate::streaming::system_events;
 use crate::streaming::systems::COMPONENT;
 use crate::streaming::systems::system::System;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use error_set::ErrContext;
 use iggy_common::Identifier;
 use iggy_common::IggyError;
+use iggy_common::SystemEventKind;
 use iggy_common::locking::IggySharedMutFn;
 use tokio::sync::RwLock;
 
@@ -228,6 +230,15 @@ impl System {
                 )
             })?;
 
+        system_events::publish(
+            Some(session.get_user_id()),
+            SystemEventKind::ConsumerGroupMemberJoined {
+                stream_id: system_events::numeric(stream_id_value),
+                topic_id: system_events::numeric(topic_id_value),
+                group_id: system_events::numeric(group_id),
+                client_id: session.client_id,
+            },
+        );
         Ok(())
     }
 
@@ -260,6 +271,7 @@ impl System {
             topic_id,
             consumer_group_id,
             session.client_id,
+            Some(session.get_user_id()),
         )
         .await
         .with_error_context(|error| {
@@ -276,6 +288,7 @@ impl System {
         topic_id: &Identifier,
         consumer_group_id: &Identifier,
         client_id: u32,
+        user_id: Option<u32>,
     ) -> Result<(), IggyError> {
         let stream_id_value;
         let topic_id_value;
@@ -316,6 +329,57 @@ impl System {
         let client_manager = self.client_manager.read().await;
         client_manager
             .leave_consumer_group(client_id, stream_id_value, topic_id_value, group_id)
-            .await
+            .await?;
+
+        system_events::publish(
+            user_id,
+            SystemEventKind::ConsumerGroupMemberLeft {
+                stream_id: system_events::numeric(stream_id_value),
+                topic_id: system_events::numeric(topic_id_value),
+                group_id: system_events::numeric(group_id),
+                client_id,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the number of messages in the partitions assigned to the consumer group member,
+    /// which haven't been consumed by its group yet, or `None` if the member doesn't exist.
+    pub(crate) async fn get_consumer_group_member_lag(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        group_id: u32,
+        member_id: u32,
+    ) -> Result<Option<u64>, IggyError> {
+        let stream = self.get_stream_by_id(stream_id)?;
+        let topic = stream.get_topic(&Identifier::numeric(topic_id)?)?;
+        let consumer_group = topic.get_consumer_group_by_id(group_id)?.read().await;
+        let mut partitions = None;
+        for member in consumer_group.get_members() {
+            let member = member.read().await;
+            if member.id == member_id {
+                partitions = Some(member.get_partitions());
+                break;
+            }
+        }
+        let Some(partitions) = partitions else {
+            return Ok(None);
+        };
+
+        let mut lag = 0;
+        for partition_id in partitions {
+            let partition = topic.get_partition(partition_id)?;
+            let partition = partition.read().await;
+            if !partition.should_increment_offset {
+                continue;
+            }
+
+            lag += match partition.consumer_group_offsets.get(&group_id) {
+                Some(offset) => partition.current_offset.saturating_sub(offset.offset),
+                None => partition.current_offset + 1,
+            };
+        }
+        Ok(Some(lag))
     }
 }
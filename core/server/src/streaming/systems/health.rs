@@ -0,0 +1,136 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::server::HealthConfig;
+use crate::streaming::diagnostics::startup;
+use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::utils::memory_pool;
+use iggy_common::locking::IggySharedMutFn;
+use iggy_common::{Health, HealthCheck, HealthProbe, IggyByteSize};
+
+/// Evaluates the liveness and readiness probes, without acquiring the system lock
+/// until the server has started, so that the probes are served during the startup.
+#[derive(Debug)]
+pub struct HealthChecker {
+    config: HealthConfig,
+    system_path: String,
+}
+
+impl HealthChecker {
+    pub fn new(config: HealthConfig, system_path: String) -> Self {
+        Self {
+            config,
+            system_path,
+        }
+    }
+
+    fn check_free_disk_space(&self) -> HealthCheck {
+        const NAME: &str = "disk";
+        let stat = match nix::sys::statvfs::statvfs(self.system_path.as_str()) {
+            Ok(stat) => stat,
+            Err(error) => {
+                return HealthCheck::down(
+                    NAME,
+                    format!("cannot read free space at: {}, {error}", self.system_path),
+                );
+            }
+        };
+
+        let free_space =
+            IggyByteSize::from(stat.blocks_available() as u64 * stat.fragment_size() as u64);
+        let details = format!(
+            "{} free, minimum: {}",
+            free_space.as_human_string(),
+            self.config.min_free_disk_space.as_human_string()
+        );
+        match free_space >= self.config.min_free_disk_space {
+            true => HealthCheck::up(NAME, details),
+            false => HealthCheck::down(NAME, details),
+        }
+    }
+
+    fn check_memory_pool(&self) -> HealthCheck {
+        const NAME: &str = "memory_pool";
+        let memory_pool = memory_pool();
+        if !memory_pool.is_enabled {
+            return HealthCheck::up(NAME, "memory pool is disabled".to_owned());
+        }
+
+        let utilization = memory_pool.pool_utilization();
+        let details = format!(
+            "{utilization:.2}% used, maximum: {}%",
+            self.config.max_memory_pool_usage
+        );
+        match utilization <= self.config.max_memory_pool_usage as f64 {
+            true => HealthCheck::up(NAME, details),
+            false => HealthCheck::down(NAME, details),
+        }
+    }
+}
+
+impl SharedSystem {
+    pub async fn get_health(&self, probe: HealthProbe) -> Health {
+        match probe {
+            HealthProbe::Liveness => Health::from_checks(Vec::new()),
+            HealthProbe::Readiness => {
+                let health = self.health_checker();
+                Health::from_checks(vec![
+                    startup::check_startup(),
+                    startup::check_state(),
+                    startup::check_partitions(),
+                    startup::check_index_rebuilding(),
+                    health.check_free_disk_space(),
+                    health.check_memory_pool(),
+                    self.check_unsaved_messages().await,
+                ])
+            }
+        }
+    }
+
+    /// Checks the size of the messages buffered in the partitions, which haven't been persisted yet.
+    async fn check_unsaved_messages(&self) -> HealthCheck {
+        const NAME: &str = "persister";
+        if !startup::is_started() {
+            return HealthCheck::down(NAME, "server is starting".to_owned());
+        }
+
+        let mut unsaved_messages_size = 0;
+        let system = self.read().await;
+        for stream in system.streams.values() {
+            for topic in stream.topics.values() {
+                for partition in topic.partitions.values() {
+                    unsaved_messages_size +=
+                        partition.read().await.unsaved_messages_size.as_bytes_u64();
+                }
+            }
+        }
+        drop(system);
+
+        let max_unsaved_messages_size = self.health_checker().config.max_unsaved_messages_size;
+        let unsaved_messages_size = IggyByteSize::from(unsaved_messages_size);
+        let details = format!(
+            "{} of messages not persisted yet, maximum: {}",
+            unsaved_messages_size.as_human_string(),
+            max_unsaved_messages_size.as_human_string()
+        );
+        match unsaved_messages_size <= max_unsaved_messages_size {
+            true => HealthCheck::up(NAME, details),
+            false => HealthCheck::down(NAME, details),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::state::command::EntryCommand;
+use crate::state::models::{CreateStreamWithId, CreateTopicWithId};
+use crate::streaming::segments::IggyMessagesBatchMut;
+use crate::streaming::session::Session;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::systems::system::System;
+use error_set::ErrContext;
+use iggy_common::create_stream::CreateStream;
+use iggy_common::create_topic::CreateTopic;
+use iggy_common::defaults::DEFAULT_ROOT_USER_ID;
+use iggy_common::{
+    CompressionAlgorithm, Identifier, IggyError, IggyExpiry, IggyMessage, MaxTopicSize,
+    Partitioning, Sizeable,
+};
+use std::net::SocketAddr;
+use tracing::info;
+
+// The internal topics (e.g. with the system events or the logs) are written by the server itself,
+// on behalf of the root user, to a single partition, so that the messages are totally ordered.
+const INTERNAL_PARTITION_ID: u32 = 1;
+
+impl System {
+    /// Creates the stream and the topic used internally by the server, unless they already exist,
+    /// and returns the ID of the stream.
+    pub async fn create_internal_topic(
+        &mut self,
+        stream_name: &str,
+        topic_name: &str,
+        message_expiry: IggyExpiry,
+    ) -> Result<u32, IggyError> {
+        let session = internal_session();
+        let stream_id = Identifier::named(stream_name)?;
+        let topic_id = Identifier::named(topic_name)?;
+        if self.get_stream(&stream_id).is_err() {
+            let stream = self
+                .create_stream(&session, None, stream_name)
+                .await
+                .with_error_context(|error| {
+                    format!(
+                        "{COMPONENT} (error: {error}) - failed to create internal stream: {stream_name}"
+                    )
+                })?;
+            let command = EntryCommand::CreateStream(CreateStreamWithId {
+                stream_id: stream.stream_id,
+                command: CreateStream {
+                    stream_id: Some(stream.stream_id),
+                    name: stream_name.to_owned(),
+                },
+            });
+            self.state.apply(DEFAULT_ROOT_USER_ID, &command).await?;
+            info!("Created internal stream: {stream_name}.");
+        }
+
+        let stream = self.get_stream(&stream_id)?;
+        let internal_stream_id = stream.stream_id;
+        let topic_exists = stream.get_topic(&topic_id).is_ok();
+        self.internal_streams.insert(internal_stream_id);
+        if topic_exists {
+            return Ok(internal_stream_id);
+        }
+
+        let topic = self
+            .create_topic(
+                &session,
+                &stream_id,
+                None,
+                topic_name,
+                1,
+                message_expiry,
+                CompressionAlgorithm::None,
+                MaxTopicSize::ServerDefault,
+                None,
+            )
+            .await
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to create internal topic: {stream_name}/{topic_name}"
+                )
+            })?;
+        let command = EntryCommand::CreateTopic(CreateTopicWithId {
+            topic_id: topic.topic_id,
+            command: CreateTopic {
+                stream_id,
+                topic_id: Some(topic.topic_id),
+                partitions_count: 1,
+                compression_algorithm: CompressionAlgorithm::None,
+                message_expiry: topic.message_expiry,
+                max_topic_size: topic.max_topic_size,
+                replication_factor: None,
+                name: topic_name.to_owned(),
+            },
+        });
+        self.state.apply(DEFAULT_ROOT_USER_ID, &command).await?;
+        info!("Created internal topic: {stream_name}/{topic_name}.");
+        Ok(internal_stream_id)
+    }
+
+    /// Appends the messages produced by the server itself to the internal topic.
+    pub async fn append_internal_messages(
+        &self,
+        stream_name: &str,
+        topic_name: &str,
+        messages: &[IggyMessage],
+    ) -> Result<(), IggyError> {
+        if messages.is_empty() {
+            return Ok(());
+        }
+
+        let messages_size = messages
+            .iter()
+            .map(|message| message.get_size_bytes().as_bytes_u32())
+            .sum();
+        let batch = IggyMessagesBatchMut::from_messages(messages, messages_size);
+        // Resolved by name on each append, rather than by an ID cached when creating the topic.
+        let topic = self
+            .get_stream(&Identifier::named(stream_name)?)?
+            .get_topic(&Identifier::named(topic_name)?)?;
+        self.append_messages_to_topic(
+            topic,
+            &Partitioning::partition_id(INTERNAL_PARTITION_ID),
+            batch,
+            None,
+        )
+        .await
+    }
+
+    /// Fails if the stream is used internally by the server, so that the users can't modify
+    /// its data or metadata, even if they're allowed to manage all the streams.
+    pub(crate) fn ensure_not_internal_stream(&self, stream_id: u32) -> Result<(), IggyError> {
+        if self.internal_streams.contains(&stream_id) {
+            return Err(IggyError::InternalStream(stream_id));
+        }
+        Ok(())
+    }
+}
+
+fn internal_session() -> Session {
+    Session::stateless(DEFAULT_ROOT_USER_ID, SocketAddr::from(([127, 0, 0, 1], 0)))
+}
@@ -0,0 +1,74 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::streaming::session::Session;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::systems::system::System;
+use error_set::ErrContext;
+use iggy_common::{IggyDuration, IggyError, LogFilter};
+use tracing::info;
+
+impl System {
+    pub fn get_log_filter(&self, session: &Session) -> Result<LogFilter, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_log_filter(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get log filter for user ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        let Some(log_filter) = &self.log_filter else {
+            return Err(IggyError::FeatureUnavailable);
+        };
+
+        Ok(log_filter.get())
+    }
+
+    /// Changes the tracing filter without restarting the server, if the filter isn't provided, the default one is restored.
+    pub fn set_log_filter(
+        &self,
+        session: &Session,
+        filter: Option<&str>,
+        ttl: Option<IggyDuration>,
+    ) -> Result<LogFilter, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .set_log_filter(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to set log filter for user ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        let Some(log_filter) = &self.log_filter else {
+            return Err(IggyError::FeatureUnavailable);
+        };
+
+        info!(
+            "Setting log filter: {}, TTL: {} by user ID: {}",
+            filter.unwrap_or("default"),
+            ttl.map(|ttl| ttl.as_human_time_string())
+                .unwrap_or_else(|| "none".to_owned()),
+            session.get_user_id()
+        );
+        log_filter.set(filter, ttl).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to set log filter")
+        })
+    }
+}
@@ -17,15 +17,17 @@
  */
 
 use crate::binary::handlers::messages::poll_messages_handler::IggyPollMetadata;
+use crate::streaming::diagnostics::trace_links;
 use crate::streaming::segments::{IggyIndexesMut, IggyMessagesBatchMut, IggyMessagesBatchSet};
 use crate::streaming::session::Session;
 use crate::streaming::systems::COMPONENT;
 use crate::streaming::systems::system::System;
+use crate::streaming::topics::topic::Topic;
 use crate::streaming::utils::PooledBuffer;
 use error_set::ErrContext;
 use iggy_common::{
     BytesSerializable, Confirmation, Consumer, EncryptorKind, IGGY_MESSAGE_HEADER_SIZE, Identifier,
-    IggyError, Partitioning, PollingStrategy,
+    IggyError, Partitioning, PollingStrategy, StreamSizePolicy,
 };
 use tracing::{error, trace};
 
@@ -69,6 +71,13 @@ impl System {
         let (metadata, batch_set) = topic
             .get_messages(polling_consumer, partition_id, args.strategy, args.count)
             .await?;
+        self.metrics.record_polled_messages(
+            topic.stream_id,
+            topic.topic_id,
+            partition_id,
+            batch_set.count(),
+            batch_set.size() as u64,
+        );
 
         if args.auto_commit && !batch_set.is_empty() {
             let offset = batch_set
@@ -90,6 +99,7 @@ impl System {
             batch_set
         };
 
+        trace_links::link_messages(batch_set.iter());
         Ok((metadata, batch_set))
     }
 
@@ -114,7 +124,32 @@ impl System {
             topic.stream_id,
             topic.topic_id
         ))?;
+        self.ensure_not_internal_stream(topic.stream_id)?;
+        self.append_messages_to_topic(topic, partitioning, messages, confirmation)
+            .await
+    }
+
+    pub(crate) async fn append_messages_to_topic(
+        &self,
+        topic: &Topic,
+        partitioning: &Partitioning,
+        messages: IggyMessagesBatchMut,
+        confirmation: Option<Confirmation>,
+    ) -> Result<(), IggyError> {
+        let stream_id = topic.stream_id;
+        let stream = self
+            .get_stream_by_id(topic.stream_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - stream not found for stream_id: {stream_id}"
+                )
+            })?;
+        if stream.size_policy == StreamSizePolicy::RejectSends && stream.is_full() {
+            return Err(IggyError::StreamFull(stream.stream_id));
+        }
+
         let messages_count = messages.count();
+        trace_links::link_messages([&messages]);
 
         // Encrypt messages if encryptor is configured
         let messages = if let Some(encryptor) = &self.encryptor {
@@ -123,11 +158,19 @@ impl System {
             messages
         };
 
-        topic
+        let messages_size = messages.size();
+        let partition_id = topic
             .append_messages(partitioning, messages, confirmation)
             .await?;
 
         self.metrics.increment_messages(messages_count as u64);
+        self.metrics.record_appended_messages(
+            topic.stream_id,
+            topic.topic_id,
+            partition_id,
+            messages_count,
+            messages_size as u64,
+        );
         Ok(())
     }
 
@@ -17,12 +17,18 @@
  */
 
 pub mod clients;
+pub mod config;
 pub mod consumer_groups;
 pub mod consumer_offsets;
+pub mod health;
 pub mod info;
+pub mod internal_topics;
+pub mod log_filter;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod profile;
+pub mod quotas;
 pub mod segments;
 pub mod snapshot;
 pub mod stats;
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::configs::system::MetricsGranularity;
 use crate::streaming::session::Session;
 use crate::streaming::systems::COMPONENT;
 use crate::streaming::systems::system::System;
@@ -86,6 +87,7 @@ impl System {
                 topic.stream_id,
                 topic.topic_id
             ))?;
+            self.ensure_not_internal_stream(topic.stream_id)?;
         }
 
         let topic = self
@@ -96,6 +98,7 @@ impl System {
                     "{COMPONENT} (error: {error}) - failed to get mutable reference to stream with id: {stream_id}"
                 )
             })?;
+        let previous_partitions_count = topic.get_partitions_count();
         let partitions = topic
             .delete_persisted_partitions(partitions_count)
             .await
@@ -103,10 +106,20 @@ impl System {
                 format!("{COMPONENT} (error: {error}) - failed to delete persisted partitions for topic: {topic}")
             })?;
         topic.reassign_consumer_groups().await;
+        let (stream_id, topic_id) = (topic.stream_id, topic.topic_id);
+        let deleted_partition_ids = topic.get_partitions_count() + 1..=previous_partitions_count;
         if let Some(partitions) = partitions {
             self.metrics.decrement_partitions(partitions_count);
             self.metrics.decrement_segments(partitions.segments_count);
             self.metrics.decrement_messages(partitions.messages_count);
+            for partition_id in deleted_partition_ids {
+                self.metrics.remove_resource(
+                    MetricsGranularity::Partition,
+                    stream_id,
+                    topic_id,
+                    partition_id,
+                );
+            }
         }
         Ok(())
     }
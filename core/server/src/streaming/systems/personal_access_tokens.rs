@@ -20,6 +20,7 @@ use crate::streaming::personal_access_tokens::personal_access_token::PersonalAcc
 use crate::streaming::session::Session;
 use crate::streaming::systems::COMPONENT;
 use crate::streaming::systems::system::System;
+use crate::streaming::users::login_limiter::LoginSource;
 use crate::streaming::users::user::User;
 use error_set::ErrContext;
 use iggy_common::IggyError;
@@ -130,7 +131,14 @@ impl System {
         };
 
         info!("Deleting personal access token: {name} for user with ID: {user_id}...");
-        user.personal_access_tokens.remove(&token);
+        let personal_access_token = user.personal_access_tokens.remove(&token);
+        if let Some((_, personal_access_token)) = personal_access_token {
+            self.quota_limiter.set_personal_access_token_quota(
+                user_id,
+                &personal_access_token.name,
+                None,
+            );
+        }
         info!("Deleted personal access token: {name} for user with ID: {user_id}.");
         Ok(())
     }
@@ -138,8 +146,10 @@ impl System {
     pub async fn login_with_personal_access_token(
         &self,
         token: &str,
+        source: LoginSource,
         session: Option<&Session>,
     ) -> Result<&User, IggyError> {
+        self.login_limiter.register_attempt(source)?;
         let token_hash = PersonalAccessToken::hash_token(token);
         let mut personal_access_token = None;
         for user in self.users.values() {
@@ -174,7 +184,15 @@ impl System {
                     personal_access_token.user_id
                 )
             })?;
-        self.login_user_with_credentials(&user.username, None, session)
-            .await
+        let name = personal_access_token.name.clone();
+        drop(personal_access_token);
+        let user = self
+            .login_user_with_credentials(&user.username, None, session)
+            .await?;
+        if let Some(session) = session {
+            self.quota_limiter
+                .bind_personal_access_token(session.client_id, &name);
+        }
+        Ok(user)
     }
 }
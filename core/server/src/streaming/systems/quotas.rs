@@ -0,0 +1,119 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::streaming::session::Session;
+use crate::streaming::systems::COMPONENT;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::quota_limiter::QuotaDirection;
+use error_set::ErrContext;
+use iggy_common::Identifier;
+use iggy_common::IggyError;
+use iggy_common::ThroughputQuota;
+use std::time::Duration;
+use tracing::{error, info};
+
+impl System {
+    pub async fn update_user_quota(
+        &mut self,
+        session: &Session,
+        user_id: &Identifier,
+        quota: Option<ThroughputQuota>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .update_quota(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to update quota for user with id: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        let user = self.get_user_mut(user_id).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}"
+            )
+        })?;
+        user.quota = quota;
+        let id = user.id;
+        info!("Updated quota for user: {} with ID: {id}.", user.username);
+        self.quota_limiter.set_user_quota(id, quota);
+        Ok(())
+    }
+
+    pub async fn update_personal_access_token_quota(
+        &mut self,
+        session: &Session,
+        user_id: &Identifier,
+        name: &str,
+        quota: Option<ThroughputQuota>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .update_quota(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to update quota for user with id: {}",
+                    session.get_user_id()
+                )
+            })?;
+
+        let user = self.get_user_mut(user_id).with_error_context(|error| {
+            format!(
+                "{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}"
+            )
+        })?;
+        let id = user.id;
+        let Some(mut personal_access_token) = user
+            .personal_access_tokens
+            .iter_mut()
+            .find(|pat| pat.name.as_str() == name)
+        else {
+            error!("Personal access token: {name} for user with ID: {id} does not exist.");
+            return Err(IggyError::ResourceNotFound(name.to_owned()));
+        };
+
+        personal_access_token.quota = quota;
+        let name = personal_access_token.name.clone();
+        drop(personal_access_token);
+        self.quota_limiter
+            .set_personal_access_token_quota(id, &name, quota);
+        info!("Updated quota for personal access token: {name} for user with ID: {id}.");
+        Ok(())
+    }
+
+    /// Records the throughput of the client against its quota and returns the time
+    /// by which the response should be delayed, if the quota has been exceeded.
+    pub fn record_throughput(
+        &self,
+        session: &Session,
+        direction: QuotaDirection,
+        bytes: u64,
+        messages: u64,
+    ) -> Option<Duration> {
+        let usage = self.quota_limiter.record(
+            session.client_id,
+            session.get_user_id(),
+            direction,
+            bytes,
+            messages,
+        )?;
+        self.metrics.record_quota_usage(direction, &usage);
+        usage.throttle_time
+    }
+}
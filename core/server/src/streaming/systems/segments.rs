@@ -48,6 +48,7 @@ impl System {
                 topic.stream_id,
                 topic.topic_id
             ))?;
+            self.ensure_not_internal_stream(topic.stream_id)?;
         }
 
         let topic = self
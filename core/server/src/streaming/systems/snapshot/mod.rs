@@ -19,15 +19,20 @@
 mod procdump;
 
 use crate::configs::system::SystemConfig;
+use crate::streaming::diagnostics::profiler;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use async_zip::tokio::write::ZipFileWriter;
 use async_zip::{Compression, ZipEntryBuilder};
-use iggy_common::{IggyDuration, IggyError, Snapshot, SnapshotCompression, SystemSnapshotType};
+use iggy_common::locking::IggySharedMutFn;
+use iggy_common::{
+    IggyDuration, IggyError, ProfileFormat, ProfileKind, Snapshot, SnapshotCompression,
+    SystemSnapshotType,
+};
 use std::io::Cursor;
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tempfile::NamedTempFile;
 use tokio::fs::File;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
@@ -35,13 +40,15 @@ use tokio::process::Command;
 use tokio_util::compat::TokioAsyncWriteCompatExt;
 use tracing::{error, info};
 
+const CPU_PROFILE_DURATION: Duration = Duration::from_secs(5);
+
 impl System {
-    pub async fn get_snapshot(
+    /// Resolves the requested snapshot types and checks whether the user can get them.
+    pub fn ensure_can_get_snapshot(
         &self,
         session: &Session,
-        compression: SnapshotCompression,
-        snapshot_types: &Vec<SystemSnapshotType>,
-    ) -> Result<Snapshot, IggyError> {
+        snapshot_types: &[SystemSnapshotType],
+    ) -> Result<Vec<SystemSnapshotType>, IggyError> {
         self.ensure_authenticated(session)?;
 
         let snapshot_types = if snapshot_types.contains(&SystemSnapshotType::All) {
@@ -49,11 +56,26 @@ impl System {
                 error!("When using 'All' snapshot type, no other types can be specified");
                 return Err(IggyError::InvalidCommand);
             }
-            &SystemSnapshotType::all_snapshot_types()
+            SystemSnapshotType::all_snapshot_types()
         } else {
-            snapshot_types
+            snapshot_types.to_vec()
         };
 
+        if snapshot_types.contains(&SystemSnapshotType::CpuProfile) {
+            self.ensure_can_get_profile(session)?;
+        }
+
+        Ok(snapshot_types)
+    }
+
+    /// Creates the snapshot of the resolved types, including the CPU profile captured beforehand
+    /// using `capture_cpu_profile`, without holding the system lock.
+    pub async fn get_snapshot(
+        &self,
+        compression: SnapshotCompression,
+        snapshot_types: &[SystemSnapshotType],
+        mut cpu_profile: Option<NamedTempFile>,
+    ) -> Result<Snapshot, IggyError> {
         let cursor = Cursor::new(Vec::new());
         let mut zip_writer = ZipFileWriter::new(cursor.compat_write());
 
@@ -70,9 +92,19 @@ impl System {
         let now = Instant::now();
 
         for snapshot_type in snapshot_types {
-            match get_command_result(snapshot_type, self.config.clone()).await {
+            let result = match snapshot_type {
+                SystemSnapshotType::Clients => self.get_clients_overview().await,
+                SystemSnapshotType::CpuProfile => cpu_profile
+                    .take()
+                    .ok_or_else(|| std::io::Error::other("CPU profile has not been captured")),
+                _ => get_command_result(snapshot_type, self.config.clone()).await,
+            };
+            match result {
                 Ok(temp_file) => {
-                    let filename = format!("{snapshot_type}.txt");
+                    let filename = match snapshot_type {
+                        SystemSnapshotType::CpuProfile => format!("{snapshot_type}.svg"),
+                        _ => format!("{snapshot_type}.txt"),
+                    };
                     let entry = ZipEntryBuilder::new(filename.clone().into(), compression);
 
                     let mut file = File::open(temp_file.path()).await.map_err(|e| {
@@ -124,6 +156,37 @@ impl System {
         info!("Final zip size: {} bytes", zip_data.len());
         Ok(Snapshot::new(zip_data))
     }
+
+    async fn get_clients_overview(&self) -> Result<NamedTempFile, std::io::Error> {
+        let temp_file = NamedTempFile::new()?;
+        let mut file = File::from_std(temp_file.as_file().try_clone()?);
+
+        let clients = self.client_manager.read().await.get_clients();
+        file.write_all(format!("=== Connected clients ({}) ===\n", clients.len()).as_bytes())
+            .await?;
+        for client in clients {
+            let client = client.read().await;
+            let metadata = client
+                .metadata
+                .as_ref()
+                .map(|metadata| metadata.to_string())
+                .unwrap_or_else(|| "-".to_string());
+            let line = format!(
+                "client_id: {}, user_id: {}, address: {}, transport: {}, metadata: {metadata}\n",
+                client.session.client_id,
+                client
+                    .user_id
+                    .map(|user_id| user_id.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                client.session.ip_address,
+                client.transport,
+            );
+            file.write_all(line.as_bytes()).await?;
+        }
+        file.flush().await?;
+
+        Ok(temp_file)
+    }
 }
 
 async fn write_command_output_to_temp_file(
@@ -187,6 +250,37 @@ async fn get_server_config(config: Arc<SystemConfig>) -> Result<NamedTempFile, s
     write_command_output_to_temp_file(Command::new("cat").arg(config_path)).await
 }
 
+/// Captures the CPU profile if it's one of the snapshot types, which takes a few seconds,
+/// thus it should be called without holding the system lock.
+pub async fn capture_cpu_profile(snapshot_types: &[SystemSnapshotType]) -> Option<NamedTempFile> {
+    if !snapshot_types.contains(&SystemSnapshotType::CpuProfile) {
+        return None;
+    }
+
+    match get_cpu_profile().await {
+        Ok(cpu_profile) => Some(cpu_profile),
+        Err(error) => {
+            error!("Failed to capture CPU profile for snapshot: {error}");
+            None
+        }
+    }
+}
+
+async fn get_cpu_profile() -> Result<NamedTempFile, std::io::Error> {
+    let profile = profiler::capture(
+        ProfileKind::Cpu,
+        ProfileFormat::Flamegraph,
+        IggyDuration::new(CPU_PROFILE_DURATION),
+    )
+    .await
+    .map_err(std::io::Error::other)?;
+    let temp_file = NamedTempFile::new()?;
+    let mut file = File::from_std(temp_file.as_file().try_clone()?);
+    file.write_all(&profile.0).await?;
+    file.flush().await?;
+    Ok(temp_file)
+}
+
 async fn get_command_result(
     snapshot_type: &SystemSnapshotType,
     config: Arc<SystemConfig>,
@@ -198,6 +292,12 @@ async fn get_command_result(
         SystemSnapshotType::Test => get_test_snapshot().await,
         SystemSnapshotType::ServerLogs => get_server_logs(config).await,
         SystemSnapshotType::ServerConfig => get_server_config(config).await,
+        SystemSnapshotType::Clients | SystemSnapshotType::CpuProfile => {
+            // This should not be reached (clients and profile are captured at the call site)
+            unreachable!(
+                "SystemSnapshotType::{snapshot_type:?} should be handled before calling get_command_result()."
+            )
+        }
         SystemSnapshotType::All => {
             // This should not be reached (we filter out `All`` at the call site)
             unreachable!(
@@ -17,11 +17,17 @@
  */
 
 use crate::VERSION;
+use crate::streaming::diagnostics::metrics::PartitionStorage;
+use crate::streaming::diagnostics::stats_history::{StatsSnapshot, TopicCounters};
+use crate::streaming::session::Session;
+use crate::streaming::systems::COMPONENT;
 use crate::streaming::systems::system::System;
 use crate::versioning::SemanticVersion;
+use error_set::ErrContext;
 use iggy_common::locking::IggySharedMutFn;
-use iggy_common::{IggyDuration, IggyError, Stats};
+use iggy_common::{IggyDuration, IggyError, IggyTimestamp, Stats, StatsHistory};
 use std::sync::OnceLock;
+use std::sync::atomic::Ordering;
 use sysinfo::{Pid, ProcessesToUpdate, System as SysinfoSystem};
 use tokio::sync::Mutex;
 
@@ -108,6 +114,83 @@ impl System {
                 .sum::<u32>();
         }
 
+        let (throttled_requests_count, throttle_time) = self.quota_limiter.get_throttling_stats();
+        stats.throttled_requests_count = throttled_requests_count;
+        stats.throttle_time = IggyDuration::new(throttle_time);
         Ok(stats)
     }
+
+    pub fn get_stats_history(&self, session: &Session) -> Result<StatsHistory, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .get_stats_history(session.get_user_id())
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - permission denied to get stats history for user ID: {}",
+                    session.get_user_id()
+                )
+            })?;
+        let Some(stats_history) = &self.stats_history else {
+            return Err(IggyError::FeatureUnavailable);
+        };
+
+        Ok(stats_history.get())
+    }
+
+    /// Samples the stats along with the number of messages appended to each topic, if the stats history is enabled.
+    pub async fn record_stats_history(&self) -> Result<(), IggyError> {
+        let Some(stats_history) = &self.stats_history else {
+            return Ok(());
+        };
+
+        let stats = self.get_stats().await?;
+        let mut topics = Vec::new();
+        for stream in self.streams.values() {
+            for topic in stream.topics.values() {
+                // The offsets only grow, unlike the messages count, which drops once the segments are deleted.
+                let mut messages_count = 0;
+                for partition in topic.partitions.values() {
+                    let partition = partition.read().await;
+                    if partition.should_increment_offset {
+                        messages_count += partition.current_offset + 1;
+                    }
+                }
+                topics.push(TopicCounters {
+                    stream_id: topic.stream_id,
+                    topic_id: topic.topic_id,
+                    messages_count,
+                    size_bytes: topic.size_bytes.load(Ordering::SeqCst),
+                });
+            }
+        }
+
+        stats_history.record(&StatsSnapshot {
+            timestamp: IggyTimestamp::now(),
+            stats,
+            topics,
+        });
+        Ok(())
+    }
+
+    /// Updates the storage gauges of the metrics with the current state of the partitions.
+    pub async fn refresh_storage_metrics(&self) {
+        let mut partitions = Vec::new();
+        for stream in self.streams.values() {
+            for topic in stream.topics.values() {
+                for partition in topic.partitions.values() {
+                    let partition = partition.read().await;
+                    partitions.push(PartitionStorage {
+                        stream_id: partition.stream_id,
+                        topic_id: partition.topic_id,
+                        partition_id: partition.partition_id,
+                        current_offset: partition.current_offset,
+                        segments_count: partition.get_segments_count(),
+                        size_bytes: partition.size_bytes.load(Ordering::SeqCst),
+                    });
+                }
+            }
+        }
+
+        self.metrics.record_storage(&partitions);
+    }
 }
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::configs::system::MetricsGranularity;
 use crate::state::system::StreamState;
 use crate::streaming::session::Session;
 use crate::streaming::streams::stream::Stream;
@@ -25,7 +26,7 @@ use ahash::{AHashMap, AHashSet};
 use error_set::ErrContext;
 use futures::future::try_join_all;
 use iggy_common::locking::IggySharedMutFn;
-use iggy_common::{IdKind, Identifier, IggyError};
+use iggy_common::{IdKind, Identifier, IggyError, IggyExpiry, MaxTopicSize, StreamSizePolicy};
 use std::cell::RefCell;
 use std::sync::atomic::{AtomicU32, Ordering};
 use tokio::fs;
@@ -75,6 +76,9 @@ impl System {
                 self.storage.clone(),
             );
             stream.created_at = stream_state.created_at;
+            stream.max_size = stream_state.max_size;
+            stream.message_expiry = stream_state.message_expiry;
+            stream.size_policy = stream_state.size_policy;
             unloaded_streams.push(stream);
         }
 
@@ -101,12 +105,15 @@ impl System {
                 for stream_id in missing_ids.iter() {
                     let stream_id = *stream_id;
                     let stream_state = streams.iter().find(|s| s.id == stream_id).unwrap();
-                    let stream = Stream::create(
+                    let mut stream = Stream::create(
                         stream_id,
                         &stream_state.name,
                         self.config.clone(),
                         self.storage.clone(),
                     );
+                    stream.max_size = stream_state.max_size;
+                    stream.message_expiry = stream_state.message_expiry;
+                    stream.size_policy = stream_state.size_policy;
                     stream.persist().await?;
                     unloaded_streams.push(stream);
                     info!(
@@ -262,7 +269,7 @@ impl System {
         self.get_stream_by_id(*stream_id.unwrap())
     }
 
-    fn get_stream_by_id(&self, stream_id: u32) -> Result<&Stream, IggyError> {
+    pub(crate) fn get_stream_by_id(&self, stream_id: u32) -> Result<&Stream, IggyError> {
         let stream = self.streams.get(&stream_id);
         if stream.is_none() {
             return Err(IggyError::StreamIdNotFound(stream_id));
@@ -360,6 +367,7 @@ impl System {
                     stream_id
                 )
             })?;
+        self.ensure_not_internal_stream(stream_id)?;
 
         {
             if let Some(stream_id_by_name) = self.streams_ids.get(name) {
@@ -388,6 +396,48 @@ impl System {
         Ok(())
     }
 
+    pub async fn update_stream_retention(
+        &mut self,
+        session: &Session,
+        id: &Identifier,
+        max_size: MaxTopicSize,
+        message_expiry: IggyExpiry,
+        size_policy: StreamSizePolicy,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let stream_id;
+        {
+            let stream = self.get_stream(id).with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get stream with ID: {id}")
+            })?;
+            stream_id = stream.stream_id;
+        }
+
+        self.permissioner
+            .update_stream(session.get_user_id(), stream_id)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - failed to update stream retention, user ID: {}, stream ID: {}",
+                    session.get_user_id(),
+                    stream_id
+                )
+            })?;
+        self.ensure_not_internal_stream(stream_id)?;
+
+        let max_size = Stream::get_max_size(max_size, &self.config)?;
+        let message_expiry = Stream::get_message_expiry(message_expiry);
+        let stream = self.get_stream_mut(id).with_error_context(|error| {
+            format!("{COMPONENT} (error: {error}) - failed to get mutable reference to stream with id: {id}")
+        })?;
+        stream.max_size = max_size;
+        stream.message_expiry = message_expiry;
+        stream.size_policy = size_policy;
+        info!(
+            "Stream with ID '{id}' retention updated, max size: {max_size}, message expiry: {message_expiry}, size policy: {size_policy}."
+        );
+        Ok(())
+    }
+
     pub async fn delete_stream(
         &mut self,
         session: &Session,
@@ -407,12 +457,23 @@ impl System {
                     stream.stream_id,
                 )
             })?;
+        self.ensure_not_internal_stream(stream_id)?;
         let stream_name = stream.name.clone();
         if stream.delete().await.is_err() {
             return Err(IggyError::CannotDeleteStream(stream_id));
         }
 
         self.metrics.decrement_streams(1);
+        for topic in stream.topics.values() {
+            for partition_id in topic.partitions.keys() {
+                self.metrics.remove_resource(
+                    MetricsGranularity::Stream,
+                    stream_id,
+                    topic.topic_id,
+                    *partition_id,
+                );
+            }
+        }
         self.metrics.decrement_topics(stream.get_topics_count());
         self.metrics
             .decrement_partitions(stream.get_partitions_count());
@@ -449,6 +510,7 @@ impl System {
                     stream.stream_id,
                 )
             })?;
+        self.ensure_not_internal_stream(stream.stream_id)?;
         stream.purge().await
     }
 }
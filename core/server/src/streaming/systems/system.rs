@@ -17,23 +17,31 @@
  */
 
 use crate::archiver::{ArchiverKind, ArchiverKindType};
-use crate::configs::server::{DataMaintenanceConfig, PersonalAccessTokenConfig};
+use crate::configs::reload::ConfigReloader;
+use crate::configs::server::{DataMaintenanceConfig, HealthConfig, PersonalAccessTokenConfig};
 use crate::configs::system::SystemConfig;
+use crate::http::jwt::jwt_manager::JwtManager;
+use crate::log::runtime_filter::RuntimeLogFilter;
 use crate::map_toggle_str;
 use crate::state::StateKind;
 use crate::state::file::FileState;
 use crate::state::system::SystemState;
 use crate::streaming::clients::client_manager::ClientManager;
 use crate::streaming::diagnostics::metrics::Metrics;
+use crate::streaming::diagnostics::startup;
+use crate::streaming::diagnostics::stats_history::StatsHistoryRecorder;
 use crate::streaming::persistence::persister::*;
 use crate::streaming::session::Session;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::COMPONENT;
+use crate::streaming::systems::health::HealthChecker;
+use crate::streaming::users::login_limiter::LoginLimiter;
 use crate::streaming::users::permissioner::Permissioner;
+use crate::streaming::users::quota_limiter::QuotaLimiter;
 use crate::streaming::users::user::User;
 use crate::versioning::SemanticVersion;
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
 use error_set::ErrContext;
 use iggy_common::locking::IggySharedMut;
 use iggy_common::locking::IggySharedMutFn;
@@ -48,15 +56,23 @@ use tracing::{error, info, instrument, trace};
 #[derive(Debug)]
 pub struct SharedSystem {
     system: Arc<RwLock<System>>,
+    health_checker: Arc<HealthChecker>,
 }
 
 impl SharedSystem {
-    pub fn new(system: System) -> SharedSystem {
+    pub fn new(system: System, health_config: HealthConfig) -> SharedSystem {
+        let health_checker = HealthChecker::new(health_config, system.config.get_system_path());
         SharedSystem {
             system: Arc::new(RwLock::new(system)),
+            health_checker: Arc::new(health_checker),
         }
     }
 
+    /// Returns the health checker, available without acquiring the system lock.
+    pub(crate) fn health_checker(&self) -> &HealthChecker {
+        &self.health_checker
+    }
+
     pub async fn read(&self) -> RwLockReadGuard<System> {
         self.system.read().await
     }
@@ -70,6 +86,7 @@ impl Clone for SharedSystem {
     fn clone(&self) -> Self {
         SharedSystem {
             system: self.system.clone(),
+            health_checker: self.health_checker.clone(),
         }
     }
 }
@@ -80,13 +97,20 @@ pub struct System {
     pub(crate) storage: Arc<SystemStorage>,
     pub(crate) streams: AHashMap<u32, Stream>,
     pub(crate) streams_ids: AHashMap<String, u32>,
+    pub(crate) internal_streams: AHashSet<u32>,
     pub(crate) users: AHashMap<UserId, User>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) client_manager: IggySharedMut<ClientManager>,
     pub(crate) encryptor: Option<Arc<EncryptorKind>>,
     pub(crate) metrics: Metrics,
+    pub(crate) stats_history: Option<StatsHistoryRecorder>,
     pub(crate) state: Arc<StateKind>,
     pub(crate) archiver: Option<Arc<ArchiverKind>>,
+    pub(crate) login_limiter: LoginLimiter,
+    pub(crate) quota_limiter: QuotaLimiter,
+    pub(crate) jwt_manager: Option<Arc<JwtManager>>,
+    pub config_reloader: Option<Arc<ConfigReloader>>,
+    pub log_filter: Option<Arc<RuntimeLogFilter>>,
     pub personal_access_token: PersonalAccessTokenConfig,
 }
 
@@ -168,19 +192,38 @@ impl System {
             None
         };
 
+        let login_limiter = LoginLimiter::new(
+            &system_config.authentication.lockout,
+            &system_config.authentication.rate_limit,
+        );
+
+        let quota_limiter = QuotaLimiter::new(&system_config.quota);
+        let metrics = Metrics::init(&system_config.metrics);
+        let stats_history = system_config
+            .stats_history
+            .enabled
+            .then(|| StatsHistoryRecorder::new(&system_config.stats_history));
+
         System {
             config: system_config,
             streams: AHashMap::new(),
             streams_ids: AHashMap::new(),
+            internal_streams: AHashSet::new(),
             storage: Arc::new(storage),
             encryptor,
             client_manager: IggySharedMut::new(ClientManager::default()),
             permissioner: Permissioner::default(),
-            metrics: Metrics::init(),
+            metrics,
+            stats_history,
             users: AHashMap::new(),
             state,
             personal_access_token: pat_config,
             archiver,
+            login_limiter,
+            quota_limiter,
+            jwt_manager: None,
+            config_reloader: None,
+            log_filter: None,
         }
     }
 
@@ -223,6 +266,13 @@ impl System {
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to initialize system state")
             })?;
+        let partitions_count = system_state
+            .streams
+            .values()
+            .flat_map(|stream| stream.topics.values())
+            .map(|topic| topic.partitions.len() as u32)
+            .sum();
+        startup::state_loaded(partitions_count);
         let now = Instant::now();
         self.load_version().await.with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to load version")
@@ -237,6 +287,7 @@ impl System {
             .with_error_context(|error| {
                 format!("{COMPONENT} (error: {error}) - failed to load streams")
             })?;
+        startup::streams_loaded();
         if let Some(archiver) = self.archiver.as_ref() {
             archiver
                 .init()
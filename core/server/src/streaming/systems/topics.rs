@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::configs::system::MetricsGranularity;
 use crate::streaming::session::Session;
 use crate::streaming::systems::COMPONENT;
 use crate::streaming::systems::system::System;
@@ -199,6 +200,7 @@ impl System {
                     topic.topic_id,
                 )
             })?;
+            self.ensure_not_internal_stream(topic.stream_id)?;
         }
 
         self.get_stream_mut(stream_id)?
@@ -254,6 +256,7 @@ impl System {
                     session.get_user_id(),
                 )
             })?;
+            self.ensure_not_internal_stream(topic.stream_id)?;
             stream_id_value = topic.stream_id;
         }
 
@@ -264,6 +267,14 @@ impl System {
             .with_error_context(|error| format!("{COMPONENT} (error: {error}) - failed to delete topic with ID: {topic_id} in stream with ID: {stream_id}"))?;
 
         self.metrics.decrement_topics(1);
+        for partition_id in topic.partitions.keys() {
+            self.metrics.remove_resource(
+                MetricsGranularity::Topic,
+                topic.stream_id,
+                topic.topic_id,
+                *partition_id,
+            );
+        }
         self.metrics
             .decrement_partitions(topic.get_partitions_count());
         self.metrics.decrement_messages(topic.get_messages_count());
@@ -295,6 +306,7 @@ impl System {
                     session.get_user_id(),
                 )
             })?;
+        self.ensure_not_internal_stream(topic.stream_id)?;
         topic.purge().await.with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to purge topic with ID: {topic_id} in stream with ID: {stream_id}")
         })
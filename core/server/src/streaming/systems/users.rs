@@ -19,10 +19,12 @@
 use crate::state::command::EntryCommand;
 use crate::state::models::CreateUserWithId;
 use crate::state::system::UserState;
+use crate::streaming::clients::client_manager::AuthenticationMethod;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::session::Session;
 use crate::streaming::systems::COMPONENT;
 use crate::streaming::systems::system::System;
+use crate::streaming::users::login_limiter::LoginSource;
 use crate::streaming::users::user::User;
 use crate::streaming::utils::crypto;
 use crate::{IGGY_ROOT_PASSWORD_ENV, IGGY_ROOT_USERNAME_ENV};
@@ -30,10 +32,11 @@ use error_set::ErrContext;
 use iggy_common::IggyError;
 use iggy_common::Permissions;
 use iggy_common::UserStatus;
+use iggy_common::change_password::ChangePassword;
 use iggy_common::create_user::CreateUser;
 use iggy_common::defaults::*;
 use iggy_common::locking::IggySharedMutFn;
-use iggy_common::{IdKind, Identifier};
+use iggy_common::{IdKind, Identifier, UserId};
 use std::env;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -81,19 +84,25 @@ impl System {
             );
 
             user.created_at = user_state.created_at;
+            user.quota = user_state.quota;
+            self.quota_limiter.set_user_quota(user.id, user.quota);
             user.personal_access_tokens = user_state
                 .personal_access_tokens
                 .into_values()
                 .map(|token| {
-                    (
-                        Arc::new(token.token_hash.clone()),
-                        PersonalAccessToken::raw(
-                            user_state.id,
-                            &token.name,
-                            &token.token_hash,
-                            token.expiry_at,
-                        ),
-                    )
+                    let mut personal_access_token = PersonalAccessToken::raw(
+                        user_state.id,
+                        &token.name,
+                        &token.token_hash,
+                        token.expiry_at,
+                    );
+                    personal_access_token.quota = token.quota;
+                    self.quota_limiter.set_personal_access_token_quota(
+                        user_state.id,
+                        &personal_access_token.name,
+                        token.quota,
+                    );
+                    (Arc::new(token.token_hash.clone()), personal_access_token)
                 })
                 .collect();
             self.users.insert(user_state.id, user);
@@ -243,6 +252,16 @@ impl System {
             return Err(IggyError::UsersLimitReached);
         }
 
+        self.config
+            .authentication
+            .password_policy
+            .verify(password)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - password policy violated for user: {username}"
+                )
+            })?;
+
         let user_id = USER_ID.fetch_add(1, Ordering::SeqCst);
         info!("Creating user: {username} with ID: {user_id}...");
         let user = User::new(user_id, username, password, status, permissions.clone());
@@ -293,6 +312,7 @@ impl System {
             .ok_or(IggyError::ResourceNotFound(user_id.to_string()))?;
         self.permissioner
             .delete_permissions_for_user(existing_user_id);
+        self.quota_limiter.remove_user(existing_user_id);
         let mut client_manager = self.client_manager.write().await;
         client_manager
             .delete_clients_for_user(existing_user_id)
@@ -409,19 +429,29 @@ impl System {
             if user.id != session_user_id {
                 self.permissioner.change_password(session_user_id)?;
             }
+
+            if !crypto::verify_password(current_password, &user.password) {
+                error!(
+                    "Invalid current password for user: {} with ID: {user_id}.",
+                    user.username
+                );
+                return Err(IggyError::InvalidCredentials);
+            }
         }
 
+        self.config
+            .authentication
+            .password_policy
+            .verify(new_password)
+            .with_error_context(|error| {
+                format!(
+                    "{COMPONENT} (error: {error}) - password policy violated for user with ID: {user_id}"
+                )
+            })?;
+
         let user = self.get_user_mut(user_id).with_error_context(|error| {
             format!("{COMPONENT} (error: {error}) - failed to get mutable reference to the user with id: {user_id}")
         })?;
-        if !crypto::verify_password(current_password, &user.password) {
-            error!(
-                "Invalid current password for user: {} with ID: {user_id}.",
-                user.username
-            );
-            return Err(IggyError::InvalidCredentials);
-        }
-
         user.password = crypto::hash_password(new_password);
         info!(
             "Changed password for user: {} with ID: {user_id}.",
@@ -434,17 +464,104 @@ impl System {
         &self,
         username: &str,
         password: &str,
+        source: LoginSource,
         session: Option<&Session>,
     ) -> Result<&User, IggyError> {
+        self.login_limiter.register_attempt(source)?;
         self.login_user_with_credentials(username, Some(password), session)
             .await
     }
 
+    /// Returns `true` if the user's password hash was produced by a legacy algorithm
+    /// and should be replaced with the default one on the successful login.
+    pub fn should_rehash_password(&self, user: &User) -> bool {
+        self.config.authentication.rehash_on_login && crypto::needs_rehash(&user.password)
+    }
+
+    /// Replaces the legacy password hash of the user with the default one and persists it in the state.
+    /// Any failure is only logged, as it must not affect the already successful login.
+    pub async fn rehash_password(&mut self, user_id: UserId, password: &str) {
+        let Some(user) = self.users.get_mut(&user_id) else {
+            return;
+        };
+
+        let password_hash = crypto::hash_password(password);
+        user.password = password_hash.clone();
+        let username = user.username.clone();
+        let command = EntryCommand::ChangePassword(ChangePassword {
+            user_id: Identifier::numeric(user_id).expect("Invalid user ID"),
+            current_password: "".into(),
+            new_password: password_hash,
+        });
+        if let Err(error) = self.state.apply(user_id, &command).await {
+            error!(
+                "{COMPONENT} (error: {error}) - failed to apply rehashed password for user: {username} with ID: {user_id}"
+            );
+            return;
+        }
+
+        info!("Rehashed password for user: {username} with ID: {user_id}.");
+    }
+
     pub async fn login_user_with_credentials(
         &self,
         username: &str,
         password: Option<&str>,
         session: Option<&Session>,
+    ) -> Result<&User, IggyError> {
+        let authentication_method = match password {
+            Some(_) => AuthenticationMethod::Password,
+            None => AuthenticationMethod::PersonalAccessToken,
+        };
+        self.login_user_with_method(username, password, session, authentication_method)
+            .await
+    }
+
+    /// Signs in the user mapped from the peer credentials of a Unix domain socket connection.
+    /// The kernel-provided UID is trusted, so no password is verified.
+    pub async fn login_user_with_peer_credentials(
+        &self,
+        username: &str,
+        session: &Session,
+    ) -> Result<&User, IggyError> {
+        self.login_user_with_method(
+            username,
+            None,
+            Some(session),
+            AuthenticationMethod::PeerCredentials,
+        )
+        .await
+    }
+
+    /// Signs in the user identified by the already verified JWT, so that the long-lived HTTP
+    /// request can be bound to the client session (e.g. to join the consumer group).
+    pub async fn login_user_with_jwt(
+        &self,
+        user_id: UserId,
+        session: &Session,
+    ) -> Result<&User, IggyError> {
+        let username = self
+            .get_user(&Identifier::numeric(user_id)?)
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to get user with ID: {user_id}")
+            })?
+            .username
+            .clone();
+        self.login_user_with_method(
+            &username,
+            None,
+            Some(session),
+            AuthenticationMethod::JsonWebToken,
+        )
+        .await
+    }
+
+    async fn login_user_with_method(
+        &self,
+        username: &str,
+        password: Option<&str>,
+        session: Option<&Session>,
+        authentication_method: AuthenticationMethod,
     ) -> Result<&User, IggyError> {
         let user = match self.get_user(&username.try_into()?) {
             Ok(user) => user,
@@ -461,13 +578,16 @@ impl System {
         }
 
         if let Some(password) = password {
+            self.login_limiter.ensure_not_locked(user.id)?;
             if !crypto::verify_password(password, &user.password) {
                 warn!(
                     "Invalid password for user: {username} with ID: {}.",
                     user.id
                 );
+                self.login_limiter.register_failure(user.id);
                 return Err(IggyError::InvalidCredentials);
             }
+            self.login_limiter.register_success(user.id);
         }
 
         info!("Logged in user: {username} with ID: {}.", user.id);
@@ -488,7 +608,7 @@ impl System {
         session.set_user_id(user.id);
         let mut client_manager = self.client_manager.write().await;
         client_manager
-            .set_user_id(session.client_id, user.id)
+            .set_user_id(session.client_id, user.id, authentication_method)
             .await
             .with_error_context(|error| {
                 format!(
@@ -516,6 +636,7 @@ impl System {
         if session.client_id > 0 {
             let mut client_manager = self.client_manager.write().await;
             client_manager.clear_user_id(session.client_id).await?;
+            self.quota_limiter.unbind_client(session.client_id);
             info!(
                 "Cleared user ID: {} for client: {}.",
                 user.id, session.client_id
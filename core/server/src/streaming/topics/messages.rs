@@ -76,12 +76,13 @@ impl Topic {
         Ok((metadata, messages))
     }
 
+    /// Appends the messages to the partition resolved from the partitioning and returns its ID.
     pub async fn append_messages(
         &self,
         partitioning: &Partitioning,
         messages: IggyMessagesBatchMut,
         confirmation: Option<Confirmation>,
-    ) -> Result<(), IggyError> {
+    ) -> Result<u32, IggyError> {
         if !self.has_partitions() {
             return Err(IggyError::NoPartitions(self.topic_id, self.stream_id));
         }
@@ -92,10 +93,6 @@ impl Topic {
             return Err(IggyError::TopicFull(self.topic_id, self.stream_id));
         }
 
-        if messages.is_empty() {
-            return Ok(());
-        }
-
         let partition_id = match partitioning.kind {
             PartitioningKind::Balanced => self.get_next_partition_id(),
             PartitioningKind::PartitionId => u32::from_le_bytes(
@@ -108,8 +105,13 @@ impl Topic {
             }
         };
 
+        if messages.is_empty() {
+            return Ok(partition_id);
+        }
+
         self.append_messages_to_partition(messages, partition_id, confirmation)
-            .await
+            .await?;
+        Ok(partition_id)
     }
 
     pub async fn flush_unsaved_buffer(
@@ -180,15 +182,22 @@ impl Topic {
         partition_id
     }
 
+    /// Returns the start offsets of the expired segments per partition. The topic's own
+    /// message expiry takes precedence, `default_message_expiry` (inherited from the stream)
+    /// is used only when the topic uses the server default one.
     pub async fn get_expired_segments_start_offsets_per_partition(
         &self,
+        default_message_expiry: IggyExpiry,
         now: IggyTimestamp,
     ) -> AHashMap<u32, Vec<u64>> {
         let mut expired_segments = AHashMap::new();
-        if let IggyExpiry::ExpireDuration(_) = self.message_expiry {
+        let message_expiry = self.get_effective_message_expiry(default_message_expiry);
+        if let IggyExpiry::ExpireDuration(_) = message_expiry {
             for (_, partition) in self.partitions.iter() {
                 let partition = partition.read().await;
-                let segments = partition.get_expired_segments_start_offsets(now).await;
+                let segments = partition
+                    .get_expired_segments_start_offsets(message_expiry, now)
+                    .await;
                 if !segments.is_empty() {
                     expired_segments.insert(partition.partition_id, segments);
                 }
@@ -208,7 +217,7 @@ mod tests {
     use crate::streaming::utils::MemoryPool;
     use bytes::Bytes;
     use iggy_common::CompressionAlgorithm;
-    use iggy_common::{IggyMessage, MaxTopicSize};
+    use iggy_common::{IggyDuration, IggyMessage, MaxTopicSize};
     use std::sync::Arc;
     use std::sync::atomic::AtomicU32;
     use std::sync::atomic::AtomicU64;
@@ -319,7 +328,47 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn topic_with_never_expire_should_not_inherit_message_expiry_of_stream() {
+        let stream_message_expiry = IggyExpiry::ExpireDuration(IggyDuration::from(1000));
+        let topic = init_topic(1).await;
+
+        assert_eq!(
+            topic.get_effective_message_expiry(stream_message_expiry),
+            IggyExpiry::NeverExpire
+        );
+        let expired_segments = topic
+            .get_expired_segments_start_offsets_per_partition(
+                stream_message_expiry,
+                IggyTimestamp::from(u64::MAX),
+            )
+            .await;
+        assert!(expired_segments.is_empty());
+    }
+
+    #[tokio::test]
+    async fn topic_with_server_default_expiry_should_inherit_message_expiry_of_stream() {
+        let stream_message_expiry = IggyExpiry::ExpireDuration(IggyDuration::from(1000));
+        let topic = init_topic_with_message_expiry(1, IggyExpiry::ServerDefault).await;
+
+        assert_eq!(
+            topic.get_effective_message_expiry(stream_message_expiry),
+            stream_message_expiry
+        );
+        assert_eq!(
+            topic.get_effective_message_expiry(IggyExpiry::NeverExpire),
+            IggyExpiry::NeverExpire
+        );
+    }
+
     async fn init_topic(partitions_count: u32) -> Topic {
+        init_topic_with_message_expiry(partitions_count, IggyExpiry::NeverExpire).await
+    }
+
+    async fn init_topic_with_message_expiry(
+        partitions_count: u32,
+        message_expiry: IggyExpiry,
+    ) -> Topic {
         let tempdir = tempfile::TempDir::new().unwrap();
         let config = Arc::new(SystemConfig {
             path: tempdir.path().to_str().unwrap().to_string(),
@@ -348,7 +397,7 @@ mod tests {
             size_of_parent_stream,
             messages_count_of_parent_stream,
             segments_count_of_parent_stream,
-            IggyExpiry::NeverExpire,
+            message_expiry,
             compression_algorithm,
             MaxTopicSize::ServerDefault,
             1,
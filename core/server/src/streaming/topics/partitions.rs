@@ -53,7 +53,7 @@ impl Topic {
                 true,
                 self.config.clone(),
                 self.storage.clone(),
-                self.message_expiry,
+                Topic::get_message_expiry(self.message_expiry, &self.config),
                 self.messages_count_of_parent_stream.clone(),
                 self.messages_count.clone(),
                 self.size_of_parent_stream.clone(),
@@ -17,6 +17,7 @@
  */
 
 use crate::state::system::TopicState;
+use crate::streaming::diagnostics::startup;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::storage::TopicStorage;
 use crate::streaming::topics::COMPONENT;
@@ -56,7 +57,7 @@ impl TopicStorage for FileTopicStorage {
         let message_expiry = Topic::get_message_expiry(state.message_expiry, &topic.config);
         let max_topic_size = Topic::get_max_topic_size(state.max_topic_size, &topic.config)?;
         topic.created_at = state.created_at;
-        topic.message_expiry = message_expiry;
+        topic.message_expiry = state.message_expiry;
         topic.max_topic_size = max_topic_size;
         topic.compression_algorithm = state.compression_algorithm;
         topic.replication_factor = state.replication_factor.unwrap_or(1);
@@ -201,6 +202,7 @@ impl TopicStorage for FileTopicStorage {
                         );
                     }
                 }
+                startup::partition_loaded();
             });
             load_partitions.push(load_partition);
         }
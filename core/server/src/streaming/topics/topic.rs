@@ -128,7 +128,7 @@ impl Topic {
             consumer_groups_ids: AHashMap::new(),
             current_consumer_group_id: AtomicU32::new(1),
             current_partition_id: AtomicU32::new(1),
-            message_expiry: Topic::get_message_expiry(message_expiry, &config),
+            message_expiry,
             max_topic_size: Topic::get_max_topic_size(max_topic_size, &config)?,
             compression_algorithm,
             replication_factor,
@@ -251,6 +251,16 @@ impl Topic {
             _ => message_expiry,
         }
     }
+
+    /// Returns the message expiry of the topic's segments. The topic without its own expiry inherits
+    /// the one of its stream if defined, or the server default otherwise, while the explicit
+    /// `NeverExpire` is always kept.
+    pub fn get_effective_message_expiry(&self, stream_message_expiry: IggyExpiry) -> IggyExpiry {
+        match (self.message_expiry, stream_message_expiry) {
+            (IggyExpiry::ServerDefault, IggyExpiry::ExpireDuration(_)) => stream_message_expiry,
+            (message_expiry, _) => Topic::get_message_expiry(message_expiry, &self.config),
+        }
+    }
 }
 
 impl Sizeable for Topic {
@@ -0,0 +1,327 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::{LoginLockoutConfig, LoginRateLimitConfig};
+use dashmap::DashMap;
+use iggy_common::{IggyDuration, IggyError, UserId};
+use std::fmt::{Display, Formatter};
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// Number of tracked login sources above which the expired entries are pruned.
+const MAX_TRACKED_SOURCES: usize = 10_000;
+
+/// Guards the login endpoints against brute-force attacks by limiting the number of attempts
+/// per login source and by temporarily locking the accounts with too many failed attempts.
+#[derive(Debug)]
+pub struct LoginLimiter {
+    lockout: Option<LockoutPolicy>,
+    rate_limit: Option<RateLimitPolicy>,
+    failed_attempts: DashMap<UserId, FailedAttempts>,
+    source_attempts: DashMap<LoginSource, AttemptsWindow>,
+}
+
+/// The source of the login attempts, which are rate limited together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoginSource {
+    /// The IP address of the network client.
+    Ip(IpAddr),
+    /// The user ID of the Unix socket peer process, as all of them share the same (lack of) address.
+    PeerUid(u32),
+}
+
+impl Display for LoginSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoginSource::Ip(ip_address) => write!(f, "IP address: {ip_address}"),
+            LoginSource::PeerUid(uid) => write!(f, "peer UID: {uid}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LockoutPolicy {
+    max_failed_attempts: u32,
+    window: Duration,
+    duration: IggyDuration,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct RateLimitPolicy {
+    max_attempts: u32,
+    window: Duration,
+}
+
+#[derive(Debug)]
+struct FailedAttempts {
+    window: AttemptsWindow,
+    locked_until: Option<Instant>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct AttemptsWindow {
+    started_at: Instant,
+    count: u32,
+}
+
+impl AttemptsWindow {
+    fn new(now: Instant) -> Self {
+        Self {
+            started_at: now,
+            count: 0,
+        }
+    }
+
+    fn increment(&mut self, now: Instant, window: Duration) -> u32 {
+        if now.duration_since(self.started_at) >= window {
+            self.started_at = now;
+            self.count = 0;
+        }
+        self.count += 1;
+        self.count
+    }
+
+    fn is_expired(&self, now: Instant, window: Duration) -> bool {
+        now.duration_since(self.started_at) >= window
+    }
+}
+
+impl LoginLimiter {
+    pub fn new(lockout: &LoginLockoutConfig, rate_limit: &LoginRateLimitConfig) -> Self {
+        Self {
+            lockout: lockout.enabled.then(|| LockoutPolicy {
+                max_failed_attempts: lockout.max_failed_attempts,
+                window: lockout.window.get_duration(),
+                duration: lockout.duration,
+            }),
+            rate_limit: rate_limit.enabled.then(|| RateLimitPolicy {
+                max_attempts: rate_limit.max_attempts,
+                window: rate_limit.window.get_duration(),
+            }),
+            failed_attempts: DashMap::new(),
+            source_attempts: DashMap::new(),
+        }
+    }
+
+    /// Registers the login attempt from the given source and fails if the limit is exceeded.
+    pub fn register_attempt(&self, source: LoginSource) -> Result<(), IggyError> {
+        self.register_attempt_at(source, Instant::now())
+    }
+
+    /// Fails if the user account is currently locked, with the same error as for the invalid
+    /// credentials, so that the caller can't tell whether the account exists or has been locked.
+    pub fn ensure_not_locked(&self, user_id: UserId) -> Result<(), IggyError> {
+        self.ensure_not_locked_at(user_id, Instant::now())
+    }
+
+    /// Records the failed login attempt and locks the account once the limit is reached.
+    pub fn register_failure(&self, user_id: UserId) {
+        self.register_failure_at(user_id, Instant::now())
+    }
+
+    /// Clears the failed login attempts after a successful login.
+    pub fn register_success(&self, user_id: UserId) {
+        if self.lockout.is_some() {
+            self.failed_attempts.remove(&user_id);
+        }
+    }
+
+    fn register_attempt_at(&self, source: LoginSource, now: Instant) -> Result<(), IggyError> {
+        let Some(policy) = self.rate_limit else {
+            return Ok(());
+        };
+
+        if self.source_attempts.len() > MAX_TRACKED_SOURCES {
+            self.source_attempts
+                .retain(|_, attempts| !attempts.is_expired(now, policy.window));
+        }
+
+        let attempts = self
+            .source_attempts
+            .entry(source)
+            .or_insert_with(|| AttemptsWindow::new(now))
+            .increment(now, policy.window);
+        if attempts > policy.max_attempts {
+            warn!("Too many login attempts: {attempts} from {source}.");
+            return Err(IggyError::TooManyLoginAttempts);
+        }
+
+        Ok(())
+    }
+
+    fn ensure_not_locked_at(&self, user_id: UserId, now: Instant) -> Result<(), IggyError> {
+        if self.lockout.is_none() {
+            return Ok(());
+        }
+
+        let Some(failed_attempts) = self.failed_attempts.get(&user_id) else {
+            return Ok(());
+        };
+
+        match failed_attempts.locked_until {
+            Some(locked_until) if locked_until > now => {
+                warn!("User with ID: {user_id} is locked due to too many failed login attempts.");
+                Err(IggyError::InvalidCredentials)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn register_failure_at(&self, user_id: UserId, now: Instant) {
+        let Some(policy) = self.lockout else {
+            return;
+        };
+
+        let mut failed_attempts =
+            self.failed_attempts
+                .entry(user_id)
+                .or_insert_with(|| FailedAttempts {
+                    window: AttemptsWindow::new(now),
+                    locked_until: None,
+                });
+        if failed_attempts
+            .locked_until
+            .is_some_and(|locked_until| locked_until <= now)
+        {
+            failed_attempts.locked_until = None;
+            failed_attempts.window = AttemptsWindow::new(now);
+        }
+
+        let attempts = failed_attempts.window.increment(now, policy.window);
+        if attempts >= policy.max_failed_attempts && failed_attempts.locked_until.is_none() {
+            warn!(
+                "User with ID: {user_id} has been locked for {} after {attempts} failed login attempts.",
+                policy.duration
+            );
+            failed_attempts.locked_until = Some(now + policy.duration.get_duration());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    fn limiter(max_failed_attempts: u32, max_attempts: u32) -> LoginLimiter {
+        LoginLimiter::new(
+            &LoginLockoutConfig {
+                enabled: true,
+                max_failed_attempts,
+                window: IggyDuration::new(Duration::from_secs(60)),
+                duration: IggyDuration::new(Duration::from_secs(300)),
+            },
+            &LoginRateLimitConfig {
+                enabled: true,
+                max_attempts,
+                window: IggyDuration::new(Duration::from_secs(60)),
+            },
+        )
+    }
+
+    #[test]
+    fn user_should_be_locked_after_reaching_max_failed_attempts() {
+        let limiter = limiter(3, 100);
+        let now = Instant::now();
+        for _ in 0..2 {
+            limiter.register_failure_at(1, now);
+            assert!(limiter.ensure_not_locked_at(1, now).is_ok());
+        }
+
+        limiter.register_failure_at(1, now);
+        assert!(matches!(
+            limiter.ensure_not_locked_at(1, now),
+            Err(IggyError::InvalidCredentials)
+        ));
+        assert!(limiter.ensure_not_locked_at(2, now).is_ok());
+    }
+
+    #[test]
+    fn user_should_be_unlocked_after_lockout_duration() {
+        let limiter = limiter(1, 100);
+        let now = Instant::now();
+        limiter.register_failure_at(1, now);
+        assert!(limiter.ensure_not_locked_at(1, now).is_err());
+        assert!(
+            limiter
+                .ensure_not_locked_at(1, now + Duration::from_secs(301))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn failed_attempts_should_be_reset_after_window_or_successful_login() {
+        let limiter = limiter(2, 100);
+        let now = Instant::now();
+        limiter.register_failure_at(1, now);
+        limiter.register_failure_at(1, now + Duration::from_secs(61));
+        assert!(
+            limiter
+                .ensure_not_locked_at(1, now + Duration::from_secs(61))
+                .is_ok()
+        );
+
+        limiter.register_success(1);
+        limiter.register_failure_at(1, now + Duration::from_secs(62));
+        assert!(
+            limiter
+                .ensure_not_locked_at(1, now + Duration::from_secs(62))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn attempts_from_ip_address_should_be_limited_within_window() {
+        let limiter = limiter(5, 2);
+        let now = Instant::now();
+        let ip_address = LoginSource::Ip(IpAddr::V4(Ipv4Addr::LOCALHOST));
+        assert!(limiter.register_attempt_at(ip_address, now).is_ok());
+        assert!(limiter.register_attempt_at(ip_address, now).is_ok());
+        assert!(matches!(
+            limiter.register_attempt_at(ip_address, now),
+            Err(IggyError::TooManyLoginAttempts)
+        ));
+        assert!(
+            limiter
+                .register_attempt_at(ip_address, now + Duration::from_secs(60))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn attempts_from_unix_peers_should_be_limited_per_uid() {
+        let limiter = limiter(5, 1);
+        let now = Instant::now();
+        assert!(
+            limiter
+                .register_attempt_at(LoginSource::PeerUid(1000), now)
+                .is_ok()
+        );
+        assert!(
+            limiter
+                .register_attempt_at(LoginSource::PeerUid(1000), now)
+                .is_err()
+        );
+        assert!(
+            limiter
+                .register_attempt_at(LoginSource::PeerUid(1001), now)
+                .is_ok()
+        );
+    }
+}
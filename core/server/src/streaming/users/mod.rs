@@ -16,6 +16,9 @@
  * under the License.
  */
 
+pub mod login_limiter;
+pub mod password_policy;
 pub mod permissioner;
 pub mod permissioner_rules;
+pub mod quota_limiter;
 pub mod user;
@@ -0,0 +1,108 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::PasswordPolicyConfig;
+use iggy_common::IggyError;
+
+impl PasswordPolicyConfig {
+    /// Verifies that the password satisfies the configured length, character classes and deny-list.
+    pub fn verify(&self, password: &str) -> Result<(), IggyError> {
+        if password.chars().count() < self.min_length as usize {
+            return Err(IggyError::PasswordPolicyViolation(format!(
+                "password must be at least {} characters long",
+                self.min_length
+            )));
+        }
+
+        if self.require_uppercase && !password.chars().any(|c| c.is_uppercase()) {
+            return Err(IggyError::PasswordPolicyViolation(
+                "password must contain an uppercase letter".to_owned(),
+            ));
+        }
+
+        if self.require_lowercase && !password.chars().any(|c| c.is_lowercase()) {
+            return Err(IggyError::PasswordPolicyViolation(
+                "password must contain a lowercase letter".to_owned(),
+            ));
+        }
+
+        if self.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+            return Err(IggyError::PasswordPolicyViolation(
+                "password must contain a digit".to_owned(),
+            ));
+        }
+
+        if self.require_special_character && password.chars().all(|c| c.is_alphanumeric()) {
+            return Err(IggyError::PasswordPolicyViolation(
+                "password must contain a special character".to_owned(),
+            ));
+        }
+
+        if self
+            .deny_list
+            .iter()
+            .filter(|denied| !denied.is_empty())
+            .any(|denied| denied.eq_ignore_ascii_case(password))
+        {
+            return Err(IggyError::PasswordPolicyViolation(
+                "password is not allowed".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> PasswordPolicyConfig {
+        PasswordPolicyConfig {
+            min_length: 8,
+            require_uppercase: true,
+            require_lowercase: true,
+            require_digit: true,
+            require_special_character: true,
+            deny_list: vec!["P@ssw0rd!".to_owned()],
+        }
+    }
+
+    #[test]
+    fn password_satisfying_policy_should_be_accepted() {
+        assert!(policy().verify("Secr3t-Pass").is_ok());
+    }
+
+    #[test]
+    fn password_violating_policy_should_be_rejected() {
+        let policy = policy();
+        for password in [
+            "Sh0rt!",
+            "lowercase-0nly",
+            "UPPERCASE-0NLY",
+            "No-Digits-Here",
+            "NoSpecial1234",
+            "p@ssw0rd!",
+        ] {
+            assert!(matches!(
+                policy.verify(password),
+                Err(IggyError::PasswordPolicyViolation(_))
+            ));
+        }
+    }
+}
@@ -24,6 +24,10 @@ impl Permissioner {
         self.get_server_info(user_id)
     }
 
+    pub fn get_stats_history(&self, user_id: u32) -> Result<(), IggyError> {
+        self.get_server_info(user_id)
+    }
+
     pub fn get_clients(&self, user_id: u32) -> Result<(), IggyError> {
         self.get_server_info(user_id)
     }
@@ -32,6 +36,38 @@ impl Permissioner {
         self.get_server_info(user_id)
     }
 
+    pub fn disconnect_client(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_servers(user_id)
+    }
+
+    pub fn reload_config(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_servers(user_id)
+    }
+
+    pub fn get_log_filter(&self, user_id: u32) -> Result<(), IggyError> {
+        self.get_server_info(user_id)
+    }
+
+    pub fn set_log_filter(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_servers(user_id)
+    }
+
+    pub fn get_profile(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_servers(user_id)
+    }
+
+    fn manage_servers(&self, user_id: u32) -> Result<(), IggyError> {
+        if self
+            .users_permissions
+            .get(&user_id)
+            .is_some_and(|global_permissions| global_permissions.manage_servers)
+        {
+            return Ok(());
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
     fn get_server_info(&self, user_id: u32) -> Result<(), IggyError> {
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_servers || global_permissions.read_servers {
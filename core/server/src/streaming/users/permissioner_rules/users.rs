@@ -44,10 +44,18 @@ impl Permissioner {
         self.manager_users(user_id)
     }
 
+    pub fn update_quota(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manager_users(user_id)
+    }
+
     pub fn change_password(&self, user_id: u32) -> Result<(), IggyError> {
         self.manager_users(user_id)
     }
 
+    pub fn revoke_user_sessions(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manager_users(user_id)
+    }
+
     fn manager_users(&self, user_id: u32) -> Result<(), IggyError> {
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_users {
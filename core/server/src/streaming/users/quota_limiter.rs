@@ -0,0 +1,423 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::configs::system::QuotaConfig;
+use dashmap::DashMap;
+use iggy_common::{ThroughputQuota, UserId};
+use std::fmt::Display;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Enforces the throughput quotas attached to the users and personal access tokens.
+/// Each quota is backed by a set of token buckets (one per limit) which allow a burst of one second
+/// worth of traffic. The usage exceeding the bucket is turned into the throttle time, which is the
+/// time needed to refill the bucket, rather than into an error.
+#[derive(Debug)]
+pub struct QuotaLimiter {
+    enabled: AtomicBool,
+    max_throttle_time_micros: AtomicU64,
+    user_quotas: DashMap<UserId, ThroughputQuota>,
+    personal_access_token_quotas: DashMap<(UserId, Arc<String>), ThroughputQuota>,
+    personal_access_token_sessions: DashMap<u32, Arc<String>>,
+    buckets: DashMap<QuotaKey, QuotaBuckets>,
+    throttled_requests: AtomicU64,
+    throttle_time: AtomicU64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuotaDirection {
+    Produce,
+    Consume,
+}
+
+impl QuotaDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuotaDirection::Produce => "produce",
+            QuotaDirection::Consume => "consume",
+        }
+    }
+}
+
+impl Display for QuotaDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QuotaKey {
+    User(UserId),
+    PersonalAccessToken(UserId, Arc<String>),
+}
+
+impl QuotaKey {
+    pub fn user_id(&self) -> UserId {
+        match self {
+            QuotaKey::User(user_id) => *user_id,
+            QuotaKey::PersonalAccessToken(user_id, _) => *user_id,
+        }
+    }
+
+    pub fn personal_access_token(&self) -> Option<&str> {
+        match self {
+            QuotaKey::User(_) => None,
+            QuotaKey::PersonalAccessToken(_, name) => Some(name),
+        }
+    }
+}
+
+/// The result of recording the usage against the quota.
+#[derive(Debug)]
+pub struct QuotaUsage {
+    pub key: QuotaKey,
+    /// The percentage of the one second budget currently in use, above 100 when throttled.
+    pub usage_percent: u64,
+    pub throttle_time: Option<Duration>,
+}
+
+#[derive(Debug, Default)]
+struct QuotaBuckets {
+    produce_bytes: TokenBucket,
+    produce_messages: TokenBucket,
+    consume_bytes: TokenBucket,
+    consume_messages: TokenBucket,
+}
+
+#[derive(Debug, Default)]
+struct TokenBucket {
+    consumed: f64,
+    updated_at: Option<Instant>,
+}
+
+impl TokenBucket {
+    /// Records the usage and returns the ratio of the consumed budget, where 1.0 is the full rate.
+    fn consume(&mut self, amount: u64, rate: u64, now: Instant) -> f64 {
+        if rate == 0 {
+            return 0.0;
+        }
+
+        let rate = rate as f64;
+        if let Some(updated_at) = self.updated_at {
+            let refilled = now.duration_since(updated_at).as_secs_f64() * rate;
+            self.consumed = (self.consumed - refilled).max(0.0);
+        }
+        self.updated_at = Some(now);
+        self.consumed += amount as f64;
+        self.consumed / rate
+    }
+}
+
+impl QuotaLimiter {
+    pub fn new(config: &QuotaConfig) -> Self {
+        Self {
+            enabled: AtomicBool::new(config.enabled),
+            max_throttle_time_micros: AtomicU64::new(config.max_throttle_time.as_micros()),
+            user_quotas: DashMap::new(),
+            personal_access_token_quotas: DashMap::new(),
+            personal_access_token_sessions: DashMap::new(),
+            buckets: DashMap::new(),
+            throttled_requests: AtomicU64::new(0),
+            throttle_time: AtomicU64::new(0),
+        }
+    }
+
+    /// Applies the reloaded configuration, the quotas and the usage of the buckets are kept intact.
+    pub fn reconfigure(&self, config: &QuotaConfig) {
+        self.enabled.store(config.enabled, Ordering::Relaxed);
+        self.max_throttle_time_micros
+            .store(config.max_throttle_time.as_micros(), Ordering::Relaxed);
+    }
+
+    pub fn set_user_quota(&self, user_id: UserId, quota: Option<ThroughputQuota>) {
+        match quota.filter(|quota| !quota.is_unlimited()) {
+            Some(quota) => {
+                self.user_quotas.insert(user_id, quota);
+            }
+            None => {
+                self.user_quotas.remove(&user_id);
+            }
+        }
+        self.buckets.remove(&QuotaKey::User(user_id));
+    }
+
+    pub fn set_personal_access_token_quota(
+        &self,
+        user_id: UserId,
+        name: &Arc<String>,
+        quota: Option<ThroughputQuota>,
+    ) {
+        let key = (user_id, name.clone());
+        match quota.filter(|quota| !quota.is_unlimited()) {
+            Some(quota) => {
+                self.personal_access_token_quotas.insert(key, quota);
+            }
+            None => {
+                self.personal_access_token_quotas.remove(&key);
+            }
+        }
+        self.buckets
+            .remove(&QuotaKey::PersonalAccessToken(user_id, name.clone()));
+    }
+
+    /// Removes all the quotas and usage of the deleted user.
+    pub fn remove_user(&self, user_id: UserId) {
+        self.user_quotas.remove(&user_id);
+        self.personal_access_token_quotas
+            .retain(|(id, _), _| *id != user_id);
+        self.buckets.retain(|key, _| key.user_id() != user_id);
+    }
+
+    /// Binds the client session to the personal access token used to authenticate it.
+    pub fn bind_personal_access_token(&self, client_id: u32, name: &Arc<String>) {
+        self.personal_access_token_sessions
+            .insert(client_id, name.clone());
+    }
+
+    /// Removes the binding between the client session and the personal access token.
+    pub fn unbind_client(&self, client_id: u32) {
+        self.personal_access_token_sessions.remove(&client_id);
+    }
+
+    /// Returns the total number of throttled requests and the total throttle time.
+    pub fn get_throttling_stats(&self) -> (u64, Duration) {
+        (
+            self.throttled_requests.load(Ordering::Relaxed),
+            Duration::from_micros(self.throttle_time.load(Ordering::Relaxed)),
+        )
+    }
+
+    /// Records the usage of the client and returns the time by which the response should be delayed,
+    /// or `None` if there's no quota applicable to the client.
+    pub fn record(
+        &self,
+        client_id: u32,
+        user_id: UserId,
+        direction: QuotaDirection,
+        bytes: u64,
+        messages: u64,
+    ) -> Option<QuotaUsage> {
+        self.record_at(
+            client_id,
+            user_id,
+            direction,
+            bytes,
+            messages,
+            Instant::now(),
+        )
+    }
+
+    fn record_at(
+        &self,
+        client_id: u32,
+        user_id: UserId,
+        direction: QuotaDirection,
+        bytes: u64,
+        messages: u64,
+        now: Instant,
+    ) -> Option<QuotaUsage> {
+        if !self.enabled.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        let (key, quota) = self.resolve_quota(client_id, user_id)?;
+        let (bytes_rate, messages_rate) = match direction {
+            QuotaDirection::Produce => (
+                quota.produce_bytes_per_second,
+                quota.produce_messages_per_second,
+            ),
+            QuotaDirection::Consume => (
+                quota.consume_bytes_per_second,
+                quota.consume_messages_per_second,
+            ),
+        };
+        if bytes_rate == 0 && messages_rate == 0 {
+            return None;
+        }
+
+        let usage = {
+            let mut buckets = self.buckets.entry(key.clone()).or_default();
+            let (bytes_bucket, messages_bucket) = match direction {
+                QuotaDirection::Produce => {
+                    let buckets = &mut *buckets;
+                    (&mut buckets.produce_bytes, &mut buckets.produce_messages)
+                }
+                QuotaDirection::Consume => {
+                    let buckets = &mut *buckets;
+                    (&mut buckets.consume_bytes, &mut buckets.consume_messages)
+                }
+            };
+            bytes_bucket
+                .consume(bytes, bytes_rate, now)
+                .max(messages_bucket.consume(messages, messages_rate, now))
+        };
+
+        // The bucket holds one second worth of traffic, the excess has to be refilled first.
+        let throttle_time = if usage > 1.0 {
+            let max_throttle_time =
+                Duration::from_micros(self.max_throttle_time_micros.load(Ordering::Relaxed));
+            let throttle_time = Duration::from_secs_f64(usage - 1.0).min(max_throttle_time);
+            self.throttled_requests.fetch_add(1, Ordering::Relaxed);
+            self.throttle_time
+                .fetch_add(throttle_time.as_micros() as u64, Ordering::Relaxed);
+            Some(throttle_time)
+        } else {
+            None
+        };
+
+        Some(QuotaUsage {
+            key,
+            usage_percent: (usage * 100.0) as u64,
+            throttle_time,
+        })
+    }
+
+    fn resolve_quota(
+        &self,
+        client_id: u32,
+        user_id: UserId,
+    ) -> Option<(QuotaKey, ThroughputQuota)> {
+        if let Some(name) = self.personal_access_token_sessions.get(&client_id) {
+            let name = name.clone();
+            if let Some(quota) = self
+                .personal_access_token_quotas
+                .get(&(user_id, name.clone()))
+            {
+                return Some((QuotaKey::PersonalAccessToken(user_id, name), *quota));
+            }
+        }
+
+        self.user_quotas
+            .get(&user_id)
+            .map(|quota| (QuotaKey::User(user_id), *quota))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy_common::IggyDuration;
+
+    fn limiter() -> QuotaLimiter {
+        QuotaLimiter::new(&QuotaConfig {
+            enabled: true,
+            max_throttle_time: IggyDuration::new(Duration::from_secs(5)),
+        })
+    }
+
+    fn quota(produce_bytes_per_second: u64, consume_messages_per_second: u64) -> ThroughputQuota {
+        ThroughputQuota {
+            produce_bytes_per_second,
+            consume_messages_per_second,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn usage_without_quota_should_not_be_throttled() {
+        let limiter = limiter();
+        assert!(
+            limiter
+                .record(1, 1, QuotaDirection::Produce, 1_000_000, 1000)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn usage_exceeding_user_quota_should_be_throttled() {
+        let limiter = limiter();
+        limiter.set_user_quota(1, Some(quota(1000, 0)));
+        let now = Instant::now();
+
+        let usage = limiter
+            .record_at(1, 1, QuotaDirection::Produce, 1000, 1, now)
+            .unwrap();
+        assert_eq!(usage.key, QuotaKey::User(1));
+        assert_eq!(usage.usage_percent, 100);
+        assert!(usage.throttle_time.is_none());
+
+        let usage = limiter
+            .record_at(1, 1, QuotaDirection::Produce, 500, 1, now)
+            .unwrap();
+        assert_eq!(usage.throttle_time, Some(Duration::from_millis(500)));
+
+        // Consuming is not limited by this quota.
+        assert!(
+            limiter
+                .record_at(1, 1, QuotaDirection::Consume, 1_000_000, 1, now)
+                .is_none()
+        );
+
+        // After the bucket is refilled, the usage is no longer throttled.
+        let usage = limiter
+            .record_at(
+                1,
+                1,
+                QuotaDirection::Produce,
+                100,
+                1,
+                now + Duration::from_secs(2),
+            )
+            .unwrap();
+        assert!(usage.throttle_time.is_none());
+        assert_eq!(
+            limiter.get_throttling_stats(),
+            (1, Duration::from_millis(500))
+        );
+    }
+
+    #[test]
+    fn throttle_time_should_be_capped() {
+        let limiter = limiter();
+        limiter.set_user_quota(1, Some(quota(0, 10)));
+        let usage = limiter
+            .record(1, 1, QuotaDirection::Consume, 0, 1000)
+            .unwrap();
+        assert_eq!(usage.throttle_time, Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn personal_access_token_quota_should_take_precedence_over_user_quota() {
+        let limiter = limiter();
+        let name = Arc::new("token".to_string());
+        limiter.set_user_quota(1, Some(quota(1000, 0)));
+        limiter.set_personal_access_token_quota(1, &name, Some(quota(100, 0)));
+        limiter.bind_personal_access_token(2, &name);
+
+        let usage = limiter
+            .record(2, 1, QuotaDirection::Produce, 200, 1)
+            .unwrap();
+        assert_eq!(usage.key, QuotaKey::PersonalAccessToken(1, name.clone()));
+        assert!(usage.throttle_time.is_some());
+
+        let usage = limiter
+            .record(3, 1, QuotaDirection::Produce, 200, 1)
+            .unwrap();
+        assert_eq!(usage.key, QuotaKey::User(1));
+        assert!(usage.throttle_time.is_none());
+
+        limiter.unbind_client(2);
+        limiter.remove_user(1);
+        assert!(
+            limiter
+                .record(2, 1, QuotaDirection::Produce, 200, 1)
+                .is_none()
+        );
+    }
+}
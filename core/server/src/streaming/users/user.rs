@@ -21,7 +21,7 @@ use dashmap::DashMap;
 use iggy_common::IggyTimestamp;
 use iggy_common::UserStatus;
 use iggy_common::defaults::*;
-use iggy_common::{Permissions, UserId};
+use iggy_common::{Permissions, ThroughputQuota, UserId};
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -32,6 +32,7 @@ pub struct User {
     pub password: String,
     pub created_at: IggyTimestamp,
     pub permissions: Option<Permissions>,
+    pub quota: Option<ThroughputQuota>,
     pub personal_access_tokens: DashMap<Arc<String>, PersonalAccessToken>,
 }
 
@@ -44,6 +45,7 @@ impl Default for User {
             password: "secret".to_string(),
             created_at: IggyTimestamp::now(),
             permissions: None,
+            quota: None,
             personal_access_tokens: DashMap::new(),
         }
     }
@@ -87,6 +89,7 @@ impl User {
             created_at: IggyTimestamp::now(),
             status,
             permissions,
+            quota: None,
             personal_access_tokens: DashMap::new(),
         }
     }
@@ -0,0 +1,133 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+use crate::configs::server::ServerConfig;
+use iggy_common::IggyError;
+use rustls::crypto::CryptoProvider;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::{ClientHello, ResolvesServerCert};
+use rustls::sign::CertifiedKey;
+use rustls_pemfile::{certs, private_key};
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::{Arc, RwLock};
+use tokio::sync::watch;
+use tracing::{error, info};
+
+/// Loads the certificate chain and the private key (PKCS#1, PKCS#8 or SEC1) from the PEM files.
+pub fn load_certificates(
+    cert_file: &str,
+    key_file: &str,
+) -> Result<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>), IggyError> {
+    let file = File::open(cert_file).map_err(|error| {
+        error!("Failed to open certificate file: {cert_file}. {error}");
+        IggyError::InvalidTlsCertificatePath
+    })?;
+    let certs = certs(&mut BufReader::new(file))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| {
+            error!("Failed to parse certificate file: {cert_file}. {error}");
+            IggyError::InvalidTlsCertificate
+        })?;
+    if certs.is_empty() {
+        error!("No certificates found in certificate file: {cert_file}");
+        return Err(IggyError::InvalidTlsCertificate);
+    }
+
+    let file = File::open(key_file).map_err(|error| {
+        error!("Failed to open private key file: {key_file}. {error}");
+        IggyError::InvalidTlsCertificatePath
+    })?;
+    let key = private_key(&mut BufReader::new(file))
+        .map_err(|error| {
+            error!("Failed to parse private key file: {key_file}. {error}");
+            IggyError::InvalidTlsCertificate
+        })?
+        .ok_or_else(|| {
+            error!("No private key found in key file: {key_file}");
+            IggyError::InvalidTlsCertificate
+        })?;
+    Ok((certs, key))
+}
+
+/// Serves the certificate which can be swapped at runtime, so that the rotated certificates
+/// are used by the new TLS handshakes without restarting the listener.
+#[derive(Debug)]
+pub struct ReloadableCertResolver {
+    provider: Arc<CryptoProvider>,
+    certified_key: RwLock<Arc<CertifiedKey>>,
+}
+
+impl ReloadableCertResolver {
+    pub fn new(
+        certs: Vec<CertificateDer<'static>>,
+        key: PrivateKeyDer<'static>,
+        provider: Arc<CryptoProvider>,
+    ) -> Result<Self, IggyError> {
+        let certified_key = Self::certify(certs, key, &provider)?;
+        Ok(Self {
+            provider,
+            certified_key: RwLock::new(Arc::new(certified_key)),
+        })
+    }
+
+    pub fn reload(&self, cert_file: &str, key_file: &str) -> Result<(), IggyError> {
+        let (certs, key) = load_certificates(cert_file, key_file)?;
+        let certified_key = Self::certify(certs, key, &self.provider)?;
+        *self.certified_key.write().unwrap() = Arc::new(certified_key);
+        Ok(())
+    }
+
+    fn certify(
+        certs: Vec<CertificateDer<'static>>,
+        key: PrivateKeyDer<'static>,
+        provider: &CryptoProvider,
+    ) -> Result<CertifiedKey, IggyError> {
+        CertifiedKey::from_der(certs, key, provider).map_err(|error| {
+            error!("Failed to use the certificate with the private key. {error}");
+            IggyError::InvalidTlsCertificate
+        })
+    }
+}
+
+impl ResolvesServerCert for ReloadableCertResolver {
+    fn resolve(&self, _client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        Some(self.certified_key.read().unwrap().clone())
+    }
+}
+
+/// Reloads the certificate of the listener each time the server configuration is reloaded.
+/// The files are re-read even if their paths didn't change, as they may have been replaced
+/// in place by the certificate rotation.
+pub fn start_certificate_reloader(
+    listener: &'static str,
+    resolver: Arc<ReloadableCertResolver>,
+    mut config_changes: watch::Receiver<Arc<ServerConfig>>,
+    certificate_files: impl Fn(&ServerConfig) -> (String, String) + Send + 'static,
+) {
+    tokio::spawn(async move {
+        while config_changes.changed().await.is_ok() {
+            let (cert_file, key_file) = certificate_files(&config_changes.borrow_and_update());
+            match resolver.reload(&cert_file, &key_file) {
+                Ok(()) => info!(
+                    "Reloaded {listener} certificate from cert_file: {cert_file}, key_file: {key_file}"
+                ),
+                Err(error) => error!("Failed to reload {listener} certificate. {error}"),
+            }
+        }
+    });
+}
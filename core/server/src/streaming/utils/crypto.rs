@@ -16,12 +16,74 @@
  * under the License.
  */
 
-use bcrypt::{hash, verify};
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
 
+const ARGON2ID_PREFIX: &str = "$argon2id$";
+const BCRYPT_PREFIXES: [&str; 4] = ["$2a$", "$2b$", "$2x$", "$2y$"];
+
+/// Hashes the password using Argon2id, which is the default algorithm for all the new hashes.
 pub fn hash_password(password: &str) -> String {
-    hash(password, 4).unwrap()
+    let salt = SaltString::generate(&mut OsRng);
+    argon2()
+        .hash_password(password.as_bytes(), &salt)
+        .unwrap()
+        .to_string()
 }
 
+/// Verifies the password against either Argon2 (PHC string format) or legacy bcrypt hash.
 pub fn verify_password(password: &str, hash: &str) -> bool {
-    verify(password, hash).unwrap_or(false)
+    if is_bcrypt_hash(hash) {
+        return bcrypt::verify(password, hash).unwrap_or(false);
+    }
+
+    let Ok(hash) = PasswordHash::new(hash) else {
+        return false;
+    };
+
+    argon2().verify_password(password.as_bytes(), &hash).is_ok()
+}
+
+/// Returns `true` if the hash was not produced by the current default algorithm and should be replaced.
+pub fn needs_rehash(hash: &str) -> bool {
+    !hash.starts_with(ARGON2ID_PREFIX)
+}
+
+fn is_bcrypt_hash(hash: &str) -> bool {
+    BCRYPT_PREFIXES
+        .iter()
+        .any(|prefix| hash.starts_with(prefix))
+}
+
+fn argon2() -> Argon2<'static> {
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, Params::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hashed_password_should_use_argon2id_and_be_verified() {
+        let hash = hash_password("secret");
+        assert!(hash.starts_with(ARGON2ID_PREFIX));
+        assert!(verify_password("secret", &hash));
+        assert!(!verify_password("invalid", &hash));
+        assert!(!needs_rehash(&hash));
+    }
+
+    #[test]
+    fn legacy_bcrypt_hash_should_be_verified_and_require_rehash() {
+        let hash = bcrypt::hash("secret", 4).unwrap();
+        assert!(verify_password("secret", &hash));
+        assert!(!verify_password("invalid", &hash));
+        assert!(needs_rehash(&hash));
+    }
+
+    #[test]
+    fn malformed_hash_should_not_be_verified() {
+        assert!(!verify_password("secret", "secret"));
+        assert!(!verify_password("secret", ""));
+    }
 }
@@ -351,7 +351,7 @@ impl MemoryPool {
     }
 
     /// Returns pool utilization percentage.
-    fn pool_utilization(&self) -> f64 {
+    pub(crate) fn pool_utilization(&self) -> f64 {
         (self.pool_current_size() as f64 / self.pool_maximum_size() as f64) * 100.0
     }
 
@@ -16,6 +16,7 @@
  * under the License.
  */
 
+pub mod certificates;
 pub mod crypto;
 pub mod file;
 pub mod hash;
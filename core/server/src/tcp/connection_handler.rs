@@ -16,76 +16,393 @@
  * under the License.
  */
 
+use crate::binary::buffered_sender::{BufferedResponse, BufferedSender};
 use crate::binary::command::ServerCommandHandler;
+use crate::binary::sender::REQUEST_ID_SIZE;
 use crate::binary::{command, sender::SenderKind};
 use crate::server_error::ConnectionError;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::connection_handler::command::ServerCommand;
-use iggy_common::IggyError;
+use bytes::Bytes;
+use iggy_common::{COMPRESSED_FRAME_FLAG, IggyError};
 use std::io::ErrorKind;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::task::{JoinError, JoinSet};
 use tracing::{debug, error, info};
 
 const INITIAL_BYTES_LENGTH: usize = 4;
 
+/// The maximum number of the pipelined requests handled concurrently on a single connection,
+/// the next requests are not read until any of them completes.
+const MAX_IN_FLIGHT_REQUESTS: usize = 128;
+
+struct TcpRequest {
+    code: u32,
+    command: ServerCommand,
+    length: u32,
+    /// Set for the pipelined requests, whose payload has been already read.
+    pipelined: Option<PipelinedRequest>,
+}
+
+struct PipelinedRequest {
+    request_id: u32,
+    sender: SenderKind,
+}
+
+struct PipelinedResponse {
+    request_id: u32,
+    code: u32,
+    elapsed: Duration,
+    sender: SenderKind,
+    result: Result<(), IggyError>,
+}
+
 pub(crate) async fn handle_connection(
     session: Arc<Session>,
     sender: &mut SenderKind,
     system: SharedSystem,
+) -> Result<(), ConnectionError> {
+    let mut in_flight = JoinSet::new();
+    let result = handle_requests(&session, sender, &system, &mut in_flight).await;
+    // The requests in flight are completed even though the connection is gone, rather than aborted halfway.
+    in_flight.detach_all();
+    result
+}
+
+async fn handle_requests(
+    session: &Arc<Session>,
+    sender: &mut SenderKind,
+    system: &SharedSystem,
+    in_flight: &mut JoinSet<PipelinedResponse>,
 ) -> Result<(), ConnectionError> {
     let mut length_buffer = [0u8; INITIAL_BYTES_LENGTH];
-    let mut code_buffer = [0u8; INITIAL_BYTES_LENGTH];
+    let mut read_length = 0;
     loop {
-        let read_length = match sender.read(&mut length_buffer).await {
-            Ok(read_length) => read_length,
+        // Reading the length is cancel-safe, so that the responses of the pipelined requests
+        // can be sent as soon as they complete, while waiting for the next request.
+        let read_result = tokio::select! {
+            _ = session.disconnected() => {
+                info!("Session: {session} has been disconnected by the server.");
+                return Err(ConnectionError::from(IggyError::Disconnected));
+            }
+            Some(response) = in_flight.join_next(), if !in_flight.is_empty() => {
+                send_pipelined_response(response, session, sender, system).await?;
+                continue;
+            }
+            read_result = sender.read_available(&mut length_buffer[read_length..]),
+                if in_flight.len() < MAX_IN_FLIGHT_REQUESTS => read_result,
+        };
+        match read_result {
+            Ok(read_bytes) => read_length += read_bytes,
             Err(error) => {
                 if error.as_code() == IggyError::ConnectionClosed.as_code() {
                     return Err(ConnectionError::from(error));
                 } else {
                     sender.send_error_response(error).await?;
+                    read_length = 0;
                     continue;
                 }
             }
         };
 
-        if read_length != INITIAL_BYTES_LENGTH {
-            sender.send_error_response(IggyError::CommandLengthError(format!(
-                "Unable to read the TCP request length, expected: {INITIAL_BYTES_LENGTH} bytes, received: {read_length} bytes."
-            ))).await?;
+        if read_length < INITIAL_BYTES_LENGTH {
             continue;
         }
+        read_length = 0;
 
-        let length = u32::from_le_bytes(length_buffer);
-        sender.read(&mut code_buffer).await?;
-        let code = u32::from_le_bytes(code_buffer);
-        debug!("Received a TCP request, length: {length}, code: {code}");
-        let command = ServerCommand::from_code_and_reader(code, sender, length - 4).await?;
-        debug!("Received a TCP command: {command}, payload size: {length}");
-        match command.handle(sender, length, &session, &system).await {
-            Ok(_) => {
-                debug!(
-                    "Command was handled successfully, session: {session}. TCP response was sent."
-                );
+        // The rest of the frame might never arrive, e.g. from a stuck client, thus reading it
+        // is interrupted as well once the session is disconnected by the server.
+        session.record_frame_start();
+        let request = tokio::select! {
+            _ = session.disconnected() => {
+                info!("Session: {session} has been disconnected by the server while sending a request.");
+                return Err(ConnectionError::from(IggyError::Disconnected));
             }
-            Err(error) => {
-                error!("Command was not handled successfully, session: {session}, error: {error}.");
-                if let IggyError::ClientNotFound(_) = error {
-                    sender.send_error_response(error).await?;
-                    debug!("TCP error response was sent to: {session}.");
-                    error!("Session: {session} will be deleted.");
-                    return Err(ConnectionError::from(IggyError::ClientNotFound(
-                        session.client_id,
-                    )));
+            request = read_request(u32::from_le_bytes(length_buffer), session, sender, system) => request?,
+        };
+        let Some(TcpRequest {
+            code,
+            command,
+            length,
+            pipelined,
+        }) = request
+        else {
+            continue;
+        };
+
+        match pipelined {
+            Some(PipelinedRequest {
+                request_id,
+                sender: mut request_sender,
+            }) if !is_connection_command(&command) => {
+                let serialized = is_serialized_command(&command);
+                if serialized {
+                    complete_in_flight_requests(session, sender, system, in_flight).await?;
+                }
+                let request_session = session.clone();
+                let request_system = system.clone();
+                let request = async move {
+                    let started_at = Instant::now();
+                    let result = command
+                        .handle(
+                            &mut request_sender,
+                            length,
+                            &request_session,
+                            &request_system,
+                        )
+                        .await;
+                    PipelinedResponse {
+                        request_id,
+                        code,
+                        elapsed: started_at.elapsed(),
+                        sender: request_sender,
+                        result,
+                    }
+                };
+                if serialized {
+                    send_pipelined_response(Ok(request.await), session, sender, system).await?;
                 } else {
-                    sender.send_error_response(error).await?;
-                    debug!("TCP error response was sent to: {session}.");
+                    in_flight.spawn(request);
                 }
             }
+            pipelined => {
+                // The connection state is changed once the previous requests have been answered.
+                if pipelined.is_some() {
+                    complete_in_flight_requests(session, sender, system, in_flight).await?;
+                }
+                let started_at = Instant::now();
+                let result = command.handle(sender, length, session, system).await;
+                complete_request(session, sender, system, code, started_at.elapsed(), result)
+                    .await?;
+            }
         }
     }
 }
 
+/// The commands changing the state of the connection, which are handled using the connection itself.
+fn is_connection_command(command: &ServerCommand) -> bool {
+    matches!(
+        command,
+        ServerCommand::EnablePipelining(_) | ServerCommand::EnableFrameCompression(_)
+    )
+}
+
+/// The pipelined commands which are handled one at a time, after the previous requests have completed,
+/// as they change the state of the session or must be applied in order.
+fn is_serialized_command(command: &ServerCommand) -> bool {
+    matches!(
+        command,
+        ServerCommand::Hello(_)
+            | ServerCommand::IdentifyClient(_)
+            | ServerCommand::LoginUser(_)
+            | ServerCommand::LoginWithPersonalAccessToken(_)
+            | ServerCommand::LogoutUser(_)
+            | ServerCommand::JoinConsumerGroup(_)
+            | ServerCommand::LeaveConsumerGroup(_)
+            | ServerCommand::SendMessages(_)
+    )
+}
+
+/// Waits for all the pipelined requests in flight and sends their responses.
+async fn complete_in_flight_requests(
+    session: &Session,
+    sender: &mut SenderKind,
+    system: &SharedSystem,
+    in_flight: &mut JoinSet<PipelinedResponse>,
+) -> Result<(), ConnectionError> {
+    while let Some(response) = in_flight.join_next().await {
+        send_pipelined_response(response, session, sender, system).await?;
+    }
+    Ok(())
+}
+
+/// Sends the responses of the completed pipelined request, tagged with its ID.
+async fn send_pipelined_response(
+    response: Result<PipelinedResponse, JoinError>,
+    session: &Session,
+    sender: &mut SenderKind,
+    system: &SharedSystem,
+) -> Result<(), ConnectionError> {
+    let PipelinedResponse {
+        request_id,
+        code,
+        elapsed,
+        sender: request_sender,
+        result,
+    } = response.map_err(|error| {
+        // The ID of the failed request is unknown, so the client would wait for its response forever.
+        error!("Pipelined request has failed, session: {session}, error: {error}.");
+        ConnectionError::from(IggyError::Error)
+    })?;
+    sender.set_request_id(request_id);
+    if let SenderKind::Buffered(BufferedSender { responses, .. }) = request_sender {
+        for response in responses {
+            match response {
+                BufferedResponse::Ok(payload) if payload.is_empty() => {
+                    sender.send_empty_ok_response().await?
+                }
+                BufferedResponse::Ok(payload) => sender.send_ok_response(&payload).await?,
+                BufferedResponse::Error(error) => sender.send_error_response(error).await?,
+            }
+        }
+    }
+    complete_request(session, sender, system, code, elapsed, result).await
+}
+
+/// Records the handled request and sends the error response, if it has failed.
+async fn complete_request(
+    session: &Session,
+    sender: &mut SenderKind,
+    system: &SharedSystem,
+    code: u32,
+    elapsed: Duration,
+    result: Result<(), IggyError>,
+) -> Result<(), ConnectionError> {
+    session.record_response(sender.take_sent_bytes());
+    record_frame_compression(sender, system).await;
+    record_request(sender.transport(), system, code, elapsed, &result).await;
+    match result {
+        Ok(_) => {
+            debug!("Command was handled successfully, session: {session}. TCP response was sent.");
+        }
+        Err(error) => {
+            error!("Command was not handled successfully, session: {session}, error: {error}.");
+            if let IggyError::ClientNotFound(_) = error {
+                sender.send_error_response(error).await?;
+                debug!("TCP error response was sent to: {session}.");
+                error!("Session: {session} will be deleted.");
+                return Err(ConnectionError::from(IggyError::ClientNotFound(
+                    session.client_id,
+                )));
+            } else {
+                sender.send_error_response(error).await?;
+                debug!("TCP error response was sent to: {session}.");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads the remainder of the request frame following its length, or returns `None` if the request
+/// has been rejected but the connection can be still reused.
+async fn read_request(
+    mut length: u32,
+    session: &Session,
+    sender: &mut SenderKind,
+    system: &SharedSystem,
+) -> Result<Option<TcpRequest>, ConnectionError> {
+    let mut code_buffer = [0u8; INITIAL_BYTES_LENGTH];
+    let mut request_id_buffer = [0u8; REQUEST_ID_SIZE];
+    let compressed = sender.frame_compression().is_enabled() && length & COMPRESSED_FRAME_FLAG != 0;
+    if compressed {
+        length &= !COMPRESSED_FRAME_FLAG;
+    }
+    let mut header_length = INITIAL_BYTES_LENGTH;
+    let mut request_id = None;
+    if sender.is_pipelining_enabled() {
+        sender.read(&mut request_id_buffer).await?;
+        let id = u32::from_le_bytes(request_id_buffer);
+        sender.set_request_id(id);
+        request_id = Some(id);
+        header_length += REQUEST_ID_SIZE;
+        debug!("Received a pipelined TCP request with ID: {id}");
+    }
+    sender.read(&mut code_buffer).await?;
+    let code = u32::from_le_bytes(code_buffer);
+    debug!("Received a TCP request, length: {length}, code: {code}");
+    if let Err(error) = system.read().await.validate_request(session, code, length) {
+        // The payload of the rejected request is not read, so the connection can't be reused.
+        error!("Rejected a TCP request with code: {code}, session: {session}, error: {error}.");
+        sender.send_error_response(error.clone()).await?;
+        return Err(ConnectionError::from(error));
+    }
+    let wire_length = length;
+    if compressed {
+        let max_size = system.read().await.max_frame_size();
+        length = match sender.read_compressed_request(length - 4, max_size).await {
+            Ok(decompressed_length) => decompressed_length + 4,
+            Err(error) => {
+                error!(
+                    "Rejected a compressed TCP request with code: {code}, session: {session}, error: {error}."
+                );
+                sender.send_error_response(error.clone()).await?;
+                return Err(ConnectionError::from(error));
+            }
+        };
+        debug!("Decompressed a TCP request, length: {wire_length} -> {length}");
+    }
+    // The payload of the pipelined request is read upfront, so that it can be handled concurrently.
+    let mut pipelined = match request_id {
+        Some(request_id) => {
+            let mut payload = vec![0u8; length as usize - 4];
+            if !payload.is_empty() {
+                sender.read(&mut payload).await?;
+            }
+            Some(PipelinedRequest {
+                request_id,
+                sender: SenderKind::get_buffered_sender(Bytes::from(payload)),
+            })
+        }
+        None => None,
+    };
+    let reader = match pipelined.as_mut() {
+        Some(pipelined) => &mut pipelined.sender,
+        None => &mut *sender,
+    };
+    let command = match ServerCommand::from_code_and_reader(code, reader, length - 4).await {
+        Ok(command) => command,
+        // The whole payload has been already read, so the client can continue sending the requests,
+        // e.g. after trying the command which is not supported by this version of the server.
+        Err(IggyError::InvalidCommand) => {
+            session.record_request(code, header_length as u64 + wire_length as u64);
+            sender
+                .send_error_response(IggyError::InvalidCommand)
+                .await?;
+            return Ok(None);
+        }
+        Err(error) => return Err(ConnectionError::from(error)),
+    };
+    debug!("Received a TCP command: {command}, payload size: {length}");
+    session.record_request(code, header_length as u64 + wire_length as u64);
+    Ok(Some(TcpRequest {
+        code,
+        command,
+        length,
+        pipelined,
+    }))
+}
+
+/// Records the compression ratio of the frames exchanged while handling the request.
+pub(crate) async fn record_frame_compression(sender: &mut SenderKind, system: &SharedSystem) {
+    let stats = sender.take_frame_compression_stats();
+    if stats.is_empty() {
+        return;
+    }
+
+    system
+        .read()
+        .await
+        .metrics
+        .record_frame_compression(sender.frame_compression().algorithm, &stats);
+}
+
+/// Records the time spent on handling the request and its error, if any.
+pub(crate) async fn record_request(
+    transport: &'static str,
+    system: &SharedSystem,
+    code: u32,
+    duration: Duration,
+    result: &Result<(), IggyError>,
+) {
+    system
+        .read()
+        .await
+        .metrics
+        .record_request(transport, code, duration, result.as_ref().err());
+}
+
 pub(crate) fn handle_error(error: ConnectionError) {
     match error {
         ConnectionError::IoError(error) => match error.kind() {
@@ -109,6 +426,9 @@ pub(crate) fn handle_error(error: ConnectionError) {
             IggyError::ConnectionClosed => {
                 debug!("Client closed connection.");
             }
+            IggyError::Disconnected => {
+                info!("Client has been disconnected.");
+            }
             _ => {
                 error!("Failure in internal SDK call: {sdk_error}");
             }
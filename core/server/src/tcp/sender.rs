@@ -16,13 +16,22 @@
  * under the License.
  */
 
-use iggy_common::IggyError;
+use crate::binary::sender::{REQUEST_ID_SIZE, RESPONSE_HEADER_SIZE};
+use iggy_common::{COMPRESSED_FRAME_FLAG, IggyError};
 use std::io::IoSlice;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use tracing::debug;
 
 const STATUS_OK: &[u8] = &[0; 4];
 
+/// Returns the size of the response header, including the request ID for the pipelined connections.
+pub(crate) fn response_header_size(request_id: Option<u32>) -> usize {
+    match request_id {
+        Some(_) => REQUEST_ID_SIZE + RESPONSE_HEADER_SIZE,
+        None => RESPONSE_HEADER_SIZE,
+    }
+}
+
 pub(crate) async fn read<T>(stream: &mut T, buffer: &mut [u8]) -> Result<usize, IggyError>
 where
     T: AsyncRead + AsyncWrite + Unpin,
@@ -40,43 +49,96 @@ where
     }
 }
 
-pub(crate) async fn send_empty_ok_response<T>(stream: &mut T) -> Result<(), IggyError>
+/// Reads the bytes which are available, unlike `read` which waits until the whole buffer is filled.
+pub(crate) async fn read_available<T>(stream: &mut T, buffer: &mut [u8]) -> Result<usize, IggyError>
+where
+    T: AsyncRead + Unpin,
+{
+    match stream.read(buffer).await {
+        Ok(0) => Err(IggyError::ConnectionClosed),
+        Ok(read_bytes) => Ok(read_bytes),
+        Err(error) => {
+            if error.kind() == std::io::ErrorKind::UnexpectedEof {
+                Err(IggyError::ConnectionClosed)
+            } else {
+                Err(IggyError::TcpError)
+            }
+        }
+    }
+}
+
+pub(crate) async fn send_empty_ok_response<T>(
+    stream: &mut T,
+    request_id: Option<u32>,
+) -> Result<(), IggyError>
 where
     T: AsyncRead + AsyncWrite + Unpin,
 {
-    send_ok_response(stream, &[]).await
+    send_ok_response(stream, request_id, &[]).await
 }
 
-pub(crate) async fn send_ok_response<T>(stream: &mut T, payload: &[u8]) -> Result<(), IggyError>
+pub(crate) async fn send_ok_response<T>(
+    stream: &mut T,
+    request_id: Option<u32>,
+    payload: &[u8],
+) -> Result<(), IggyError>
 where
     T: AsyncRead + AsyncWrite + Unpin,
 {
-    send_response(stream, STATUS_OK, payload).await
+    send_response(stream, request_id, STATUS_OK, payload).await
 }
 
 pub(crate) async fn send_ok_response_vectored<T>(
     stream: &mut T,
+    request_id: Option<u32>,
     length: &[u8],
     slices: Vec<IoSlice<'_>>,
 ) -> Result<(), IggyError>
 where
     T: AsyncRead + AsyncWrite + Unpin,
 {
-    send_response_vectored(stream, STATUS_OK, length, slices).await
+    send_response_vectored(stream, request_id, STATUS_OK, length, slices).await
+}
+
+/// Sends the OK response with the payload compressed by the `FrameCompressor`.
+pub(crate) async fn send_compressed_ok_response<T>(
+    stream: &mut T,
+    request_id: Option<u32>,
+    payload: &[u8],
+) -> Result<(), IggyError>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    debug!("Sending compressed response of len: {}...", payload.len());
+    let length = (payload.len() as u32 | COMPRESSED_FRAME_FLAG).to_le_bytes();
+    let request_id = request_id.map(u32::to_le_bytes);
+    let request_id = request_id.as_ref().map_or(&[][..], |id| &id[..]);
+    stream
+        .write_all(
+            &[request_id, STATUS_OK, &length, payload]
+                .as_slice()
+                .concat(),
+        )
+        .await
+        .map_err(|_| IggyError::TcpError)?;
+    debug!("Sent compressed response");
+    Ok(())
 }
 
 pub(crate) async fn send_error_response<T>(
     stream: &mut T,
+    request_id: Option<u32>,
     error: IggyError,
 ) -> Result<(), IggyError>
 where
     T: AsyncRead + AsyncWrite + Unpin,
 {
-    send_response(stream, &error.as_code().to_le_bytes(), &[]).await
+    send_response(stream, request_id, &error.as_code().to_le_bytes(), &[]).await
 }
 
 pub(crate) async fn send_response<T>(
     stream: &mut T,
+    request_id: Option<u32>,
     status: &[u8],
     payload: &[u8],
 ) -> Result<(), IggyError>
@@ -89,8 +151,10 @@ where
         status
     );
     let length = (payload.len() as u32).to_le_bytes();
+    let request_id = request_id.map(u32::to_le_bytes);
+    let request_id = request_id.as_ref().map_or(&[][..], |id| &id[..]);
     stream
-        .write_all(&[status, &length, payload].as_slice().concat())
+        .write_all(&[request_id, status, &length, payload].as_slice().concat())
         .await
         .map_err(|_| IggyError::TcpError)?;
     debug!("Sent response with status: {:?}", status);
@@ -99,6 +163,7 @@ where
 
 pub(crate) async fn send_response_vectored<T>(
     stream: &mut T,
+    request_id: Option<u32>,
     status: &[u8],
     length: &[u8],
     mut slices: Vec<IoSlice<'_>>,
@@ -111,7 +176,13 @@ where
         slices.len(),
         status
     );
-    let prefix = [IoSlice::new(status), IoSlice::new(length)];
+    let request_id = request_id.map(u32::to_le_bytes);
+    let request_id = request_id.as_ref().map_or(&[][..], |id| &id[..]);
+    let prefix = [
+        IoSlice::new(request_id),
+        IoSlice::new(status),
+        IoSlice::new(length),
+    ];
     slices.splice(0..0, prefix);
     let mut slice_refs = slices.as_mut_slice();
     while !slice_refs.is_empty() {
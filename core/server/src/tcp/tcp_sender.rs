@@ -16,6 +16,7 @@
  * under the License.
  */
 
+use crate::binary::frame_compressor::FrameCompressor;
 use crate::binary::sender::Sender;
 use crate::tcp::COMPONENT;
 use crate::{server_error::ServerError, tcp::sender};
@@ -25,24 +26,37 @@ use tokio::{io::AsyncWriteExt, net::TcpStream};
 
 #[derive(Debug)]
 pub struct TcpSender {
+    pub(crate) sent_bytes: u64,
+    pub(crate) pipelining: bool,
+    pub(crate) request_id: Option<u32>,
+    pub(crate) compressor: FrameCompressor,
     pub(crate) stream: TcpStream,
 }
 
 impl Sender for TcpSender {
     async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError> {
+        if let Some(result) = self.compressor.read(buffer) {
+            return result;
+        }
         sender::read(&mut self.stream, buffer).await
     }
 
     async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
-        sender::send_empty_ok_response(&mut self.stream).await
+        self.sent_bytes += sender::response_header_size(self.request_id) as u64;
+        sender::send_empty_ok_response(&mut self.stream, self.request_id).await
     }
 
     async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
-        sender::send_ok_response(&mut self.stream, payload).await
+        if let Some(compressed) = self.compressor.compress_response(payload) {
+            return self.send_compressed_ok_response(&compressed).await;
+        }
+        self.sent_bytes += (sender::response_header_size(self.request_id) + payload.len()) as u64;
+        sender::send_ok_response(&mut self.stream, self.request_id, payload).await
     }
 
     async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
-        sender::send_error_response(&mut self.stream, error).await
+        self.sent_bytes += sender::response_header_size(self.request_id) as u64;
+        sender::send_error_response(&mut self.stream, self.request_id, error).await
     }
 
     async fn shutdown(&mut self) -> Result<(), ServerError> {
@@ -60,6 +74,19 @@ impl Sender for TcpSender {
         length: &[u8],
         slices: Vec<std::io::IoSlice<'_>>,
     ) -> Result<(), IggyError> {
-        sender::send_ok_response_vectored(&mut self.stream, length, slices).await
+        if let Some(compressed) = self.compressor.compress_response_vectored(&slices) {
+            return self.send_compressed_ok_response(&compressed).await;
+        }
+        self.sent_bytes += (sender::response_header_size(self.request_id)
+            + slices.iter().map(|slice| slice.len()).sum::<usize>())
+            as u64;
+        sender::send_ok_response_vectored(&mut self.stream, self.request_id, length, slices).await
+    }
+}
+
+impl TcpSender {
+    async fn send_compressed_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        self.sent_bytes += (sender::response_header_size(self.request_id) + payload.len()) as u64;
+        sender::send_compressed_ok_response(&mut self.stream, self.request_id, payload).await
     }
 }
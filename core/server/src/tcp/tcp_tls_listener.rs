@@ -20,11 +20,13 @@ use crate::binary::sender::SenderKind;
 use crate::configs::tcp::TcpTlsConfig;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::utils::certificates::{
+    ReloadableCertResolver, load_certificates, start_certificate_reloader,
+};
 use crate::tcp::connection_handler::{handle_connection, handle_error};
 use rustls::ServerConfig;
+use rustls::crypto::CryptoProvider;
 use rustls::pki_types::{CertificateDer, PrivateKeyDer};
-use rustls_pemfile::{certs, private_key};
-use std::io::BufReader;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tokio::net::TcpSocket;
@@ -43,24 +45,40 @@ pub(crate) async fn start(
     tokio::spawn(async move {
         let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
 
-        let (certs, key) =
-            if config.self_signed && !std::path::Path::new(&config.cert_file).exists() {
-                info!("Generating self-signed certificate for TCP TLS server");
-                generate_self_signed_cert()
-                    .unwrap_or_else(|e| panic!("Failed to generate self-signed certificate: {e}"))
-            } else {
-                info!(
-                    "Loading certificates from cert_file: {}, key_file: {}",
-                    config.cert_file, config.key_file
-                );
-                load_certificates(&config.cert_file, &config.key_file)
-                    .unwrap_or_else(|e| panic!("Failed to load certificates: {e}"))
-            };
-
+        let self_signed = config.self_signed && !std::path::Path::new(&config.cert_file).exists();
+        let (certs, key) = if self_signed {
+            info!("Generating self-signed certificate for TCP TLS server");
+            generate_self_signed_cert()
+                .unwrap_or_else(|e| panic!("Failed to generate self-signed certificate: {e}"))
+        } else {
+            info!(
+                "Loading certificates from cert_file: {}, key_file: {}",
+                config.cert_file, config.key_file
+            );
+            load_certificates(&config.cert_file, &config.key_file)
+                .unwrap_or_else(|e| panic!("Failed to load certificates: {e}"))
+        };
+
+        let provider = CryptoProvider::get_default()
+            .cloned()
+            .unwrap_or_else(|| Arc::new(rustls::crypto::aws_lc_rs::default_provider()));
+        let resolver = Arc::new(
+            ReloadableCertResolver::new(certs, key, provider)
+                .unwrap_or_else(|e| panic!("Unable to create TLS certificate resolver: {e}")),
+        );
         let server_config = ServerConfig::builder()
             .with_no_client_auth()
-            .with_single_cert(certs, key)
-            .unwrap_or_else(|e| panic!("Unable to create TLS server config: {e}"));
+            .with_cert_resolver(resolver.clone());
+
+        let config_changes = system.read().await.subscribe_config_changes();
+        if let Some(config_changes) = config_changes.filter(|_| !self_signed) {
+            start_certificate_reloader("TCP TLS", resolver, config_changes, |config| {
+                (
+                    config.tcp.tls.cert_file.clone(),
+                    config.tcp.tls.key_file.clone(),
+                )
+            });
+        }
 
         let acceptor = TlsAcceptor::from(Arc::new(server_config));
 
@@ -141,22 +159,3 @@ fn generate_self_signed_cert()
 -> Result<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>), Box<dyn std::error::Error>> {
     iggy_common::generate_self_signed_certificate("localhost")
 }
-
-fn load_certificates(
-    cert_file: &str,
-    key_file: &str,
-) -> Result<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>), Box<dyn std::error::Error>> {
-    let cert_file = std::fs::File::open(cert_file)?;
-    let mut cert_reader = BufReader::new(cert_file);
-    let certs: Vec<_> = certs(&mut cert_reader).collect::<Result<Vec<_>, _>>()?;
-
-    if certs.is_empty() {
-        return Err("No certificates found in certificate file".into());
-    }
-
-    let key_file = std::fs::File::open(key_file)?;
-    let mut key_reader = BufReader::new(key_file);
-    let key = private_key(&mut key_reader)?.ok_or("No private key found in key file")?;
-
-    Ok((certs, key))
-}
@@ -0,0 +1,125 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::sender::SenderKind;
+use crate::configs::unix::UnixConfig;
+use crate::streaming::systems::system::SharedSystem;
+use crate::tcp::connection_handler::{handle_connection, handle_error};
+use crate::unix::COMPONENT;
+use std::fs::Permissions;
+use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+use std::path::Path;
+use tokio::net::{UnixListener, UnixStream};
+use tracing::{error, info, warn};
+
+pub async fn start(config: UnixConfig, system: SharedSystem) {
+    let path = Path::new(&config.path);
+    if let Ok(metadata) = std::fs::symlink_metadata(path) {
+        if !metadata.file_type().is_socket() {
+            panic!(
+                "Unable to bind Unix socket, the path: {} already exists and is not a socket.",
+                config.path
+            );
+        }
+
+        warn!("Removing the stale Unix socket: {}", config.path);
+        std::fs::remove_file(path).expect("Unable to remove the stale Unix socket.");
+    }
+
+    let listener = UnixListener::bind(path).expect("Unable to start Unix server.");
+    let mode = config
+        .get_permissions_mode()
+        .expect("Invalid Unix socket permissions.");
+    std::fs::set_permissions(path, Permissions::from_mode(mode))
+        .expect("Unable to set the Unix socket permissions.");
+
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    let peer_uid = get_peer_uid(&stream);
+                    info!("Accepted new Unix connection, peer UID: {peer_uid:?}");
+                    let session = system.read().await.add_unix_client(peer_uid).await;
+
+                    let client_id = session.client_id;
+                    info!("Created new session: {session}");
+                    let peer_username = get_peer_username(&config, peer_uid);
+
+                    let system = system.clone();
+                    let mut sender = SenderKind::get_unix_sender(stream);
+                    tokio::spawn(async move {
+                        // The login waits for the system lock, so it's done by the connection task before
+                        // handling any request, rather than by the accept loop stalling the other connections.
+                        if let Some(username) = peer_username
+                            && let Err(error) = system
+                                .read()
+                                .await
+                                .login_user_with_peer_credentials(&username, &session)
+                                .await
+                        {
+                            warn!(
+                                "{COMPONENT} (error: {error}) - failed to login user: {username} using peer credentials, client: {client_id}"
+                            );
+                        }
+
+                        if let Err(error) =
+                            handle_connection(session, &mut sender, system.clone()).await
+                        {
+                            handle_error(error);
+                            system.read().await.delete_client(client_id).await;
+                            if let Err(error) = sender.shutdown().await {
+                                error!(
+                                    "Failed to shutdown Unix stream for client: {client_id}. {error}"
+                                );
+                            } else {
+                                info!("Successfully closed Unix stream for client: {client_id}.");
+                            }
+                        }
+                    });
+                }
+                Err(error) => error!("Unable to accept Unix socket. {error}"),
+            }
+        }
+    });
+}
+
+/// Returns the user ID of the peer process, which identifies the client for the login rate limiting.
+fn get_peer_uid(stream: &UnixStream) -> Option<u32> {
+    match stream.peer_cred() {
+        Ok(credentials) => Some(credentials.uid()),
+        Err(error) => {
+            warn!("{COMPONENT} (error: {error}) - failed to read the peer credentials");
+            None
+        }
+    }
+}
+
+/// Returns the username mapped to the user ID of the peer process, if the peer credentials
+/// based authentication is enabled.
+fn get_peer_username(config: &UnixConfig, uid: Option<u32>) -> Option<String> {
+    if !config.peer_credentials.enabled {
+        return None;
+    }
+
+    let uid = uid?;
+    let username = config.peer_credentials.get_username(uid);
+    if username.is_none() {
+        info!("No user is mapped to the peer UID: {uid}, the client must sign in.");
+    }
+    username.map(ToString::to_string)
+}
@@ -0,0 +1,74 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::sender::Sender;
+use crate::unix::COMPONENT;
+use crate::{server_error::ServerError, tcp::sender};
+use error_set::ErrContext;
+use iggy_common::IggyError;
+use tokio::{io::AsyncWriteExt, net::UnixStream};
+
+#[derive(Debug)]
+pub struct UnixSender {
+    pub(crate) sent_bytes: u64,
+    pub(crate) pipelining: bool,
+    pub(crate) request_id: Option<u32>,
+    pub(crate) stream: UnixStream,
+}
+
+impl Sender for UnixSender {
+    async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError> {
+        sender::read(&mut self.stream, buffer).await
+    }
+
+    async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
+        self.sent_bytes += sender::response_header_size(self.request_id) as u64;
+        sender::send_empty_ok_response(&mut self.stream, self.request_id).await
+    }
+
+    async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        self.sent_bytes += (sender::response_header_size(self.request_id) + payload.len()) as u64;
+        sender::send_ok_response(&mut self.stream, self.request_id, payload).await
+    }
+
+    async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
+        self.sent_bytes += sender::response_header_size(self.request_id) as u64;
+        sender::send_error_response(&mut self.stream, self.request_id, error).await
+    }
+
+    async fn shutdown(&mut self) -> Result<(), ServerError> {
+        self.stream
+            .shutdown()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to shutdown Unix stream")
+            })
+            .map_err(ServerError::IoError)
+    }
+
+    async fn send_ok_response_vectored(
+        &mut self,
+        length: &[u8],
+        slices: Vec<std::io::IoSlice<'_>>,
+    ) -> Result<(), IggyError> {
+        self.sent_bytes += (sender::response_header_size(self.request_id)
+            + slices.iter().map(|slice| slice.len()).sum::<usize>())
+            as u64;
+        sender::send_ok_response_vectored(&mut self.stream, self.request_id, length, slices).await
+    }
+}
@@ -0,0 +1,73 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::sender::SenderKind;
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::systems::system::SharedSystem;
+use crate::tcp::connection_handler::{handle_connection, handle_error};
+use crate::websocket::COMPONENT;
+use hyper::upgrade::OnUpgrade;
+use hyper_util::rt::TokioIo;
+use iggy_common::websocket::{WebSocketRole, WebSocketStream};
+use std::net::SocketAddr;
+use tracing::{error, info};
+
+/// Handles the WebSocket connection, once the HTTP connection has been upgraded,
+/// exactly the same way as the TCP connection.
+pub fn start(system: SharedSystem, address: SocketAddr, upgrade: OnUpgrade, max_frame_size: usize) {
+    tokio::spawn(async move {
+        let upgraded = match upgrade.await {
+            Ok(upgraded) => upgraded,
+            Err(error) => {
+                error!(
+                    "{COMPONENT} (error: {error}) - failed to upgrade the connection from: {address}"
+                );
+                return;
+            }
+        };
+
+        info!("Accepted new WebSocket connection: {address}");
+        let session = system
+            .read()
+            .await
+            .add_client(&address, Transport::WebSocket)
+            .await;
+
+        let client_id = session.client_id;
+        info!("Created new session: {session}");
+        let stream = WebSocketStream::new(
+            TokioIo::new(upgraded),
+            WebSocketRole::Server,
+            max_frame_size,
+        );
+        let mut sender = SenderKind::get_websocket_sender(stream);
+        if let Err(error) = handle_connection(session, &mut sender, system.clone()).await {
+            handle_error(error);
+            system.read().await.delete_client(client_id).await;
+            if let Err(error) = sender.shutdown().await {
+                error!(
+                    "Failed to shutdown WebSocket connection for client: {client_id}, address: {address}. {error}"
+                );
+            } else {
+                info!(
+                    "Successfully closed WebSocket connection for client: {client_id}, address: {address}."
+                );
+            }
+        }
+    });
+}
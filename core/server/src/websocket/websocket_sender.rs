@@ -0,0 +1,98 @@
+/* Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use crate::binary::sender::Sender;
+use crate::websocket::COMPONENT;
+use crate::{server_error::ServerError, tcp::sender};
+use error_set::ErrContext;
+use hyper::upgrade::Upgraded;
+use hyper_util::rt::TokioIo;
+use iggy_common::IggyError;
+use iggy_common::websocket::WebSocketStream;
+use tokio::io::AsyncWriteExt;
+
+/// The upgraded HTTP connection carrying the binary protocol frames.
+pub type WebSocketConnection = WebSocketStream<TokioIo<Upgraded>>;
+
+#[derive(Debug)]
+pub struct WebSocketSender {
+    pub(crate) sent_bytes: u64,
+    pub(crate) pipelining: bool,
+    pub(crate) request_id: Option<u32>,
+    pub(crate) stream: WebSocketConnection,
+}
+
+impl WebSocketSender {
+    /// The responses are buffered as WebSocket frames, so they must be flushed once written.
+    async fn flush(&mut self) -> Result<(), IggyError> {
+        self.stream.flush().await.map_err(|_| IggyError::TcpError)
+    }
+}
+
+impl Sender for WebSocketSender {
+    async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError> {
+        // The invalid WebSocket frames leave the stream unusable, so the connection is closed rather than answered with an error.
+        sender::read(&mut self.stream, buffer)
+            .await
+            .map_err(|error| match error {
+                IggyError::TcpError => IggyError::ConnectionClosed,
+                error => error,
+            })
+    }
+
+    async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
+        self.sent_bytes += sender::response_header_size(self.request_id) as u64;
+        sender::send_empty_ok_response(&mut self.stream, self.request_id).await?;
+        self.flush().await
+    }
+
+    async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        self.sent_bytes += (sender::response_header_size(self.request_id) + payload.len()) as u64;
+        sender::send_ok_response(&mut self.stream, self.request_id, payload).await?;
+        self.flush().await
+    }
+
+    async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
+        self.sent_bytes += sender::response_header_size(self.request_id) as u64;
+        sender::send_error_response(&mut self.stream, self.request_id, error).await?;
+        self.flush().await
+    }
+
+    async fn shutdown(&mut self) -> Result<(), ServerError> {
+        self.stream
+            .shutdown()
+            .await
+            .with_error_context(|error| {
+                format!("{COMPONENT} (error: {error}) - failed to shutdown WebSocket connection")
+            })
+            .map_err(ServerError::IoError)
+    }
+
+    async fn send_ok_response_vectored(
+        &mut self,
+        length: &[u8],
+        slices: Vec<std::io::IoSlice<'_>>,
+    ) -> Result<(), IggyError> {
+        self.sent_bytes += (sender::response_header_size(self.request_id)
+            + slices.iter().map(|slice| slice.len()).sum::<usize>())
+            as u64;
+        sender::send_ok_response_vectored(&mut self.stream, self.request_id, length, slices)
+            .await?;
+        self.flush().await
+    }
+}
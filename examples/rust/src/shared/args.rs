@@ -17,8 +17,8 @@
  */
 
 use clap::Parser;
-use iggy::prelude::IggyDuration;
 use iggy::prelude::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::prelude::{FrameCompression, IggyDuration};
 use std::str::FromStr;
 
 #[derive(Parser, Debug)]
@@ -108,6 +108,15 @@ pub struct Args {
     #[arg(long, default_value = "false")]
     pub tcp_nodelay: bool,
 
+    #[arg(long, default_value = "false")]
+    pub tcp_pipelining: bool,
+
+    #[arg(long, default_value = "/tmp/iggy.sock")]
+    pub unix_socket_path: String,
+
+    #[arg(long, default_value = "ws://localhost:3000/ws")]
+    pub websocket_url: String,
+
     #[arg(long, default_value = "127.0.0.1:0")]
     pub quic_client_address: String,
 
@@ -158,6 +167,12 @@ pub struct Args {
 
     #[arg(long, default_value = "5s")]
     pub quic_heartbeat_interval: String,
+
+    #[arg(long, default_value = "none")]
+    pub frame_compression: String,
+
+    #[arg(long, default_value = "1024")]
+    pub frame_compression_min_size: u32,
 }
 
 impl Args {
@@ -202,6 +217,9 @@ impl Default for Args {
             tcp_tls_enabled: false,
             tcp_tls_domain: "localhost".to_string(),
             tcp_nodelay: true,
+            tcp_pipelining: false,
+            unix_socket_path: "/tmp/iggy.sock".to_string(),
+            websocket_url: "ws://localhost:3000/ws".to_string(),
             quic_client_address: "127.0.0.1:0".to_string(),
             quic_server_address: "127.0.0.1:8080".to_string(),
             quic_server_name: "localhost".to_string(),
@@ -219,6 +237,8 @@ impl Default for Args {
             quic_max_idle_timeout: 10000,
             quic_validate_certificate: false,
             quic_heartbeat_interval: "5s".to_string(),
+            frame_compression: "none".to_string(),
+            frame_compression_min_size: 1024,
         }
     }
 }
@@ -295,6 +315,9 @@ impl Args {
             tcp_tls_domain: self.tcp_tls_domain.clone(),
             tcp_tls_ca_file: None,
             tcp_nodelay: self.tcp_nodelay,
+            tcp_pipelining: self.tcp_pipelining,
+            unix_socket_path: self.unix_socket_path.clone(),
+            websocket_url: self.websocket_url.clone(),
             quic_client_address: self.quic_client_address.clone(),
             quic_server_address: self.quic_server_address.clone(),
             quic_server_name: self.quic_server_name.clone(),
@@ -312,6 +335,10 @@ impl Args {
             quic_max_idle_timeout: self.quic_max_idle_timeout,
             quic_validate_certificate: self.quic_validate_certificate,
             quic_heartbeat_interval: self.quic_heartbeat_interval.clone(),
+            frame_compression: FrameCompression::new(
+                self.frame_compression.parse().unwrap_or_default(),
+                self.frame_compression_min_size,
+            ),
         }
     }
 
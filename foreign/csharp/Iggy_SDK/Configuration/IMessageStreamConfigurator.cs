@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+using Apache.Iggy.Contracts.Http;
 using Apache.Iggy.Enums;
 
 namespace Apache.Iggy.Configuration;
@@ -28,4 +29,5 @@ public interface IMessageStreamConfigurator
     public Action<TlsSettings> TlsSettings { get; set; }
     public int ReceiveBufferSize { get; set; }
     public int SendBufferSize { get; set; }
+    public ClientMetadata ClientMetadata { get; set; }
 }
\ No newline at end of file
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+using Apache.Iggy.Contracts.Http;
 using Apache.Iggy.Enums;
 
 namespace Apache.Iggy.Configuration;
@@ -44,4 +45,5 @@ public sealed class MessageStreamConfigurator : IMessageStreamConfigurator
     };
     public int ReceiveBufferSize { get; set; } = 4096;
     public int SendBufferSize { get; set; } = 4096;
+    public ClientMetadata ClientMetadata { get; set; } = new();
 }
\ No newline at end of file
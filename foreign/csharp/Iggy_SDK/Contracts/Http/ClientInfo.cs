@@ -24,5 +24,11 @@ public sealed class ClientResponse
     public required uint UserId { get; init; }
     public required string Transport { get; init; }
     public required int ConsumerGroupsCount { get; init; }
+    public ulong ConnectedAt { get; init; }
+    public ulong LastActivityAt { get; init; }
+    public ulong BytesReceived { get; init; }
+    public ulong BytesSent { get; init; }
+    public string? AuthenticationMethod { get; init; }
+    public ClientMetadata? Metadata { get; init; }
     public IEnumerable<ConsumerGroupInfo> ConsumerGroups { get; init; } = [];
 }
\ No newline at end of file
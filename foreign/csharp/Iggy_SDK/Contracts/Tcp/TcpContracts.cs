@@ -63,6 +63,29 @@ internal static class TcpContracts
         return bytes;
     }
     
+    internal static byte[] IdentifyClient(ClientMetadata metadata)
+    {
+        var bytes = new List<byte>();
+        WriteShortString(bytes, metadata.ApplicationName);
+        WriteShortString(bytes, metadata.SdkLanguage);
+        WriteShortString(bytes, metadata.SdkVersion);
+        WriteShortString(bytes, metadata.Hostname);
+        bytes.Add((byte)metadata.Labels.Count);
+        foreach (var (key, value) in metadata.Labels.OrderBy(label => label.Key, StringComparer.Ordinal))
+        {
+            WriteShortString(bytes, key);
+            WriteShortString(bytes, value);
+        }
+        return bytes.ToArray();
+    }
+
+    private static void WriteShortString(List<byte> bytes, string value)
+    {
+        var encoded = Encoding.UTF8.GetBytes(value);
+        bytes.Add((byte)encoded.Length);
+        bytes.AddRange(encoded);
+    }
+
     internal static byte[] GetUser(Identifier userId)
     {
         Span<byte> bytes = stackalloc byte[userId.Length + 2];
@@ -34,6 +34,8 @@ public enum SystemSnapshotType
     ServerLogs,
     /// Server configuration
     ServerConfig,
+    /// Connected clients with their identification metadata
+    Clients,
     /// Everything
     All,
 }
\ No newline at end of file
@@ -18,7 +18,9 @@
 using System.ComponentModel;
 using System.Net.Security;
 using System.Net.Sockets;
+using System.Reflection;
 using Apache.Iggy.Configuration;
+using Apache.Iggy.Contracts.Http;
 using Apache.Iggy.ConnectionStream;
 using Apache.Iggy.Enums;
 using Apache.Iggy.Exceptions;
@@ -47,9 +49,33 @@ public static class MessageStreamFactory
     private static TcpMessageStream CreateTcpMessageStream(IMessageStreamConfigurator options, ILoggerFactory loggerFactory)
     {
         var socket = CreateTcpStream(options);
-        return new TcpMessageStreamBuilder(socket, options, loggerFactory)
+        var stream = new TcpMessageStreamBuilder(socket, options, loggerFactory)
             .WithSendMessagesDispatcher() //this internally resolves whether the message dispatcher is created or not.
             .Build();
+        try
+        {
+            stream.IdentifyClientAsync(WithSdkDefaults(options.ClientMetadata)).GetAwaiter().GetResult();
+        }
+        catch (Exception e)
+        {
+            loggerFactory.CreateLogger(typeof(MessageStreamFactory))
+                .LogWarning(e, "Could not send identification metadata to the server");
+        }
+        return stream;
+    }
+
+    private static ClientMetadata WithSdkDefaults(ClientMetadata metadata)
+    {
+        return new ClientMetadata
+        {
+            ApplicationName = string.IsNullOrEmpty(metadata.ApplicationName)
+                ? Assembly.GetEntryAssembly()?.GetName().Name ?? string.Empty
+                : metadata.ApplicationName,
+            SdkLanguage = "csharp",
+            SdkVersion = typeof(MessageStreamFactory).Assembly.GetName().Version?.ToString() ?? string.Empty,
+            Hostname = string.IsNullOrEmpty(metadata.Hostname) ? Environment.MachineName : metadata.Hostname,
+            Labels = metadata.Labels
+        };
     }
 
     private static IConnectionStream CreateTcpStream(IMessageStreamConfigurator options)
@@ -26,4 +26,5 @@ public interface IIggySystem
     public Task<ClientResponse?> GetMeAsync(CancellationToken token = default);
     public Task<Stats?> GetStatsAsync(CancellationToken token = default);
     public Task PingAsync(CancellationToken token = default);
+    public Task IdentifyClientAsync(ClientMetadata metadata, CancellationToken token = default);
 }
\ No newline at end of file
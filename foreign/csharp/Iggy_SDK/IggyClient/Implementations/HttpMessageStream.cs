@@ -529,6 +529,12 @@ public class HttpMessageStream : IIggyClient
         return null;
     }
 
+    [Obsolete("This method is only supported in TCP protocol", true)]
+    public Task IdentifyClientAsync(ClientMetadata metadata, CancellationToken token = default)
+    {
+        throw new FeatureUnavailableException();
+    }
+
     [Obsolete("This method is only supported in TCP protocol", true)]
     public Task JoinConsumerGroupAsync(JoinConsumerGroupRequest request, CancellationToken token = default)
     {
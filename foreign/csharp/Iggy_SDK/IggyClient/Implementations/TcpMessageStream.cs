@@ -737,6 +737,18 @@ public sealed class TcpMessageStream : IIggyClient, IDisposable
         await CheckResponseAsync(token);
     }
 
+    public async Task IdentifyClientAsync(ClientMetadata metadata, CancellationToken token = default)
+    {
+        var message = TcpContracts.IdentifyClient(metadata);
+        var payload = new byte[4 + BufferSizes.InitialBytesLength + message.Length];
+        TcpMessageStreamHelpers.CreatePayload(payload, message, CommandCodes.IDENTIFY_CLIENT_CODE);
+
+        await _stream.SendAsync(payload, token);
+        await _stream.FlushAsync(token);
+
+        await CheckResponseAsync(token);
+    }
+
     public async Task<IReadOnlyList<ClientResponse>> GetClientsAsync(CancellationToken token = default)
     {
         var message = Array.Empty<byte>();
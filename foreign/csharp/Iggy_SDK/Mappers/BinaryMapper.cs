@@ -252,6 +252,12 @@ internal static class BinaryMapper
             UserId = response.UserId,
             Transport = response.Transport,
             ConsumerGroupsCount = response.ConsumerGroupsCount,
+            ConnectedAt = response.ConnectedAt,
+            LastActivityAt = response.LastActivityAt,
+            BytesReceived = response.BytesReceived,
+            BytesSent = response.BytesSent,
+            AuthenticationMethod = response.AuthenticationMethod,
+            Metadata = response.Metadata,
             ConsumerGroups = consumerGroups
         };
     }
@@ -291,7 +297,21 @@ internal static class BinaryMapper
         readBytes = 4 + 1 + 4 + 4 + addressLength;
         position += readBytes;
         int consumerGroupsCount = BinaryPrimitives.ReadInt32LittleEndian(payload[position..(position + 4)]);
-        readBytes += 4;
+        ulong connectedAt = BinaryPrimitives.ReadUInt64LittleEndian(payload[(position + 4)..(position + 12)]);
+        ulong lastActivityAt = BinaryPrimitives.ReadUInt64LittleEndian(payload[(position + 12)..(position + 20)]);
+        ulong bytesReceived = BinaryPrimitives.ReadUInt64LittleEndian(payload[(position + 20)..(position + 28)]);
+        ulong bytesSent = BinaryPrimitives.ReadUInt64LittleEndian(payload[(position + 28)..(position + 36)]);
+        string? authenticationMethod = payload[position + 36] switch
+        {
+            1 => "Password",
+            2 => "PersonalAccessToken",
+            _ => null,
+        };
+        int metadataLength = BinaryPrimitives.ReadInt32LittleEndian(payload[(position + 37)..(position + 41)]);
+        ClientMetadata? metadata = metadataLength > 0
+            ? MapClientMetadata(payload[(position + 41)..(position + 41 + metadataLength)])
+            : null;
+        readBytes += 4 + 8 + 8 + 8 + 8 + 1 + 4 + metadataLength;
 
         return (new ClientResponse
         {
@@ -299,9 +319,49 @@ internal static class BinaryMapper
             UserId = userId,
             Transport = transport,
             Address = address,
-            ConsumerGroupsCount = consumerGroupsCount
+            ConsumerGroupsCount = consumerGroupsCount,
+            ConnectedAt = connectedAt,
+            LastActivityAt = lastActivityAt,
+            BytesReceived = bytesReceived,
+            BytesSent = bytesSent,
+            AuthenticationMethod = authenticationMethod,
+            Metadata = metadata
         }, readBytes);
     }
+
+    private static ClientMetadata MapClientMetadata(ReadOnlySpan<byte> payload)
+    {
+        var position = 0;
+        var applicationName = ReadShortString(payload, ref position);
+        var sdkLanguage = ReadShortString(payload, ref position);
+        var sdkVersion = ReadShortString(payload, ref position);
+        var hostname = ReadShortString(payload, ref position);
+        int labelsCount = payload[position];
+        position += 1;
+        var labels = new Dictionary<string, string>(labelsCount);
+        for (int i = 0; i < labelsCount; i++)
+        {
+            var key = ReadShortString(payload, ref position);
+            labels[key] = ReadShortString(payload, ref position);
+        }
+
+        return new ClientMetadata
+        {
+            ApplicationName = applicationName,
+            SdkLanguage = sdkLanguage,
+            SdkVersion = sdkVersion,
+            Hostname = hostname,
+            Labels = labels
+        };
+    }
+
+    private static string ReadShortString(ReadOnlySpan<byte> payload, ref int position)
+    {
+        int length = payload[position];
+        var value = Encoding.UTF8.GetString(payload[(position + 1)..(position + 1 + length)]);
+        position += 1 + length;
+        return value;
+    }
     
     internal static OffsetResponse MapOffsets(ReadOnlySpan<byte> payload)
     {
@@ -557,9 +617,13 @@ internal static class BinaryMapper
         return streams.AsReadOnly();
     }
 
+    private const int StreamRetentionSize = 17;
+
     internal static StreamResponse MapStream(ReadOnlySpan<byte> payload)
     {
         (StreamResponse stream, int position) = MapToStream(payload, 0);
+        // Stream retention: max size (u64), message expiry (u64) and size policy (u8).
+        position += StreamRetentionSize;
         List<TopicResponse> topics = new();
         int length = payload.Length;
 
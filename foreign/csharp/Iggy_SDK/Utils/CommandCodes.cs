@@ -25,6 +25,7 @@ internal static class CommandCodes
     internal const int GET_ME_CODE = 20;
     internal const int GET_CLIENT_CODE = 21;
     internal const int GET_CLIENTS_CODE = 22;
+    internal const int IDENTIFY_CLIENT_CODE = 24;
     internal const int GET_USER_CODE = 31;
     internal const int GET_USERS_CODE = 32;
     internal const int CREATE_USER_CODE = 33;
@@ -202,9 +202,12 @@ public sealed class BinaryMapper
         byte[] topicCombinedPayload = new byte[topicPayload1.Length];
         topicPayload1.CopyTo(topicCombinedPayload.AsSpan());
 
-        byte[] streamCombinedPayload = new byte[streamPayload.Length + topicCombinedPayload.Length];
+        byte[] retentionPayload = BinaryFactory.CreateStreamRetentionPayload(ulong.MaxValue, ulong.MaxValue, 1);
+
+        byte[] streamCombinedPayload = new byte[streamPayload.Length + retentionPayload.Length + topicCombinedPayload.Length];
         streamPayload.CopyTo(streamCombinedPayload.AsSpan());
-        topicCombinedPayload.CopyTo(streamCombinedPayload.AsSpan(streamPayload.Length));
+        retentionPayload.CopyTo(streamCombinedPayload.AsSpan(streamPayload.Length));
+        topicCombinedPayload.CopyTo(streamCombinedPayload.AsSpan(streamPayload.Length + retentionPayload.Length));
 
         // Act
         var response = Mappers.BinaryMapper.MapStream(streamCombinedPayload);
@@ -72,6 +72,15 @@ internal sealed class BinaryFactory
         nameBytes.CopyTo(payload.AsSpan(33));
         return payload;
     }
+
+    internal static byte[] CreateStreamRetentionPayload(ulong maxSize, ulong messageExpiry, byte sizePolicy)
+    {
+        var payload = new byte[17];
+        BinaryPrimitives.WriteUInt64LittleEndian(payload, maxSize);
+        BinaryPrimitives.WriteUInt64LittleEndian(payload.AsSpan(8), messageExpiry);
+        payload[16] = sizePolicy;
+        return payload;
+    }
     
     internal static byte[] CreateTopicPayload(int id, int partitionsCount, int messageExpiry, string name,
         ulong sizeBytes, ulong messagesCount, ulong createdAt, byte replicationFactor, ulong maxTopicSize, int compressionType)
@@ -19,6 +19,7 @@ package binaryserialization
 
 import (
 	"encoding/binary"
+	"sort"
 
 	iggcon "github.com/apache/iggy/foreign/go/contracts"
 )
@@ -349,3 +350,27 @@ func SerializeCreatePersonalAccessToken(request iggcon.CreatePersonalAccessToken
 	binary.LittleEndian.PutUint32(bytes[len(bytes)-4:], request.Expiry)
 	return bytes
 }
+
+func SerializeClientMetadata(metadata iggcon.ClientMetadata) []byte {
+	keys := make([]string, 0, len(metadata.Labels))
+	for key := range metadata.Labels {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+
+	var bytes []byte
+	writeString := func(value string) {
+		bytes = append(bytes, byte(len(value)))
+		bytes = append(bytes, value...)
+	}
+	writeString(metadata.ApplicationName)
+	writeString(metadata.SdkLanguage)
+	writeString(metadata.SdkVersion)
+	writeString(metadata.Hostname)
+	bytes = append(bytes, byte(len(keys)))
+	for _, key := range keys {
+		writeString(key)
+		writeString(metadata.Labels[key])
+	}
+	return bytes
+}
@@ -483,17 +483,67 @@ func MapClientInfo(payload []byte, position int) (iggcon.ClientInfo, int) {
 	readBytes = 4 + 1 + 4 + 4 + addressLength
 	position += readBytes
 	consumerGroupsCount := binary.LittleEndian.Uint32(payload[position : position+4])
-	readBytes += 4
+	connectedAt := binary.LittleEndian.Uint64(payload[position+4 : position+12])
+	lastActivityAt := binary.LittleEndian.Uint64(payload[position+12 : position+20])
+	bytesReceived := binary.LittleEndian.Uint64(payload[position+20 : position+28])
+	bytesSent := binary.LittleEndian.Uint64(payload[position+28 : position+36])
+	authenticationMethod := ""
+	switch payload[position+36] {
+	case 1:
+		authenticationMethod = "Password"
+	case 2:
+		authenticationMethod = "PersonalAccessToken"
+	}
+	metadataLength := int(binary.LittleEndian.Uint32(payload[position+37 : position+41]))
+	var metadata *iggcon.ClientMetadata
+	if metadataLength > 0 {
+		metadata = deserializeClientMetadata(payload[position+41 : position+41+metadataLength])
+	}
+	readBytes += 4 + 8 + 8 + 8 + 8 + 1 + 4 + metadataLength
 
 	return iggcon.ClientInfo{
-		ID:                  id,
-		UserID:              userId,
-		Transport:           transport,
-		Address:             address,
-		ConsumerGroupsCount: consumerGroupsCount,
+		ID:                   id,
+		UserID:               userId,
+		Transport:            transport,
+		Address:              address,
+		ConsumerGroupsCount:  consumerGroupsCount,
+		ConnectedAt:          connectedAt,
+		LastActivityAt:       lastActivityAt,
+		BytesReceived:        bytesReceived,
+		BytesSent:            bytesSent,
+		AuthenticationMethod: authenticationMethod,
+		Metadata:             metadata,
 	}, readBytes
 }
 
+func deserializeClientMetadata(payload []byte) *iggcon.ClientMetadata {
+	position := 0
+	readString := func() string {
+		length := int(payload[position])
+		value := string(payload[position+1 : position+1+length])
+		position += 1 + length
+		return value
+	}
+
+	metadata := &iggcon.ClientMetadata{
+		ApplicationName: readString(),
+		SdkLanguage:     readString(),
+		SdkVersion:      readString(),
+		Hostname:        readString(),
+	}
+	labelsCount := int(payload[position])
+	position++
+	if labelsCount > 0 {
+		metadata.Labels = make(map[string]string, labelsCount)
+		for i := 0; i < labelsCount; i++ {
+			key := readString()
+			metadata.Labels[key] = readString()
+		}
+	}
+
+	return metadata
+}
+
 func DeserializeClient(payload []byte) *iggcon.ClientInfoDetails {
 	clientInfo, position := MapClientInfo(payload, 0)
 	consumerGroups := make([]iggcon.ConsumerGroupInfo, clientInfo.ConsumerGroupsCount)
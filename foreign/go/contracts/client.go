@@ -23,9 +23,24 @@ type ClientInfoDetails struct {
 }
 
 type ClientInfo struct {
-	ID                  uint32 `json:"id"`
-	Address             string `json:"address"`
-	UserID              uint32 `json:"userId"`
-	Transport           string `json:"transport"`
-	ConsumerGroupsCount uint32 `json:"consumerGroupsCount"`
+	ID                   uint32          `json:"id"`
+	Address              string          `json:"address"`
+	UserID               uint32          `json:"userId"`
+	Transport            string          `json:"transport"`
+	ConsumerGroupsCount  uint32          `json:"consumerGroupsCount"`
+	ConnectedAt          uint64          `json:"connectedAt"`
+	LastActivityAt       uint64          `json:"lastActivityAt"`
+	BytesReceived        uint64          `json:"bytesReceived"`
+	BytesSent            uint64          `json:"bytesSent"`
+	AuthenticationMethod string          `json:"authenticationMethod,omitempty"`
+	Metadata             *ClientMetadata `json:"metadata,omitempty"`
+}
+
+// ClientMetadata identifies the application behind a connected client.
+type ClientMetadata struct {
+	ApplicationName string            `json:"applicationName"`
+	SdkLanguage     string            `json:"sdkLanguage"`
+	SdkVersion      string            `json:"sdkVersion"`
+	Hostname        string            `json:"hostname"`
+	Labels          map[string]string `json:"labels,omitempty"`
 }
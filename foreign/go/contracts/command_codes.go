@@ -25,6 +25,7 @@ const (
 	GetMeCode                CommandCode = 20
 	GetClientCode            CommandCode = 21
 	GetClientsCode           CommandCode = 22
+	IdentifyClientCode       CommandCode = 24
 	GetUserCode              CommandCode = 31
 	GetUsersCode             CommandCode = 32
 	CreateUserCode           CommandCode = 33
@@ -255,4 +255,8 @@ type Client interface {
 	// GetClient get the info about a specific client by unique ID (not to be confused with the user).
 	// Authentication is required, and the permission to read the server info.
 	GetClient(clientId uint32) (*iggcon.ClientInfoDetails, error)
+
+	// IdentifyClient send the identification metadata (application name, SDK, hostname and labels) of the current client.
+	// Authentication is not required.
+	IdentifyClient(metadata iggcon.ClientMetadata) error
 }
@@ -18,6 +18,10 @@
 package tcp
 
 import (
+	"os"
+	"path/filepath"
+	"runtime/debug"
+
 	binaryserialization "github.com/apache/iggy/foreign/go/binary_serialization"
 	iggcon "github.com/apache/iggy/foreign/go/contracts"
 )
@@ -40,3 +44,41 @@ func (tms *IggyTcpClient) GetClient(clientId uint32) (*iggcon.ClientInfoDetails,
 
 	return binaryserialization.DeserializeClient(buffer), nil
 }
+
+func (tms *IggyTcpClient) IdentifyClient(metadata iggcon.ClientMetadata) error {
+	message := binaryserialization.SerializeClientMetadata(metadata)
+	_, err := tms.sendAndFetchResponse(message, iggcon.IdentifyClientCode)
+	return err
+}
+
+const sdkModulePath = "github.com/apache/iggy/foreign/go"
+
+func withSdkDefaults(metadata iggcon.ClientMetadata) iggcon.ClientMetadata {
+	metadata.SdkLanguage = "go"
+	metadata.SdkVersion = sdkVersion()
+	if metadata.ApplicationName == "" && len(os.Args) > 0 {
+		metadata.ApplicationName = filepath.Base(os.Args[0])
+	}
+	if metadata.Hostname == "" {
+		if hostname, err := os.Hostname(); err == nil {
+			metadata.Hostname = hostname
+		}
+	}
+	return metadata
+}
+
+func sdkVersion() string {
+	info, ok := debug.ReadBuildInfo()
+	if !ok {
+		return ""
+	}
+	if info.Main.Path == sdkModulePath {
+		return info.Main.Version
+	}
+	for _, dep := range info.Deps {
+		if dep.Path == sdkModulePath {
+			return dep.Version
+		}
+	}
+	return ""
+}
@@ -35,6 +35,7 @@ type Options struct {
 	Ctx               context.Context
 	ServerAddress     string
 	HeartbeatInterval time.Duration
+	ClientMetadata    iggcon.ClientMetadata
 }
 
 func GetDefaultOptions() Options {
@@ -65,6 +66,14 @@ func WithContext(ctx context.Context) Option {
 	}
 }
 
+// WithClientMetadata sets the identification metadata sent to the server after connecting.
+// The SDK language, version and hostname are filled in automatically when left empty.
+func WithClientMetadata(metadata iggcon.ClientMetadata) Option {
+	return func(opts *Options) {
+		opts.ClientMetadata = metadata
+	}
+}
+
 func NewIggyTcpClient(options ...Option) (*IggyTcpClient, error) {
 	opts := GetDefaultOptions()
 	for _, opt := range options {
@@ -89,6 +98,10 @@ func NewIggyTcpClient(options ...Option) (*IggyTcpClient, error) {
 		conn: conn.(*net.TCPConn),
 	}
 
+	if err = client.IdentifyClient(withSdkDefaults(opts.ClientMetadata)); err != nil {
+		log.Printf("[WARN] could not send identification metadata to the server: %v", err)
+	}
+
 	heartbeatInterval := opts.HeartbeatInterval
 	if heartbeatInterval > 0 {
 		go func() {
@@ -21,6 +21,7 @@ package org.apache.iggy.client.blocking;
 
 import org.apache.iggy.system.ClientInfo;
 import org.apache.iggy.system.ClientInfoDetails;
+import org.apache.iggy.system.ClientMetadata;
 import org.apache.iggy.system.Stats;
 import java.util.List;
 
@@ -34,6 +35,8 @@ public interface SystemClient {
 
     List<ClientInfo> getClients();
 
+    void identifyClient(ClientMetadata metadata);
+
     String ping();
 
 }
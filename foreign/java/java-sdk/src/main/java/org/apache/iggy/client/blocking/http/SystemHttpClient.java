@@ -23,6 +23,7 @@ import com.fasterxml.jackson.core.type.TypeReference;
 import org.apache.iggy.client.blocking.SystemClient;
 import org.apache.iggy.system.ClientInfo;
 import org.apache.iggy.system.ClientInfoDetails;
+import org.apache.iggy.system.ClientMetadata;
 import org.apache.iggy.system.Stats;
 import java.util.List;
 
@@ -61,6 +62,11 @@ class SystemHttpClient implements SystemClient {
         });
     }
 
+    @Override
+    public void identifyClient(ClientMetadata metadata) {
+        throw new UnsupportedOperationException("Method not available in HTTP client");
+    }
+
     @Override
     public String ping() {
         var request = httpClient.prepareGetRequest(PING);
@@ -43,6 +43,7 @@ import org.apache.iggy.stream.StreamBase;
 import org.apache.iggy.stream.StreamDetails;
 import org.apache.iggy.system.ClientInfo;
 import org.apache.iggy.system.ClientInfoDetails;
+import org.apache.iggy.system.ClientMetadata;
 import org.apache.iggy.system.ConsumerGroupInfo;
 import org.apache.iggy.system.Stats;
 import org.apache.iggy.topic.CompressionAlgorithm;
@@ -54,6 +55,8 @@ import java.util.*;
 
 final class BytesDeserializer {
 
+    private static final int STREAM_RETENTION_SIZE = 17;
+
     private BytesDeserializer() {
     }
 
@@ -71,6 +74,8 @@ final class BytesDeserializer {
 
     static StreamDetails readStreamDetails(ByteBuf response) {
         var streamBase = readStreamBase(response);
+        // Skip the stream retention: max size (u64), message expiry (u64) and size policy (u8).
+        response.skipBytes(STREAM_RETENTION_SIZE);
 
         List<Topic> topics = new ArrayList<>();
         if (response.isReadable()) {
@@ -266,7 +271,41 @@ final class BytesDeserializer {
         var addressLength = response.readUnsignedIntLE();
         var address = response.readCharSequence(toInt(addressLength), StandardCharsets.UTF_8).toString();
         var consumerGroupsCount = response.readUnsignedIntLE();
-        return new ClientInfo(clientId, userIdOptional, address, transportString, consumerGroupsCount);
+        var connectedAt = readU64AsBigInteger(response);
+        var lastActivityAt = readU64AsBigInteger(response);
+        var bytesReceived = readU64AsBigInteger(response);
+        var bytesSent = readU64AsBigInteger(response);
+        var authenticationMethod = switch (response.readByte()) {
+            case 1 -> Optional.of("Password");
+            case 2 -> Optional.of("PersonalAccessToken");
+            default -> Optional.<String>empty();
+        };
+        var metadataLength = response.readUnsignedIntLE();
+        var metadata = Optional.<ClientMetadata>empty();
+        if (metadataLength > 0) {
+            metadata = Optional.of(readClientMetadata(response));
+        }
+        return new ClientInfo(clientId, userIdOptional, address, transportString, consumerGroupsCount,
+                connectedAt, lastActivityAt, bytesReceived, bytesSent, authenticationMethod, metadata);
+    }
+
+    static ClientMetadata readClientMetadata(ByteBuf response) {
+        var applicationName = readShortString(response);
+        var sdkLanguage = readShortString(response);
+        var sdkVersion = readShortString(response);
+        var hostname = readShortString(response);
+        var labelsCount = response.readUnsignedByte();
+        var labels = new TreeMap<String, String>();
+        for (int i = 0; i < labelsCount; i++) {
+            var key = readShortString(response);
+            labels.put(key, readShortString(response));
+        }
+        return new ClientMetadata(applicationName, sdkLanguage, sdkVersion, hostname, labels);
+    }
+
+    private static String readShortString(ByteBuf response) {
+        var length = response.readUnsignedByte();
+        return response.readCharSequence(length, StandardCharsets.UTF_8).toString();
     }
 
     static ConsumerGroupInfo readConsumerGroupInfo(ByteBuf response) {
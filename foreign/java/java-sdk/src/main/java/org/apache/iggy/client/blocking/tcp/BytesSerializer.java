@@ -29,12 +29,15 @@ import org.apache.iggy.message.Message;
 import org.apache.iggy.message.MessageHeader;
 import org.apache.iggy.message.Partitioning;
 import org.apache.iggy.message.PollingStrategy;
+import org.apache.iggy.system.ClientMetadata;
 import org.apache.iggy.user.GlobalPermissions;
 import org.apache.iggy.user.Permissions;
 import org.apache.iggy.user.StreamPermissions;
 import org.apache.iggy.user.TopicPermissions;
 import java.math.BigInteger;
+import java.nio.charset.StandardCharsets;
 import java.util.Map;
+import java.util.TreeMap;
 
 public final class BytesSerializer {
 
@@ -189,6 +192,26 @@ public final class BytesSerializer {
         return buffer;
     }
 
+    static ByteBuf toBytes(ClientMetadata metadata) {
+        var buffer = Unpooled.buffer();
+        writeShortString(buffer, metadata.applicationName());
+        writeShortString(buffer, metadata.sdkLanguage());
+        writeShortString(buffer, metadata.sdkVersion());
+        writeShortString(buffer, metadata.hostname());
+        buffer.writeByte(metadata.labels().size());
+        for (Map.Entry<String, String> label : new TreeMap<>(metadata.labels()).entrySet()) {
+            writeShortString(buffer, label.getKey());
+            writeShortString(buffer, label.getValue());
+        }
+        return buffer;
+    }
+
+    private static void writeShortString(ByteBuf buffer, String value) {
+        var bytes = value.getBytes(StandardCharsets.UTF_8);
+        buffer.writeByte(bytes.length);
+        buffer.writeBytes(bytes);
+    }
+
     static ByteBuf toBytesAsU64(BigInteger value) {
         if (value.signum() == -1) {
             throw new IllegalArgumentException("Negative value cannot be serialized to unsigned 64: " + value);
@@ -28,7 +28,8 @@ public interface CommandCode {
     GET_STATS(10),
     GET_ME(20),
     GET_CLIENT(21),
-    GET_ALL_CLIENTS(22);
+    GET_ALL_CLIENTS(22),
+    IDENTIFY_CLIENT(24);
 
     private final int value;
 
@@ -29,9 +29,15 @@ import org.apache.iggy.client.blocking.StreamsClient;
 import org.apache.iggy.client.blocking.SystemClient;
 import org.apache.iggy.client.blocking.TopicsClient;
 import org.apache.iggy.client.blocking.UsersClient;
+import org.apache.iggy.system.ClientMetadata;
+import org.slf4j.Logger;
+import org.slf4j.LoggerFactory;
+import java.util.Map;
 
 public class IggyTcpClient implements IggyBaseClient {
 
+    private static final Logger log = LoggerFactory.getLogger(IggyTcpClient.class);
+
     private final UsersTcpClient usersClient;
     private final StreamsTcpClient streamsClient;
     private final TopicsTcpClient topicsClient;
@@ -43,6 +49,10 @@ public class IggyTcpClient implements IggyBaseClient {
     private final PersonalAccessTokensTcpClient personalAccessTokensClient;
 
     public IggyTcpClient(String host, Integer port) {
+        this(host, port, ClientMetadata.of("", Map.of()));
+    }
+
+    public IggyTcpClient(String host, Integer port, ClientMetadata metadata) {
         InternalTcpClient tcpClient = new InternalTcpClient(host, port);
         tcpClient.connect();
         usersClient = new UsersTcpClient(tcpClient);
@@ -54,6 +64,11 @@ public class IggyTcpClient implements IggyBaseClient {
         messagesClient = new MessagesTcpClient(tcpClient);
         systemClient = new SystemTcpClient(tcpClient);
         personalAccessTokensClient = new PersonalAccessTokensTcpClient(tcpClient);
+        try {
+            systemClient.identifyClient(metadata.withSdkDefaults());
+        } catch (RuntimeException e) {
+            log.warn("Could not send identification metadata to the server", e);
+        }
     }
 
     @Override
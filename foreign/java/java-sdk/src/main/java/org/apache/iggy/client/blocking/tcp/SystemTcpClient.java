@@ -23,6 +23,7 @@ import io.netty.buffer.Unpooled;
 import org.apache.iggy.client.blocking.SystemClient;
 import org.apache.iggy.system.ClientInfo;
 import org.apache.iggy.system.ClientInfoDetails;
+import org.apache.iggy.system.ClientMetadata;
 import org.apache.iggy.system.Stats;
 import java.util.ArrayList;
 import java.util.List;
@@ -66,6 +67,11 @@ class SystemTcpClient implements SystemClient {
         return clients;
     }
 
+    @Override
+    public void identifyClient(ClientMetadata metadata) {
+        tcpClient.send(CommandCode.System.IDENTIFY_CLIENT, BytesSerializer.toBytes(metadata));
+    }
+
     @Override
     public String ping() {
         tcpClient.send(CommandCode.System.PING);
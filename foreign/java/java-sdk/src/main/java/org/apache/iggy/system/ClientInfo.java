@@ -19,6 +19,7 @@
 
 package org.apache.iggy.system;
 
+import java.math.BigInteger;
 import java.util.Optional;
 
 public record ClientInfo(
@@ -26,6 +27,12 @@ public record ClientInfo(
         Optional<Long> userId,
         String address,
         String transport,
-        Long consumerGroupsCount
+        Long consumerGroupsCount,
+        BigInteger connectedAt,
+        BigInteger lastActivityAt,
+        BigInteger bytesReceived,
+        BigInteger bytesSent,
+        Optional<String> authenticationMethod,
+        Optional<ClientMetadata> metadata
 ) {
 }
@@ -19,6 +19,7 @@
 
 package org.apache.iggy.system;
 
+import java.math.BigInteger;
 import java.util.ArrayList;
 import java.util.List;
 import java.util.Optional;
@@ -29,9 +30,18 @@ public record ClientInfoDetails(
         String address,
         String transport,
         Long consumerGroupsCount,
+        BigInteger connectedAt,
+        BigInteger lastActivityAt,
+        BigInteger bytesReceived,
+        BigInteger bytesSent,
+        Optional<String> authenticationMethod,
+        Optional<ClientMetadata> metadata,
         List<ConsumerGroupInfo> consumerGroups
 ) {
     public ClientInfoDetails(ClientInfo clientInfo, ArrayList<ConsumerGroupInfo> consumerGroups) {
-        this(clientInfo.clientId(), clientInfo.userId(), clientInfo.address(), clientInfo.transport(), clientInfo.consumerGroupsCount(), consumerGroups);
+        this(clientInfo.clientId(), clientInfo.userId(), clientInfo.address(), clientInfo.transport(),
+                clientInfo.consumerGroupsCount(), clientInfo.connectedAt(), clientInfo.lastActivityAt(),
+                clientInfo.bytesReceived(), clientInfo.bytesSent(), clientInfo.authenticationMethod(), clientInfo.metadata(),
+                consumerGroups);
     }
 }
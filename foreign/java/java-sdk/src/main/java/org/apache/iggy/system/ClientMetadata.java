@@ -0,0 +1,58 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+package org.apache.iggy.system;
+
+import java.net.InetAddress;
+import java.net.UnknownHostException;
+import java.util.Map;
+import java.util.Optional;
+import java.util.TreeMap;
+
+public record ClientMetadata(
+        String applicationName,
+        String sdkLanguage,
+        String sdkVersion,
+        String hostname,
+        Map<String, String> labels
+) {
+    public static ClientMetadata of(String applicationName, Map<String, String> labels) {
+        return new ClientMetadata(applicationName, "", "", "", labels);
+    }
+
+    public ClientMetadata withSdkDefaults() {
+        var version = Optional.ofNullable(ClientMetadata.class.getPackage().getImplementationVersion()).orElse("");
+        var host = hostname;
+        if (host == null || host.isEmpty()) {
+            try {
+                host = InetAddress.getLocalHost().getHostName();
+            } catch (UnknownHostException e) {
+                host = "";
+            }
+        }
+        var application = applicationName;
+        if (application == null || application.isEmpty()) {
+            application = Optional.ofNullable(System.getProperty("sun.java.command"))
+                    .map(command -> command.split(" ")[0])
+                    .orElse("");
+        }
+        return new ClientMetadata(application, "java", version, host,
+                labels == null ? new TreeMap<>() : new TreeMap<>(labels));
+    }
+}
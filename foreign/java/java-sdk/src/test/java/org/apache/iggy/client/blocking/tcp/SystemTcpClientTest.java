@@ -41,6 +41,8 @@ class SystemTcpClientTest extends SystemClientBaseTest {
 
         // then
         assertThat(me).isNotNull();
+        assertThat(me.metadata()).hasValueSatisfying(metadata ->
+                assertThat(metadata.sdkLanguage()).isEqualTo("java"));
 
         // when
         var clientInfo = systemClient.getClient(me.clientId());
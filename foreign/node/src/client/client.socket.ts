@@ -28,13 +28,14 @@ import { handleResponse } from './client.utils.js';
 import { responseError } from '../wire/error.utils.js';
 import { debug } from './client.debug.js';
 import { IggyConnection } from './client.connection.js';
-import { LOGIN, LOGIN_WITH_TOKEN, PING } from '../wire/index.js';
+import { IDENTIFY_CLIENT, LOGIN, LOGIN_WITH_TOKEN, PING } from '../wire/index.js';
 
 
 const UNLOGGED_COMMAND_CODE = [
   PING.code,
   LOGIN.code,
-  LOGIN_WITH_TOKEN.code
+  LOGIN_WITH_TOKEN.code,
+  IDENTIFY_CLIENT.code
 ];
 
 type Job = {
@@ -141,6 +142,7 @@ export class CommandResponseStream extends EventEmitter {
   }
 
   async authenticate(creds: ClientCredentials) {
+    await this._identify();
     const r = ('token' in creds) ?
       await this._authWithToken(creds) :
       await this._authWithPassword(creds);
@@ -149,6 +151,15 @@ export class CommandResponseStream extends EventEmitter {
     return this.isAuthenticated;
   }
 
+  async _identify() {
+    try {
+      const pl = IDENTIFY_CLIENT.serialize(this.options.metadata || {});
+      await this.sendCommand(IDENTIFY_CLIENT.code, pl, true, false);
+    } catch (err) {
+      debug('could not send identification metadata to the server', err);
+    }
+  }
+
   async _authWithPassword(creds: PasswordCredentials) {
     const pl = LOGIN.serialize(creds);
     const logr = await this.sendCommand(LOGIN.code, pl, true, false);
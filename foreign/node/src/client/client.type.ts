@@ -21,6 +21,7 @@
 import type { Readable } from 'stream';
 import { type TcpSocketConnectOpts } from 'node:net';
 import { type ConnectionOptions } from 'node:tls';
+import type { IdentifyClient } from '../wire/client/identify-client.command.js';
 
 export type TcpOption = TcpSocketConnectOpts;
 export type TlsOption = { port: number } & ConnectionOptions;
@@ -78,5 +79,6 @@ export type ClientConfig = {
   credentials: ClientCredentials,
   poolSize?: PoolSizeOption,
   reconnect?: ReconnectOption,
-  heartbeatInterval?: number
+  heartbeatInterval?: number,
+  metadata?: IdentifyClient
 }
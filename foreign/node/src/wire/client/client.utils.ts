@@ -17,13 +17,28 @@
  * under the License.
  */
 
+import { toDate } from '../serialize.utils.js';
 
 export type Client = {
   clientId: number,
   userId: number,
   transport: string,
   address: string,
-  consumerGroupCount: number
+  consumerGroupCount: number,
+  connectedAt: Date,
+  lastActivityAt: Date,
+  bytesReceived: bigint,
+  bytesSent: bigint,
+  authenticationMethod: string | null,
+  metadata: ClientMetadata | null
+};
+
+export type ClientMetadata = {
+  applicationName: string,
+  sdkLanguage: string,
+  sdkVersion: string,
+  hostname: string,
+  labels: Record<string, string>
 };
 
 type ClientDeserialized = {
@@ -40,6 +55,57 @@ const transportString = (t: number): string => {
   }
 }
 
+const authenticationMethodString = (m: number): string | null => {
+  switch (m.toString()) {
+    case '1': return 'password';
+    case '2': return 'personal_access_token';
+    default: return null;
+  }
+}
+
+const readShortString = (r: Buffer, pos: number): [string, number] => {
+  const len = r.readUInt8(pos);
+  return [r.subarray(pos + 1, pos + 1 + len).toString(), 1 + len];
+};
+
+export const deserializeClientMetadata = (r: Buffer, pos = 0): ClientMetadata => {
+  const fields: string[] = [];
+  for (let i = 0; i < 4; i++) {
+    const [value, read] = readShortString(r, pos);
+    fields.push(value);
+    pos += read;
+  }
+  const labelsCount = r.readUInt8(pos);
+  pos += 1;
+  const labels: Record<string, string> = {};
+  for (let i = 0; i < labelsCount; i++) {
+    const [key, keyRead] = readShortString(r, pos);
+    const [value, valueRead] = readShortString(r, pos + keyRead);
+    labels[key] = value;
+    pos += keyRead + valueRead;
+  }
+  const [applicationName, sdkLanguage, sdkVersion, hostname] = fields;
+  return { applicationName, sdkLanguage, sdkVersion, hostname, labels };
+};
+
+export const serializeClientMetadata = (m: ClientMetadata): Buffer => {
+  const str = (s: string) => {
+    const b = Buffer.from(s);
+    if (b.length > 255)
+      throw new Error('client metadata value should not exceed 255 bytes');
+    return Buffer.concat([Buffer.from([b.length]), b]);
+  };
+  const labels = Object.entries(m.labels).sort(([a], [b]) => a < b ? -1 : a > b ? 1 : 0);
+  return Buffer.concat([
+    str(m.applicationName),
+    str(m.sdkLanguage),
+    str(m.sdkVersion),
+    str(m.hostname),
+    Buffer.from([labels.length]),
+    ...labels.flatMap(([k, v]) => [str(k), str(v)])
+  ]);
+};
+
 export const deserializeClient = (r: Buffer, pos = 0): ClientDeserialized => {
   /**
    *  0 - 4   u32 - client_id
@@ -48,20 +114,35 @@ export const deserializeClient = (r: Buffer, pos = 0): ClientDeserialized => {
    *  9 - 13  u32 - adress length x
    * 13 - x   string - adress
    *  x - x+4 u32 - consumerGroupCount
+   *  x+4 - x+12 u64 - connectedAt
+   *  x+12 - x+20 u64 - lastActivityAt
+   *  x+20 - x+28 u64 - bytesReceived
+   *  x+28 - x+36 u64 - bytesSent
+   *  x+36 - x+37 u8 - authenticationMethod
+   *  x+37 - x+41 u32 - metadata length y
+   *  x+41 - x+41+y   - metadata
    */
   if(r.length < 17)
     throw new Error('Client does not exist');
 
   const addressLength = r.readUInt32LE(pos + 9);
+  const x = pos + 13 + addressLength;
+  const metadataLength = r.readUInt32LE(x + 37);
 
   return {
-    bytesRead: 13 + addressLength + 4,
+    bytesRead: 13 + addressLength + 4 + 8 * 4 + 1 + 4 + metadataLength,
     data: {
       clientId: r.readUInt32LE(pos),
       userId: r.readUInt32LE(pos + 4),
       transport: transportString(r.readUInt8(pos + 8)), // { 1: tcp, 2: quic }
       address: r.subarray(pos + 13, pos + 13 + addressLength).toString(),
-      consumerGroupCount: r.readUInt32LE(pos + 13 + addressLength)
+      consumerGroupCount: r.readUInt32LE(x),
+      connectedAt: toDate(r.readBigUInt64LE(x + 4)),
+      lastActivityAt: toDate(r.readBigUInt64LE(x + 12)),
+      bytesReceived: r.readBigUInt64LE(x + 20),
+      bytesSent: r.readBigUInt64LE(x + 28),
+      authenticationMethod: authenticationMethodString(r.readUInt8(x + 36)),
+      metadata: metadataLength > 0 ? deserializeClientMetadata(r, x + 41) : null
     }
   }
 };
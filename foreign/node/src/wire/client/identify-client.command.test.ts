@@ -0,0 +1,50 @@
+/**
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+
+import { describe, it } from 'node:test';
+import assert from 'node:assert/strict';
+import { IDENTIFY_CLIENT } from './identify-client.command.js';
+import { deserializeClientMetadata } from './client.utils.js';
+
+describe("IdentifyClient Command", () => {
+
+  const m1 = {
+    applicationName: 'orders-service',
+    hostname: 'host-1',
+    labels: { env: 'prod', az: 'eu-1' }
+  };
+
+  it("serialize metadata into a buffer", () => {
+    const b = IDENTIFY_CLIENT.serialize(m1);
+    const m = deserializeClientMetadata(b);
+    assert.deepEqual(m.applicationName, m1.applicationName);
+    assert.deepEqual(m.hostname, m1.hostname);
+    assert.deepEqual(m.sdkLanguage, 'node');
+    assert.deepEqual(m.labels, m1.labels);
+  });
+
+  it("throw on application name > 255 bytes", () => {
+    const m2 = { ...m1, applicationName: "YoLo".repeat(65) };
+    assert.throws(
+      () => IDENTIFY_CLIENT.serialize(m2)
+    );
+  });
+
+});
@@ -0,0 +1,51 @@
+/**
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+
+import { hostname } from 'node:os';
+import { basename } from 'node:path';
+import { deserializeVoidResponse } from '../../client/client.utils.js';
+import { serializeClientMetadata, type ClientMetadata } from './client.utils.js';
+import { wrapCommand } from '../command.utils.js';
+import { COMMAND_CODE } from '../command.code.js';
+
+// keep in sync with package.json
+const SDK_VERSION = '0.5.0';
+
+export type IdentifyClient = Partial<Pick<ClientMetadata, 'applicationName' | 'hostname' | 'labels'>>;
+
+export const withSdkDefaults = (m: IdentifyClient = {}): ClientMetadata => ({
+  applicationName: m.applicationName || basename(process.argv[1] || process.argv[0]),
+  sdkLanguage: 'node',
+  sdkVersion: SDK_VERSION,
+  hostname: m.hostname || hostname(),
+  labels: m.labels || {}
+});
+
+// IDENTIFY CLIENT
+export const IDENTIFY_CLIENT = {
+  code: COMMAND_CODE.IdentifyClient,
+
+  serialize: (metadata: IdentifyClient): Buffer =>
+    serializeClientMetadata(withSdkDefaults(metadata)),
+
+  deserialize: deserializeVoidResponse
+};
+
+export const identifyClient = wrapCommand<IdentifyClient, boolean>(IDENTIFY_CLIENT);
@@ -21,3 +21,4 @@
 export * from './get-client.command.js';
 export * from './get-clients.command.js';
 export * from './get-me.command.js';
+export * from './identify-client.command.js';
@@ -27,6 +27,7 @@ import { loginWithToken } from './session/login-with-token.command.js';
 import { getMe } from './client/get-me.command.js';
 import { getClients } from './client/get-clients.command.js';
 import { getClient } from './client/get-client.command.js';
+import { identifyClient } from './client/identify-client.command.js';
 
 import { createGroup } from './consumer-group/create-group.command.js';
 import { joinGroup } from './consumer-group/join-group.command.js';
@@ -102,7 +103,8 @@ type SessionAPI = ReturnType<typeof sessionAPI>;
 const clientAPI = (c: ClientProvider) => ({
   get: getClient(c),
   getMe: getMe(c),
-  list: getClients(c)
+  list: getClients(c),
+  identify: identifyClient(c)
 });
 
 type ClientAPI = ReturnType<typeof clientAPI>;
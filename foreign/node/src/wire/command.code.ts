@@ -27,6 +27,7 @@ export const COMMAND_CODE = {
   GetMe: 20,
   GetClient: 21,
   GetClients: 22,
+  IdentifyClient: 24,
   GetUser: 31,
   GetUsers: 32,
   CreateUser: 33,